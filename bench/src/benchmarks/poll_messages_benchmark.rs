@@ -40,6 +40,8 @@ pub async fn run(
         strategy: PollingStrategy::offset(0),
         count: args.messages_per_batch,
         auto_commit: false,
+        max_wait: 0,
+        filter: None,
     };
 
     let mut latencies: Vec<Duration> = Vec::with_capacity(args.message_batches as usize);
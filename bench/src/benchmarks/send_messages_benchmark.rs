@@ -7,7 +7,7 @@ use iggy::client::MessageClient;
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -38,6 +38,9 @@ pub async fn run(
         stream_id: Identifier::numeric(stream_id)?,
         topic_id: Identifier::numeric(topic_id)?,
         partitioning: Partitioning::partition_id(partition_id),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
         messages,
     };
 
@@ -3,6 +3,7 @@ use crate::benchmarks::login_root;
 use crate::client_factory::ClientFactory;
 use iggy::client::{StreamClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
 use iggy::streams::create_stream::CreateStream;
@@ -43,6 +44,10 @@ pub async fn init_streams(
                     partitions_count,
                     name,
                     message_expiry: None,
+                    compression_algorithm: CompressionAlgorithm::default(),
+                    compaction_enabled: false,
+                    max_topic_size: None,
+                    max_partition_size: None,
                 })
                 .await?;
         }
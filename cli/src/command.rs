@@ -1,6 +1,6 @@
 use crate::{
-    consumer_groups, consumer_offsets, messages, partitions, personal_access_tokens, streams,
-    system, topics, users,
+    consumer_groups, consumer_offsets, messages, partitions, personal_access_tokens, schemas,
+    streams, system, topics, transactions, users,
 };
 use iggy::client_error::ClientError;
 use iggy::clients::client::IggyClient;
@@ -14,7 +14,11 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
     info!("Handling '{}' command...", command);
     match command {
         Command::Ping(payload) => system::ping(&payload, client).await,
+        Command::GetClusterMetadata(payload) => {
+            system::get_cluster_metadata(&payload, client).await
+        }
         Command::GetStats(payload) => system::get_stats(&payload, client).await,
+        Command::CreateBackup(payload) => system::create_backup(&payload, client).await,
         Command::GetMe(payload) => system::get_me(&payload, client).await,
         Command::GetClient(payload) => system::get_client(&payload, client).await,
         Command::GetClients(payload) => system::get_clients(&payload, client).await,
@@ -40,6 +44,9 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
             personal_access_tokens::login_with_personal_access_token(&payload, client).await
         }
         Command::SendMessages(mut payload) => messages::send_messages(&mut payload, client).await,
+        Command::PoisonMessage(payload) => messages::poison_message(&payload, client).await,
+        // The messages are replicated only by the cluster nodes themselves.
+        Command::ReplicateMessages(_) => Err(ClientError::InvalidCommand),
         Command::PollMessages(payload) => {
             let format = match input.split('|').last() {
                 Some(format) => match format {
@@ -58,6 +65,18 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
         Command::GetConsumerOffset(payload) => {
             consumer_offsets::get_consumer_offset(&payload, client).await
         }
+        Command::GetConsumerLag(payload) => {
+            consumer_offsets::get_consumer_lag(&payload, client).await
+        }
+        Command::BeginTransaction(payload) => {
+            transactions::begin_transaction(&payload, client).await
+        }
+        Command::CommitTransaction(payload) => {
+            transactions::commit_transaction(&payload, client).await
+        }
+        Command::AbortTransaction(payload) => {
+            transactions::abort_transaction(&payload, client).await
+        }
         Command::GetStream(payload) => streams::get_stream(&payload, client).await,
         Command::GetStreams(payload) => streams::get_streams(&payload, client).await,
         Command::CreateStream(payload) => streams::create_stream(&payload, client).await,
@@ -88,6 +107,16 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
         Command::LeaveConsumerGroup(payload) => {
             consumer_groups::leave_consumer_group(&payload, client).await
         }
+        Command::HeartbeatConsumerGroup(payload) => {
+            consumer_groups::heartbeat_consumer_group(&payload, client).await
+        }
+        Command::GetSchema(payload) => schemas::get_schema(&payload, client).await,
+        Command::GetSchemas(payload) => schemas::get_schemas(&payload, client).await,
+        Command::RegisterSchema(payload) => schemas::register_schema(&payload, client).await,
+        Command::DeleteSchema(payload) => schemas::delete_schema(&payload, client).await,
+        Command::UpdateSchemaSettings(payload) => {
+            schemas::update_schema_settings(&payload, client).await
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 use iggy::client::Client;
 use iggy::client_error::ClientError;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use tracing::info;
@@ -20,3 +21,12 @@ pub async fn store_consumer_offset(
     client.store_consumer_offset(command).await?;
     Ok(())
 }
+
+pub async fn get_consumer_lag(
+    command: &GetConsumerLag,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let lag = client.get_consumer_lag(command).await?;
+    info!("Consumer lag: {:#?}", lag);
+    Ok(())
+}
@@ -4,9 +4,11 @@ mod consumer_offsets;
 mod messages;
 mod partitions;
 mod personal_access_tokens;
+mod schemas;
 mod streams;
 mod system;
 mod topics;
+mod transactions;
 mod users;
 
 use anyhow::Result;
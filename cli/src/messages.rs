@@ -1,6 +1,7 @@
 use crate::command::{Format, PollMessagesWithFormat};
 use iggy::client::Client;
 use iggy::client_error::ClientError;
+use iggy::messages::poison_message::PoisonMessage;
 use iggy::messages::send_messages::SendMessages;
 use tracing::info;
 
@@ -12,6 +13,14 @@ pub async fn send_messages(
     Ok(())
 }
 
+pub async fn poison_message(
+    command: &PoisonMessage,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.poison_message(command).await?;
+    Ok(())
+}
+
 pub async fn poll_messages(
     command: &PollMessagesWithFormat,
     client: &dyn Client,
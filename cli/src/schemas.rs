@@ -0,0 +1,42 @@
+use iggy::client::Client;
+use iggy::client_error::ClientError;
+use iggy::schemas::delete_schema::DeleteSchema;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_settings::UpdateSchemaSettings;
+use tracing::info;
+
+pub async fn get_schema(command: &GetSchema, client: &dyn Client) -> Result<(), ClientError> {
+    let schema = client.get_schema(command).await?;
+    info!("Schema: {:#?}", schema);
+    Ok(())
+}
+
+pub async fn get_schemas(command: &GetSchemas, client: &dyn Client) -> Result<(), ClientError> {
+    let schemas = client.get_schemas(command).await?;
+    info!("Schemas: {:#?}", schemas);
+    Ok(())
+}
+
+pub async fn register_schema(
+    command: &RegisterSchema,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let schema = client.register_schema(command).await?;
+    info!("Registered schema with ID: {}", schema.id);
+    Ok(())
+}
+
+pub async fn delete_schema(command: &DeleteSchema, client: &dyn Client) -> Result<(), ClientError> {
+    client.delete_schema(command).await?;
+    Ok(())
+}
+
+pub async fn update_schema_settings(
+    command: &UpdateSchemaSettings,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.update_schema_settings(command).await?;
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use iggy::client::Client;
 use iggy::client_error::ClientError;
+use iggy::cluster::get_cluster_metadata::GetClusterMetadata;
+use iggy::system::create_backup::CreateBackup;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
@@ -12,12 +14,27 @@ pub async fn ping(command: &Ping, client: &dyn Client) -> Result<(), ClientError
     Ok(())
 }
 
+pub async fn get_cluster_metadata(
+    command: &GetClusterMetadata,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let metadata = client.get_cluster_metadata(command).await?;
+    info!("Cluster metadata: {:#?}", metadata);
+    Ok(())
+}
+
 pub async fn get_stats(command: &GetStats, client: &dyn Client) -> Result<(), ClientError> {
     let stats = client.get_stats(command).await?;
     info!("Stats: {:#?}", stats);
     Ok(())
 }
 
+pub async fn create_backup(command: &CreateBackup, client: &dyn Client) -> Result<(), ClientError> {
+    let backup = client.create_backup(command).await?;
+    info!("Backup: {:#?}", backup);
+    Ok(())
+}
+
 pub async fn get_me(command: &GetMe, client: &dyn Client) -> Result<(), ClientError> {
     let me = client.get_me(command).await?;
     info!("Me: {:#?}", me);
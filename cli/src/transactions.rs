@@ -0,0 +1,31 @@
+use iggy::client::Client;
+use iggy::client_error::ClientError;
+use iggy::transactions::abort_transaction::AbortTransaction;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use iggy::transactions::commit_transaction::CommitTransaction;
+use tracing::info;
+
+pub async fn begin_transaction(
+    command: &BeginTransaction,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let transaction = client.begin_transaction(command).await?;
+    info!("Transaction: {:#?}", transaction);
+    Ok(())
+}
+
+pub async fn commit_transaction(
+    command: &CommitTransaction,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.commit_transaction(command).await?;
+    Ok(())
+}
+
+pub async fn abort_transaction(
+    command: &AbortTransaction,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.abort_transaction(command).await?;
+    Ok(())
+}
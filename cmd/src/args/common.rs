@@ -1,7 +1,10 @@
 use clap::ValueEnum;
+use iggy::cmd::consumer_groups::get_consumer_groups::GetConsumerGroupsOutput;
 use iggy::cmd::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokensOutput;
+use iggy::cmd::schemas::get_schemas::GetSchemasOutput;
 use iggy::cmd::streams::get_streams::GetStreamsOutput;
 use iggy::cmd::topics::get_topics::GetTopicsOutput;
+use iggy::cmd::users::get_users::GetUsersOutput;
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub(crate) enum ListMode {
@@ -35,3 +38,30 @@ impl From<ListMode> for GetPersonalAccessTokensOutput {
         }
     }
 }
+
+impl From<ListMode> for GetSchemasOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetSchemasOutput::Table,
+            ListMode::List => GetSchemasOutput::List,
+        }
+    }
+}
+
+impl From<ListMode> for GetConsumerGroupsOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetConsumerGroupsOutput::Table,
+            ListMode::List => GetConsumerGroupsOutput::List,
+        }
+    }
+}
+
+impl From<ListMode> for GetUsersOutput {
+    fn from(mode: ListMode) -> Self {
+        match mode {
+            ListMode::Table => GetUsersOutput::Table,
+            ListMode::List => GetUsersOutput::List,
+        }
+    }
+}
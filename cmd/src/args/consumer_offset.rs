@@ -0,0 +1,24 @@
+use clap::Args;
+use iggy::identifier::Identifier;
+
+#[derive(Debug, Args)]
+pub(crate) struct ConsumerLagArgs {
+    /// Stream ID of the topic consumed
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID consumed
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Consumer ID or consumer group ID which stored the offsets
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer_id: Identifier,
+    /// Treat the consumer ID as the consumer group ID
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) group: bool,
+}
@@ -1,13 +1,19 @@
 pub(crate) mod common;
+pub(crate) mod consumer_group;
+pub(crate) mod consumer_offset;
 pub(crate) mod partition;
 pub(crate) mod personal_access_token;
+pub(crate) mod schema;
 pub(crate) mod stream;
 pub(crate) mod system;
 pub(crate) mod topic;
+pub(crate) mod user;
 
 use crate::args::{
+    consumer_group::ConsumerGroupAction, consumer_offset::ConsumerLagArgs,
     partition::PartitionAction, personal_access_token::PersonalAccessTokenAction,
-    stream::StreamAction, system::PingArgs, topic::TopicAction,
+    schema::SchemaAction, stream::StreamAction, system::PingArgs, topic::TopicAction,
+    user::UserAction,
 };
 use clap::{Parser, Subcommand};
 use iggy::args::Args as IggyArgs;
@@ -50,6 +56,12 @@ pub(crate) enum Command {
     /// partition operations
     #[clap(subcommand)]
     Partition(PartitionAction),
+    /// schema registry operations
+    #[clap(subcommand)]
+    Schema(SchemaAction),
+    /// consumer group operations
+    #[clap(subcommand)]
+    ConsumerGroup(ConsumerGroupAction),
     /// ping iggy server
     ///
     /// Check if iggy server is up and running and what's the response ping response time
@@ -64,6 +76,25 @@ pub(crate) enum Command {
     /// Collect basic Iggy server statistics like number of streams, topics, partitions, etc.
     /// Server OS name, version, etc. are also collected.
     Stats,
+    /// get consumer or consumer group lag
+    ///
+    /// Get the number of messages not consumed yet by the consumer or the consumer group
+    /// in each partition of the topic, based on the stored consumer offsets.
+    ///
+    /// Examples
+    ///  iggy lag 1 1 1
+    ///  iggy lag --group prod orders 2
+    #[clap(verbatim_doc_comment)]
+    Lag(ConsumerLagArgs),
+    /// create server state backup
+    ///
+    /// Create the archive with the consistent snapshot of the server state, including
+    /// the metadata and the segment files. The server state can be restored from the archive
+    /// by starting the server with the --restore-backup option.
+    Backup,
+    /// user operations
+    #[clap(subcommand)]
+    User(UserAction),
     /// personal access token operations
     #[clap(subcommand)]
     Pat(PersonalAccessTokenAction),
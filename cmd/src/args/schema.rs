@@ -0,0 +1,151 @@
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand};
+use iggy::identifier::Identifier;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+use iggy::schemas::schema_format::SchemaFormat;
+use std::path::PathBuf;
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum SchemaAction {
+    /// Register new version of the schema from given file
+    /// for topic ID in given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy schema register 1 1 ./order.json
+    ///  iggy schema register -f protobuf prod orders ./order.proto
+    #[clap(verbatim_doc_comment)]
+    Register(SchemaRegisterArgs),
+    /// Delete schema with given ID for topic ID in given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy schema delete 1 1 1
+    ///  iggy schema delete prod orders 2
+    #[clap(verbatim_doc_comment)]
+    Delete(SchemaDeleteArgs),
+    /// Get schema with given ID for topic ID in given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy schema get 1 1 1
+    ///  iggy schema get prod orders 2
+    #[clap(verbatim_doc_comment)]
+    Get(SchemaGetArgs),
+    /// List all schemas for topic ID in given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy schema list 1 1
+    ///  iggy schema list prod orders
+    #[clap(verbatim_doc_comment)]
+    List(SchemaListArgs),
+    /// Update compatibility mode and payload validation of the schemas
+    /// for topic ID in given stream ID
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy schema settings 1 1 full
+    ///  iggy schema settings --validate prod orders backward
+    ///  iggy schema settings prod orders none
+    #[clap(verbatim_doc_comment)]
+    Settings(SchemaSettingsArgs),
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct SchemaRegisterArgs {
+    /// Stream ID to register schema
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to register schema
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Path to the file with the schema definition
+    pub(crate) definition_file: PathBuf,
+    /// Format of the schema definition (json_schema, protobuf or avro)
+    #[arg(short, long, default_value_t = SchemaFormat::JsonSchema)]
+    pub(crate) format: SchemaFormat,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct SchemaDeleteArgs {
+    /// Stream ID to delete schema
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to delete schema
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Schema ID to delete
+    pub(crate) schema_id: u32,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct SchemaGetArgs {
+    /// Stream ID to get schema
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get schema
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Schema ID to get
+    pub(crate) schema_id: u32,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct SchemaListArgs {
+    /// Stream ID to list schemas
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to list schemas
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct SchemaSettingsArgs {
+    /// Stream ID to update schema settings
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to update schema settings
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Compatibility mode checked for the newly registered schemas (none, backward, forward or full)
+    pub(crate) compatibility: SchemaCompatibility,
+    /// Validate the payloads of the sent messages against the schema given in their header
+    #[arg(long)]
+    pub(crate) validate: bool,
+}
@@ -1,6 +1,8 @@
 use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
 use iggy::cmd::utils::message_expiry::MessageExpiry;
+use iggy::cmd::utils::topic_size::TopicSize;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
 use std::convert::From;
 
@@ -15,6 +17,9 @@ pub(crate) enum TopicAction {
     ///  iggy topic create 1 1 2 sensor1 15days
     ///  iggy topic create prod 2 2 sensor2
     ///  iggy topic create test 3 2 debugs 1day 1hour 1min 1sec
+    ///  iggy topic create -c zstd prod 4 2 logs 7days
+    ///  iggy topic create --compaction prod 5 2 changelog
+    ///  iggy topic create --max-topic-size 10GB --max-partition-size 2GB prod 6 5 metrics
     #[clap(verbatim_doc_comment)]
     Create(TopicCreateArgs),
     /// Delete topic with given ID in given stream ID
@@ -40,6 +45,7 @@ pub(crate) enum TopicAction {
     ///  iggy update test debugs ready 15days
     ///  iggy update 1 1 new-name
     ///  iggy update 1 2 new-name 1day 1hour 1min 1sec
+    ///  iggy topic update --max-topic-size 5GB prod metrics metrics
     #[clap(verbatim_doc_comment)]
     Update(TopicUpdateArgs),
     /// Get topic detail for given topic ID and stream ID
@@ -82,6 +88,20 @@ pub(crate) struct TopicCreateArgs {
     /// ("none" or skipping parameter disables message expiry functionality in topic)
     #[arg(value_parser = clap::value_parser!(MessageExpiry))]
     pub(crate) message_expiry: Option<Vec<MessageExpiry>>,
+    /// Compression algorithm used for the messages stored on disk (none, lz4 or zstd)
+    #[arg(short, long, default_value_t = CompressionAlgorithm::None)]
+    pub(crate) compression_algorithm: CompressionAlgorithm,
+    /// Enable log compaction, which keeps only the latest message for each messages key
+    #[arg(long)]
+    pub(crate) compaction: bool,
+    /// Maximum size of the topic in human readable format like 10GB or 512MiB,
+    /// the oldest segments are deleted once it is exceeded
+    #[arg(long, value_parser = clap::value_parser!(TopicSize))]
+    pub(crate) max_topic_size: Option<TopicSize>,
+    /// Maximum size of every partition in the topic in human readable format like 1GB,
+    /// the oldest segments of the partition are deleted once it is exceeded
+    #[arg(long, value_parser = clap::value_parser!(TopicSize))]
+    pub(crate) max_partition_size: Option<TopicSize>,
 }
 
 #[derive(Debug, Args)]
@@ -116,6 +136,14 @@ pub(crate) struct TopicUpdateArgs {
     /// ("none" or skipping parameter causes removal of expiry parameter in topic)
     #[arg(value_parser = clap::value_parser!(MessageExpiry))]
     pub(crate) message_expiry: Option<Vec<MessageExpiry>>,
+    /// New maximum size of the topic in human readable format like 10GB or 512MiB
+    /// (skipping parameter causes removal of the topic size limit)
+    #[arg(long, value_parser = clap::value_parser!(TopicSize))]
+    pub(crate) max_topic_size: Option<TopicSize>,
+    /// New maximum size of every partition in the topic in human readable format like 1GB
+    /// (skipping parameter causes removal of the partition size limit)
+    #[arg(long, value_parser = clap::value_parser!(TopicSize))]
+    pub(crate) max_partition_size: Option<TopicSize>,
 }
 
 #[derive(Debug, Args)]
@@ -0,0 +1,201 @@
+use crate::args::common::ListMode;
+use clap::{Args, Subcommand, ValueEnum};
+use iggy::cmd::utils::permissions::{GlobalPermissionsArg, PermissionsArgs, StreamPermissionsArg};
+use iggy::identifier::Identifier;
+use iggy::models::user_quota::UserQuota;
+use iggy::models::user_status::UserStatus;
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum UserAction {
+    /// Create user with given username and password
+    ///
+    /// Permissions are granted with the compact syntax, global permissions are
+    /// a comma separated list of: m_srv, r_srv, m_usr, r_usr, m_str, r_str,
+    /// m_top, r_top, p_msg, s_msg (manage or read servers, users, streams
+    /// and topics, poll and send messages). Stream permissions are specified as
+    /// stream_id[:permissions][#topic_id[:permissions]]... where the stream
+    /// permissions are m_str, r_str, m_top, r_top, p_msg, s_msg and the topic
+    /// permissions are m_top, r_top, p_msg, s_msg.
+    ///
+    /// Examples
+    ///  iggy user create testuser pass#1%X!
+    ///  iggy user create guest guess --user-status inactive
+    ///  iggy user create admin secret -g m_srv,m_usr,m_str
+    ///  iggy user create reader secret -s 1:r_str,p_msg -s 2#1:p_msg#2:p_msg,s_msg
+    #[clap(verbatim_doc_comment)]
+    Create(UserCreateArgs),
+    /// Delete user with given ID
+    ///
+    /// User ID can be specified as a username or ID
+    ///
+    /// Examples
+    ///  iggy user delete 2
+    ///  iggy user delete testuser
+    #[clap(verbatim_doc_comment)]
+    Delete(UserDeleteArgs),
+    /// Get details of a single user with given ID
+    ///
+    /// User ID can be specified as a username or ID
+    ///
+    /// Examples
+    ///  iggy user get 2
+    ///  iggy user get testuser
+    #[clap(verbatim_doc_comment)]
+    Get(UserGetArgs),
+    /// List all users
+    ///
+    /// Examples
+    ///  iggy user list
+    ///  iggy user list --list-mode table
+    ///  iggy user list -l table
+    #[clap(verbatim_doc_comment)]
+    List(UserListArgs),
+    /// Update username, status and quota of the user with given ID
+    ///
+    /// User ID can be specified as a username or ID
+    ///
+    /// Examples
+    ///  iggy user update 2 --username new_name
+    ///  iggy user update testuser -u inactive
+    ///  iggy user update guest -n user -u active
+    ///  iggy user update producer -q 1048576:100
+    #[clap(verbatim_doc_comment)]
+    Update(UserUpdateArgs),
+    /// Change password for user with given ID
+    ///
+    /// User ID can be specified as a username or ID
+    ///
+    /// Examples
+    ///  iggy user password 2 current_password new_password
+    ///  iggy user password client secret n3w$ecret
+    #[clap(verbatim_doc_comment)]
+    Password(UserPasswordArgs),
+    /// Set permissions for user with given ID
+    ///
+    /// User ID can be specified as a username or ID
+    /// Permissions use the same compact syntax as in the create command,
+    /// skipping them removes all the permissions of the user.
+    ///
+    /// Examples
+    ///  iggy user permissions 2 -g m_srv,r_srv
+    ///  iggy user permissions client -s 1:p_msg,s_msg
+    ///  iggy user permissions producer -g r_str -s 3#1:s_msg -s 4:r_top
+    #[clap(verbatim_doc_comment)]
+    Permissions(UserPermissionsArgs),
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum UserStatusArg {
+    Active,
+    Inactive,
+}
+
+impl From<UserStatusArg> for UserStatus {
+    fn from(status: UserStatusArg) -> Self {
+        match status {
+            UserStatusArg::Active => UserStatus::Active,
+            UserStatusArg::Inactive => UserStatus::Inactive,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct PermissionsArgsGroup {
+    /// Global permissions, comma separated list of: m_srv, r_srv, m_usr,
+    /// r_usr, m_str, r_str, m_top, r_top, p_msg, s_msg
+    #[arg(short, long, value_parser = clap::value_parser!(GlobalPermissionsArg))]
+    pub(crate) global_permissions: Option<GlobalPermissionsArg>,
+    /// Permissions to the stream and its topics, specified as
+    /// stream_id[:permissions][#topic_id[:permissions]]...
+    #[arg(short, long, value_parser = clap::value_parser!(StreamPermissionsArg))]
+    pub(crate) stream_permissions: Option<Vec<StreamPermissionsArg>>,
+}
+
+impl From<&PermissionsArgsGroup> for PermissionsArgs {
+    fn from(args: &PermissionsArgsGroup) -> Self {
+        PermissionsArgs::new(
+            args.global_permissions.clone(),
+            args.stream_permissions.clone(),
+        )
+    }
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct UserCreateArgs {
+    /// Username
+    pub(crate) username: String,
+    /// Password
+    pub(crate) password: String,
+    /// User status
+    #[clap(short, long, value_enum, default_value_t = UserStatusArg::Active)]
+    pub(crate) user_status: UserStatusArg,
+    #[clap(flatten)]
+    pub(crate) permissions: PermissionsArgsGroup,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct UserDeleteArgs {
+    /// User ID to delete
+    ///
+    /// User ID can be specified as a username or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) user_id: Identifier,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct UserGetArgs {
+    /// User ID to get
+    ///
+    /// User ID can be specified as a username or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) user_id: Identifier,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct UserListArgs {
+    /// List mode (table or list)
+    #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
+    pub(crate) list_mode: ListMode,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct UserUpdateArgs {
+    /// User ID to update
+    ///
+    /// User ID can be specified as a username or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) user_id: Identifier,
+    /// New username
+    #[clap(short = 'n', long)]
+    pub(crate) username: Option<String>,
+    /// New user status
+    #[clap(short, long, value_enum)]
+    pub(crate) user_status: Option<UserStatusArg>,
+    /// New user quota as bytes_per_second:requests_per_second, 0 means unlimited
+    #[clap(short, long, value_parser = clap::value_parser!(UserQuota))]
+    pub(crate) quota: Option<UserQuota>,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct UserPasswordArgs {
+    /// User ID to change password
+    ///
+    /// User ID can be specified as a username or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) user_id: Identifier,
+    /// Current password
+    pub(crate) current_password: String,
+    /// New password
+    pub(crate) new_password: String,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct UserPermissionsArgs {
+    /// User ID to set permissions
+    ///
+    /// User ID can be specified as a username or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) user_id: Identifier,
+    #[clap(flatten)]
+    pub(crate) permissions: PermissionsArgsGroup,
+}
@@ -10,32 +10,51 @@ use crate::args::{
 use crate::credentials::IggyCredentials;
 use crate::error::IggyCmdError;
 use crate::logging::Logging;
+use args::consumer_group::ConsumerGroupAction;
 use args::partition::PartitionAction;
+use args::schema::SchemaAction;
+use args::user::UserAction;
 use clap::Parser;
 use iggy::cli_command::{CliCommand, PRINT_TARGET};
 use iggy::client_provider;
 use iggy::client_provider::ClientProviderConfig;
 use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::cmd::{
+    consumer_groups::{
+        create_consumer_group::CreateConsumerGroupCmd,
+        delete_consumer_group::DeleteConsumerGroupCmd, get_consumer_group::GetConsumerGroupCmd,
+        get_consumer_groups::GetConsumerGroupsCmd,
+    },
+    consumer_offsets::get_consumer_lag::GetConsumerLagCmd,
     partitions::{create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd},
     personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
         get_personal_access_tokens::GetPersonalAccessTokensCmd,
     },
+    schemas::{
+        delete_schema::DeleteSchemaCmd, get_schema::GetSchemaCmd, get_schemas::GetSchemasCmd,
+        register_schema::RegisterSchemaCmd, update_schema_settings::UpdateSchemaSettingsCmd,
+    },
     streams::{
         create_stream::CreateStreamCmd, delete_stream::DeleteStreamCmd, get_stream::GetStreamCmd,
         get_streams::GetStreamsCmd, update_stream::UpdateStreamCmd,
     },
-    system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
+    system::{backup::CreateBackupCmd, me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
     topics::{
         create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
         get_topics::GetTopicsCmd, update_topic::UpdateTopicCmd,
     },
+    users::{
+        change_password::ChangePasswordCmd, create_user::CreateUserCmd, delete_user::DeleteUserCmd,
+        get_user::GetUserCmd, get_users::GetUsersCmd, update_permissions::UpdatePermissionsCmd,
+        update_user::UpdateUserCmd,
+    },
     utils::{
         message_expiry::MessageExpiry, personal_access_token_expiry::PersonalAccessTokenExpiry,
     },
 };
+use iggy::consumer::Consumer;
 use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
 use std::sync::Arc;
 use tracing::{event, Level};
@@ -62,6 +81,10 @@ fn get_command(args: &IggyConsoleArgs) -> Box<dyn CliCommand> {
                 args.partitions_count,
                 args.name.clone(),
                 MessageExpiry::new(args.message_expiry.clone()),
+                args.compression_algorithm,
+                args.compaction,
+                args.max_topic_size,
+                args.max_partition_size,
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
@@ -72,6 +95,8 @@ fn get_command(args: &IggyConsoleArgs) -> Box<dyn CliCommand> {
                 args.topic_id.clone(),
                 args.name.clone(),
                 MessageExpiry::new(args.message_expiry.clone()),
+                args.max_topic_size,
+                args.max_partition_size,
             )),
             TopicAction::Get(args) => Box::new(GetTopicCmd::new(
                 args.stream_id.clone(),
@@ -94,9 +119,97 @@ fn get_command(args: &IggyConsoleArgs) -> Box<dyn CliCommand> {
                 args.partitions_count,
             )),
         },
+        Command::Schema(command) => match command {
+            SchemaAction::Register(args) => Box::new(RegisterSchemaCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.format,
+                args.definition_file.clone(),
+            )),
+            SchemaAction::Delete(args) => Box::new(DeleteSchemaCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.schema_id,
+            )),
+            SchemaAction::Get(args) => Box::new(GetSchemaCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.schema_id,
+            )),
+            SchemaAction::List(args) => Box::new(GetSchemasCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.list_mode.into(),
+            )),
+            SchemaAction::Settings(args) => Box::new(UpdateSchemaSettingsCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.compatibility,
+                args.validate,
+            )),
+        },
+        Command::ConsumerGroup(command) => match command {
+            ConsumerGroupAction::Create(args) => Box::new(CreateConsumerGroupCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.consumer_group_id,
+                args.name.clone(),
+                args.strategy,
+            )),
+            ConsumerGroupAction::Delete(args) => Box::new(DeleteConsumerGroupCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.consumer_group_id.clone(),
+            )),
+            ConsumerGroupAction::Get(args) => Box::new(GetConsumerGroupCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.consumer_group_id.clone(),
+            )),
+            ConsumerGroupAction::List(args) => Box::new(GetConsumerGroupsCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.list_mode.into(),
+            )),
+        },
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
         Command::Stats => Box::new(GetStatsCmd::new()),
+        Command::Lag(args) => Box::new(GetConsumerLagCmd::new(
+            args.stream_id.clone(),
+            args.topic_id.clone(),
+            match args.group {
+                true => Consumer::group(args.consumer_id.clone()),
+                false => Consumer::new(args.consumer_id.clone()),
+            },
+        )),
+        Command::Backup => Box::new(CreateBackupCmd::new()),
+        Command::User(command) => match command {
+            UserAction::Create(args) => Box::new(CreateUserCmd::new(
+                args.username.clone(),
+                args.password.clone(),
+                args.user_status.into(),
+                (&args.permissions).into(),
+            )),
+            UserAction::Delete(args) => Box::new(DeleteUserCmd::new(args.user_id.clone())),
+            UserAction::Get(args) => Box::new(GetUserCmd::new(args.user_id.clone())),
+            UserAction::List(args) => Box::new(GetUsersCmd::new(args.list_mode.into())),
+            UserAction::Update(args) => Box::new(UpdateUserCmd::new(
+                args.user_id.clone(),
+                args.username.clone(),
+                args.user_status.map(|status| status.into()),
+                args.quota,
+            )),
+            UserAction::Password(args) => Box::new(ChangePasswordCmd::new(
+                args.user_id.clone(),
+                args.current_password.clone(),
+                args.new_password.clone(),
+            )),
+            UserAction::Permissions(args) => Box::new(UpdatePermissionsCmd::new(
+                args.user_id.clone(),
+                (&args.permissions).into(),
+            )),
+        },
         Command::Pat(command) => match command {
             PersonalAccessTokenAction::Create(pat_create_args) => {
                 Box::new(CreatePersonalAccessTokenCmd::new(
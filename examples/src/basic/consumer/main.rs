@@ -45,6 +45,8 @@ async fn consume_messages(args: &Args, client: &dyn Client) -> Result<(), Box<dy
                 strategy: PollingStrategy::next(),
                 count: args.messages_per_batch,
                 auto_commit: true,
+                max_wait: 0,
+                filter: None,
             })
             .await?;
         if polled_messages.messages.is_empty() {
@@ -5,7 +5,7 @@ use iggy::client::Client;
 use iggy::client_provider;
 use iggy::client_provider::ClientProviderConfig;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
 use std::error::Error;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -49,6 +49,9 @@ async fn produce_messages(args: &Args, client: &dyn Client) -> Result<(), Box<dy
                 stream_id: Identifier::numeric(args.stream_id)?,
                 topic_id: Identifier::numeric(args.topic_id)?,
                 partitioning: Partitioning::partition_id(args.partition_id),
+                producer: None,
+                transaction_id: None,
+                acks: Acks::default(),
                 messages,
             })
             .await?;
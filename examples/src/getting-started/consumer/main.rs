@@ -51,6 +51,8 @@ async fn consume_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 strategy: PollingStrategy::offset(offset),
                 count: messages_per_batch,
                 auto_commit: false,
+                max_wait: 0,
+                filter: None,
             })
             .await?;
         if polled_messages.messages.is_empty() {
@@ -1,7 +1,8 @@
 use iggy::client::{Client, StreamClient, TopicClient, UserClient};
 use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::users::defaults::*;
@@ -50,6 +51,10 @@ async fn init_system(client: &IggyClient) {
             partitions_count: 1,
             name: "sample-topic".to_string(),
             message_expiry: None,
+            compression_algorithm: CompressionAlgorithm::default(),
+            compaction_enabled: false,
+            max_topic_size: None,
+            max_partition_size: None,
         })
         .await
     {
@@ -83,6 +88,9 @@ async fn produce_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 stream_id: Identifier::numeric(STREAM_ID)?,
                 topic_id: Identifier::numeric(TOPIC_ID)?,
                 partitioning: Partitioning::partition_id(PARTITION_ID),
+                producer: None,
+                transaction_id: None,
+                acks: Acks::default(),
                 messages,
             })
             .await?;
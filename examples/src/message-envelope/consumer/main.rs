@@ -28,6 +28,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         .with_config(IggyClientConfig {
             poll_messages: PollMessagesConfig {
                 interval: args.interval,
+                max_wait: 0,
                 store_offset_kind: StoreOffsetKind::WhenMessagesAreProcessed,
             },
             ..Default::default()
@@ -54,6 +55,8 @@ async fn consume_messages(args: &Args, client: &IggyClient) -> Result<(), Box<dy
                 strategy: PollingStrategy::next(),
                 count: args.messages_per_batch,
                 auto_commit: true,
+                max_wait: 0,
+                filter: None,
             },
             Some(|message| {
                 let result = handle_message(&message);
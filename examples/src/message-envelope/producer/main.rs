@@ -8,7 +8,7 @@ use iggy::client_provider;
 use iggy::client_provider::ClientProviderConfig;
 use iggy::clients::client::IggyClient;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
 use std::error::Error;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -54,6 +54,9 @@ async fn produce_messages(args: &Args, client: &IggyClient) -> Result<(), Box<dy
                 stream_id: Identifier::numeric(args.stream_id)?,
                 topic_id: Identifier::numeric(args.topic_id)?,
                 partitioning: Partitioning::partition_id(args.partition_id),
+                producer: None,
+                transaction_id: None,
+                acks: Acks::default(),
                 messages,
             })
             .await?;
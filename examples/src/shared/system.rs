@@ -1,5 +1,6 @@
 use crate::shared::args::Args;
 use iggy::client::Client;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
 use iggy::streams::create_stream::CreateStream;
@@ -86,6 +87,10 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Er
             partitions_count: args.partitions_count,
             name: "orders".to_string(),
             message_expiry: None,
+            compression_algorithm: CompressionAlgorithm::default(),
+            compaction_enabled: false,
+            max_topic_size: None,
+            max_partition_size: None,
         })
         .await?;
     Ok(())
@@ -99,6 +99,20 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             converts_from: "".to_string(),
             template: "Cannot create base directory".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_create_backup".to_string(),
+            code: 11,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Cannot create backup for path: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_restore_backup".to_string(),
+            code: 12,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Cannot restore backup from path: {0}".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "resource_not_found".to_string(),
             code: 20,
@@ -344,6 +358,13 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             converts_from: "".to_string(),
             template: "Invalid client ID".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "quota_exceeded".to_string(),
+            code: 102,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Quota exceeded, the request has been throttled".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "io_error".to_string(),
             code: 200,
@@ -449,6 +470,13 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             converts_from: "ReadToEndError".to_string(),
             template: "Read to end error".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_correlation_id".to_string(),
+            code: 310,
+            signature: "u32, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid correlation ID: {0}, expected: {1}".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "cannot_create_streams_directory".to_string(),
             code: 1000,
@@ -687,6 +715,13 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             converts_from: "".to_string(),
             template: "Cannot read topics for stream with ID: {0}".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_compression_algorithm".to_string(),
+            code: 2018,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid compression algorithm".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "cannot_create_partition".to_string(),
             code: 3000,
@@ -953,6 +988,69 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             converts_from: "".to_string(),
             template: "Invalid key value length".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_compress_messages".to_string(),
+            code: 4029,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Cannot compress messages".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_decompress_messages".to_string(),
+            code: 4030,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Cannot decompress messages".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "producer_requires_partition_id".to_string(),
+            code: 4031,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Messages of the idempotent producer have to be sent to the specific partition ID".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "out_of_order_producer_sequence".to_string(),
+            code: 4032,
+            signature: "u64, u64, u64".to_string(),
+            converts_from: "".to_string(),
+            template: "Out of order sequence number: {0}, expected: {1}, for producer with ID: {2}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_message_filter".to_string(),
+            code: 4033,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid message filter".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_upload_segment".to_string(),
+            code: 4034,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Failed to upload segment file for path: {0} to the remote storage.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_download_segment".to_string(),
+            code: 4035,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Failed to download segment file for path: {0} from the remote storage.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_delete_remote_segment".to_string(),
+            code: 4036,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Failed to delete segment file: {0} from the remote storage.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cannot_save_delayed_index_to_segment".to_string(),
+            code: 4037,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Cannot save delayed index to segment".to_string(),
+        },
         ErrorRepositoryEntry {
             snake_case_name: "invalid_offset".to_string(),
             code: 4100,
@@ -1030,6 +1128,167 @@ pub fn load_errors() -> Vec<ErrorRepositoryEntry> {
             converts_from: "".to_string(),
             template: "Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.".to_string(),
         },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_dead_letter_topic".to_string(),
+            code: 5009,
+            signature: "u32, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid dead letter topic with ID: {0} for stream with ID: {1}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_partition_assignment_strategy".to_string(),
+            code: 5010,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid partition assignment strategy".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "transaction_not_found".to_string(),
+            code: 6000,
+            signature: "u64".to_string(),
+            converts_from: "".to_string(),
+            template: "Transaction with ID: {0} was not found.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "transaction_expired".to_string(),
+            code: 6001,
+            signature: "u64".to_string(),
+            converts_from: "".to_string(),
+            template: "Transaction with ID: {0} has expired.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "too_many_transaction_messages".to_string(),
+            code: 6002,
+            signature: "u64, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Transaction with ID: {0} exceeds the limit of: {1} messages.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cluster_disabled".to_string(),
+            code: 7000,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Cluster mode is disabled.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "not_cluster_controller".to_string(),
+            code: 7001,
+            signature: "u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Metadata can be changed only on the cluster controller, which is node with ID: {0}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "not_partition_leader".to_string(),
+            code: 7002,
+            signature: "u32, u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Partition with ID: {0} for topic with ID: {1} and stream with ID: {2} is led by node with ID: {3}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "replication_timeout".to_string(),
+            code: 7003,
+            signature: "u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Messages appended to partition with ID: {0} for topic with ID: {1} and stream with ID: {2} were not replicated by all in-sync replicas in time.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_cluster_node".to_string(),
+            code: 7004,
+            signature: "u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid cluster node ID: {0}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_cluster_nodes".to_string(),
+            code: 7005,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid cluster nodes: '{0}'.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "cluster_quorum_unavailable".to_string(),
+            code: 7006,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "The majority of the cluster nodes is offline.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_leader_epoch".to_string(),
+            code: 7007,
+            signature: "u32, u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid leader epoch: {0} of partition with ID: {1} for topic with ID: {2} and stream with ID: {3}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "not_enough_in_sync_replicas".to_string(),
+            code: 7008,
+            signature: "u32, u32, u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Partition with ID: {0} for topic with ID: {1} and stream with ID: {2} has: {3} in-sync replicas, fewer than the required: {4}.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "schema_not_found".to_string(),
+            code: 8000,
+            signature: "u32, u32, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Schema with ID: {0} for topic with ID: {1} and stream with ID: {2} was not found.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_schema_id".to_string(),
+            code: 8001,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid schema ID".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_schema_format".to_string(),
+            code: 8002,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid schema format".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_schema_compatibility".to_string(),
+            code: 8003,
+            signature: "".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid schema compatibility".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_schema_definition".to_string(),
+            code: 8004,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Invalid schema definition: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "incompatible_schema".to_string(),
+            code: 8005,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Schema is incompatible with the latest one: {0}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "missing_schema_id".to_string(),
+            code: 8006,
+            signature: "u32, u32".to_string(),
+            converts_from: "".to_string(),
+            template: "Messages for topic with ID: {0} and stream with ID: {1} require the schema ID header.".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "invalid_schema_payload".to_string(),
+            code: 8007,
+            signature: "u32, String".to_string(),
+            converts_from: "".to_string(),
+            template: "Message payload doesn't match the schema with ID: {0}: {1}".to_string(),
+        },
+        ErrorRepositoryEntry {
+            snake_case_name: "unsupported_schema_format".to_string(),
+            code: 8008,
+            signature: "String".to_string(),
+            converts_from: "".to_string(),
+            template: "Schemas in format: {0} are not supported yet, as their payloads can't be validated.".to_string(),
+        },
     ];
 
     error_codes
@@ -0,0 +1,31 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::cluster::get_cluster_metadata::GetClusterMetadata;
+use crate::cluster::replicate_messages::ReplicateMessages;
+use crate::command::{GET_CLUSTER_METADATA_CODE, REPLICATE_MESSAGES_CODE};
+use crate::error::Error;
+use crate::models::cluster::{ClusterMetadata, ReplicatedMessages};
+
+pub async fn get_cluster_metadata(
+    client: &dyn BinaryClient,
+    command: &GetClusterMetadata,
+) -> Result<ClusterMetadata, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_CLUSTER_METADATA_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_cluster_metadata(&response)
+}
+
+/// Used by the cluster nodes to fetch the messages from the partition leader, it's not a part of the `Client` trait.
+pub async fn replicate_messages(
+    client: &dyn BinaryClient,
+    command: &ReplicateMessages,
+) -> Result<ReplicatedMessages, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(REPLICATE_MESSAGES_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_replicated_messages(&response)
+}
@@ -1,10 +1,12 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{GET_CONSUMER_OFFSET_CODE, STORE_CONSUMER_OFFSET_CODE};
+use crate::command::{GET_CONSUMER_LAG_CODE, GET_CONSUMER_OFFSET_CODE, STORE_CONSUMER_OFFSET_CODE};
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 
 pub async fn store_consumer_offset(
@@ -28,3 +30,14 @@ pub async fn get_consumer_offset(
         .await?;
     mapper::map_consumer_offset(&response)
 }
+
+pub async fn get_consumer_lag(
+    client: &dyn BinaryClient,
+    command: &GetConsumerLag,
+) -> Result<ConsumerLag, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_CONSUMER_LAG_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_consumer_lag(&response)
+}
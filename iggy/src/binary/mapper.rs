@@ -1,18 +1,33 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use crate::error::Error;
-use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::backup::Backup;
+use crate::models::client_info::{
+    ClientInfo, ClientInfoDetails, ConsumerGroupInfo, QuotaUsageInfo,
+};
+use crate::models::cluster::{
+    ClusterMetadata, ClusterNode, ClusterNodeStatus, PartitionReplicas, ReplicatedMessages,
+};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember, DeadLetterTopic,
+};
+use crate::models::consumer_lag::{ConsumerLag, PartitionLag};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{Message, MessageState, PolledMessages};
 use crate::models::partition::Partition;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{Schema, TopicSchemas};
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
+use crate::schemas::schema_compatibility::SchemaCompatibility;
+use crate::schemas::schema_format::SchemaFormat;
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::str::from_utf8;
@@ -43,7 +58,8 @@ pub fn map_stats(payload: &[u8]) -> Result<Stats, Error> {
     let messages_count = u64::from_le_bytes(payload[88..96].try_into()?);
     let clients_count = u32::from_le_bytes(payload[96..100].try_into()?);
     let consumer_groups_count = u32::from_le_bytes(payload[100..104].try_into()?);
-    let mut current_position = 104;
+    let throttled_requests_count = u64::from_le_bytes(payload[104..112].try_into()?);
+    let mut current_position = 112;
     let hostname_length =
         u32::from_le_bytes(payload[current_position..current_position + 4].try_into()?) as usize;
     let hostname =
@@ -85,6 +101,7 @@ pub fn map_stats(payload: &[u8]) -> Result<Stats, Error> {
         messages_count,
         clients_count,
         consumer_groups_count,
+        throttled_requests_count,
         hostname,
         os_name,
         os_version,
@@ -103,6 +120,103 @@ pub fn map_consumer_offset(payload: &[u8]) -> Result<ConsumerOffsetInfo, Error>
     })
 }
 
+pub fn map_consumer_lag(payload: &[u8]) -> Result<ConsumerLag, Error> {
+    let total_lag = u64::from_le_bytes(payload[..8].try_into()?);
+    let mut partitions = Vec::new();
+    let mut position = 8;
+    while position < payload.len() {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let current_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        let has_stored_offset = payload[position + 12] == 1;
+        let stored_offset = u64::from_le_bytes(payload[position + 13..position + 21].try_into()?);
+        let lag = u64::from_le_bytes(payload[position + 21..position + 29].try_into()?);
+        partitions.push(PartitionLag {
+            partition_id,
+            current_offset,
+            stored_offset: if has_stored_offset {
+                Some(stored_offset)
+            } else {
+                None
+            },
+            lag,
+        });
+        position += 29;
+    }
+
+    Ok(ConsumerLag {
+        total_lag,
+        partitions,
+    })
+}
+
+pub fn map_cluster_metadata(payload: &[u8]) -> Result<ClusterMetadata, Error> {
+    let node_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let controller_id = u32::from_le_bytes(payload[4..8].try_into()?);
+    let nodes_count = u32::from_le_bytes(payload[8..12].try_into()?);
+    let mut position = 12;
+    let mut nodes = Vec::with_capacity(nodes_count as usize);
+    for _ in 0..nodes_count {
+        let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let status = ClusterNodeStatus::from_code(payload[position + 4])?;
+        let address_length = payload[position + 5] as usize;
+        let address = from_utf8(&payload[position + 6..position + 6 + address_length])?.to_string();
+        position += 6 + address_length;
+        nodes.push(ClusterNode {
+            id,
+            address,
+            status,
+        });
+    }
+
+    let partitions_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    position += 4;
+    let mut partitions = Vec::with_capacity(partitions_count as usize);
+    for _ in 0..partitions_count {
+        let stream_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let topic_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+        let partition_id = u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+        let leader_id = u32::from_le_bytes(payload[position + 12..position + 16].try_into()?);
+        let leader_epoch = u32::from_le_bytes(payload[position + 16..position + 20].try_into()?);
+        position += 20;
+        let replicas = map_to_node_ids(payload, &mut position)?;
+        let in_sync_replicas = map_to_node_ids(payload, &mut position)?;
+        partitions.push(PartitionReplicas {
+            stream_id,
+            topic_id,
+            partition_id,
+            leader_id,
+            leader_epoch,
+            replicas,
+            in_sync_replicas,
+        });
+    }
+
+    Ok(ClusterMetadata {
+        node_id,
+        controller_id,
+        nodes,
+        partitions,
+    })
+}
+
+fn map_to_node_ids(payload: &[u8], position: &mut usize) -> Result<Vec<u32>, Error> {
+    let count = u32::from_le_bytes(payload[*position..*position + 4].try_into()?);
+    *position += 4;
+    let mut node_ids = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        node_ids.push(u32::from_le_bytes(
+            payload[*position..*position + 4].try_into()?,
+        ));
+        *position += 4;
+    }
+    Ok(node_ids)
+}
+
+pub fn map_transaction(payload: &[u8]) -> Result<TransactionInfo, Error> {
+    let id = u64::from_le_bytes(payload[..8].try_into()?);
+    Ok(TransactionInfo { id })
+}
+
 pub fn map_user(payload: &[u8]) -> Result<UserInfoDetails, Error> {
     let (user, position) = map_to_user_info(payload, 0)?;
     let has_permissions = payload[position];
@@ -173,8 +287,30 @@ pub fn map_raw_pat(payload: &[u8]) -> Result<RawPersonalAccessToken, Error> {
     Ok(RawPersonalAccessToken { token })
 }
 
+pub fn map_backup(payload: &[u8]) -> Result<Backup, Error> {
+    let created_at = u64::from_le_bytes(payload[..8].try_into()?);
+    let size_bytes = u64::from_le_bytes(payload[8..16].try_into()?);
+    let name_length = u32::from_le_bytes(payload[16..20].try_into()?) as usize;
+    let name = from_utf8(&payload[20..20 + name_length])?.to_string();
+    let position = 20 + name_length;
+    let path_length = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+    let path = from_utf8(&payload[position + 4..position + 4 + path_length])?.to_string();
+    Ok(Backup {
+        name,
+        path,
+        size_bytes,
+        created_at,
+    })
+}
+
 pub fn map_client(payload: &[u8]) -> Result<ClientInfoDetails, Error> {
     let (client, mut position) = map_to_client_info(payload, 0)?;
+    let quota_usage = QuotaUsageInfo {
+        requests: u32::from_le_bytes(payload[position..position + 4].try_into()?),
+        bytes: u64::from_le_bytes(payload[position + 4..position + 12].try_into()?),
+        throttled_requests: u64::from_le_bytes(payload[position + 12..position + 20].try_into()?),
+    };
+    position += 20;
     let mut consumer_groups = Vec::new();
     let length = payload.len();
     while position < length {
@@ -201,6 +337,7 @@ pub fn map_client(payload: &[u8]) -> Result<ClientInfoDetails, Error> {
         transport: client.transport,
         consumer_groups_count: client.consumer_groups_count,
         consumer_groups,
+        quota_usage,
     };
     Ok(client)
 }
@@ -222,12 +359,22 @@ pub fn map_clients(payload: &[u8]) -> Result<Vec<ClientInfo>, Error> {
     Ok(clients)
 }
 
+pub fn map_replicated_messages(payload: &[u8]) -> Result<ReplicatedMessages, Error> {
+    let epoch_start_offset = u64::from_le_bytes(payload[..8].try_into()?);
+    let messages = map_polled_messages(&payload[8..])?;
+    Ok(ReplicatedMessages {
+        epoch_start_offset,
+        messages,
+    })
+}
+
 pub fn map_polled_messages(payload: &[u8]) -> Result<PolledMessages, Error> {
     if payload.is_empty() {
         return Ok(PolledMessages {
             messages: EMPTY_MESSAGES,
             partition_id: 0,
             current_offset: 0,
+            generation: 0,
         });
     }
 
@@ -236,7 +383,8 @@ pub fn map_polled_messages(payload: &[u8]) -> Result<PolledMessages, Error> {
     let current_offset = u64::from_le_bytes(payload[4..12].try_into()?);
     // Currently ignored
     let _messages_count = u32::from_le_bytes(payload[12..16].try_into()?);
-    let mut position = 16;
+    let generation = u32::from_le_bytes(payload[16..20].try_into()?);
+    let mut position = 20;
     let mut messages = Vec::new();
     while position < length {
         let offset = u64::from_le_bytes(payload[position..position + 8].try_into()?);
@@ -281,6 +429,7 @@ pub fn map_polled_messages(payload: &[u8]) -> Result<PolledMessages, Error> {
     Ok(PolledMessages {
         partition_id,
         current_offset,
+        generation,
         messages,
     })
 }
@@ -383,6 +532,11 @@ pub fn map_topic(payload: &[u8]) -> Result<TopicDetails, Error> {
         size_bytes: topic.size_bytes,
         messages_count: topic.messages_count,
         message_expiry: topic.message_expiry,
+        compression_algorithm: topic.compression_algorithm,
+        compression_ratio: topic.compression_ratio,
+        compaction_enabled: topic.compaction_enabled,
+        max_topic_size: topic.max_topic_size,
+        max_partition_size: topic.max_partition_size,
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
         partitions,
@@ -401,10 +555,23 @@ fn map_to_topic(payload: &[u8], position: usize) -> Result<(Topic, usize), Error
     };
     let size_bytes = u64::from_le_bytes(payload[position + 20..position + 28].try_into()?);
     let messages_count = u64::from_le_bytes(payload[position + 28..position + 36].try_into()?);
-    let name_length = payload[position + 36];
+    let compression_algorithm = CompressionAlgorithm::from_code(payload[position + 36])?;
+    let compression_ratio = f64::from_le_bytes(payload[position + 37..position + 45].try_into()?);
+    let compaction_enabled = matches!(payload[position + 45], 1);
+    let max_topic_size = u64::from_le_bytes(payload[position + 46..position + 54].try_into()?);
+    let max_topic_size = match max_topic_size {
+        0 => None,
+        _ => Some(max_topic_size),
+    };
+    let max_partition_size = u64::from_le_bytes(payload[position + 54..position + 62].try_into()?);
+    let max_partition_size = match max_partition_size {
+        0 => None,
+        _ => Some(max_partition_size),
+    };
+    let name_length = payload[position + 62];
     let name =
-        from_utf8(&payload[position + 37..position + 37 + name_length as usize])?.to_string();
-    let read_bytes = 4 + 8 + 4 + 4 + 8 + 8 + 1 + name_length as usize;
+        from_utf8(&payload[position + 63..position + 63 + name_length as usize])?.to_string();
+    let read_bytes = 4 + 8 + 4 + 4 + 8 + 8 + 1 + 8 + 1 + 8 + 8 + 1 + name_length as usize;
     Ok((
         Topic {
             id,
@@ -414,6 +581,11 @@ fn map_to_topic(payload: &[u8], position: usize) -> Result<(Topic, usize), Error
             size_bytes,
             messages_count,
             message_expiry,
+            compression_algorithm,
+            compression_ratio,
+            compaction_enabled,
+            max_topic_size,
+            max_partition_size,
         },
         read_bytes,
     ))
@@ -459,6 +631,24 @@ pub fn map_consumer_groups(payload: &[u8]) -> Result<Vec<ConsumerGroup>, Error>
 
 pub fn map_consumer_group(payload: &[u8]) -> Result<ConsumerGroupDetails, Error> {
     let (consumer_group, mut position) = map_to_consumer_group(payload, 0)?;
+    let partition_assignment_strategy = PartitionAssignmentStrategy::from_code(payload[position])?;
+    let session_timeout = match u32::from_le_bytes(payload[position + 1..position + 5].try_into()?)
+    {
+        0 => None,
+        session_timeout => Some(session_timeout),
+    };
+    let generation = u32::from_le_bytes(payload[position + 5..position + 9].try_into()?);
+    position += 9;
+    let dead_letter_stream_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let dead_letter_topic_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+    let dead_letter_topic = match dead_letter_stream_id {
+        0 => None,
+        _ => Some(DeadLetterTopic {
+            stream_id: dead_letter_stream_id,
+            topic_id: dead_letter_topic_id,
+        }),
+    };
+    position += 8;
     let mut members = Vec::new();
     let length = payload.len();
     while position < length {
@@ -472,11 +662,59 @@ pub fn map_consumer_group(payload: &[u8]) -> Result<ConsumerGroupDetails, Error>
         name: consumer_group.name,
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.members_count,
+        partition_assignment_strategy,
+        session_timeout,
+        generation,
+        dead_letter_topic,
         members,
     };
     Ok(consumer_group_details)
 }
 
+pub fn map_schema(payload: &[u8]) -> Result<Schema, Error> {
+    let (schema, _) = map_to_schema(payload, 0)?;
+    Ok(schema)
+}
+
+pub fn map_topic_schemas(payload: &[u8]) -> Result<TopicSchemas, Error> {
+    let compatibility = SchemaCompatibility::from_code(payload[0])?;
+    let validation_enabled = payload[1] == 1;
+    let mut schemas = Vec::new();
+    let length = payload.len();
+    let mut position = 2;
+    while position < length {
+        let (schema, read_bytes) = map_to_schema(payload, position)?;
+        schemas.push(schema);
+        position += read_bytes;
+    }
+    schemas.sort_by(|x, y| x.id.cmp(&y.id));
+    Ok(TopicSchemas {
+        compatibility,
+        validation_enabled,
+        schemas,
+    })
+}
+
+fn map_to_schema(payload: &[u8], position: usize) -> Result<(Schema, usize), Error> {
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let format = SchemaFormat::from_code(payload[position + 4])?;
+    let created_at = u64::from_le_bytes(payload[position + 5..position + 13].try_into()?);
+    let definition_length =
+        u32::from_le_bytes(payload[position + 13..position + 17].try_into()?) as usize;
+    let definition =
+        from_utf8(&payload[position + 17..position + 17 + definition_length])?.to_string();
+    let read_bytes = 17 + definition_length;
+    Ok((
+        Schema {
+            id,
+            format,
+            definition,
+            created_at,
+        },
+        read_bytes,
+    ))
+}
+
 fn map_to_consumer_group(payload: &[u8], position: usize) -> Result<(ConsumerGroup, usize), Error> {
     let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
     let partitions_count = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
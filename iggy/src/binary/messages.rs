@@ -1,8 +1,9 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{POISON_MESSAGE_CODE, POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
 use crate::error::Error;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
@@ -25,3 +26,14 @@ pub async fn send_messages(client: &dyn BinaryClient, command: &SendMessages) ->
         .await?;
     Ok(())
 }
+
+pub async fn poison_message(
+    client: &dyn BinaryClient,
+    command: &PoisonMessage,
+) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(POISON_MESSAGE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,64 @@
+/// The code of the handshake request, which is sent by the TCP and QUIC clients right after connecting.
+/// The handshake always uses the legacy framing and its payload is the protocol version requested by the client.
+/// The server responds with the negotiated protocol version, which applies to all the subsequent requests.
+/// The servers which don't know the handshake reject it, in which case the client keeps using the legacy framing.
+pub const HANDSHAKE_CODE: u32 = 2;
+
+/// The request is framed as its length and command code, and the response as its status and length.
+/// The responses are sent in the same order as the requests were received.
+pub const LEGACY_PROTOCOL_VERSION: u32 = 1;
+
+/// Both the request and the response are additionally prefixed with the correlation ID,
+/// so that many requests can be in flight at the same time and their responses returned out of order.
+pub const CORRELATION_ID_PROTOCOL_VERSION: u32 = 2;
+
+/// Returns the handshake request, framed as a legacy request, for the given protocol version.
+pub fn handshake_request(version: u32) -> Vec<u8> {
+    let mut request = Vec::with_capacity(12);
+    request.extend(8u32.to_le_bytes());
+    request.extend(HANDSHAKE_CODE.to_le_bytes());
+    request.extend(version.to_le_bytes());
+    request
+}
+
+/// Returns the protocol version negotiated by the server for the given legacy response status and payload.
+pub fn negotiated_version(status: u32, payload: &[u8]) -> u32 {
+    if status != 0 || payload.len() != 4 {
+        return LEGACY_PROTOCOL_VERSION;
+    }
+
+    u32::from_le_bytes(payload.try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handshake_request_should_be_framed_as_legacy_request() {
+        let request = handshake_request(CORRELATION_ID_PROTOCOL_VERSION);
+        assert_eq!(request.len(), 12);
+        assert_eq!(u32::from_le_bytes(request[..4].try_into().unwrap()), 8);
+        assert_eq!(
+            u32::from_le_bytes(request[4..8].try_into().unwrap()),
+            HANDSHAKE_CODE
+        );
+        assert_eq!(
+            u32::from_le_bytes(request[8..].try_into().unwrap()),
+            CORRELATION_ID_PROTOCOL_VERSION
+        );
+    }
+
+    #[test]
+    fn rejected_handshake_should_fall_back_to_legacy_version() {
+        assert_eq!(
+            negotiated_version(3, &CORRELATION_ID_PROTOCOL_VERSION.to_le_bytes()),
+            LEGACY_PROTOCOL_VERSION
+        );
+        assert_eq!(negotiated_version(0, &[]), LEGACY_PROTOCOL_VERSION);
+        assert_eq!(
+            negotiated_version(0, &CORRELATION_ID_PROTOCOL_VERSION.to_le_bytes()),
+            CORRELATION_ID_PROTOCOL_VERSION
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{
+    DELETE_SCHEMA_CODE, GET_SCHEMAS_CODE, GET_SCHEMA_CODE, REGISTER_SCHEMA_CODE,
+    UPDATE_SCHEMA_SETTINGS_CODE,
+};
+use crate::error::Error;
+use crate::models::schema::{Schema, TopicSchemas};
+use crate::schemas::delete_schema::DeleteSchema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_settings::UpdateSchemaSettings;
+
+pub async fn get_schema(client: &dyn BinaryClient, command: &GetSchema) -> Result<Schema, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_SCHEMA_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_schema(&response)
+}
+
+pub async fn get_schemas(
+    client: &dyn BinaryClient,
+    command: &GetSchemas,
+) -> Result<TopicSchemas, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(GET_SCHEMAS_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_topic_schemas(&response)
+}
+
+pub async fn register_schema(
+    client: &dyn BinaryClient,
+    command: &RegisterSchema,
+) -> Result<Schema, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(REGISTER_SCHEMA_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_schema(&response)
+}
+
+pub async fn delete_schema(client: &dyn BinaryClient, command: &DeleteSchema) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(DELETE_SCHEMA_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn update_schema_settings(
+    client: &dyn BinaryClient,
+    command: &UpdateSchemaSettings,
+) -> Result<(), Error> {
+    fail_if_not_authenticated(client).await?;
+    client
+        .send_with_response(UPDATE_SCHEMA_SETTINGS_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
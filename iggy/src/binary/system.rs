@@ -1,10 +1,14 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE};
+use crate::command::{
+    CREATE_BACKUP_CODE, GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE,
+};
 use crate::error::Error;
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -19,6 +23,17 @@ pub async fn get_stats(client: &dyn BinaryClient, command: &GetStats) -> Result<
     mapper::map_stats(&response)
 }
 
+pub async fn create_backup(
+    client: &dyn BinaryClient,
+    command: &CreateBackup,
+) -> Result<Backup, Error> {
+    fail_if_not_authenticated(client).await?;
+    let response = client
+        .send_with_response(CREATE_BACKUP_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_backup(&response)
+}
+
 pub async fn get_me(
     client: &dyn BinaryClient,
     command: &GetMe,
@@ -1,23 +1,32 @@
+use crate::cluster::get_cluster_metadata::GetClusterMetadata;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::cluster::ClusterMetadata;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{Schema, TopicSchemas};
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
@@ -25,11 +34,17 @@ use crate::personal_access_tokens::create_personal_access_token::CreatePersonalA
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::schemas::delete_schema::DeleteSchema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_settings::UpdateSchemaSettings;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -40,6 +55,9 @@ use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -63,6 +81,9 @@ pub trait Client:
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + TransactionClient
+    + ClusterClient
+    + SchemaClient
     + Sync
     + Send
     + Debug
@@ -74,6 +95,7 @@ pub trait Client:
 #[async_trait]
 pub trait SystemClient {
     async fn get_stats(&self, command: &GetStats) -> Result<Stats, Error>;
+    async fn create_backup(&self, command: &CreateBackup) -> Result<Backup, Error>;
     async fn get_me(&self, command: &GetMe) -> Result<ClientInfoDetails, Error>;
     async fn get_client(&self, command: &GetClient) -> Result<ClientInfoDetails, Error>;
     async fn get_clients(&self, command: &GetClients) -> Result<Vec<ClientInfo>, Error>;
@@ -141,6 +163,7 @@ pub trait PartitionClient {
 pub trait MessageClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<PolledMessages, Error>;
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), Error>;
+    async fn poison_message(&self, command: &PoisonMessage) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -150,6 +173,7 @@ pub trait ConsumerOffsetClient {
         &self,
         command: &GetConsumerOffset,
     ) -> Result<ConsumerOffsetInfo, Error>;
+    async fn get_consumer_lag(&self, command: &GetConsumerLag) -> Result<ConsumerLag, Error>;
 }
 
 #[async_trait]
@@ -166,4 +190,31 @@ pub trait ConsumerGroupClient {
     async fn delete_consumer_group(&self, command: &DeleteConsumerGroup) -> Result<(), Error>;
     async fn join_consumer_group(&self, command: &JoinConsumerGroup) -> Result<(), Error>;
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error>;
+    async fn heartbeat_consumer_group(&self, command: &HeartbeatConsumerGroup)
+        -> Result<(), Error>;
+}
+
+#[async_trait]
+pub trait TransactionClient {
+    async fn begin_transaction(&self, command: &BeginTransaction)
+        -> Result<TransactionInfo, Error>;
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), Error>;
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), Error>;
+}
+
+#[async_trait]
+pub trait ClusterClient {
+    async fn get_cluster_metadata(
+        &self,
+        command: &GetClusterMetadata,
+    ) -> Result<ClusterMetadata, Error>;
+}
+
+#[async_trait]
+pub trait SchemaClient {
+    async fn get_schema(&self, command: &GetSchema) -> Result<Schema, Error>;
+    async fn get_schemas(&self, command: &GetSchemas) -> Result<TopicSchemas, Error>;
+    async fn register_schema(&self, command: &RegisterSchema) -> Result<Schema, Error>;
+    async fn delete_schema(&self, command: &DeleteSchema) -> Result<(), Error>;
+    async fn update_schema_settings(&self, command: &UpdateSchemaSettings) -> Result<(), Error>;
 }
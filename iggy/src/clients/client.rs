@@ -1,30 +1,40 @@
 use crate::client::{
-    Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    PersonalAccessTokenClient, StreamClient, SystemClient, TopicClient, UserClient,
+    Client, ClusterClient, ConsumerGroupClient, ConsumerOffsetClient, MessageClient,
+    PartitionClient, PersonalAccessTokenClient, SchemaClient, StreamClient, SystemClient,
+    TopicClient, TransactionClient, UserClient,
 };
+use crate::cluster::get_cluster_metadata::GetClusterMetadata;
 use crate::consumer::Consumer;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
 use crate::identifier::Identifier;
 use crate::message_handler::MessageHandler;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::{PollMessages, PollingKind};
-use crate::messages::send_messages::{Partitioning, PartitioningKind, SendMessages};
+use crate::messages::send_messages::{Acks, Partitioning, PartitioningKind, SendMessages};
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::cluster::ClusterMetadata;
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{Message, PolledMessages};
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::schema::{Schema, TopicSchemas};
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitioner::Partitioner;
 use crate::partitions::create_partitions::CreatePartitions;
@@ -33,11 +43,18 @@ use crate::personal_access_tokens::create_personal_access_token::CreatePersonalA
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::rebalance_listener::RebalanceListener;
+use crate::schemas::delete_schema::DeleteSchema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_settings::UpdateSchemaSettings;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -49,6 +66,9 @@ use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -81,6 +101,7 @@ pub struct IggyClient {
     encryptor: Option<Box<dyn Encryptor>>,
     message_handler: Option<Arc<Box<dyn MessageHandler>>>,
     message_channel_sender: Option<Arc<Sender<Message>>>,
+    rebalance_listener: Option<Arc<Box<dyn RebalanceListener>>>,
 }
 
 #[derive(Debug)]
@@ -116,6 +137,14 @@ impl IggyClientBuilder {
         self
     }
 
+    pub fn with_rebalance_listener(
+        mut self,
+        rebalance_listener: Box<dyn RebalanceListener>,
+    ) -> Self {
+        self.client.rebalance_listener = Some(Arc::new(rebalance_listener));
+        self
+    }
+
     pub fn build(self) -> IggyClient {
         self.client
     }
@@ -142,6 +171,7 @@ pub struct SendMessagesConfig {
 #[derive(Debug, Copy, Clone)]
 pub struct PollMessagesConfig {
     pub interval: u64,
+    pub max_wait: u32,
     pub store_offset_kind: StoreOffsetKind,
 }
 
@@ -167,6 +197,7 @@ impl Default for PollMessagesConfig {
     fn default() -> Self {
         PollMessagesConfig {
             interval: 100,
+            max_wait: 0,
             store_offset_kind: StoreOffsetKind::WhenMessagesAreProcessed,
         }
     }
@@ -192,6 +223,7 @@ impl IggyClient {
             encryptor: None,
             message_handler: None,
             message_channel_sender: None,
+            rebalance_listener: None,
         }
     }
 
@@ -229,6 +261,7 @@ impl IggyClient {
             send_messages_batch: Some(send_messages_batch),
             message_handler: message_handler.map(Arc::new),
             message_channel_sender: None,
+            rebalance_listener: None,
             partitioner,
             encryptor,
         }
@@ -253,6 +286,7 @@ impl IggyClient {
         let mut interval = Duration::from_millis(100);
         let message_handler = self.message_handler.clone();
         let message_channel_sender = self.message_channel_sender.clone();
+        let rebalance_listener = self.rebalance_listener.clone();
         let mut store_offset_after_processing_each_message = false;
         let mut store_offset_when_messages_are_processed = false;
 
@@ -264,6 +298,9 @@ impl IggyClient {
             if config.interval > 0 {
                 interval = Duration::from_millis(config.interval);
             }
+            if config.max_wait > 0 {
+                poll_messages.max_wait = config.max_wait;
+            }
             match config.store_offset_kind {
                 StoreOffsetKind::Never => {
                     poll_messages.auto_commit = false;
@@ -282,17 +319,50 @@ impl IggyClient {
             }
         }
 
+        // With long polling enabled, the server parks the request until the new messages arrive,
+        // so there's no need to sleep between the subsequent polls, unless an error occurs.
+        let long_polling = poll_messages.max_wait > 0;
         tokio::spawn(async move {
+            let mut generation = 0;
+            let mut assigned_partitions = Vec::new();
             loop {
-                sleep(interval).await;
+                if !long_polling {
+                    sleep(interval).await;
+                }
                 let client = client.read().await;
                 let polled_messages = client.poll_messages(&poll_messages).await;
                 if let Err(error) = polled_messages {
                     error!("There was an error while polling messages: {:?}", error);
+                    if long_polling {
+                        sleep(interval).await;
+                    }
                     continue;
                 }
 
-                let messages = polled_messages.unwrap().messages;
+                let polled_messages = polled_messages.unwrap();
+                // The generation is always 0 for the regular consumer, so the listener is invoked only for the consumer group members.
+                if let Some(rebalance_listener) = &rebalance_listener {
+                    if polled_messages.generation != generation {
+                        match Self::get_assigned_partitions(client.as_ref(), &poll_messages).await {
+                            Ok(partitions) => {
+                                Self::notify_rebalance_listener(
+                                    rebalance_listener.as_ref().as_ref(),
+                                    &poll_messages.consumer,
+                                    &assigned_partitions,
+                                    &partitions,
+                                );
+                                assigned_partitions = partitions;
+                                generation = polled_messages.generation;
+                            }
+                            Err(error) => error!(
+                                "There was an error while getting the assigned partitions: {:?}",
+                                error
+                            ),
+                        }
+                    }
+                }
+
+                let messages = polled_messages.messages;
                 if messages.is_empty() {
                     continue;
                 }
@@ -343,6 +413,56 @@ impl IggyClient {
         self.send_messages(command).await
     }
 
+    async fn get_assigned_partitions(
+        client: &dyn Client,
+        poll_messages: &PollMessages,
+    ) -> Result<Vec<u32>, Error> {
+        let client_id = client.get_me(&GetMe {}).await?.client_id;
+        let consumer_group = client
+            .get_consumer_group(&GetConsumerGroup {
+                stream_id: Identifier::from_identifier(&poll_messages.stream_id),
+                topic_id: Identifier::from_identifier(&poll_messages.topic_id),
+                consumer_group_id: Identifier::from_identifier(&poll_messages.consumer.id),
+            })
+            .await?;
+        let mut partitions = consumer_group
+            .members
+            .into_iter()
+            .find(|member| member.id == client_id)
+            .map(|member| member.partitions)
+            .unwrap_or_default();
+        partitions.sort();
+        Ok(partitions)
+    }
+
+    fn notify_rebalance_listener(
+        rebalance_listener: &dyn RebalanceListener,
+        consumer: &Consumer,
+        previous_partitions: &[u32],
+        current_partitions: &[u32],
+    ) {
+        let revoked_partitions = previous_partitions
+            .iter()
+            .filter(|partition_id| !current_partitions.contains(partition_id))
+            .copied()
+            .collect::<Vec<_>>();
+        let assigned_partitions = current_partitions
+            .iter()
+            .filter(|partition_id| !previous_partitions.contains(partition_id))
+            .copied()
+            .collect::<Vec<_>>();
+        if !revoked_partitions.is_empty() {
+            info!(
+                "Partitions: {revoked_partitions:?} were revoked by the consumer group rebalance."
+            );
+            rebalance_listener.on_partitions_revoked(consumer, &revoked_partitions);
+        }
+        if !assigned_partitions.is_empty() {
+            info!("Partitions: {assigned_partitions:?} were assigned by the consumer group rebalance.");
+            rebalance_listener.on_partitions_assigned(consumer, &assigned_partitions);
+        }
+    }
+
     async fn store_offset(client: &dyn Client, poll_messages: &PollMessages, offset: u64) {
         let result = client
             .store_consumer_offset(&StoreConsumerOffset {
@@ -437,6 +557,9 @@ impl IggyClient {
                             length: 4,
                             value: key.value.clone(),
                         },
+                        producer: None,
+                        transaction_id: None,
+                        acks: Acks::default(),
                         messages,
                     };
 
@@ -562,6 +685,10 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_stats(command).await
     }
 
+    async fn create_backup(&self, command: &CreateBackup) -> Result<Backup, Error> {
+        self.client.read().await.create_backup(command).await
+    }
+
     async fn get_me(&self, command: &GetMe) -> Result<ClientInfoDetails, Error> {
         self.client.read().await.get_me(command).await
     }
@@ -639,7 +766,14 @@ impl PartitionClient for IggyClient {
 #[async_trait]
 impl MessageClient for IggyClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<PolledMessages, Error> {
-        let mut polled_messages = self.client.read().await.poll_messages(command).await?;
+        #[cfg(feature = "telemetry")]
+        let span = crate::telemetry::start_poll_span();
+        let poll_messages = async { self.client.read().await.poll_messages(command).await };
+        #[cfg(feature = "telemetry")]
+        let poll_messages = tracing::Instrument::instrument(poll_messages, span.clone());
+        let mut polled_messages = poll_messages.await?;
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::record_polled_messages(&span, &polled_messages.messages);
         if let Some(ref encryptor) = self.encryptor {
             for message in &mut polled_messages.messages {
                 let payload = encryptor.decrypt(&message.payload)?;
@@ -664,6 +798,9 @@ impl MessageClient for IggyClient {
             command.partitioning = Partitioning::partition_id(partition_id);
         }
 
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::inject_current_context(&mut command.messages);
+
         if let Some(encryptor) = &self.encryptor {
             for message in &mut command.messages {
                 message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
@@ -671,7 +808,13 @@ impl MessageClient for IggyClient {
             }
         }
 
+        // The messages of the idempotent producer can't be merged with other batches, as it would break the sequence numbers.
+        // The same applies to the transactional messages, which belong only to the given transaction,
+        // and to the messages sent with the non-default acks, which the batch would not wait for.
         let send_messages_now = self.send_messages_batch.is_none()
+            || command.producer.is_some()
+            || command.transaction_id.is_some()
+            || command.acks != Acks::default()
             || match &self.config {
                 Some(config) => !config.send_messages.enabled || config.send_messages.interval == 0,
                 None => true,
@@ -695,6 +838,9 @@ impl MessageClient for IggyClient {
             stream_id: Identifier::from_identifier(&command.stream_id),
             topic_id: Identifier::from_identifier(&command.topic_id),
             partitioning: Partitioning::from_partitioning(&command.partitioning),
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
             messages,
         };
 
@@ -702,6 +848,10 @@ impl MessageClient for IggyClient {
         batch.commands.push_back(send_messages);
         Ok(())
     }
+
+    async fn poison_message(&self, command: &PoisonMessage) -> Result<(), Error> {
+        self.client.read().await.poison_message(command).await
+    }
 }
 
 #[async_trait]
@@ -720,6 +870,10 @@ impl ConsumerOffsetClient for IggyClient {
     ) -> Result<ConsumerOffsetInfo, Error> {
         self.client.read().await.get_consumer_offset(command).await
     }
+
+    async fn get_consumer_lag(&self, command: &GetConsumerLag) -> Result<ConsumerLag, Error> {
+        self.client.read().await.get_consumer_lag(command).await
+    }
 }
 
 #[async_trait]
@@ -761,6 +915,72 @@ impl ConsumerGroupClient for IggyClient {
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error> {
         self.client.read().await.leave_consumer_group(command).await
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        command: &HeartbeatConsumerGroup,
+    ) -> Result<(), Error> {
+        self.client
+            .read()
+            .await
+            .heartbeat_consumer_group(command)
+            .await
+    }
+}
+
+#[async_trait]
+impl TransactionClient for IggyClient {
+    async fn begin_transaction(
+        &self,
+        command: &BeginTransaction,
+    ) -> Result<TransactionInfo, Error> {
+        self.client.read().await.begin_transaction(command).await
+    }
+
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), Error> {
+        self.client.read().await.commit_transaction(command).await
+    }
+
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), Error> {
+        self.client.read().await.abort_transaction(command).await
+    }
+}
+
+#[async_trait]
+impl ClusterClient for IggyClient {
+    async fn get_cluster_metadata(
+        &self,
+        command: &GetClusterMetadata,
+    ) -> Result<ClusterMetadata, Error> {
+        self.client.read().await.get_cluster_metadata(command).await
+    }
+}
+
+#[async_trait]
+impl SchemaClient for IggyClient {
+    async fn get_schema(&self, command: &GetSchema) -> Result<Schema, Error> {
+        self.client.read().await.get_schema(command).await
+    }
+
+    async fn get_schemas(&self, command: &GetSchemas) -> Result<TopicSchemas, Error> {
+        self.client.read().await.get_schemas(command).await
+    }
+
+    async fn register_schema(&self, command: &RegisterSchema) -> Result<Schema, Error> {
+        self.client.read().await.register_schema(command).await
+    }
+
+    async fn delete_schema(&self, command: &DeleteSchema) -> Result<(), Error> {
+        self.client.read().await.delete_schema(command).await
+    }
+
+    async fn update_schema_settings(&self, command: &UpdateSchemaSettings) -> Result<(), Error> {
+        self.client
+            .read()
+            .await
+            .update_schema_settings(command)
+            .await
+    }
 }
 
 #[async_trait]
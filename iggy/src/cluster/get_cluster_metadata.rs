@@ -0,0 +1,88 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `GetClusterMetadata` command is used to get the nodes of the cluster and the leaders of the partitions.
+/// It's also used by the nodes themselves as a heartbeat, to exchange the state of the cluster.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetClusterMetadata {}
+
+impl CommandPayload for GetClusterMetadata {}
+
+impl Validatable<Error> for GetClusterMetadata {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for GetClusterMetadata {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if !input.is_empty() {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = GetClusterMetadata {};
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for GetClusterMetadata {
+    fn as_bytes(&self) -> Vec<u8> {
+        Vec::with_capacity(0)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GetClusterMetadata, Error> {
+        if !bytes.is_empty() {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = GetClusterMetadata {};
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetClusterMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = GetClusterMetadata {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let bytes: Vec<u8> = vec![];
+        let command = GetClusterMetadata::from_bytes(&bytes);
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let bytes: Vec<u8> = vec![0];
+        let command = GetClusterMetadata::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_be_read_from_empty_string() {
+        let input = "";
+        let command = GetClusterMetadata::from_str(input);
+        assert!(command.is_ok());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_cluster_metadata;
+pub mod replicate_messages;
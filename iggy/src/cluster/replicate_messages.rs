@@ -0,0 +1,172 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `ReplicateMessages` command is sent by the follower to the leader of the partition, to fetch the messages it's missing.
+/// The fetched offset is also an acknowledgement, that all the previous messages have been already replicated by the follower.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric).
+/// - `topic_id` - unique topic ID (numeric).
+/// - `partition_id` - unique partition ID (numeric).
+/// - `replica_id` - ID of the node, which replicates the partition.
+/// - `leader_epoch` - the epoch of the partition leader known to the follower, the requests with the different epoch are rejected.
+/// - `offset` - the next offset, which the follower doesn't have yet.
+/// - `count` - the maximum number of the messages to fetch.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ReplicateMessages {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub replica_id: u32,
+    pub leader_epoch: u32,
+    pub offset: u64,
+    pub count: u32,
+}
+
+impl Default for ReplicateMessages {
+    fn default() -> Self {
+        ReplicateMessages {
+            stream_id: 1,
+            topic_id: 1,
+            partition_id: 1,
+            replica_id: 1,
+            leader_epoch: 1,
+            offset: 0,
+            count: 1000,
+        }
+    }
+}
+
+impl CommandPayload for ReplicateMessages {}
+
+impl Validatable<Error> for ReplicateMessages {
+    fn validate(&self) -> Result<(), Error> {
+        if self.count == 0 {
+            return Err(Error::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ReplicateMessages {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 7 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = ReplicateMessages {
+            stream_id: parts[0].parse::<u32>()?,
+            topic_id: parts[1].parse::<u32>()?,
+            partition_id: parts[2].parse::<u32>()?,
+            replica_id: parts[3].parse::<u32>()?,
+            leader_epoch: parts[4].parse::<u32>()?,
+            offset: parts[5].parse::<u64>()?,
+            count: parts[6].parse::<u32>()?,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for ReplicateMessages {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32);
+        bytes.put_u32_le(self.stream_id);
+        bytes.put_u32_le(self.topic_id);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u32_le(self.replica_id);
+        bytes.put_u32_le(self.leader_epoch);
+        bytes.put_u64_le(self.offset);
+        bytes.put_u32_le(self.count);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<ReplicateMessages, Error> {
+        if bytes.len() != 32 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = ReplicateMessages {
+            stream_id: u32::from_le_bytes(bytes[..4].try_into()?),
+            topic_id: u32::from_le_bytes(bytes[4..8].try_into()?),
+            partition_id: u32::from_le_bytes(bytes[8..12].try_into()?),
+            replica_id: u32::from_le_bytes(bytes[12..16].try_into()?),
+            leader_epoch: u32::from_le_bytes(bytes[16..20].try_into()?),
+            offset: u64::from_le_bytes(bytes[20..28].try_into()?),
+            count: u32::from_le_bytes(bytes[28..32].try_into()?),
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for ReplicateMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.replica_id,
+            self.leader_epoch,
+            self.offset,
+            self.count
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
+        let command = ReplicateMessages {
+            stream_id: 1,
+            topic_id: 2,
+            partition_id: 3,
+            replica_id: 4,
+            leader_epoch: 5,
+            offset: 6,
+            count: 7,
+        };
+
+        let bytes = command.as_bytes();
+        let deserialized_command = ReplicateMessages::from_bytes(&bytes);
+
+        assert!(deserialized_command.is_ok());
+        assert_eq!(deserialized_command.unwrap(), command);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let input = "1|2|3|4|5|6|7";
+        let command = ReplicateMessages::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, 1);
+        assert_eq!(command.topic_id, 2);
+        assert_eq!(command.partition_id, 3);
+        assert_eq!(command.replica_id, 4);
+        assert_eq!(command.leader_epoch, 5);
+        assert_eq!(command.offset, 6);
+        assert_eq!(command.count, 7);
+    }
+
+    #[test]
+    fn should_not_be_read_from_string_with_zero_count() {
+        let input = "1|2|3|4|5|6|0";
+        let command = ReplicateMessages::from_str(input);
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use crate::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct CreateConsumerGroupCmd {
+    create_consumer_group: CreateConsumerGroup,
+}
+
+impl CreateConsumerGroupCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        consumer_group_id: u32,
+        name: String,
+        partition_assignment_strategy: PartitionAssignmentStrategy,
+    ) -> Self {
+        Self {
+            create_consumer_group: CreateConsumerGroup {
+                stream_id,
+                topic_id,
+                consumer_group_id,
+                name,
+                partition_assignment_strategy,
+                session_timeout: None,
+                dead_letter_topic: None,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateConsumerGroupCmd {
+    fn explain(&self) -> String {
+        format!(
+            "create consumer group with ID: {}, name: {} and partition assignment strategy: {} for topic with ID: {} and stream with ID: {}",
+            self.create_consumer_group.consumer_group_id,
+            self.create_consumer_group.name,
+            self.create_consumer_group.partition_assignment_strategy,
+            self.create_consumer_group.topic_id,
+            self.create_consumer_group.stream_id,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .create_consumer_group(&self.create_consumer_group)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem creating consumer group (ID: {}, name: {}) for topic with ID: {} and stream with ID: {}",
+                    self.create_consumer_group.consumer_group_id,
+                    self.create_consumer_group.name,
+                    self.create_consumer_group.topic_id,
+                    self.create_consumer_group.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Consumer group with ID: {}, name: {} and partition assignment strategy: {} created for topic with ID: {} and stream with ID: {}",
+            self.create_consumer_group.consumer_group_id,
+            self.create_consumer_group.name,
+            self.create_consumer_group.partition_assignment_strategy,
+            self.create_consumer_group.topic_id,
+            self.create_consumer_group.stream_id,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetConsumerGroupCmd {
+    get_consumer_group: GetConsumerGroup,
+}
+
+impl GetConsumerGroupCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, consumer_group_id: Identifier) -> Self {
+        Self {
+            get_consumer_group: GetConsumerGroup {
+                stream_id,
+                topic_id,
+                consumer_group_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetConsumerGroupCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.get_consumer_group.consumer_group_id,
+            self.get_consumer_group.topic_id,
+            self.get_consumer_group.stream_id,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let consumer_group = client
+            .get_consumer_group(&self.get_consumer_group)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.get_consumer_group.consumer_group_id,
+                    self.get_consumer_group.topic_id,
+                    self.get_consumer_group.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec![
+            "Consumer group id",
+            format!("{}", consumer_group.id).as_str(),
+        ]);
+        table.add_row(vec!["Consumer group name", consumer_group.name.as_str()]);
+        table.add_row(vec![
+            "Partitions count",
+            format!("{}", consumer_group.partitions_count).as_str(),
+        ]);
+        table.add_row(vec![
+            "Members count",
+            format!("{}", consumer_group.members_count).as_str(),
+        ]);
+        table.add_row(vec![
+            "Partition assignment strategy",
+            format!("{}", consumer_group.partition_assignment_strategy).as_str(),
+        ]);
+        table.add_row(vec![
+            "Generation",
+            format!("{}", consumer_group.generation).as_str(),
+        ]);
+        for member in consumer_group.members.iter() {
+            let mut partitions = member.partitions.clone();
+            partitions.sort_unstable();
+            table.add_row(vec![
+                format!("Member {} partitions", member.id).as_str(),
+                partitions
+                    .iter()
+                    .map(|partition_id| partition_id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+                    .as_str(),
+            ]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_consumer_group;
+pub mod delete_consumer_group;
+pub mod get_consumer_group;
+pub mod get_consumer_groups;
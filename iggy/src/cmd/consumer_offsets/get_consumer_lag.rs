@@ -0,0 +1,79 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetConsumerLagCmd {
+    get_consumer_lag: GetConsumerLag,
+}
+
+impl GetConsumerLagCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, consumer: Consumer) -> Self {
+        Self {
+            get_consumer_lag: GetConsumerLag {
+                consumer,
+                stream_id,
+                topic_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetConsumerLagCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get lag of {} with ID: {} for topic with ID: {} in stream with ID: {}",
+            self.get_consumer_lag.consumer.kind,
+            self.get_consumer_lag.consumer.id,
+            self.get_consumer_lag.topic_id,
+            self.get_consumer_lag.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let consumer_lag = client
+            .get_consumer_lag(&self.get_consumer_lag)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting lag of {} with ID: {} for topic {} in stream {}",
+                    self.get_consumer_lag.consumer.kind,
+                    self.get_consumer_lag.consumer.id,
+                    self.get_consumer_lag.topic_id,
+                    self.get_consumer_lag.stream_id
+                )
+            })?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec![
+            "Partition ID",
+            "Current offset",
+            "Stored offset",
+            "Lag",
+        ]);
+
+        consumer_lag.partitions.iter().for_each(|partition| {
+            table.add_row(vec![
+                format!("{}", partition.partition_id),
+                format!("{}", partition.current_offset),
+                match partition.stored_offset {
+                    Some(stored_offset) => format!("{}", stored_offset),
+                    None => String::from("-"),
+                },
+                format!("{}", partition.lag),
+            ]);
+        });
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        event!(target: PRINT_TARGET, Level::INFO, "Total lag: {}", consumer_lag.total_lag);
+
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+pub mod get_consumer_lag;
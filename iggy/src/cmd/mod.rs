@@ -1,6 +1,10 @@
+pub mod consumer_groups;
+pub mod consumer_offsets;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod users;
 pub mod utils;
@@ -0,0 +1,54 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::schemas::delete_schema::DeleteSchema;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct DeleteSchemaCmd {
+    delete_schema: DeleteSchema,
+}
+
+impl DeleteSchemaCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, schema_id: u32) -> Self {
+        Self {
+            delete_schema: DeleteSchema {
+                stream_id,
+                topic_id,
+                schema_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for DeleteSchemaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "delete schema with ID: {} for topic with ID: {} in stream with ID: {}",
+            self.delete_schema.schema_id, self.delete_schema.topic_id, self.delete_schema.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .delete_schema(&self.delete_schema)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem deleting schema with ID: {} for topic {} in stream {}",
+                    self.delete_schema.schema_id,
+                    self.delete_schema.topic_id,
+                    self.delete_schema.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Schema with ID: {} for topic with ID: {} in stream with ID: {} deleted",
+            self.delete_schema.schema_id, self.delete_schema.topic_id, self.delete_schema.stream_id
+        );
+
+        Ok(())
+    }
+}
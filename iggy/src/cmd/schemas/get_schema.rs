@@ -0,0 +1,61 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::schemas::get_schema::GetSchema;
+use crate::utils::timestamp::TimeStamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetSchemaCmd {
+    get_schema: GetSchema,
+}
+
+impl GetSchemaCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, schema_id: u32) -> Self {
+        Self {
+            get_schema: GetSchema {
+                stream_id,
+                topic_id,
+                schema_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetSchemaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get schema with ID: {} for topic with ID: {} in stream with ID: {}",
+            self.get_schema.schema_id, self.get_schema.topic_id, self.get_schema.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let schema = client.get_schema(&self.get_schema).await.with_context(|| {
+            format!(
+                "Problem getting schema with ID: {} for topic {} in stream {}",
+                self.get_schema.schema_id, self.get_schema.topic_id, self.get_schema.stream_id
+            )
+        })?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Schema id", format!("{}", schema.id).as_str()]);
+        table.add_row(vec![
+            "Created",
+            TimeStamp::from(schema.created_at)
+                .to_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec!["Format", format!("{}", schema.format).as_str()]);
+        table.add_row(vec!["Definition", schema.definition.as_str()]);
+
+        event!(target: PRINT_TARGET, Level::INFO,"{table}");
+
+        Ok(())
+    }
+}
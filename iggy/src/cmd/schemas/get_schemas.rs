@@ -0,0 +1,103 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::utils::timestamp::TimeStamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub enum GetSchemasOutput {
+    Table,
+    List,
+}
+
+impl Display for GetSchemasOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GetSchemasOutput::Table => write!(f, "table"),
+            GetSchemasOutput::List => write!(f, "list"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct GetSchemasCmd {
+    get_schemas: GetSchemas,
+    output: GetSchemasOutput,
+}
+
+impl GetSchemasCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, output: GetSchemasOutput) -> Self {
+        Self {
+            get_schemas: GetSchemas {
+                stream_id,
+                topic_id,
+            },
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetSchemasCmd {
+    fn explain(&self) -> String {
+        format!(
+            "list schemas for topic with ID: {} in stream with ID: {} in {} mode",
+            self.get_schemas.topic_id, self.get_schemas.stream_id, self.output
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let topic_schemas = client
+            .get_schemas(&self.get_schemas)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting schemas for topic {} in stream {}",
+                    self.get_schemas.topic_id, self.get_schemas.stream_id
+                )
+            })?;
+
+        match self.output {
+            GetSchemasOutput::Table => {
+                event!(target: PRINT_TARGET, Level::INFO,
+                    "Compatibility: {}, validation enabled: {}",
+                    topic_schemas.compatibility, topic_schemas.validation_enabled
+                );
+
+                let mut table = Table::new();
+
+                table.set_header(vec!["ID", "Created", "Format", "Definition Size (B)"]);
+
+                topic_schemas.schemas.iter().for_each(|schema| {
+                    table.add_row(vec![
+                        format!("{}", schema.id),
+                        TimeStamp::from(schema.created_at).to_string("%Y-%m-%d %H:%M:%S"),
+                        format!("{}", schema.format),
+                        format!("{}", schema.definition.len()),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetSchemasOutput::List => {
+                topic_schemas.schemas.iter().for_each(|schema| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}",
+                        schema.id,
+                        TimeStamp::from(schema.created_at).to_string("%Y-%m-%d %H:%M:%S"),
+                        schema.format,
+                        schema.definition.len()
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod delete_schema;
+pub mod get_schema;
+pub mod get_schemas;
+pub mod register_schema;
+pub mod update_schema_settings;
@@ -0,0 +1,74 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::schema_format::SchemaFormat;
+use anyhow::Context;
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tracing::{event, Level};
+
+pub struct RegisterSchemaCmd {
+    register_schema: RegisterSchema,
+    definition_file: PathBuf,
+}
+
+impl RegisterSchemaCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        format: SchemaFormat,
+        definition_file: PathBuf,
+    ) -> Self {
+        Self {
+            register_schema: RegisterSchema {
+                stream_id,
+                topic_id,
+                format,
+                definition: String::new(),
+            },
+            definition_file,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for RegisterSchemaCmd {
+    fn explain(&self) -> String {
+        format!(
+            "register {} schema from file: {} for topic with ID: {} in stream with ID: {}",
+            self.register_schema.format,
+            self.definition_file.display(),
+            self.register_schema.topic_id,
+            self.register_schema.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        self.register_schema.definition = tokio::fs::read_to_string(&self.definition_file)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem reading schema definition from file: {}",
+                    self.definition_file.display()
+                )
+            })?;
+
+        let schema = client
+            .register_schema(&self.register_schema)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem registering schema for topic {} in stream {}",
+                    self.register_schema.topic_id, self.register_schema.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Schema with ID: {} for topic with ID: {} in stream with ID: {} registered",
+            schema.id, self.register_schema.topic_id, self.register_schema.stream_id
+        );
+
+        Ok(())
+    }
+}
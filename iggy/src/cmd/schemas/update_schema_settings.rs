@@ -0,0 +1,62 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::schemas::schema_compatibility::SchemaCompatibility;
+use crate::schemas::update_schema_settings::UpdateSchemaSettings;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UpdateSchemaSettingsCmd {
+    update_schema_settings: UpdateSchemaSettings,
+}
+
+impl UpdateSchemaSettingsCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+    ) -> Self {
+        Self {
+            update_schema_settings: UpdateSchemaSettings {
+                stream_id,
+                topic_id,
+                compatibility,
+                validation_enabled,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateSchemaSettingsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update schema settings (compatibility: {}, validation enabled: {}) for topic with ID: {} in stream with ID: {}",
+            self.update_schema_settings.compatibility,
+            self.update_schema_settings.validation_enabled,
+            self.update_schema_settings.topic_id,
+            self.update_schema_settings.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_schema_settings(&self.update_schema_settings)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating schema settings for topic {} in stream {}",
+                    self.update_schema_settings.topic_id, self.update_schema_settings.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Schema settings for topic with ID: {} in stream with ID: {} updated",
+            self.update_schema_settings.topic_id, self.update_schema_settings.stream_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::create_backup::CreateBackup;
+use crate::utils::timestamp::TimeStamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct CreateBackupCmd {
+    create_backup: CreateBackup,
+}
+
+impl CreateBackupCmd {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for CreateBackupCmd {
+    fn default() -> Self {
+        Self {
+            create_backup: CreateBackup {},
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateBackupCmd {
+    fn explain(&self) -> String {
+        "backup command".to_owned()
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let backup = client
+            .create_backup(&self.create_backup)
+            .await
+            .with_context(|| "Problem sending create_backup command".to_owned())?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Backup name", backup.name.as_str()]);
+        table.add_row(vec!["Backup path", backup.path.as_str()]);
+        table.add_row(vec![
+            "Created",
+            TimeStamp::from(backup.created_at)
+                .to_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec![
+            "Backup size",
+            format!("{}", backup.size_bytes).as_str(),
+        ]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod backup;
 pub mod me;
 pub mod ping;
 pub mod stats;
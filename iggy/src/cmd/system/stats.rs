@@ -137,6 +137,10 @@ impl CliCommand for GetStatsCmd {
             "Consumer Groups Count",
             format!("{}", stats.consumer_groups_count).as_str(),
         ]);
+        table.add_row(vec![
+            "Throttled Requests Count",
+            format!("{}", stats.throttled_requests_count).as_str(),
+        ]);
 
         table.add_row(vec!["OS Name", stats.os_name.as_str()]);
         table.add_row(vec!["OS Version", stats.os_version.as_str()]);
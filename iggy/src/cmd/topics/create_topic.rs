@@ -1,6 +1,8 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::cmd::utils::message_expiry::MessageExpiry;
+use crate::cmd::utils::topic_size::TopicSize;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
 use crate::topics::create_topic::CreateTopic;
 use anyhow::Context;
@@ -13,12 +15,17 @@ pub struct CreateTopicCmd {
 }
 
 impl CreateTopicCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: u32,
         partitions_count: u32,
         name: String,
         message_expiry: Option<MessageExpiry>,
+        compression_algorithm: CompressionAlgorithm,
+        compaction_enabled: bool,
+        max_topic_size: Option<TopicSize>,
+        max_partition_size: Option<TopicSize>,
     ) -> Self {
         Self {
             create_topic: CreateTopic {
@@ -30,6 +37,10 @@ impl CreateTopicCmd {
                     None => None,
                     Some(value) => value.into(),
                 },
+                compression_algorithm,
+                compaction_enabled,
+                max_topic_size: max_topic_size.as_ref().and_then(|size| size.into()),
+                max_partition_size: max_partition_size.as_ref().and_then(|size| size.into()),
             },
             message_expiry,
         }
@@ -44,10 +55,11 @@ impl CliCommand for CreateTopicCmd {
             None => String::from("without message expire time"),
         };
         format!(
-            "create topic with ID: {}, name: {}, partitions count: {} and {} in stream with ID: {}",
+            "create topic with ID: {}, name: {}, partitions count: {}, compression algorithm: {} and {} in stream with ID: {}",
             self.create_topic.topic_id,
             self.create_topic.name,
             self.create_topic.partitions_count,
+            self.create_topic.compression_algorithm,
             expiry_text,
             self.create_topic.stream_id
         )
@@ -65,10 +77,11 @@ impl CliCommand for CreateTopicCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with ID: {}, name: {}, partitions count: {} and {} created in stream with ID: {}",
+            "Topic with ID: {}, name: {}, partitions count: {}, compression algorithm: {} and {} created in stream with ID: {}",
             self.create_topic.topic_id,
             self.create_topic.name,
             self.create_topic.partitions_count,
+            self.create_topic.compression_algorithm,
             match &self.message_expiry {
                 Some(value) => format!("message expire time: {}", value),
                 None => String::from("without message expire time"),
@@ -60,6 +60,34 @@ impl CliCommand for GetTopicCmd {
             }
             .as_str(),
         ]);
+        table.add_row(vec![
+            "Compression algorithm",
+            format!("{}", topic.compression_algorithm).as_str(),
+        ]);
+        table.add_row(vec![
+            "Compression ratio",
+            format!("{:.2}", topic.compression_ratio).as_str(),
+        ]);
+        table.add_row(vec![
+            "Compaction enabled",
+            format!("{}", topic.compaction_enabled).as_str(),
+        ]);
+        table.add_row(vec![
+            "Max topic size",
+            match topic.max_topic_size {
+                Some(value) => format!("{}", value),
+                None => String::from("None"),
+            }
+            .as_str(),
+        ]);
+        table.add_row(vec![
+            "Max partition size",
+            match topic.max_partition_size {
+                Some(value) => format!("{}", value),
+                None => String::from("None"),
+            }
+            .as_str(),
+        ]);
         table.add_row(vec![
             "Topic message count",
             format!("{}", topic.messages_count).as_str(),
@@ -1,6 +1,7 @@
 use crate::cli_command::{CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::cmd::utils::message_expiry::MessageExpiry;
+use crate::cmd::utils::topic_size::TopicSize;
 use crate::identifier::Identifier;
 use crate::topics::update_topic::UpdateTopic;
 use anyhow::Context;
@@ -18,6 +19,8 @@ impl UpdateTopicCmd {
         topic_id: Identifier,
         name: String,
         message_expiry: Option<MessageExpiry>,
+        max_topic_size: Option<TopicSize>,
+        max_partition_size: Option<TopicSize>,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -28,6 +31,8 @@ impl UpdateTopicCmd {
                     None => None,
                     Some(value) => value.into(),
                 },
+                max_topic_size: max_topic_size.as_ref().and_then(|size| size.into()),
+                max_partition_size: max_partition_size.as_ref().and_then(|size| size.into()),
             },
             message_expiry,
         }
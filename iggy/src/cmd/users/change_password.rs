@@ -0,0 +1,52 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::users::change_password::ChangePassword;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct ChangePasswordCmd {
+    change_password: ChangePassword,
+}
+
+impl ChangePasswordCmd {
+    pub fn new(user_id: Identifier, current_password: String, new_password: String) -> Self {
+        Self {
+            change_password: ChangePassword {
+                user_id,
+                current_password,
+                new_password,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for ChangePasswordCmd {
+    fn explain(&self) -> String {
+        format!(
+            "change password for user with ID: {}",
+            self.change_password.user_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .change_password(&self.change_password)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem changing password for user with ID: {}",
+                    self.change_password.user_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Password for user with ID: {} changed",
+            self.change_password.user_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::cmd::utils::permissions::PermissionsArgs;
+use crate::models::user_status::UserStatus;
+use crate::users::create_user::CreateUser;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct CreateUserCmd {
+    create_user: CreateUser,
+}
+
+impl CreateUserCmd {
+    pub fn new(
+        username: String,
+        password: String,
+        status: UserStatus,
+        permissions: PermissionsArgs,
+    ) -> Self {
+        Self {
+            create_user: CreateUser {
+                username,
+                password,
+                status,
+                permissions: permissions.into(),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for CreateUserCmd {
+    fn explain(&self) -> String {
+        format!(
+            "create user with username: {} and status: {}",
+            self.create_user.username, self.create_user.status
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .create_user(&self.create_user)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem creating user (username: {} and status: {})",
+                    self.create_user.username, self.create_user.status
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "User with username: {} and status: {} created",
+            self.create_user.username, self.create_user.status
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::cmd::utils::permissions::{GlobalPermissionsArg, StreamPermissionsArg};
+use crate::identifier::Identifier;
+use crate::users::get_user::GetUser;
+use crate::utils::timestamp::TimeStamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetUserCmd {
+    get_user: GetUser,
+}
+
+impl GetUserCmd {
+    pub fn new(user_id: Identifier) -> Self {
+        Self {
+            get_user: GetUser { user_id },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetUserCmd {
+    fn explain(&self) -> String {
+        format!("get user with ID: {}", self.get_user.user_id)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let user = client
+            .get_user(&self.get_user)
+            .await
+            .with_context(|| format!("Problem getting user with ID: {}", self.get_user.user_id))?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["User ID", format!("{}", user.id).as_str()]);
+        table.add_row(vec![
+            "Created",
+            TimeStamp::from(user.created_at)
+                .to_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec!["Status", format!("{}", user.status).as_str()]);
+        table.add_row(vec!["Username", user.username.as_str()]);
+
+        match user.permissions {
+            Some(permissions) => {
+                table.add_row(vec![
+                    "Global permissions",
+                    GlobalPermissionsArg::from(permissions.global)
+                        .to_string()
+                        .as_str(),
+                ]);
+                if let Some(streams) = permissions.streams {
+                    let mut streams = streams.into_iter().collect::<Vec<_>>();
+                    streams.sort_by_key(|(stream_id, _)| *stream_id);
+                    for (stream_id, stream) in streams {
+                        table.add_row(vec![
+                            format!("Stream {} permissions", stream_id).as_str(),
+                            StreamPermissionsArg::new(stream_id, stream)
+                                .to_string()
+                                .as_str(),
+                        ]);
+                    }
+                }
+            }
+            None => {
+                table.add_row(vec!["Permissions", "None"]);
+            }
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
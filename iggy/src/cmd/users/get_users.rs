@@ -0,0 +1,86 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::users::get_users::GetUsers;
+use crate::utils::timestamp::TimeStamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub enum GetUsersOutput {
+    Table,
+    List,
+}
+
+impl Display for GetUsersOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GetUsersOutput::Table => write!(f, "table"),
+            GetUsersOutput::List => write!(f, "list"),
+        }?;
+
+        Ok(())
+    }
+}
+
+pub struct GetUsersCmd {
+    get_users: GetUsers,
+    output: GetUsersOutput,
+}
+
+impl GetUsersCmd {
+    pub fn new(output: GetUsersOutput) -> Self {
+        Self {
+            get_users: GetUsers {},
+            output,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetUsersCmd {
+    fn explain(&self) -> String {
+        format!("list users in {} mode", self.output)
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let users = client
+            .get_users(&self.get_users)
+            .await
+            .with_context(|| String::from("Problem getting list of users"))?;
+
+        match self.output {
+            GetUsersOutput::Table => {
+                let mut table = Table::new();
+
+                table.set_header(vec!["ID", "Created", "Status", "Username"]);
+
+                users.iter().for_each(|user| {
+                    table.add_row(vec![
+                        format!("{}", user.id),
+                        TimeStamp::from(user.created_at).to_string("%Y-%m-%d %H:%M:%S"),
+                        format!("{}", user.status),
+                        user.username.clone(),
+                    ]);
+                });
+
+                event!(target: PRINT_TARGET, Level::INFO, "{table}");
+            }
+            GetUsersOutput::List => {
+                users.iter().for_each(|user| {
+                    event!(target: PRINT_TARGET, Level::INFO,
+                        "{}|{}|{}|{}",
+                        user.id,
+                        TimeStamp::from(user.created_at).to_string("%Y-%m-%d %H:%M:%S"),
+                        user.status,
+                        user.username
+                    );
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod change_password;
+pub mod create_user;
+pub mod delete_user;
+pub mod get_user;
+pub mod get_users;
+pub mod update_permissions;
+pub mod update_user;
@@ -0,0 +1,79 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::models::user_quota::UserQuota;
+use crate::models::user_status::UserStatus;
+use crate::users::update_user::UpdateUser;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct UpdateUserCmd {
+    update_user: UpdateUser,
+}
+
+impl UpdateUserCmd {
+    pub fn new(
+        user_id: Identifier,
+        username: Option<String>,
+        status: Option<UserStatus>,
+        quota: Option<UserQuota>,
+    ) -> Self {
+        Self {
+            update_user: UpdateUser {
+                user_id,
+                username,
+                status,
+                quota,
+            },
+        }
+    }
+
+    fn changes(&self) -> String {
+        let mut changes = vec![];
+        if let Some(username) = &self.update_user.username {
+            changes.push(format!("username: {username}"));
+        }
+        if let Some(status) = &self.update_user.status {
+            changes.push(format!("status: {status}"));
+        }
+        if let Some(quota) = &self.update_user.quota {
+            changes.push(format!("quota: {quota}"));
+        }
+        match changes.is_empty() {
+            true => String::from("no changes"),
+            false => changes.join(" and "),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for UpdateUserCmd {
+    fn explain(&self) -> String {
+        format!(
+            "update user with ID: {} with {}",
+            self.update_user.user_id,
+            self.changes()
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .update_user(&self.update_user)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem updating user with ID: {} with {}",
+                    self.update_user.user_id,
+                    self.changes()
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "User with ID: {} updated with {}",
+            self.update_user.user_id, self.changes()
+        );
+
+        Ok(())
+    }
+}
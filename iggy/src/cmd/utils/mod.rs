@@ -1,2 +1,4 @@
 pub mod message_expiry;
+pub mod permissions;
 pub mod personal_access_token_expiry;
+pub mod topic_size;
@@ -0,0 +1,368 @@
+use crate::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const MANAGE_SERVERS: &str = "m_srv";
+const READ_SERVERS: &str = "r_srv";
+const MANAGE_USERS: &str = "m_usr";
+const READ_USERS: &str = "r_usr";
+const MANAGE_STREAMS: &str = "m_str";
+const READ_STREAMS: &str = "r_str";
+const MANAGE_TOPICS: &str = "m_top";
+const READ_TOPICS: &str = "r_top";
+const POLL_MESSAGES: &str = "p_msg";
+const SEND_MESSAGES: &str = "s_msg";
+const NO_PERMISSIONS: &str = "none";
+const TOPIC_SEPARATOR: char = '#';
+const ID_SEPARATOR: char = ':';
+const PERMISSIONS_SEPARATOR: char = ',';
+
+/// Global permissions in the compact form, comma separated list of the permissions:
+/// m_srv, r_srv, m_usr, r_usr, m_str, r_str, m_top, r_top, p_msg and s_msg
+/// (manage/read servers, users, streams and topics, poll and send messages).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalPermissionsArg {
+    pub permissions: GlobalPermissions,
+}
+
+/// Permissions to the single stream in the compact form `stream_id[:permissions][#topic_id[:permissions]]...`,
+/// where the stream permissions are m_str, r_str, m_top, r_top, p_msg and s_msg
+/// and the topic permissions are m_top, r_top, p_msg and s_msg, e.g. `1:r_str,p_msg#2:s_msg`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamPermissionsArg {
+    pub stream_id: u32,
+    pub permissions: StreamPermissions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionsArgs {
+    global: Option<GlobalPermissionsArg>,
+    streams: Option<Vec<StreamPermissionsArg>>,
+}
+
+impl PermissionsArgs {
+    pub fn new(
+        global: Option<GlobalPermissionsArg>,
+        streams: Option<Vec<StreamPermissionsArg>>,
+    ) -> Self {
+        Self { global, streams }
+    }
+}
+
+impl From<PermissionsArgs> for Option<Permissions> {
+    fn from(value: PermissionsArgs) -> Self {
+        if value.global.is_none() && value.streams.is_none() {
+            return None;
+        }
+
+        Some(Permissions {
+            global: value
+                .global
+                .map(|global| global.permissions)
+                .unwrap_or_default(),
+            streams: value.streams.map(|streams| {
+                streams
+                    .into_iter()
+                    .map(|stream| (stream.stream_id, stream.permissions))
+                    .collect()
+            }),
+        })
+    }
+}
+
+fn parse_permissions(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(PERMISSIONS_SEPARATOR)
+        .map(|permission| permission.trim())
+        .filter(|permission| !permission.is_empty() && *permission != NO_PERMISSIONS)
+}
+
+fn join_permissions(permissions: &[(&str, bool)]) -> String {
+    let permissions = permissions
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    match permissions.is_empty() {
+        true => NO_PERMISSIONS.to_string(),
+        false => permissions.join(&PERMISSIONS_SEPARATOR.to_string()),
+    }
+}
+
+fn parse_id(value: &str, kind: &str) -> Result<u32, String> {
+    match value.trim().parse::<u32>() {
+        Ok(id) if id > 0 => Ok(id),
+        _ => Err(format!(
+            "Invalid {kind} ID: {value}, it must be a positive number"
+        )),
+    }
+}
+
+impl From<GlobalPermissions> for GlobalPermissionsArg {
+    fn from(permissions: GlobalPermissions) -> Self {
+        Self { permissions }
+    }
+}
+
+impl FromStr for GlobalPermissionsArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut permissions = GlobalPermissions::default();
+        for permission in parse_permissions(s) {
+            match permission {
+                MANAGE_SERVERS => permissions.manage_servers = true,
+                READ_SERVERS => permissions.read_servers = true,
+                MANAGE_USERS => permissions.manage_users = true,
+                READ_USERS => permissions.read_users = true,
+                MANAGE_STREAMS => permissions.manage_streams = true,
+                READ_STREAMS => permissions.read_streams = true,
+                MANAGE_TOPICS => permissions.manage_topics = true,
+                READ_TOPICS => permissions.read_topics = true,
+                POLL_MESSAGES => permissions.poll_messages = true,
+                SEND_MESSAGES => permissions.send_messages = true,
+                unknown => return Err(format!("Unknown global permission: {unknown}")),
+            }
+        }
+
+        Ok(Self { permissions })
+    }
+}
+
+impl Display for GlobalPermissionsArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let permissions = &self.permissions;
+        write!(
+            f,
+            "{}",
+            join_permissions(&[
+                (MANAGE_SERVERS, permissions.manage_servers),
+                (READ_SERVERS, permissions.read_servers),
+                (MANAGE_USERS, permissions.manage_users),
+                (READ_USERS, permissions.read_users),
+                (MANAGE_STREAMS, permissions.manage_streams),
+                (READ_STREAMS, permissions.read_streams),
+                (MANAGE_TOPICS, permissions.manage_topics),
+                (READ_TOPICS, permissions.read_topics),
+                (POLL_MESSAGES, permissions.poll_messages),
+                (SEND_MESSAGES, permissions.send_messages),
+            ])
+        )
+    }
+}
+
+impl StreamPermissionsArg {
+    pub fn new(stream_id: u32, permissions: StreamPermissions) -> Self {
+        Self {
+            stream_id,
+            permissions,
+        }
+    }
+
+    fn parse_topic(value: &str) -> Result<(u32, TopicPermissions), String> {
+        let (topic_id, value) = value.split_once(ID_SEPARATOR).unwrap_or((value, ""));
+        let topic_id = parse_id(topic_id, "topic")?;
+        let mut permissions = TopicPermissions::default();
+        for permission in parse_permissions(value) {
+            match permission {
+                MANAGE_TOPICS => permissions.manage_topic = true,
+                READ_TOPICS => permissions.read_topic = true,
+                POLL_MESSAGES => permissions.poll_messages = true,
+                SEND_MESSAGES => permissions.send_messages = true,
+                unknown => {
+                    return Err(format!(
+                        "Unknown permission: {unknown} for topic with ID: {topic_id}"
+                    ))
+                }
+            }
+        }
+
+        Ok((topic_id, permissions))
+    }
+}
+
+impl FromStr for StreamPermissionsArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(TOPIC_SEPARATOR);
+        let stream = parts.next().unwrap_or_default();
+        let (stream_id, value) = stream.split_once(ID_SEPARATOR).unwrap_or((stream, ""));
+        let stream_id = parse_id(stream_id, "stream")?;
+        let mut permissions = StreamPermissions::default();
+        for permission in parse_permissions(value) {
+            match permission {
+                MANAGE_STREAMS => permissions.manage_stream = true,
+                READ_STREAMS => permissions.read_stream = true,
+                MANAGE_TOPICS => permissions.manage_topics = true,
+                READ_TOPICS => permissions.read_topics = true,
+                POLL_MESSAGES => permissions.poll_messages = true,
+                SEND_MESSAGES => permissions.send_messages = true,
+                unknown => {
+                    return Err(format!(
+                        "Unknown permission: {unknown} for stream with ID: {stream_id}"
+                    ))
+                }
+            }
+        }
+
+        let topics = parts
+            .map(Self::parse_topic)
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        if !topics.is_empty() {
+            permissions.topics = Some(topics);
+        }
+
+        Ok(Self {
+            stream_id,
+            permissions,
+        })
+    }
+}
+
+impl Display for StreamPermissionsArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let permissions = &self.permissions;
+        write!(
+            f,
+            "{}{ID_SEPARATOR}{}",
+            self.stream_id,
+            join_permissions(&[
+                (MANAGE_STREAMS, permissions.manage_stream),
+                (READ_STREAMS, permissions.read_stream),
+                (MANAGE_TOPICS, permissions.manage_topics),
+                (READ_TOPICS, permissions.read_topics),
+                (POLL_MESSAGES, permissions.poll_messages),
+                (SEND_MESSAGES, permissions.send_messages),
+            ])
+        )?;
+
+        if let Some(topics) = &permissions.topics {
+            let mut topics = topics.iter().collect::<Vec<_>>();
+            topics.sort_by_key(|(topic_id, _)| **topic_id);
+            for (topic_id, topic) in topics {
+                write!(
+                    f,
+                    "{TOPIC_SEPARATOR}{topic_id}{ID_SEPARATOR}{}",
+                    join_permissions(&[
+                        (MANAGE_TOPICS, topic.manage_topic),
+                        (READ_TOPICS, topic.read_topic),
+                        (POLL_MESSAGES, topic.poll_messages),
+                        (SEND_MESSAGES, topic.send_messages),
+                    ])
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_global_permissions() {
+        let global = GlobalPermissionsArg::from_str("m_srv,r_usr, p_msg").unwrap();
+        assert_eq!(
+            global.permissions,
+            GlobalPermissions {
+                manage_servers: true,
+                read_users: true,
+                poll_messages: true,
+                ..GlobalPermissions::default()
+            }
+        );
+        assert_eq!(
+            GlobalPermissionsArg::from_str("none").unwrap().permissions,
+            GlobalPermissions::default()
+        );
+    }
+
+    #[test]
+    fn should_fail_parsing_global_permissions() {
+        assert!(GlobalPermissionsArg::from_str("m_srv,x_msg").is_err());
+        assert!(GlobalPermissionsArg::from_str("m_stream").is_err());
+    }
+
+    #[test]
+    fn should_parse_stream_permissions() {
+        let stream = StreamPermissionsArg::from_str("3:r_str,s_msg#1:m_top#2").unwrap();
+        assert_eq!(stream.stream_id, 3);
+        assert_eq!(
+            stream.permissions,
+            StreamPermissions {
+                read_stream: true,
+                send_messages: true,
+                topics: Some(HashMap::from([
+                    (
+                        1,
+                        TopicPermissions {
+                            manage_topic: true,
+                            ..TopicPermissions::default()
+                        }
+                    ),
+                    (2, TopicPermissions::default()),
+                ])),
+                ..StreamPermissions::default()
+            }
+        );
+        assert_eq!(
+            StreamPermissionsArg::from_str("7").unwrap(),
+            StreamPermissionsArg::new(7, StreamPermissions::default())
+        );
+    }
+
+    #[test]
+    fn should_fail_parsing_stream_permissions() {
+        assert!(StreamPermissionsArg::from_str("stream:r_str").is_err());
+        assert!(StreamPermissionsArg::from_str("0:r_str").is_err());
+        assert!(StreamPermissionsArg::from_str("1:m_srv").is_err());
+        assert!(StreamPermissionsArg::from_str("1:r_str#2:r_str").is_err());
+        assert!(StreamPermissionsArg::from_str("1#topic").is_err());
+    }
+
+    #[test]
+    fn should_check_display_permissions() {
+        for value in ["m_srv,r_srv,m_top,s_msg", "none"] {
+            let global = GlobalPermissionsArg::from_str(value).unwrap();
+            assert_eq!(global.to_string(), value);
+        }
+        for value in ["1:r_str,p_msg#1:r_top,p_msg#2:s_msg", "2:none#5:none"] {
+            let stream = StreamPermissionsArg::from_str(value).unwrap();
+            assert_eq!(stream.to_string(), value);
+        }
+    }
+
+    #[test]
+    fn should_convert_permissions_args() {
+        let permissions: Option<Permissions> = PermissionsArgs::new(None, None).into();
+        assert_eq!(permissions, None);
+
+        let permissions: Option<Permissions> = PermissionsArgs::new(
+            Some(GlobalPermissionsArg::from_str("r_srv").unwrap()),
+            Some(vec![StreamPermissionsArg::from_str("1:p_msg").unwrap()]),
+        )
+        .into();
+        assert_eq!(
+            permissions,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_servers: true,
+                    ..GlobalPermissions::default()
+                },
+                streams: Some(HashMap::from([(
+                    1,
+                    StreamPermissions {
+                        poll_messages: true,
+                        ..StreamPermissions::default()
+                    }
+                )])),
+            })
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use byte_unit::Byte;
+use std::fmt::Display;
+use std::{convert::From, str::FromStr};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TopicSize {
+    /// Limit the size of the topic (or partition) to given number of bytes
+    MaxSize(u64),
+    /// Do not limit the size of the topic (or partition)
+    Unlimited,
+}
+
+impl From<&TopicSize> for Option<u64> {
+    fn from(value: &TopicSize) -> Self {
+        match value {
+            TopicSize::MaxSize(value) => Some(*value),
+            TopicSize::Unlimited => None,
+        }
+    }
+}
+
+impl From<Option<u64>> for TopicSize {
+    fn from(value: Option<u64>) -> Self {
+        match value {
+            Some(value) => TopicSize::MaxSize(value),
+            None => TopicSize::Unlimited,
+        }
+    }
+}
+
+impl Display for TopicSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unlimited => write!(f, "unlimited"),
+            Self::MaxSize(value) => write!(
+                f,
+                "{}",
+                Byte::from_bytes(*value as u128).get_appropriate_unit(false)
+            ),
+        }
+    }
+}
+
+impl FromStr for TopicSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let result = match s {
+            "unlimited" | "none" => TopicSize::Unlimited,
+            value => {
+                let size = Byte::from_str(value).map_err(|e| format!("{e}"))?;
+                let size = size.get_bytes();
+                if size > u64::MAX as u128 {
+                    return Err(format!(
+                        "Value too big for topic size, maximum value is {}",
+                        u64::MAX
+                    ));
+                }
+
+                match size {
+                    0 => TopicSize::Unlimited,
+                    size => TopicSize::MaxSize(size as u64),
+                }
+            }
+        };
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_topic_size() {
+        assert_eq!(
+            TopicSize::from_str("unlimited").unwrap(),
+            TopicSize::Unlimited
+        );
+        assert_eq!(TopicSize::from_str("none").unwrap(), TopicSize::Unlimited);
+        assert_eq!(TopicSize::from_str("0").unwrap(), TopicSize::Unlimited);
+        assert_eq!(
+            TopicSize::from_str("1000").unwrap(),
+            TopicSize::MaxSize(1000)
+        );
+        assert_eq!(
+            TopicSize::from_str("5MB").unwrap(),
+            TopicSize::MaxSize(5_000_000)
+        );
+        assert_eq!(
+            TopicSize::from_str("2GiB").unwrap(),
+            TopicSize::MaxSize(2 * 1024 * 1024 * 1024)
+        );
+    }
+
+    #[test]
+    fn should_fail_parsing_topic_size() {
+        assert!(TopicSize::from_str("5XB").is_err());
+        assert!(TopicSize::from_str("-1").is_err());
+    }
+
+    #[test]
+    fn should_check_display_topic_size() {
+        assert_eq!(TopicSize::Unlimited.to_string(), "unlimited");
+        assert_eq!(TopicSize::MaxSize(5_000_000).to_string(), "5.00 MB");
+    }
+
+    #[test]
+    fn should_convert_topic_size_to_option() {
+        let result: Option<u64> = From::from(&TopicSize::Unlimited);
+        assert_eq!(result, None);
+        let result: Option<u64> = From::from(&TopicSize::MaxSize(42));
+        assert_eq!(result, Some(42));
+    }
+}
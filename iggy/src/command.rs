@@ -1,13 +1,18 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::cluster::get_cluster_metadata::GetClusterMetadata;
+use crate::cluster::replicate_messages::ReplicateMessages;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::partitions::create_partitions::CreatePartitions;
@@ -16,11 +21,17 @@ use crate::personal_access_tokens::create_personal_access_token::CreatePersonalA
 use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use crate::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use crate::schemas::delete_schema::DeleteSchema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_settings::UpdateSchemaSettings;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
 use crate::streams::get_streams::GetStreams;
 use crate::streams::update_stream::UpdateStream;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -31,6 +42,9 @@ use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -46,8 +60,12 @@ use std::str::FromStr;
 
 pub const PING: &str = "ping";
 pub const PING_CODE: u32 = 1;
+pub const GET_CLUSTER_METADATA: &str = "cluster.metadata";
+pub const GET_CLUSTER_METADATA_CODE: u32 = 5;
 pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
+pub const CREATE_BACKUP: &str = "backup.create";
+pub const CREATE_BACKUP_CODE: u32 = 11;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
@@ -84,10 +102,22 @@ pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
+pub const POISON_MESSAGE: &str = "message.poison";
+pub const POISON_MESSAGE_CODE: u32 = 102;
+pub const REPLICATE_MESSAGES: &str = "message.replicate";
+pub const REPLICATE_MESSAGES_CODE: u32 = 103;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
 pub const STORE_CONSUMER_OFFSET_CODE: u32 = 121;
+pub const GET_CONSUMER_LAG: &str = "consumer_offset.lag";
+pub const GET_CONSUMER_LAG_CODE: u32 = 122;
+pub const BEGIN_TRANSACTION: &str = "transaction.begin";
+pub const BEGIN_TRANSACTION_CODE: u32 = 130;
+pub const COMMIT_TRANSACTION: &str = "transaction.commit";
+pub const COMMIT_TRANSACTION_CODE: u32 = 131;
+pub const ABORT_TRANSACTION: &str = "transaction.abort";
+pub const ABORT_TRANSACTION_CODE: u32 = 132;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS: &str = "stream.list";
@@ -124,11 +154,25 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const HEARTBEAT_CONSUMER_GROUP: &str = "consumer_group.heartbeat";
+pub const HEARTBEAT_CONSUMER_GROUP_CODE: u32 = 606;
+pub const GET_SCHEMA: &str = "schema.get";
+pub const GET_SCHEMA_CODE: u32 = 700;
+pub const GET_SCHEMAS: &str = "schema.list";
+pub const GET_SCHEMAS_CODE: u32 = 701;
+pub const REGISTER_SCHEMA: &str = "schema.register";
+pub const REGISTER_SCHEMA_CODE: u32 = 702;
+pub const DELETE_SCHEMA: &str = "schema.delete";
+pub const DELETE_SCHEMA_CODE: u32 = 703;
+pub const UPDATE_SCHEMA_SETTINGS: &str = "schema.settings";
+pub const UPDATE_SCHEMA_SETTINGS_CODE: u32 = 704;
 
 #[derive(Debug, PartialEq)]
 pub enum Command {
     Ping(Ping),
+    GetClusterMetadata(GetClusterMetadata),
     GetStats(GetStats),
+    CreateBackup(CreateBackup),
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
@@ -146,9 +190,15 @@ pub enum Command {
     DeletePersonalAccessToken(DeletePersonalAccessToken),
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken),
     SendMessages(SendMessages),
+    PoisonMessage(PoisonMessage),
+    ReplicateMessages(ReplicateMessages),
     PollMessages(PollMessages),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
+    GetConsumerLag(GetConsumerLag),
+    BeginTransaction(BeginTransaction),
+    CommitTransaction(CommitTransaction),
+    AbortTransaction(AbortTransaction),
     GetStream(GetStream),
     GetStreams(GetStreams),
     CreateStream(CreateStream),
@@ -167,6 +217,12 @@ pub enum Command {
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    HeartbeatConsumerGroup(HeartbeatConsumerGroup),
+    GetSchema(GetSchema),
+    GetSchemas(GetSchemas),
+    RegisterSchema(RegisterSchema),
+    DeleteSchema(DeleteSchema),
+    UpdateSchemaSettings(UpdateSchemaSettings),
 }
 
 pub trait CommandPayload: BytesSerializable + Display {}
@@ -175,7 +231,11 @@ impl BytesSerializable for Command {
     fn as_bytes(&self) -> Vec<u8> {
         match self {
             Command::Ping(payload) => as_bytes(PING_CODE, &payload.as_bytes()),
+            Command::GetClusterMetadata(payload) => {
+                as_bytes(GET_CLUSTER_METADATA_CODE, &payload.as_bytes())
+            }
             Command::GetStats(payload) => as_bytes(GET_STATS_CODE, &payload.as_bytes()),
+            Command::CreateBackup(payload) => as_bytes(CREATE_BACKUP_CODE, &payload.as_bytes()),
             Command::GetMe(payload) => as_bytes(GET_ME_CODE, &payload.as_bytes()),
             Command::GetClient(payload) => as_bytes(GET_CLIENT_CODE, &payload.as_bytes()),
             Command::GetClients(payload) => as_bytes(GET_CLIENTS_CODE, &payload.as_bytes()),
@@ -203,6 +263,10 @@ impl BytesSerializable for Command {
                 as_bytes(LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE, &payload.as_bytes())
             }
             Command::SendMessages(payload) => as_bytes(SEND_MESSAGES_CODE, &payload.as_bytes()),
+            Command::PoisonMessage(payload) => as_bytes(POISON_MESSAGE_CODE, &payload.as_bytes()),
+            Command::ReplicateMessages(payload) => {
+                as_bytes(REPLICATE_MESSAGES_CODE, &payload.as_bytes())
+            }
             Command::PollMessages(payload) => as_bytes(POLL_MESSAGES_CODE, &payload.as_bytes()),
             Command::StoreConsumerOffset(payload) => {
                 as_bytes(STORE_CONSUMER_OFFSET_CODE, &payload.as_bytes())
@@ -210,6 +274,18 @@ impl BytesSerializable for Command {
             Command::GetConsumerOffset(payload) => {
                 as_bytes(GET_CONSUMER_OFFSET_CODE, &payload.as_bytes())
             }
+            Command::GetConsumerLag(payload) => {
+                as_bytes(GET_CONSUMER_LAG_CODE, &payload.as_bytes())
+            }
+            Command::BeginTransaction(payload) => {
+                as_bytes(BEGIN_TRANSACTION_CODE, &payload.as_bytes())
+            }
+            Command::CommitTransaction(payload) => {
+                as_bytes(COMMIT_TRANSACTION_CODE, &payload.as_bytes())
+            }
+            Command::AbortTransaction(payload) => {
+                as_bytes(ABORT_TRANSACTION_CODE, &payload.as_bytes())
+            }
             Command::GetStream(payload) => as_bytes(GET_STREAM_CODE, &payload.as_bytes()),
             Command::GetStreams(payload) => as_bytes(GET_STREAMS_CODE, &payload.as_bytes()),
             Command::CreateStream(payload) => as_bytes(CREATE_STREAM_CODE, &payload.as_bytes()),
@@ -244,6 +320,16 @@ impl BytesSerializable for Command {
             Command::LeaveConsumerGroup(payload) => {
                 as_bytes(LEAVE_CONSUMER_GROUP_CODE, &payload.as_bytes())
             }
+            Command::HeartbeatConsumerGroup(payload) => {
+                as_bytes(HEARTBEAT_CONSUMER_GROUP_CODE, &payload.as_bytes())
+            }
+            Command::GetSchema(payload) => as_bytes(GET_SCHEMA_CODE, &payload.as_bytes()),
+            Command::GetSchemas(payload) => as_bytes(GET_SCHEMAS_CODE, &payload.as_bytes()),
+            Command::RegisterSchema(payload) => as_bytes(REGISTER_SCHEMA_CODE, &payload.as_bytes()),
+            Command::DeleteSchema(payload) => as_bytes(DELETE_SCHEMA_CODE, &payload.as_bytes()),
+            Command::UpdateSchemaSettings(payload) => {
+                as_bytes(UPDATE_SCHEMA_SETTINGS_CODE, &payload.as_bytes())
+            }
         }
     }
 
@@ -252,7 +338,11 @@ impl BytesSerializable for Command {
         let payload = &bytes[4..];
         match command {
             PING_CODE => Ok(Command::Ping(Ping::from_bytes(payload)?)),
+            GET_CLUSTER_METADATA_CODE => Ok(Command::GetClusterMetadata(
+                GetClusterMetadata::from_bytes(payload)?,
+            )),
             GET_STATS_CODE => Ok(Command::GetStats(GetStats::from_bytes(payload)?)),
+            CREATE_BACKUP_CODE => Ok(Command::CreateBackup(CreateBackup::from_bytes(payload)?)),
             GET_ME_CODE => Ok(Command::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(Command::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(Command::GetClients(GetClients::from_bytes(payload)?)),
@@ -282,6 +372,10 @@ impl BytesSerializable for Command {
                 LoginWithPersonalAccessToken::from_bytes(payload)?,
             )),
             SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
+            POISON_MESSAGE_CODE => Ok(Command::PoisonMessage(PoisonMessage::from_bytes(payload)?)),
+            REPLICATE_MESSAGES_CODE => Ok(Command::ReplicateMessages(
+                ReplicateMessages::from_bytes(payload)?,
+            )),
             POLL_MESSAGES_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
             STORE_CONSUMER_OFFSET_CODE => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
@@ -289,6 +383,18 @@ impl BytesSerializable for Command {
             GET_CONSUMER_OFFSET_CODE => Ok(Command::GetConsumerOffset(
                 GetConsumerOffset::from_bytes(payload)?,
             )),
+            GET_CONSUMER_LAG_CODE => Ok(Command::GetConsumerLag(GetConsumerLag::from_bytes(
+                payload,
+            )?)),
+            BEGIN_TRANSACTION_CODE => Ok(Command::BeginTransaction(BeginTransaction::from_bytes(
+                payload,
+            )?)),
+            COMMIT_TRANSACTION_CODE => Ok(Command::CommitTransaction(
+                CommitTransaction::from_bytes(payload)?,
+            )),
+            ABORT_TRANSACTION_CODE => Ok(Command::AbortTransaction(AbortTransaction::from_bytes(
+                payload,
+            )?)),
             GET_STREAM_CODE => Ok(Command::GetStream(GetStream::from_bytes(payload)?)),
             GET_STREAMS_CODE => Ok(Command::GetStreams(GetStreams::from_bytes(payload)?)),
             CREATE_STREAM_CODE => Ok(Command::CreateStream(CreateStream::from_bytes(payload)?)),
@@ -323,6 +429,18 @@ impl BytesSerializable for Command {
             LEAVE_CONSUMER_GROUP_CODE => Ok(Command::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            HEARTBEAT_CONSUMER_GROUP_CODE => Ok(Command::HeartbeatConsumerGroup(
+                HeartbeatConsumerGroup::from_bytes(payload)?,
+            )),
+            GET_SCHEMA_CODE => Ok(Command::GetSchema(GetSchema::from_bytes(payload)?)),
+            GET_SCHEMAS_CODE => Ok(Command::GetSchemas(GetSchemas::from_bytes(payload)?)),
+            REGISTER_SCHEMA_CODE => Ok(Command::RegisterSchema(RegisterSchema::from_bytes(
+                payload,
+            )?)),
+            DELETE_SCHEMA_CODE => Ok(Command::DeleteSchema(DeleteSchema::from_bytes(payload)?)),
+            UPDATE_SCHEMA_SETTINGS_CODE => Ok(Command::UpdateSchemaSettings(
+                UpdateSchemaSettings::from_bytes(payload)?,
+            )),
             _ => Err(Error::InvalidCommand),
         }
     }
@@ -341,7 +459,11 @@ impl FromStr for Command {
         let (command, payload) = input.split_once('|').unwrap_or((input, ""));
         match command {
             PING => Ok(Command::Ping(Ping::from_str(payload)?)),
+            GET_CLUSTER_METADATA => Ok(Command::GetClusterMetadata(GetClusterMetadata::from_str(
+                payload,
+            )?)),
             GET_STATS => Ok(Command::GetStats(GetStats::from_str(payload)?)),
+            CREATE_BACKUP => Ok(Command::CreateBackup(CreateBackup::from_str(payload)?)),
             GET_ME => Ok(Command::GetMe(GetMe::from_str(payload)?)),
             GET_CLIENT => Ok(Command::GetClient(GetClient::from_str(payload)?)),
             GET_CLIENTS => Ok(Command::GetClients(GetClients::from_str(payload)?)),
@@ -369,6 +491,10 @@ impl FromStr for Command {
                 LoginWithPersonalAccessToken::from_str(payload)?,
             )),
             SEND_MESSAGES => Ok(Command::SendMessages(SendMessages::from_str(payload)?)),
+            POISON_MESSAGE => Ok(Command::PoisonMessage(PoisonMessage::from_str(payload)?)),
+            REPLICATE_MESSAGES => Ok(Command::ReplicateMessages(ReplicateMessages::from_str(
+                payload,
+            )?)),
             POLL_MESSAGES => Ok(Command::PollMessages(PollMessages::from_str(payload)?)),
             STORE_CONSUMER_OFFSET => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_str(payload)?,
@@ -376,6 +502,16 @@ impl FromStr for Command {
             GET_CONSUMER_OFFSET => Ok(Command::GetConsumerOffset(GetConsumerOffset::from_str(
                 payload,
             )?)),
+            GET_CONSUMER_LAG => Ok(Command::GetConsumerLag(GetConsumerLag::from_str(payload)?)),
+            BEGIN_TRANSACTION => Ok(Command::BeginTransaction(BeginTransaction::from_str(
+                payload,
+            )?)),
+            COMMIT_TRANSACTION => Ok(Command::CommitTransaction(CommitTransaction::from_str(
+                payload,
+            )?)),
+            ABORT_TRANSACTION => Ok(Command::AbortTransaction(AbortTransaction::from_str(
+                payload,
+            )?)),
             GET_STREAM => Ok(Command::GetStream(GetStream::from_str(payload)?)),
             GET_STREAMS => Ok(Command::GetStreams(GetStreams::from_str(payload)?)),
             CREATE_STREAM => Ok(Command::CreateStream(CreateStream::from_str(payload)?)),
@@ -410,16 +546,90 @@ impl FromStr for Command {
             LEAVE_CONSUMER_GROUP => Ok(Command::LeaveConsumerGroup(LeaveConsumerGroup::from_str(
                 payload,
             )?)),
+            HEARTBEAT_CONSUMER_GROUP => Ok(Command::HeartbeatConsumerGroup(
+                HeartbeatConsumerGroup::from_str(payload)?,
+            )),
+            GET_SCHEMA => Ok(Command::GetSchema(GetSchema::from_str(payload)?)),
+            GET_SCHEMAS => Ok(Command::GetSchemas(GetSchemas::from_str(payload)?)),
+            REGISTER_SCHEMA => Ok(Command::RegisterSchema(RegisterSchema::from_str(payload)?)),
+            DELETE_SCHEMA => Ok(Command::DeleteSchema(DeleteSchema::from_str(payload)?)),
+            UPDATE_SCHEMA_SETTINGS => Ok(Command::UpdateSchemaSettings(
+                UpdateSchemaSettings::from_str(payload)?,
+            )),
             _ => Err(Error::InvalidCommand),
         }
     }
 }
 
+impl Command {
+    /// Returns the name of the command without its payload, e.g. to be used as the tracing span attribute.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Ping(_) => PING,
+            Command::GetClusterMetadata(_) => GET_CLUSTER_METADATA,
+            Command::GetStats(_) => GET_STATS,
+            Command::CreateBackup(_) => CREATE_BACKUP,
+            Command::GetMe(_) => GET_ME,
+            Command::GetClient(_) => GET_CLIENT,
+            Command::GetClients(_) => GET_CLIENTS,
+            Command::GetUser(_) => GET_USER,
+            Command::GetUsers(_) => GET_USERS,
+            Command::CreateUser(_) => CREATE_USER,
+            Command::DeleteUser(_) => DELETE_USER,
+            Command::UpdateUser(_) => UPDATE_USER,
+            Command::UpdatePermissions(_) => UPDATE_PERMISSIONS,
+            Command::ChangePassword(_) => CHANGE_PASSWORD,
+            Command::LoginUser(_) => LOGIN_USER,
+            Command::LogoutUser(_) => LOGOUT_USER,
+            Command::GetPersonalAccessTokens(_) => GET_PERSONAL_ACCESS_TOKENS,
+            Command::CreatePersonalAccessToken(_) => CREATE_PERSONAL_ACCESS_TOKEN,
+            Command::DeletePersonalAccessToken(_) => DELETE_PERSONAL_ACCESS_TOKEN,
+            Command::LoginWithPersonalAccessToken(_) => LOGIN_WITH_PERSONAL_ACCESS_TOKEN,
+            Command::GetStream(_) => GET_STREAM,
+            Command::GetStreams(_) => GET_STREAMS,
+            Command::CreateStream(_) => CREATE_STREAM,
+            Command::DeleteStream(_) => DELETE_STREAM,
+            Command::UpdateStream(_) => UPDATE_STREAM,
+            Command::GetTopic(_) => GET_TOPIC,
+            Command::GetTopics(_) => GET_TOPICS,
+            Command::CreateTopic(_) => CREATE_TOPIC,
+            Command::DeleteTopic(_) => DELETE_TOPIC,
+            Command::UpdateTopic(_) => UPDATE_TOPIC,
+            Command::CreatePartitions(_) => CREATE_PARTITIONS,
+            Command::DeletePartitions(_) => DELETE_PARTITIONS,
+            Command::PollMessages(_) => POLL_MESSAGES,
+            Command::SendMessages(_) => SEND_MESSAGES,
+            Command::PoisonMessage(_) => POISON_MESSAGE,
+            Command::ReplicateMessages(_) => REPLICATE_MESSAGES,
+            Command::StoreConsumerOffset(_) => STORE_CONSUMER_OFFSET,
+            Command::GetConsumerOffset(_) => GET_CONSUMER_OFFSET,
+            Command::GetConsumerLag(_) => GET_CONSUMER_LAG,
+            Command::BeginTransaction(_) => BEGIN_TRANSACTION,
+            Command::CommitTransaction(_) => COMMIT_TRANSACTION,
+            Command::AbortTransaction(_) => ABORT_TRANSACTION,
+            Command::GetConsumerGroup(_) => GET_CONSUMER_GROUP,
+            Command::GetConsumerGroups(_) => GET_CONSUMER_GROUPS,
+            Command::CreateConsumerGroup(_) => CREATE_CONSUMER_GROUP,
+            Command::DeleteConsumerGroup(_) => DELETE_CONSUMER_GROUP,
+            Command::JoinConsumerGroup(_) => JOIN_CONSUMER_GROUP,
+            Command::LeaveConsumerGroup(_) => LEAVE_CONSUMER_GROUP,
+            Command::HeartbeatConsumerGroup(_) => HEARTBEAT_CONSUMER_GROUP,
+            Command::GetSchema(_) => GET_SCHEMA,
+            Command::GetSchemas(_) => GET_SCHEMAS,
+            Command::RegisterSchema(_) => REGISTER_SCHEMA,
+            Command::DeleteSchema(_) => DELETE_SCHEMA,
+            Command::UpdateSchemaSettings(_) => UPDATE_SCHEMA_SETTINGS,
+        }
+    }
+}
+
 impl Display for Command {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Command::Ping(_) => write!(formatter, "{PING}"),
+            Command::GetClusterMetadata(_) => write!(formatter, "{GET_CLUSTER_METADATA}"),
             Command::GetStats(_) => write!(formatter, "{GET_STATS}"),
+            Command::CreateBackup(_) => write!(formatter, "{CREATE_BACKUP}"),
             Command::GetMe(_) => write!(formatter, "{GET_ME}"),
             Command::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             Command::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
@@ -466,12 +676,28 @@ impl Display for Command {
             }
             Command::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
             Command::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
+            Command::PoisonMessage(payload) => write!(formatter, "{POISON_MESSAGE}|{payload}"),
+            Command::ReplicateMessages(payload) => {
+                write!(formatter, "{REPLICATE_MESSAGES}|{payload}")
+            }
             Command::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
             }
             Command::GetConsumerOffset(payload) => {
                 write!(formatter, "{GET_CONSUMER_OFFSET}|{payload}")
             }
+            Command::GetConsumerLag(payload) => {
+                write!(formatter, "{GET_CONSUMER_LAG}|{payload}")
+            }
+            Command::BeginTransaction(payload) => {
+                write!(formatter, "{BEGIN_TRANSACTION}|{payload}")
+            }
+            Command::CommitTransaction(payload) => {
+                write!(formatter, "{COMMIT_TRANSACTION}|{payload}")
+            }
+            Command::AbortTransaction(payload) => {
+                write!(formatter, "{ABORT_TRANSACTION}|{payload}")
+            }
             Command::GetConsumerGroup(payload) => {
                 write!(formatter, "{GET_CONSUMER_GROUP}|{payload}")
             }
@@ -490,6 +716,16 @@ impl Display for Command {
             Command::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            Command::HeartbeatConsumerGroup(payload) => {
+                write!(formatter, "{HEARTBEAT_CONSUMER_GROUP}|{payload}")
+            }
+            Command::GetSchema(payload) => write!(formatter, "{GET_SCHEMA}|{payload}"),
+            Command::GetSchemas(payload) => write!(formatter, "{GET_SCHEMAS}|{payload}"),
+            Command::RegisterSchema(payload) => write!(formatter, "{REGISTER_SCHEMA}|{payload}"),
+            Command::DeleteSchema(payload) => write!(formatter, "{DELETE_SCHEMA}|{payload}"),
+            Command::UpdateSchemaSettings(payload) => {
+                write!(formatter, "{UPDATE_SCHEMA_SETTINGS}|{payload}")
+            }
         }
     }
 }
@@ -505,11 +741,21 @@ mod tests {
             PING_CODE,
             &Ping::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetClusterMetadata(GetClusterMetadata::default()),
+            GET_CLUSTER_METADATA_CODE,
+            &GetClusterMetadata::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetStats(GetStats::default()),
             GET_STATS_CODE,
             &GetStats::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateBackup(CreateBackup::default()),
+            CREATE_BACKUP_CODE,
+            &CreateBackup::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetMe(GetMe::default()),
             GET_ME_CODE,
@@ -600,6 +846,16 @@ mod tests {
             POLL_MESSAGES_CODE,
             &PollMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::PoisonMessage(PoisonMessage::default()),
+            POISON_MESSAGE_CODE,
+            &PoisonMessage::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::ReplicateMessages(ReplicateMessages::default()),
+            REPLICATE_MESSAGES_CODE,
+            &ReplicateMessages::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET_CODE,
@@ -610,6 +866,26 @@ mod tests {
             GET_CONSUMER_OFFSET_CODE,
             &GetConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetConsumerLag(GetConsumerLag::default()),
+            GET_CONSUMER_LAG_CODE,
+            &GetConsumerLag::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION_CODE,
+            &BeginTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION_CODE,
+            &CommitTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION_CODE,
+            &AbortTransaction::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetStream(GetStream::default()),
             GET_STREAM_CODE,
@@ -700,16 +976,56 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::HeartbeatConsumerGroup(HeartbeatConsumerGroup::default()),
+            HEARTBEAT_CONSUMER_GROUP_CODE,
+            &HeartbeatConsumerGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetSchemas(GetSchemas::default()),
+            GET_SCHEMAS_CODE,
+            &GetSchemas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA_CODE,
+            &RegisterSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteSchema(DeleteSchema::default()),
+            DELETE_SCHEMA_CODE,
+            &DeleteSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateSchemaSettings(UpdateSchemaSettings::default()),
+            UPDATE_SCHEMA_SETTINGS_CODE,
+            &UpdateSchemaSettings::default(),
+        );
     }
 
     #[test]
     fn should_be_read_from_string() {
         assert_read_from_string(&Command::Ping(Ping::default()), PING, &Ping::default());
+        assert_read_from_string(
+            &Command::GetClusterMetadata(GetClusterMetadata::default()),
+            GET_CLUSTER_METADATA,
+            &GetClusterMetadata::default(),
+        );
         assert_read_from_string(
             &Command::GetStats(GetStats::default()),
             GET_STATS,
             &GetStats::default(),
         );
+        assert_read_from_string(
+            &Command::CreateBackup(CreateBackup::default()),
+            CREATE_BACKUP,
+            &CreateBackup::default(),
+        );
         assert_read_from_string(&Command::GetMe(GetMe::default()), GET_ME, &GetMe::default());
         assert_read_from_string(
             &Command::GetClient(GetClient::default()),
@@ -796,6 +1112,16 @@ mod tests {
             POLL_MESSAGES,
             &PollMessages::default(),
         );
+        assert_read_from_string(
+            &Command::PoisonMessage(PoisonMessage::default()),
+            POISON_MESSAGE,
+            &PoisonMessage::default(),
+        );
+        assert_read_from_string(
+            &Command::ReplicateMessages(ReplicateMessages::default()),
+            REPLICATE_MESSAGES,
+            &ReplicateMessages::default(),
+        );
         assert_read_from_string(
             &Command::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET,
@@ -806,6 +1132,26 @@ mod tests {
             GET_CONSUMER_OFFSET,
             &GetConsumerOffset::default(),
         );
+        assert_read_from_string(
+            &Command::GetConsumerLag(GetConsumerLag::default()),
+            GET_CONSUMER_LAG,
+            &GetConsumerLag::default(),
+        );
+        assert_read_from_string(
+            &Command::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION,
+            &BeginTransaction::default(),
+        );
+        assert_read_from_string(
+            &Command::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION,
+            &CommitTransaction::default(),
+        );
+        assert_read_from_string(
+            &Command::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION,
+            &AbortTransaction::default(),
+        );
         assert_read_from_string(
             &Command::GetStream(GetStream::default()),
             GET_STREAM,
@@ -896,6 +1242,36 @@ mod tests {
             LEAVE_CONSUMER_GROUP,
             &LeaveConsumerGroup::default(),
         );
+        assert_read_from_string(
+            &Command::HeartbeatConsumerGroup(HeartbeatConsumerGroup::default()),
+            HEARTBEAT_CONSUMER_GROUP,
+            &HeartbeatConsumerGroup::default(),
+        );
+        assert_read_from_string(
+            &Command::GetSchema(GetSchema::default()),
+            GET_SCHEMA,
+            &GetSchema::default(),
+        );
+        assert_read_from_string(
+            &Command::GetSchemas(GetSchemas::default()),
+            GET_SCHEMAS,
+            &GetSchemas::default(),
+        );
+        assert_read_from_string(
+            &Command::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA,
+            &RegisterSchema::default(),
+        );
+        assert_read_from_string(
+            &Command::DeleteSchema(DeleteSchema::default()),
+            DELETE_SCHEMA,
+            &DeleteSchema::default(),
+        );
+        assert_read_from_string(
+            &Command::UpdateSchemaSettings(UpdateSchemaSettings::default()),
+            UPDATE_SCHEMA_SETTINGS,
+            &UpdateSchemaSettings::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
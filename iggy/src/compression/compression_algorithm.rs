@@ -0,0 +1,89 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionAlgorithm {
+    #[default]
+    None,
+    Lz4,
+    Zstd,
+}
+
+impl CompressionAlgorithm {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CompressionAlgorithm::None => 1,
+            CompressionAlgorithm::Lz4 => 2,
+            CompressionAlgorithm::Zstd => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(CompressionAlgorithm::None),
+            2 => Ok(CompressionAlgorithm::Lz4),
+            3 => Ok(CompressionAlgorithm::Zstd),
+            _ => Err(Error::InvalidCompressionAlgorithm),
+        }
+    }
+}
+
+impl Display for CompressionAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompressionAlgorithm::None => write!(f, "none"),
+            CompressionAlgorithm::Lz4 => write!(f, "lz4"),
+            CompressionAlgorithm::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+impl FromStr for CompressionAlgorithm {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(CompressionAlgorithm::None),
+            "lz4" => Ok(CompressionAlgorithm::Lz4),
+            "zstd" => Ok(CompressionAlgorithm::Zstd),
+            _ => Err(Error::InvalidCompressionAlgorithm),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_converted_from_and_to_code() {
+        for algorithm in [
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+        ] {
+            let code = algorithm.as_code();
+            assert_eq!(CompressionAlgorithm::from_code(code).unwrap(), algorithm);
+        }
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        for algorithm in [
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+        ] {
+            let value = algorithm.to_string();
+            assert_eq!(value.parse::<CompressionAlgorithm>().unwrap(), algorithm);
+        }
+    }
+
+    #[test]
+    fn should_not_be_read_from_invalid_code_or_string() {
+        assert!(CompressionAlgorithm::from_code(0).is_err());
+        assert!("gzip".parse::<CompressionAlgorithm>().is_err());
+    }
+}
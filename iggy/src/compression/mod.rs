@@ -0,0 +1 @@
+pub mod compression_algorithm;
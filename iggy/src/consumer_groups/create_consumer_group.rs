@@ -1,7 +1,9 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
+use crate::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::models::consumer_group::DeadLetterTopic;
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::BufMut;
@@ -19,6 +21,12 @@ pub struct CreateConsumerGroup {
     pub topic_id: Identifier,
     pub consumer_group_id: u32,
     pub name: String,
+    #[serde(default)]
+    pub partition_assignment_strategy: PartitionAssignmentStrategy,
+    #[serde(default)]
+    pub session_timeout: Option<u32>,
+    #[serde(default)]
+    pub dead_letter_topic: Option<DeadLetterTopic>,
 }
 
 impl CommandPayload for CreateConsumerGroup {}
@@ -30,6 +38,9 @@ impl Default for CreateConsumerGroup {
             topic_id: Identifier::default(),
             consumer_group_id: 1,
             name: "consumer_group_1".to_string(),
+            partition_assignment_strategy: PartitionAssignmentStrategy::default(),
+            session_timeout: None,
+            dead_letter_topic: None,
         }
     }
 }
@@ -48,6 +59,15 @@ impl Validatable<Error> for CreateConsumerGroup {
             return Err(Error::InvalidConsumerGroupName);
         }
 
+        if let Some(dead_letter_topic) = self.dead_letter_topic {
+            if dead_letter_topic.stream_id == 0 || dead_letter_topic.topic_id == 0 {
+                return Err(Error::InvalidDeadLetterTopic(
+                    dead_letter_topic.topic_id,
+                    dead_letter_topic.stream_id,
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -56,7 +76,7 @@ impl FromStr for CreateConsumerGroup {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 4 {
+        if parts.len() < 4 || parts.len() == 7 || parts.len() > 8 {
             return Err(Error::InvalidCommand);
         }
 
@@ -64,11 +84,32 @@ impl FromStr for CreateConsumerGroup {
         let topic_id = parts[1].parse::<Identifier>()?;
         let consumer_group_id = parts[2].parse::<u32>()?;
         let name = parts[3].to_string();
+        let partition_assignment_strategy = match parts.get(4) {
+            Some(strategy) => strategy.parse::<PartitionAssignmentStrategy>()?,
+            None => PartitionAssignmentStrategy::default(),
+        };
+        let session_timeout = match parts.get(5) {
+            Some(session_timeout) => match session_timeout.parse::<u32>()? {
+                0 => None,
+                session_timeout => Some(session_timeout),
+            },
+            None => None,
+        };
+        let dead_letter_topic = match parts.len() {
+            8 => Some(DeadLetterTopic {
+                stream_id: parts[6].parse::<u32>()?,
+                topic_id: parts[7].parse::<u32>()?,
+            }),
+            _ => None,
+        };
         let command = CreateConsumerGroup {
             stream_id,
             topic_id,
             consumer_group_id,
             name,
+            partition_assignment_strategy,
+            session_timeout,
+            dead_letter_topic,
         };
         command.validate()?;
         Ok(command)
@@ -80,13 +121,19 @@ impl BytesSerializable for CreateConsumerGroup {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes =
-            Vec::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
+            Vec::with_capacity(18 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.put_u32_le(self.consumer_group_id);
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        bytes.put_u8(self.partition_assignment_strategy.as_code());
+        bytes.put_u32_le(self.session_timeout.unwrap_or(0));
+        if let Some(dead_letter_topic) = self.dead_letter_topic {
+            bytes.put_u32_le(dead_letter_topic.stream_id);
+            bytes.put_u32_le(dead_letter_topic.topic_id);
+        }
         bytes
     }
 
@@ -104,11 +151,41 @@ impl BytesSerializable for CreateConsumerGroup {
         let name_length = bytes[position + 4];
         let name =
             from_utf8(&bytes[position + 5..position + 5 + name_length as usize])?.to_string();
+        position += 5 + name_length as usize;
+        // The partition assignment strategy with the session timeout and the dead letter topic are optional,
+        // so they're present only if there are any bytes left.
+        let mut partition_assignment_strategy = PartitionAssignmentStrategy::default();
+        let mut session_timeout = None;
+        if bytes.len() > position {
+            if bytes.len() < position + 5 {
+                return Err(Error::InvalidCommand);
+            }
+
+            partition_assignment_strategy =
+                PartitionAssignmentStrategy::from_code(bytes[position])?;
+            session_timeout =
+                match u32::from_le_bytes(bytes[position + 1..position + 5].try_into()?) {
+                    0 => None,
+                    session_timeout => Some(session_timeout),
+                };
+            position += 5;
+        }
+        let dead_letter_topic = match bytes.len() - position {
+            0 => None,
+            8 => Some(DeadLetterTopic {
+                stream_id: u32::from_le_bytes(bytes[position..position + 4].try_into()?),
+                topic_id: u32::from_le_bytes(bytes[position + 4..position + 8].try_into()?),
+            }),
+            _ => return Err(Error::InvalidCommand),
+        };
         let command = CreateConsumerGroup {
             stream_id,
             topic_id,
             consumer_group_id,
             name,
+            partition_assignment_strategy,
+            session_timeout,
+            dead_letter_topic,
         };
         command.validate()?;
         Ok(command)
@@ -119,9 +196,22 @@ impl Display for CreateConsumerGroup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
-            self.stream_id, self.topic_id, self.consumer_group_id, self.name
-        )
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.consumer_group_id,
+            self.name,
+            self.partition_assignment_strategy,
+            self.session_timeout.unwrap_or(0)
+        )?;
+        if let Some(dead_letter_topic) = self.dead_letter_topic {
+            write!(
+                f,
+                "|{}|{}",
+                dead_letter_topic.stream_id, dead_letter_topic.topic_id
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -136,6 +226,9 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             consumer_group_id: 3,
             name: "test".to_string(),
+            partition_assignment_strategy: PartitionAssignmentStrategy::Range,
+            session_timeout: Some(30),
+            dead_letter_topic: None,
         };
 
         let bytes = command.as_bytes();
@@ -148,12 +241,22 @@ mod tests {
             u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
         let name_length = bytes[position + 4];
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize]).unwrap();
+        position += 5 + name_length as usize;
+        let partition_assignment_strategy =
+            PartitionAssignmentStrategy::from_code(bytes[position]).unwrap();
+        let session_timeout =
+            u32::from_le_bytes(bytes[position + 1..position + 5].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(consumer_group_id, command.consumer_group_id);
         assert_eq!(name, command.name);
+        assert_eq!(
+            partition_assignment_strategy,
+            command.partition_assignment_strategy
+        );
+        assert_eq!(Some(session_timeout), command.session_timeout);
     }
 
     #[test]
@@ -180,6 +283,11 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.consumer_group_id, consumer_group_id);
         assert_eq!(command.name, name);
+        assert_eq!(
+            command.partition_assignment_strategy,
+            PartitionAssignmentStrategy::RoundRobin
+        );
+        assert!(command.session_timeout.is_none());
     }
 
     #[test]
@@ -198,4 +306,57 @@ mod tests {
         assert_eq!(command.consumer_group_id, consumer_group_id);
         assert_eq!(command.name, name);
     }
+
+    #[test]
+    fn dead_letter_topic_should_be_serialized_and_deserialized_from_bytes() {
+        let command = CreateConsumerGroup {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            consumer_group_id: 3,
+            name: "test".to_string(),
+            partition_assignment_strategy: PartitionAssignmentStrategy::Sticky,
+            session_timeout: None,
+            dead_letter_topic: Some(DeadLetterTopic {
+                stream_id: 4,
+                topic_id: 5,
+            }),
+        };
+
+        let deserialized_command = CreateConsumerGroup::from_bytes(&command.as_bytes());
+        assert!(deserialized_command.is_ok());
+        assert_eq!(deserialized_command.unwrap(), command);
+    }
+
+    #[test]
+    fn dead_letter_topic_should_be_read_from_string() {
+        let input = "1|2|3|test|round_robin|0|4|5";
+        let command = CreateConsumerGroup::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(
+            command.dead_letter_topic,
+            Some(DeadLetterTopic {
+                stream_id: 4,
+                topic_id: 5
+            })
+        );
+        assert_eq!(command.to_string(), input);
+    }
+
+    #[test]
+    fn partition_assignment_strategy_and_session_timeout_should_be_read_from_string() {
+        let input = "1|2|3|test|sticky|10";
+        let command = CreateConsumerGroup::from_str(input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(
+            command.partition_assignment_strategy,
+            PartitionAssignmentStrategy::Sticky
+        );
+        assert_eq!(command.session_timeout, Some(10));
+        assert!(command.dead_letter_topic.is_none());
+        assert_eq!(command.to_string(), input);
+    }
 }
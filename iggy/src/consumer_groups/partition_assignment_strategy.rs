@@ -0,0 +1,100 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Decides how the partitions of the topic are distributed between the members of the consumer group:
+/// - `RoundRobin` - partitions are dealt one by one to the members, all of them are reassigned on every membership change.
+/// - `Range` - each member gets the contiguous range of partitions, all of them are reassigned on every membership change.
+/// - `Sticky` - members keep the partitions they already own, only the partitions of the departed members
+///   or the ones required to balance the group are moved.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionAssignmentStrategy {
+    #[default]
+    RoundRobin,
+    Range,
+    Sticky,
+}
+
+impl PartitionAssignmentStrategy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PartitionAssignmentStrategy::RoundRobin => 1,
+            PartitionAssignmentStrategy::Range => 2,
+            PartitionAssignmentStrategy::Sticky => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(PartitionAssignmentStrategy::RoundRobin),
+            2 => Ok(PartitionAssignmentStrategy::Range),
+            3 => Ok(PartitionAssignmentStrategy::Sticky),
+            _ => Err(Error::InvalidPartitionAssignmentStrategy),
+        }
+    }
+}
+
+impl Display for PartitionAssignmentStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitionAssignmentStrategy::RoundRobin => write!(f, "round_robin"),
+            PartitionAssignmentStrategy::Range => write!(f, "range"),
+            PartitionAssignmentStrategy::Sticky => write!(f, "sticky"),
+        }
+    }
+}
+
+impl FromStr for PartitionAssignmentStrategy {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "round_robin" => Ok(PartitionAssignmentStrategy::RoundRobin),
+            "range" => Ok(PartitionAssignmentStrategy::Range),
+            "sticky" => Ok(PartitionAssignmentStrategy::Sticky),
+            _ => Err(Error::InvalidPartitionAssignmentStrategy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_converted_from_and_to_code() {
+        for strategy in [
+            PartitionAssignmentStrategy::RoundRobin,
+            PartitionAssignmentStrategy::Range,
+            PartitionAssignmentStrategy::Sticky,
+        ] {
+            let code = strategy.as_code();
+            assert_eq!(
+                PartitionAssignmentStrategy::from_code(code).unwrap(),
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        for strategy in [
+            PartitionAssignmentStrategy::RoundRobin,
+            PartitionAssignmentStrategy::Range,
+            PartitionAssignmentStrategy::Sticky,
+        ] {
+            let value = strategy.to_string();
+            assert_eq!(
+                value.parse::<PartitionAssignmentStrategy>().unwrap(),
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn should_not_be_read_from_invalid_code_or_string() {
+        assert!(PartitionAssignmentStrategy::from_code(0).is_err());
+        assert!("random".parse::<PartitionAssignmentStrategy>().is_err());
+    }
+}
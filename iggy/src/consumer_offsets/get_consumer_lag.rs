@@ -0,0 +1,171 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `GetConsumerLag` command is used to calculate the lag of the consumer or the consumer group across all the partitions of the topic.
+/// The lag of the partition is the difference between the current offset of the partition and the offset stored by the consumer.
+/// It has additional payload:
+/// - `consumer` - the consumer that has the offsets stored, either the regular consumer or the consumer group.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetConsumerLag {
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+}
+
+impl CommandPayload for GetConsumerLag {}
+
+impl Validatable<Error> for GetConsumerLag {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for GetConsumerLag {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let consumer_kind = ConsumerKind::from_str(parts[0])?;
+        let consumer_id = parts[1].parse::<Identifier>()?;
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = parts[2].parse::<Identifier>()?;
+        let topic_id = parts[3].parse::<Identifier>()?;
+        let command = GetConsumerLag {
+            consumer,
+            stream_id,
+            topic_id,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for GetConsumerLag {
+    fn as_bytes(&self) -> Vec<u8> {
+        let consumer_bytes = self.consumer.as_bytes();
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes =
+            Vec::with_capacity(consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(consumer_bytes);
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GetConsumerLag, Error> {
+        if bytes.len() < 11 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = Identifier::from_bytes(&bytes[1..])?;
+        position += 1 + consumer_id.get_size_bytes() as usize;
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(&bytes[position..])?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        let command = GetConsumerLag {
+            consumer,
+            stream_id,
+            topic_id,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetConsumerLag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.consumer, self.stream_id, self.topic_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetConsumerLag {
+            consumer: Consumer::group(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0]).unwrap();
+        let consumer_id = Identifier::from_bytes(&bytes[1..]).unwrap();
+        position += 1 + consumer_id.get_size_bytes() as usize;
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(consumer, command.consumer);
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let consumer = Consumer::new(Identifier::numeric(1).unwrap());
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+
+        let mut bytes = Vec::new();
+        bytes.extend(consumer.as_bytes());
+        bytes.extend(stream_id.as_bytes());
+        bytes.extend(topic_id.as_bytes());
+
+        let command = GetConsumerLag::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.consumer, consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let consumer = Consumer::group(Identifier::named("group").unwrap());
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let input = format!("{consumer}|{stream_id}|{topic_id}");
+        let command = GetConsumerLag::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.consumer, consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::client::ClusterClient;
+use crate::cluster::get_cluster_metadata::GetClusterMetadata;
+use crate::error::Error;
+use crate::http::client::HttpClient;
+use crate::models::cluster::ClusterMetadata;
+use async_trait::async_trait;
+
+const PATH: &str = "/cluster";
+
+#[async_trait]
+impl ClusterClient for HttpClient {
+    async fn get_cluster_metadata(
+        &self,
+        _command: &GetClusterMetadata,
+    ) -> Result<ClusterMetadata, Error> {
+        let response = self.get(PATH).await?;
+        let metadata = response.json().await?;
+        Ok(metadata)
+    }
+}
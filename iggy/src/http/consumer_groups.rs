@@ -3,6 +3,7 @@ use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::Error;
@@ -76,6 +77,13 @@ impl ConsumerGroupClient for HttpClient {
     async fn leave_consumer_group(&self, _command: &LeaveConsumerGroup) -> Result<(), Error> {
         Err(Error::FeatureUnavailable)
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        _command: &HeartbeatConsumerGroup,
+    ) -> Result<(), Error> {
+        Err(Error::FeatureUnavailable)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
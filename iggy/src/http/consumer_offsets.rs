@@ -1,8 +1,11 @@
 use crate::client::ConsumerOffsetClient;
+use crate::consumer::ConsumerKind;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use async_trait::async_trait;
 
@@ -36,6 +39,31 @@ impl ConsumerOffsetClient for HttpClient {
         let offset = response.json().await?;
         Ok(offset)
     }
+
+    async fn get_consumer_lag(&self, command: &GetConsumerLag) -> Result<ConsumerLag, Error> {
+        let stream_id = command.stream_id.as_string();
+        let topic_id = command.topic_id.as_string();
+        let response = match command.consumer.kind {
+            ConsumerKind::Consumer => {
+                self.get_with_query(
+                    &format!("{}/lag", get_path(&stream_id, &topic_id)),
+                    &command,
+                )
+                .await?
+            }
+            ConsumerKind::ConsumerGroup => {
+                self.get(&format!(
+                    "streams/{}/topics/{}/consumer-groups/{}/lag",
+                    stream_id,
+                    topic_id,
+                    command.consumer.id.as_string()
+                ))
+                .await?
+            }
+        };
+        let lag = response.json().await?;
+        Ok(lag)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
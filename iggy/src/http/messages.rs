@@ -1,6 +1,7 @@
 use crate::client::MessageClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
@@ -33,6 +34,21 @@ impl MessageClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn poison_message(&self, command: &PoisonMessage) -> Result<(), Error> {
+        self.post(
+            &format!(
+                "{}/poison",
+                get_path(
+                    &command.stream_id.as_string(),
+                    &command.topic_id.as_string()
+                )
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
@@ -0,0 +1,82 @@
+use crate::client::SchemaClient;
+use crate::error::Error;
+use crate::http::client::HttpClient;
+use crate::models::schema::{Schema, TopicSchemas};
+use crate::schemas::delete_schema::DeleteSchema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_settings::UpdateSchemaSettings;
+use async_trait::async_trait;
+
+#[async_trait]
+impl SchemaClient for HttpClient {
+    async fn get_schema(&self, command: &GetSchema) -> Result<Schema, Error> {
+        let response = self
+            .get(&format!(
+                "{}/{}",
+                get_path(
+                    &command.stream_id.as_string(),
+                    &command.topic_id.as_string()
+                ),
+                command.schema_id
+            ))
+            .await?;
+        let schema = response.json().await?;
+        Ok(schema)
+    }
+
+    async fn get_schemas(&self, command: &GetSchemas) -> Result<TopicSchemas, Error> {
+        let response = self
+            .get(&get_path(
+                &command.stream_id.as_string(),
+                &command.topic_id.as_string(),
+            ))
+            .await?;
+        let schemas = response.json().await?;
+        Ok(schemas)
+    }
+
+    async fn register_schema(&self, command: &RegisterSchema) -> Result<Schema, Error> {
+        let response = self
+            .post(
+                &get_path(
+                    &command.stream_id.as_string(),
+                    &command.topic_id.as_string(),
+                ),
+                &command,
+            )
+            .await?;
+        let schema = response.json().await?;
+        Ok(schema)
+    }
+
+    async fn delete_schema(&self, command: &DeleteSchema) -> Result<(), Error> {
+        let path = format!(
+            "{}/{}",
+            get_path(
+                &command.stream_id.as_string(),
+                &command.topic_id.as_string()
+            ),
+            command.schema_id
+        );
+        self.delete(&path).await?;
+        Ok(())
+    }
+
+    async fn update_schema_settings(&self, command: &UpdateSchemaSettings) -> Result<(), Error> {
+        self.put(
+            &get_path(
+                &command.stream_id.as_string(),
+                &command.topic_id.as_string(),
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+fn get_path(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/schemas")
+}
@@ -1,8 +1,10 @@
 use crate::client::SystemClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -13,6 +15,7 @@ use async_trait::async_trait;
 const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
 const STATS: &str = "/stats";
+const BACKUPS: &str = "/backups";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -22,6 +25,12 @@ impl SystemClient for HttpClient {
         Ok(stats)
     }
 
+    async fn create_backup(&self, command: &CreateBackup) -> Result<Backup, Error> {
+        let response = self.post(BACKUPS, &command).await?;
+        let backup = response.json().await?;
+        Ok(backup)
+    }
+
     async fn get_me(&self, _command: &GetMe) -> Result<ClientInfoDetails, Error> {
         Err(Error::FeatureUnavailable)
     }
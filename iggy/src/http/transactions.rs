@@ -0,0 +1,41 @@
+use crate::client::TransactionClient;
+use crate::error::Error;
+use crate::http::client::HttpClient;
+use crate::models::transaction::TransactionInfo;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
+use async_trait::async_trait;
+
+const PATH: &str = "/transactions";
+
+#[async_trait]
+impl TransactionClient for HttpClient {
+    async fn begin_transaction(
+        &self,
+        command: &BeginTransaction,
+    ) -> Result<TransactionInfo, Error> {
+        let response = self.post(PATH, &command).await?;
+        let transaction = response.json().await?;
+        Ok(transaction)
+    }
+
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), Error> {
+        self.post(
+            &format!("{}/commit", get_details_path(command.transaction_id)),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), Error> {
+        self.delete(&get_details_path(command.transaction_id))
+            .await?;
+        Ok(())
+    }
+}
+
+fn get_details_path(transaction_id: u64) -> String {
+    format!("{PATH}/{transaction_id}")
+}
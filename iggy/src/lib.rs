@@ -6,9 +6,11 @@ pub mod client;
 pub mod client_error;
 pub mod client_provider;
 pub mod clients;
+pub mod cluster;
 #[cfg(feature = "iggy-cmd")]
 pub mod cmd;
 pub mod command;
+pub mod compression;
 pub mod consumer;
 pub mod consumer_groups;
 pub mod consumer_offsets;
@@ -22,11 +24,16 @@ pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod quic;
+pub mod rebalance_listener;
+pub mod schemas;
 pub mod sizeable;
 pub mod streams;
 pub mod system;
 pub mod tcp;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
 pub mod validatable;
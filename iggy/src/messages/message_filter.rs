@@ -0,0 +1,455 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::Error;
+use crate::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use base64::engine::general_purpose;
+use base64::Engine;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const MAX_CONDITIONS: usize = 255;
+
+/// The filter evaluated by the server while polling the messages, so that only the matching ones are returned.
+/// All the conditions have to be met by the message headers. The text representation (used by the HTTP API
+/// and the CLI) is a comma-separated list of the conditions, e.g. `priority>=uint32:5,type=string:order,trace`,
+/// where the condition without the operator checks only if the header exists.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct MessageFilter {
+    pub conditions: Vec<HeaderCondition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct HeaderCondition {
+    pub key: HeaderKey,
+    pub predicate: HeaderPredicate,
+}
+
+/// The values are compared only with the headers of the same kind, the headers of the other kinds never match.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "operator", content = "value", rename_all = "snake_case")]
+pub enum HeaderPredicate {
+    Exists,
+    Equals(HeaderValue),
+    Greater(HeaderValue),
+    GreaterOrEqual(HeaderValue),
+    Less(HeaderValue),
+    LessOrEqual(HeaderValue),
+}
+
+impl MessageFilter {
+    pub fn new(conditions: Vec<HeaderCondition>) -> Result<Self, Error> {
+        let filter = Self { conditions };
+        filter.validate()?;
+        Ok(filter)
+    }
+
+    pub fn matches(&self, headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> bool {
+        self.conditions.iter().all(|condition| {
+            headers
+                .as_ref()
+                .and_then(|headers| headers.get(&condition.key))
+                .is_some_and(|value| condition.predicate.matches(value))
+        })
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if self.conditions.is_empty() || self.conditions.len() > MAX_CONDITIONS {
+            return Err(Error::InvalidMessageFilter);
+        }
+
+        Ok(())
+    }
+}
+
+impl HeaderCondition {
+    pub fn exists(key: HeaderKey) -> Self {
+        Self {
+            key,
+            predicate: HeaderPredicate::Exists,
+        }
+    }
+
+    pub fn equals(key: HeaderKey, value: HeaderValue) -> Self {
+        Self {
+            key,
+            predicate: HeaderPredicate::Equals(value),
+        }
+    }
+
+    /// Creates the conditions matching the values between `min` and `max` (both inclusive).
+    pub fn range(key: HeaderKey, min: HeaderValue, max: HeaderValue) -> [Self; 2] {
+        [
+            Self {
+                key: key.clone(),
+                predicate: HeaderPredicate::GreaterOrEqual(min),
+            },
+            Self {
+                key,
+                predicate: HeaderPredicate::LessOrEqual(max),
+            },
+        ]
+    }
+}
+
+impl HeaderPredicate {
+    pub fn matches(&self, value: &HeaderValue) -> bool {
+        match self {
+            HeaderPredicate::Exists => true,
+            HeaderPredicate::Equals(expected) => value == expected,
+            HeaderPredicate::Greater(expected) => {
+                compare(value, expected) == Some(Ordering::Greater)
+            }
+            HeaderPredicate::GreaterOrEqual(expected) => matches!(
+                compare(value, expected),
+                Some(Ordering::Greater | Ordering::Equal)
+            ),
+            HeaderPredicate::Less(expected) => compare(value, expected) == Some(Ordering::Less),
+            HeaderPredicate::LessOrEqual(expected) => matches!(
+                compare(value, expected),
+                Some(Ordering::Less | Ordering::Equal)
+            ),
+        }
+    }
+
+    pub fn as_code(&self) -> u8 {
+        match self {
+            HeaderPredicate::Exists => 1,
+            HeaderPredicate::Equals(_) => 2,
+            HeaderPredicate::Greater(_) => 3,
+            HeaderPredicate::GreaterOrEqual(_) => 4,
+            HeaderPredicate::Less(_) => 5,
+            HeaderPredicate::LessOrEqual(_) => 6,
+        }
+    }
+
+    fn from_code(code: u8, value: Option<HeaderValue>) -> Result<Self, Error> {
+        match (code, value) {
+            (1, None) => Ok(HeaderPredicate::Exists),
+            (2, Some(value)) => Ok(HeaderPredicate::Equals(value)),
+            (3, Some(value)) => Ok(HeaderPredicate::Greater(value)),
+            (4, Some(value)) => Ok(HeaderPredicate::GreaterOrEqual(value)),
+            (5, Some(value)) => Ok(HeaderPredicate::Less(value)),
+            (6, Some(value)) => Ok(HeaderPredicate::LessOrEqual(value)),
+            _ => Err(Error::InvalidMessageFilter),
+        }
+    }
+
+    fn as_operator(&self) -> &'static str {
+        match self {
+            HeaderPredicate::Exists => "",
+            HeaderPredicate::Equals(_) => "=",
+            HeaderPredicate::Greater(_) => ">",
+            HeaderPredicate::GreaterOrEqual(_) => ">=",
+            HeaderPredicate::Less(_) => "<",
+            HeaderPredicate::LessOrEqual(_) => "<=",
+        }
+    }
+
+    fn value(&self) -> Option<&HeaderValue> {
+        match self {
+            HeaderPredicate::Exists => None,
+            HeaderPredicate::Equals(value)
+            | HeaderPredicate::Greater(value)
+            | HeaderPredicate::GreaterOrEqual(value)
+            | HeaderPredicate::Less(value)
+            | HeaderPredicate::LessOrEqual(value) => Some(value),
+        }
+    }
+}
+
+fn compare(value: &HeaderValue, other: &HeaderValue) -> Option<Ordering> {
+    if value.kind != other.kind {
+        return None;
+    }
+
+    match value.kind {
+        HeaderKind::Raw | HeaderKind::String => Some(value.value.cmp(&other.value)),
+        HeaderKind::Bool => Some(value.as_bool().ok()?.cmp(&other.as_bool().ok()?)),
+        HeaderKind::Int8 => Some(value.as_int8().ok()?.cmp(&other.as_int8().ok()?)),
+        HeaderKind::Int16 => Some(value.as_int16().ok()?.cmp(&other.as_int16().ok()?)),
+        HeaderKind::Int32 => Some(value.as_int32().ok()?.cmp(&other.as_int32().ok()?)),
+        HeaderKind::Int64 => Some(value.as_int64().ok()?.cmp(&other.as_int64().ok()?)),
+        HeaderKind::Int128 => Some(value.as_int128().ok()?.cmp(&other.as_int128().ok()?)),
+        HeaderKind::Uint8 => Some(value.as_uint8().ok()?.cmp(&other.as_uint8().ok()?)),
+        HeaderKind::Uint16 => Some(value.as_uint16().ok()?.cmp(&other.as_uint16().ok()?)),
+        HeaderKind::Uint32 => Some(value.as_uint32().ok()?.cmp(&other.as_uint32().ok()?)),
+        HeaderKind::Uint64 => Some(value.as_uint64().ok()?.cmp(&other.as_uint64().ok()?)),
+        HeaderKind::Uint128 => Some(value.as_uint128().ok()?.cmp(&other.as_uint128().ok()?)),
+        HeaderKind::Float32 => value
+            .as_float32()
+            .ok()?
+            .partial_cmp(&other.as_float32().ok()?),
+        HeaderKind::Float64 => value
+            .as_float64()
+            .ok()?
+            .partial_cmp(&other.as_float64().ok()?),
+    }
+}
+
+fn parse_value(input: &str) -> Result<HeaderValue, Error> {
+    let (kind, value) = input.split_once(':').ok_or(Error::InvalidMessageFilter)?;
+    let kind = HeaderKind::from_str(kind)?;
+    let value = match kind {
+        HeaderKind::Raw => HeaderValue::from_raw(
+            &general_purpose::STANDARD
+                .decode(value)
+                .map_err(|_| Error::InvalidMessageFilter)?,
+        ),
+        HeaderKind::String => HeaderValue::from_str(value),
+        HeaderKind::Bool => {
+            HeaderValue::from_bool(value.parse().map_err(|_| Error::InvalidMessageFilter)?)
+        }
+        HeaderKind::Int8 => HeaderValue::from_int8(value.parse()?),
+        HeaderKind::Int16 => HeaderValue::from_int16(value.parse()?),
+        HeaderKind::Int32 => HeaderValue::from_int32(value.parse()?),
+        HeaderKind::Int64 => HeaderValue::from_int64(value.parse()?),
+        HeaderKind::Int128 => HeaderValue::from_int128(value.parse()?),
+        HeaderKind::Uint8 => HeaderValue::from_uint8(value.parse()?),
+        HeaderKind::Uint16 => HeaderValue::from_uint16(value.parse()?),
+        HeaderKind::Uint32 => HeaderValue::from_uint32(value.parse()?),
+        HeaderKind::Uint64 => HeaderValue::from_uint64(value.parse()?),
+        HeaderKind::Uint128 => HeaderValue::from_uint128(value.parse()?),
+        HeaderKind::Float32 => {
+            HeaderValue::from_float32(value.parse().map_err(|_| Error::InvalidMessageFilter)?)
+        }
+        HeaderKind::Float64 => {
+            HeaderValue::from_float64(value.parse().map_err(|_| Error::InvalidMessageFilter)?)
+        }
+    }?;
+    Ok(value)
+}
+
+fn format_value(value: &HeaderValue) -> String {
+    match value.kind {
+        HeaderKind::Raw => format!(
+            "{}:{}",
+            value.kind,
+            general_purpose::STANDARD.encode(&value.value)
+        ),
+        // The value is prefixed with its kind, which has to be separated without the whitespace.
+        _ => value.to_string().replacen(": ", ":", 1),
+    }
+}
+
+impl FromStr for HeaderCondition {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some(position) = input.find(['=', '<', '>']) else {
+            return Ok(HeaderCondition::exists(HeaderKey::new(input)?));
+        };
+
+        let key = HeaderKey::new(&input[..position])?;
+        let expression = &input[position..];
+        let (predicate, value): (fn(HeaderValue) -> HeaderPredicate, &str) =
+            if let Some(value) = expression.strip_prefix(">=") {
+                (HeaderPredicate::GreaterOrEqual, value)
+            } else if let Some(value) = expression.strip_prefix("<=") {
+                (HeaderPredicate::LessOrEqual, value)
+            } else if let Some(value) = expression.strip_prefix('>') {
+                (HeaderPredicate::Greater, value)
+            } else if let Some(value) = expression.strip_prefix('<') {
+                (HeaderPredicate::Less, value)
+            } else if let Some(value) = expression.strip_prefix('=') {
+                (HeaderPredicate::Equals, value)
+            } else {
+                return Err(Error::InvalidMessageFilter);
+            };
+
+        Ok(HeaderCondition {
+            key,
+            predicate: predicate(parse_value(value)?),
+        })
+    }
+}
+
+impl Display for HeaderCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.key.as_str(), self.predicate.as_operator())?;
+        if let Some(value) = self.predicate.value() {
+            write!(f, "{}", format_value(value))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MessageFilter {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let conditions = input
+            .split(',')
+            .map(HeaderCondition::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(conditions)
+    }
+}
+
+impl Display for MessageFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let conditions = self
+            .conditions
+            .iter()
+            .map(|condition| condition.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "{}", conditions.join(","))
+    }
+}
+
+impl BytesSerializable for MessageFilter {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.conditions.len() as u8);
+        for condition in &self.conditions {
+            let key = condition.key.as_str();
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(key.len() as u8);
+            bytes.extend(key.as_bytes());
+            bytes.put_u8(condition.predicate.as_code());
+            if let Some(value) = condition.predicate.value() {
+                bytes.put_u8(value.kind.as_code());
+                #[allow(clippy::cast_possible_truncation)]
+                bytes.put_u8(value.value.len() as u8);
+                bytes.extend(&value.value);
+            }
+        }
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let conditions_count = *bytes.first().ok_or(Error::InvalidMessageFilter)? as usize;
+        let mut conditions = Vec::with_capacity(conditions_count);
+        let mut position = 1;
+        for _ in 0..conditions_count {
+            let key_length = read_u8(bytes, position)? as usize;
+            position += 1;
+            let key = std::str::from_utf8(read_bytes(bytes, position, key_length)?)
+                .map_err(|_| Error::InvalidHeaderKey)?;
+            let key = HeaderKey::new(key)?;
+            position += key_length;
+            let code = read_u8(bytes, position)?;
+            position += 1;
+            let value = if code == HeaderPredicate::Exists.as_code() {
+                None
+            } else {
+                let kind = HeaderKind::from_code(read_u8(bytes, position)?)?;
+                let value_length = read_u8(bytes, position + 1)? as usize;
+                position += 2;
+                let value = read_bytes(bytes, position, value_length)?.to_vec();
+                position += value_length;
+                Some(HeaderValue { kind, value })
+            };
+            conditions.push(HeaderCondition {
+                key,
+                predicate: HeaderPredicate::from_code(code, value)?,
+            });
+        }
+
+        if position != bytes.len() {
+            return Err(Error::InvalidMessageFilter);
+        }
+
+        Self::new(conditions)
+    }
+}
+
+fn read_u8(bytes: &[u8], position: usize) -> Result<u8, Error> {
+    bytes
+        .get(position)
+        .copied()
+        .ok_or(Error::InvalidMessageFilter)
+}
+
+fn read_bytes(bytes: &[u8], position: usize, length: usize) -> Result<&[u8], Error> {
+    bytes
+        .get(position..position + length)
+        .ok_or(Error::InvalidMessageFilter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let mut conditions = vec![
+            HeaderCondition::exists(HeaderKey::new("trace").unwrap()),
+            HeaderCondition::equals(
+                HeaderKey::new("type").unwrap(),
+                HeaderValue::from_str("order").unwrap(),
+            ),
+        ];
+        conditions.extend(HeaderCondition::range(
+            HeaderKey::new("priority").unwrap(),
+            HeaderValue::from_uint32(5).unwrap(),
+            HeaderValue::from_uint32(10).unwrap(),
+        ));
+        let filter = MessageFilter::new(conditions).unwrap();
+
+        let deserialized_filter = MessageFilter::from_bytes(&filter.as_bytes()).unwrap();
+
+        assert_eq!(deserialized_filter, filter);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let input = "priority>=uint32:5,priority<uint32:10,type=string:order,trace,raw=raw:AQI=";
+
+        let filter = MessageFilter::from_str(input).unwrap();
+
+        assert_eq!(filter.conditions.len(), 5);
+        assert_eq!(
+            filter.conditions[0].predicate,
+            HeaderPredicate::GreaterOrEqual(HeaderValue::from_uint32(5).unwrap())
+        );
+        assert_eq!(
+            filter.conditions[1].predicate,
+            HeaderPredicate::Less(HeaderValue::from_uint32(10).unwrap())
+        );
+        assert_eq!(
+            filter.conditions[2].predicate,
+            HeaderPredicate::Equals(HeaderValue::from_str("order").unwrap())
+        );
+        assert_eq!(filter.conditions[3].predicate, HeaderPredicate::Exists);
+        assert_eq!(
+            filter.conditions[4].predicate,
+            HeaderPredicate::Equals(HeaderValue::from_raw(&[1, 2]).unwrap())
+        );
+        assert_eq!(filter.to_string(), input);
+    }
+
+    #[test]
+    fn should_not_be_read_from_invalid_string() {
+        assert!(MessageFilter::from_str("").is_err());
+        assert!(MessageFilter::from_str("priority>=5").is_err());
+        assert!(MessageFilter::from_str("priority=>uint32:5").is_err());
+        assert!(MessageFilter::from_str("priority>=uint8:256").is_err());
+    }
+
+    #[test]
+    fn should_match_headers_meeting_all_conditions() {
+        let filter =
+            MessageFilter::from_str("priority>=uint32:5,priority<=uint32:10,trace").unwrap();
+        let headers = |priority: HeaderValue, trace: bool| {
+            let mut headers = HashMap::new();
+            headers.insert(HeaderKey::new("priority").unwrap(), priority);
+            if trace {
+                headers.insert(
+                    HeaderKey::new("trace").unwrap(),
+                    HeaderValue::from_bool(true).unwrap(),
+                );
+            }
+            Some(headers)
+        };
+
+        assert!(filter.matches(&headers(HeaderValue::from_uint32(5).unwrap(), true)));
+        assert!(filter.matches(&headers(HeaderValue::from_uint32(10).unwrap(), true)));
+        assert!(!filter.matches(&headers(HeaderValue::from_uint32(11).unwrap(), true)));
+        assert!(!filter.matches(&headers(HeaderValue::from_uint32(5).unwrap(), false)));
+        assert!(!filter.matches(&headers(HeaderValue::from_uint64(5).unwrap(), true)));
+        assert!(!filter.matches(&None));
+    }
+}
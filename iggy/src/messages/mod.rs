@@ -1,2 +1,4 @@
+pub mod message_filter;
+pub mod poison_message;
 pub mod poll_messages;
 pub mod send_messages;
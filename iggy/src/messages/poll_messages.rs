@@ -3,6 +3,7 @@ use crate::command::CommandPayload;
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::messages::message_filter::MessageFilter;
 use crate::validatable::Validatable;
 use bytes::BufMut;
 use serde::{Deserialize, Serialize};
@@ -10,6 +11,7 @@ use serde_with::{serde_as, DisplayFromStr};
 use std::fmt::Display;
 use std::str::FromStr;
 
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     #[serde(flatten)]
@@ -26,6 +28,13 @@ pub struct PollMessages {
     pub count: u32,
     #[serde(default)]
     pub auto_commit: bool,
+    #[serde(default)]
+    pub max_wait: u32,
+    /// Only the messages matching the filter are returned. If fewer messages than `count` were returned,
+    /// the remaining ones (up to the partition's current offset) have been filtered out.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<MessageFilter>,
 }
 
 #[serde_as]
@@ -60,6 +69,8 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            max_wait: 0,
+            filter: None,
         }
     }
 }
@@ -219,6 +230,14 @@ impl FromStr for PollMessages {
             },
             None => false,
         };
+        let max_wait = match parts.get(9) {
+            Some(max_wait) => max_wait.parse::<u32>()?,
+            None => 0,
+        };
+        let filter = match parts.get(10) {
+            Some(filter) => Some(filter.parse::<MessageFilter>()?),
+            None => None,
+        };
 
         let command = PollMessages {
             consumer,
@@ -228,6 +247,8 @@ impl FromStr for PollMessages {
             strategy,
             count,
             auto_commit,
+            max_wait,
+            filter,
         };
         command.validate()?;
         Ok(command)
@@ -241,7 +262,7 @@ impl BytesSerializable for PollMessages {
         let topic_id_bytes = self.topic_id.as_bytes();
         let strategy_bytes = self.strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            13 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -261,6 +282,10 @@ impl BytesSerializable for PollMessages {
         } else {
             bytes.put_u8(0);
         }
+        bytes.put_u32_le(self.max_wait);
+        if let Some(filter) = &self.filter {
+            bytes.extend(filter.as_bytes());
+        }
 
         bytes
     }
@@ -297,6 +322,16 @@ impl BytesSerializable for PollMessages {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?);
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        position += 13;
+        let max_wait = match bytes.len() >= position + 4 {
+            true => u32::from_le_bytes(bytes[position..position + 4].try_into()?),
+            false => 0,
+        };
+        position += 4;
+        let filter = match bytes.len() > position {
+            true => Some(MessageFilter::from_bytes(&bytes[position..])?),
+            false => None,
+        };
         let command = PollMessages {
             consumer,
             stream_id,
@@ -305,6 +340,8 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            max_wait,
+            filter,
         };
         command.validate()?;
         Ok(command)
@@ -315,15 +352,20 @@ impl Display for PollMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
-            auto_commit_to_string(self.auto_commit)
-        )
+            auto_commit_to_string(self.auto_commit),
+            self.max_wait
+        )?;
+        if let Some(filter) = &self.filter {
+            write!(f, "|{filter}")?;
+        }
+        Ok(())
     }
 }
 
@@ -375,6 +417,8 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            max_wait: 1000,
+            filter: None,
         };
 
         let bytes = command.as_bytes();
@@ -401,6 +445,8 @@ mod tests {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        position += 13;
+        let max_wait = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -410,6 +456,7 @@ mod tests {
         assert_eq!(strategy, command.strategy);
         assert_eq!(count, command.count);
         assert_eq!(auto_commit, command.auto_commit);
+        assert_eq!(max_wait, command.max_wait);
     }
 
     #[test]
@@ -421,6 +468,7 @@ mod tests {
         let strategy = PollingStrategy::offset(2);
         let count = 3u32;
         let auto_commit = 1u8;
+        let max_wait = 1000u32;
 
         let consumer_bytes = consumer.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
@@ -439,6 +487,7 @@ mod tests {
         bytes.extend(strategy_bytes);
         bytes.put_u32_le(count);
         bytes.put_u8(auto_commit);
+        bytes.put_u32_le(max_wait);
 
         let command = PollMessages::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -453,6 +502,7 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.max_wait, max_wait);
     }
 
     #[test]
@@ -465,9 +515,10 @@ mod tests {
         let count = 3u32;
         let auto_commit = 1u8;
         let auto_commit_str = "auto_commit";
+        let max_wait = 1000u32;
 
         let input = format!(
-            "{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{count}|{auto_commit_str}",
+            "{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{count}|{auto_commit_str}|{max_wait}",
         );
         let command = PollMessages::from_str(&input);
         assert!(command.is_ok());
@@ -482,5 +533,6 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert_eq!(command.max_wait, max_wait);
     }
 }
@@ -17,6 +17,12 @@ const MAX_HEADERS_SIZE: u32 = 100 * 1024;
 const MAX_PAYLOAD_SIZE: u32 = 10 * 1024 * 1024;
 
 const EMPTY_KEY_VALUE: Vec<u8> = vec![];
+// Set on the partitioning kind code, when the producer is sent right after the partitioning.
+const PRODUCER_FLAG: u8 = 0b1000_0000;
+// Set on the partitioning kind code, when the transaction ID is sent after the partitioning (and the producer).
+const TRANSACTION_FLAG: u8 = 0b0100_0000;
+// Set on the partitioning kind code, when the acks code is sent after the partitioning (and the producer and transaction ID).
+const ACKS_FLAG: u8 = 0b0010_0000;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct SendMessages {
@@ -25,9 +31,41 @@ pub struct SendMessages {
     #[serde(skip)]
     pub topic_id: Identifier,
     pub partitioning: Partitioning,
+    #[serde(default)]
+    pub producer: Option<Producer>,
+    #[serde(default)]
+    pub transaction_id: Option<u64>,
+    #[serde(default)]
+    pub acks: Acks,
     pub messages: Vec<Message>,
 }
 
+/// The acknowledgement, which the server has to receive before responding to the sent messages.
+/// It matters only in the cluster mode, where the partitions are replicated to the multiple nodes.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Acks {
+    /// The server responds as soon as it receives the messages, before they are validated and appended,
+    /// so the client is not notified about the messages which failed to be appended.
+    None,
+    /// The server responds once the messages have been appended by the leader of the partition,
+    /// without waiting for the replication of the partition.
+    #[default]
+    Leader,
+    /// The server responds once the messages have been replicated by all the in-sync replicas of the partition.
+    All,
+}
+
+/// The idempotent producer, for which the server keeps the last appended sequence number per partition.
+/// The messages which were already appended (e.g. sent again after the network error) are skipped.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Copy, Clone)]
+pub struct Producer {
+    /// Unique ID of the producer chosen by the client.
+    pub id: u64,
+    /// Sequence number of the first message in the batch, the next messages get the consecutive numbers.
+    pub sequence: u64,
+}
+
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct Partitioning {
@@ -69,6 +107,9 @@ impl Default for SendMessages {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
             messages: vec![Message::default()],
         }
     }
@@ -167,6 +208,11 @@ impl Validatable<Error> for SendMessages {
             return Err(Error::InvalidKeyValueLength);
         }
 
+        // Sequence numbers are tracked per partition, so the producer has to know where the messages go.
+        if self.producer.is_some() && self.partitioning.kind != PartitioningKind::PartitionId {
+            return Err(Error::ProducerRequiresPartitionId);
+        }
+
         let mut headers_size = 0;
         let mut payload_size = 0;
         for message in &self.messages {
@@ -184,7 +230,14 @@ impl Validatable<Error> for SendMessages {
             }
         }
 
-        if payload_size == 0 {
+        // The empty payload is allowed only for the keyed messages, as it's a tombstone for the topic compaction.
+        if payload_size == 0
+            && self.partitioning.kind != PartitioningKind::MessagesKey
+            && self
+                .messages
+                .iter()
+                .any(|message| message.get_messages_key().is_none())
+        {
             return Err(Error::EmptyMessagePayload);
         }
 
@@ -211,6 +264,47 @@ impl PartitioningKind {
     }
 }
 
+impl Acks {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            Acks::None => 0,
+            Acks::Leader => 1,
+            Acks::All => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            0 => Ok(Acks::None),
+            1 => Ok(Acks::Leader),
+            2 => Ok(Acks::All),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for Acks {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(Acks::None),
+            "leader" => Ok(Acks::Leader),
+            "all" => Ok(Acks::All),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for Acks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Acks::None => write!(f, "none"),
+            Acks::Leader => write!(f, "leader"),
+            Acks::All => write!(f, "all"),
+        }
+    }
+}
+
 impl FromStr for PartitioningKind {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
@@ -238,6 +332,21 @@ impl Message {
         }
     }
 
+    /// Delays the delivery of the message to the consumers polling the next messages
+    /// until the given timestamp (microseconds since the Unix epoch).
+    pub fn with_deliver_at(mut self, deliver_at: u64) -> Result<Self, Error> {
+        header::set_deliver_at(self.headers.get_or_insert_with(HashMap::new), deliver_at)?;
+        Ok(self)
+    }
+
+    pub fn get_messages_key(&self) -> Option<&[u8]> {
+        header::get_messages_key(&self.headers)
+    }
+
+    pub fn get_deliver_at(&self) -> Option<u64> {
+        header::get_deliver_at(&self.headers)
+    }
+
     pub fn get_size_bytes(&self) -> u32 {
         // ID + Length + Payload + Headers
         16 + 4 + self.payload.len() as u32 + header::get_headers_size_bytes(&self.headers)
@@ -328,9 +437,6 @@ impl BytesSerializable for Message {
         let payload_length = u32::from_le_bytes(
             bytes[20 + headers_length as usize..24 + headers_length as usize].try_into()?,
         );
-        if payload_length == 0 {
-            return Err(Error::EmptyMessagePayload);
-        }
 
         let payload = Bytes::from(
             bytes[24 + headers_length as usize
@@ -417,6 +523,9 @@ impl FromStr for SendMessages {
                 length: key_length,
                 value: key_value,
             },
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
             messages: vec![message],
         };
         command.validate()?;
@@ -432,15 +541,39 @@ impl BytesSerializable for SendMessages {
             .map(Message::get_size_bytes)
             .sum::<u32>();
 
-        let key_bytes = self.partitioning.as_bytes();
+        let mut key_bytes = self.partitioning.as_bytes();
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + messages_size as usize,
+            stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + key_bytes.len()
+                + 25
+                + messages_size as usize,
         );
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
+        if self.producer.is_some() {
+            key_bytes[0] |= PRODUCER_FLAG;
+        }
+        if self.transaction_id.is_some() {
+            key_bytes[0] |= TRANSACTION_FLAG;
+        }
+        let has_acks = self.acks != Acks::default();
+        if has_acks {
+            key_bytes[0] |= ACKS_FLAG;
+        }
         bytes.extend(key_bytes);
+        if let Some(producer) = &self.producer {
+            bytes.put_u64_le(producer.id);
+            bytes.put_u64_le(producer.sequence);
+        }
+        if let Some(transaction_id) = self.transaction_id {
+            bytes.put_u64_le(transaction_id);
+        }
+        if has_acks {
+            bytes.put_u8(self.acks.as_code());
+        }
         for message in &self.messages {
             bytes.extend(message.as_bytes());
         }
@@ -458,8 +591,52 @@ impl BytesSerializable for SendMessages {
         position += stream_id.get_size_bytes() as usize;
         let topic_id = Identifier::from_bytes(&bytes[position..])?;
         position += topic_id.get_size_bytes() as usize;
-        let key = Partitioning::from_bytes(&bytes[position..])?;
+        // The producer, transaction and acks are optional to keep the compatibility with the older clients.
+        let has_producer = bytes[position] & PRODUCER_FLAG != 0;
+        let has_transaction = bytes[position] & TRANSACTION_FLAG != 0;
+        let has_acks = bytes[position] & ACKS_FLAG != 0;
+        let key = if has_producer || has_transaction || has_acks {
+            let key_length = 2 + bytes[position + 1] as usize;
+            let mut key_bytes = bytes
+                .get(position..position + key_length)
+                .ok_or(Error::InvalidCommand)?
+                .to_vec();
+            key_bytes[0] &= !(PRODUCER_FLAG | TRANSACTION_FLAG | ACKS_FLAG);
+            Partitioning::from_bytes(&key_bytes)?
+        } else {
+            Partitioning::from_bytes(&bytes[position..])?
+        };
         position += key.get_size_bytes() as usize;
+        let producer = if has_producer {
+            if bytes.len() < position + 16 {
+                return Err(Error::InvalidCommand);
+            }
+
+            let id = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+            let sequence = u64::from_le_bytes(bytes[position + 8..position + 16].try_into()?);
+            position += 16;
+            Some(Producer { id, sequence })
+        } else {
+            None
+        };
+        let transaction_id = if has_transaction {
+            if bytes.len() < position + 8 {
+                return Err(Error::InvalidCommand);
+            }
+
+            let transaction_id = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+            position += 8;
+            Some(transaction_id)
+        } else {
+            None
+        };
+        let acks = if has_acks {
+            let code = *bytes.get(position).ok_or(Error::InvalidCommand)?;
+            position += 1;
+            Acks::from_code(code)?
+        } else {
+            Acks::default()
+        };
         let messages_payloads = &bytes[position..];
         position = 0;
         let mut messages = Vec::new();
@@ -473,6 +650,9 @@ impl BytesSerializable for SendMessages {
             stream_id,
             topic_id,
             partitioning: key,
+            producer,
+            transaction_id,
+            acks,
             messages,
         };
         command.validate()?;
@@ -538,6 +718,9 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
             messages,
         };
 
@@ -607,6 +790,8 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitioning, key);
+        assert_eq!(command.producer, None);
+        assert_eq!(command.transaction_id, None);
         for (index, message) in command.messages.iter().enumerate() {
             let command_message = &command.messages[index];
             assert_eq!(command_message.id, message.id);
@@ -689,4 +874,141 @@ mod tests {
         let key = Partitioning::messages_key_str(&messages_key);
         assert!(key.is_err());
     }
+
+    #[test]
+    fn empty_payload_should_be_valid_only_for_keyed_messages() {
+        let tombstone = Message::new(Some(1), Bytes::new(), None);
+        let command = SendMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitioning: Partitioning::partition_id(1),
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
+            messages: vec![tombstone],
+        };
+        let error = command.validate().unwrap_err();
+        assert_eq!(error.as_code(), Error::EmptyMessagePayload.as_code());
+
+        let tombstone = Message::new(Some(1), Bytes::new(), None);
+        let command = SendMessages {
+            partitioning: Partitioning::messages_key_str("key-1").unwrap(),
+            messages: vec![tombstone],
+            ..command
+        };
+        assert!(command.validate().is_ok());
+    }
+
+    #[test]
+    fn producer_should_be_serialized_and_deserialized_from_bytes() {
+        let command = SendMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitioning: Partitioning::partition_id(4),
+            producer: Some(Producer {
+                id: 5,
+                sequence: 10,
+            }),
+            transaction_id: None,
+            acks: Acks::default(),
+            messages: vec![
+                Message::from_str("hello 1").unwrap(),
+                Message::from_str("hello 2").unwrap(),
+            ],
+        };
+
+        let bytes = command.as_bytes();
+        let position =
+            (command.stream_id.get_size_bytes() + command.topic_id.get_size_bytes()) as usize;
+        assert_eq!(
+            bytes[position],
+            PartitioningKind::PartitionId.as_code() | PRODUCER_FLAG
+        );
+
+        let deserialized_command = SendMessages::from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized_command, command);
+    }
+
+    #[test]
+    fn transaction_should_be_serialized_and_deserialized_from_bytes() {
+        for producer in [
+            None,
+            Some(Producer {
+                id: 5,
+                sequence: 10,
+            }),
+        ] {
+            let command = SendMessages {
+                stream_id: Identifier::numeric(1).unwrap(),
+                topic_id: Identifier::numeric(2).unwrap(),
+                partitioning: Partitioning::partition_id(4),
+                producer,
+                transaction_id: Some(7),
+                acks: Acks::default(),
+                messages: vec![Message::from_str("hello").unwrap()],
+            };
+
+            let bytes = command.as_bytes();
+            let deserialized_command = SendMessages::from_bytes(&bytes).unwrap();
+            assert_eq!(deserialized_command, command);
+        }
+    }
+
+    #[test]
+    fn acks_should_be_serialized_and_deserialized_from_bytes() {
+        for acks in [Acks::None, Acks::Leader, Acks::All] {
+            let command = SendMessages {
+                stream_id: Identifier::numeric(1).unwrap(),
+                topic_id: Identifier::numeric(2).unwrap(),
+                partitioning: Partitioning::partition_id(4),
+                producer: Some(Producer {
+                    id: 5,
+                    sequence: 10,
+                }),
+                transaction_id: None,
+                acks,
+                messages: vec![Message::from_str("hello").unwrap()],
+            };
+
+            let bytes = command.as_bytes();
+            let position =
+                (command.stream_id.get_size_bytes() + command.topic_id.get_size_bytes()) as usize;
+            assert_eq!(bytes[position] & ACKS_FLAG != 0, acks != Acks::Leader);
+            let deserialized_command = SendMessages::from_bytes(&bytes).unwrap();
+            assert_eq!(deserialized_command, command);
+        }
+    }
+
+    #[test]
+    fn producer_should_require_partition_id() {
+        let mut command = SendMessages {
+            producer: Some(Producer { id: 1, sequence: 0 }),
+            ..SendMessages::default()
+        };
+        assert!(matches!(
+            command.validate(),
+            Err(Error::ProducerRequiresPartitionId)
+        ));
+
+        command.partitioning = Partitioning::messages_key_u32(1);
+        assert!(matches!(
+            command.validate(),
+            Err(Error::ProducerRequiresPartitionId)
+        ));
+
+        command.partitioning = Partitioning::partition_id(1);
+        assert!(command.validate().is_ok());
+    }
+
+    #[test]
+    fn message_with_deliver_at_should_keep_it_in_headers() {
+        let message = Message::from_str("hello").unwrap();
+        assert_eq!(message.get_deliver_at(), None);
+
+        let message = message.with_deliver_at(1_700_000_000_000_000).unwrap();
+        assert_eq!(message.get_deliver_at(), Some(1_700_000_000_000_000));
+
+        let message = Message::from_bytes(&message.as_bytes()).unwrap();
+        assert_eq!(message.get_deliver_at(), Some(1_700_000_000_000_000));
+    }
 }
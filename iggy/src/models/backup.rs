@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// The archive with the consistent snapshot of the server state, created by the `CreateBackup` command.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Backup {
+    /// The file name of the archive.
+    pub name: String,
+    /// The path of the archive on the server, which can be passed to the server on startup to restore the state.
+    pub path: String,
+    pub size_bytes: u64,
+    /// The timestamp (in microseconds) at which the snapshot was taken.
+    pub created_at: u64,
+}
@@ -17,6 +17,16 @@ pub struct ClientInfoDetails {
     pub transport: String,
     pub consumer_groups_count: u32,
     pub consumer_groups: Vec<ConsumerGroupInfo>,
+    /// The quota usage of the client within the current one second window.
+    #[serde(default)]
+    pub quota_usage: QuotaUsageInfo,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct QuotaUsageInfo {
+    pub requests: u32,
+    pub bytes: u64,
+    pub throttled_requests: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -0,0 +1,101 @@
+use crate::error::Error;
+use crate::models::messages::PolledMessages;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The state of the cluster as seen by the node, which returned it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ClusterMetadata {
+    /// ID of the node, which returned the metadata.
+    pub node_id: u32,
+    /// ID of the node, which assigns the partition leaders and accepts the changes of the streams, topics and partitions.
+    /// It's 0, if there is no controller, as the majority of the nodes is offline.
+    pub controller_id: u32,
+    pub nodes: Vec<ClusterNode>,
+    pub partitions: Vec<PartitionReplicas>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ClusterNode {
+    pub id: u32,
+    /// The address of the TCP server of the node.
+    pub address: String,
+    pub status: ClusterNodeStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClusterNodeStatus {
+    Online,
+    Offline,
+}
+
+/// Each partition is replicated to the number of the nodes equal to the replication factor,
+/// one of them is the leader accepting the messages.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PartitionReplicas {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub leader_id: u32,
+    /// Incremented each time the new leader is elected.
+    pub leader_epoch: u32,
+    /// The nodes (including the leader) assigned to store the partition.
+    pub replicas: Vec<u32>,
+    /// The nodes (including the leader), which have replicated all the messages of the partition.
+    pub in_sync_replicas: Vec<u32>,
+}
+
+/// The messages fetched by the follower from the leader of the partition.
+#[derive(Debug)]
+pub struct ReplicatedMessages {
+    /// The next offset of the partition at the time the leader was elected. The messages from this offset,
+    /// which the follower has got from the previous leaders, are not present on the current leader and must be truncated.
+    pub epoch_start_offset: u64,
+    pub messages: PolledMessages,
+}
+
+impl ClusterNodeStatus {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ClusterNodeStatus::Online => 1,
+            ClusterNodeStatus::Offline => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(ClusterNodeStatus::Online),
+            2 => Ok(ClusterNodeStatus::Offline),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for ClusterNodeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClusterNodeStatus::Online => write!(f, "online"),
+            ClusterNodeStatus::Offline => write!(f, "offline"),
+        }
+    }
+}
+
+impl ClusterMetadata {
+    pub fn get_partition(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Option<&PartitionReplicas> {
+        self.partitions.iter().find(|partition| {
+            partition.stream_id == stream_id
+                && partition.topic_id == topic_id
+                && partition.partition_id == partition_id
+        })
+    }
+
+    pub fn get_node(&self, node_id: u32) -> Option<&ClusterNode> {
+        self.nodes.iter().find(|node| node.id == node_id)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +15,14 @@ pub struct ConsumerGroupDetails {
     pub name: String,
     pub partitions_count: u32,
     pub members_count: u32,
+    #[serde(default)]
+    pub partition_assignment_strategy: PartitionAssignmentStrategy,
+    #[serde(default)]
+    pub session_timeout: Option<u32>,
+    #[serde(default)]
+    pub generation: u32,
+    #[serde(default)]
+    pub dead_letter_topic: Option<DeadLetterTopic>,
     pub members: Vec<ConsumerGroupMember>,
 }
 
@@ -23,3 +32,10 @@ pub struct ConsumerGroupMember {
     pub partitions_count: u32,
     pub partitions: Vec<u32>,
 }
+
+/// The topic, to which the messages marked as poisoned by the consumer group are copied.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Copy, Clone)]
+pub struct DeadLetterTopic {
+    pub stream_id: u32,
+    pub topic_id: u32,
+}
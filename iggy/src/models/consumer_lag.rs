@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// `ConsumerLag` represents the lag of the consumer or the consumer group across all the partitions of the topic.
+/// It consists of the following fields:
+/// - `total_lag`: the sum of the lags of all the partitions.
+/// - `partitions`: the collection of the lags per partition.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsumerLag {
+    pub total_lag: u64,
+    pub partitions: Vec<PartitionLag>,
+}
+
+/// `PartitionLag` represents the lag of the consumer or the consumer group in the partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `current_offset`: the current offset of the partition.
+/// - `stored_offset`: the offset stored by the consumer, if any.
+/// - `lag`: the number of messages that have not been consumed yet.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartitionLag {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub stored_offset: Option<u64>,
+    pub lag: u64,
+}
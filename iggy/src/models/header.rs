@@ -11,6 +11,16 @@ use std::str::FromStr;
 
 const EMPTY_BYTES: Vec<u8> = vec![];
 
+/// The reserved header containing the messages key, which is used by the topics with enabled compaction.
+pub const MESSAGES_KEY_HEADER: &str = "iggy-messages-key";
+
+/// The reserved header containing the ID of the topic schema (`uint32`), which the message payload conforms to.
+pub const SCHEMA_ID_HEADER: &str = "iggy-schema-id";
+
+/// The reserved header containing the timestamp (`uint64`, microseconds since the Unix epoch),
+/// before which the message isn't delivered to the consumers polling the next messages.
+pub const DELIVER_AT_HEADER: &str = "iggy-deliver-at";
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct HeaderKey(String);
 
@@ -26,6 +36,18 @@ impl HeaderKey {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    pub fn messages_key() -> Self {
+        Self(MESSAGES_KEY_HEADER.to_string())
+    }
+
+    pub fn schema_id() -> Self {
+        Self(SCHEMA_ID_HEADER.to_string())
+    }
+
+    pub fn deliver_at() -> Self {
+        Self(DELIVER_AT_HEADER.to_string())
+    }
 }
 
 impl Hash for HeaderKey {
@@ -562,6 +584,50 @@ pub fn get_headers_size_bytes(headers: &Option<HashMap<HeaderKey, HeaderValue>>)
     size
 }
 
+pub fn get_messages_key(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> Option<&[u8]> {
+    headers
+        .as_ref()?
+        .get(&HeaderKey::messages_key())
+        .map(|value| value.value.as_slice())
+}
+
+/// Returns the schema ID stored in the reserved header, or `None` if the header is missing or isn't a valid `uint32`.
+pub fn get_schema_id(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> Option<u32> {
+    headers
+        .as_ref()?
+        .get(&HeaderKey::schema_id())
+        .and_then(|value| value.as_uint32().ok())
+}
+
+/// Stores the schema ID in the reserved header, so the message can be validated by the server against the topic schema.
+pub fn set_schema_id(
+    headers: &mut HashMap<HeaderKey, HeaderValue>,
+    schema_id: u32,
+) -> Result<(), Error> {
+    headers.insert(HeaderKey::schema_id(), HeaderValue::from_uint32(schema_id)?);
+    Ok(())
+}
+
+/// Returns the delivery timestamp stored in the reserved header, or `None` if the header is missing or isn't a valid `uint64`.
+pub fn get_deliver_at(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> Option<u64> {
+    headers
+        .as_ref()?
+        .get(&HeaderKey::deliver_at())
+        .and_then(|value| value.as_uint64().ok())
+}
+
+/// Stores the delivery timestamp in the reserved header, so the message is held back by the server until it's due.
+pub fn set_deliver_at(
+    headers: &mut HashMap<HeaderKey, HeaderValue>,
+    deliver_at: u64,
+) -> Result<(), Error> {
+    headers.insert(
+        HeaderKey::deliver_at(),
+        HeaderValue::from_uint64(deliver_at)?,
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -854,4 +920,49 @@ mod tests {
             assert_eq!(deserialized_value.value, value.value);
         }
     }
+
+    #[test]
+    fn messages_key_should_be_read_from_headers() {
+        let mut headers = HashMap::new();
+        assert_eq!(get_messages_key(&None), None);
+        assert_eq!(get_messages_key(&Some(headers.clone())), None);
+
+        headers.insert(
+            HeaderKey::new(MESSAGES_KEY_HEADER).unwrap(),
+            HeaderValue::from_raw(b"key-1").unwrap(),
+        );
+        assert_eq!(get_messages_key(&Some(headers)), Some(b"key-1".as_slice()));
+    }
+
+    #[test]
+    fn schema_id_should_be_stored_in_and_read_from_headers() {
+        let mut headers = HashMap::new();
+        assert_eq!(get_schema_id(&None), None);
+        assert_eq!(get_schema_id(&Some(headers.clone())), None);
+
+        set_schema_id(&mut headers, 3).unwrap();
+        assert_eq!(get_schema_id(&Some(headers.clone())), Some(3));
+
+        headers.insert(HeaderKey::schema_id(), HeaderValue::from_raw(b"3").unwrap());
+        assert_eq!(get_schema_id(&Some(headers)), None);
+    }
+
+    #[test]
+    fn deliver_at_should_be_stored_in_and_read_from_headers() {
+        let mut headers = HashMap::new();
+        assert_eq!(get_deliver_at(&None), None);
+        assert_eq!(get_deliver_at(&Some(headers.clone())), None);
+
+        set_deliver_at(&mut headers, 1_700_000_000_000_000).unwrap();
+        assert_eq!(
+            get_deliver_at(&Some(headers.clone())),
+            Some(1_700_000_000_000_000)
+        );
+
+        headers.insert(
+            HeaderKey::deliver_at(),
+            HeaderValue::from_uint32(1).unwrap(),
+        );
+        assert_eq!(get_deliver_at(&Some(headers)), None);
+    }
 }
@@ -18,11 +18,14 @@ use std::sync::Arc;
 pub struct PolledMessages {
     pub partition_id: u32,
     pub current_offset: u64,
+    /// The generation of the consumer group, which assigned the partition, or 0 if the messages were polled by the regular consumer.
+    #[serde(default)]
+    pub generation: u32,
     pub messages: Vec<Message>,
 }
 
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Message {
     pub offset: u64,
     pub state: MessageState,
@@ -145,6 +148,22 @@ impl Message {
         }
     }
 
+    pub fn get_messages_key(&self) -> Option<&[u8]> {
+        header::get_messages_key(&self.headers)
+    }
+
+    pub fn get_deliver_at(&self) -> Option<u64> {
+        header::get_deliver_at(&self.headers)
+    }
+
+    /// Checks whether the message can be delivered to the consumers polling the next messages at the given time.
+    pub fn is_due(&self, now: u64) -> bool {
+        match self.get_deliver_at() {
+            Some(deliver_at) => deliver_at <= now,
+            None => true,
+        }
+    }
+
     pub fn get_size_bytes(&self) -> u32 {
         // Offset + State + Timestamp + ID + Checksum + Length + Payload + Headers
         8 + 1 + 8 + 16 + 4 + 4 + self.length + header::get_headers_size_bytes(&self.headers)
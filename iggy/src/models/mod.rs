@@ -1,5 +1,8 @@
+pub mod backup;
 pub mod client_info;
+pub mod cluster;
 pub mod consumer_group;
+pub mod consumer_lag;
 pub mod consumer_offset_info;
 pub mod header;
 pub mod identity_info;
@@ -7,8 +10,11 @@ pub mod messages;
 pub mod partition;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod schema;
 pub mod stats;
 pub mod stream;
 pub mod topic;
+pub mod transaction;
 pub mod user_info;
+pub mod user_quota;
 pub mod user_status;
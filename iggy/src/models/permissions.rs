@@ -11,7 +11,7 @@ pub struct Permissions {
     pub streams: Option<HashMap<u32, StreamPermissions>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
 pub struct GlobalPermissions {
     pub manage_servers: bool,
     pub read_servers: bool,
@@ -25,7 +25,7 @@ pub struct GlobalPermissions {
     pub send_messages: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
 pub struct StreamPermissions {
     pub manage_stream: bool,
     pub read_stream: bool,
@@ -36,7 +36,7 @@ pub struct StreamPermissions {
     pub topics: Option<HashMap<u32, TopicPermissions>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
 pub struct TopicPermissions {
     pub manage_topic: bool,
     pub read_topic: bool,
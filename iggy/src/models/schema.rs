@@ -0,0 +1,20 @@
+use crate::schemas::schema_compatibility::SchemaCompatibility;
+use crate::schemas::schema_format::SchemaFormat;
+use serde::{Deserialize, Serialize};
+
+/// The version of the schema registered for the topic, the IDs are assigned sequentially and never reused.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Schema {
+    pub id: u32,
+    pub format: SchemaFormat,
+    pub definition: String,
+    pub created_at: u64,
+}
+
+/// The schemas registered for the topic along with the settings of the registry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TopicSchemas {
+    pub compatibility: SchemaCompatibility,
+    pub validation_enabled: bool,
+    pub schemas: Vec<Schema>,
+}
@@ -19,6 +19,8 @@ pub struct Stats {
     pub messages_count: u64,
     pub clients_count: u32,
     pub consumer_groups_count: u32,
+    #[serde(default)]
+    pub throttled_requests_count: u64,
     pub hostname: String,
     pub os_name: String,
     pub os_version: String,
@@ -1,3 +1,4 @@
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::models::partition::Partition;
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +9,11 @@ pub struct Topic {
     pub name: String,
     pub size_bytes: u64,
     pub message_expiry: Option<u32>,
+    pub compression_algorithm: CompressionAlgorithm,
+    pub compression_ratio: f64,
+    pub compaction_enabled: bool,
+    pub max_topic_size: Option<u64>,
+    pub max_partition_size: Option<u64>,
     pub messages_count: u64,
     pub partitions_count: u32,
 }
@@ -19,6 +25,11 @@ pub struct TopicDetails {
     pub name: String,
     pub size_bytes: u64,
     pub message_expiry: Option<u32>,
+    pub compression_algorithm: CompressionAlgorithm,
+    pub compression_ratio: f64,
+    pub compaction_enabled: bool,
+    pub max_topic_size: Option<u64>,
+    pub max_partition_size: Option<u64>,
     pub messages_count: u64,
     pub partitions_count: u32,
     pub partitions: Vec<Partition>,
@@ -0,0 +1,57 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The byte-rate and request-rate limits of the user, overriding the default ones from the server config.
+/// The limit set to 0 means that it's unlimited.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct UserQuota {
+    pub bytes_per_second: u64,
+    pub requests_per_second: u32,
+}
+
+impl UserQuota {
+    pub fn new(bytes_per_second: u64, requests_per_second: u32) -> Self {
+        UserQuota {
+            bytes_per_second,
+            requests_per_second,
+        }
+    }
+}
+
+impl FromStr for UserQuota {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (bytes_per_second, requests_per_second) =
+            input.split_once(':').ok_or(Error::InvalidCommand)?;
+        Ok(UserQuota {
+            bytes_per_second: bytes_per_second
+                .parse()
+                .map_err(|_| Error::InvalidCommand)?,
+            requests_per_second: requests_per_second
+                .parse()
+                .map_err(|_| Error::InvalidCommand)?,
+        })
+    }
+}
+
+impl Display for UserQuota {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.bytes_per_second, self.requests_per_second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_read_from_string() {
+        let quota = UserQuota::from_str("1000:10").unwrap();
+        assert_eq!(quota, UserQuota::new(1000, 10));
+        assert_eq!(quota.to_string(), "1000:10");
+        assert!(UserQuota::from_str("1000").is_err());
+        assert!(UserQuota::from_str("1000:a").is_err());
+    }
+}
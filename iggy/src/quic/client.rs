@@ -1,4 +1,5 @@
 use crate::binary::binary_client::{BinaryClient, ClientState};
+use crate::binary::protocol::{self, CORRELATION_ID_PROTOCOL_VERSION};
 use crate::client::Client;
 use crate::error::Error;
 use crate::quic::config::QuicClientConfig;
@@ -6,17 +7,23 @@ use async_trait::async_trait;
 use bytes::BufMut;
 use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio::time::sleep;
-use tracing::{error, info, trace};
+use tracing::{error, info, trace, warn};
 
-const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
-const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const REQUEST_INITIAL_BYTES_LENGTH: usize = 8;
+const RESPONSE_INITIAL_BYTES_LENGTH: usize = 12;
+const LEGACY_RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
 const EMPTY_RESPONSE: Vec<u8> = vec![];
 const NAME: &str = "Iggy";
 
+/// The QUIC client sends each request on its own bidirectional stream together with a correlation ID
+/// which is echoed back by the server, so that many requests can be in flight over a single connection.
+/// The correlation IDs are negotiated by the handshake when connecting, and if the server doesn't support them,
+/// the client falls back to the legacy protocol.
 #[derive(Debug)]
 pub struct QuicClient {
     pub(crate) endpoint: Endpoint,
@@ -24,6 +31,8 @@ pub struct QuicClient {
     pub(crate) config: Arc<QuicClientConfig>,
     pub(crate) server_address: SocketAddr,
     pub(crate) state: Mutex<ClientState>,
+    next_correlation_id: AtomicU32,
+    correlation_ids_enabled: AtomicBool,
 }
 
 unsafe impl Send for QuicClient {}
@@ -80,6 +89,10 @@ impl Client for QuicClient {
             break;
         }
 
+        let version = self.handshake(&connection).await;
+        self.correlation_ids_enabled
+            .store(version >= CORRELATION_ID_PROTOCOL_VERSION, Ordering::SeqCst);
+
         self.set_state(ClientState::Connected).await;
         self.connection.lock().await.replace(connection);
 
@@ -115,19 +128,28 @@ impl BinaryClient for QuicClient {
             return Err(Error::NotConnected);
         }
 
-        let connection = self.connection.lock().await;
-        if let Some(connection) = connection.as_ref() {
-            let payload_length = payload.len() + 4;
-            let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
-            #[allow(clippy::cast_possible_truncation)]
-            buffer.put_u32_le(payload_length as u32);
+        // The connection is cloned, so the lock is not held while awaiting the response.
+        let connection = self.connection.lock().await.clone();
+        if let Some(connection) = connection {
+            let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload.len());
+            let correlation_id = if self.correlation_ids_enabled.load(Ordering::SeqCst) {
+                let correlation_id = self.next_correlation_id.fetch_add(1, Ordering::Relaxed);
+                #[allow(clippy::cast_possible_truncation)]
+                buffer.put_u32_le((payload.len() + 8) as u32);
+                buffer.put_u32_le(correlation_id);
+                Some(correlation_id)
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                buffer.put_u32_le((payload.len() + 4) as u32);
+                None
+            };
             buffer.put_u32_le(command);
             buffer.extend(payload);
 
             let (mut send, mut recv) = connection.open_bi().await?;
             send.write_all(&buffer).await?;
             send.finish().await?;
-            return self.handle_response(&mut recv).await;
+            return self.handle_response(correlation_id, &mut recv).await;
         }
 
         error!("Cannot send data. Client is not connected.");
@@ -170,14 +192,76 @@ impl QuicClient {
             server_address,
             connection: Mutex::new(None),
             state: Mutex::new(ClientState::Disconnected),
+            next_correlation_id: AtomicU32::new(1),
+            correlation_ids_enabled: AtomicBool::new(false),
         })
     }
 
-    async fn handle_response(&self, recv: &mut RecvStream) -> Result<Vec<u8>, Error> {
+    /// Sends the handshake on its own stream and returns the protocol version negotiated by the server.
+    /// The legacy servers don't respond to the handshake, in which case the legacy protocol is used.
+    async fn handshake(&self, connection: &Connection) -> u32 {
+        let response = async {
+            let (mut send, mut recv) = connection.open_bi().await?;
+            send.write_all(&protocol::handshake_request(
+                CORRELATION_ID_PROTOCOL_VERSION,
+            ))
+            .await?;
+            send.finish().await?;
+            let buffer = recv
+                .read_to_end(self.config.response_buffer_size as usize)
+                .await?;
+            Ok::<Vec<u8>, Error>(buffer)
+        }
+        .await;
+
+        let version = match response {
+            Ok(buffer) if buffer.len() >= LEGACY_RESPONSE_INITIAL_BYTES_LENGTH => {
+                let status = u32::from_le_bytes(buffer[..4].try_into().unwrap());
+                protocol::negotiated_version(
+                    status,
+                    &buffer[LEGACY_RESPONSE_INITIAL_BYTES_LENGTH..],
+                )
+            }
+            _ => {
+                warn!("The server has not responded to the handshake, falling back to the legacy protocol.");
+                protocol::LEGACY_PROTOCOL_VERSION
+            }
+        };
+        info!("Negotiated the QUIC protocol version: {}", version);
+        version
+    }
+
+    async fn handle_response(
+        &self,
+        correlation_id: Option<u32>,
+        recv: &mut RecvStream,
+    ) -> Result<Vec<u8>, Error> {
         let buffer = recv
             .read_to_end(self.config.response_buffer_size as usize)
             .await?;
-        if buffer.is_empty() {
+        let buffer = match correlation_id {
+            Some(correlation_id) => {
+                if buffer.len() < RESPONSE_INITIAL_BYTES_LENGTH {
+                    return Err(Error::EmptyResponse);
+                }
+
+                let response_correlation_id = u32::from_le_bytes(buffer[..4].try_into().unwrap());
+                if response_correlation_id != correlation_id {
+                    error!(
+                        "Received a response for correlation ID: {}, expected: {}.",
+                        response_correlation_id, correlation_id
+                    );
+                    return Err(Error::InvalidCorrelationId(
+                        response_correlation_id,
+                        correlation_id,
+                    ));
+                }
+
+                &buffer[4..]
+            }
+            None => &buffer[..],
+        };
+        if buffer.len() < LEGACY_RESPONSE_INITIAL_BYTES_LENGTH {
             return Err(Error::EmptyResponse);
         }
 
@@ -191,17 +275,19 @@ impl QuicClient {
             return Err(Error::InvalidResponse(status));
         }
 
-        let length =
-            u32::from_le_bytes(buffer[4..RESPONSE_INITIAL_BYTES_LENGTH].try_into().unwrap());
+        let length = u32::from_le_bytes(
+            buffer[4..LEGACY_RESPONSE_INITIAL_BYTES_LENGTH]
+                .try_into()
+                .unwrap(),
+        );
         trace!("Status: OK. Response length: {}", length);
         if length <= 1 {
             return Ok(EMPTY_RESPONSE);
         }
 
-        Ok(
-            buffer[RESPONSE_INITIAL_BYTES_LENGTH..RESPONSE_INITIAL_BYTES_LENGTH + length as usize]
-                .to_vec(),
-        )
+        Ok(buffer[LEGACY_RESPONSE_INITIAL_BYTES_LENGTH
+            ..LEGACY_RESPONSE_INITIAL_BYTES_LENGTH + length as usize]
+            .to_vec())
     }
 }
 
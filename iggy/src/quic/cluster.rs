@@ -0,0 +1,17 @@
+use crate::binary;
+use crate::client::ClusterClient;
+use crate::cluster::get_cluster_metadata::GetClusterMetadata;
+use crate::error::Error;
+use crate::models::cluster::ClusterMetadata;
+use crate::quic::client::QuicClient;
+use async_trait::async_trait;
+
+#[async_trait]
+impl ClusterClient for QuicClient {
+    async fn get_cluster_metadata(
+        &self,
+        command: &GetClusterMetadata,
+    ) -> Result<ClusterMetadata, Error> {
+        binary::cluster::get_cluster_metadata(self, command).await
+    }
+}
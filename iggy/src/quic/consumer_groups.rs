@@ -4,6 +4,7 @@ use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::Error;
@@ -42,4 +43,11 @@ impl ConsumerGroupClient for QuicClient {
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error> {
         binary::consumer_groups::leave_group(self, command).await
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        command: &HeartbeatConsumerGroup,
+    ) -> Result<(), Error> {
+        binary::consumer_groups::heartbeat_group(self, command).await
+    }
 }
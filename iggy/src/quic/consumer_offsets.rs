@@ -1,8 +1,10 @@
 use crate::binary;
 use crate::client::ConsumerOffsetClient;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
 use crate::quic::client::QuicClient;
 use async_trait::async_trait;
@@ -19,4 +21,8 @@ impl ConsumerOffsetClient for QuicClient {
     ) -> Result<ConsumerOffsetInfo, Error> {
         binary::consumer_offsets::get_consumer_offset(self, command).await
     }
+
+    async fn get_consumer_lag(&self, command: &GetConsumerLag) -> Result<ConsumerLag, Error> {
+        binary::consumer_offsets::get_consumer_lag(self, command).await
+    }
 }
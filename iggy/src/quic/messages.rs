@@ -1,6 +1,7 @@
 use crate::binary;
 use crate::client::MessageClient;
 use crate::error::Error;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
 use crate::models::messages::PolledMessages;
@@ -16,4 +17,8 @@ impl MessageClient for QuicClient {
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), Error> {
         binary::messages::send_messages(self, command).await
     }
+
+    async fn poison_message(&self, command: &PoisonMessage) -> Result<(), Error> {
+        binary::messages::poison_message(self, command).await
+    }
 }
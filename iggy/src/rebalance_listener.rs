@@ -0,0 +1,11 @@
+use crate::consumer::Consumer;
+use std::fmt::Debug;
+
+/// Notified by the client polling the messages as a member of the consumer group, whenever the generation
+/// of the group returned by the server changes, which means that the partitions were reassigned between its members.
+pub trait RebalanceListener: Send + Sync + Debug {
+    /// Invoked with the partitions, which are no longer assigned to the client, e.g. to store the offsets of the processed messages.
+    fn on_partitions_revoked(&self, consumer: &Consumer, partitions: &[u32]);
+    /// Invoked with the partitions, which have been newly assigned to the client.
+    fn on_partitions_assigned(&self, consumer: &Consumer, partitions: &[u32]);
+}
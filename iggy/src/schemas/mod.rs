@@ -0,0 +1,7 @@
+pub mod delete_schema;
+pub mod get_schema;
+pub mod get_schemas;
+pub mod register_schema;
+pub mod schema_compatibility;
+pub mod schema_format;
+pub mod update_schema_settings;
@@ -0,0 +1,211 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::schemas::schema_format::SchemaFormat;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+const MAX_DEFINITION_LENGTH: usize = 1_000_000;
+
+/// Registers the new version of the schema for the topic, the ID of the schema is assigned by the server.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RegisterSchema {
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    pub format: SchemaFormat,
+    pub definition: String,
+}
+
+impl CommandPayload for RegisterSchema {}
+
+impl Default for RegisterSchema {
+    fn default() -> Self {
+        RegisterSchema {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            format: SchemaFormat::default(),
+            definition: r#"{"type":"object"}"#.to_string(),
+        }
+    }
+}
+
+impl Validatable<Error> for RegisterSchema {
+    fn validate(&self) -> Result<(), Error> {
+        if self.definition.trim().is_empty() {
+            return Err(Error::InvalidSchemaDefinition(
+                "definition cannot be empty".to_string(),
+            ));
+        }
+
+        if self.definition.len() > MAX_DEFINITION_LENGTH {
+            return Err(Error::InvalidSchemaDefinition(format!(
+                "definition cannot be longer than {MAX_DEFINITION_LENGTH} bytes"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for RegisterSchema {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.splitn(4, '|').collect::<Vec<&str>>();
+        if parts.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let stream_id = parts[0].parse::<Identifier>()?;
+        let topic_id = parts[1].parse::<Identifier>()?;
+        let format = parts[2].parse::<SchemaFormat>()?;
+        let definition = parts[3].to_string();
+        let command = RegisterSchema {
+            stream_id,
+            topic_id,
+            format,
+            definition,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for RegisterSchema {
+    fn as_bytes(&self) -> Vec<u8> {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(
+            5 + stream_id_bytes.len() + topic_id_bytes.len() + self.definition.len(),
+        );
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u8(self.format.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(self.definition.len() as u32);
+        bytes.extend(self.definition.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<RegisterSchema, Error> {
+        if bytes.len() < 12 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes)?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 5 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let format = SchemaFormat::from_code(bytes[position])?;
+        let definition_length =
+            u32::from_le_bytes(bytes[position + 1..position + 5].try_into()?) as usize;
+        position += 5;
+        if bytes.len() != position + definition_length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let definition = from_utf8(&bytes[position..])?.to_string();
+        let command = RegisterSchema {
+            stream_id,
+            topic_id,
+            format,
+            definition,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for RegisterSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.format, self.definition
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RegisterSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            format: SchemaFormat::Avro,
+            definition: r#"{"type":"record","name":"test","fields":[]}"#.to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let format = SchemaFormat::from_code(bytes[position]).unwrap();
+        let definition_length =
+            u32::from_le_bytes(bytes[position + 1..position + 5].try_into().unwrap()) as usize;
+        let definition = from_utf8(&bytes[position + 5..position + 5 + definition_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(format, command.format);
+        assert_eq!(definition, command.definition);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let format = SchemaFormat::Protobuf;
+        let definition = "syntax = \"proto3\"; message Test { string name = 1; }";
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes =
+            Vec::with_capacity(5 + stream_id_bytes.len() + topic_id_bytes.len() + definition.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u8(format.as_code());
+        bytes.put_u32_le(definition.len() as u32);
+        bytes.extend(definition.as_bytes());
+        let command = RegisterSchema::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.format, format);
+        assert_eq!(command.definition, definition);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let format = SchemaFormat::JsonSchema;
+        let definition = r#"{"type":"object","description":"a|b"}"#;
+        let input = format!("{stream_id}|{topic_id}|{format}|{definition}");
+        let command = RegisterSchema::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.format, format);
+        assert_eq!(command.definition, definition);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Decides which new versions of the schema can be registered for the topic, when compared with the latest one:
+/// - `None` - any schema is accepted, including the one in the different format.
+/// - `Backward` - consumers using the new schema can read the data produced with the latest one.
+/// - `Forward` - consumers using the latest schema can read the data produced with the new one.
+/// - `Full` - the new schema is both backward and forward compatible.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaCompatibility {
+    None,
+    #[default]
+    Backward,
+    Forward,
+    Full,
+}
+
+impl SchemaCompatibility {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaCompatibility::None => 1,
+            SchemaCompatibility::Backward => 2,
+            SchemaCompatibility::Forward => 3,
+            SchemaCompatibility::Full => 4,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(SchemaCompatibility::None),
+            2 => Ok(SchemaCompatibility::Backward),
+            3 => Ok(SchemaCompatibility::Forward),
+            4 => Ok(SchemaCompatibility::Full),
+            _ => Err(Error::InvalidSchemaCompatibility),
+        }
+    }
+
+    pub fn is_backward(&self) -> bool {
+        matches!(
+            self,
+            SchemaCompatibility::Backward | SchemaCompatibility::Full
+        )
+    }
+
+    pub fn is_forward(&self) -> bool {
+        matches!(
+            self,
+            SchemaCompatibility::Forward | SchemaCompatibility::Full
+        )
+    }
+}
+
+impl Display for SchemaCompatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaCompatibility::None => write!(f, "none"),
+            SchemaCompatibility::Backward => write!(f, "backward"),
+            SchemaCompatibility::Forward => write!(f, "forward"),
+            SchemaCompatibility::Full => write!(f, "full"),
+        }
+    }
+}
+
+impl FromStr for SchemaCompatibility {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(SchemaCompatibility::None),
+            "backward" => Ok(SchemaCompatibility::Backward),
+            "forward" => Ok(SchemaCompatibility::Forward),
+            "full" => Ok(SchemaCompatibility::Full),
+            _ => Err(Error::InvalidSchemaCompatibility),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_converted_from_and_to_code() {
+        for compatibility in [
+            SchemaCompatibility::None,
+            SchemaCompatibility::Backward,
+            SchemaCompatibility::Forward,
+            SchemaCompatibility::Full,
+        ] {
+            let code = compatibility.as_code();
+            assert_eq!(SchemaCompatibility::from_code(code).unwrap(), compatibility);
+        }
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        for compatibility in [
+            SchemaCompatibility::None,
+            SchemaCompatibility::Backward,
+            SchemaCompatibility::Forward,
+            SchemaCompatibility::Full,
+        ] {
+            let value = compatibility.to_string();
+            assert_eq!(value.parse::<SchemaCompatibility>().unwrap(), compatibility);
+        }
+    }
+
+    #[test]
+    fn should_not_be_read_from_invalid_code_or_string() {
+        assert!(SchemaCompatibility::from_code(0).is_err());
+        assert!("transitive".parse::<SchemaCompatibility>().is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The format of the schema definition registered for the topic:
+/// - `JsonSchema` - JSON Schema document, the message payloads are validated against it when the validation is enabled.
+/// - `Avro` - Avro schema in its JSON representation.
+/// - `Protobuf` - Protobuf definition (`.proto` file) containing the message types.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaFormat {
+    #[default]
+    JsonSchema,
+    Avro,
+    Protobuf,
+}
+
+impl SchemaFormat {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaFormat::JsonSchema => 1,
+            SchemaFormat::Avro => 2,
+            SchemaFormat::Protobuf => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(SchemaFormat::JsonSchema),
+            2 => Ok(SchemaFormat::Avro),
+            3 => Ok(SchemaFormat::Protobuf),
+            _ => Err(Error::InvalidSchemaFormat),
+        }
+    }
+}
+
+impl Display for SchemaFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaFormat::JsonSchema => write!(f, "json_schema"),
+            SchemaFormat::Avro => write!(f, "avro"),
+            SchemaFormat::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+impl FromStr for SchemaFormat {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json_schema" => Ok(SchemaFormat::JsonSchema),
+            "avro" => Ok(SchemaFormat::Avro),
+            "protobuf" => Ok(SchemaFormat::Protobuf),
+            _ => Err(Error::InvalidSchemaFormat),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_converted_from_and_to_code() {
+        for format in [
+            SchemaFormat::JsonSchema,
+            SchemaFormat::Avro,
+            SchemaFormat::Protobuf,
+        ] {
+            let code = format.as_code();
+            assert_eq!(SchemaFormat::from_code(code).unwrap(), format);
+        }
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        for format in [
+            SchemaFormat::JsonSchema,
+            SchemaFormat::Avro,
+            SchemaFormat::Protobuf,
+        ] {
+            let value = format.to_string();
+            assert_eq!(value.parse::<SchemaFormat>().unwrap(), format);
+        }
+    }
+
+    #[test]
+    fn should_not_be_read_from_invalid_code_or_string() {
+        assert!(SchemaFormat::from_code(0).is_err());
+        assert!("thrift".parse::<SchemaFormat>().is_err());
+    }
+}
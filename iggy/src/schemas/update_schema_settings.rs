@@ -0,0 +1,180 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::schemas::schema_compatibility::SchemaCompatibility;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Updates the compatibility mode applied to the newly registered schemas of the topic,
+/// and decides whether the payloads of the sent messages are validated against the schemas.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateSchemaSettings {
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    pub compatibility: SchemaCompatibility,
+    pub validation_enabled: bool,
+}
+
+impl CommandPayload for UpdateSchemaSettings {}
+
+impl Validatable<Error> for UpdateSchemaSettings {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for UpdateSchemaSettings {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let stream_id = parts[0].parse::<Identifier>()?;
+        let topic_id = parts[1].parse::<Identifier>()?;
+        let compatibility = parts[2].parse::<SchemaCompatibility>()?;
+        let validation_enabled = match parts[3] {
+            "t" | "true" => true,
+            "f" | "false" => false,
+            _ => return Err(Error::InvalidCommand),
+        };
+        let command = UpdateSchemaSettings {
+            stream_id,
+            topic_id,
+            compatibility,
+            validation_enabled,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for UpdateSchemaSettings {
+    fn as_bytes(&self) -> Vec<u8> {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(2 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u8(self.compatibility.as_code());
+        bytes.put_u8(u8::from(self.validation_enabled));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateSchemaSettings, Error> {
+        if bytes.len() < 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes)?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() != position + 2 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let compatibility = SchemaCompatibility::from_code(bytes[position])?;
+        let validation_enabled = match bytes[position + 1] {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::InvalidCommand),
+        };
+        let command = UpdateSchemaSettings {
+            stream_id,
+            topic_id,
+            compatibility,
+            validation_enabled,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateSchemaSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.compatibility, self.validation_enabled
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateSchemaSettings {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            compatibility: SchemaCompatibility::Full,
+            validation_enabled: true,
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let compatibility = SchemaCompatibility::from_code(bytes[position]).unwrap();
+        let validation_enabled = bytes[position + 1] == 1;
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(compatibility, command.compatibility);
+        assert_eq!(validation_enabled, command.validation_enabled);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let compatibility = SchemaCompatibility::Forward;
+        let validation_enabled = true;
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(2 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u8(compatibility.as_code());
+        bytes.put_u8(u8::from(validation_enabled));
+        let command = UpdateSchemaSettings::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.compatibility, compatibility);
+        assert_eq!(command.validation_enabled, validation_enabled);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let compatibility = SchemaCompatibility::None;
+        let validation_enabled = false;
+        let input = format!("{stream_id}|{topic_id}|{compatibility}|{validation_enabled}");
+        let command = UpdateSchemaSettings::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.compatibility, compatibility);
+        assert_eq!(command.validation_enabled, validation_enabled);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod create_backup;
 pub mod get_client;
 pub mod get_clients;
 pub mod get_me;
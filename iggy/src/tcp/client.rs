@@ -1,33 +1,51 @@
 use crate::binary::binary_client::{BinaryClient, ClientState};
+use crate::binary::protocol::{self, CORRELATION_ID_PROTOCOL_VERSION, LEGACY_PROTOCOL_VERSION};
 use crate::client::Client;
 use crate::error::Error;
 use crate::tcp::config::TcpClientConfig;
 use async_trait::async_trait;
 use bytes::BufMut;
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{split, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadHalf, WriteHalf};
 use tokio::net::TcpStream;
-use tokio::sync::Mutex;
+use tokio::sync::{oneshot, Mutex};
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tokio_native_tls::native_tls::TlsConnector;
 use tokio_native_tls::TlsStream;
 use tracing::log::trace;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-const REQUEST_INITIAL_BYTES_LENGTH: usize = 4;
-const RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+const REQUEST_INITIAL_BYTES_LENGTH: usize = 8;
+const RESPONSE_INITIAL_BYTES_LENGTH: usize = 12;
+const LEGACY_RESPONSE_INITIAL_BYTES_LENGTH: usize = 8;
+// The correlation IDs start from 1, so this one is used only by the single request in flight of the legacy protocol.
+const LEGACY_CORRELATION_ID: u32 = 0;
 const EMPTY_RESPONSE: Vec<u8> = vec![];
 const NAME: &str = "Iggy";
 
+type PendingResponses = Arc<Mutex<HashMap<u32, oneshot::Sender<Result<Vec<u8>, Error>>>>>;
+
+/// The TCP client sends each request with a correlation ID which is echoed back by the server,
+/// so that many requests can be in flight over a single connection at the same time.
+/// The responses are read by a background task and dispatched to the awaiting requests.
+/// The correlation IDs are negotiated by the handshake when connecting, and if the server doesn't support them,
+/// the client falls back to the legacy protocol, sending a single request at a time.
 #[derive(Debug)]
 pub struct TcpClient {
     pub(crate) server_address: SocketAddr,
     pub(crate) stream: Mutex<Option<Box<dyn ConnectionStream>>>,
     pub(crate) config: Arc<TcpClientConfig>,
     pub(crate) state: Mutex<ClientState>,
+    pending_responses: PendingResponses,
+    next_correlation_id: AtomicU32,
+    correlation_ids_enabled: AtomicBool,
+    response_reader: Mutex<Option<JoinHandle<()>>>,
 }
 
 unsafe impl Send for TcpClient {}
@@ -35,18 +53,22 @@ unsafe impl Sync for TcpClient {}
 
 #[async_trait]
 pub(crate) trait ConnectionStream: Debug + Sync + Send {
-    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error>;
     async fn write(&mut self, buf: &[u8]) -> Result<(), Error>;
 }
 
+#[async_trait]
+trait ConnectionReader: Send {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error>;
+}
+
 #[derive(Debug)]
 struct TcpConnectionStream {
-    stream: TcpStream,
+    stream: WriteHalf<TcpStream>,
 }
 
 #[derive(Debug)]
 struct TcpTlsConnectionStream {
-    stream: TlsStream<TcpStream>,
+    stream: WriteHalf<TlsStream<TcpStream>>,
 }
 
 unsafe impl Send for TcpConnectionStream {}
@@ -57,15 +79,18 @@ unsafe impl Sync for TcpTlsConnectionStream {}
 
 #[async_trait]
 impl ConnectionStream for TcpConnectionStream {
-    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
-        let result = self.stream.read_exact(buf).await;
+    async fn write(&mut self, buf: &[u8]) -> Result<(), Error> {
+        let result = self.stream.write_all(buf).await;
         if let Err(error) = result {
             return Err(Error::from(error));
         }
 
-        Ok(result.unwrap())
+        Ok(())
     }
+}
 
+#[async_trait]
+impl ConnectionStream for TcpTlsConnectionStream {
     async fn write(&mut self, buf: &[u8]) -> Result<(), Error> {
         let result = self.stream.write_all(buf).await;
         if let Err(error) = result {
@@ -77,24 +102,18 @@ impl ConnectionStream for TcpConnectionStream {
 }
 
 #[async_trait]
-impl ConnectionStream for TcpTlsConnectionStream {
+impl<T> ConnectionReader for ReadHalf<T>
+where
+    T: AsyncRead + Send,
+{
     async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
-        let result = self.stream.read_exact(buf).await;
+        let result = self.read_exact(buf).await;
         if let Err(error) = result {
             return Err(Error::from(error));
         }
 
         Ok(result.unwrap())
     }
-
-    async fn write(&mut self, buf: &[u8]) -> Result<(), Error> {
-        let result = self.stream.write_all(buf).await;
-        if let Err(error) = result {
-            return Err(Error::from(error));
-        }
-
-        Ok(())
-    }
 }
 
 impl Default for TcpClient {
@@ -112,7 +131,9 @@ impl Client for TcpClient {
 
         let tls_enabled = self.config.tls_enabled;
         let mut retry_count = 0;
+        let mut protocol_version = CORRELATION_ID_PROTOCOL_VERSION;
         let connection_stream: Box<dyn ConnectionStream>;
+        let connection_reader: Box<dyn ConnectionReader>;
         let remote_address;
         loop {
             info!(
@@ -142,28 +163,59 @@ impl Client for TcpClient {
                 return Err(Error::NotConnected);
             }
 
-            let stream = connection.unwrap();
-            remote_address = stream.peer_addr()?;
+            let mut stream = connection.unwrap();
+            let peer_address = stream.peer_addr()?;
 
             if !tls_enabled {
-                connection_stream = Box::new(TcpConnectionStream { stream });
+                match handshake(&mut stream, protocol_version).await {
+                    Some(version) => protocol_version = version,
+                    None => {
+                        protocol_version = LEGACY_PROTOCOL_VERSION;
+                        continue;
+                    }
+                }
+                let (reader, writer) = split(stream);
+                connection_stream = Box::new(TcpConnectionStream { stream: writer });
+                connection_reader = Box::new(reader);
+                remote_address = peer_address;
                 break;
             }
 
             let connector =
                 tokio_native_tls::TlsConnector::from(TlsConnector::builder().build().unwrap());
-            let stream = tokio_native_tls::TlsConnector::connect(
+            let mut stream = tokio_native_tls::TlsConnector::connect(
                 &connector,
                 &self.config.tls_domain,
                 stream,
             )
             .await
             .unwrap();
-            connection_stream = Box::new(TcpTlsConnectionStream { stream });
+            match handshake(&mut stream, protocol_version).await {
+                Some(version) => protocol_version = version,
+                None => {
+                    protocol_version = LEGACY_PROTOCOL_VERSION;
+                    continue;
+                }
+            }
+            let (reader, writer) = split(stream);
+            connection_stream = Box::new(TcpTlsConnectionStream { stream: writer });
+            connection_reader = Box::new(reader);
+            remote_address = peer_address;
             break;
         }
 
+        let correlation_ids_enabled = protocol_version >= CORRELATION_ID_PROTOCOL_VERSION;
+        self.correlation_ids_enabled
+            .store(correlation_ids_enabled, Ordering::SeqCst);
         self.stream.lock().await.replace(connection_stream);
+        let response_reader = tokio::spawn(read_responses(
+            connection_reader,
+            self.pending_responses.clone(),
+            correlation_ids_enabled,
+        ));
+        if let Some(previous_reader) = self.response_reader.lock().await.replace(response_reader) {
+            previous_reader.abort();
+        }
         self.set_state(ClientState::Connected).await;
 
         info!(
@@ -182,6 +234,10 @@ impl Client for TcpClient {
         info!("{} client is disconnecting from server...", NAME);
         self.set_state(ClientState::Disconnected).await;
         self.stream.lock().await.take();
+        if let Some(response_reader) = self.response_reader.lock().await.take() {
+            response_reader.abort();
+        }
+        self.pending_responses.lock().await.clear();
         info!("{} client has disconnected from server.", NAME);
         Ok(())
     }
@@ -202,33 +258,63 @@ impl BinaryClient for TcpClient {
             return Err(Error::NotConnected);
         }
 
-        let mut stream = self.stream.lock().await;
-        if let Some(stream) = stream.as_mut() {
-            let payload_length = payload.len() + 4;
-            let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
+        let correlation_ids_enabled = self.correlation_ids_enabled.load(Ordering::SeqCst);
+        let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload.len());
+        let correlation_id = if correlation_ids_enabled {
+            let correlation_id = self.next_correlation_id.fetch_add(1, Ordering::Relaxed);
             #[allow(clippy::cast_possible_truncation)]
-            buffer.put_u32_le(payload_length as u32);
-            buffer.put_u32_le(command);
-            buffer.extend(payload);
-
-            trace!("Sending a TCP request...");
-            stream.write(&buffer).await?;
-            trace!("Sent a TCP request, waiting for a response...");
-
-            let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
-            let read_bytes = stream.read(&mut response_buffer).await?;
-            if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
-                error!("Received an invalid or empty response.");
-                return Err(Error::EmptyResponse);
-            }
+            buffer.put_u32_le((payload.len() + 8) as u32);
+            buffer.put_u32_le(correlation_id);
+            correlation_id
+        } else {
+            #[allow(clippy::cast_possible_truncation)]
+            buffer.put_u32_le((payload.len() + 4) as u32);
+            LEGACY_CORRELATION_ID
+        };
+        buffer.put_u32_le(command);
+        buffer.extend(payload);
+
+        // The pending response is registered while holding the stream, so that the legacy requests,
+        // which all share the same correlation ID, can't replace each other's pending response.
+        let mut stream = self.stream.lock().await;
+        let Some(connection_stream) = stream.as_mut() else {
+            error!("Cannot send data. Client is not connected.");
+            return Err(Error::NotConnected);
+        };
 
-            let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
-            let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
-            return self.handle_response(status, length, stream.as_mut()).await;
+        let (sender, receiver) = oneshot::channel();
+        self.pending_responses
+            .lock()
+            .await
+            .insert(correlation_id, sender);
+        trace!(
+            "Sending a TCP request with correlation ID: {}...",
+            correlation_id
+        );
+        if let Err(error) = connection_stream.write(&buffer).await {
+            self.pending_responses.lock().await.remove(&correlation_id);
+            return Err(error);
         }
 
-        error!("Cannot send data. Client is not connected.");
-        Err(Error::NotConnected)
+        // The legacy protocol allows only a single request in flight, so the stream is held until the response.
+        if correlation_ids_enabled {
+            drop(stream);
+        }
+
+        trace!(
+            "Sent a TCP request with correlation ID: {}, waiting for a response...",
+            correlation_id
+        );
+        match receiver.await {
+            Ok(response) => response,
+            Err(_) => {
+                error!(
+                    "Connection has been closed before receiving a response for correlation ID: {}.",
+                    correlation_id
+                );
+                Err(Error::NotConnected)
+            }
+        }
     }
 }
 
@@ -257,31 +343,118 @@ impl TcpClient {
             server_address,
             stream: Mutex::new(None),
             state: Mutex::new(ClientState::Disconnected),
+            pending_responses: Arc::new(Mutex::new(HashMap::new())),
+            next_correlation_id: AtomicU32::new(1),
+            correlation_ids_enabled: AtomicBool::new(false),
+            response_reader: Mutex::new(None),
         })
     }
+}
 
-    async fn handle_response(
-        &self,
-        status: u32,
-        length: u32,
-        stream: &mut dyn ConnectionStream,
-    ) -> Result<Vec<u8>, Error> {
-        if status != 0 {
-            error!(
-                "Received an invalid response with status: {} ({}).",
-                status,
-                Error::from_code_as_string(status)
-            );
-            return Err(Error::InvalidResponse(status));
+/// Sends the handshake requesting the given protocol version and returns the version negotiated by the server.
+/// Returns none if the connection has been closed, as the legacy servers close it on an unknown command.
+async fn handshake<T>(stream: &mut T, version: u32) -> Option<u32>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    if version == LEGACY_PROTOCOL_VERSION {
+        return Some(version);
+    }
+
+    let mut response_buffer = [0u8; LEGACY_RESPONSE_INITIAL_BYTES_LENGTH];
+    if stream
+        .write_all(&protocol::handshake_request(version))
+        .await
+        .is_err()
+        || stream.read_exact(&mut response_buffer).await.is_err()
+    {
+        warn!("The server has closed the connection during the handshake, falling back to the legacy protocol.");
+        return None;
+    }
+
+    let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
+    let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
+    let mut payload = vec![0u8; length as usize];
+    if stream.read_exact(&mut payload).await.is_err() {
+        return None;
+    }
+
+    let version = protocol::negotiated_version(status, &payload);
+    info!("Negotiated the TCP protocol version: {}", version);
+    Some(version)
+}
+
+async fn read_responses(
+    mut reader: Box<dyn ConnectionReader>,
+    pending_responses: PendingResponses,
+    correlation_ids_enabled: bool,
+) {
+    let initial_bytes_length = if correlation_ids_enabled {
+        RESPONSE_INITIAL_BYTES_LENGTH
+    } else {
+        LEGACY_RESPONSE_INITIAL_BYTES_LENGTH
+    };
+    loop {
+        let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+        let response_buffer = &mut response_buffer[..initial_bytes_length];
+        let read_bytes = match reader.read(response_buffer).await {
+            Ok(read_bytes) => read_bytes,
+            Err(error) => {
+                trace!("Stopped reading TCP responses: {}", error);
+                break;
+            }
+        };
+        if read_bytes != initial_bytes_length {
+            error!("Received an invalid or empty response.");
+            break;
         }
 
-        trace!("Status: OK. Response length: {}", length);
-        if length <= 1 {
-            return Ok(EMPTY_RESPONSE);
+        let (correlation_id, response_buffer) = if correlation_ids_enabled {
+            (
+                u32::from_le_bytes(response_buffer[..4].try_into().unwrap()),
+                &response_buffer[4..],
+            )
+        } else {
+            (LEGACY_CORRELATION_ID, &response_buffer[..])
+        };
+        let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
+        let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
+        let mut payload = vec![0u8; length as usize];
+        if length > 0 {
+            if let Err(error) = reader.read(&mut payload).await {
+                error!("Failed to read the TCP response payload: {}", error);
+                break;
+            }
         }
 
-        let mut response_buffer = vec![0u8; length as usize];
-        stream.read(&mut response_buffer).await?;
-        Ok(response_buffer)
+        let Some(sender) = pending_responses.lock().await.remove(&correlation_id) else {
+            warn!(
+                "Received a TCP response for an unknown correlation ID: {}.",
+                correlation_id
+            );
+            continue;
+        };
+        let _ = sender.send(handle_response(status, length, payload));
     }
+
+    // Dropping the pending senders notifies the awaiting requests that the connection is gone.
+    pending_responses.lock().await.clear();
+}
+
+fn handle_response(status: u32, length: u32, payload: Vec<u8>) -> Result<Vec<u8>, Error> {
+    if status != 0 {
+        error!(
+            "Received an invalid response with status: {} ({}).",
+            status,
+            Error::from_code_as_string(status)
+        );
+        return Err(Error::InvalidResponse(status));
+    }
+
+    trace!("Status: OK. Response length: {}", length);
+    if length <= 1 {
+        return Ok(EMPTY_RESPONSE);
+    }
+
+    Ok(payload)
 }
@@ -0,0 +1,34 @@
+use crate::binary;
+use crate::client::SchemaClient;
+use crate::error::Error;
+use crate::models::schema::{Schema, TopicSchemas};
+use crate::schemas::delete_schema::DeleteSchema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::schemas::update_schema_settings::UpdateSchemaSettings;
+use crate::tcp::client::TcpClient;
+use async_trait::async_trait;
+
+#[async_trait]
+impl SchemaClient for TcpClient {
+    async fn get_schema(&self, command: &GetSchema) -> Result<Schema, Error> {
+        binary::schemas::get_schema(self, command).await
+    }
+
+    async fn get_schemas(&self, command: &GetSchemas) -> Result<TopicSchemas, Error> {
+        binary::schemas::get_schemas(self, command).await
+    }
+
+    async fn register_schema(&self, command: &RegisterSchema) -> Result<Schema, Error> {
+        binary::schemas::register_schema(self, command).await
+    }
+
+    async fn delete_schema(&self, command: &DeleteSchema) -> Result<(), Error> {
+        binary::schemas::delete_schema(self, command).await
+    }
+
+    async fn update_schema_settings(&self, command: &UpdateSchemaSettings) -> Result<(), Error> {
+        binary::schemas::update_schema_settings(self, command).await
+    }
+}
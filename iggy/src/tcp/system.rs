@@ -1,8 +1,10 @@
 use crate::binary;
 use crate::client::SystemClient;
 use crate::error::Error;
+use crate::models::backup::Backup;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
+use crate::system::create_backup::CreateBackup;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
@@ -17,6 +19,10 @@ impl SystemClient for TcpClient {
         binary::system::get_stats(self, command).await
     }
 
+    async fn create_backup(&self, command: &CreateBackup) -> Result<Backup, Error> {
+        binary::system::create_backup(self, command).await
+    }
+
     async fn get_me(&self, command: &GetMe) -> Result<ClientInfoDetails, Error> {
         binary::system::get_me(self, command).await
     }
@@ -0,0 +1,27 @@
+use crate::binary;
+use crate::client::TransactionClient;
+use crate::error::Error;
+use crate::models::transaction::TransactionInfo;
+use crate::tcp::client::TcpClient;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
+use async_trait::async_trait;
+
+#[async_trait]
+impl TransactionClient for TcpClient {
+    async fn begin_transaction(
+        &self,
+        command: &BeginTransaction,
+    ) -> Result<TransactionInfo, Error> {
+        binary::transactions::begin_transaction(self, command).await
+    }
+
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), Error> {
+        binary::transactions::commit_transaction(self, command).await
+    }
+
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), Error> {
+        binary::transactions::abort_transaction(self, command).await
+    }
+}
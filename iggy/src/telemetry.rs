@@ -0,0 +1,202 @@
+use crate::messages::send_messages;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::messages::Message;
+use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry::Context;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::{field, info_span, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// The header containing the W3C trace context (`traceparent`) of the span in which the message was sent.
+pub const TRACE_PARENT_HEADER: &str = "traceparent";
+
+struct HeadersInjector<'a>(&'a mut HashMap<HeaderKey, HeaderValue>);
+
+impl Injector for HeadersInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (HeaderKey::new(key), HeaderValue::from_str(&value)) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+struct HeadersExtractor<'a>(&'a HashMap<HeaderKey, HeaderValue>);
+
+impl Extractor for HeadersExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(&HeaderKey::new(key).ok()?)?.as_str().ok()
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+/// Stores the W3C trace context (`traceparent` and `tracestate`) of the provided context in the headers.
+pub fn inject_context(context: &Context, headers: &mut HashMap<HeaderKey, HeaderValue>) {
+    TraceContextPropagator::new().inject_context(context, &mut HeadersInjector(headers));
+}
+
+/// Returns the context with the remote span stored in the headers, or the empty context if there's no valid trace context.
+pub fn extract_context(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> Context {
+    match headers {
+        Some(headers) => TraceContextPropagator::new().extract(&HeadersExtractor(headers)),
+        None => Context::new(),
+    }
+}
+
+/// Injects the trace context of the current span into the messages, unless there's no span being traced
+/// or the message already has its own trace context (e.g. when it's being forwarded).
+/// The current `tracing` span is used if it's exported via `tracing-opentelemetry`, otherwise the current OpenTelemetry context.
+pub fn inject_current_context(messages: &mut [send_messages::Message]) {
+    let mut context = Span::current().context();
+    if !context.span().span_context().is_valid() {
+        context = Context::current();
+    }
+    if !context.span().span_context().is_valid() {
+        return;
+    }
+
+    let trace_parent = HeaderKey::new(TRACE_PARENT_HEADER).unwrap();
+    for message in messages {
+        let headers = message.headers.get_or_insert_with(HashMap::new);
+        if headers.contains_key(&trace_parent) {
+            continue;
+        }
+
+        inject_context(&context, headers);
+    }
+}
+
+/// Creates the span of polling the messages, which should be entered while the request is sent.
+pub fn start_poll_span() -> Span {
+    info_span!("iggy.poll_messages", messages_count = field::Empty)
+}
+
+/// Records the polled messages in the span of polling them, linked to the spans in which the messages were sent.
+/// To continue the trace while processing the message, use `extract_context` as the parent of the processing span.
+pub fn record_polled_messages(span: &Span, messages: &[Message]) {
+    span.record("messages_count", messages.len() as i64);
+    for message in messages {
+        let context = extract_context(&message.headers);
+        let span_context = context.span().span_context().clone();
+        if span_context.is_valid() {
+            span.add_link(span_context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use opentelemetry::trace::{
+        SpanContext, SpanId, TraceFlags, TraceId, TraceState, TracerProvider as _,
+    };
+    use opentelemetry::Value;
+    use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+    use opentelemetry_sdk::trace::TracerProvider;
+    use std::future::{ready, Future};
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Debug, Clone, Default)]
+    struct TestExporter(Arc<Mutex<Vec<SpanData>>>);
+
+    impl SpanExporter for TestExporter {
+        fn export(
+            &mut self,
+            batch: Vec<SpanData>,
+        ) -> Pin<Box<dyn Future<Output = ExportResult> + Send>> {
+            self.0.lock().unwrap().extend(batch);
+            Box::pin(ready(Ok(())))
+        }
+    }
+
+    fn create_context() -> Context {
+        Context::new().with_remote_span_context(SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ))
+    }
+
+    #[test]
+    fn context_should_be_injected_into_headers() {
+        let mut headers = HashMap::new();
+        inject_context(&create_context(), &mut headers);
+
+        let trace_parent = headers
+            .get(&HeaderKey::new(TRACE_PARENT_HEADER).unwrap())
+            .unwrap();
+        assert_eq!(
+            trace_parent.as_str().unwrap(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+    }
+
+    #[test]
+    fn context_should_be_extracted_from_headers() {
+        let context = create_context();
+        let mut headers = HashMap::new();
+        inject_context(&context, &mut headers);
+
+        let extracted_context = extract_context(&Some(headers));
+
+        assert_eq!(
+            extracted_context.span().span_context(),
+            context.span().span_context()
+        );
+    }
+
+    #[test]
+    fn polled_messages_should_be_recorded_in_the_entered_poll_span() {
+        let exporter = TestExporter::default();
+        let provider = TracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        let _subscriber = tracing::subscriber::set_default(subscriber);
+        let mut headers = HashMap::new();
+        inject_context(&create_context(), &mut headers);
+        let message = Message::from_message(&send_messages::Message::new(
+            None,
+            Bytes::from("message"),
+            Some(headers),
+        ));
+
+        let span = start_poll_span();
+        {
+            let _entered = span.enter();
+            assert!(Span::current().context().span().span_context().is_valid());
+        }
+        record_polled_messages(&span, &[message]);
+        drop(span);
+
+        let spans = exporter.0.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "iggy.poll_messages");
+        assert!(spans[0].attributes.iter().any(|attribute| {
+            attribute.key.as_str() == "messages_count" && attribute.value == Value::I64(1)
+        }));
+        let links = &spans[0].links.links;
+        assert_eq!(links.len(), 1);
+        assert_eq!(
+            &links[0].span_context,
+            create_context().span().span_context()
+        );
+    }
+
+    #[test]
+    fn empty_context_should_be_extracted_given_no_headers() {
+        let context = extract_context(&None);
+        assert!(!context.span().span_context().is_valid());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::Error;
 use crate::identifier::Identifier;
 use crate::utils::text;
@@ -19,6 +20,14 @@ pub struct CreateTopic {
     pub topic_id: u32,
     pub partitions_count: u32,
     pub message_expiry: Option<u32>,
+    #[serde(default)]
+    pub compression_algorithm: CompressionAlgorithm,
+    #[serde(default)]
+    pub compaction_enabled: bool,
+    #[serde(default)]
+    pub max_topic_size: Option<u64>,
+    #[serde(default)]
+    pub max_partition_size: Option<u64>,
     pub name: String,
 }
 
@@ -31,6 +40,10 @@ impl Default for CreateTopic {
             topic_id: 1,
             partitions_count: 1,
             message_expiry: None,
+            compression_algorithm: CompressionAlgorithm::default(),
+            compaction_enabled: false,
+            max_topic_size: None,
+            max_partition_size: None,
             name: "topic".to_string(),
         }
     }
@@ -62,7 +75,7 @@ impl FromStr for CreateTopic {
     type Err = Error;
     fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 5 {
+        if !(5..=9).contains(&parts.len()) {
             return Err(Error::InvalidCommand);
         }
 
@@ -78,11 +91,29 @@ impl FromStr for CreateTopic {
             Err(_) => None,
         };
         let name = parts[4].to_string();
+        let compression_algorithm = match parts.get(5) {
+            Some(compression_algorithm) => compression_algorithm.parse::<CompressionAlgorithm>()?,
+            None => CompressionAlgorithm::default(),
+        };
+        let compaction_enabled = match parts.get(6) {
+            Some(compaction) => match *compaction {
+                "c" | "compaction" => true,
+                "n" | "no_compaction" => false,
+                _ => return Err(Error::InvalidCommand),
+            },
+            None => false,
+        };
+        let max_topic_size = parse_size(parts.get(7))?;
+        let max_partition_size = parse_size(parts.get(8))?;
         let command = CreateTopic {
             stream_id,
             topic_id,
             partitions_count,
             message_expiry,
+            compression_algorithm,
+            compaction_enabled,
+            max_topic_size,
+            max_partition_size,
             name,
         };
         command.validate()?;
@@ -93,7 +124,7 @@ impl FromStr for CreateTopic {
 impl BytesSerializable for CreateTopic {
     fn as_bytes(&self) -> Vec<u8> {
         let stream_id_bytes = self.stream_id.as_bytes();
-        let mut bytes = Vec::with_capacity(13 + stream_id_bytes.len() + self.name.len());
+        let mut bytes = Vec::with_capacity(31 + stream_id_bytes.len() + self.name.len());
         bytes.extend(stream_id_bytes);
         bytes.put_u32_le(self.topic_id);
         bytes.put_u32_le(self.partitions_count);
@@ -104,6 +135,14 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        bytes.put_u8(self.compression_algorithm.as_code());
+        if self.compaction_enabled {
+            bytes.put_u8(1);
+        } else {
+            bytes.put_u8(0);
+        }
+        bytes.put_u64_le(self.max_topic_size.unwrap_or(0));
+        bytes.put_u64_le(self.max_partition_size.unwrap_or(0));
         bytes
     }
 
@@ -128,11 +167,26 @@ impl BytesSerializable for CreateTopic {
         if name.len() != name_length as usize {
             return Err(Error::InvalidCommand);
         }
+        // Compression algorithm, compaction and size limits are optional to keep the compatibility with the older clients.
+        let compression_algorithm = match bytes.get(position + 13 + name_length as usize) {
+            Some(code) => CompressionAlgorithm::from_code(*code)?,
+            None => CompressionAlgorithm::default(),
+        };
+        let compaction_enabled = match bytes.get(position + 14 + name_length as usize) {
+            Some(compaction_enabled) => matches!(compaction_enabled, 1),
+            None => false,
+        };
+        let max_topic_size = read_size(bytes, position + 15 + name_length as usize)?;
+        let max_partition_size = read_size(bytes, position + 23 + name_length as usize)?;
         let command = CreateTopic {
             stream_id,
             topic_id,
             partitions_count,
             message_expiry,
+            compression_algorithm,
+            compaction_enabled,
+            max_topic_size,
+            max_partition_size,
             name,
         };
         command.validate()?;
@@ -144,16 +198,48 @@ impl Display for CreateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.partitions_count,
             self.message_expiry.unwrap_or(0),
-            self.name
+            self.name,
+            self.compression_algorithm,
+            compaction_to_string(self.compaction_enabled),
+            self.max_topic_size.unwrap_or(0),
+            self.max_partition_size.unwrap_or(0)
         )
     }
 }
 
+fn compaction_to_string(compaction_enabled: bool) -> &'static str {
+    if compaction_enabled {
+        "c"
+    } else {
+        "n"
+    }
+}
+
+fn parse_size(input: Option<&&str>) -> Result<Option<u64>, Error> {
+    match input {
+        Some(size) => match size.parse::<u64>()? {
+            0 => Ok(None),
+            size => Ok(Some(size)),
+        },
+        None => Ok(None),
+    }
+}
+
+fn read_size(bytes: &[u8], position: usize) -> Result<Option<u64>, Error> {
+    match bytes.get(position..position + 8) {
+        Some(size) => match u64::from_le_bytes(size.try_into()?) {
+            0 => Ok(None),
+            size => Ok(Some(size)),
+        },
+        None => Ok(None),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,6 +252,10 @@ mod tests {
             topic_id: 2,
             partitions_count: 3,
             message_expiry: Some(10),
+            compression_algorithm: CompressionAlgorithm::Zstd,
+            compaction_enabled: true,
+            max_topic_size: Some(1000),
+            max_partition_size: None,
             name: "test".to_string(),
         };
 
@@ -186,6 +276,19 @@ mod tests {
         let name = from_utf8(&bytes[position + 13..position + 13 + name_length as usize])
             .unwrap()
             .to_string();
+        let compression_algorithm =
+            CompressionAlgorithm::from_code(bytes[position + 13 + name_length as usize]).unwrap();
+        let compaction_enabled = matches!(bytes[position + 14 + name_length as usize], 1);
+        let max_topic_size = u64::from_le_bytes(
+            bytes[position + 15 + name_length as usize..position + 23 + name_length as usize]
+                .try_into()
+                .unwrap(),
+        );
+        let max_partition_size = u64::from_le_bytes(
+            bytes[position + 23 + name_length as usize..position + 31 + name_length as usize]
+                .try_into()
+                .unwrap(),
+        );
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -194,6 +297,10 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        assert_eq!(compression_algorithm, command.compression_algorithm);
+        assert_eq!(compaction_enabled, command.compaction_enabled);
+        assert_eq!(Some(max_topic_size), command.max_topic_size);
+        assert_eq!(max_partition_size, 0);
     }
 
     #[test]
@@ -203,9 +310,12 @@ mod tests {
         let partitions_count = 3u32;
         let name = "test".to_string();
         let message_expiry = 10;
+        let compression_algorithm = CompressionAlgorithm::Lz4;
+        let max_topic_size = 1000;
+        let max_partition_size = 100;
 
         let stream_id_bytes = stream_id.as_bytes();
-        let mut bytes = Vec::with_capacity(13 + stream_id_bytes.len() + name.len());
+        let mut bytes = Vec::with_capacity(14 + stream_id_bytes.len() + name.len());
         bytes.extend(stream_id_bytes);
         bytes.put_u32_le(topic_id);
         bytes.put_u32_le(partitions_count);
@@ -213,6 +323,10 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.extend(name.as_bytes());
+        bytes.put_u8(compression_algorithm.as_code());
+        bytes.put_u8(1);
+        bytes.put_u64_le(max_topic_size);
+        bytes.put_u64_le(max_partition_size);
 
         let command = CreateTopic::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -222,6 +336,33 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitions_count, partitions_count);
         assert_eq!(command.message_expiry, Some(message_expiry));
+        assert_eq!(command.compression_algorithm, compression_algorithm);
+        assert!(command.compaction_enabled);
+        assert_eq!(command.max_topic_size, Some(max_topic_size));
+        assert_eq!(command.max_partition_size, Some(max_partition_size));
+        assert_eq!(command.name, name);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_compression_algorithm() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let name = "test".to_string();
+
+        let stream_id_bytes = stream_id.as_bytes();
+        let mut bytes = Vec::with_capacity(13 + stream_id_bytes.len() + name.len());
+        bytes.extend(stream_id_bytes);
+        bytes.put_u32_le(2);
+        bytes.put_u32_le(3);
+        bytes.put_u32_le(0);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.extend(name.as_bytes());
+
+        let command = CreateTopic::from_bytes(&bytes).unwrap();
+        assert_eq!(command.compression_algorithm, CompressionAlgorithm::None);
+        assert!(!command.compaction_enabled);
+        assert_eq!(command.max_topic_size, None);
+        assert_eq!(command.max_partition_size, None);
         assert_eq!(command.name, name);
     }
 
@@ -232,7 +373,10 @@ mod tests {
         let partitions_count = 3u32;
         let message_expiry = 10;
         let name = "test".to_string();
-        let input = format!("{stream_id}|{topic_id}|{partitions_count}|{message_expiry}|{name}");
+        let compression_algorithm = CompressionAlgorithm::Zstd;
+        let input = format!(
+            "{stream_id}|{topic_id}|{partitions_count}|{message_expiry}|{name}|{compression_algorithm}|c|1000|0"
+        );
         let command = CreateTopic::from_str(&input);
         assert!(command.is_ok());
 
@@ -241,6 +385,10 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitions_count, partitions_count);
         assert_eq!(command.message_expiry, Some(message_expiry));
+        assert_eq!(command.compression_algorithm, compression_algorithm);
+        assert!(command.compaction_enabled);
+        assert_eq!(command.max_topic_size, Some(1000));
+        assert_eq!(command.max_partition_size, None);
         assert_eq!(command.name, name);
     }
 }
@@ -18,6 +18,10 @@ pub struct UpdateTopic {
     #[serde(skip)]
     pub topic_id: Identifier,
     pub message_expiry: Option<u32>,
+    #[serde(default)]
+    pub max_topic_size: Option<u64>,
+    #[serde(default)]
+    pub max_partition_size: Option<u64>,
     pub name: String,
 }
 
@@ -29,6 +33,8 @@ impl Default for UpdateTopic {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             message_expiry: None,
+            max_topic_size: None,
+            max_partition_size: None,
             name: "topic".to_string(),
         }
     }
@@ -52,7 +58,7 @@ impl FromStr for UpdateTopic {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 4 {
+        if !(4..=6).contains(&parts.len()) {
             return Err(Error::InvalidCommand);
         }
 
@@ -67,10 +73,14 @@ impl FromStr for UpdateTopic {
             Err(_) => None,
         };
         let name = parts[3].to_string();
+        let max_topic_size = parse_size(parts.get(4))?;
+        let max_partition_size = parse_size(parts.get(5))?;
         let command = UpdateTopic {
             stream_id,
             topic_id,
             message_expiry,
+            max_topic_size,
+            max_partition_size,
             name,
         };
         command.validate()?;
@@ -83,7 +93,7 @@ impl BytesSerializable for UpdateTopic {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes =
-            Vec::with_capacity(5 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
+            Vec::with_capacity(21 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         match self.message_expiry {
@@ -93,6 +103,8 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        bytes.put_u64_le(self.max_topic_size.unwrap_or(0));
+        bytes.put_u64_le(self.max_partition_size.unwrap_or(0));
         bytes
     }
 
@@ -117,10 +129,15 @@ impl BytesSerializable for UpdateTopic {
         if name.len() != name_length as usize {
             return Err(Error::InvalidCommand);
         }
+        // Size limits are optional to keep the compatibility with the older clients.
+        let max_topic_size = read_size(bytes, position + 5 + name_length as usize)?;
+        let max_partition_size = read_size(bytes, position + 13 + name_length as usize)?;
         let command = UpdateTopic {
             stream_id,
             topic_id,
             message_expiry,
+            max_topic_size,
+            max_partition_size,
             name,
         };
         command.validate()?;
@@ -132,15 +149,37 @@ impl Display for UpdateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry.unwrap_or(0),
-            self.name
+            self.name,
+            self.max_topic_size.unwrap_or(0),
+            self.max_partition_size.unwrap_or(0)
         )
     }
 }
 
+fn parse_size(input: Option<&&str>) -> Result<Option<u64>, Error> {
+    match input {
+        Some(size) => match size.parse::<u64>()? {
+            0 => Ok(None),
+            size => Ok(Some(size)),
+        },
+        None => Ok(None),
+    }
+}
+
+fn read_size(bytes: &[u8], position: usize) -> Result<Option<u64>, Error> {
+    match bytes.get(position..position + 8) {
+        Some(size) => match u64::from_le_bytes(size.try_into()?) {
+            0 => Ok(None),
+            size => Ok(Some(size)),
+        },
+        None => Ok(None),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,6 +191,8 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             message_expiry: Some(10),
+            max_topic_size: Some(1000),
+            max_partition_size: Some(100),
             name: "test".to_string(),
         };
 
@@ -170,6 +211,16 @@ mod tests {
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize])
             .unwrap()
             .to_string();
+        let max_topic_size = u64::from_le_bytes(
+            bytes[position + 5 + name_length as usize..position + 13 + name_length as usize]
+                .try_into()
+                .unwrap(),
+        );
+        let max_partition_size = u64::from_le_bytes(
+            bytes[position + 13 + name_length as usize..position + 21 + name_length as usize]
+                .try_into()
+                .unwrap(),
+        );
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -177,6 +228,8 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        assert_eq!(Some(max_topic_size), command.max_topic_size);
+        assert_eq!(Some(max_partition_size), command.max_partition_size);
     }
 
     #[test]
@@ -185,6 +238,7 @@ mod tests {
         let topic_id = Identifier::numeric(2).unwrap();
         let name = "test".to_string();
         let message_expiry = 10;
+        let max_topic_size = 1000;
 
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
@@ -196,6 +250,8 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.extend(name.as_bytes());
+        bytes.put_u64_le(max_topic_size);
+        bytes.put_u64_le(0);
 
         let command = UpdateTopic::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -205,6 +261,8 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.message_expiry, Some(message_expiry));
         assert_eq!(command.name, name);
+        assert_eq!(command.max_topic_size, Some(max_topic_size));
+        assert_eq!(command.max_partition_size, None);
     }
 
     #[test]
@@ -213,7 +271,10 @@ mod tests {
         let topic_id = Identifier::numeric(2).unwrap();
         let message_expiry = 10;
         let name = "test".to_string();
-        let input = format!("{}|{}|{}|{}", stream_id, topic_id, message_expiry, name);
+        let input = format!(
+            "{}|{}|{}|{}|1000|100",
+            stream_id, topic_id, message_expiry, name
+        );
         let command = UpdateTopic::from_str(&input);
         assert!(command.is_ok());
 
@@ -222,5 +283,7 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.message_expiry, Some(message_expiry));
         assert_eq!(command.name, name);
+        assert_eq!(command.max_topic_size, Some(1000));
+        assert_eq!(command.max_partition_size, Some(100));
     }
 }
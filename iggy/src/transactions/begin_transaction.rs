@@ -0,0 +1,96 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `BeginTransaction` command is used to start a new transaction.
+/// The messages sent with the returned transaction ID are kept by the server
+/// and appended to their partitions only when the transaction is committed.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct BeginTransaction {}
+
+impl CommandPayload for BeginTransaction {}
+
+impl Validatable<Error> for BeginTransaction {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for BeginTransaction {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if !input.is_empty() {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = BeginTransaction {};
+        command.validate()?;
+        Ok(BeginTransaction {})
+    }
+}
+
+impl BytesSerializable for BeginTransaction {
+    fn as_bytes(&self) -> Vec<u8> {
+        Vec::with_capacity(0)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<BeginTransaction, Error> {
+        if !bytes.is_empty() {
+            return Err(Error::InvalidCommand);
+        }
+
+        let command = BeginTransaction {};
+        command.validate()?;
+        Ok(BeginTransaction {})
+    }
+}
+
+impl Display for BeginTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = BeginTransaction {};
+        let bytes = command.as_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let bytes: Vec<u8> = vec![];
+        let command = BeginTransaction::from_bytes(&bytes);
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let bytes: Vec<u8> = vec![0];
+        let command = BeginTransaction::from_bytes(&bytes);
+        assert!(command.is_err());
+    }
+
+    #[test]
+    fn should_be_read_from_empty_string() {
+        let input = "";
+        let command = BeginTransaction::from_str(input);
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_read_from_non_empty_string() {
+        let input = " ";
+        let command = BeginTransaction::from_str(input);
+        assert!(command.is_err());
+    }
+}
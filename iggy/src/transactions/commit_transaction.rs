@@ -0,0 +1,99 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `CommitTransaction` command is used to atomically append all the messages sent within the transaction.
+/// It has additional payload:
+/// - `transaction_id` - unique transaction ID returned by the `BeginTransaction` command.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct CommitTransaction {
+    #[serde(skip)]
+    pub transaction_id: u64,
+}
+
+impl CommandPayload for CommitTransaction {}
+
+impl Validatable<Error> for CommitTransaction {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for CommitTransaction {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let transaction_id = parts[0].parse::<u64>()?;
+        let command = CommitTransaction { transaction_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for CommitTransaction {
+    fn as_bytes(&self) -> Vec<u8> {
+        self.transaction_id.to_le_bytes().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<CommitTransaction, Error> {
+        if bytes.len() != 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let transaction_id = u64::from_le_bytes(bytes.try_into()?);
+        let command = CommitTransaction { transaction_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for CommitTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.transaction_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CommitTransaction { transaction_id: 1 };
+
+        let bytes = command.as_bytes();
+        let transaction_id = u64::from_le_bytes(bytes.as_slice().try_into().unwrap());
+
+        assert_eq!(transaction_id, command.transaction_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let transaction_id = 1u64;
+        let bytes = transaction_id.to_le_bytes();
+        let command = CommitTransaction::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.transaction_id, transaction_id);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let transaction_id = 1u64;
+        let input = format!("{transaction_id}");
+        let command = CommitTransaction::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.transaction_id, transaction_id);
+    }
+}
@@ -2,6 +2,7 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::models::user_quota::UserQuota;
 use crate::models::user_status::UserStatus;
 use crate::users::defaults::*;
 use crate::utils::text;
@@ -17,6 +18,7 @@ pub struct UpdateUser {
     pub user_id: Identifier,
     pub username: Option<String>,
     pub status: Option<UserStatus>,
+    pub quota: Option<UserQuota>,
 }
 
 impl CommandPayload for UpdateUser {}
@@ -47,7 +49,7 @@ impl FromStr for UpdateUser {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.is_empty() || parts.len() > 3 {
+        if parts.is_empty() || parts.len() > 4 {
             return Err(Error::InvalidCommand);
         }
 
@@ -69,10 +71,18 @@ impl FromStr for UpdateUser {
             },
             None => None,
         };
+        let quota = match parts.get(3) {
+            Some(quota) => match *quota {
+                "" => None,
+                _ => Some(UserQuota::from_str(quota)?),
+            },
+            None => None,
+        };
         let command = UpdateUser {
             user_id,
             username,
             status,
+            quota,
         };
         command.validate()?;
         Ok(command)
@@ -98,6 +108,13 @@ impl BytesSerializable for UpdateUser {
         } else {
             bytes.put_u8(0);
         }
+        if let Some(quota) = &self.quota {
+            bytes.put_u8(1);
+            bytes.put_u64_le(quota.bytes_per_second);
+            bytes.put_u32_le(quota.requests_per_second);
+        } else {
+            bytes.put_u8(0);
+        }
 
         bytes
     }
@@ -139,10 +156,33 @@ impl BytesSerializable for UpdateUser {
             None
         };
 
+        // The quota is optional at the end of the payload, so that the command sent by the older clients is still valid.
+        position += 1;
+        let has_quota = bytes.get(position).copied().unwrap_or(0);
+        if has_quota > 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let quota = if has_quota == 1 {
+            position += 1;
+            if bytes.len() < position + 12 {
+                return Err(Error::InvalidCommand);
+            }
+
+            let bytes_per_second =
+                u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+            let requests_per_second =
+                u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
+            Some(UserQuota::new(bytes_per_second, requests_per_second))
+        } else {
+            None
+        };
+
         let command = UpdateUser {
             user_id,
             username,
             status,
+            quota,
         };
         command.validate()?;
         Ok(command)
@@ -156,7 +196,12 @@ impl Display for UpdateUser {
             .status
             .as_ref()
             .map_or_else(String::new, |s| s.to_string());
-        write!(f, "{}|{username}|{status}", self.user_id)
+        write!(f, "{}|{username}|{status}", self.user_id)?;
+        if let Some(quota) = &self.quota {
+            write!(f, "|{quota}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -170,6 +215,7 @@ mod tests {
             user_id: Identifier::numeric(1).unwrap(),
             username: Some("user".to_string()),
             status: Some(UserStatus::Active),
+            quota: Some(UserQuota::new(1000, 10)),
         };
 
         let bytes = command.as_bytes();
@@ -184,6 +230,13 @@ mod tests {
         let has_status = bytes[position];
         position += 1;
         let status = UserStatus::from_code(bytes[position]).unwrap();
+        position += 1;
+        let has_quota = bytes[position];
+        position += 1;
+        let bytes_per_second =
+            u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+        let requests_per_second =
+            u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(user_id, command.user_id);
@@ -191,6 +244,11 @@ mod tests {
         assert_eq!(username, command.username.unwrap());
         assert_eq!(has_status, 1);
         assert_eq!(status, command.status.unwrap());
+        assert_eq!(has_quota, 1);
+        assert_eq!(
+            UserQuota::new(bytes_per_second, requests_per_second),
+            command.quota.unwrap()
+        );
     }
 
     #[test]
@@ -213,6 +271,13 @@ mod tests {
         assert_eq!(command.user_id, user_id);
         assert_eq!(command.username.unwrap(), username);
         assert_eq!(command.status.unwrap(), status);
+        assert!(command.quota.is_none());
+
+        bytes.put_u8(1);
+        bytes.put_u64_le(1000);
+        bytes.put_u32_le(10);
+        let command = UpdateUser::from_bytes(&bytes).unwrap();
+        assert_eq!(command.quota.unwrap(), UserQuota::new(1000, 10));
     }
 
     #[test]
@@ -220,7 +285,7 @@ mod tests {
         let user_id = Identifier::numeric(1).unwrap();
         let username = "user";
         let status = UserStatus::Active;
-        let input = format!("{user_id}|{username}|{status}");
+        let input = format!("{user_id}|{username}|{status}|1000:10");
         let command = UpdateUser::from_str(&input);
         assert!(command.is_ok());
 
@@ -228,5 +293,6 @@ mod tests {
         assert_eq!(command.user_id, user_id);
         assert_eq!(command.username.unwrap(), username);
         assert_eq!(command.status.unwrap(), status);
+        assert_eq!(command.quota.unwrap(), UserQuota::new(1000, 10));
     }
 }
@@ -29,6 +29,11 @@ pub(crate) enum TestTopicId {
     Named,
 }
 
+pub(crate) enum TestConsumerGroupId {
+    Numeric,
+    Named,
+}
+
 pub(crate) enum OutputFormat {
     Default,
     List,
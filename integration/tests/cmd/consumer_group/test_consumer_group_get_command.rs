@@ -0,0 +1,338 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestConsumerGroupId, TestHelpCmd, TestStreamId,
+    TestTopicId, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, is_match, starts_with};
+use serial_test::parallel;
+
+struct TestConsumerGroupGetCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partitions_count: u32,
+    consumer_group_id: u32,
+    consumer_group_name: String,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    using_consumer_group_id: TestConsumerGroupId,
+}
+
+impl TestConsumerGroupGetCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partitions_count: u32,
+        consumer_group_id: u32,
+        consumer_group_name: String,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        using_consumer_group_id: TestConsumerGroupId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partitions_count,
+            consumer_group_id,
+            consumer_group_name,
+            using_stream_id,
+            using_topic_id,
+            using_consumer_group_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(match self.using_consumer_group_id {
+            TestConsumerGroupId::Numeric => format!("{}", self.consumer_group_id),
+            TestConsumerGroupId::Named => self.consumer_group_name.clone(),
+        });
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerGroupGetCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: self.stream_name.clone(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: self.partitions_count,
+                name: self.topic_name.clone(),
+                message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let consumer_group = client
+            .create_consumer_group(&CreateConsumerGroup {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                consumer_group_id: self.consumer_group_id,
+                name: self.consumer_group_name.clone(),
+                ..CreateConsumerGroup::default()
+            })
+            .await;
+        assert!(consumer_group.is_ok());
+
+        let join = client
+            .join_consumer_group(&JoinConsumerGroup {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                consumer_group_id: Identifier::numeric(self.consumer_group_id).unwrap(),
+            })
+            .await;
+        assert!(join.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-group")
+            .arg("get")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let consumer_group_id = match self.using_consumer_group_id {
+            TestConsumerGroupId::Numeric => format!("{}", self.consumer_group_id),
+            TestConsumerGroupId::Named => self.consumer_group_name.clone(),
+        };
+
+        let start_message = format!(
+            "Executing get consumer group with ID: {} for topic with ID: {} and stream with ID: {}",
+            consumer_group_id, topic_id, stream_id
+        );
+
+        let partitions = (1..=self.partitions_count)
+            .map(|partition_id| partition_id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains(format!(
+                "Consumer group id             | {}",
+                self.consumer_group_id
+            )))
+            .stdout(contains(format!(
+                "Consumer group name           | {}",
+                self.consumer_group_name
+            )))
+            .stdout(contains(format!(
+                "Partitions count              | {}",
+                self.partitions_count
+            )))
+            .stdout(contains("Members count                 | 1"))
+            .stdout(contains("Partition assignment strategy | round_robin"))
+            .stdout(is_match(format!(r"Member \d+ partitions\s+\| {partitions} ")).unwrap());
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let leave = client
+            .leave_consumer_group(&LeaveConsumerGroup {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                consumer_group_id: Identifier::numeric(self.consumer_group_id).unwrap(),
+            })
+            .await;
+        assert!(leave.is_ok());
+
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupGetCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            1,
+            1,
+            String::from("group1"),
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            TestConsumerGroupId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupGetCmd::new(
+            2,
+            String::from("stream"),
+            3,
+            String::from("topic"),
+            3,
+            3,
+            String::from("group3"),
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+            TestConsumerGroupId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupGetCmd::new(
+            4,
+            String::from("development"),
+            1,
+            String::from("probe"),
+            2,
+            7,
+            String::from("group7"),
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+            TestConsumerGroupId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupGetCmd::new(
+            2,
+            String::from("production"),
+            5,
+            String::from("test"),
+            4,
+            4,
+            String::from("group4"),
+            TestStreamId::Named,
+            TestTopicId::Named,
+            TestConsumerGroupId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "get", "--help"],
+            format!(
+                r#"Get details of a single consumer group with given ID for given stream ID and topic ID
+
+Details include the members of the consumer group and the partitions assigned to them.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+Consumer group ID can be specified as a consumer group name or ID
+
+Examples
+ iggy consumer-group get 1 2 3
+ iggy consumer-group get stream 2 3
+ iggy consumer-group get 1 topic 3
+ iggy consumer-group get 1 2 group
+ iggy consumer-group get stream topic group
+
+{USAGE_PREFIX} consumer-group get <STREAM_ID> <TOPIC_ID> <CONSUMER_GROUP_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to get consumer group
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to get consumer group
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <CONSUMER_GROUP_ID>
+          Consumer group ID to get
+{CLAP_INDENT}
+          Consumer group ID can be specified as a consumer group name or ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "get", "-h"],
+            format!(
+                r#"Get details of a single consumer group with given ID for given stream ID and topic ID
+
+{USAGE_PREFIX} consumer-group get <STREAM_ID> <TOPIC_ID> <CONSUMER_GROUP_ID>
+
+Arguments:
+  <STREAM_ID>          Stream ID to get consumer group
+  <TOPIC_ID>           Topic ID to get consumer group
+  <CONSUMER_GROUP_ID>  Consumer group ID to get
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
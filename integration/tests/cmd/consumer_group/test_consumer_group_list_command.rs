@@ -0,0 +1,266 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, OutputFormat, TestHelpCmd, TestStreamId,
+    TestTopicId, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestConsumerGroupListCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    consumer_group_id: u32,
+    consumer_group_name: String,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    output: OutputFormat,
+}
+
+impl TestConsumerGroupListCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        consumer_group_id: u32,
+        consumer_group_name: String,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        output: OutputFormat,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            consumer_group_id,
+            consumer_group_name,
+            using_stream_id,
+            using_topic_id,
+            output,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.extend(self.output.to_args().into_iter().map(String::from));
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerGroupListCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: self.stream_name.clone(),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: 1,
+                name: self.topic_name.clone(),
+                message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let consumer_group = client
+            .create_consumer_group(&CreateConsumerGroup {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                consumer_group_id: self.consumer_group_id,
+                name: self.consumer_group_name.clone(),
+                ..CreateConsumerGroup::default()
+            })
+            .await;
+        assert!(consumer_group.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-group")
+            .arg("list")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing list consumer groups for topic with ID: {} and stream with ID: {} in {} mode",
+                topic_id, stream_id, self.output
+            )))
+            .stdout(contains(self.consumer_group_name.clone()));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .delete_stream(&DeleteStream {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupListCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            1,
+            String::from("group1"),
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            OutputFormat::Default,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupListCmd::new(
+            2,
+            String::from("stream"),
+            3,
+            String::from("topic"),
+            3,
+            String::from("group3"),
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+            OutputFormat::List,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerGroupListCmd::new(
+            4,
+            String::from("development"),
+            1,
+            String::from("probe"),
+            7,
+            String::from("group7"),
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+            OutputFormat::Table,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "list", "--help"],
+            format!(
+                r#"List all consumer groups for given stream ID and topic ID
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy consumer-group list 1 1
+ iggy consumer-group list stream 2 --list-mode table
+ iggy consumer-group list 3 topic -l table
+ iggy consumer-group list production sensor -l table
+
+{USAGE_PREFIX} consumer-group list [OPTIONS] <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to list consumer groups
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to list consumer groups
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+Options:
+  -l, --list-mode <LIST_MODE>
+          List mode (table or list)
+{CLAP_INDENT}
+          [default: table]
+          [possible values: table, list]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-group", "list", "-h"],
+            format!(
+                r#"List all consumer groups for given stream ID and topic ID
+
+{USAGE_PREFIX} consumer-group list [OPTIONS] <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <STREAM_ID>  Stream ID to list consumer groups
+  <TOPIC_ID>   Topic ID to list consumer groups
+
+Options:
+  -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
+  -h, --help                   Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1 @@
+mod test_consumer_lag_command;
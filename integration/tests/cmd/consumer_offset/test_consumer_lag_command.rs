@@ -0,0 +1,210 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestConsumerLagCmd {
+    stream_id: u32,
+    topic_id: u32,
+    consumer_id: u32,
+    messages_count: u32,
+    stored_offset: u64,
+}
+
+impl TestConsumerLagCmd {
+    fn new(
+        stream_id: u32,
+        topic_id: u32,
+        consumer_id: u32,
+        messages_count: u32,
+        stored_offset: u64,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            consumer_id,
+            messages_count,
+            stored_offset,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerLagCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: format!("stream-{}", self.stream_id),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: 1,
+                name: format!("topic-{}", self.topic_id),
+                message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let messages = (0..self.messages_count)
+            .map(|offset| Message::new(None, Bytes::from(format!("message {offset}")), None))
+            .collect::<Vec<_>>();
+        let sent = client
+            .send_messages(&mut SendMessages {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partitioning: Partitioning::partition_id(1),
+                producer: None,
+                transaction_id: None,
+                acks: Acks::default(),
+                messages,
+            })
+            .await;
+        assert!(sent.is_ok());
+
+        let stored = client
+            .store_consumer_offset(&StoreConsumerOffset {
+                consumer: Consumer::new(Identifier::numeric(self.consumer_id).unwrap()),
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                partition_id: Some(1),
+                offset: self.stored_offset,
+            })
+            .await;
+        assert!(stored.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("lag")
+            .arg(format!("{}", self.stream_id))
+            .arg(format!("{}", self.topic_id))
+            .arg(format!("{}", self.consumer_id))
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let start_message = format!(
+            "Executing get lag of consumer with ID: {} for topic with ID: {} in stream with ID: {}\n",
+            self.consumer_id, self.topic_id, self.stream_id
+        );
+        let total_lag = self.messages_count as u64 - 1 - self.stored_offset;
+
+        command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(contains("Stored offset"))
+            .stdout(contains(format!("Total lag: {total_lag}")));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestConsumerLagCmd::new(1, 1, 1, 10, 3))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestConsumerLagCmd::new(2, 3, 5, 20, 19))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["lag", "--help"],
+            format!(
+                r#"get consumer or consumer group lag
+
+Get the number of messages not consumed yet by the consumer or the consumer group
+in each partition of the topic, based on the stored consumer offsets.
+
+Examples
+ iggy lag 1 1 1
+ iggy lag --group prod orders 2
+
+{USAGE_PREFIX} lag [OPTIONS] <STREAM_ID> <TOPIC_ID> <CONSUMER_ID>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID of the topic consumed
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID consumed
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <CONSUMER_ID>
+          Consumer ID or consumer group ID which stored the offsets
+{CLAP_INDENT}
+          Consumer ID can be specified as a consumer name or ID
+
+Options:
+  -g, --group
+          Treat the consumer ID as the consumer group ID
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["lag", "-h"],
+            format!(
+                r#"get consumer or consumer group lag
+
+{USAGE_PREFIX} lag [OPTIONS] <STREAM_ID> <TOPIC_ID> <CONSUMER_ID>
+
+Arguments:
+  <STREAM_ID>    Stream ID of the topic consumed
+  <TOPIC_ID>     Topic ID consumed
+  <CONSUMER_ID>  Consumer ID or consumer group ID which stored the offsets
+
+Options:
+  -g, --group  Treat the consumer ID as the consumer group ID
+  -h, --help   Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -15,14 +15,19 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} [OPTIONS] <COMMAND>
 
 Commands:
-  stream     stream operations
-  topic      topic operations
-  partition  partition operations
-  ping       ping iggy server
-  me         get current client info
-  stats      get iggy server statistics
-  pat        personal access token operations
-  help       Print this message or the help of the given subcommand(s)
+  stream          stream operations
+  topic           topic operations
+  partition       partition operations
+  schema          schema registry operations
+  consumer-group  consumer group operations
+  ping            ping iggy server
+  me              get current client info
+  stats           get iggy server statistics
+  lag             get consumer or consumer group lag
+  backup          create server state backup
+  user            user operations
+  pat             personal access token operations
+  help            Print this message or the help of the given subcommand(s)
 
 Options:
       --transport <TRANSPORT>
@@ -1,7 +1,11 @@
 mod common;
+mod consumer_group;
+mod consumer_offset;
 mod general;
 mod partition;
 mod personal_access_token;
+mod schema;
 mod stream;
 mod system;
 mod topic;
+mod user;
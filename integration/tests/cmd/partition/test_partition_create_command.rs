@@ -4,6 +4,7 @@ use crate::cmd::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -83,6 +84,10 @@ impl IggyCmdTestCase for TestPartitionCreateCmd {
                 partitions_count: self.partitions_count,
                 name: self.topic_name.clone(),
                 message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -0,0 +1,3 @@
+mod test_schema_delete_command;
+mod test_schema_help_command;
+mod test_schema_register_command;
@@ -0,0 +1,106 @@
+use crate::cmd::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestSchemaDeleteCmd {
+    stream_id: u32,
+    topic_id: u32,
+}
+
+impl TestSchemaDeleteCmd {
+    fn new(stream_id: u32, topic_id: u32) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestSchemaDeleteCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: format!("stream-{}", self.stream_id),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: 1,
+                name: format!("topic-{}", self.topic_id),
+                message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        let schema = client
+            .register_schema(&RegisterSchema {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+                ..RegisterSchema::default()
+            })
+            .await;
+        assert!(schema.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("schema")
+            .arg("delete")
+            .arg(format!("{}", self.stream_id))
+            .arg(format!("{}", self.topic_id))
+            .arg("1")
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing delete schema with ID: 1 for topic with ID: {} in stream with ID: {}\nSchema with ID: 1 for topic with ID: {} in stream with ID: {} deleted\n",
+            self.topic_id, self.stream_id, self.topic_id, self.stream_id
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic_schemas = client
+            .get_schemas(&GetSchemas {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+            })
+            .await;
+        assert!(topic_schemas.is_ok());
+        assert!(topic_schemas.unwrap().schemas.is_empty());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestSchemaDeleteCmd::new(1, 1))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestSchemaDeleteCmd::new(2, 3))
+        .await;
+}
@@ -0,0 +1,33 @@
+use crate::cmd::common::{help::TestHelpCmd, IggyCmdTest, USAGE_PREFIX};
+use serial_test::parallel;
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["schema", "help"],
+            format!(
+                r#"schema registry operations
+
+{USAGE_PREFIX} schema <COMMAND>
+
+Commands:
+  register  Register new version of the schema from given file
+                for topic ID in given stream ID
+  delete    Delete schema with given ID for topic ID in given stream ID
+  get       Get schema with given ID for topic ID in given stream ID
+  list      List all schemas for topic ID in given stream ID
+  settings  Update compatibility mode and payload validation of the schemas
+                for topic ID in given stream ID
+  help      Print this message or the help of the given subcommand(s)
+
+Options:
+  -h, --help  Print help
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,138 @@
+use crate::cmd::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::schema_format::SchemaFormat;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::path::PathBuf;
+
+struct TestSchemaRegisterCmd {
+    stream_id: u32,
+    topic_id: u32,
+    format: SchemaFormat,
+    definition: String,
+    definition_file: PathBuf,
+}
+
+impl TestSchemaRegisterCmd {
+    fn new(stream_id: u32, topic_id: u32, format: SchemaFormat, definition: &str) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            format,
+            definition: definition.to_string(),
+            definition_file: std::env::temp_dir()
+                .join(format!("iggy-schema-{}", uuid::Uuid::new_v4())),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![
+            "--format".into(),
+            format!("{}", self.format),
+            format!("{}", self.stream_id),
+            format!("{}", self.topic_id),
+            self.definition_file.display().to_string(),
+        ]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestSchemaRegisterCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: self.stream_id,
+                name: format!("stream-{}", self.stream_id),
+            })
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(&CreateTopic {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: self.topic_id,
+                partitions_count: 1,
+                name: format!("topic-{}", self.topic_id),
+                message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
+            })
+            .await;
+        assert!(topic.is_ok());
+
+        tokio::fs::write(&self.definition_file, &self.definition)
+            .await
+            .unwrap();
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("schema")
+            .arg("register")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing register {} schema from file: {} for topic with ID: {} in stream with ID: {}\nSchema with ID: 1 for topic with ID: {} in stream with ID: {} registered\n",
+            self.format,
+            self.definition_file.display(),
+            self.topic_id,
+            self.stream_id,
+            self.topic_id,
+            self.stream_id
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic_schemas = client
+            .get_schemas(&GetSchemas {
+                stream_id: Identifier::numeric(self.stream_id).unwrap(),
+                topic_id: Identifier::numeric(self.topic_id).unwrap(),
+            })
+            .await;
+        assert!(topic_schemas.is_ok());
+        let topic_schemas = topic_schemas.unwrap();
+        assert_eq!(topic_schemas.schemas.len(), 1);
+        assert_eq!(topic_schemas.schemas[0].id, 1);
+        assert_eq!(topic_schemas.schemas[0].format, self.format);
+        assert_eq!(topic_schemas.schemas[0].definition, self.definition);
+
+        tokio::fs::remove_file(&self.definition_file).await.unwrap();
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestSchemaRegisterCmd::new(
+            1,
+            1,
+            SchemaFormat::JsonSchema,
+            r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestSchemaRegisterCmd::new(
+            2,
+            1,
+            SchemaFormat::Protobuf,
+            "syntax = \"proto3\";\nmessage Order {\n  uint64 id = 1;\n}\n",
+        ))
+        .await;
+}
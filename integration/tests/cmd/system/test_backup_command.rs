@@ -0,0 +1,91 @@
+use crate::cmd::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::streams::create_stream::CreateStream;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestBackupCmd {}
+
+#[async_trait]
+impl IggyCmdTestCase for TestBackupCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&CreateStream {
+                stream_id: 1,
+                name: String::from("logs"),
+            })
+            .await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new().arg("backup").with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with("Executing backup command\n"))
+            .stdout(contains("Backup name"))
+            .stdout(contains("Backup path"))
+            .stdout(contains("Backup size"));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test.execute_test(TestBackupCmd {}).await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["backup", "--help"],
+            format!(
+                r#"create server state backup
+
+Create the archive with the consistent snapshot of the server state, including the metadata and the segment files. The server state can be restored from the archive by starting the server with the --restore-backup option.
+
+{USAGE_PREFIX} backup
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["backup", "-h"],
+            format!(
+                r#"create server state backup
+
+{USAGE_PREFIX} backup
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
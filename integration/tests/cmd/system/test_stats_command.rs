@@ -1,6 +1,7 @@
 use crate::cmd::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::{client::Client, identifier::Identifier};
@@ -27,6 +28,10 @@ impl IggyCmdTestCase for TestStatsCmd {
                 stream_id,
                 partitions_count: 5,
                 message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
                 name: String::from("topic"),
             })
             .await;
@@ -6,6 +6,7 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use humantime::format_duration;
 use humantime::Duration as HumanDuration;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::get_topic::GetTopic;
 use iggy::{client::Client, identifier::Identifier};
@@ -20,10 +21,13 @@ struct TestTopicCreateCmd {
     topic_name: String,
     partitions_count: u32,
     message_expiry: Option<Vec<String>>,
+    compression_algorithm: Option<CompressionAlgorithm>,
+    compaction_enabled: bool,
     using_identifier: TestStreamId,
 }
 
 impl TestTopicCreateCmd {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         stream_id: u32,
         stream_name: String,
@@ -31,6 +35,8 @@ impl TestTopicCreateCmd {
         topic_name: String,
         partitions_count: u32,
         message_expiry: Option<Vec<String>>,
+        compression_algorithm: Option<CompressionAlgorithm>,
+        compaction_enabled: bool,
         using_identifier: TestStreamId,
     ) -> Self {
         Self {
@@ -40,15 +46,27 @@ impl TestTopicCreateCmd {
             topic_name,
             partitions_count,
             message_expiry,
+            compression_algorithm,
+            compaction_enabled,
             using_identifier,
         }
     }
 
     fn to_args(&self) -> Vec<String> {
-        let mut command = match self.using_identifier {
-            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
-            TestStreamId::Named => vec![self.stream_name.clone()],
-        };
+        let mut command = Vec::new();
+        if let Some(compression_algorithm) = self.compression_algorithm {
+            command.push(String::from("--compression-algorithm"));
+            command.push(compression_algorithm.to_string());
+        }
+
+        if self.compaction_enabled {
+            command.push(String::from("--compaction"));
+        }
+
+        command.push(match self.using_identifier {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        });
 
         command.push(format!("{}", self.topic_id));
         command.push(format!("{}", self.partitions_count));
@@ -95,8 +113,10 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
             TestStreamId::Named => self.stream_name.clone(),
         };
 
-        let message = format!("Executing create topic with ID: {}, name: {}, partitions count: {} {} in stream with ID: {}\nTopic with ID: {}, name: {}, partitions count: {} {} created in stream with ID: {}\n",
-            self.topic_id, self.topic_name, self.partitions_count, message_expiry_text, stream_id, self.topic_id, self.topic_name, self.partitions_count, message_expiry_text, stream_id);
+        let compression_algorithm = self.compression_algorithm.unwrap_or_default();
+
+        let message = format!("Executing create topic with ID: {}, name: {}, partitions count: {}, compression algorithm: {} {} in stream with ID: {}\nTopic with ID: {}, name: {}, partitions count: {}, compression algorithm: {} {} created in stream with ID: {}\n",
+            self.topic_id, self.topic_name, self.partitions_count, compression_algorithm, message_expiry_text, stream_id, self.topic_id, self.topic_name, self.partitions_count, compression_algorithm, message_expiry_text, stream_id);
 
         command_state.success().stdout(diff(message));
     }
@@ -114,6 +134,11 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
         assert_eq!(topic_details.id, self.topic_id);
         assert_eq!(topic_details.partitions_count, self.partitions_count);
         assert_eq!(topic_details.messages_count, 0);
+        assert_eq!(
+            topic_details.compression_algorithm,
+            self.compression_algorithm.unwrap_or_default()
+        );
+        assert_eq!(topic_details.compaction_enabled, self.compaction_enabled);
 
         if self.message_expiry.is_some() {
             let duration: Duration = *self
@@ -145,6 +170,8 @@ pub async fn should_be_successful() {
             String::from("sync"),
             1,
             None,
+            None,
+            false,
             TestStreamId::Numeric,
         ))
         .await;
@@ -156,6 +183,8 @@ pub async fn should_be_successful() {
             String::from("topic"),
             5,
             None,
+            None,
+            false,
             TestStreamId::Named,
         ))
         .await;
@@ -167,6 +196,8 @@ pub async fn should_be_successful() {
             String::from("named"),
             1,
             Some(vec![String::from("3days"), String::from("5s")]),
+            Some(CompressionAlgorithm::Lz4),
+            false,
             TestStreamId::Named,
         ))
         .await;
@@ -183,6 +214,8 @@ pub async fn should_be_successful() {
                 String::from("1min"),
                 String::from("1sec"),
             ]),
+            Some(CompressionAlgorithm::Zstd),
+            true,
             TestStreamId::Numeric,
         ))
         .await;
@@ -206,8 +239,11 @@ Examples
  iggy topic create 1 1 2 sensor1 15days
  iggy topic create prod 2 2 sensor2
  iggy topic create test 3 2 debugs 1day 1hour 1min 1sec
+ iggy topic create -c zstd prod 4 2 logs 7days
+ iggy topic create --compaction prod 5 2 changelog
+ iggy topic create --max-topic-size 10GB --max-partition-size 2GB prod 6 5 metrics
 
-{USAGE_PREFIX} topic create <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
+{USAGE_PREFIX} topic create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
 
 Arguments:
   <STREAM_ID>
@@ -228,6 +264,20 @@ Arguments:
           Message expiry time in human readable format like 15days 2min 2s ("none" or skipping parameter disables message expiry functionality in topic)
 
 Options:
+  -c, --compression-algorithm <COMPRESSION_ALGORITHM>
+          Compression algorithm used for the messages stored on disk (none, lz4 or zstd)
+{CLAP_INDENT}
+          [default: none]
+
+      --compaction
+          Enable log compaction, which keeps only the latest message for each messages key
+
+      --max-topic-size <MAX_TOPIC_SIZE>
+          Maximum size of the topic in human readable format like 10GB or 512MiB, the oldest segments are deleted once it is exceeded
+
+      --max-partition-size <MAX_PARTITION_SIZE>
+          Maximum size of every partition in the topic in human readable format like 1GB, the oldest segments of the partition are deleted once it is exceeded
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -248,7 +298,7 @@ pub async fn should_short_help_match() {
                 r#"Create topic with given ID, name, number of partitions
 and expiry time for given stream ID
 
-{USAGE_PREFIX} topic create <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
+{USAGE_PREFIX} topic create [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITIONS_COUNT> <NAME> [MESSAGE_EXPIRY]...
 
 Arguments:
   <STREAM_ID>          Stream ID to create topic
@@ -258,7 +308,16 @@ Arguments:
   [MESSAGE_EXPIRY]...  Message expiry time in human readable format like 15days 2min 2s ("none" or skipping parameter disables message expiry functionality in topic)
 
 Options:
-  -h, --help  Print help (see more with '--help')
+  -c, --compression-algorithm <COMPRESSION_ALGORITHM>
+          Compression algorithm used for the messages stored on disk (none, lz4 or zstd) [default: none]
+      --compaction
+          Enable log compaction, which keeps only the latest message for each messages key
+      --max-topic-size <MAX_TOPIC_SIZE>
+          Maximum size of the topic in human readable format like 10GB or 512MiB, the oldest segments are deleted once it is exceeded
+      --max-partition-size <MAX_PARTITION_SIZE>
+          Maximum size of every partition in the topic in human readable format like 1GB, the oldest segments of the partition are deleted once it is exceeded
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -4,6 +4,7 @@ use crate::cmd::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -73,6 +74,10 @@ impl IggyCmdTestCase for TestTopicGetCmd {
                 partitions_count: 1,
                 name: self.topic_name.clone(),
                 message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -105,15 +110,23 @@ impl IggyCmdTestCase for TestTopicGetCmd {
         command_state
             .success()
             .stdout(starts_with(start_message))
-            .stdout(contains(format!("Topic id            | {}", self.topic_id)))
             .stdout(contains(format!(
-                "Topic name          | {}",
+                "Topic id              | {}",
+                self.topic_id
+            )))
+            .stdout(contains(format!(
+                "Topic name            | {}",
                 self.topic_name
             )))
-            .stdout(contains("Topic size          | 0"))
-            .stdout(contains("Message expiry      | None"))
-            .stdout(contains("Topic message count | 0"))
-            .stdout(contains("Partitions count    | 1"));
+            .stdout(contains("Topic size            | 0"))
+            .stdout(contains("Message expiry        | None"))
+            .stdout(contains("Compression algorithm | none"))
+            .stdout(contains("Compression ratio     | 1.00"))
+            .stdout(contains("Compaction enabled    | false"))
+            .stdout(contains("Max topic size        | None"))
+            .stdout(contains("Max partition size    | None"))
+            .stdout(contains("Topic message count   | 0"))
+            .stdout(contains("Partitions count      | 1"));
     }
 
     async fn verify_server_state(&self, client: &dyn Client) {
@@ -4,8 +4,10 @@ use crate::cmd::common::{
 };
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
+use byte_unit::Byte;
 use humantime::format_duration;
 use humantime::Duration as HumanDuration;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -24,6 +26,7 @@ struct TestTopicUpdateCmd {
     message_expiry: Option<Vec<String>>,
     topic_new_name: String,
     topic_new_message_expiry: Option<Vec<String>>,
+    topic_new_max_topic_size: Option<String>,
     using_stream_id: TestStreamId,
     using_topic_id: TestTopicId,
 }
@@ -38,6 +41,7 @@ impl TestTopicUpdateCmd {
         message_expiry: Option<Vec<String>>,
         topic_new_name: String,
         topic_new_message_expiry: Option<Vec<String>>,
+        topic_new_max_topic_size: Option<String>,
         using_stream_id: TestStreamId,
         using_topic_id: TestTopicId,
     ) -> Self {
@@ -49,16 +53,23 @@ impl TestTopicUpdateCmd {
             message_expiry,
             topic_new_name,
             topic_new_message_expiry,
+            topic_new_max_topic_size,
             using_stream_id,
             using_topic_id,
         }
     }
 
     fn to_args(&self) -> Vec<String> {
-        let mut command = match self.using_stream_id {
-            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
-            TestStreamId::Named => vec![self.stream_name.clone()],
-        };
+        let mut command = Vec::new();
+        if let Some(max_topic_size) = &self.topic_new_max_topic_size {
+            command.push(String::from("--max-topic-size"));
+            command.push(max_topic_size.clone());
+        }
+
+        command.push(match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        });
 
         command.push(match self.using_topic_id {
             TestTopicId::Numeric => format!("{}", self.topic_id),
@@ -103,6 +114,10 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
                 partitions_count: 1,
                 name: self.topic_name.clone(),
                 message_expiry,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
             })
             .await;
         assert!(topic.is_ok());
@@ -157,6 +172,12 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
         assert_eq!(topic_details.name, self.topic_new_name);
         assert_eq!(topic_details.id, self.topic_id);
         assert_eq!(topic_details.messages_count, 0);
+        assert_eq!(
+            topic_details.max_topic_size,
+            self.topic_new_max_topic_size
+                .as_ref()
+                .map(|size| Byte::from_str(size).unwrap().get_bytes() as u64)
+        );
 
         if self.topic_new_message_expiry.is_some() {
             let duration: Duration = *self
@@ -204,6 +225,7 @@ pub async fn should_be_successful() {
             None,
             String::from("new_name"),
             None,
+            None,
             TestStreamId::Numeric,
             TestTopicId::Numeric,
         ))
@@ -217,6 +239,7 @@ pub async fn should_be_successful() {
             None,
             String::from("testing"),
             None,
+            None,
             TestStreamId::Named,
             TestTopicId::Numeric,
         ))
@@ -230,6 +253,7 @@ pub async fn should_be_successful() {
             None,
             String::from("development"),
             None,
+            None,
             TestStreamId::Numeric,
             TestTopicId::Named,
         ))
@@ -248,6 +272,7 @@ pub async fn should_be_successful() {
                 String::from("1min"),
                 String::from("1sec"),
             ]),
+            Some(String::from("5GB")),
             TestStreamId::Numeric,
             TestTopicId::Numeric,
         ))
@@ -261,6 +286,7 @@ pub async fn should_be_successful() {
             Some(vec![String::from("1s")]),
             String::from("testing"),
             Some(vec![String::from("66sec")]),
+            None,
             TestStreamId::Numeric,
             TestTopicId::Numeric,
         ))
@@ -278,6 +304,7 @@ pub async fn should_be_successful() {
             ]),
             String::from("testing"),
             None,
+            None,
             TestStreamId::Numeric,
             TestTopicId::Named,
         ))
@@ -304,8 +331,9 @@ Examples
  iggy update test debugs ready 15days
  iggy update 1 1 new-name
  iggy update 1 2 new-name 1day 1hour 1min 1sec
+ iggy topic update --max-topic-size 5GB prod metrics metrics
 
-{USAGE_PREFIX} topic update <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
+{USAGE_PREFIX} topic update [OPTIONS] <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
 
 Arguments:
   <STREAM_ID>
@@ -325,6 +353,12 @@ Arguments:
           New message expiry time in human readable format like 15days 2min 2s ("none" or skipping parameter causes removal of expiry parameter in topic)
 
 Options:
+      --max-topic-size <MAX_TOPIC_SIZE>
+          New maximum size of the topic in human readable format like 10GB or 512MiB (skipping parameter causes removal of the topic size limit)
+
+      --max-partition-size <MAX_PARTITION_SIZE>
+          New maximum size of every partition in the topic in human readable format like 1GB (skipping parameter causes removal of the partition size limit)
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -344,7 +378,7 @@ pub async fn should_short_help_match() {
             format!(
                 r#"Update topic name an message expiry time for given topic ID in given stream ID
 
-{USAGE_PREFIX} topic update <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
+{USAGE_PREFIX} topic update [OPTIONS] <STREAM_ID> <TOPIC_ID> <NAME> [MESSAGE_EXPIRY]...
 
 Arguments:
   <STREAM_ID>          Stream ID to update topic
@@ -353,7 +387,12 @@ Arguments:
   [MESSAGE_EXPIRY]...  New message expiry time in human readable format like 15days 2min 2s ("none" or skipping parameter causes removal of expiry parameter in topic)
 
 Options:
-  -h, --help  Print help (see more with '--help')
+      --max-topic-size <MAX_TOPIC_SIZE>
+          New maximum size of the topic in human readable format like 10GB or 512MiB (skipping parameter causes removal of the topic size limit)
+      --max-partition-size <MAX_PARTITION_SIZE>
+          New maximum size of every partition in the topic in human readable format like 1GB (skipping parameter causes removal of the partition size limit)
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -0,0 +1,8 @@
+mod test_user_create_command;
+mod test_user_delete_command;
+mod test_user_get_command;
+mod test_user_help_command;
+mod test_user_list_command;
+mod test_user_password_command;
+mod test_user_permissions_command;
+mod test_user_update_command;
@@ -0,0 +1,271 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use iggy::models::user_status::UserStatus;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::collections::HashMap;
+
+struct TestUserCreateCmd {
+    username: String,
+    password: String,
+    status: UserStatus,
+    permissions_args: Vec<String>,
+    permissions: Option<Permissions>,
+}
+
+impl TestUserCreateCmd {
+    fn new(
+        username: String,
+        password: String,
+        status: UserStatus,
+        permissions_args: Vec<String>,
+        permissions: Option<Permissions>,
+    ) -> Self {
+        Self {
+            username,
+            password,
+            status,
+            permissions_args,
+            permissions,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![self.username.clone(), self.password.clone()];
+
+        if self.status != UserStatus::default() {
+            command.push("--user-status".into());
+            command.push(format!("{}", self.status));
+        }
+
+        command.extend(self.permissions_args.clone());
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserCreateCmd {
+    async fn prepare_server_state(&self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("create")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing create user with username: {} and status: {}\nUser with username: {} and status: {} created\n",
+            self.username, self.status, self.username, self.status
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap();
+        assert_eq!(user.username, self.username);
+        assert_eq!(user.status, self.status);
+        assert_eq!(user.permissions, self.permissions);
+
+        let delete = client
+            .delete_user(&DeleteUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserCreateCmd::new(
+            String::from("producer"),
+            String::from("secret"),
+            UserStatus::Active,
+            vec![],
+            None,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserCreateCmd::new(
+            String::from("guest"),
+            String::from("guess"),
+            UserStatus::Inactive,
+            vec![],
+            None,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserCreateCmd::new(
+            String::from("admin"),
+            String::from("P@ssw0rd"),
+            UserStatus::Active,
+            vec!["-g".into(), "m_srv,r_srv,m_usr".into()],
+            Some(Permissions {
+                global: GlobalPermissions {
+                    manage_servers: true,
+                    read_servers: true,
+                    manage_users: true,
+                    ..GlobalPermissions::default()
+                },
+                streams: None,
+            }),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserCreateCmd::new(
+            String::from("consumer"),
+            String::from("pass#1%X!"),
+            UserStatus::Active,
+            vec![
+                "--global-permissions".into(),
+                "r_str".into(),
+                "-s".into(),
+                "1:r_top,p_msg".into(),
+                "-s".into(),
+                "2#1:p_msg,s_msg".into(),
+            ],
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    ..GlobalPermissions::default()
+                },
+                streams: Some(HashMap::from([
+                    (
+                        1,
+                        StreamPermissions {
+                            read_topics: true,
+                            poll_messages: true,
+                            ..StreamPermissions::default()
+                        },
+                    ),
+                    (
+                        2,
+                        StreamPermissions {
+                            topics: Some(HashMap::from([(
+                                1,
+                                TopicPermissions {
+                                    poll_messages: true,
+                                    send_messages: true,
+                                    ..TopicPermissions::default()
+                                },
+                            )])),
+                            ..StreamPermissions::default()
+                        },
+                    ),
+                ])),
+            }),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "create", "--help"],
+            format!(
+                r#"Create user with given username and password
+
+Permissions are granted with the compact syntax, global permissions are
+a comma separated list of: m_srv, r_srv, m_usr, r_usr, m_str, r_str,
+m_top, r_top, p_msg, s_msg (manage or read servers, users, streams
+and topics, poll and send messages). Stream permissions are specified as
+stream_id[:permissions][#topic_id[:permissions]]... where the stream
+permissions are m_str, r_str, m_top, r_top, p_msg, s_msg and the topic
+permissions are m_top, r_top, p_msg, s_msg.
+
+Examples
+ iggy user create testuser pass#1%X!
+ iggy user create guest guess --user-status inactive
+ iggy user create admin secret -g m_srv,m_usr,m_str
+ iggy user create reader secret -s 1:r_str,p_msg -s 2#1:p_msg#2:p_msg,s_msg
+
+{USAGE_PREFIX} user create [OPTIONS] <USERNAME> <PASSWORD>
+
+Arguments:
+  <USERNAME>
+          Username
+
+  <PASSWORD>
+          Password
+
+Options:
+  -u, --user-status <USER_STATUS>
+          User status
+{CLAP_INDENT}
+          [default: active]
+          [possible values: active, inactive]
+
+  -g, --global-permissions <GLOBAL_PERMISSIONS>
+          Global permissions, comma separated list of: m_srv, r_srv, m_usr, r_usr, m_str, r_str, m_top, r_top, p_msg, s_msg
+
+  -s, --stream-permissions <STREAM_PERMISSIONS>
+          Permissions to the stream and its topics, specified as stream_id[:permissions][#topic_id[:permissions]]...
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "create", "-h"],
+            format!(
+                r#"Create user with given username and password
+
+{USAGE_PREFIX} user create [OPTIONS] <USERNAME> <PASSWORD>
+
+Arguments:
+  <USERNAME>  Username
+  <PASSWORD>  Password
+
+Options:
+  -u, --user-status <USER_STATUS>
+          User status [default: active] [possible values: active, inactive]
+  -g, --global-permissions <GLOBAL_PERMISSIONS>
+          Global permissions, comma separated list of: m_srv, r_srv, m_usr, r_usr, m_str, r_str, m_top, r_top, p_msg, s_msg
+  -s, --stream-permissions <STREAM_PERMISSIONS>
+          Permissions to the stream and its topics, specified as stream_id[:permissions][#topic_id[:permissions]]...
+  -h, --help
+          Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,136 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::models::user_status::UserStatus;
+use iggy::users::create_user::CreateUser;
+use iggy::users::get_users::GetUsers;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserDeleteCmd {
+    username: String,
+}
+
+impl TestUserDeleteCmd {
+    fn new(username: String) -> Self {
+        Self { username }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![self.username.clone()]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserDeleteCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                password: String::from("secret"),
+                status: UserStatus::Active,
+                permissions: None,
+            })
+            .await;
+        assert!(user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("delete")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing delete user with ID: {}\nUser with ID: {} deleted\n",
+            self.username, self.username
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let users = client.get_users(&GetUsers {}).await;
+        assert!(users.is_ok());
+        let users = users.unwrap();
+        assert!(users.iter().all(|user| user.username != self.username));
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserDeleteCmd::new(String::from("producer")))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserDeleteCmd::new(String::from("testuser")))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "delete", "--help"],
+            format!(
+                r#"Delete user with given ID
+
+User ID can be specified as a username or ID
+
+Examples
+ iggy user delete 2
+ iggy user delete testuser
+
+{USAGE_PREFIX} user delete <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to delete
+{CLAP_INDENT}
+          User ID can be specified as a username or ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "delete", "-h"],
+            format!(
+                r#"Delete user with given ID
+
+{USAGE_PREFIX} user delete <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to delete
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
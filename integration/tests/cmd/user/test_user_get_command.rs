@@ -0,0 +1,210 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use iggy::models::user_status::UserStatus;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{is_match, starts_with};
+use serial_test::parallel;
+use std::collections::HashMap;
+
+struct TestUserGetCmd {
+    username: String,
+    status: UserStatus,
+    permissions: Option<Permissions>,
+    expected_permissions: Vec<(&'static str, &'static str)>,
+}
+
+impl TestUserGetCmd {
+    fn new(
+        username: String,
+        status: UserStatus,
+        permissions: Option<Permissions>,
+        expected_permissions: Vec<(&'static str, &'static str)>,
+    ) -> Self {
+        Self {
+            username,
+            status,
+            permissions,
+            expected_permissions,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![self.username.clone()]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserGetCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                password: String::from("secret"),
+                status: self.status,
+                permissions: self.permissions.clone(),
+            })
+            .await;
+        assert!(user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("get")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let start_message = format!("Executing get user with ID: {}", self.username);
+
+        let mut command_state = command_state
+            .success()
+            .stdout(starts_with(start_message))
+            .stdout(is_match(format!(r"Status\s+\| {} ", self.status)).unwrap())
+            .stdout(is_match(format!(r"Username\s+\| {} ", self.username)).unwrap());
+
+        for (property, value) in self.expected_permissions.iter() {
+            command_state =
+                command_state.stdout(is_match(format!(r"{property}\s+\| {value} ")).unwrap());
+        }
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let delete = client
+            .delete_user(&DeleteUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserGetCmd::new(
+            String::from("producer"),
+            UserStatus::Active,
+            None,
+            vec![("Permissions", "None")],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserGetCmd::new(
+            String::from("guest"),
+            UserStatus::Inactive,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_servers: true,
+                    read_users: true,
+                    ..GlobalPermissions::default()
+                },
+                streams: None,
+            }),
+            vec![("Global permissions", "r_srv,r_usr")],
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserGetCmd::new(
+            String::from("consumer"),
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions::default(),
+                streams: Some(HashMap::from([
+                    (
+                        2,
+                        StreamPermissions {
+                            read_stream: true,
+                            poll_messages: true,
+                            topics: Some(HashMap::from([(
+                                3,
+                                TopicPermissions {
+                                    send_messages: true,
+                                    ..TopicPermissions::default()
+                                },
+                            )])),
+                            ..StreamPermissions::default()
+                        },
+                    ),
+                    (1, StreamPermissions::default()),
+                ])),
+            }),
+            vec![
+                ("Global permissions", "none"),
+                ("Stream 1 permissions", "1:none"),
+                ("Stream 2 permissions", "2:r_str,p_msg#3:s_msg"),
+            ],
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "get", "--help"],
+            format!(
+                r#"Get details of a single user with given ID
+
+User ID can be specified as a username or ID
+
+Examples
+ iggy user get 2
+ iggy user get testuser
+
+{USAGE_PREFIX} user get <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to get
+{CLAP_INDENT}
+          User ID can be specified as a username or ID
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "get", "-h"],
+            format!(
+                r#"Get details of a single user with given ID
+
+{USAGE_PREFIX} user get <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to get
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
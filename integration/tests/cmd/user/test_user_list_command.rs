@@ -0,0 +1,156 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, OutputFormat, TestHelpCmd, CLAP_INDENT,
+    USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::user_status::UserStatus;
+use iggy::users::create_user::CreateUser;
+use iggy::users::defaults::DEFAULT_ROOT_USERNAME;
+use iggy::users::delete_user::DeleteUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestUserListCmd {
+    username: String,
+    output: OutputFormat,
+}
+
+impl TestUserListCmd {
+    fn new(username: String, output: OutputFormat) -> Self {
+        Self { username, output }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        self.output
+            .to_args()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserListCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                password: String::from("secret"),
+                status: UserStatus::Active,
+                permissions: None,
+            })
+            .await;
+        assert!(user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("list")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(starts_with(format!(
+                "Executing list users in {} mode",
+                self.output
+            )))
+            .stdout(contains(DEFAULT_ROOT_USERNAME))
+            .stdout(contains(self.username.clone()));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let delete = client
+            .delete_user(&DeleteUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserListCmd::new(
+            String::from("producer"),
+            OutputFormat::Default,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserListCmd::new(
+            String::from("consumer"),
+            OutputFormat::List,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserListCmd::new(
+            String::from("testuser"),
+            OutputFormat::Table,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "list", "--help"],
+            format!(
+                r#"List all users
+
+Examples
+ iggy user list
+ iggy user list --list-mode table
+ iggy user list -l table
+
+{USAGE_PREFIX} user list [OPTIONS]
+
+Options:
+  -l, --list-mode <LIST_MODE>
+          List mode (table or list)
+{CLAP_INDENT}
+          [default: table]
+          [possible values: table, list]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "list", "-h"],
+            format!(
+                r#"List all users
+
+{USAGE_PREFIX} user list [OPTIONS]
+
+Options:
+  -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
+  -h, --help                   Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
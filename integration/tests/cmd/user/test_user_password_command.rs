@@ -0,0 +1,175 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::user_status::UserStatus;
+use iggy::users::change_password::ChangePassword;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserPasswordCmd {
+    username: String,
+    password: String,
+    new_password: String,
+}
+
+impl TestUserPasswordCmd {
+    fn new(username: String, password: String, new_password: String) -> Self {
+        Self {
+            username,
+            password,
+            new_password,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![
+            self.username.clone(),
+            self.password.clone(),
+            self.new_password.clone(),
+        ]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserPasswordCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                password: self.password.clone(),
+                status: UserStatus::Active,
+                permissions: None,
+            })
+            .await;
+        assert!(user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("password")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing change password for user with ID: {}\nPassword for user with ID: {} changed\n",
+            self.username, self.username
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        // Changing the password back succeeds only if the new password is the current one
+        let change_password = client
+            .change_password(&ChangePassword {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+                current_password: self.new_password.clone(),
+                new_password: self.password.clone(),
+            })
+            .await;
+        assert!(change_password.is_ok());
+
+        let delete = client
+            .delete_user(&DeleteUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserPasswordCmd::new(
+            String::from("producer"),
+            String::from("secret"),
+            String::from("n3w$ecret"),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserPasswordCmd::new(
+            String::from("testuser"),
+            String::from("pass#1%X!"),
+            String::from("P@ssw0rd"),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "password", "--help"],
+            format!(
+                r#"Change password for user with given ID
+
+User ID can be specified as a username or ID
+
+Examples
+ iggy user password 2 current_password new_password
+ iggy user password client secret n3w$ecret
+
+{USAGE_PREFIX} user password <USER_ID> <CURRENT_PASSWORD> <NEW_PASSWORD>
+
+Arguments:
+  <USER_ID>
+          User ID to change password
+{CLAP_INDENT}
+          User ID can be specified as a username or ID
+
+  <CURRENT_PASSWORD>
+          Current password
+
+  <NEW_PASSWORD>
+          New password
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "password", "-h"],
+            format!(
+                r#"Change password for user with given ID
+
+{USAGE_PREFIX} user password <USER_ID> <CURRENT_PASSWORD> <NEW_PASSWORD>
+
+Arguments:
+  <USER_ID>           User ID to change password
+  <CURRENT_PASSWORD>  Current password
+  <NEW_PASSWORD>      New password
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,239 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::permissions::{
+    GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
+};
+use iggy::models::user_status::UserStatus;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+use std::collections::HashMap;
+
+struct TestUserPermissionsCmd {
+    username: String,
+    initial_permissions: Option<Permissions>,
+    permissions_args: Vec<String>,
+    permissions: Option<Permissions>,
+}
+
+impl TestUserPermissionsCmd {
+    fn new(
+        username: String,
+        initial_permissions: Option<Permissions>,
+        permissions_args: Vec<String>,
+        permissions: Option<Permissions>,
+    ) -> Self {
+        Self {
+            username,
+            initial_permissions,
+            permissions_args,
+            permissions,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![self.username.clone()];
+        command.extend(self.permissions_args.clone());
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserPermissionsCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                password: String::from("secret"),
+                status: UserStatus::Active,
+                permissions: self.initial_permissions.clone(),
+            })
+            .await;
+        assert!(user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("permissions")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing update permissions for user with ID: {}\nPermissions for user with ID: {} updated\n",
+            self.username, self.username
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        assert_eq!(user.unwrap().permissions, self.permissions);
+
+        let delete = client
+            .delete_user(&DeleteUser {
+                user_id: Identifier::named(self.username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserPermissionsCmd::new(
+            String::from("admin"),
+            None,
+            vec!["-g".into(), "m_srv,r_srv".into()],
+            Some(Permissions {
+                global: GlobalPermissions {
+                    manage_servers: true,
+                    read_servers: true,
+                    ..GlobalPermissions::default()
+                },
+                streams: None,
+            }),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserPermissionsCmd::new(
+            String::from("producer"),
+            None,
+            vec![
+                "--global-permissions".into(),
+                "r_str".into(),
+                "--stream-permissions".into(),
+                "3#1:s_msg".into(),
+                "-s".into(),
+                "4:r_top".into(),
+            ],
+            Some(Permissions {
+                global: GlobalPermissions {
+                    read_streams: true,
+                    ..GlobalPermissions::default()
+                },
+                streams: Some(HashMap::from([
+                    (
+                        3,
+                        StreamPermissions {
+                            topics: Some(HashMap::from([(
+                                1,
+                                TopicPermissions {
+                                    send_messages: true,
+                                    ..TopicPermissions::default()
+                                },
+                            )])),
+                            ..StreamPermissions::default()
+                        },
+                    ),
+                    (
+                        4,
+                        StreamPermissions {
+                            read_topics: true,
+                            ..StreamPermissions::default()
+                        },
+                    ),
+                ])),
+            }),
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserPermissionsCmd::new(
+            String::from("guest"),
+            Some(Permissions::root()),
+            vec![],
+            None,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "permissions", "--help"],
+            format!(
+                r#"Set permissions for user with given ID
+
+User ID can be specified as a username or ID
+Permissions use the same compact syntax as in the create command,
+skipping them removes all the permissions of the user.
+
+Examples
+ iggy user permissions 2 -g m_srv,r_srv
+ iggy user permissions client -s 1:p_msg,s_msg
+ iggy user permissions producer -g r_str -s 3#1:s_msg -s 4:r_top
+
+{USAGE_PREFIX} user permissions [OPTIONS] <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to set permissions
+{CLAP_INDENT}
+          User ID can be specified as a username or ID
+
+Options:
+  -g, --global-permissions <GLOBAL_PERMISSIONS>
+          Global permissions, comma separated list of: m_srv, r_srv, m_usr, r_usr, m_str, r_str, m_top, r_top, p_msg, s_msg
+
+  -s, --stream-permissions <STREAM_PERMISSIONS>
+          Permissions to the stream and its topics, specified as stream_id[:permissions][#topic_id[:permissions]]...
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "permissions", "-h"],
+            format!(
+                r#"Set permissions for user with given ID
+
+{USAGE_PREFIX} user permissions [OPTIONS] <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to set permissions
+
+Options:
+  -g, --global-permissions <GLOBAL_PERMISSIONS>
+          Global permissions, comma separated list of: m_srv, r_srv, m_usr, r_usr, m_str, r_str, m_top, r_top, p_msg, s_msg
+  -s, --stream-permissions <STREAM_PERMISSIONS>
+          Permissions to the stream and its topics, specified as stream_id[:permissions][#topic_id[:permissions]]...
+  -h, --help
+          Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,240 @@
+use crate::cmd::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::models::user_quota::UserQuota;
+use iggy::models::user_status::UserStatus;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::{client::Client, identifier::Identifier};
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestUserUpdateCmd {
+    username: String,
+    new_username: Option<String>,
+    new_status: Option<UserStatus>,
+    new_quota: Option<UserQuota>,
+}
+
+impl TestUserUpdateCmd {
+    fn new(
+        username: String,
+        new_username: Option<String>,
+        new_status: Option<UserStatus>,
+        new_quota: Option<UserQuota>,
+    ) -> Self {
+        Self {
+            username,
+            new_username,
+            new_status,
+            new_quota,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![self.username.clone()];
+
+        if let Some(username) = &self.new_username {
+            command.push("--username".into());
+            command.push(username.clone());
+        }
+
+        if let Some(status) = &self.new_status {
+            command.push("-u".into());
+            command.push(format!("{}", status));
+        }
+
+        if let Some(quota) = &self.new_quota {
+            command.push("-q".into());
+            command.push(format!("{}", quota));
+        }
+
+        command
+    }
+
+    fn changes(&self) -> String {
+        let mut changes = vec![];
+        if let Some(username) = &self.new_username {
+            changes.push(format!("username: {username}"));
+        }
+        if let Some(status) = &self.new_status {
+            changes.push(format!("status: {status}"));
+        }
+        if let Some(quota) = &self.new_quota {
+            changes.push(format!("quota: {quota}"));
+        }
+        changes.join(" and ")
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestUserUpdateCmd {
+    async fn prepare_server_state(&self, client: &dyn Client) {
+        let user = client
+            .create_user(&CreateUser {
+                username: self.username.clone(),
+                password: String::from("secret"),
+                status: UserStatus::Active,
+                permissions: None,
+            })
+            .await;
+        assert!(user.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("user")
+            .arg("update")
+            .args(self.to_args())
+            .with_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing update user with ID: {} with {}\nUser with ID: {} updated with {}\n",
+            self.username,
+            self.changes(),
+            self.username,
+            self.changes()
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let username = self.new_username.as_ref().unwrap_or(&self.username);
+        let user = client
+            .get_user(&GetUser {
+                user_id: Identifier::named(username.as_str()).unwrap(),
+            })
+            .await;
+        assert!(user.is_ok());
+        let user = user.unwrap();
+        assert_eq!(&user.username, username);
+        assert_eq!(user.status, self.new_status.unwrap_or(UserStatus::Active));
+
+        let delete = client
+            .delete_user(&DeleteUser {
+                user_id: Identifier::numeric(user.id).unwrap(),
+            })
+            .await;
+        assert!(delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestUserUpdateCmd::new(
+            String::from("producer"),
+            Some(String::from("publisher")),
+            None,
+            None,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserUpdateCmd::new(
+            String::from("guest"),
+            None,
+            Some(UserStatus::Inactive),
+            None,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserUpdateCmd::new(
+            String::from("testuser"),
+            Some(String::from("consumer")),
+            Some(UserStatus::Inactive),
+            None,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestUserUpdateCmd::new(
+            String::from("limited"),
+            None,
+            None,
+            Some(UserQuota::new(1048576, 100)),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "update", "--help"],
+            format!(
+                r#"Update username, status and quota of the user with given ID
+
+User ID can be specified as a username or ID
+
+Examples
+ iggy user update 2 --username new_name
+ iggy user update testuser -u inactive
+ iggy user update guest -n user -u active
+ iggy user update producer -q 1048576:100
+
+{USAGE_PREFIX} user update [OPTIONS] <USER_ID>
+
+Arguments:
+  <USER_ID>
+          User ID to update
+{CLAP_INDENT}
+          User ID can be specified as a username or ID
+
+Options:
+  -n, --username <USERNAME>
+          New username
+
+  -u, --user-status <USER_STATUS>
+          New user status
+{CLAP_INDENT}
+          [possible values: active, inactive]
+
+  -q, --quota <QUOTA>
+          New user quota as bytes_per_second:requests_per_second, 0 means unlimited
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["user", "update", "-h"],
+            format!(
+                r#"Update username, status and quota of the user with given ID
+
+{USAGE_PREFIX} user update [OPTIONS] <USER_ID>
+
+Arguments:
+  <USER_ID>  User ID to update
+
+Options:
+  -n, --username <USERNAME>        New username
+  -u, --user-status <USER_STATUS>  New user status [possible values: active, inactive]
+  -q, --quota <QUOTA>              New user quota as bytes_per_second:requests_per_second, 0 means unlimited
+  -h, --help                       Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -1,4 +1,8 @@
-use crate::server::scenarios::{message_headers_scenario, system_scenario, user_scenario};
+use crate::server::scenarios::{
+    consumer_lag_scenario, delayed_messages_scenario, long_polling_scenario,
+    message_filter_scenario, message_headers_scenario, schema_registry_scenario, system_scenario,
+    transactions_scenario, user_scenario,
+};
 use crate::utils::http_client::HttpClientFactory;
 use crate::utils::test_server::TestServer;
 use serial_test::parallel;
@@ -32,3 +36,63 @@ async fn message_headers_scenario_should_be_valid() {
     let client_factory = HttpClientFactory { server_addr };
     message_headers_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn message_filter_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    message_filter_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_lag_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    consumer_lag_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn delayed_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    delayed_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn long_polling_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(Some(long_polling_scenario::get_server_envs()));
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    long_polling_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn transactions_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    transactions_scenario::run(&client_factory).await;
+}
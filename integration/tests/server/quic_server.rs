@@ -1,7 +1,10 @@
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, message_headers_scenario,
-    system_scenario, user_scenario,
+    consumer_group_join_scenario, consumer_group_session_timeout_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, consumer_lag_scenario,
+    dead_letter_topic_scenario, delayed_messages_scenario, long_polling_scenario,
+    message_filter_scenario, message_headers_scenario, quota_scenario, request_pipelining_scenario,
+    schema_registry_scenario, system_scenario, transactions_scenario, user_scenario,
 };
 use crate::utils::quic_client::QuicClientFactory;
 use crate::utils::test_server::TestServer;
@@ -37,6 +40,46 @@ async fn message_headers_scenario_should_be_valid() {
     message_headers_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn message_filter_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    message_filter_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_lag_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    consumer_lag_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn delayed_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    delayed_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn consumer_group_join_scenario_should_be_valid() {
@@ -47,6 +90,16 @@ async fn consumer_group_join_scenario_should_be_valid() {
     consumer_group_join_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn consumer_group_session_timeout_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    consumer_group_session_timeout_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn consumer_group_with_single_client_polling_messages_scenario_should_be_valid() {
@@ -66,3 +119,53 @@ async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_b
     let client_factory = QuicClientFactory { server_addr };
     consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn long_polling_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(Some(long_polling_scenario::get_server_envs()));
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    long_polling_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn transactions_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    transactions_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn dead_letter_topic_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    dead_letter_topic_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn request_pipelining_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    request_pipelining_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn quota_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(Some(quota_scenario::get_server_envs()));
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    quota_scenario::run(&client_factory).await;
+}
@@ -0,0 +1,267 @@
+use crate::utils::tcp_client::TcpClientFactory;
+use crate::utils::test_server::{login_root, ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{ClusterClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::cluster::get_cluster_metadata::GetClusterMetadata;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::models::cluster::PartitionReplicas;
+use iggy::models::messages::PolledMessages;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 2;
+// The leaders are spread across the nodes, so the first partition is led by the first node and the second one by the second node.
+const FIRST_NODE_PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const WAIT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Creates three nodes, which know about each other, but are not started yet.
+/// The third node keeps the majority of the nodes online, once the first one is stopped,
+/// but it doesn't store the partition, as the replication factor is 2.
+pub fn create_nodes() -> Vec<TestServer> {
+    let mut nodes = vec![
+        TestServer::new(None),
+        TestServer::new(None),
+        TestServer::new(None),
+    ];
+    let cluster_nodes = nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| format!("{}@{}", index + 1, node.get_raw_tcp_addr().unwrap()))
+        .collect::<Vec<_>>()
+        .join(",");
+    for (index, node) in nodes.iter_mut().enumerate() {
+        node.set_env("IGGY_CLUSTER_ENABLED", "true");
+        node.set_env("IGGY_CLUSTER_NODE_ID", &(index + 1).to_string());
+        node.set_env("IGGY_CLUSTER_NODES", &cluster_nodes);
+        node.set_env("IGGY_CLUSTER_HEARTBEAT_INTERVAL", "100");
+        node.set_env("IGGY_CLUSTER_NODE_TIMEOUT", "1000");
+        node.set_env("IGGY_CLUSTER_REPLICATION_INTERVAL", "50");
+        node.set_env("IGGY_CLUSTER_REPLICATION_FACTOR", "2");
+    }
+    nodes
+}
+
+pub async fn run(mut nodes: Vec<TestServer>) {
+    for node in nodes.iter_mut() {
+        node.start();
+    }
+    let first_node = create_client(&nodes[0]).await;
+    let second_node = create_client(&nodes[1]).await;
+    let third_node = create_client(&nodes[2]).await;
+
+    // 1. The node with the lowest ID is the controller, the metadata can't be changed on the other nodes
+    let metadata = first_node
+        .get_cluster_metadata(&GetClusterMetadata {})
+        .await
+        .unwrap();
+    assert_eq!(metadata.node_id, 1);
+    assert_eq!(metadata.controller_id, 1);
+    assert_eq!(metadata.nodes.len(), 3);
+
+    let result = second_node
+        .create_stream(&CreateStream {
+            stream_id: STREAM_ID,
+            name: STREAM_NAME.to_string(),
+        })
+        .await;
+    assert!(
+        matches!(result, Err(Error::InvalidResponse(code)) if code == Error::NotClusterController(1).as_code())
+    );
+
+    // 2. The streams and topics created on the controller are synchronized by the other nodes
+    init_system(&first_node).await;
+    wait_until(|| async {
+        second_node
+            .get_topic(&GetTopic {
+                stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            })
+            .await
+            .is_ok()
+    })
+    .await;
+
+    // 3. The messages can be appended only by the leader of the partition
+    wait_until(|| async { get_replicas(&first_node).await.leader_epoch > 0 }).await;
+    let replicas = get_replicas(&first_node).await;
+    assert_eq!(replicas.leader_id, 1);
+    assert_eq!(replicas.leader_epoch, 1);
+    assert_eq!(replicas.replicas, vec![1, 2]);
+
+    let result = send_messages(&second_node, 0..1, Acks::Leader).await;
+    assert!(
+        matches!(result, Err(Error::InvalidResponse(code)) if code == Error::NotPartitionLeader(FIRST_NODE_PARTITION_ID, TOPIC_ID, STREAM_ID, 1).as_code())
+    );
+
+    // The server responds to the messages sent without acks before appending them, so the error is not returned
+    send_messages(&second_node, 0..1, Acks::None).await.unwrap();
+
+    // 4. The messages appended by the leader are replicated to the followers
+    send_messages(&first_node, 0..MESSAGES_COUNT, Acks::All)
+        .await
+        .unwrap();
+    wait_until(|| async { get_replicas(&first_node).await.in_sync_replicas == vec![1, 2] }).await;
+    wait_until(|| async {
+        poll_messages(&second_node).await.messages.len() == MESSAGES_COUNT as usize
+    })
+    .await;
+
+    let polled_messages = poll_messages(&second_node).await;
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(message.offset, index as u64);
+        assert_eq!(message.payload, Bytes::from(format!("message {index}")));
+    }
+
+    // 5. The messages are not replicated to the node, which is not the replica of the partition
+    wait_until(|| async {
+        third_node
+            .get_topic(&GetTopic {
+                stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+                topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            })
+            .await
+            .is_ok()
+    })
+    .await;
+    assert!(poll_messages(&third_node).await.messages.is_empty());
+
+    // 6. Once the leader is offline, the in-sync replica becomes the new leader, as long as the controller knows it's in sync
+    wait_until(|| async { get_replicas(&second_node).await.in_sync_replicas == vec![1, 2] }).await;
+    let first_node_server = nodes.remove(0);
+    drop(first_node);
+    drop(first_node_server);
+    wait_until(|| async { get_replicas(&second_node).await.leader_id == 2 }).await;
+
+    let metadata = second_node
+        .get_cluster_metadata(&GetClusterMetadata {})
+        .await
+        .unwrap();
+    assert_eq!(metadata.controller_id, 2);
+    let replicas = get_replicas(&second_node).await;
+    assert_eq!(replicas.leader_epoch, 2);
+    assert_eq!(replicas.in_sync_replicas, vec![2]);
+
+    send_messages(&second_node, MESSAGES_COUNT..MESSAGES_COUNT + 1, Acks::All)
+        .await
+        .unwrap();
+    let polled_messages = poll_messages(&second_node).await;
+    assert_eq!(polled_messages.messages.len(), MESSAGES_COUNT as usize + 1);
+    assert_eq!(polled_messages.current_offset, MESSAGES_COUNT as u64);
+}
+
+async fn create_client(node: &TestServer) -> IggyClient {
+    let client_factory = TcpClientFactory {
+        server_addr: node.get_raw_tcp_addr().unwrap(),
+    };
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+    client
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(&CreateStream {
+            stream_id: STREAM_ID,
+            name: STREAM_NAME.to_string(),
+        })
+        .await
+        .unwrap();
+
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: TOPIC_ID,
+            partitions_count: PARTITIONS_COUNT,
+            name: TOPIC_NAME.to_string(),
+            message_expiry: None,
+            compression_algorithm: CompressionAlgorithm::None,
+            compaction_enabled: false,
+            max_topic_size: None,
+            max_partition_size: None,
+        })
+        .await
+        .unwrap();
+}
+
+async fn get_replicas(client: &IggyClient) -> PartitionReplicas {
+    let metadata = client
+        .get_cluster_metadata(&GetClusterMetadata {})
+        .await
+        .unwrap();
+    metadata
+        .get_partition(STREAM_ID, TOPIC_ID, FIRST_NODE_PARTITION_ID)
+        .unwrap()
+        .clone()
+}
+
+async fn send_messages(
+    client: &IggyClient,
+    range: std::ops::Range<u32>,
+    acks: Acks,
+) -> Result<(), Error> {
+    let mut messages = Vec::new();
+    for index in range {
+        messages.push(Message::new(
+            None,
+            Bytes::from(format!("message {index}")),
+            None,
+        ));
+    }
+
+    client
+        .send_messages(&mut SendMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitioning: Partitioning::partition_id(FIRST_NODE_PARTITION_ID),
+            producer: None,
+            transaction_id: None,
+            acks,
+            messages,
+        })
+        .await
+}
+
+async fn poll_messages(client: &IggyClient) -> PolledMessages {
+    client
+        .poll_messages(&PollMessages {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(FIRST_NODE_PARTITION_ID),
+            strategy: PollingStrategy::offset(0),
+            count: MESSAGES_COUNT * 2,
+            auto_commit: false,
+            max_wait: 0,
+            filter: None,
+        })
+        .await
+        .unwrap()
+}
+
+async fn wait_until<F, Fut>(condition: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let deadline = Instant::now() + WAIT_TIMEOUT;
+    while !condition().await {
+        assert!(Instant::now() < deadline, "Condition was not met in time.");
+        sleep(Duration::from_millis(100)).await;
+    }
+}
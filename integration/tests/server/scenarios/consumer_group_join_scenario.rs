@@ -3,9 +3,11 @@ use crate::utils::test_server::{
 };
 use iggy::client::{ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::identifier::Identifier;
 use iggy::models::client_info::ClientInfoDetails;
 use iggy::models::consumer_group::ConsumerGroupDetails;
@@ -47,6 +49,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -56,6 +62,9 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
         name: CONSUMER_GROUP_NAME.to_string(),
+        partition_assignment_strategy: PartitionAssignmentStrategy::default(),
+        session_timeout: None,
+        dead_letter_topic: None,
     };
     system_client
         .create_consumer_group(&create_group)
@@ -165,6 +174,8 @@ async fn get_consumer_group_and_validate_members(
     assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
     assert_eq!(consumer_group.members_count, members_count);
     assert_eq!(consumer_group.members.len() as u32, members_count);
+    // Every member joining the group triggers the rebalance, which increments the generation.
+    assert_eq!(consumer_group.generation, members_count);
 
     consumer_group
 }
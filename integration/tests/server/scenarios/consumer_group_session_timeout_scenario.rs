@@ -0,0 +1,155 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use iggy::client::{ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
+use iggy::identifier::Identifier;
+use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::streams::create_stream::CreateStream;
+use iggy::system::get_me::GetMe;
+use iggy::topics::create_topic::CreateTopic;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 3;
+const CONSUMER_GROUP_ID: u32 = 10;
+const CONSUMER_GROUP_NAME: &str = "test-consumer-group";
+const SESSION_TIMEOUT: u32 = 2;
+const MAX_HEARTBEATS: u32 = 40;
+const HEARTBEAT_INTERVAL_MS: u64 = 500;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let system_client = create_client(client_factory).await;
+    let client1 = create_client(client_factory).await;
+    let client2 = create_client(client_factory).await;
+
+    login_root(&system_client).await;
+    login_root(&client1).await;
+    login_root(&client2).await;
+
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    system_client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
+    };
+    system_client.create_topic(&create_topic).await.unwrap();
+
+    // 3. Create the consumer group with the session timeout
+    let create_group = CreateConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+        name: CONSUMER_GROUP_NAME.to_string(),
+        partition_assignment_strategy: PartitionAssignmentStrategy::Sticky,
+        session_timeout: Some(SESSION_TIMEOUT),
+        dead_letter_topic: None,
+    };
+    system_client
+        .create_consumer_group(&create_group)
+        .await
+        .unwrap();
+
+    // 4. Join the consumer group by both clients and send the heartbeats
+    let join_group = JoinConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+    };
+    client1.join_consumer_group(&join_group).await.unwrap();
+    client2.join_consumer_group(&join_group).await.unwrap();
+    client1
+        .heartbeat_consumer_group(&heartbeat())
+        .await
+        .unwrap();
+    client2
+        .heartbeat_consumer_group(&heartbeat())
+        .await
+        .unwrap();
+
+    let consumer_group = get_consumer_group(&system_client).await;
+    assert_eq!(consumer_group.session_timeout, Some(SESSION_TIMEOUT));
+    assert_eq!(consumer_group.members_count, 2);
+
+    // 5. Keep sending the heartbeats only by client 2, until client 1 is removed from the group
+    let mut consumer_group = consumer_group;
+    for _ in 0..MAX_HEARTBEATS {
+        sleep(Duration::from_millis(HEARTBEAT_INTERVAL_MS)).await;
+        client2
+            .heartbeat_consumer_group(&heartbeat())
+            .await
+            .unwrap();
+        consumer_group = get_consumer_group(&system_client).await;
+        if consumer_group.members_count == 1 {
+            break;
+        }
+    }
+
+    // 6. Validate that client 2 is the only member and all the partitions were reassigned to it
+    let client2_info = client2.get_me(&GetMe {}).await.unwrap();
+    assert_eq!(consumer_group.members_count, 1);
+    let member = &consumer_group.members[0];
+    assert_eq!(member.id, client2_info.client_id);
+    assert_eq!(member.partitions_count, PARTITIONS_COUNT);
+
+    // 7. Validate that client 1 is no longer the member of the group
+    let client1_info = client1.get_me(&GetMe {}).await.unwrap();
+    assert_eq!(client1_info.consumer_groups_count, 0);
+    assert!(client1
+        .heartbeat_consumer_group(&heartbeat())
+        .await
+        .is_err());
+
+    cleanup(&system_client).await;
+    assert_clean_system(&system_client).await;
+}
+
+fn heartbeat() -> HeartbeatConsumerGroup {
+    HeartbeatConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+    }
+}
+
+async fn get_consumer_group(client: &IggyClient) -> ConsumerGroupDetails {
+    let get_group = GetConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+    };
+    client.get_consumer_group(&get_group).await.unwrap()
+}
+
+async fn create_client(client_factory: &dyn ClientFactory) -> IggyClient {
+    let client = client_factory.create_client().await;
+    IggyClient::create(client, IggyClientConfig::default(), None, None, None)
+}
+
+async fn cleanup(system_client: &IggyClient) {
+    let delete_stream = iggy::streams::delete_stream::DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    system_client.delete_stream(&delete_stream).await.unwrap();
+}
@@ -3,13 +3,15 @@ use crate::utils::test_server::{
 };
 use iggy::client::{ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::Consumer;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
@@ -70,6 +72,10 @@ async fn init_system(
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -79,6 +85,9 @@ async fn init_system(
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
         name: CONSUMER_GROUP_NAME.to_string(),
+        partition_assignment_strategy: PartitionAssignmentStrategy::default(),
+        session_timeout: None,
+        dead_letter_topic: None,
     };
     system_client
         .create_consumer_group(&create_group)
@@ -137,6 +146,9 @@ async fn execute_using_messages_key_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
             messages,
         };
         system_client
@@ -163,6 +175,8 @@ async fn poll_messages(client: &IggyClient) -> u32 {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        max_wait: 0,
+        filter: None,
     };
 
     let mut total_read_messages_count = 0;
@@ -198,6 +212,9 @@ async fn execute_using_none_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
             messages,
         };
         system_client
@@ -246,6 +263,8 @@ async fn validate_message_polling(client: &IggyClient, consumer_group: &Consumer
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        max_wait: 0,
+        filter: None,
     };
 
     for i in 1..=MESSAGES_COUNT {
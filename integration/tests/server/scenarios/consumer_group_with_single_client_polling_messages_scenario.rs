@@ -1,13 +1,15 @@
 use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
 use iggy::client::{ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::Consumer;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::system::get_me::GetMe;
@@ -57,6 +59,10 @@ async fn init_system(client: &IggyClient) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -66,6 +72,9 @@ async fn init_system(client: &IggyClient) {
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
         name: CONSUMER_GROUP_NAME.to_string(),
+        partition_assignment_strategy: PartitionAssignmentStrategy::default(),
+        session_timeout: None,
+        dead_letter_topic: None,
     };
     client.create_consumer_group(&create_group).await.unwrap();
 
@@ -108,6 +117,9 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
             messages,
         };
         client.send_messages(&mut send_messages).await.unwrap();
@@ -122,6 +134,8 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        max_wait: 0,
+        filter: None,
     };
 
     let mut total_read_messages_count = 0;
@@ -152,6 +166,9 @@ async fn execute_using_none_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            producer: None,
+            transaction_id: None,
+            acks: Acks::default(),
             messages,
         };
         client.send_messages(&mut send_messages).await.unwrap();
@@ -166,6 +183,8 @@ async fn execute_using_none_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        max_wait: 0,
+        filter: None,
     };
 
     let mut partition_id = 1;
@@ -174,6 +193,7 @@ async fn execute_using_none_key(client: &IggyClient) {
     for _ in 1..=PARTITIONS_COUNT * MESSAGES_COUNT {
         let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
         assert_eq!(polled_messages.messages.len(), 1);
+        assert_eq!(polled_messages.generation, 1);
         let message = &polled_messages.messages[0];
         assert_eq!(message.offset, offset);
         let payload = from_utf8(&message.payload).unwrap();
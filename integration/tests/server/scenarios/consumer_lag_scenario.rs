@@ -0,0 +1,188 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use bytes::Bytes;
+use iggy::client::{
+    ConsumerGroupClient, ConsumerOffsetClient, MessageClient, StreamClient, TopicClient,
+};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::models::consumer_lag::ConsumerLag;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 2;
+const CONSUMER_ID: u32 = 1;
+const CONSUMER_GROUP_ID: u32 = 10;
+const CONSUMER_GROUP_NAME: &str = "test-consumer-group";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send 10 messages to the first partition and 5 messages to the second one
+    send_messages(&client, 1, 10).await;
+    send_messages(&client, 2, 5).await;
+
+    // 2. The consumer without stored offsets has all the messages pending
+    let lag = get_consumer_lag(
+        &client,
+        Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap()),
+    )
+    .await;
+    assert_eq!(lag.total_lag, 15);
+    assert_eq!(lag.partitions.len(), PARTITIONS_COUNT as usize);
+    assert_partition_lag(&lag, 1, 9, None, 10);
+    assert_partition_lag(&lag, 2, 4, None, 5);
+
+    // 3. Store the consumer offset in the first partition and validate that the lag has decreased
+    client
+        .store_consumer_offset(&StoreConsumerOffset {
+            consumer: Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap()),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(1),
+            offset: 3,
+        })
+        .await
+        .unwrap();
+    let lag = get_consumer_lag(
+        &client,
+        Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap()),
+    )
+    .await;
+    assert_eq!(lag.total_lag, 11);
+    assert_partition_lag(&lag, 1, 9, Some(3), 6);
+    assert_partition_lag(&lag, 2, 4, None, 5);
+
+    // 4. The consumer group offsets are tracked separately from the consumer ones
+    let lag = get_consumer_lag(
+        &client,
+        Consumer::group(Identifier::named(CONSUMER_GROUP_NAME).unwrap()),
+    )
+    .await;
+    assert_eq!(lag.total_lag, 15);
+    assert_partition_lag(&lag, 1, 9, None, 10);
+    assert_partition_lag(&lag, 2, 4, None, 5);
+
+    // 5. Getting the lag of the non-existing consumer group should fail
+    let lag = client
+        .get_consumer_lag(&GetConsumerLag {
+            consumer: Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID + 1).unwrap()),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await;
+    assert!(lag.is_err());
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+
+    // 3. Create the consumer group
+    let create_consumer_group = CreateConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+        name: CONSUMER_GROUP_NAME.to_string(),
+        partition_assignment_strategy: PartitionAssignmentStrategy::Sticky,
+        session_timeout: None,
+        dead_letter_topic: None,
+    };
+    client
+        .create_consumer_group(&create_consumer_group)
+        .await
+        .unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32, messages_count: u32) {
+    let mut messages = Vec::new();
+    for offset in 0..messages_count {
+        messages.push(Message::new(
+            None,
+            Bytes::from(format!("message {offset}")),
+            None,
+        ));
+    }
+
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(partition_id),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn get_consumer_lag(client: &IggyClient, consumer: Consumer) -> ConsumerLag {
+    client
+        .get_consumer_lag(&GetConsumerLag {
+            consumer,
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap()
+}
+
+fn assert_partition_lag(
+    lag: &ConsumerLag,
+    partition_id: u32,
+    current_offset: u64,
+    stored_offset: Option<u64>,
+    expected_lag: u64,
+) {
+    let partition = lag
+        .partitions
+        .iter()
+        .find(|partition| partition.partition_id == partition_id)
+        .unwrap();
+    assert_eq!(partition.current_offset, current_offset);
+    assert_eq!(partition.stored_offset, stored_offset);
+    assert_eq!(partition.lag, expected_lag);
+}
@@ -0,0 +1,205 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use bytes::Bytes;
+use iggy::client::{ConsumerGroupClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
+use iggy::identifier::Identifier;
+use iggy::messages::poison_message::PoisonMessage;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::models::consumer_group::DeadLetterTopic;
+use iggy::models::messages::MessageState;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const DEAD_LETTER_TOPIC_ID: u32 = 2;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const DEAD_LETTER_TOPIC_NAME: &str = "test-dead-letter-topic";
+const PARTITION_ID: u32 = 1;
+const CONSUMER_GROUP_ID: u32 = 10;
+const CONSUMER_GROUP_NAME: &str = "test-consumer-group";
+const MESSAGES_COUNT: u32 = 10;
+const POISONED_OFFSET: u64 = 3;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The consumer group exposes its dead letter topic
+    let consumer_group = client
+        .get_consumer_group(&GetConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        consumer_group.dead_letter_topic,
+        Some(DeadLetterTopic {
+            stream_id: STREAM_ID,
+            topic_id: DEAD_LETTER_TOPIC_ID,
+        })
+    );
+
+    // 2. Poll the first message, so that the member gets its partition assigned
+    let polled_messages = client.poll_messages(&get_poll_messages(1)).await.unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+
+    // 3. Mark the message as poisoned
+    client
+        .poison_message(&PoisonMessage {
+            consumer: Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap()),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: None,
+            offset: POISONED_OFFSET,
+        })
+        .await
+        .unwrap();
+
+    // 4. The poisoned message is skipped when polling the next messages by the consumer group
+    let polled_messages = client
+        .poll_messages(&get_poll_messages(MESSAGES_COUNT))
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT - 1);
+    assert!(polled_messages
+        .messages
+        .iter()
+        .all(|message| message.offset != POISONED_OFFSET));
+
+    // 5. The poisoned message is copied to the dead letter topic
+    let polled_messages = client
+        .poll_messages(&PollMessages {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(DEAD_LETTER_TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+            strategy: PollingStrategy::offset(0),
+            count: MESSAGES_COUNT,
+            auto_commit: false,
+            max_wait: 0,
+            filter: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    let message = &polled_messages.messages[0];
+    assert_eq!(message.state, MessageState::Poisoned);
+    assert_eq!(
+        message.payload,
+        Bytes::from(get_payload(POISONED_OFFSET as u32))
+    );
+
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic and the dead letter topic
+    for (topic_id, name) in [
+        (TOPIC_ID, TOPIC_NAME),
+        (DEAD_LETTER_TOPIC_ID, DEAD_LETTER_TOPIC_NAME),
+    ] {
+        let create_topic = CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id,
+            partitions_count: 1,
+            name: name.to_string(),
+            message_expiry: None,
+            compression_algorithm: CompressionAlgorithm::default(),
+            compaction_enabled: false,
+            max_topic_size: None,
+            max_partition_size: None,
+        };
+        client.create_topic(&create_topic).await.unwrap();
+    }
+
+    // 3. Create the consumer group with the dead letter topic
+    let create_group = CreateConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+        name: CONSUMER_GROUP_NAME.to_string(),
+        partition_assignment_strategy: PartitionAssignmentStrategy::default(),
+        session_timeout: None,
+        dead_letter_topic: Some(DeadLetterTopic {
+            stream_id: STREAM_ID,
+            topic_id: DEAD_LETTER_TOPIC_ID,
+        }),
+    };
+    client.create_consumer_group(&create_group).await.unwrap();
+
+    // 4. Join the consumer group
+    let join_group = JoinConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+    };
+    client.join_consumer_group(&join_group).await.unwrap();
+
+    // 5. Send the messages
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let payload = Bytes::from(get_payload(offset));
+        messages.push(Message {
+            id: (offset + 1) as u128,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        });
+    }
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+fn get_poll_messages(count: u32) -> PollMessages {
+    PollMessages {
+        consumer: Consumer::group(Identifier::numeric(CONSUMER_GROUP_ID).unwrap()),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: None,
+        strategy: PollingStrategy::next(),
+        count,
+        auto_commit: false,
+        max_wait: 0,
+        filter: None,
+    }
+}
+
+fn get_payload(offset: u32) -> String {
+    format!("message {}", offset)
+}
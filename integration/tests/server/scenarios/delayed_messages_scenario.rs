@@ -0,0 +1,142 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::timestamp::TimeStamp;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const CONSUMER_ID: u32 = 1;
+const DELAY: Duration = Duration::from_millis(1500);
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send the messages, the second one is delayed
+    let deliver_at = TimeStamp::now().to_micros() + DELAY.as_micros() as u64;
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
+        messages: vec![
+            get_message(0),
+            get_message(1).with_deliver_at(deliver_at).unwrap(),
+            get_message(2),
+        ],
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 2. Poll the next messages, the delayed message is skipped without holding back the following ones
+    let polled_messages = client.poll_messages(&get_poll_messages()).await.unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![0, 2]);
+
+    let polled_messages = client.poll_messages(&get_poll_messages()).await.unwrap();
+    assert!(polled_messages.messages.is_empty());
+
+    // 3. The delayed message can still be polled by offset
+    let mut poll_messages = get_poll_messages();
+    poll_messages.strategy = PollingStrategy::offset(1);
+    poll_messages.auto_commit = false;
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![1, 2]);
+    assert_eq!(
+        polled_messages.messages[0].get_deliver_at(),
+        Some(deliver_at)
+    );
+
+    // 4. Once the delayed message is due, it's delivered on its own and only once
+    sleep(DELAY).await;
+    let polled_messages = client.poll_messages(&get_poll_messages()).await.unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![1]);
+
+    let polled_messages = client.poll_messages(&get_poll_messages()).await.unwrap();
+    assert!(polled_messages.messages.is_empty());
+
+    // 5. The long polling consumer is woken up, once the delayed message becomes due
+    send_messages.messages = vec![get_message(3)
+        .with_deliver_at(TimeStamp::now().to_micros() + DELAY.as_micros() as u64)
+        .unwrap()];
+    client.send_messages(&mut send_messages).await.unwrap();
+    let mut poll_messages = get_poll_messages();
+    poll_messages.max_wait = 3 * DELAY.as_millis() as u32;
+    let started_at = Instant::now();
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![3]);
+    assert!(started_at.elapsed() < 2 * DELAY);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+fn get_message(id: u32) -> Message {
+    Message::new(None, Bytes::from(format!("message {id}")), None)
+}
+
+fn get_poll_messages() -> PollMessages {
+    PollMessages {
+        consumer: Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap()),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::next(),
+        count: 10,
+        auto_commit: true,
+        max_wait: 0,
+        filter: None,
+    }
+}
+
+fn get_offsets(messages: &[iggy::models::messages::Message]) -> Vec<u64> {
+    messages.iter().map(|message| message.offset).collect()
+}
@@ -0,0 +1,173 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 2;
+const PARTITION_ID: u32 = 1;
+const OTHER_PARTITION_ID: u32 = 2;
+const MESSAGES_COUNT: u32 = 10;
+const SHORT_MAX_WAIT: u32 = 200;
+const LONG_MAX_WAIT: u32 = 10_000;
+const SERVER_MAX_WAIT: u32 = 2_000;
+const SEND_DELAY: u64 = 500;
+
+pub fn get_server_envs() -> HashMap<String, String> {
+    HashMap::from([(
+        "IGGY_SYSTEM_POLLING_MAX_WAIT".to_string(),
+        SERVER_MAX_WAIT.to_string(),
+    )])
+}
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let producer = client_factory.create_client().await;
+    let producer = IggyClient::create(producer, IggyClientConfig::default(), None, None, None);
+    let consumer = client_factory.create_client().await;
+    let consumer = IggyClient::create(consumer, IggyClientConfig::default(), None, None, None);
+
+    login_root(&producer).await;
+    login_root(&consumer).await;
+    init_system(&producer).await;
+
+    // 1. Polling the empty partition without max wait returns immediately
+    let polled_messages = consumer
+        .poll_messages(&get_poll_messages(0, 0))
+        .await
+        .unwrap();
+    assert!(polled_messages.messages.is_empty());
+
+    // 2. Polling the empty partition with max wait returns empty messages once the timeout fires
+    let now = Instant::now();
+    let polled_messages = consumer
+        .poll_messages(&get_poll_messages(0, SHORT_MAX_WAIT))
+        .await
+        .unwrap();
+    assert!(polled_messages.messages.is_empty());
+    assert!(now.elapsed() >= Duration::from_millis(SHORT_MAX_WAIT as u64));
+
+    // 3. Polling with max wait returns as soon as the messages are appended
+    let poll_task = tokio::spawn(async move {
+        let now = Instant::now();
+        let polled_messages = consumer
+            .poll_messages(&get_poll_messages(0, LONG_MAX_WAIT))
+            .await
+            .unwrap();
+        (consumer, polled_messages, now.elapsed())
+    });
+
+    sleep(Duration::from_millis(SEND_DELAY)).await;
+    send_messages(&producer, PARTITION_ID).await;
+
+    let (consumer, polled_messages, elapsed) = poll_task.await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    assert!(elapsed >= Duration::from_millis(SEND_DELAY));
+    assert!(elapsed < Duration::from_millis(SERVER_MAX_WAIT as u64));
+
+    // 4. The messages appended to the other partition don't wake up the consumer,
+    // and the wait is limited by the max wait of the server
+    let poll_task = tokio::spawn(async move {
+        let now = Instant::now();
+        let polled_messages = consumer
+            .poll_messages(&get_poll_messages(MESSAGES_COUNT as u64, LONG_MAX_WAIT))
+            .await
+            .unwrap();
+        (polled_messages, now.elapsed())
+    });
+
+    sleep(Duration::from_millis(SEND_DELAY)).await;
+    send_messages(&producer, OTHER_PARTITION_ID).await;
+
+    let (polled_messages, elapsed) = poll_task.await.unwrap();
+    assert!(polled_messages.messages.is_empty());
+    assert!(elapsed >= Duration::from_millis(SERVER_MAX_WAIT as u64));
+    assert!(elapsed < Duration::from_millis(LONG_MAX_WAIT as u64));
+
+    cleanup_system(&producer).await;
+    assert_clean_system(&producer).await;
+}
+
+async fn send_messages(client: &IggyClient, partition_id: u32) {
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(partition_id),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
+        messages: get_messages(),
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+fn get_poll_messages(offset: u64, max_wait: u32) -> PollMessages {
+    PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(offset),
+        count: MESSAGES_COUNT,
+        auto_commit: false,
+        max_wait,
+        filter: None,
+    }
+}
+
+fn get_messages() -> Vec<Message> {
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {}", offset));
+        messages.push(Message {
+            id: (offset + 1) as u128,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        });
+    }
+    messages
+}
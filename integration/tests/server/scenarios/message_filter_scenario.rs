@@ -0,0 +1,192 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use bytes::Bytes;
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::messages::message_filter::MessageFilter;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const MESSAGES_COUNT: u32 = 100;
+const PARTITION_ID: u32 = 1;
+const CONSUMER_ID: u32 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send messages with the priority header, and the trace header included only in the even ones
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {offset}"));
+        messages.push(Message::new(
+            None,
+            payload,
+            Some(get_message_headers(offset)),
+        ));
+    }
+
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 2. Poll the messages matching the range, the non-matching ones are skipped until the count is reached
+    let polled_messages = client
+        .poll_messages(&get_poll_messages(
+            PollingStrategy::offset(0),
+            5,
+            false,
+            "priority>=uint32:8,priority<=uint32:9",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        get_offsets(&polled_messages.messages),
+        vec![8, 9, 18, 19, 28]
+    );
+    assert_eq!(polled_messages.current_offset, (MESSAGES_COUNT - 1) as u64);
+
+    // 3. Poll the messages, which don't match the filter
+    let polled_messages = client
+        .poll_messages(&get_poll_messages(
+            PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+            "priority=uint64:1",
+        ))
+        .await
+        .unwrap();
+    assert!(polled_messages.messages.is_empty());
+
+    // 4. Poll the last messages matching the filter
+    let polled_messages = client
+        .poll_messages(&get_poll_messages(
+            PollingStrategy::last(),
+            2,
+            false,
+            "priority=uint32:9",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![89, 99]);
+
+    // 5. Poll the next messages with the auto commit, the offset is stored up to the last scanned message
+    let polled_messages = client
+        .poll_messages(&get_poll_messages(
+            PollingStrategy::next(),
+            MESSAGES_COUNT,
+            true,
+            "trace,priority<uint32:2",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(
+        get_offsets(&polled_messages.messages),
+        (0..MESSAGES_COUNT as u64).step_by(10).collect::<Vec<_>>()
+    );
+    let offset = client
+        .get_consumer_offset(&GetConsumerOffset {
+            consumer: Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap()),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+        })
+        .await
+        .unwrap();
+    assert_eq!(offset.stored_offset, (MESSAGES_COUNT - 1) as u64);
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+fn get_poll_messages(
+    strategy: PollingStrategy,
+    count: u32,
+    auto_commit: bool,
+    filter: &str,
+) -> PollMessages {
+    PollMessages {
+        consumer: Consumer::new(Identifier::numeric(CONSUMER_ID).unwrap()),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy,
+        count,
+        auto_commit,
+        max_wait: 0,
+        filter: Some(MessageFilter::from_str(filter).unwrap()),
+    }
+}
+
+fn get_offsets(messages: &[iggy::models::messages::Message]) -> Vec<u64> {
+    messages.iter().map(|message| message.offset).collect()
+}
+
+fn get_message_headers(offset: u32) -> HashMap<HeaderKey, HeaderValue> {
+    let mut headers = HashMap::new();
+    headers.insert(
+        HeaderKey::new("priority").unwrap(),
+        HeaderValue::from_uint32(offset % 10).unwrap(),
+    );
+    if offset & 1 == 0 {
+        headers.insert(
+            HeaderKey::new("trace").unwrap(),
+            HeaderValue::from_bool(true).unwrap(),
+        );
+    }
+    headers
+}
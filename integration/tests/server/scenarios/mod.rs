@@ -1,6 +1,18 @@
+pub mod cluster_scenario;
 pub mod consumer_group_join_scenario;
+pub mod consumer_group_session_timeout_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
+pub mod consumer_lag_scenario;
+pub mod dead_letter_topic_scenario;
+pub mod delayed_messages_scenario;
+pub mod long_polling_scenario;
+pub mod message_filter_scenario;
 pub mod message_headers_scenario;
+pub mod quota_scenario;
+pub mod request_pipelining_scenario;
+pub mod schema_registry_scenario;
 pub mod system_scenario;
+pub mod telemetry_scenario;
+pub mod transactions_scenario;
 pub mod user_scenario;
@@ -0,0 +1,138 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, SystemClient, TopicClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::models::user_quota::UserQuota;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::system::get_me::GetMe;
+use iggy::system::get_stats::GetStats;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::users::update_user::UpdateUser;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+pub const CLIENT_REQUESTS_PER_SECOND: u32 = 3;
+
+pub fn get_server_envs() -> HashMap<String, String> {
+    HashMap::from([
+        ("IGGY_SYSTEM_QUOTA_ENABLED".to_string(), "true".to_string()),
+        (
+            "IGGY_SYSTEM_QUOTA_CLIENT_REQUESTS_PER_SECOND".to_string(),
+            CLIENT_REQUESTS_PER_SECOND.to_string(),
+        ),
+    ])
+}
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. The requests within the quota are accepted
+    for _ in 0..CLIENT_REQUESTS_PER_SECOND {
+        send_message(&client).await.unwrap();
+    }
+
+    // 2. The next request within the same window is throttled with the distinct error code
+    let result = send_message(&client).await;
+    assert!(
+        matches!(result, Err(Error::InvalidResponse(code)) if code == Error::QuotaExceeded.as_code())
+    );
+
+    // 3. The usage is visible for the client and in the stats
+    let me = client.get_me(&GetMe {}).await.unwrap();
+    assert_eq!(me.quota_usage.throttled_requests, 1);
+    let stats = client.get_stats(&GetStats {}).await.unwrap();
+    assert_eq!(stats.throttled_requests_count, 1);
+
+    // 4. The quota of the user overrides the default user limits
+    update_root_quota(&client, UserQuota::new(0, 1)).await;
+    sleep(Duration::from_secs(1)).await;
+    send_message(&client).await.unwrap();
+    let result = send_message(&client).await;
+    assert!(
+        matches!(result, Err(Error::InvalidResponse(code)) if code == Error::QuotaExceeded.as_code())
+    );
+    update_root_quota(&client, UserQuota::default()).await;
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn send_message(client: &IggyClient) -> Result<(), Error> {
+    let payload = Bytes::from("message");
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
+        messages: vec![Message {
+            id: 0,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        }],
+    };
+    client.send_messages(&mut send_messages).await
+}
+
+async fn update_root_quota(client: &IggyClient, quota: UserQuota) {
+    client
+        .update_user(&UpdateUser {
+            user_id: Identifier::numeric(DEFAULT_ROOT_USER_ID).unwrap(),
+            username: None,
+            status: None,
+            quota: Some(quota),
+        })
+        .await
+        .unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(&CreateStream {
+            stream_id: STREAM_ID,
+            name: STREAM_NAME.to_string(),
+        })
+        .await
+        .unwrap();
+
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: TOPIC_ID,
+            partitions_count: 1,
+            name: TOPIC_NAME.to_string(),
+            message_expiry: None,
+            compression_algorithm: CompressionAlgorithm::default(),
+            compaction_enabled: false,
+            max_topic_size: None,
+            max_partition_size: None,
+        })
+        .await
+        .unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    client
+        .delete_stream(&DeleteStream {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+}
@@ -0,0 +1,164 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use futures::future::join_all;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, Producer, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const STREAMS_COUNT: u32 = 50;
+const MISSING_STREAM_ID: u32 = STREAMS_COUNT + 1;
+const TOPIC_ID: u32 = 1;
+const LONG_POLLING_MAX_WAIT: u32 = 3000;
+// Up to 30 futures are joined in the order they are given, so the requests are written in the order of the sequence numbers.
+const PRODUCER_BATCHES_COUNT: u64 = 20;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    login_root(&client).await;
+
+    // 1. Create the streams concurrently over a single connection
+    let create_streams = (1..=STREAMS_COUNT)
+        .map(|stream_id| CreateStream {
+            stream_id,
+            name: get_stream_name(stream_id),
+        })
+        .collect::<Vec<_>>();
+    let results = join_all(
+        create_streams
+            .iter()
+            .map(|create_stream| client.create_stream(create_stream)),
+    )
+    .await;
+    assert!(results.iter().all(|result| result.is_ok()));
+
+    // 2. Get the streams concurrently, including a missing one, and match each response to its request
+    let get_streams = (1..=MISSING_STREAM_ID)
+        .map(|stream_id| GetStream {
+            stream_id: Identifier::numeric(stream_id).unwrap(),
+        })
+        .collect::<Vec<_>>();
+    let results = join_all(
+        get_streams
+            .iter()
+            .map(|get_stream| client.get_stream(get_stream)),
+    )
+    .await;
+    for (index, result) in results.into_iter().enumerate() {
+        let stream_id = index as u32 + 1;
+        if stream_id == MISSING_STREAM_ID {
+            assert!(result.is_err());
+            continue;
+        }
+
+        let stream = result.unwrap();
+        assert_eq!(stream.id, stream_id);
+        assert_eq!(stream.name, get_stream_name(stream_id));
+    }
+
+    // 3. The long polling request doesn't block the other requests sent over the same connection
+    client
+        .create_topic(&CreateTopic {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: TOPIC_ID,
+            partitions_count: 1,
+            name: "test-topic".to_string(),
+            message_expiry: None,
+            compression_algorithm: CompressionAlgorithm::None,
+            compaction_enabled: false,
+            max_topic_size: None,
+            max_partition_size: None,
+        })
+        .await
+        .unwrap();
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(1).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(1),
+        strategy: PollingStrategy::offset(0),
+        count: 1,
+        auto_commit: false,
+        max_wait: LONG_POLLING_MAX_WAIT,
+        filter: None,
+    };
+    let get_stream = GetStream {
+        stream_id: Identifier::numeric(1).unwrap(),
+    };
+    let start = Instant::now();
+    let (polled_messages, get_stream_elapsed) =
+        tokio::join!(client.poll_messages(&poll_messages), async {
+            // Give the long polling request a head start, so it's already being handled.
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            client.get_stream(&get_stream).await.unwrap();
+            start.elapsed()
+        });
+    assert!(polled_messages.unwrap().messages.is_empty());
+    assert!(get_stream_elapsed < Duration::from_millis(LONG_POLLING_MAX_WAIT as u64 / 2));
+    assert!(start.elapsed() >= Duration::from_millis(LONG_POLLING_MAX_WAIT as u64 / 2));
+
+    // 4. The messages of the idempotent producer sent concurrently are appended in the order of their sequence numbers
+    let mut send_messages = (0..PRODUCER_BATCHES_COUNT)
+        .map(|sequence| SendMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitioning: Partitioning::partition_id(1),
+            producer: Some(Producer { id: 1, sequence }),
+            transaction_id: None,
+            acks: Acks::default(),
+            messages: vec![Message::from_str(&sequence.to_string()).unwrap()],
+        })
+        .collect::<Vec<_>>();
+    let results = join_all(
+        send_messages
+            .iter_mut()
+            .map(|send_messages| client.send_messages(send_messages)),
+    )
+    .await;
+    assert!(results.iter().all(|result| result.is_ok()));
+    let polled_messages = client
+        .poll_messages(&PollMessages {
+            count: PRODUCER_BATCHES_COUNT as u32,
+            max_wait: 0,
+            ..poll_messages
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        polled_messages.messages.len() as u64,
+        PRODUCER_BATCHES_COUNT
+    );
+    for (sequence, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(message.payload, sequence.to_string().as_bytes());
+    }
+
+    // 5. Delete the streams concurrently
+    let delete_streams = (1..=STREAMS_COUNT)
+        .map(|stream_id| DeleteStream {
+            stream_id: Identifier::numeric(stream_id).unwrap(),
+        })
+        .collect::<Vec<_>>();
+    let results = join_all(
+        delete_streams
+            .iter()
+            .map(|delete_stream| client.delete_stream(delete_stream)),
+    )
+    .await;
+    assert!(results.iter().all(|result| result.is_ok()));
+
+    assert_clean_system(&client).await;
+}
+
+fn get_stream_name(stream_id: u32) -> String {
+    format!("test-stream-{}", stream_id)
+}
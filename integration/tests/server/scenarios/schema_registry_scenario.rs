@@ -0,0 +1,290 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use bytes::Bytes;
+use iggy::client::{MessageClient, SchemaClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::models::header::set_schema_id;
+use iggy::schemas::delete_schema::DeleteSchema;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+use iggy::schemas::schema_format::SchemaFormat;
+use iggy::schemas::update_schema_settings::UpdateSchemaSettings;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::collections::HashMap;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const ORDER_SCHEMA: &str =
+    r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#;
+const ORDER_SCHEMA_WITH_OPTIONAL_PRICE: &str = r#"{"type":"object","properties":{"id":{"type":"integer"},"price":{"type":"number"}},"required":["id"]}"#;
+const ORDER_SCHEMA_WITH_REQUIRED_PRICE: &str = r#"{"type":"object","properties":{"id":{"type":"integer"},"price":{"type":"number"}},"required":["id","price"]}"#;
+const ORDER_AVRO_SCHEMA: &str =
+    r#"{"type":"record","name":"order","fields":[{"name":"id","type":"long"}]}"#;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Register the first schema, the IDs are assigned sequentially starting from 1
+    let schema = client
+        .register_schema(&get_register_schema(SchemaFormat::JsonSchema, ORDER_SCHEMA))
+        .await
+        .unwrap();
+    assert_eq!(schema.id, 1);
+    assert_eq!(schema.format, SchemaFormat::JsonSchema);
+    assert_eq!(schema.definition, ORDER_SCHEMA);
+
+    // 2. Registering the schema, which can't read the data of the previous one fails in the backward mode
+    let result = client
+        .register_schema(&get_register_schema(
+            SchemaFormat::JsonSchema,
+            ORDER_SCHEMA_WITH_REQUIRED_PRICE,
+        ))
+        .await;
+    assert!(result.is_err());
+
+    // 3. Registering the schema in a different format fails as well
+    let result = client
+        .register_schema(&get_register_schema(
+            SchemaFormat::Protobuf,
+            "syntax = \"proto3\";\nmessage Order {\n  uint64 id = 1;\n}\n",
+        ))
+        .await;
+    assert!(result.is_err());
+
+    let result = client
+        .register_schema(&get_register_schema(SchemaFormat::Avro, ORDER_AVRO_SCHEMA))
+        .await;
+    assert!(result.is_err());
+
+    // 4. Register the compatible schema with the optional property
+    let schema = client
+        .register_schema(&get_register_schema(
+            SchemaFormat::JsonSchema,
+            ORDER_SCHEMA_WITH_OPTIONAL_PRICE,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(schema.id, 2);
+
+    // 5. Get the schemas with the default settings
+    let topic_schemas = client
+        .get_schemas(&GetSchemas {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic_schemas.compatibility, SchemaCompatibility::Backward);
+    assert!(!topic_schemas.validation_enabled);
+    assert_eq!(topic_schemas.schemas.len(), 2);
+    assert_eq!(topic_schemas.schemas[0].id, 1);
+    assert_eq!(topic_schemas.schemas[1].id, 2);
+
+    // 6. Messages without the schema ID are accepted while the validation is disabled
+    client
+        .send_messages(&mut get_send_messages(vec![Message::new(
+            None,
+            Bytes::from("not a json"),
+            None,
+        )]))
+        .await
+        .unwrap();
+
+    // 7. Enable the validation
+    client
+        .update_schema_settings(&UpdateSchemaSettings {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            compatibility: SchemaCompatibility::Full,
+            validation_enabled: true,
+        })
+        .await
+        .unwrap();
+    let topic_schemas = client
+        .get_schemas(&GetSchemas {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(topic_schemas.compatibility, SchemaCompatibility::Full);
+    assert!(topic_schemas.validation_enabled);
+
+    // 8. Messages without the schema ID, with the unknown schema ID or invalid payload are rejected
+    let result = client
+        .send_messages(&mut get_send_messages(vec![Message::new(
+            None,
+            Bytes::from(r#"{"id":1}"#),
+            None,
+        )]))
+        .await;
+    assert!(result.is_err());
+
+    let result = client
+        .send_messages(&mut get_send_messages(vec![get_message(r#"{"id":1}"#, 3)]))
+        .await;
+    assert!(result.is_err());
+
+    let result = client
+        .send_messages(&mut get_send_messages(vec![get_message(
+            r#"{"id":"first"}"#,
+            1,
+        )]))
+        .await;
+    assert!(result.is_err());
+
+    // 9. Messages matching the schemas given in their headers are accepted
+    client
+        .send_messages(&mut get_send_messages(vec![
+            get_message(r#"{"id":1}"#, 1),
+            get_message(r#"{"id":2,"price":9.99}"#, 2),
+        ]))
+        .await
+        .unwrap();
+
+    // 10. Delete the first schema, the ID of the next schema isn't reused
+    client
+        .delete_schema(&DeleteSchema {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            schema_id: 1,
+        })
+        .await
+        .unwrap();
+    let result = client
+        .get_schema(&GetSchema {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            schema_id: 1,
+        })
+        .await;
+    assert!(result.is_err());
+
+    let schema = client
+        .get_schema(&GetSchema {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            schema_id: 2,
+        })
+        .await
+        .unwrap();
+    assert_eq!(schema.definition, ORDER_SCHEMA_WITH_OPTIONAL_PRICE);
+
+    let schema = client
+        .register_schema(&get_register_schema(
+            SchemaFormat::JsonSchema,
+            ORDER_SCHEMA_WITH_OPTIONAL_PRICE,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(schema.id, 3);
+
+    // 11. The schema in a different format can be registered without the compatibility checks
+    client
+        .update_schema_settings(&UpdateSchemaSettings {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            compatibility: SchemaCompatibility::None,
+            validation_enabled: true,
+        })
+        .await
+        .unwrap();
+    let schema = client
+        .register_schema(&get_register_schema(SchemaFormat::Avro, ORDER_AVRO_SCHEMA))
+        .await
+        .unwrap();
+    assert_eq!(schema.id, 4);
+    assert_eq!(schema.format, SchemaFormat::Avro);
+
+    // 12. The Avro encoded messages are validated against the schema, the ID 1 is encoded as the zigzag varint
+    client
+        .send_messages(&mut get_send_messages(vec![get_binary_message(&[0x02], 4)]))
+        .await
+        .unwrap();
+
+    let result = client
+        .send_messages(&mut get_send_messages(vec![get_binary_message(
+            &[0x02, 0x00],
+            4,
+        )]))
+        .await;
+    assert!(result.is_err());
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+fn get_register_schema(format: SchemaFormat, definition: &str) -> RegisterSchema {
+    RegisterSchema {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        format,
+        definition: definition.to_string(),
+    }
+}
+
+fn get_send_messages(messages: Vec<Message>) -> SendMessages {
+    SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
+        messages,
+    }
+}
+
+fn get_message(payload: &str, schema_id: u32) -> Message {
+    get_binary_message(payload.as_bytes(), schema_id)
+}
+
+fn get_binary_message(payload: &[u8], schema_id: u32) -> Message {
+    let mut headers = HashMap::new();
+    set_schema_id(&mut headers, schema_id).unwrap();
+    Message::new(None, Bytes::copy_from_slice(payload), Some(headers))
+}
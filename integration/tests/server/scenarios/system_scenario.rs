@@ -5,6 +5,7 @@ use iggy::client::{
     SystemClient, TopicClient, UserClient,
 };
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
@@ -12,12 +13,13 @@ use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::streams::create_stream::CreateStream;
@@ -25,6 +27,7 @@ use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::system::create_backup::CreateBackup;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
 use iggy::system::get_stats::GetStats;
@@ -129,6 +132,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -230,6 +237,9 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
         messages,
     };
     client.send_messages(&mut send_messages).await.unwrap();
@@ -246,6 +256,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        max_wait: 0,
+        filter: None,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -272,6 +284,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             strategy: PollingStrategy::offset(start_offset),
             count: batch_size,
             auto_commit: false,
+            max_wait: 0,
+            filter: None,
         };
 
         let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -316,6 +330,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        max_wait: 0,
+        filter: None,
     };
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
     assert!(polled_messages.messages.is_empty());
@@ -383,6 +399,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::next(),
         count: messages_count,
         auto_commit: true,
+        max_wait: 0,
+        filter: None,
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -428,6 +446,9 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             consumer_group_id: CONSUMER_GROUP_ID,
             name: CONSUMER_GROUP_NAME.to_string(),
+            partition_assignment_strategy: PartitionAssignmentStrategy::Sticky,
+            session_timeout: None,
+            dead_letter_topic: None,
         })
         .await
         .unwrap();
@@ -460,6 +481,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.id, CONSUMER_GROUP_ID);
     assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
     assert_eq!(consumer_group.members_count, 0);
+    assert_eq!(
+        consumer_group.partition_assignment_strategy,
+        PartitionAssignmentStrategy::Sticky
+    );
     assert!(consumer_group.members.is_empty());
 
     // 31. Join the consumer group and then leave it if the feature is available
@@ -537,7 +562,14 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(stats.segments_count, PARTITIONS_COUNT);
     assert_eq!(stats.messages_count, MESSAGES_COUNT as u64);
 
-    // 33. Delete the consumer group
+    // 33. Create the backup of the server state
+    let backup = client.create_backup(&CreateBackup {}).await.unwrap();
+    assert!(backup.name.starts_with("backup_"));
+    assert!(backup.path.ends_with(&backup.name));
+    assert!(backup.size_bytes > 0);
+    assert!(backup.created_at > 0);
+
+    // 34. Delete the consumer group
     client
         .delete_consumer_group(&DeleteConsumerGroup {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -547,7 +579,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
-    // 34. Create new partitions and validate that the number of partitions is increased
+    // 35. Create new partitions and validate that the number of partitions is increased
     client
         .create_partitions(&CreatePartitions {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -567,7 +599,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(topic.partitions_count, 2 * PARTITIONS_COUNT);
 
-    // 35. Delete the partitions and validate that the number of partitions is decreased
+    // 36. Delete the partitions and validate that the number of partitions is decreased
     client
         .delete_partitions(&DeletePartitions {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -587,9 +619,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
 
-    // 36. Update the existing topic and ensure it's updated
+    // 37. Update the existing topic and ensure it's updated
     let updated_topic_name = format!("{}-updated", TOPIC_NAME);
     let updated_message_expiry = 1000;
+    let updated_max_topic_size = 1_000_000_000;
 
     client
         .update_topic(&UpdateTopic {
@@ -597,6 +630,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             name: updated_topic_name.clone(),
             message_expiry: Some(updated_message_expiry),
+            max_topic_size: Some(updated_max_topic_size),
+            max_partition_size: None,
         })
         .await
         .unwrap();
@@ -611,8 +646,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(updated_topic.name, updated_topic_name);
     assert_eq!(updated_topic.message_expiry, Some(updated_message_expiry));
+    assert_eq!(updated_topic.max_topic_size, Some(updated_max_topic_size));
+    assert_eq!(updated_topic.max_partition_size, None);
 
-    // 37. Delete the existing topic and ensure it doesn't exist anymore
+    // 38. Delete the existing topic and ensure it doesn't exist anymore
     client
         .delete_topic(&DeleteTopic {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -628,7 +665,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .unwrap();
     assert!(topics.is_empty());
 
-    // 38. Update the existing stream and ensure it's updated
+    // 39. Update the existing stream and ensure it's updated
     let updated_stream_name = format!("{}-updated", STREAM_NAME);
 
     client
@@ -648,7 +685,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(updated_stream.name, updated_stream_name);
 
-    // 39. Delete the existing stream and ensure it doesn't exist anymore
+    // 40. Delete the existing stream and ensure it doesn't exist anymore
     client
         .delete_stream(&DeleteStream {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
@@ -658,7 +695,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let streams = client.get_streams(&GetStreams {}).await.unwrap();
     assert!(streams.is_empty());
 
-    // 40. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
+    // 41. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
     let clients = client.get_clients(&GetClients {}).await.unwrap();
 
     assert!(clients.len() <= 1);
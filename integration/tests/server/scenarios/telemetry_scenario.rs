@@ -0,0 +1,218 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use axum::extract::State;
+use axum::routing::post;
+use axum::Router;
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::telemetry::{extract_context, inject_context};
+use iggy::topics::create_topic::CreateTopic;
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+use opentelemetry::Context;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tokio::runtime::Runtime;
+use tokio::time::{sleep, Duration, Instant};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+const SPAN_ID: &str = "00f067aa0ba902b7";
+const SEND_MESSAGES_SPAN_NAME: &str = "message.send";
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Stands in for the OpenTelemetry collector, storing the spans received via OTLP/HTTP (JSON).
+pub struct CollectorStandIn {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl CollectorStandIn {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let app = Router::new()
+            .route(
+                "/v1/traces",
+                post(
+                    |State(requests): State<Arc<Mutex<Vec<String>>>>, body: String| async move {
+                        requests.lock().unwrap().push(body);
+                    },
+                ),
+            )
+            .with_state(requests.clone());
+        // The collector runs on its own runtime, so that it keeps receiving the spans exported by the server
+        // during its shutdown, while the test runtime is blocked on stopping the server.
+        thread::spawn(move || {
+            Runtime::new().unwrap().block_on(async move {
+                axum::Server::from_tcp(listener)
+                    .unwrap()
+                    .serve(app.into_make_service())
+                    .await
+                    .unwrap();
+            });
+        });
+
+        Self { address, requests }
+    }
+
+    /// Returns the `(trace_id, parent_span_id)` of the received spans with the given name.
+    fn get_spans(&self, name: &str) -> Vec<(String, String)> {
+        let mut spans = Vec::new();
+        for request in self.requests.lock().unwrap().iter() {
+            let request: Value = serde_json::from_str(request).unwrap();
+            for resource_spans in request["resourceSpans"].as_array().unwrap() {
+                for scope_spans in resource_spans["scopeSpans"].as_array().unwrap() {
+                    for span in scope_spans["spans"].as_array().unwrap() {
+                        if span["name"] == name {
+                            spans.push((
+                                span["traceId"].as_str().unwrap().to_string(),
+                                span["parentSpanId"]
+                                    .as_str()
+                                    .unwrap_or_default()
+                                    .to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        spans
+    }
+}
+
+pub fn get_server_envs(collector: &CollectorStandIn) -> HashMap<String, String> {
+    HashMap::from([
+        ("IGGY_TELEMETRY_ENABLED".to_string(), "true".to_string()),
+        (
+            "IGGY_TELEMETRY_ENDPOINT".to_string(),
+            format!("http://{}/v1/traces", collector.address),
+        ),
+        // Export the spans right away instead of the default 5 seconds delay.
+        ("OTEL_BSP_SCHEDULE_DELAY".to_string(), "100".to_string()),
+    ])
+}
+
+pub async fn run(client_factory: &dyn ClientFactory, collector: &CollectorStandIn) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Send messages with the trace context of the producer span stored in the headers
+    let producer_context = Context::new().with_remote_span_context(SpanContext::new(
+        TraceId::from_hex(TRACE_ID).unwrap(),
+        SpanId::from_hex(SPAN_ID).unwrap(),
+        TraceFlags::SAMPLED,
+        true,
+        TraceState::default(),
+    ));
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {offset}"));
+        let mut headers = HashMap::new();
+        inject_context(&producer_context, &mut headers);
+        messages.push(Message {
+            id: (offset + 1) as u128,
+            length: payload.len() as u32,
+            payload,
+            headers: Some(headers),
+        });
+    }
+
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        acks: Acks::default(),
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 2. Poll messages and validate that the consumer can continue the trace of the producer
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        auto_commit: false,
+        max_wait: 0,
+        filter: None,
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for message in polled_messages.messages {
+        let context = extract_context(&message.headers);
+        let span_context = context.span().span_context().clone();
+        assert_eq!(span_context.trace_id().to_string(), TRACE_ID);
+        assert_eq!(span_context.span_id().to_string(), SPAN_ID);
+    }
+
+    // 3. Validate that the server exported the span of appending the messages as the child of the producer span
+    let deadline = Instant::now() + EXPORT_TIMEOUT;
+    loop {
+        let spans = collector.get_spans(SEND_MESSAGES_SPAN_NAME);
+        if spans.contains(&(TRACE_ID.to_string(), SPAN_ID.to_string())) {
+            break;
+        }
+
+        assert!(
+            Instant::now() < deadline,
+            "The span of sending the messages was not exported, received spans: {spans:?}"
+        );
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
@@ -0,0 +1,207 @@
+use crate::utils::test_server::{assert_clean_system, login_root, ClientFactory};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient, TransactionClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, Producer, SendMessages};
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::transactions::abort_transaction::AbortTransaction;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use iggy::transactions::commit_transaction::CommitTransaction;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 2;
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    login_root(&client).await;
+    init_system(&client).await;
+
+    // 1. Messages sent within the aborted transaction are never appended
+    let transaction = client
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap();
+    send_messages(&client, transaction.id).await;
+    assert_partitions_messages(&client, 0).await;
+    client
+        .abort_transaction(&AbortTransaction {
+            transaction_id: transaction.id,
+        })
+        .await
+        .unwrap();
+    assert_partitions_messages(&client, 0).await;
+
+    // 2. The aborted transaction can't be committed anymore
+    let result = client
+        .commit_transaction(&CommitTransaction {
+            transaction_id: transaction.id,
+        })
+        .await;
+    assert!(result.is_err());
+
+    // 3. Messages sent within the committed transaction become visible in all the partitions at once
+    let transaction = client
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap();
+    send_messages(&client, transaction.id).await;
+    assert_partitions_messages(&client, 0).await;
+    client
+        .commit_transaction(&CommitTransaction {
+            transaction_id: transaction.id,
+        })
+        .await
+        .unwrap();
+    assert_partitions_messages(&client, MESSAGES_COUNT).await;
+
+    // 4. Sending the messages within the unknown transaction fails
+    let mut unknown_transaction_messages = get_send_messages(1, Some(transaction.id));
+    let result = client
+        .send_messages(&mut unknown_transaction_messages)
+        .await;
+    assert!(result.is_err());
+    if let Err(Error::TransactionNotFound(transaction_id)) = result {
+        assert_eq!(transaction_id, transaction.id);
+    }
+
+    // 5. The transaction with the out of order producer sequence is rejected as a whole and can be aborted
+    let producer = Producer { id: 1, sequence: 0 };
+    let mut producer_messages = get_send_messages(PARTITIONS_COUNT, None);
+    producer_messages.producer = Some(producer);
+    client.send_messages(&mut producer_messages).await.unwrap();
+    let transaction = client
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap();
+    send_messages(&client, transaction.id).await;
+    let mut producer_messages = get_send_messages(PARTITIONS_COUNT, Some(transaction.id));
+    producer_messages.producer = Some(Producer {
+        id: producer.id,
+        sequence: 2 * MESSAGES_COUNT as u64,
+    });
+    client.send_messages(&mut producer_messages).await.unwrap();
+    let result = client
+        .commit_transaction(&CommitTransaction {
+            transaction_id: transaction.id,
+        })
+        .await;
+    assert!(result.is_err());
+    assert_partition_messages(&client, 1, MESSAGES_COUNT).await;
+    assert_partition_messages(&client, PARTITIONS_COUNT, 2 * MESSAGES_COUNT).await;
+    client
+        .abort_transaction(&AbortTransaction {
+            transaction_id: transaction.id,
+        })
+        .await
+        .unwrap();
+
+    cleanup_system(&client).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        compression_algorithm: CompressionAlgorithm::default(),
+        compaction_enabled: false,
+        max_topic_size: None,
+        max_partition_size: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn cleanup_system(client: &IggyClient) {
+    let delete_stream = DeleteStream {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+    };
+    client.delete_stream(&delete_stream).await.unwrap();
+}
+
+async fn send_messages(client: &IggyClient, transaction_id: u64) {
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let mut send_messages = get_send_messages(partition_id, Some(transaction_id));
+        client.send_messages(&mut send_messages).await.unwrap();
+    }
+}
+
+async fn assert_partitions_messages(client: &IggyClient, expected_messages_count: u32) {
+    for partition_id in 1..=PARTITIONS_COUNT {
+        assert_partition_messages(client, partition_id, expected_messages_count).await;
+    }
+}
+
+async fn assert_partition_messages(
+    client: &IggyClient,
+    partition_id: u32,
+    expected_messages_count: u32,
+) {
+    let polled_messages = client
+        .poll_messages(&PollMessages {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(partition_id),
+            strategy: PollingStrategy::offset(0),
+            count: 10 * MESSAGES_COUNT,
+            auto_commit: false,
+            max_wait: 0,
+            filter: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        polled_messages.messages.len() as u32,
+        expected_messages_count
+    );
+}
+
+fn get_send_messages(partition_id: u32, transaction_id: Option<u64>) -> SendMessages {
+    SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(partition_id),
+        producer: None,
+        transaction_id,
+        acks: Acks::default(),
+        messages: get_messages(),
+    }
+}
+
+fn get_messages() -> Vec<Message> {
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {}", offset));
+        messages.push(Message {
+            id: (offset + 1) as u128,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        });
+    }
+    messages
+}
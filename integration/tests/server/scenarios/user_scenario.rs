@@ -263,6 +263,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             user_id: Identifier::named(test_user).unwrap(),
             username: Some(updated_test_user.to_string()),
             status: Some(UserStatus::Inactive),
+            quota: None,
         })
         .await
         .unwrap();
@@ -1,11 +1,19 @@
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, message_headers_scenario,
-    system_scenario, user_scenario,
+    cluster_scenario, consumer_group_join_scenario, consumer_group_session_timeout_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, consumer_lag_scenario,
+    dead_letter_topic_scenario, delayed_messages_scenario, long_polling_scenario,
+    message_filter_scenario, message_headers_scenario, quota_scenario, request_pipelining_scenario,
+    schema_registry_scenario, system_scenario, telemetry_scenario, transactions_scenario,
+    user_scenario,
 };
 use crate::utils::tcp_client::TcpClientFactory;
 use crate::utils::test_server::TestServer;
+use iggy::binary::protocol::{handshake_request, CORRELATION_ID_PROTOCOL_VERSION};
+use iggy::command::PING_CODE;
 use serial_test::parallel;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
 
 #[tokio::test]
 #[parallel]
@@ -37,6 +45,46 @@ async fn message_headers_scenario_should_be_valid() {
     message_headers_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn message_filter_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    message_filter_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_lag_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_lag_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn delayed_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    delayed_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn schema_registry_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    schema_registry_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn consumer_group_join_scenario_should_be_valid() {
@@ -47,6 +95,16 @@ async fn consumer_group_join_scenario_should_be_valid() {
     consumer_group_join_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn consumer_group_session_timeout_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_group_session_timeout_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn consumer_group_with_single_client_polling_messages_scenario_should_be_valid() {
@@ -66,3 +124,121 @@ async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_b
     let client_factory = TcpClientFactory { server_addr };
     consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn long_polling_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(Some(long_polling_scenario::get_server_envs()));
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    long_polling_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn transactions_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    transactions_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn dead_letter_topic_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    dead_letter_topic_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn request_pipelining_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    request_pipelining_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn quota_scenario_should_be_valid() {
+    let mut test_server = TestServer::new(Some(quota_scenario::get_server_envs()));
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    quota_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn telemetry_scenario_should_be_valid() {
+    let collector = telemetry_scenario::CollectorStandIn::start();
+    let mut test_server = TestServer::new(Some(telemetry_scenario::get_server_envs(&collector)));
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    telemetry_scenario::run(&client_factory, &collector).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn cluster_scenario_should_be_valid() {
+    let nodes = cluster_scenario::create_nodes();
+    cluster_scenario::run(nodes).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn legacy_client_should_receive_responses_without_correlation_ids() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let mut stream = TcpStream::connect(test_server.get_raw_tcp_addr().unwrap())
+        .await
+        .unwrap();
+
+    // The legacy client doesn't send the handshake, and frames the request as its length and command code.
+    stream
+        .write_all(&[4u32.to_le_bytes(), PING_CODE.to_le_bytes()].concat())
+        .await
+        .unwrap();
+
+    // The response is framed as its status and length.
+    let mut response = [0u8; 8];
+    stream.read_exact(&mut response).await.unwrap();
+    assert_eq!(response, [0u8; 8]);
+}
+
+#[tokio::test]
+#[parallel]
+async fn request_without_correlation_id_should_close_connection() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let mut stream = TcpStream::connect(test_server.get_raw_tcp_addr().unwrap())
+        .await
+        .unwrap();
+    stream
+        .write_all(&handshake_request(CORRELATION_ID_PROTOCOL_VERSION))
+        .await
+        .unwrap();
+    let mut response = [0u8; 12];
+    stream.read_exact(&mut response).await.unwrap();
+    assert_eq!(response[..4], [0u8; 4]);
+    assert_eq!(
+        u32::from_le_bytes(response[8..].try_into().unwrap()),
+        CORRELATION_ID_PROTOCOL_VERSION
+    );
+
+    stream
+        .write_all(&[&2u32.to_le_bytes()[..], &[0u8; 2]].concat())
+        .await
+        .unwrap();
+
+    let mut buffer = [0u8; 1];
+    assert_eq!(stream.read(&mut buffer).await.unwrap(), 0);
+}
@@ -1,6 +1,7 @@
 use server::configs::system::SystemConfig;
 use server::streaming::persistence::persister::FilePersister;
 use server::streaming::storage::SystemStorage;
+use server::streaming::tiered_storage::remote_storage;
 use sled::Db;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::SeqCst;
@@ -25,9 +26,13 @@ impl TestSetup {
         config.path = format!("test_local_data_{}", directory_id);
         let config = Arc::new(config);
         fs::create_dir(config.get_system_path()).await.unwrap();
-        let persister = FilePersister {};
+        let persister = FilePersister::default();
         let db = Arc::new(sled::open(config.get_database_path()).unwrap());
-        let storage = Arc::new(SystemStorage::new(db.clone(), Arc::new(persister)));
+        let storage = Arc::new(SystemStorage::new(
+            db.clone(),
+            Arc::new(persister),
+            remote_storage::create(&config),
+        ));
         TestSetup {
             config,
             storage,
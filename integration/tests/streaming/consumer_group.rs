@@ -1,4 +1,6 @@
 use crate::streaming::common::test_setup::TestSetup;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::identifier::Identifier;
 use server::streaming::topics::topic::Topic;
 
@@ -10,13 +12,24 @@ async fn should_persist_consumer_group_and_then_load_it_from_disk() {
     let consumer_group_id = 1;
     let consumer_group_name = "test";
     topic
-        .create_consumer_group(consumer_group_id, consumer_group_name)
+        .create_consumer_group(
+            consumer_group_id,
+            consumer_group_name,
+            PartitionAssignmentStrategy::Sticky,
+            Some(30),
+            None,
+        )
         .await
         .unwrap();
 
     let consumer_groups = storage.load_consumer_groups(&topic).await.unwrap();
     assert_eq!(consumer_groups.len(), 1);
     let consumer_group = consumer_groups.get(0).unwrap();
+    assert_eq!(
+        consumer_group.partition_assignment_strategy,
+        PartitionAssignmentStrategy::Sticky
+    );
+    assert_eq!(consumer_group.session_timeout, Some(30));
 
     let consumer_group_by_id = topic
         .get_consumer_group(&Identifier::numeric(consumer_group_id).unwrap())
@@ -47,7 +60,13 @@ async fn should_delete_consumer_group_from_disk() {
     let consumer_group_id = 1;
     let consumer_group_name = "test";
     topic
-        .create_consumer_group(consumer_group_id, consumer_group_name)
+        .create_consumer_group(
+            consumer_group_id,
+            consumer_group_name,
+            PartitionAssignmentStrategy::default(),
+            None,
+            None,
+        )
         .await
         .unwrap();
 
@@ -81,6 +100,10 @@ async fn init_topic(setup: &TestSetup) -> Topic {
         setup.config.clone(),
         setup.storage.clone(),
         None,
+        CompressionAlgorithm::default(),
+        false,
+        None,
+        None,
     )
     .unwrap();
     topic.persist().await.unwrap();
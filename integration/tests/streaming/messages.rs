@@ -1,5 +1,6 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::{checksum, timestamp::TimeStamp};
@@ -32,6 +33,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         config.clone(),
         setup.storage.clone(),
         None,
+        CompressionAlgorithm::default(),
     );
 
     let mut messages = Vec::with_capacity(messages_count as usize);
@@ -80,7 +82,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
 
     setup.create_partitions_directory(stream_id, topic_id).await;
     partition.persist().await.unwrap();
-    partition.append_messages(messages).await.unwrap();
+    partition.append_messages(messages, None).await.unwrap();
     assert_eq!(partition.unsaved_messages_count, 0);
 
     let mut loaded_partition = Partition::create(
@@ -91,6 +93,7 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         config.clone(),
         setup.storage.clone(),
         None,
+        CompressionAlgorithm::default(),
     );
     loaded_partition.load().await.unwrap();
     let loaded_messages = loaded_partition
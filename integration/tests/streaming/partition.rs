@@ -1,6 +1,14 @@
 use crate::streaming::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::checksum;
+use iggy::utils::timestamp::TimeStamp;
+use server::configs::system::{PartitionConfig, SystemConfig};
 use server::streaming::partitions::partition::Partition;
+use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use std::sync::Arc;
 use tokio::fs;
 
 #[tokio::test]
@@ -20,6 +28,7 @@ async fn should_persist_partition_with_segment() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
         );
 
         partition.persist().await.unwrap();
@@ -45,6 +54,7 @@ async fn should_load_existing_partition_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
         );
         partition.persist().await.unwrap();
         assert_persisted_partition(&partition.path, with_segment).await;
@@ -57,6 +67,7 @@ async fn should_load_existing_partition_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
         );
         loaded_partition.load().await.unwrap();
 
@@ -101,6 +112,7 @@ async fn should_delete_existing_partition_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
         );
         partition.persist().await.unwrap();
         assert_persisted_partition(&partition.path, with_segment).await;
@@ -111,6 +123,85 @@ async fn should_delete_existing_partition_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_skip_poisoned_messages_of_the_consumer_after_loading_partition_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let poisoned_offset = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 3,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+        CompressionAlgorithm::default(),
+    );
+    partition.persist().await.unwrap();
+    let messages = (1..=3)
+        .map(|id| {
+            let payload = Bytes::from(format!("message {}", id));
+            Message::empty(
+                TimeStamp::now().to_micros(),
+                MessageState::Available,
+                id,
+                payload.clone(),
+                checksum::calculate(&payload),
+                None,
+            )
+        })
+        .collect();
+    partition.append_messages(messages, None).await.unwrap();
+    let consumer = PollingConsumer::Consumer(1, partition_id);
+    assert!(partition
+        .poison_message(consumer, poisoned_offset)
+        .await
+        .unwrap());
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+        CompressionAlgorithm::default(),
+    );
+    loaded_partition.load().await.unwrap();
+
+    let messages = loaded_partition
+        .get_next_messages(consumer, 10, None)
+        .await
+        .unwrap()
+        .messages;
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec![0, 2]);
+
+    let other_consumer = PollingConsumer::Consumer(2, partition_id);
+    let messages = loaded_partition
+        .get_next_messages(other_consumer, 10, None)
+        .await
+        .unwrap()
+        .messages;
+    assert_eq!(messages.len(), 3);
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
@@ -129,3 +220,98 @@ async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
 fn get_partition_ids() -> Vec<u32> {
     vec![1, 2, 3, 5, 10, 100, 1000, 99999]
 }
+
+#[tokio::test]
+async fn should_truncate_messages_not_replicated_by_the_new_leader() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        CompressionAlgorithm::default(),
+    );
+    partition.persist().await.unwrap();
+    let appended_messages = partition
+        .append_replicated_messages(1, 0, create_replicated_messages(0..5, "first"))
+        .await
+        .unwrap();
+    assert_eq!(appended_messages.count, 5);
+
+    let appended_messages = partition
+        .append_replicated_messages(2, 3, create_replicated_messages(5..6, "first"))
+        .await
+        .unwrap();
+    assert_eq!(appended_messages.count, 0);
+    assert_eq!(partition.get_next_offset(), 3);
+
+    let appended_messages = partition
+        .append_replicated_messages(2, 3, create_replicated_messages(3..5, "second"))
+        .await
+        .unwrap();
+    assert_eq!(appended_messages.count, 2);
+    assert!(partition
+        .append_replicated_messages(1, 0, create_replicated_messages(5..6, "first"))
+        .await
+        .is_err());
+    for segment in partition.get_segments_mut() {
+        segment
+            .persist_messages(setup.storage.segment.clone())
+            .await
+            .unwrap();
+    }
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+        CompressionAlgorithm::default(),
+    );
+    loaded_partition.load().await.unwrap();
+    let payloads = loaded_partition
+        .get_messages_by_offset(0, 10)
+        .await
+        .unwrap()
+        .iter()
+        .map(|message| message.payload.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        payloads,
+        vec![
+            Bytes::from("first 0"),
+            Bytes::from("first 1"),
+            Bytes::from("first 2"),
+            Bytes::from("second 3"),
+            Bytes::from("second 4"),
+        ]
+    );
+}
+
+fn create_replicated_messages(offsets: std::ops::Range<u64>, prefix: &str) -> Vec<Arc<Message>> {
+    offsets
+        .map(|offset| {
+            let payload = Bytes::from(format!("{prefix} {offset}"));
+            let mut message = Message::empty(
+                TimeStamp::now().to_micros(),
+                MessageState::Available,
+                offset as u128 + 1,
+                payload.clone(),
+                checksum::calculate(&payload),
+                None,
+            );
+            message.offset = offset;
+            Arc::new(message)
+        })
+        .collect()
+}
@@ -1,5 +1,6 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::{checksum, timestamp::TimeStamp};
 use server::streaming::segments::segment;
@@ -23,6 +24,7 @@ async fn should_persist_segment() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
         );
 
         setup
@@ -55,6 +57,7 @@ async fn should_load_existing_segment_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
         );
         setup
             .create_partition_directory(stream_id, topic_id, partition_id)
@@ -76,6 +79,7 @@ async fn should_load_existing_segment_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
         );
         loaded_segment.load().await.unwrap();
         let loaded_messages = loaded_segment.get_messages(0, 10).await.unwrap();
@@ -111,6 +115,7 @@ async fn should_persist_and_load_segment_with_messages() {
         setup.config.clone(),
         setup.storage.clone(),
         None,
+        CompressionAlgorithm::default(),
     );
 
     setup
@@ -143,6 +148,7 @@ async fn should_persist_and_load_segment_with_messages() {
         setup.config.clone(),
         setup.storage.clone(),
         None,
+        CompressionAlgorithm::default(),
     );
     loaded_segment.load().await.unwrap();
     let messages = loaded_segment
@@ -152,6 +158,91 @@ async fn should_persist_and_load_segment_with_messages() {
     assert_eq!(messages.len(), messages_count as usize);
 }
 
+#[tokio::test]
+async fn should_persist_and_load_compressed_segment_with_messages() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let start_offset = 0;
+    let compression_algorithms = [CompressionAlgorithm::Lz4, CompressionAlgorithm::Zstd];
+    for (index, compression_algorithm) in compression_algorithms.into_iter().enumerate() {
+        let partition_id = 1 + index as u32;
+        let mut segment = segment::Segment::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            start_offset,
+            setup.config.clone(),
+            setup.storage.clone(),
+            None,
+            compression_algorithm,
+        );
+
+        setup
+            .create_partition_directory(stream_id, topic_id, partition_id)
+            .await;
+        segment.persist().await.unwrap();
+        let messages_count = 10;
+        let payload = "test ".repeat(100);
+        for i in 0..messages_count {
+            let message = create_message(i, &payload, TimeStamp::now().to_micros());
+            segment.append_messages(&[Arc::new(message)]).await.unwrap();
+            // Persist the messages in two separate batches.
+            if i == messages_count / 2 {
+                segment
+                    .persist_messages(setup.storage.segment.clone())
+                    .await
+                    .unwrap();
+            }
+        }
+
+        segment
+            .persist_messages(setup.storage.segment.clone())
+            .await
+            .unwrap();
+        assert!(segment.current_size_bytes < segment.current_uncompressed_size_bytes);
+
+        // The compression algorithm is read from the segment rather than the passed one.
+        let mut loaded_segment = segment::Segment::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            start_offset,
+            setup.config.clone(),
+            setup.storage.clone(),
+            None,
+            CompressionAlgorithm::None,
+        );
+        loaded_segment.load().await.unwrap();
+        assert_eq!(loaded_segment.compression_algorithm, compression_algorithm);
+        assert_eq!(
+            loaded_segment.current_size_bytes,
+            segment.current_size_bytes
+        );
+        assert_eq!(
+            loaded_segment.current_uncompressed_size_bytes,
+            segment.current_uncompressed_size_bytes
+        );
+
+        let messages = loaded_segment
+            .get_messages(0, messages_count as u32)
+            .await
+            .unwrap();
+        assert_eq!(messages.len(), messages_count as usize);
+        for (offset, message) in messages.iter().enumerate() {
+            assert_eq!(message.offset, offset as u64);
+            assert_eq!(message.payload, Bytes::from(payload.clone()));
+        }
+
+        let messages = loaded_segment.get_messages(4, 3).await.unwrap();
+        let offsets = messages
+            .iter()
+            .map(|message| message.offset)
+            .collect::<Vec<u64>>();
+        assert_eq!(offsets, vec![4, 5, 6]);
+    }
+}
+
 #[tokio::test]
 async fn given_all_expired_messages_segment_should_be_expired() {
     let setup = TestSetup::init().await;
@@ -168,6 +259,7 @@ async fn given_all_expired_messages_segment_should_be_expired() {
         setup.config.clone(),
         setup.storage.clone(),
         Some(message_expiry),
+        CompressionAlgorithm::default(),
     );
 
     setup
@@ -216,6 +308,7 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
         setup.config.clone(),
         setup.storage.clone(),
         Some(message_expiry),
+        CompressionAlgorithm::default(),
     );
 
     setup
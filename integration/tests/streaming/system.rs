@@ -1,9 +1,15 @@
 use crate::streaming::common::test_setup::TestSetup;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
 use server::configs::server::PersonalAccessTokenConfig;
+use server::configs::system::SystemConfig;
+use server::streaming::segments::segment::LOG_EXTENSION;
 use server::streaming::session::Session;
 use server::streaming::systems::system::System;
+use std::sync::Arc;
 use tokio::fs;
+use tokio::sync::RwLock;
 
 #[tokio::test]
 async fn should_initialize_system_and_base_directories() {
@@ -41,9 +47,9 @@ async fn should_create_and_persist_stream() {
     let stream_name = "test";
     let session = Session::new(1, 1);
     system.init().await.unwrap();
+    let system = RwLock::new(system);
 
-    system
-        .create_stream(&session, stream_id, stream_name)
+    System::create_stream(&system, &session, stream_id, stream_name)
         .await
         .unwrap();
 
@@ -62,25 +68,175 @@ async fn should_delete_persisted_stream() {
     let stream_name = "test";
     let session = Session::new(1, 1);
     system.init().await.unwrap();
-    system
-        .create_stream(&session, stream_id, stream_name)
+    let system = RwLock::new(system);
+    System::create_stream(&system, &session, stream_id, stream_name)
         .await
         .unwrap();
     assert_persisted_stream(&setup.config.get_streams_path(), stream_id).await;
     let stream_path = system
+        .read()
+        .await
         .get_stream(&Identifier::numeric(stream_id).unwrap())
         .unwrap()
         .path
         .clone();
 
-    system
-        .delete_stream(&session, &Identifier::numeric(1).unwrap())
+    System::delete_stream(&system, &session, &Identifier::numeric(1).unwrap())
         .await
         .unwrap();
 
     assert!(fs::metadata(stream_path).await.is_err());
 }
 
+#[tokio::test]
+async fn should_create_user_with_given_status() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let session = Session::new(1, 1);
+    system.init().await.unwrap();
+
+    system
+        .create_user(
+            &session,
+            "inactive_user",
+            "secret",
+            UserStatus::Inactive,
+            None,
+        )
+        .await
+        .unwrap();
+    system
+        .create_user(&session, "active_user", "secret", UserStatus::Active, None)
+        .await
+        .unwrap();
+
+    let inactive_user = system
+        .get_user(&Identifier::named("inactive_user").unwrap())
+        .await
+        .unwrap();
+    let active_user = system
+        .get_user(&Identifier::named("active_user").unwrap())
+        .await
+        .unwrap();
+    assert_eq!(inactive_user.status, UserStatus::Inactive);
+    assert_eq!(active_user.status, UserStatus::Active);
+}
+
+#[tokio::test]
+async fn should_create_backup_and_restore_system_from_it() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let stream_id = 1;
+    let topic_id = 1;
+    let partitions_count = 2;
+    let username = "backup_user";
+    let session = Session::new(1, 1);
+    system.init().await.unwrap();
+    system
+        .create_user(&session, username, "secret", UserStatus::Active, None)
+        .await
+        .unwrap();
+    let system = RwLock::new(system);
+    System::create_stream(&system, &session, stream_id, "test")
+        .await
+        .unwrap();
+    System::create_topic(
+        &system,
+        &session,
+        &Identifier::numeric(stream_id).unwrap(),
+        topic_id,
+        "test",
+        partitions_count,
+        None,
+        CompressionAlgorithm::None,
+        false,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let backup = System::create_backup(&system, &session).await.unwrap();
+
+    assert!(backup.size_bytes > 0);
+    assert_eq!(
+        fs::metadata(&backup.path).await.unwrap().len(),
+        backup.size_bytes
+    );
+
+    let restored_config = Arc::new(SystemConfig {
+        path: format!("{}_restored", setup.config.path),
+        ..SystemConfig::default()
+    });
+    System::restore_backup(&restored_config, &backup.path)
+        .await
+        .unwrap();
+    let mut restored_system = System::new(
+        restored_config.clone(),
+        None,
+        PersonalAccessTokenConfig::default(),
+    );
+    restored_system.init().await.unwrap();
+
+    let stream = restored_system
+        .get_stream(&Identifier::numeric(stream_id).unwrap())
+        .unwrap();
+    let topic = stream
+        .get_topic(&Identifier::numeric(topic_id).unwrap())
+        .unwrap();
+    assert_eq!(topic.get_partitions().len() as u32, partitions_count);
+    for partition_id in 1..=partitions_count {
+        let segment_path = restored_config.get_segment_path(stream_id, topic_id, partition_id, 0);
+        assert!(fs::metadata(format!("{}.{}", segment_path, LOG_EXTENSION))
+            .await
+            .is_ok());
+    }
+    let user = restored_system
+        .get_user(&Identifier::named(username).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(user.username, username);
+
+    drop(restored_system);
+    fs::remove_dir_all(restored_config.get_system_path())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_keep_existing_state_when_restoring_invalid_backup() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(
+        setup.config.clone(),
+        Some(setup.db.clone()),
+        PersonalAccessTokenConfig::default(),
+    );
+    let stream_id = 1;
+    let session = Session::new(1, 1);
+    system.init().await.unwrap();
+    let system = RwLock::new(system);
+    System::create_stream(&system, &session, stream_id, "test")
+        .await
+        .unwrap();
+    drop(system);
+
+    let invalid_backup_path = format!("{}/invalid_backup.tar", setup.config.path);
+    fs::write(&invalid_backup_path, b"invalid").await.unwrap();
+
+    assert!(System::restore_backup(&setup.config, &invalid_backup_path)
+        .await
+        .is_err());
+    assert_persisted_stream(&setup.config.get_streams_path(), stream_id).await;
+}
+
 async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let streams_metadata = fs::metadata(streams_path).await.unwrap();
     assert!(streams_metadata.is_dir());
@@ -1,4 +1,5 @@
 use crate::streaming::common::test_setup::TestSetup;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use server::streaming::topics::topic::Topic;
 use tokio::fs;
 
@@ -19,6 +20,10 @@ async fn should_persist_topics_with_partitions_directories_and_info_file() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
+            false,
+            None,
+            None,
         )
         .unwrap();
 
@@ -50,6 +55,10 @@ async fn should_load_existing_topic_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
+            false,
+            Some(1_000_000),
+            Some(100_000),
         )
         .unwrap();
         topic.persist().await.unwrap();
@@ -69,6 +78,8 @@ async fn should_load_existing_topic_from_disk() {
         loaded_topic.load().await.unwrap();
 
         assert_eq!(loaded_topic.stream_id, topic.stream_id);
+        assert_eq!(loaded_topic.max_topic_size, topic.max_topic_size);
+        assert_eq!(loaded_topic.max_partition_size, topic.max_partition_size);
         assert_eq!(loaded_topic.topic_id, topic.topic_id);
         assert_eq!(loaded_topic.name, topic.name);
         assert_eq!(loaded_topic.path, topic.path);
@@ -93,6 +104,10 @@ async fn should_delete_existing_topic_from_disk() {
             setup.config.clone(),
             setup.storage.clone(),
             None,
+            CompressionAlgorithm::default(),
+            false,
+            None,
+            None,
         )
         .unwrap();
         topic.persist().await.unwrap();
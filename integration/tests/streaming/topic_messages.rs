@@ -1,16 +1,30 @@
 use crate::streaming::common::test_setup::TestSetup;
 use byte_unit::Byte;
+use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::Error;
+use iggy::messages::message_filter::{HeaderCondition, MessageFilter};
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{Partitioning, Producer};
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::Message;
+use iggy::utils::timestamp::TimeStamp;
 use server::configs::resource_quota::MemoryResourceQuota;
-use server::configs::system::{CacheConfig, SystemConfig};
+use server::configs::system::{
+    CacheConfig, PartitionConfig, SegmentConfig, SystemConfig, TieredStorageConfig,
+};
+use server::streaming::partitions::partition::Partition;
 use server::streaming::polling_consumer::PollingConsumer;
+use server::streaming::segments::segment::Segment;
 use server::streaming::topics::topic::Topic;
 use server::streaming::utils::hash;
 use std::collections::HashMap;
+use std::path::Path;
 use std::str::{from_utf8, FromStr};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
 
 #[tokio::test]
 async fn given_disabled_cache_all_messages_should_be_polled() {
@@ -69,7 +83,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
         sent_messages.push(get_message(from_utf8(&message.payload).unwrap()))
     }
     topic
-        .append_messages(&partitioning, messages)
+        .append_messages(&partitioning, None, messages)
         .await
         .unwrap();
 
@@ -80,6 +94,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
             partition_id,
             PollingStrategy::offset(0),
             messages_count,
+            None,
         )
         .await
         .unwrap();
@@ -109,7 +124,7 @@ async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -129,7 +144,7 @@ async fn given_key_partition_id_messages_should_be_appended_to_the_chosen_partit
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -153,7 +168,7 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
         let payload = get_payload(entity_id);
         let partitioning = Partitioning::messages_key_u32(entity_id);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -178,6 +193,647 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
     }
 }
 
+#[tokio::test]
+async fn given_compacted_topic_only_the_latest_messages_per_key_should_be_retained_in_closed_segments(
+) {
+    assert_compacted_messages(CacheConfig {
+        enabled: false,
+        ..Default::default()
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn given_compacted_topic_with_enabled_cache_removed_messages_should_not_be_polled_from_cache()
+{
+    assert_compacted_messages(CacheConfig {
+        enabled: true,
+        ..Default::default()
+    })
+    .await;
+}
+
+async fn assert_compacted_messages(cache: CacheConfig) {
+    let config = SystemConfig {
+        cache,
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size_bytes: 500,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let partition_id = 1;
+    let keys_count = 5;
+    setup.create_topics_directory(stream_id).await;
+    let compression_algorithms = [CompressionAlgorithm::None, CompressionAlgorithm::Lz4];
+    for (index, compression_algorithm) in compression_algorithms.into_iter().enumerate() {
+        let topic_id = 1 + index as u32;
+        let topic = Topic::create(
+            stream_id,
+            topic_id,
+            &format!("compacted-{}", topic_id),
+            1,
+            setup.config.clone(),
+            setup.storage.clone(),
+            None,
+            compression_algorithm,
+            true,
+            None,
+            None,
+        )
+        .unwrap();
+        topic.persist().await.unwrap();
+
+        // 3 versions of each key, then the tombstone of the first key and the 4th version of the others.
+        let mut sent_messages = Vec::new();
+        for version in 1..=4 {
+            for key in 1..=keys_count {
+                let payload = match (version, key) {
+                    (4, 1) => String::new(),
+                    _ => format!("key-{key}-version-{version}"),
+                };
+                let message = send_messages::Message::new(None, Bytes::from(payload.clone()), None);
+                topic
+                    .append_messages(
+                        &Partitioning::messages_key_u32(key),
+                        None,
+                        vec![Message::from_message(&message)],
+                    )
+                    .await
+                    .unwrap();
+                sent_messages.push((key, payload));
+            }
+        }
+
+        let partition = topic.get_partition(partition_id).unwrap();
+        let mut partition = partition.write().await;
+        let closed_end_offset = partition
+            .get_segments()
+            .iter()
+            .filter(|segment| segment.is_closed)
+            .map(|segment| segment.end_offset)
+            .max()
+            .unwrap();
+        let compacted_segments = partition
+            .compact(TimeStamp::now().to_micros(), 0)
+            .await
+            .unwrap();
+        assert!(compacted_segments.segments_count > 0);
+        assert!(compacted_segments.messages_count > 0);
+        drop(partition);
+
+        let mut latest_offsets = HashMap::new();
+        for (offset, (key, _)) in sent_messages.iter().enumerate() {
+            latest_offsets.insert(*key, offset as u64);
+        }
+        let expected_messages = sent_messages
+            .iter()
+            .enumerate()
+            .filter(|(offset, (key, payload))| {
+                let offset = *offset as u64;
+                offset > closed_end_offset || (latest_offsets[key] == offset && !payload.is_empty())
+            })
+            .map(|(offset, (_, payload))| (offset as u64, payload.clone()))
+            .collect::<Vec<_>>();
+
+        let mut loaded_topic = Topic::empty(
+            stream_id,
+            topic_id,
+            setup.config.clone(),
+            setup.storage.clone(),
+        );
+        loaded_topic.load().await.unwrap();
+        for topic in [&topic, &loaded_topic] {
+            let polled_messages = topic
+                .get_messages(
+                    PollingConsumer::Consumer(1, partition_id),
+                    partition_id,
+                    PollingStrategy::offset(0),
+                    sent_messages.len() as u32,
+                    None,
+                )
+                .await
+                .unwrap();
+            let polled_messages = polled_messages
+                .messages
+                .iter()
+                .map(|message| {
+                    assert!(message.get_messages_key().is_some());
+                    (
+                        message.offset,
+                        from_utf8(&message.payload).unwrap().to_string(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(polled_messages, expected_messages);
+        }
+    }
+}
+
+#[tokio::test]
+async fn given_topic_exceeding_size_limits_the_oldest_closed_segments_should_be_deleted() {
+    let config = SystemConfig {
+        cache: CacheConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size_bytes: 500,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let partitions_count = 2;
+    let messages_count = 50;
+    setup.create_topics_directory(stream_id).await;
+    let size_limits = [(Some(1500), None), (None, Some(700))];
+    for (index, (max_topic_size, max_partition_size)) in size_limits.into_iter().enumerate() {
+        let topic_id = 1 + index as u32;
+        let topic = Topic::create(
+            stream_id,
+            topic_id,
+            &format!("limited-{}", topic_id),
+            partitions_count,
+            setup.config.clone(),
+            setup.storage.clone(),
+            None,
+            CompressionAlgorithm::None,
+            false,
+            max_topic_size,
+            max_partition_size,
+        )
+        .unwrap();
+        topic.persist().await.unwrap();
+
+        for id in 1..=messages_count {
+            let payload = Bytes::from(vec![id as u8; 100]);
+            let message = send_messages::Message::new(Some(id as u128), payload, None);
+            topic
+                .append_messages(
+                    &Partitioning::partition_id(1 + id % partitions_count),
+                    None,
+                    vec![Message::from_message(&message)],
+                )
+                .await
+                .unwrap();
+        }
+
+        let segments_count = topic.get_segments_count().await;
+        let deleted_segments = topic.delete_oversized_segments().await.unwrap().unwrap();
+        assert!(deleted_segments.segments_count > 0);
+        assert!(deleted_segments.messages_count > 0);
+        assert_eq!(
+            topic.get_segments_count().await,
+            segments_count - deleted_segments.segments_count
+        );
+        assert!(topic.delete_oversized_segments().await.unwrap().is_none());
+
+        if let Some(max_topic_size) = max_topic_size {
+            assert!(topic.get_size_bytes().await <= max_topic_size);
+        }
+
+        for partition in topic.get_partitions() {
+            let partition = partition.read().await;
+            if let Some(max_partition_size) = max_partition_size {
+                assert!(partition.get_size_bytes() <= max_partition_size);
+            }
+
+            // The oldest segments are deleted first and the current one is always kept.
+            let segments = partition.get_segments();
+            assert!(segments[0].start_offset > 0);
+            assert!(!segments.last().unwrap().is_closed);
+            for window in segments.windows(2) {
+                assert_eq!(window[0].end_offset + 1, window[1].start_offset);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn given_idempotent_producer_duplicated_messages_should_be_skipped_and_gaps_rejected() {
+    let config = SystemConfig {
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let topic = init_topic(&setup, 1).await;
+    let partition_id = 1;
+    let partitioning = Partitioning::partition_id(partition_id);
+    let producer = Producer { id: 1, sequence: 0 };
+    let messages = |ids: std::ops::RangeInclusive<u32>| {
+        ids.map(|id| get_message(&get_payload(id)))
+            .collect::<Vec<_>>()
+    };
+
+    let appended_messages = topic
+        .append_messages(&partitioning, Some(&producer), messages(1..=3))
+        .await
+        .unwrap();
+    assert_eq!(appended_messages.count, 3);
+    // The retried batch was already appended, so it's skipped entirely.
+    let appended_messages = topic
+        .append_messages(&partitioning, Some(&producer), messages(1..=3))
+        .await
+        .unwrap();
+    assert_eq!(appended_messages.count, 0);
+    // Only the messages with the sequence numbers greater than the last appended one are added.
+    let appended_messages = topic
+        .append_messages(
+            &partitioning,
+            Some(&Producer { id: 1, sequence: 2 }),
+            messages(3..=5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(appended_messages.count, 2);
+    assert_messages(&topic, partition_id, 5).await;
+
+    let result = topic
+        .append_messages(
+            &partitioning,
+            Some(&Producer {
+                id: 1,
+                sequence: 10,
+            }),
+            messages(11..=11),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::OutOfOrderProducerSequence(10, 5, 1))
+    ));
+
+    // The other producers have their own sequence numbers.
+    topic
+        .append_messages(
+            &partitioning,
+            Some(&Producer { id: 2, sequence: 0 }),
+            messages(6..=6),
+        )
+        .await
+        .unwrap();
+    assert_messages(&topic, partition_id, 6).await;
+
+    let mut loaded_topic = Topic::empty(
+        topic.stream_id,
+        topic.topic_id,
+        setup.config.clone(),
+        setup.storage.clone(),
+    );
+    loaded_topic.load().await.unwrap();
+    loaded_topic
+        .append_messages(
+            &partitioning,
+            Some(&Producer { id: 1, sequence: 3 }),
+            messages(4..=6),
+        )
+        .await
+        .unwrap();
+    assert_messages(&loaded_topic, partition_id, 7).await;
+}
+
+#[tokio::test]
+async fn given_delayed_messages_next_messages_should_skip_them_until_they_are_due() {
+    let config = SystemConfig {
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let topic = init_topic(&setup, 1).await;
+    let partition_id = 1;
+    let partitioning = Partitioning::partition_id(partition_id);
+    let now = TimeStamp::now().to_micros();
+    let delay = Duration::from_millis(300);
+    let messages = vec![
+        get_message(&get_payload(1)),
+        get_delayed_message(&get_payload(2), now - 1_000_000),
+        get_delayed_message(&get_payload(3), now + 3_600_000_000),
+        get_message(&get_payload(4)),
+        get_delayed_message(&get_payload(5), now + delay.as_micros() as u64),
+    ];
+    topic
+        .append_messages(&partitioning, None, messages)
+        .await
+        .unwrap();
+
+    // The messages which are due are delivered, the delayed ones don't hold back the following ones.
+    let consumer = PollingConsumer::Consumer(1, partition_id);
+    let polled_messages = topic
+        .get_messages(consumer, partition_id, PollingStrategy::next(), 10, None)
+        .await
+        .unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![0, 1, 3]);
+    assert_eq!(polled_messages.last_scanned_offset, Some(4));
+    topic.store_consumer_offset(consumer, 4).await.unwrap();
+
+    // Once the delayed message is due, it's delivered only once, without moving the consumer offset back.
+    sleep(delay).await;
+    let polled_messages = topic
+        .get_messages(consumer, partition_id, PollingStrategy::next(), 10, None)
+        .await
+        .unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![4]);
+    assert_eq!(polled_messages.last_scanned_offset, None);
+
+    let polled_messages = topic
+        .get_messages(consumer, partition_id, PollingStrategy::next(), 10, None)
+        .await
+        .unwrap();
+    assert!(polled_messages.messages.is_empty());
+    assert_eq!(polled_messages.last_scanned_offset, None);
+    assert_eq!(
+        topic
+            .get_next_delivery_at(partition_id, TimeStamp::now().to_micros())
+            .await,
+        Some(now + 3_600_000_000)
+    );
+
+    // The delayed messages are persisted, can still be polled by offset and are indexed again on load
+    // from the delayed index of the segment, which has the delivery timestamp and the offset of each of them.
+    let delayed_index_path = {
+        let partition = topic.get_partition(partition_id).unwrap();
+        let partition = partition.read().await;
+        partition.get_segments()[0].delayed_index_path.clone()
+    };
+    assert_eq!(
+        tokio::fs::metadata(&delayed_index_path)
+            .await
+            .unwrap()
+            .len(),
+        32
+    );
+    let mut loaded_topic = Topic::empty(
+        topic.stream_id,
+        topic.topic_id,
+        setup.config.clone(),
+        setup.storage.clone(),
+    );
+    loaded_topic.load().await.unwrap();
+    let polled_messages = loaded_topic
+        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 10, None)
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 5);
+    assert_eq!(
+        polled_messages.messages[2].get_deliver_at(),
+        Some(now + 3_600_000_000)
+    );
+    assert_eq!(
+        loaded_topic
+            .get_next_delivery_at(partition_id, TimeStamp::now().to_micros())
+            .await,
+        Some(now + 3_600_000_000)
+    );
+}
+
+#[tokio::test]
+async fn given_filter_matching_sparse_messages_scan_should_be_limited_by_budget() {
+    let config = SystemConfig {
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            max_scanned_messages: 3,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let topic = init_topic(&setup, 1).await;
+    let partition_id = 1;
+    let partitioning = Partitioning::partition_id(partition_id);
+    let messages = (0..10)
+        .map(|offset| match offset {
+            1 | 8 => get_message_with_header(&get_payload(offset), "kind"),
+            _ => get_message(&get_payload(offset)),
+        })
+        .collect();
+    topic
+        .append_messages(&partitioning, None, messages)
+        .await
+        .unwrap();
+
+    // Each poll scans up to 3 messages, and the consumer continues from the last scanned one.
+    let filter = MessageFilter::new(vec![HeaderCondition::exists(
+        HeaderKey::new("kind").unwrap(),
+    )])
+    .unwrap();
+    let consumer = PollingConsumer::Consumer(1, partition_id);
+    let expected_polls = [
+        (vec![1], Some(2)),
+        (vec![], Some(5)),
+        (vec![8], Some(8)),
+        (vec![], Some(9)),
+    ];
+    for (expected_offsets, expected_last_scanned_offset) in expected_polls {
+        let polled_messages = topic
+            .get_messages(
+                consumer,
+                partition_id,
+                PollingStrategy::next(),
+                10,
+                Some(&filter),
+            )
+            .await
+            .unwrap();
+        assert_eq!(get_offsets(&polled_messages.messages), expected_offsets);
+        assert_eq!(
+            polled_messages.last_scanned_offset,
+            expected_last_scanned_offset
+        );
+        topic
+            .store_consumer_offset(consumer, expected_last_scanned_offset.unwrap())
+            .await
+            .unwrap();
+    }
+
+    // Once enough messages were found, the following ones are left to be scanned by the next poll.
+    let polled_messages = topic
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::offset(0),
+            1,
+            Some(&filter),
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![1]);
+    assert_eq!(polled_messages.last_scanned_offset, Some(1));
+
+    let polled_messages = topic
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::last(),
+            10,
+            Some(&filter),
+        )
+        .await
+        .unwrap();
+    assert_eq!(get_offsets(&polled_messages.messages), vec![8]);
+    assert_eq!(polled_messages.last_scanned_offset, Some(9));
+}
+
+#[tokio::test]
+async fn given_offloaded_segments_messages_should_be_fetched_from_the_remote_storage() {
+    let config = SystemConfig {
+        cache: CacheConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size_bytes: 500,
+            ..Default::default()
+        },
+        tiered_storage: TieredStorageConfig {
+            enabled: true,
+            retain_local_segments: 0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let partition_id = 1;
+    let messages_count = 30;
+    setup.create_topics_directory(stream_id).await;
+    let compression_algorithms = [CompressionAlgorithm::None, CompressionAlgorithm::Lz4];
+    for (index, compression_algorithm) in compression_algorithms.into_iter().enumerate() {
+        let topic_id = 1 + index as u32;
+        let topic = Topic::create(
+            stream_id,
+            topic_id,
+            &format!("tiered-{}", topic_id),
+            1,
+            setup.config.clone(),
+            setup.storage.clone(),
+            None,
+            compression_algorithm,
+            false,
+            None,
+            None,
+        )
+        .unwrap();
+        topic.persist().await.unwrap();
+
+        let mut sent_payloads = Vec::new();
+        for id in 1..=messages_count {
+            let payload = get_payload(id);
+            topic
+                .append_messages(
+                    &Partitioning::partition_id(partition_id),
+                    None,
+                    vec![get_message(&payload)],
+                )
+                .await
+                .unwrap();
+            sent_payloads.push(payload);
+        }
+
+        let partition = topic.get_partition(partition_id).unwrap();
+        let offloaded_segments = Partition::offload_segments(&partition, 0).await.unwrap();
+        let partition = partition.read().await;
+        assert!(offloaded_segments.uploaded_segments_count > 0);
+        assert_eq!(
+            offloaded_segments.uploaded_segments_count,
+            offloaded_segments.removed_local_segments_count
+        );
+        let uploaded_segments = partition
+            .get_segments()
+            .iter()
+            .filter(|segment| segment.is_uploaded)
+            .map(|segment| (segment.log_path.clone(), get_remote_path(&setup, segment)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            uploaded_segments.len() as u32,
+            offloaded_segments.uploaded_segments_count
+        );
+        assert!(partition
+            .get_segments()
+            .iter()
+            .all(|segment| segment.is_uploaded == segment.is_closed));
+        for (local_path, remote_path) in &uploaded_segments {
+            assert!(!Path::new(local_path).exists());
+            assert!(Path::new(remote_path).exists());
+        }
+        drop(partition);
+
+        assert_polled_payloads(&topic, partition_id, &sent_payloads).await;
+        for (local_path, _) in &uploaded_segments {
+            assert!(Path::new(local_path).exists());
+        }
+
+        let partition = topic.get_partition(partition_id).unwrap();
+        let offloaded_segments = Partition::offload_segments(&partition, 0).await.unwrap();
+        assert_eq!(offloaded_segments.uploaded_segments_count, 0);
+        assert_eq!(
+            offloaded_segments.removed_local_segments_count as usize,
+            uploaded_segments.len()
+        );
+
+        let mut loaded_topic = Topic::empty(
+            stream_id,
+            topic_id,
+            setup.config.clone(),
+            setup.storage.clone(),
+        );
+        loaded_topic.load().await.unwrap();
+        assert_polled_payloads(&loaded_topic, partition_id, &sent_payloads).await;
+
+        loaded_topic.delete().await.unwrap();
+        for (_, remote_path) in &uploaded_segments {
+            assert!(!Path::new(remote_path).exists());
+        }
+    }
+}
+
+fn get_remote_path(setup: &TestSetup, segment: &Segment) -> String {
+    segment.log_path.replace(
+        &setup.config.get_system_path(),
+        &setup.config.get_tiered_storage_path(),
+    )
+}
+
+async fn assert_polled_payloads(topic: &Topic, partition_id: u32, expected_payloads: &[String]) {
+    let polled_messages = topic
+        .get_messages(
+            PollingConsumer::Consumer(1, partition_id),
+            partition_id,
+            PollingStrategy::offset(0),
+            expected_payloads.len() as u32,
+            None,
+        )
+        .await
+        .unwrap();
+    let polled_payloads = polled_messages
+        .messages
+        .iter()
+        .map(|message| from_utf8(&message.payload).unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(polled_payloads, expected_payloads);
+}
+
 fn get_payload(id: u32) -> String {
     format!("message-{}", id)
 }
@@ -185,7 +841,13 @@ fn get_payload(id: u32) -> String {
 async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u32) {
     let consumer = PollingConsumer::Consumer(0, partition_id);
     let polled_messages = topic
-        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000)
+        .get_messages(
+            consumer,
+            partition_id,
+            PollingStrategy::offset(0),
+            1000,
+            None,
+        )
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, expected_messages);
@@ -204,6 +866,10 @@ async fn init_topic(setup: &TestSetup, partitions_count: u32) -> Topic {
         setup.config.clone(),
         setup.storage.clone(),
         None,
+        CompressionAlgorithm::default(),
+        false,
+        None,
+        None,
     )
     .unwrap();
     topic.persist().await.unwrap();
@@ -214,6 +880,28 @@ fn get_message(payload: &str) -> Message {
     Message::from_message(&send_messages::Message::from_str(payload).unwrap())
 }
 
+fn get_message_with_header(payload: &str, key: &str) -> Message {
+    let mut message = send_messages::Message::from_str(payload).unwrap();
+    message.headers = Some(HashMap::from([(
+        HeaderKey::new(key).unwrap(),
+        HeaderValue::from_bool(true).unwrap(),
+    )]));
+    Message::from_message(&message)
+}
+
+fn get_delayed_message(payload: &str, deliver_at: u64) -> Message {
+    Message::from_message(
+        &send_messages::Message::from_str(payload)
+            .unwrap()
+            .with_deliver_at(deliver_at)
+            .unwrap(),
+    )
+}
+
+fn get_offsets(messages: &[Arc<Message>]) -> Vec<u64> {
+    messages.iter().map(|message| message.offset).collect()
+}
+
 fn create_payload(size: u32) -> String {
     let mut payload = String::with_capacity(size as usize);
     for i in 0..size {
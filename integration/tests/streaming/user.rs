@@ -2,6 +2,7 @@ use crate::streaming::common::test_setup::TestSetup;
 use iggy::models::permissions::{
     GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
 };
+use iggy::models::user_quota::UserQuota;
 use iggy::models::user_status::UserStatus;
 use iggy::utils::timestamp::TimeStamp;
 use server::streaming::users::user::User;
@@ -68,6 +69,7 @@ fn assert_user(user: &User, loaded_user: &User) {
     assert_eq!(loaded_user.password, user.password);
     assert_eq!(loaded_user.created_at, user.created_at);
     assert_eq!(loaded_user.status, user.status);
+    assert_eq!(loaded_user.quota, user.quota);
     if user.permissions.is_none() {
         assert!(loaded_user.permissions.is_none());
         return;
@@ -202,5 +204,6 @@ fn create_user(id: u32) -> User {
                 map
             }),
         }),
+        quota: Some(UserQuota::new(1000, 10)),
     }
 }
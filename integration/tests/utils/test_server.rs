@@ -82,6 +82,12 @@ impl TestServer {
         }
     }
 
+    pub fn set_env(&mut self, key: &str, value: &str) {
+        self.envs
+            .get_or_insert_with(HashMap::new)
+            .insert(key.to_string(), value.to_string());
+    }
+
     pub fn start(&mut self) {
         self.set_server_addrs_from_env();
         self.wait_until_server_freed_ports();
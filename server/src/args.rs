@@ -5,4 +5,8 @@ use clap::Parser;
 pub struct Args {
     #[arg(short, long, default_value = "file")]
     pub config_provider: String,
+
+    /// Path of the backup archive, from which the server state is restored on startup
+    #[arg(long)]
+    pub restore_backup: Option<String>,
 }
@@ -1,6 +1,7 @@
 use crate::binary::handlers::consumer_groups::{
     create_consumer_group_handler, delete_consumer_group_handler, get_consumer_group_handler,
-    get_consumer_groups_handler, join_consumer_group_handler, leave_consumer_group_handler,
+    get_consumer_groups_handler, heartbeat_consumer_group_handler, join_consumer_group_handler,
+    leave_consumer_group_handler,
 };
 use crate::binary::handlers::consumer_offsets::*;
 use crate::binary::handlers::messages::*;
@@ -9,9 +10,16 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::schemas::{
+    delete_schema_handler, get_schema_handler, get_schemas_handler, register_schema_handler,
+    update_schema_settings_handler,
+};
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
+use crate::binary::handlers::transactions::{
+    abort_transaction_handler, begin_transaction_handler, commit_transaction_handler,
+};
 use crate::binary::handlers::users::{
     change_password_handler, create_user_handler, delete_user_handler, get_user_handler,
     get_users_handler, login_user_handler, logout_user_handler, update_permissions_handler,
@@ -20,8 +28,10 @@ use crate::binary::handlers::users::{
 use crate::binary::sender::Sender;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use crate::telemetry;
 use iggy::command::Command;
 use iggy::error::Error;
+use opentelemetry::trace::{Span, Status};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::debug;
@@ -32,14 +42,23 @@ pub async fn handle(
     session: &mut Session,
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
-    let result = try_handle(command, sender, session, system).await;
+    let mut span = telemetry::start_command_span(command, session.client_id, session.user_id);
+    let result = try_handle(command, sender, session, system.clone()).await;
     if result.is_ok() {
         debug!("Command was handled successfully, session: {session}.",);
+        span.end();
         return Ok(());
     }
 
     let error = result.err().unwrap();
+    span.set_status(Status::error(error.to_string()));
+    span.end();
     debug!("Command was not handled successfully, session: {session}, error: {error}.",);
+    system
+        .read()
+        .await
+        .metrics
+        .increment_errors(error.as_code(), error.as_string());
     sender.send_error_response(error).await?;
     Ok(())
 }
@@ -51,11 +70,21 @@ async fn try_handle(
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     debug!("Handling command '{command}', session: {session}...");
+    if changes_metadata(command) {
+        system.read().await.ensure_cluster_controller().await?;
+    }
+
     match command {
         Command::Ping(command) => ping_handler::handle(command, sender, session).await,
+        Command::GetClusterMetadata(command) => {
+            get_cluster_metadata_handler::handle(command, sender, session, system).await
+        }
         Command::GetStats(command) => {
             get_stats_handler::handle(command, sender, session, system).await
         }
+        Command::CreateBackup(command) => {
+            create_backup_handler::handle(command, sender, session, system).await
+        }
         Command::GetMe(command) => get_me_handler::handle(command, sender, session, system).await,
         Command::GetClient(command) => {
             get_client_handler::handle(command, sender, session, system).await
@@ -105,6 +134,12 @@ async fn try_handle(
         Command::SendMessages(command) => {
             send_messages_handler::handle(command, sender, session, system).await
         }
+        Command::PoisonMessage(command) => {
+            poison_message_handler::handle(command, sender, session, system).await
+        }
+        Command::ReplicateMessages(command) => {
+            replicate_messages_handler::handle(command, sender, session, system).await
+        }
         Command::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, session, system).await
         }
@@ -114,6 +149,18 @@ async fn try_handle(
         Command::StoreConsumerOffset(command) => {
             store_consumer_offset_handler::handle(command, sender, session, system).await
         }
+        Command::GetConsumerLag(command) => {
+            get_consumer_lag_handler::handle(command, sender, session, system).await
+        }
+        Command::BeginTransaction(command) => {
+            begin_transaction_handler::handle(command, sender, session, system).await
+        }
+        Command::CommitTransaction(command) => {
+            commit_transaction_handler::handle(command, sender, session, system).await
+        }
+        Command::AbortTransaction(command) => {
+            abort_transaction_handler::handle(command, sender, session, system).await
+        }
         Command::GetStream(command) => {
             get_stream_handler::handle(command, sender, session, system).await
         }
@@ -168,5 +215,44 @@ async fn try_handle(
         Command::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, session, system).await
         }
+        Command::HeartbeatConsumerGroup(command) => {
+            heartbeat_consumer_group_handler::handle(command, sender, session, system).await
+        }
+        Command::GetSchema(command) => {
+            get_schema_handler::handle(command, sender, session, system).await
+        }
+        Command::GetSchemas(command) => {
+            get_schemas_handler::handle(command, sender, session, system).await
+        }
+        Command::RegisterSchema(command) => {
+            register_schema_handler::handle(command, sender, session, system).await
+        }
+        Command::DeleteSchema(command) => {
+            delete_schema_handler::handle(command, sender, session, system).await
+        }
+        Command::UpdateSchemaSettings(command) => {
+            update_schema_settings_handler::handle(command, sender, session, system).await
+        }
     }
 }
+
+/// Polling the messages might wait for the new ones, so it's handled concurrently with the next requests
+/// from the same connection, with its own copy of the session. The other requests are handled in order.
+pub fn is_handled_concurrently(command: &Command) -> bool {
+    matches!(command, Command::PollMessages(_))
+}
+
+/// In the cluster mode, the streams, topics and partitions can be changed only on the controller.
+fn changes_metadata(command: &Command) -> bool {
+    matches!(
+        command,
+        Command::CreateStream(_)
+            | Command::UpdateStream(_)
+            | Command::DeleteStream(_)
+            | Command::CreateTopic(_)
+            | Command::UpdateTopic(_)
+            | Command::DeleteTopic(_)
+            | Command::CreatePartitions(_)
+            | Command::DeletePartitions(_)
+    )
+}
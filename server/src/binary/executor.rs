@@ -0,0 +1,93 @@
+use crate::binary::command;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::command::Command;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::{mpsc, OwnedSemaphorePermit, RwLock, Semaphore};
+use tracing::{debug, error};
+
+const MAX_IN_FLIGHT_REQUESTS: usize = 1000;
+
+/// Handles the pipelined requests of a single connection in the order, in which they were received,
+/// so e.g. the messages of the idempotent producer are appended in the order of their sequence numbers.
+/// Only polling the messages, which might wait for the new ones, is handled by its own task, so it doesn't block the others.
+/// Each request holds the permit until it's handled, so the connection stops reading the requests once the limit is reached.
+#[derive(Debug)]
+pub struct RequestExecutor {
+    requests: mpsc::Sender<Request>,
+    permits: Arc<Semaphore>,
+}
+
+struct Request {
+    command: Command,
+    sender: Box<dyn Sender>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl RequestExecutor {
+    pub fn start(session: Session, system: Arc<RwLock<System>>, transport: &'static str) -> Self {
+        let (requests, receiver) = mpsc::channel(MAX_IN_FLIGHT_REQUESTS);
+        tokio::spawn(handle_requests(receiver, session, system, transport));
+        RequestExecutor {
+            requests,
+            permits: Arc::new(Semaphore::new(MAX_IN_FLIGHT_REQUESTS)),
+        }
+    }
+
+    /// Waits until the number of the requests being handled is below the limit and queues the request.
+    pub async fn execute(&self, command: Command, sender: Box<dyn Sender>) -> Result<(), Error> {
+        let permit = self
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| Error::NotConnected)?;
+        let request = Request {
+            command,
+            sender,
+            _permit: permit,
+        };
+        if self.requests.send(request).await.is_err() {
+            return Err(Error::NotConnected);
+        }
+
+        Ok(())
+    }
+}
+
+async fn handle_requests(
+    mut requests: mpsc::Receiver<Request>,
+    mut session: Session,
+    system: Arc<RwLock<System>>,
+    transport: &'static str,
+) {
+    while let Some(mut request) = requests.recv().await {
+        if command::is_handled_concurrently(&request.command) {
+            let mut session = session.clone();
+            let system = system.clone();
+            tokio::spawn(async move {
+                handle_request(&mut request, &mut session, system, transport).await;
+            });
+            continue;
+        }
+
+        handle_request(&mut request, &mut session, system.clone(), transport).await;
+    }
+}
+
+async fn handle_request(
+    request: &mut Request,
+    session: &mut Session,
+    system: Arc<RwLock<System>>,
+    transport: &'static str,
+) {
+    if let Err(error) =
+        command::handle(&request.command, request.sender.as_mut(), session, system).await
+    {
+        error!("Error when handling the {transport} request: {:?}", error);
+        return;
+    }
+    debug!("Handled a {transport} request.");
+}
@@ -23,6 +23,9 @@ pub async fn handle(
             &command.topic_id,
             command.consumer_group_id,
             &command.name,
+            command.partition_assignment_strategy,
+            command.session_timeout,
+            command.dead_letter_topic,
         )
         .await?;
     sender.send_empty_ok_response().await?;
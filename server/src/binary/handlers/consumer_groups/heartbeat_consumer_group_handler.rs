@@ -0,0 +1,29 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &HeartbeatConsumerGroup,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    system
+        .heartbeat_consumer_group(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            &command.consumer_group_id,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetConsumerLag,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let lag = system
+        .get_consumer_lag(
+            session,
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+        )
+        .await?;
+    let lag = mapper::map_consumer_lag(&lag);
+    sender.send_ok_response(&lag).await?;
+    Ok(())
+}
@@ -1,2 +1,3 @@
+pub mod get_consumer_lag_handler;
 pub mod get_consumer_offset_handler;
 pub mod store_consumer_offset_handler;
@@ -1,2 +1,4 @@
+pub mod poison_message_handler;
 pub mod poll_messages_handler;
+pub mod replicate_messages_handler;
 pub mod send_messages_handler;
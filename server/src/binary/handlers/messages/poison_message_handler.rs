@@ -0,0 +1,33 @@
+use crate::binary::sender::Sender;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::messages::poison_message::PoisonMessage;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &PoisonMessage,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let consumer =
+        PollingConsumer::from_consumer(&command.consumer, session.client_id, command.partition_id);
+    system
+        .poison_message(
+            session,
+            consumer,
+            &command.stream_id,
+            &command.topic_id,
+            command.offset,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
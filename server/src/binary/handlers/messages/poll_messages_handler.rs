@@ -20,16 +20,21 @@ pub async fn handle(
     debug!("session: {session}, command: {command}");
     let consumer =
         PollingConsumer::from_consumer(&command.consumer, session.client_id, command.partition_id);
-    let system = system.read().await;
-    let messages = system
-        .poll_messages(
-            session,
-            consumer,
-            &command.stream_id,
-            &command.topic_id,
-            PollingArgs::new(command.strategy, command.count, command.auto_commit),
-        )
-        .await?;
+    let messages = System::poll_messages_with_wait(
+        &system,
+        session,
+        consumer,
+        &command.stream_id,
+        &command.topic_id,
+        PollingArgs::new(
+            command.strategy,
+            command.count,
+            command.auto_commit,
+            command.max_wait,
+            command.filter.clone(),
+        ),
+    )
+    .await?;
     let messages = mapper::map_polled_messages(&messages);
     sender.send_ok_response(&messages).await?;
     Ok(())
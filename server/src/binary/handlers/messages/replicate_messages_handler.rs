@@ -0,0 +1,24 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::cluster::replicate_messages::ReplicateMessages;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &ReplicateMessages,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let (epoch_start_offset, messages) = system.replicate_messages(session, command).await?;
+    let messages = mapper::map_replicated_messages(epoch_start_offset, &messages);
+    sender.send_ok_response(&messages).await?;
+    Ok(())
+}
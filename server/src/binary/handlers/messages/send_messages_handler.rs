@@ -3,10 +3,10 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use anyhow::Result;
 use iggy::error::Error;
-use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_messages::{Acks, SendMessages};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::debug;
+use tracing::{debug, warn};
 
 pub async fn handle(
     command: &SendMessages,
@@ -15,16 +15,35 @@ pub async fn handle(
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
-    let system = system.read().await;
-    system
-        .append_messages(
-            session,
-            &command.stream_id,
-            &command.topic_id,
-            &command.partitioning,
-            &command.messages,
-        )
-        .await?;
+    if command.acks == Acks::None {
+        // The messages are still appended before handling the next request of the connection, to keep their order.
+        sender.send_empty_ok_response().await?;
+        if let Err(error) = append_messages(command, session, &system).await {
+            warn!("Failed to append the messages sent without acks, session: {session}, error: {error}");
+        }
+        return Ok(());
+    }
+
+    append_messages(command, session, &system).await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
+
+async fn append_messages(
+    command: &SendMessages,
+    session: &Session,
+    system: &RwLock<System>,
+) -> Result<(), Error> {
+    System::append_messages_with_acks(
+        system,
+        session,
+        &command.stream_id,
+        &command.topic_id,
+        &command.partitioning,
+        command.producer.as_ref(),
+        command.transaction_id,
+        command.acks,
+        &command.messages,
+    )
+    .await
+}
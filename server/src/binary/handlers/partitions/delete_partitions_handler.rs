@@ -15,15 +15,14 @@ pub async fn handle(
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write().await;
-    system
-        .delete_partitions(
-            session,
-            &command.stream_id,
-            &command.topic_id,
-            command.partitions_count,
-        )
-        .await?;
+    System::delete_partitions(
+        &system,
+        session,
+        &command.stream_id,
+        &command.topic_id,
+        command.partitions_count,
+    )
+    .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
@@ -0,0 +1,29 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::schemas::delete_schema::DeleteSchema;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &DeleteSchema,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write().await;
+    system
+        .delete_schema(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.schema_id,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
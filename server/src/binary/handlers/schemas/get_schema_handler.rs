@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::schemas::get_schema::GetSchema;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetSchema,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let schema = system.get_schema(
+        session,
+        &command.stream_id,
+        &command.topic_id,
+        command.schema_id,
+    )?;
+    let schema = mapper::map_schema(schema);
+    sender.send_ok_response(schema.as_slice()).await?;
+    Ok(())
+}
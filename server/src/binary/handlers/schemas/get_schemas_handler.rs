@@ -0,0 +1,24 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::schemas::get_schemas::GetSchemas;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetSchemas,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let topic = system.get_schemas(session, &command.stream_id, &command.topic_id)?;
+    let schemas = mapper::map_topic_schemas(topic);
+    sender.send_ok_response(schemas.as_slice()).await?;
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod delete_schema_handler;
+pub mod get_schema_handler;
+pub mod get_schemas_handler;
+pub mod register_schema_handler;
+pub mod update_schema_settings_handler;
@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::schemas::schema::SchemaDefinition;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::schemas::register_schema::RegisterSchema;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &RegisterSchema,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    // The definition is parsed before taking the lock, as it might take a while.
+    let definition = SchemaDefinition::parse_blocking(command.format, &command.definition).await?;
+    let mut system = system.write().await;
+    let schema = system
+        .register_schema(session, &command.stream_id, &command.topic_id, definition)
+        .await?;
+    let schema = mapper::map_schema(schema);
+    sender.send_ok_response(schema.as_slice()).await?;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::schemas::update_schema_settings::UpdateSchemaSettings;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &UpdateSchemaSettings,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let mut system = system.write().await;
+    system
+        .update_schema_settings(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.compatibility,
+            command.validation_enabled,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -15,8 +15,7 @@ pub async fn handle(
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write().await;
-    system.delete_stream(session, &command.stream_id).await?;
+    System::delete_stream(&system, session, &command.stream_id).await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
@@ -15,10 +15,7 @@ pub async fn handle(
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write().await;
-    system
-        .update_stream(session, &command.stream_id, &command.name)
-        .await?;
+    System::update_stream(&system, session, &command.stream_id, &command.name).await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
@@ -0,0 +1,22 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::Error;
+use iggy::system::create_backup::CreateBackup;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &CreateBackup,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let backup = System::create_backup(&system, session).await?;
+    let bytes = mapper::map_backup(&backup);
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
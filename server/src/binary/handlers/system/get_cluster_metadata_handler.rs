@@ -0,0 +1,23 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::cluster::get_cluster_metadata::GetClusterMetadata;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+pub async fn handle(
+    command: &GetClusterMetadata,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let metadata = system.get_cluster_metadata(session).await?;
+    let bytes = mapper::map_cluster_metadata(&metadata);
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
@@ -21,7 +21,8 @@ pub async fn handle(
         let client = system.get_client(session, session.client_id).await?;
         {
             let client = client.read().await;
-            bytes = mapper::map_client(&client).await;
+            let quota_usage = system.quotas.get_client_usage(client.client_id);
+            bytes = mapper::map_client(&client, &quota_usage).await;
         }
     }
     sender.send_ok_response(bytes.as_slice()).await?;
@@ -1,5 +1,7 @@
+pub mod create_backup_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
+pub mod get_cluster_metadata_handler;
 pub mod get_me_handler;
 pub mod get_stats_handler;
 pub mod ping_handler;
@@ -15,17 +15,20 @@ pub async fn handle(
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write().await;
-    system
-        .create_topic(
-            session,
-            &command.stream_id,
-            command.topic_id,
-            &command.name,
-            command.partitions_count,
-            command.message_expiry,
-        )
-        .await?;
+    System::create_topic(
+        &system,
+        session,
+        &command.stream_id,
+        command.topic_id,
+        &command.name,
+        command.partitions_count,
+        command.message_expiry,
+        command.compression_algorithm,
+        command.compaction_enabled,
+        command.max_topic_size,
+        command.max_partition_size,
+    )
+    .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
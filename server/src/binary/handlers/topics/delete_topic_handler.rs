@@ -15,10 +15,7 @@ pub async fn handle(
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write().await;
-    system
-        .delete_topic(session, &command.stream_id, &command.topic_id)
-        .await?;
+    System::delete_topic(&system, session, &command.stream_id, &command.topic_id).await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
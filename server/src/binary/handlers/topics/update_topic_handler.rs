@@ -15,16 +15,17 @@ pub async fn handle(
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     debug!("session: {session}, command: {command}");
-    let mut system = system.write().await;
-    system
-        .update_topic(
-            session,
-            &command.stream_id,
-            &command.topic_id,
-            &command.name,
-            command.message_expiry,
-        )
-        .await?;
+    System::update_topic(
+        &system,
+        session,
+        &command.stream_id,
+        &command.topic_id,
+        &command.name,
+        command.message_expiry,
+        command.max_topic_size,
+        command.max_partition_size,
+    )
+    .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
 }
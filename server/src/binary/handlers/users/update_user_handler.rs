@@ -22,6 +22,7 @@ pub async fn handle(
             &command.user_id,
             command.username.clone(),
             command.status,
+            command.quota,
         )
         .await?;
     sender.send_empty_ok_response().await?;
@@ -0,0 +1,54 @@
+use iggy::binary::protocol::{
+    CORRELATION_ID_PROTOCOL_VERSION, HANDSHAKE_CODE, LEGACY_PROTOCOL_VERSION,
+};
+
+const CODE_BYTES_LENGTH: usize = 4;
+const VERSION_BYTES_LENGTH: usize = 4;
+
+/// Returns the protocol version negotiated for the given request (the command code followed by its payload),
+/// or none, if the request is not a handshake. The clients which don't send the handshake use the legacy framing.
+pub fn negotiate_protocol_version(request: &[u8]) -> Option<u32> {
+    if request.len() != CODE_BYTES_LENGTH + VERSION_BYTES_LENGTH {
+        return None;
+    }
+
+    let code = u32::from_le_bytes(request[..CODE_BYTES_LENGTH].try_into().unwrap());
+    if code != HANDSHAKE_CODE {
+        return None;
+    }
+
+    let version = u32::from_le_bytes(request[CODE_BYTES_LENGTH..].try_into().unwrap());
+    Some(version.clamp(LEGACY_PROTOCOL_VERSION, CORRELATION_ID_PROTOCOL_VERSION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::command::PING_CODE;
+
+    #[test]
+    fn handshake_should_negotiate_the_highest_supported_version() {
+        assert_eq!(
+            negotiate_protocol_version(&request(HANDSHAKE_CODE, CORRELATION_ID_PROTOCOL_VERSION)),
+            Some(CORRELATION_ID_PROTOCOL_VERSION)
+        );
+        assert_eq!(
+            negotiate_protocol_version(&request(HANDSHAKE_CODE, 100)),
+            Some(CORRELATION_ID_PROTOCOL_VERSION)
+        );
+        assert_eq!(
+            negotiate_protocol_version(&request(HANDSHAKE_CODE, LEGACY_PROTOCOL_VERSION)),
+            Some(LEGACY_PROTOCOL_VERSION)
+        );
+    }
+
+    #[test]
+    fn other_commands_should_not_be_treated_as_handshake() {
+        assert_eq!(negotiate_protocol_version(&request(PING_CODE, 2)), None);
+        assert_eq!(negotiate_protocol_version(&PING_CODE.to_le_bytes()), None);
+    }
+
+    fn request(code: u32, version: u32) -> Vec<u8> {
+        [code.to_le_bytes(), version.to_le_bytes()].concat()
+    }
+}
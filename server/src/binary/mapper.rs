@@ -1,4 +1,7 @@
 use bytes::BufMut;
+use iggy::models::backup::Backup;
+use iggy::models::cluster::ClusterMetadata;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
 use iggy::models::user_info::UserId;
 
@@ -6,6 +9,8 @@ use crate::streaming::clients::client_manager::{Client, Transport};
 use crate::streaming::models::messages::PolledMessages;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::quotas::quota_manager::QuotaUsage;
+use crate::streaming::schemas::schema::TopicSchema;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
@@ -16,7 +21,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 pub fn map_stats(stats: &Stats) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity(104);
+    let mut bytes = Vec::with_capacity(112);
     bytes.put_u32_le(stats.process_id);
     bytes.put_f32_le(stats.cpu_usage);
     bytes.put_u64_le(stats.memory_usage);
@@ -34,6 +39,7 @@ pub fn map_stats(stats: &Stats) -> Vec<u8> {
     bytes.put_u64_le(stats.messages_count);
     bytes.put_u32_le(stats.clients_count);
     bytes.put_u32_le(stats.consumer_groups_count);
+    bytes.put_u64_le(stats.throttled_requests_count);
     bytes.put_u32_le(stats.hostname.len() as u32);
     bytes.extend(stats.hostname.as_bytes());
     bytes.put_u32_le(stats.os_name.len() as u32);
@@ -45,6 +51,17 @@ pub fn map_stats(stats: &Stats) -> Vec<u8> {
     bytes
 }
 
+pub fn map_backup(backup: &Backup) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(24 + backup.name.len() + backup.path.len());
+    bytes.put_u64_le(backup.created_at);
+    bytes.put_u64_le(backup.size_bytes);
+    bytes.put_u32_le(backup.name.len() as u32);
+    bytes.extend(backup.name.as_bytes());
+    bytes.put_u32_le(backup.path.len() as u32);
+    bytes.extend(backup.path.as_bytes());
+    bytes
+}
+
 pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Vec<u8> {
     let mut bytes = Vec::with_capacity(20);
     bytes.put_u32_le(offset.partition_id);
@@ -53,9 +70,63 @@ pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Vec<u8> {
     bytes
 }
 
-pub async fn map_client(client: &Client) -> Vec<u8> {
+pub fn map_consumer_lag(lag: &ConsumerLag) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8 + 29 * lag.partitions.len());
+    bytes.put_u64_le(lag.total_lag);
+    for partition in &lag.partitions {
+        bytes.put_u32_le(partition.partition_id);
+        bytes.put_u64_le(partition.current_offset);
+        bytes.put_u8(if partition.stored_offset.is_some() {
+            1
+        } else {
+            0
+        });
+        bytes.put_u64_le(partition.stored_offset.unwrap_or(0));
+        bytes.put_u64_le(partition.lag);
+    }
+    bytes
+}
+
+pub fn map_cluster_metadata(metadata: &ClusterMetadata) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.put_u32_le(metadata.node_id);
+    bytes.put_u32_le(metadata.controller_id);
+    bytes.put_u32_le(metadata.nodes.len() as u32);
+    for node in &metadata.nodes {
+        bytes.put_u32_le(node.id);
+        bytes.put_u8(node.status.as_code());
+        bytes.put_u8(node.address.len() as u8);
+        bytes.extend(node.address.as_bytes());
+    }
+    bytes.put_u32_le(metadata.partitions.len() as u32);
+    for partition in &metadata.partitions {
+        bytes.put_u32_le(partition.stream_id);
+        bytes.put_u32_le(partition.topic_id);
+        bytes.put_u32_le(partition.partition_id);
+        bytes.put_u32_le(partition.leader_id);
+        bytes.put_u32_le(partition.leader_epoch);
+        bytes.put_u32_le(partition.replicas.len() as u32);
+        for replica_id in &partition.replicas {
+            bytes.put_u32_le(*replica_id);
+        }
+        bytes.put_u32_le(partition.in_sync_replicas.len() as u32);
+        for replica_id in &partition.in_sync_replicas {
+            bytes.put_u32_le(*replica_id);
+        }
+    }
+    bytes
+}
+
+pub fn map_transaction(transaction_id: u64) -> Vec<u8> {
+    transaction_id.to_le_bytes().to_vec()
+}
+
+pub async fn map_client(client: &Client, quota_usage: &QuotaUsage) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_client(client, &mut bytes);
+    bytes.put_u32_le(quota_usage.requests);
+    bytes.put_u64_le(quota_usage.bytes);
+    bytes.put_u64_le(quota_usage.throttled_requests);
     for consumer_group in &client.consumer_groups {
         bytes.put_u32_le(consumer_group.stream_id);
         bytes.put_u32_le(consumer_group.topic_id);
@@ -117,6 +188,16 @@ pub fn map_personal_access_tokens(personal_access_tokens: &[PersonalAccessToken]
     bytes
 }
 
+pub fn map_replicated_messages(
+    epoch_start_offset: u64,
+    polled_messages: &PolledMessages,
+) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.put_u64_le(epoch_start_offset);
+    bytes.extend(map_polled_messages(polled_messages));
+    bytes
+}
+
 pub fn map_polled_messages(polled_messages: &PolledMessages) -> Vec<u8> {
     let messages_count = polled_messages.messages.len() as u32;
     let messages_size = polled_messages
@@ -125,10 +206,11 @@ pub fn map_polled_messages(polled_messages: &PolledMessages) -> Vec<u8> {
         .map(|message| message.get_size_bytes())
         .sum::<u32>();
 
-    let mut bytes = Vec::with_capacity(20 + messages_size as usize);
+    let mut bytes = Vec::with_capacity(24 + messages_size as usize);
     bytes.put_u32_le(polled_messages.partition_id);
     bytes.put_u64_le(polled_messages.current_offset);
     bytes.put_u32_le(messages_count);
+    bytes.put_u32_le(polled_messages.generation);
     for message in polled_messages.messages.iter() {
         message.extend(&mut bytes);
     }
@@ -174,6 +256,16 @@ pub async fn map_topic(topic: &Topic) -> Vec<u8> {
 pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_consumer_group(consumer_group, &mut bytes);
+    bytes.put_u8(consumer_group.partition_assignment_strategy.as_code());
+    bytes.put_u32_le(consumer_group.session_timeout.unwrap_or(0));
+    bytes.put_u32_le(consumer_group.get_generation());
+    match consumer_group.dead_letter_topic {
+        Some(dead_letter_topic) => {
+            bytes.put_u32_le(dead_letter_topic.stream_id);
+            bytes.put_u32_le(dead_letter_topic.topic_id);
+        }
+        None => bytes.put_u64_le(0),
+    }
     let members = consumer_group.get_members();
     for member in members {
         let member = member.read().await;
@@ -196,6 +288,30 @@ pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) ->
     bytes
 }
 
+pub fn map_schema(schema: &TopicSchema) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(17 + schema.definition.len());
+    extend_schema(schema, &mut bytes);
+    bytes
+}
+
+pub fn map_topic_schemas(topic: &Topic) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.put_u8(topic.schema_compatibility.as_code());
+    bytes.put_u8(u8::from(topic.schema_validation_enabled));
+    for schema in topic.get_schemas() {
+        extend_schema(schema, &mut bytes);
+    }
+    bytes
+}
+
+fn extend_schema(schema: &TopicSchema, bytes: &mut Vec<u8>) {
+    bytes.put_u32_le(schema.id);
+    bytes.put_u8(schema.format.as_code());
+    bytes.put_u64_le(schema.created_at);
+    bytes.put_u32_le(schema.definition.len() as u32);
+    bytes.extend(schema.definition.as_bytes());
+}
+
 async fn extend_stream(stream: &Stream, bytes: &mut Vec<u8>) {
     bytes.put_u32_le(stream.stream_id);
     bytes.put_u64_le(stream.created_at);
@@ -216,6 +332,15 @@ async fn extend_topic(topic: &Topic, bytes: &mut Vec<u8>) {
     };
     bytes.put_u64_le(topic.get_size_bytes().await);
     bytes.put_u64_le(topic.get_messages_count().await);
+    bytes.put_u8(topic.compression_algorithm.as_code());
+    bytes.put_f64_le(topic.get_compression_ratio().await);
+    if topic.compaction_enabled {
+        bytes.put_u8(1);
+    } else {
+        bytes.put_u8(0);
+    }
+    bytes.put_u64_le(topic.max_topic_size.unwrap_or(0));
+    bytes.put_u64_le(topic.max_partition_size.unwrap_or(0));
     bytes.put_u8(topic.name.len() as u8);
     bytes.extend(topic.name.as_bytes());
 }
@@ -253,7 +378,7 @@ fn extend_client(client: &Client, bytes: &mut Vec<u8>) {
 
 fn extend_user(user: &User, bytes: &mut Vec<u8>) {
     bytes.put_u32_le(user.id);
-    bytes.put_u64(user.created_at);
+    bytes.put_u64_le(user.created_at);
     bytes.put_u8(user.status.as_code());
     bytes.put_u8(user.username.len() as u8);
     bytes.extend(user.username.as_bytes());
@@ -264,3 +389,32 @@ fn extend_pat(personal_access_token: &PersonalAccessToken, bytes: &mut Vec<u8>)
     bytes.extend(personal_access_token.name.as_bytes());
     bytes.put_u64_le(personal_access_token.expiry.unwrap_or(0));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::models::user_status::UserStatus;
+
+    #[test]
+    fn user_should_be_mapped_with_little_endian_created_at_and_status() {
+        let user = User {
+            id: 7,
+            status: UserStatus::Inactive,
+            username: "user".to_string(),
+            created_at: 1_700_000_000_000_000,
+            ..Default::default()
+        };
+
+        let bytes = map_users(&[user]);
+
+        assert_eq!(u32::from_le_bytes(bytes[0..4].try_into().unwrap()), 7);
+        assert_eq!(
+            u64::from_le_bytes(bytes[4..12].try_into().unwrap()),
+            1_700_000_000_000_000
+        );
+        assert_eq!(bytes[12], UserStatus::Inactive.as_code());
+        assert_eq!(bytes[13], 4);
+        assert_eq!(&bytes[14..18], b"user");
+        assert_eq!(bytes.len(), 18);
+    }
+}
@@ -1,4 +1,6 @@
 pub mod command;
+pub mod executor;
 mod handlers;
+pub mod handshake;
 mod mapper;
 pub mod sender;
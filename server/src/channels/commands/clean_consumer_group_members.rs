@@ -0,0 +1,104 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ConsumerGroupMemberCleanerConfig;
+use crate::streaming::systems::system::System;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::timestamp::TimeStamp;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+use tokio::time;
+use tracing::{debug, error, info};
+
+pub struct ConsumerGroupMemberCleaner {
+    enabled: bool,
+    interval: Duration,
+    sender: Sender<CleanConsumerGroupMembersCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanConsumerGroupMembersCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct CleanConsumerGroupMembersExecutor;
+
+impl ConsumerGroupMemberCleaner {
+    pub fn new(
+        config: &ConsumerGroupMemberCleanerConfig,
+        sender: Sender<CleanConsumerGroupMembersCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: Duration::from_secs(config.interval),
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Consumer group member cleaner is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Consumer group member cleaner is enabled, members exceeding the session timeout will be removed every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval);
+            loop {
+                interval_timer.tick().await;
+                if sender.send(CleanConsumerGroupMembersCommand).is_err() {
+                    error!("Failed to send CleanConsumerGroupMembersCommand");
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CleanConsumerGroupMembersCommand> for CleanConsumerGroupMembersExecutor {
+    async fn execute(
+        &mut self,
+        system: &Arc<RwLock<System>>,
+        _command: CleanConsumerGroupMembersCommand,
+    ) {
+        let now = TimeStamp::now().to_micros();
+        let system = system.read().await;
+        match system.delete_stale_consumer_group_members(now).await {
+            Ok(0) => debug!("No stale consumer group members to remove."),
+            Ok(deleted_members_count) => {
+                info!("Removed {deleted_members_count} stale consumer group members.")
+            }
+            Err(error) => error!("Failed to remove stale consumer group members. Error: {error}"),
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: Arc<RwLock<System>>,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CleanConsumerGroupMembersCommand>,
+    ) {
+        let consumer_group_member_cleaner =
+            ConsumerGroupMemberCleaner::new(&config.consumer_group_member_cleaner, sender);
+        consumer_group_member_cleaner.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: Arc<RwLock<System>>,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CleanConsumerGroupMembersCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Consumer group member cleaner receiver stopped.");
+        });
+    }
+}
@@ -1,4 +1,5 @@
 use crate::streaming::systems::system::System;
+use crate::streaming::topics::segments::DeletedSegments;
 use crate::streaming::topics::topic::Topic;
 use crate::{channels::server_command::ServerCommand, configs::server::MessageCleanerConfig};
 use async_trait::async_trait;
@@ -10,11 +11,6 @@ use tokio::sync::RwLock;
 use tokio::time;
 use tracing::{error, info};
 
-struct DeletedSegments {
-    pub segments_count: u32,
-    pub messages_count: u64,
-}
-
 pub struct MessagesCleaner {
     enabled: bool,
     interval: Duration,
@@ -45,7 +41,7 @@ impl MessagesCleaner {
         let interval = self.interval;
         let sender = self.sender.clone();
         info!(
-            "Message cleaner is enabled, expired messages will be deleted every: {:?}.",
+            "Message cleaner is enabled, expired messages and segments exceeding the topic size limits will be deleted every: {:?}.",
             interval
         );
 
@@ -70,26 +66,34 @@ impl ServerCommand<CleanMessagesCommand> for CleanMessagesExecutor {
         for stream in streams {
             let topics = stream.get_topics();
             for topic in topics {
-                let deleted_segments = delete_expired_segments(topic, now).await;
-                if let Ok(Some(deleted_segments)) = deleted_segments {
-                    info!(
-                        "Deleted {} segments and {} messages for stream ID: {}, topic ID: {}",
-                        deleted_segments.segments_count,
-                        deleted_segments.messages_count,
-                        topic.stream_id,
-                        topic.topic_id
-                    );
-
-                    system
-                        .write()
-                        .await
-                        .metrics
-                        .decrement_segments(deleted_segments.segments_count);
-                    system
-                        .write()
-                        .await
-                        .metrics
-                        .decrement_messages(deleted_segments.messages_count);
+                let expired_segments = delete_expired_segments(topic, now).await;
+                let oversized_segments = topic.delete_oversized_segments().await;
+                for deleted_segments in [expired_segments, oversized_segments] {
+                    match deleted_segments {
+                        Ok(Some(deleted_segments)) => {
+                            info!(
+                                "Deleted {} segments and {} messages for stream ID: {}, topic ID: {}",
+                                deleted_segments.segments_count,
+                                deleted_segments.messages_count,
+                                topic.stream_id,
+                                topic.topic_id
+                            );
+
+                            system_read
+                                .metrics
+                                .decrement_segments(deleted_segments.segments_count);
+                            system_read
+                                .metrics
+                                .decrement_messages(deleted_segments.messages_count);
+                        }
+                        Ok(None) => {}
+                        Err(error) => {
+                            error!(
+                                "Failed to delete segments for stream ID: {}, topic ID: {}. Error: {}",
+                                topic.stream_id, topic.topic_id, error
+                            );
+                        }
+                    }
                 }
             }
         }
@@ -0,0 +1,163 @@
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use crate::{channels::server_command::ServerCommand, configs::server::MessageCompactorConfig};
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::error::Error;
+use iggy::utils::timestamp::TimeStamp;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+use tokio::time;
+use tracing::{error, info};
+
+struct CompactedSegments {
+    pub segments_count: u32,
+    pub messages_count: u64,
+}
+
+pub struct MessagesCompactor {
+    enabled: bool,
+    interval: Duration,
+    tombstone_retention: Duration,
+    sender: Sender<CompactMessagesCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactMessagesCommand {
+    tombstone_retention: Duration,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactMessagesExecutor;
+
+impl MessagesCompactor {
+    pub fn new(config: &MessageCompactorConfig, sender: Sender<CompactMessagesCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: Duration::from_secs(config.interval),
+            tombstone_retention: Duration::from_secs(config.tombstone_retention),
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Message compactor is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let tombstone_retention = self.tombstone_retention;
+        let sender = self.sender.clone();
+        info!(
+            "Message compactor is enabled, topics with enabled compaction will be compacted every: {:?}, tombstones will be retained for: {:?}.",
+            interval, tombstone_retention
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval);
+            loop {
+                interval_timer.tick().await;
+                if sender
+                    .send(CompactMessagesCommand {
+                        tombstone_retention,
+                    })
+                    .is_err()
+                {
+                    error!("Failed to send CompactMessagesCommand");
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CompactMessagesCommand> for CompactMessagesExecutor {
+    async fn execute(&mut self, system: &Arc<RwLock<System>>, command: CompactMessagesCommand) {
+        let now = TimeStamp::now().to_micros();
+        let tombstone_retention = command.tombstone_retention.as_micros() as u64;
+        let system = system.read().await;
+        let streams = system.get_streams();
+        for stream in streams {
+            let topics = stream.get_topics();
+            for topic in topics {
+                if !topic.compaction_enabled {
+                    continue;
+                }
+
+                let compacted_segments = compact_segments(topic, now, tombstone_retention).await;
+                match compacted_segments {
+                    Ok(Some(compacted_segments)) => {
+                        info!(
+                            "Compacted {} segments and removed {} messages for stream ID: {}, topic ID: {}",
+                            compacted_segments.segments_count,
+                            compacted_segments.messages_count,
+                            topic.stream_id,
+                            topic.topic_id
+                        );
+                    }
+                    Ok(None) => {}
+                    Err(error) => {
+                        error!(
+                            "Failed to compact segments for stream ID: {}, topic ID: {}. Error: {}",
+                            topic.stream_id, topic.topic_id, error
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: Arc<RwLock<System>>,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CompactMessagesCommand>,
+    ) {
+        let messages_compactor = MessagesCompactor::new(&config.message_compactor, sender);
+        messages_compactor.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: Arc<RwLock<System>>,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CompactMessagesCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Messages compactor receiver stopped.");
+        });
+    }
+}
+
+async fn compact_segments(
+    topic: &Topic,
+    now: u64,
+    tombstone_retention: u64,
+) -> Result<Option<CompactedSegments>, Error> {
+    let mut segments_count = 0;
+    let mut messages_count = 0;
+    for partition in topic.get_partitions() {
+        let mut partition = partition.write().await;
+        let compacted_segments = partition.compact(now, tombstone_retention).await?;
+        segments_count += compacted_segments.segments_count;
+        messages_count += compacted_segments.messages_count;
+    }
+
+    if segments_count == 0 {
+        info!(
+            "No segments to compact found for stream ID: {}, topic ID: {}",
+            topic.stream_id, topic.topic_id
+        );
+        return Ok(None);
+    }
+
+    Ok(Some(CompactedSegments {
+        segments_count,
+        messages_count,
+    }))
+}
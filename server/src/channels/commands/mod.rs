@@ -1,3 +1,8 @@
+pub mod clean_consumer_group_members;
 pub mod clean_messages;
 pub mod clean_personal_access_tokens;
+pub mod compact_messages;
+pub mod offload_segments;
+pub mod replicate_messages;
 pub mod save_messages;
+pub mod send_cluster_heartbeats;
@@ -0,0 +1,153 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use crate::{channels::server_command::ServerCommand, configs::system::TieredStorageConfig};
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::error::Error;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+use tokio::time;
+use tracing::{error, info};
+
+struct OffloadedSegments {
+    pub uploaded_segments_count: u32,
+    pub removed_local_segments_count: u32,
+}
+
+pub struct SegmentsOffloader {
+    enabled: bool,
+    interval: Duration,
+    retain_local_segments: u32,
+    sender: Sender<OffloadSegmentsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OffloadSegmentsCommand {
+    retain_local_segments: u32,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OffloadSegmentsExecutor;
+
+impl SegmentsOffloader {
+    pub fn new(config: &TieredStorageConfig, sender: Sender<OffloadSegmentsCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: Duration::from_secs(config.interval),
+            retain_local_segments: config.retain_local_segments,
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Segments offloader is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let retain_local_segments = self.retain_local_segments;
+        let sender = self.sender.clone();
+        info!(
+            "Segments offloader is enabled, closed segments will be uploaded to the remote storage every: {:?}, local copies will be retained for the newest: {} closed segments.",
+            interval, retain_local_segments
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval);
+            loop {
+                interval_timer.tick().await;
+                if sender
+                    .send(OffloadSegmentsCommand {
+                        retain_local_segments,
+                    })
+                    .is_err()
+                {
+                    error!("Failed to send OffloadSegmentsCommand");
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<OffloadSegmentsCommand> for OffloadSegmentsExecutor {
+    async fn execute(&mut self, system: &Arc<RwLock<System>>, command: OffloadSegmentsCommand) {
+        let system = system.read().await;
+        let streams = system.get_streams();
+        for stream in streams {
+            let topics = stream.get_topics();
+            for topic in topics {
+                let offloaded_segments =
+                    offload_segments(topic, command.retain_local_segments).await;
+                match offloaded_segments {
+                    Ok(Some(offloaded_segments)) => {
+                        info!(
+                            "Uploaded {} segments and removed {} local segments for stream ID: {}, topic ID: {}",
+                            offloaded_segments.uploaded_segments_count,
+                            offloaded_segments.removed_local_segments_count,
+                            topic.stream_id,
+                            topic.topic_id
+                        );
+                    }
+                    Ok(None) => {}
+                    Err(error) => {
+                        error!(
+                            "Failed to offload segments for stream ID: {}, topic ID: {}. Error: {}",
+                            topic.stream_id, topic.topic_id, error
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: Arc<RwLock<System>>,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<OffloadSegmentsCommand>,
+    ) {
+        let segments_offloader = SegmentsOffloader::new(&config.system.tiered_storage, sender);
+        segments_offloader.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: Arc<RwLock<System>>,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<OffloadSegmentsCommand>,
+    ) {
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Segments offloader receiver stopped.");
+        });
+    }
+}
+
+async fn offload_segments(
+    topic: &Topic,
+    retain_local_segments: u32,
+) -> Result<Option<OffloadedSegments>, Error> {
+    let mut uploaded_segments_count = 0;
+    let mut removed_local_segments_count = 0;
+    for partition in topic.get_partitions() {
+        let offloaded_segments =
+            Partition::offload_segments(&partition, retain_local_segments).await?;
+        uploaded_segments_count += offloaded_segments.uploaded_segments_count;
+        removed_local_segments_count += offloaded_segments.removed_local_segments_count;
+    }
+
+    if uploaded_segments_count == 0 && removed_local_segments_count == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(OffloadedSegments {
+        uploaded_segments_count,
+        removed_local_segments_count,
+    }))
+}
@@ -0,0 +1,162 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::cluster::ClusterConfig;
+use crate::configs::server::ServerConfig;
+use crate::streaming::cluster::peer_clients::PeerClients;
+use crate::streaming::systems::system::System;
+use async_trait::async_trait;
+use flume::{Receiver, Sender};
+use iggy::binary;
+use iggy::cluster::replicate_messages::ReplicateMessages;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+use tokio::time;
+use tracing::{error, info};
+
+const REPLICATION_BATCH_SIZE: u32 = 1000;
+
+pub struct MessagesReplicator {
+    enabled: bool,
+    interval: Duration,
+    sender: Sender<ReplicateMessagesCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ReplicateMessagesCommand;
+
+/// Fetches the new messages of the partitions led by the other nodes, for which this node is one of the replicas.
+/// Each fetch also acknowledges the previously fetched messages, which is used by the leader to track the in-sync replicas.
+#[derive(Debug, Default)]
+pub struct ReplicateMessagesExecutor {
+    peer_clients: Option<PeerClients>,
+}
+
+impl MessagesReplicator {
+    pub fn new(config: &ClusterConfig, sender: Sender<ReplicateMessagesCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: Duration::from_millis(config.replication_interval),
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Messages replicator is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Messages replicator is enabled, the partitions will be replicated from their leaders every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval);
+            loop {
+                interval_timer.tick().await;
+                if sender.send(ReplicateMessagesCommand).is_err() {
+                    error!("Failed to send ReplicateMessagesCommand");
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<ReplicateMessagesCommand> for ReplicateMessagesExecutor {
+    async fn execute(&mut self, system: &Arc<RwLock<System>>, _command: ReplicateMessagesCommand) {
+        let Some(cluster) = system.read().await.cluster.clone() else {
+            return;
+        };
+        let Some(peer_clients) = &mut self.peer_clients else {
+            return;
+        };
+
+        let partitions = system.read().await.get_partition_keys();
+        for key in partitions {
+            if !cluster.is_replica(&key, cluster.node_id) {
+                continue;
+            }
+
+            let leader_id = cluster.get_leader_id(key.0, key.1, key.2).await;
+            if leader_id == cluster.node_id || !cluster.is_node_online(leader_id).await {
+                continue;
+            }
+
+            let Some(leader) = cluster.get_node(leader_id).cloned() else {
+                continue;
+            };
+            let Ok(offset) = system.read().await.get_replication_offset(key).await else {
+                continue;
+            };
+
+            let leader_epoch = cluster.get_leader_epoch(&key).await;
+
+            let command = ReplicateMessages {
+                stream_id: key.0,
+                topic_id: key.1,
+                partition_id: key.2,
+                replica_id: cluster.node_id,
+                leader_epoch,
+                offset,
+                count: REPLICATION_BATCH_SIZE,
+            };
+            let Ok(replicated_messages) = peer_clients
+                .send(&leader, |client| async move {
+                    binary::cluster::replicate_messages(client.as_ref(), &command).await
+                })
+                .await
+            else {
+                continue;
+            };
+
+            // Even if there are no new messages, the follower might have to truncate the ones not present on the new leader.
+            if let Err(error) = system
+                .read()
+                .await
+                .append_replicated_messages(
+                    key,
+                    leader_epoch,
+                    replicated_messages.epoch_start_offset,
+                    replicated_messages.messages.messages,
+                )
+                .await
+            {
+                error!(
+                    "Failed to append the replicated messages to partition with ID: {} for topic with ID: {} and stream with ID: {}. Error: {error}",
+                    key.2, key.1, key.0
+                );
+            }
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: Arc<RwLock<System>>,
+        config: &ServerConfig,
+        sender: Sender<ReplicateMessagesCommand>,
+    ) {
+        let messages_replicator = MessagesReplicator::new(&config.cluster, sender);
+        messages_replicator.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: Arc<RwLock<System>>,
+        config: &ServerConfig,
+        receiver: Receiver<ReplicateMessagesCommand>,
+    ) {
+        self.peer_clients = Some(PeerClients::new(&config.cluster));
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                // The replication rounds missed while fetching from the slow leaders are skipped.
+                receiver.drain().for_each(drop);
+                self.execute(&system, command).await;
+            }
+            info!("Messages replicator receiver stopped.");
+        });
+    }
+}
@@ -65,13 +65,14 @@ impl MessagesSaver {
 #[async_trait]
 impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
     async fn execute(&mut self, system: &Arc<RwLock<System>>, command: SaveMessagesCommand) {
+        let flush_duration = system.read().await.metrics.segment_flush_duration();
         let persister: Arc<dyn Persister> = if command.enforce_fsync {
-            Arc::new(FileWithSyncPersister)
+            Arc::new(FileWithSyncPersister::new(flush_duration))
         } else {
-            Arc::new(FilePersister)
+            Arc::new(FilePersister::new(flush_duration))
         };
 
-        let storage = Arc::new(FileSegmentStorage::new(persister));
+        let storage = Arc::new(FileSegmentStorage::new(persister, None));
         if system
             .write()
             .await
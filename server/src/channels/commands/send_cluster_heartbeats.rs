@@ -0,0 +1,182 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::cluster::ClusterConfig;
+use crate::configs::server::ServerConfig;
+use crate::streaming::cluster::peer_clients::PeerClients;
+use crate::streaming::systems::system::System;
+use async_trait::async_trait;
+use flume::{Receiver, Sender};
+use iggy::binary;
+use iggy::cluster::get_cluster_metadata::GetClusterMetadata;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::models::stream::StreamDetails;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::get_streams::GetStreams;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+use tokio::time;
+use tracing::{error, info, warn};
+
+pub struct ClusterHeartbeat {
+    enabled: bool,
+    interval: Duration,
+    sender: Sender<SendClusterHeartbeatsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SendClusterHeartbeatsCommand;
+
+/// Exchanges the metadata with the other nodes. The controller assigns the partition leaders,
+/// while the other nodes adopt its assignments and synchronize its streams, topics and partitions.
+#[derive(Debug, Default)]
+pub struct SendClusterHeartbeatsExecutor {
+    peer_clients: Option<PeerClients>,
+}
+
+impl ClusterHeartbeat {
+    pub fn new(config: &ClusterConfig, sender: Sender<SendClusterHeartbeatsCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: Duration::from_millis(config.heartbeat_interval),
+            sender,
+        }
+    }
+
+    pub fn start(&self) {
+        if !self.enabled {
+            info!("Cluster heartbeat is disabled.");
+            return;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Cluster heartbeat is enabled, the metadata will be exchanged with the other nodes every: {:?}.",
+            interval
+        );
+
+        tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval);
+            loop {
+                interval_timer.tick().await;
+                if sender.send(SendClusterHeartbeatsCommand).is_err() {
+                    error!("Failed to send SendClusterHeartbeatsCommand");
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl ServerCommand<SendClusterHeartbeatsCommand> for SendClusterHeartbeatsExecutor {
+    async fn execute(
+        &mut self,
+        system: &Arc<RwLock<System>>,
+        _command: SendClusterHeartbeatsCommand,
+    ) {
+        let Some(cluster) = system.read().await.cluster.clone() else {
+            return;
+        };
+        let Some(peer_clients) = &mut self.peer_clients else {
+            return;
+        };
+
+        let mut peers_metadata = Vec::new();
+        for peer in cluster.get_peers() {
+            if let Ok(metadata) = peer_clients
+                .send(&peer, |client| async move {
+                    binary::cluster::get_cluster_metadata(client.as_ref(), &GetClusterMetadata {})
+                        .await
+                })
+                .await
+            {
+                cluster.record_heartbeat(peer.id).await;
+                peers_metadata.push(metadata);
+            }
+        }
+
+        let Some(controller_id) = cluster.get_controller_id().await else {
+            warn!("The majority of the cluster nodes is offline, there is no cluster controller.");
+            return;
+        };
+
+        if controller_id == cluster.node_id {
+            let partitions = system.read().await.get_partition_keys();
+            cluster.assign_leaders(&partitions).await;
+        } else if let Some(controller_metadata) = peers_metadata
+            .iter()
+            .find(|metadata| metadata.node_id == controller_id)
+        {
+            // The partitions have to exist, before the node can replicate them.
+            let controller = cluster.get_node(controller_id).unwrap().clone();
+            match peer_clients
+                .send(&controller, |client| async move {
+                    get_streams(client.as_ref()).await
+                })
+                .await
+            {
+                Ok(streams) => {
+                    if let Err(error) = System::sync_cluster_streams(system, &streams).await {
+                        warn!("Failed to synchronize the streams with the cluster controller. Error: {error}");
+                    }
+                }
+                Err(error) => {
+                    warn!("Failed to fetch the streams from the cluster controller. Error: {error}")
+                }
+            }
+            cluster.apply_controller_metadata(controller_metadata).await;
+        }
+
+        // The in-sync replicas reported by the leaders are more recent than the ones known to the controller.
+        for metadata in &peers_metadata {
+            cluster.apply_leader_metadata(metadata).await;
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: Arc<RwLock<System>>,
+        config: &ServerConfig,
+        sender: Sender<SendClusterHeartbeatsCommand>,
+    ) {
+        let cluster_heartbeat = ClusterHeartbeat::new(&config.cluster, sender);
+        cluster_heartbeat.start();
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: Arc<RwLock<System>>,
+        config: &ServerConfig,
+        receiver: Receiver<SendClusterHeartbeatsCommand>,
+    ) {
+        self.peer_clients = Some(PeerClients::new(&config.cluster));
+        tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                // The heartbeats missed while waiting for the unresponsive nodes are skipped.
+                receiver.drain().for_each(drop);
+                self.execute(&system, command).await;
+            }
+            info!("Cluster heartbeat receiver stopped.");
+        });
+    }
+}
+
+async fn get_streams(
+    client: &dyn binary::binary_client::BinaryClient,
+) -> Result<Vec<StreamDetails>, Error> {
+    let streams = binary::streams::get_streams(client, &GetStreams {}).await?;
+    let mut streams_details = Vec::with_capacity(streams.len());
+    for stream in streams {
+        streams_details.push(
+            binary::streams::get_stream(
+                client,
+                &GetStream {
+                    stream_id: Identifier::numeric(stream.id)?,
+                },
+            )
+            .await?,
+        );
+    }
+    Ok(streams_details)
+}
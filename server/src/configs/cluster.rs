@@ -0,0 +1,121 @@
+use iggy::error::Error;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClusterConfig {
+    pub enabled: bool,
+    pub node_id: u32,
+    /// Comma separated list of the cluster nodes in the format of `id@tcp_address`, e.g. `1@127.0.0.1:8090,2@127.0.0.1:8091`.
+    pub nodes: String,
+    /// The credentials used by the node to connect to the other nodes.
+    pub username: String,
+    pub password: String,
+    /// The number of the nodes storing each partition (including its leader).
+    pub replication_factor: u32,
+    /// The minimum number of the in-sync replicas (including the leader), which must replicate the messages sent with `Acks::All`.
+    pub min_insync_replicas: u32,
+    /// Allows electing the replica, which is not in sync, as the leader when none of the in-sync replicas is online.
+    /// The partition stays available, but the messages not replicated by the new leader are lost.
+    pub unclean_leader_election: bool,
+    pub heartbeat_interval: u64,
+    pub node_timeout: u64,
+    pub replica_lag_timeout: u64,
+    pub replication_interval: u64,
+    pub acks_timeout: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterNodeConfig {
+    pub id: u32,
+    pub address: String,
+}
+
+impl ClusterConfig {
+    pub fn get_nodes(&self) -> Result<Vec<ClusterNodeConfig>, Error> {
+        let mut nodes: Vec<ClusterNodeConfig> = Vec::new();
+        for node in self.nodes.split(',').map(|node| node.trim()) {
+            let Some((id, address)) = node.split_once('@') else {
+                return Err(Error::InvalidClusterNodes(self.nodes.clone()));
+            };
+            let Ok(id) = id.trim().parse::<u32>() else {
+                return Err(Error::InvalidClusterNodes(self.nodes.clone()));
+            };
+            let address = address.trim();
+            if id == 0 || address.is_empty() || nodes.iter().any(|node| node.id == id) {
+                return Err(Error::InvalidClusterNodes(self.nodes.clone()));
+            }
+
+            nodes.push(ClusterNodeConfig {
+                id,
+                address: address.to_string(),
+            });
+        }
+
+        if !nodes.iter().any(|node| node.id == self.node_id) {
+            return Err(Error::InvalidClusterNode(self.node_id));
+        }
+
+        nodes.sort_by_key(|node| node.id);
+        Ok(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nodes_should_be_parsed_and_sorted_by_id() {
+        let config = ClusterConfig {
+            node_id: 1,
+            nodes: "2@127.0.0.1:8091, 1@127.0.0.1:8090".to_string(),
+            ..ClusterConfig::default()
+        };
+
+        let nodes = config.get_nodes().unwrap();
+
+        assert_eq!(
+            nodes,
+            vec![
+                ClusterNodeConfig {
+                    id: 1,
+                    address: "127.0.0.1:8090".to_string()
+                },
+                ClusterNodeConfig {
+                    id: 2,
+                    address: "127.0.0.1:8091".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn nodes_should_not_be_parsed_given_invalid_format() {
+        for nodes in ["", "1", "a@127.0.0.1:8090", "0@127.0.0.1:8090", "1@"] {
+            let config = ClusterConfig {
+                nodes: nodes.to_string(),
+                ..ClusterConfig::default()
+            };
+            assert!(config.get_nodes().is_err());
+        }
+    }
+
+    #[test]
+    fn nodes_should_not_be_parsed_given_duplicated_id() {
+        let config = ClusterConfig {
+            nodes: "1@127.0.0.1:8090,1@127.0.0.1:8091".to_string(),
+            ..ClusterConfig::default()
+        };
+        assert!(config.get_nodes().is_err());
+    }
+
+    #[test]
+    fn nodes_should_not_be_parsed_given_missing_current_node() {
+        let config = ClusterConfig {
+            node_id: 3,
+            nodes: "1@127.0.0.1:8090,2@127.0.0.1:8091".to_string(),
+            ..ClusterConfig::default()
+        };
+        assert!(config.get_nodes().is_err());
+    }
+}
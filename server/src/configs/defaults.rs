@@ -1,28 +1,66 @@
+use crate::configs::cluster::ClusterConfig;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
 };
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    MessageCleanerConfig, MessageSaverConfig, PersonalAccessTokenCleanerConfig,
-    PersonalAccessTokenConfig, ServerConfig,
+    ConsumerGroupMemberCleanerConfig, MessageCleanerConfig, MessageCompactorConfig,
+    MessageSaverConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig, ServerConfig,
 };
 use crate::configs::system::{
-    CacheConfig, DatabaseConfig, EncryptionConfig, LoggingConfig, PartitionConfig, SegmentConfig,
-    StreamConfig, SystemConfig, TopicConfig,
+    BackupConfig, CacheConfig, DatabaseConfig, EncryptionConfig, FilesystemStorageConfig,
+    LoggingConfig, PartitionConfig, PollingConfig, QuotaConfig, S3StorageConfig, SegmentConfig,
+    StreamConfig, SystemConfig, TieredStorageBackend, TieredStorageConfig, TopicConfig,
+    TransactionConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use crate::configs::telemetry::TelemetryConfig;
 use std::sync::Arc;
 
 impl Default for ServerConfig {
     fn default() -> ServerConfig {
         ServerConfig {
+            consumer_group_member_cleaner: ConsumerGroupMemberCleanerConfig::default(),
             message_cleaner: MessageCleanerConfig::default(),
+            message_compactor: MessageCompactorConfig::default(),
             message_saver: MessageSaverConfig::default(),
             personal_access_token: PersonalAccessTokenConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
             http: HttpConfig::default(),
+            cluster: ClusterConfig::default(),
+            telemetry: TelemetryConfig::default(),
+        }
+    }
+}
+
+impl Default for ClusterConfig {
+    fn default() -> ClusterConfig {
+        ClusterConfig {
+            enabled: false,
+            node_id: 1,
+            nodes: "1@127.0.0.1:8090".to_string(),
+            username: "iggy".to_string(),
+            password: "iggy".to_string(),
+            replication_factor: 3,
+            min_insync_replicas: 1,
+            unclean_leader_election: false,
+            heartbeat_interval: 1000,
+            node_timeout: 5000,
+            replica_lag_timeout: 10000,
+            replication_interval: 100,
+            acks_timeout: 5000,
+        }
+    }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> TelemetryConfig {
+        TelemetryConfig {
+            enabled: false,
+            service_name: "iggy".to_string(),
+            endpoint: "http://localhost:4318/v1/traces".to_string(),
         }
     }
 }
@@ -86,6 +124,25 @@ impl Default for MessageCleanerConfig {
     }
 }
 
+impl Default for ConsumerGroupMemberCleanerConfig {
+    fn default() -> ConsumerGroupMemberCleanerConfig {
+        ConsumerGroupMemberCleanerConfig {
+            enabled: true,
+            interval: 5,
+        }
+    }
+}
+
+impl Default for MessageCompactorConfig {
+    fn default() -> MessageCompactorConfig {
+        MessageCompactorConfig {
+            enabled: true,
+            interval: 300,
+            tombstone_retention: 86400,
+        }
+    }
+}
+
 impl Default for MessageSaverConfig {
     fn default() -> MessageSaverConfig {
         MessageSaverConfig {
@@ -119,6 +176,7 @@ impl Default for SystemConfig {
         SystemConfig {
             path: "local_data".to_string(),
             database: DatabaseConfig::default(),
+            backup: BackupConfig::default(),
             logging: LoggingConfig::default(),
             cache: CacheConfig::default(),
             stream: StreamConfig::default(),
@@ -126,6 +184,31 @@ impl Default for SystemConfig {
             topic: TopicConfig::default(),
             partition: PartitionConfig::default(),
             segment: SegmentConfig::default(),
+            transaction: TransactionConfig::default(),
+            polling: PollingConfig::default(),
+            quota: QuotaConfig::default(),
+            tiered_storage: TieredStorageConfig::default(),
+        }
+    }
+}
+
+impl Default for TieredStorageConfig {
+    fn default() -> TieredStorageConfig {
+        TieredStorageConfig {
+            enabled: false,
+            interval: 60,
+            retain_local_segments: 1,
+            backend: TieredStorageBackend::Filesystem,
+            filesystem: FilesystemStorageConfig::default(),
+            s3: S3StorageConfig::default(),
+        }
+    }
+}
+
+impl Default for FilesystemStorageConfig {
+    fn default() -> FilesystemStorageConfig {
+        FilesystemStorageConfig {
+            path: "remote_storage".to_string(),
         }
     }
 }
@@ -138,6 +221,14 @@ impl Default for DatabaseConfig {
     }
 }
 
+impl Default for BackupConfig {
+    fn default() -> BackupConfig {
+        BackupConfig {
+            path: "backups".to_string(),
+        }
+    }
+}
+
 impl Default for LoggingConfig {
     fn default() -> LoggingConfig {
         LoggingConfig {
@@ -180,12 +271,29 @@ impl Default for PartitionConfig {
             path: "partitions".to_string(),
             messages_required_to_save: 1000,
             deduplicate_messages: false,
+            max_producers: 1000,
             enforce_fsync: false,
             validate_checksum: false,
+            max_scanned_messages: 100_000,
         }
     }
 }
 
+impl Default for TransactionConfig {
+    fn default() -> TransactionConfig {
+        TransactionConfig {
+            timeout: 60,
+            max_messages: 100_000,
+        }
+    }
+}
+
+impl Default for PollingConfig {
+    fn default() -> PollingConfig {
+        PollingConfig { max_wait: 30_000 }
+    }
+}
+
 impl Default for SegmentConfig {
     fn default() -> SegmentConfig {
         SegmentConfig {
@@ -2,14 +2,21 @@ use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use std::fmt::{Display, Formatter};
 
 use crate::configs::{
+    cluster::ClusterConfig,
     http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig},
     resource_quota::MemoryResourceQuota,
-    server::{MessageCleanerConfig, MessageSaverConfig, ServerConfig},
+    server::{
+        ConsumerGroupMemberCleanerConfig, MessageCleanerConfig, MessageCompactorConfig,
+        MessageSaverConfig, ServerConfig,
+    },
     system::{
-        CacheConfig, DatabaseConfig, EncryptionConfig, LoggingConfig, PartitionConfig,
-        SegmentConfig, StreamConfig, SystemConfig, TopicConfig,
+        BackupConfig, CacheConfig, DatabaseConfig, EncryptionConfig, FilesystemStorageConfig,
+        LoggingConfig, PartitionConfig, PollingConfig, QuotaConfig, S3StorageConfig, SegmentConfig,
+        StreamConfig, SystemConfig, TieredStorageBackend, TieredStorageConfig, TopicConfig,
+        TransactionConfig,
     },
     tcp::{TcpConfig, TcpTlsConfig},
+    telemetry::TelemetryConfig,
 };
 
 impl Display for HttpConfig {
@@ -104,8 +111,58 @@ impl Display for ServerConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ message_cleaner: {}, message_saver: {}, system: {}, quic: {}, tcp: {}, http: {} }}",
-            self.message_cleaner, self.message_saver, self.system, self.quic, self.tcp, self.http
+            "{{ consumer_group_member_cleaner: {}, message_cleaner: {}, message_compactor: {}, message_saver: {}, system: {}, quic: {}, tcp: {}, http: {}, cluster: {}, telemetry: {} }}",
+            self.consumer_group_member_cleaner,
+            self.message_cleaner,
+            self.message_compactor,
+            self.message_saver,
+            self.system,
+            self.quic,
+            self.tcp,
+            self.http,
+            self.cluster,
+            self.telemetry
+        )
+    }
+}
+
+impl Display for TelemetryConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, service_name: {}, endpoint: {} }}",
+            self.enabled, self.service_name, self.endpoint
+        )
+    }
+}
+
+impl Display for ClusterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, node_id: {}, nodes: {}, username: {}, replication_factor: {}, min_insync_replicas: {}, unclean_leader_election: {}, heartbeat_interval: {}, node_timeout: {}, replica_lag_timeout: {}, replication_interval: {}, acks_timeout: {} }}",
+            self.enabled,
+            self.node_id,
+            self.nodes,
+            self.username,
+            self.replication_factor,
+            self.min_insync_replicas,
+            self.unclean_leader_election,
+            self.heartbeat_interval,
+            self.node_timeout,
+            self.replica_lag_timeout,
+            self.replication_interval,
+            self.acks_timeout
+        )
+    }
+}
+
+impl Display for ConsumerGroupMemberCleanerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {} }}",
+            self.enabled, self.interval
         )
     }
 }
@@ -120,6 +177,16 @@ impl Display for MessageCleanerConfig {
     }
 }
 
+impl Display for MessageCompactorConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, tombstone_retention: {} }}",
+            self.enabled, self.interval, self.tombstone_retention
+        )
+    }
+}
+
 impl Display for MessageSaverConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -136,6 +203,12 @@ impl Display for DatabaseConfig {
     }
 }
 
+impl Display for BackupConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ path: {} }}", self.path)
+    }
+}
+
 impl Display for CacheConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ enabled: {}, size: {} }}", self.enabled, self.size)
@@ -148,6 +221,76 @@ impl Display for EncryptionConfig {
     }
 }
 
+impl Display for TransactionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ timeout: {}, max_messages: {} }}",
+            self.timeout, self.max_messages
+        )
+    }
+}
+
+impl Display for PollingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ max_wait: {} }}", self.max_wait)
+    }
+}
+
+impl Display for QuotaConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, user_bytes_per_second: {}, user_requests_per_second: {}, client_bytes_per_second: {}, client_requests_per_second: {} }}",
+            self.enabled,
+            self.user_bytes_per_second,
+            self.user_requests_per_second,
+            self.client_bytes_per_second,
+            self.client_requests_per_second
+        )
+    }
+}
+
+impl Display for TieredStorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, retain_local_segments: {}, backend: {}, filesystem: {}, s3: {} }}",
+            self.enabled,
+            self.interval,
+            self.retain_local_segments,
+            self.backend,
+            self.filesystem,
+            self.s3
+        )
+    }
+}
+
+impl Display for TieredStorageBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TieredStorageBackend::Filesystem => write!(f, "filesystem"),
+            TieredStorageBackend::S3 => write!(f, "s3"),
+        }
+    }
+}
+
+impl Display for FilesystemStorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{ path: {} }}", self.path)
+    }
+}
+
+impl Display for S3StorageConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ endpoint: {}, region: {}, bucket: {}, access_key_id: {} }}",
+            self.endpoint, self.region, self.bucket, self.access_key_id
+        )
+    }
+}
+
 impl Display for StreamConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{{ path: {} }}", self.path)
@@ -164,12 +307,14 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, deduplicate_messages: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, deduplicate_messages: {}, max_producers: {}, enforce_fsync: {}, validate_checksum: {}, max_scanned_messages: {} }}",
           self.path,
           self.messages_required_to_save,
           self.deduplicate_messages,
+          self.max_producers,
           self.enforce_fsync,
-          self.validate_checksum
+          self.validate_checksum,
+          self.max_scanned_messages
       )
     }
 }
@@ -218,16 +363,21 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, database: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {} }}",
+          "{{ path: {}, database: {}, backup: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {}, transaction: {}, polling: {}, quota: {}, tiered_storage: {} }}",
           self.path,
           self.database,
+          self.backup,
           self.logging,
           self.cache,
           self.stream,
           self.topic,
           self.partition,
           self.segment,
-          self.encryption
+          self.encryption,
+          self.transaction,
+          self.polling,
+          self.quota,
+          self.tiered_storage
       )
     }
 }
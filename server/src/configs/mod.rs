@@ -1,5 +1,7 @@
+pub mod cluster;
 pub mod server;
 pub mod system;
+pub mod telemetry;
 
 pub mod http;
 pub mod quic;
@@ -1,8 +1,10 @@
+use crate::configs::cluster::ClusterConfig;
 use crate::configs::config_provider::ConfigProvider;
 use crate::configs::http::HttpConfig;
 use crate::configs::quic::QuicConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
+use crate::configs::telemetry::TelemetryConfig;
 use crate::server_error::ServerError;
 use iggy::validatable::Validatable;
 use serde::{Deserialize, Serialize};
@@ -10,13 +12,23 @@ use std::sync::Arc;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ServerConfig {
+    pub consumer_group_member_cleaner: ConsumerGroupMemberCleanerConfig,
     pub message_cleaner: MessageCleanerConfig,
+    pub message_compactor: MessageCompactorConfig,
     pub message_saver: MessageSaverConfig,
     pub personal_access_token: PersonalAccessTokenConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
     pub http: HttpConfig,
+    pub cluster: ClusterConfig,
+    pub telemetry: TelemetryConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConsumerGroupMemberCleanerConfig {
+    pub enabled: bool,
+    pub interval: u64,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -25,6 +37,13 @@ pub struct MessageCleanerConfig {
     pub interval: u64,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageCompactorConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub tombstone_retention: u64,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MessageSaverConfig {
     pub enabled: bool,
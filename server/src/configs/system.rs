@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 pub struct SystemConfig {
     pub path: String,
     pub database: DatabaseConfig,
+    pub backup: BackupConfig,
     pub logging: LoggingConfig,
     pub cache: CacheConfig,
     pub stream: StreamConfig,
@@ -12,6 +13,10 @@ pub struct SystemConfig {
     pub partition: PartitionConfig,
     pub segment: SegmentConfig,
     pub encryption: EncryptionConfig,
+    pub transaction: TransactionConfig,
+    pub polling: PollingConfig,
+    pub quota: QuotaConfig,
+    pub tiered_storage: TieredStorageConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -19,6 +24,11 @@ pub struct DatabaseConfig {
     pub path: String,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BackupConfig {
+    pub path: String,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct LoggingConfig {
     pub path: String,
@@ -54,8 +64,10 @@ pub struct PartitionConfig {
     pub path: String,
     pub messages_required_to_save: u32,
     pub deduplicate_messages: bool,
+    pub max_producers: u32,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub max_scanned_messages: u32,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -66,6 +78,57 @@ pub struct SegmentConfig {
     pub cache_time_indexes: bool,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransactionConfig {
+    pub timeout: u64,
+    pub max_messages: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PollingConfig {
+    pub max_wait: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct QuotaConfig {
+    pub enabled: bool,
+    pub user_bytes_per_second: u64,
+    pub user_requests_per_second: u32,
+    pub client_bytes_per_second: u64,
+    pub client_requests_per_second: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TieredStorageConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub retain_local_segments: u32,
+    pub backend: TieredStorageBackend,
+    pub filesystem: FilesystemStorageConfig,
+    pub s3: S3StorageConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TieredStorageBackend {
+    Filesystem,
+    S3,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FilesystemStorageConfig {
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct S3StorageConfig {
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
 impl SystemConfig {
     pub fn get_system_path(&self) -> String {
         self.path.to_string()
@@ -75,6 +138,22 @@ impl SystemConfig {
         format!("{}/{}", self.get_system_path(), self.database.path)
     }
 
+    pub fn get_backups_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.backup.path)
+    }
+
+    pub fn get_backup_path(&self, name: &str) -> String {
+        format!("{}/{}", self.get_backups_path(), name)
+    }
+
+    pub fn get_tiered_storage_path(&self) -> String {
+        format!(
+            "{}/{}",
+            self.get_system_path(),
+            self.tiered_storage.filesystem.path
+        )
+    }
+
     pub fn get_streams_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.stream.path)
     }
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    pub service_name: String,
+    /// The OTLP/HTTP endpoint of the collector receiving the spans, e.g. `http://localhost:4318/v1/traces`.
+    pub endpoint: String,
+}
@@ -1,7 +1,11 @@
 extern crate sysinfo;
 
+use crate::configs::cluster::ClusterConfig;
 use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, SegmentConfig};
+use crate::configs::system::{
+    CacheConfig, SegmentConfig, TieredStorageBackend, TieredStorageConfig,
+};
+use crate::configs::telemetry::TelemetryConfig;
 use crate::server_error::ServerError;
 use crate::streaming::segments::segment;
 use byte_unit::{Byte, ByteUnit};
@@ -9,13 +13,21 @@ use iggy::validatable::Validatable;
 use sysinfo::SystemExt;
 use tracing::{error, info, warn};
 
-use super::server::{MessageCleanerConfig, MessageSaverConfig};
+use super::server::{
+    ConsumerGroupMemberCleanerConfig, MessageCleanerConfig, MessageCompactorConfig,
+    MessageSaverConfig,
+};
 
 impl Validatable<ServerError> for ServerConfig {
     fn validate(&self) -> Result<(), ServerError> {
         self.system.segment.validate()?;
         self.system.cache.validate()?;
+        self.system.tiered_storage.validate()?;
         self.personal_access_token.validate()?;
+        self.message_compactor.validate()?;
+        self.consumer_group_member_cleaner.validate()?;
+        self.cluster.validate()?;
+        self.telemetry.validate()?;
 
         Ok(())
     }
@@ -97,6 +109,63 @@ impl Validatable<ServerError> for MessageCleanerConfig {
     }
 }
 
+impl Validatable<ServerError> for MessageCompactorConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.interval == 0 {
+            error!("Message compactor interval size cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for TieredStorageConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval == 0 {
+            error!("Tiered storage interval cannot be zero, it must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        match self.backend {
+            TieredStorageBackend::Filesystem => {
+                if self.filesystem.path.is_empty() {
+                    error!("Tiered storage filesystem path cannot be empty.");
+                    return Err(ServerError::InvalidConfiguration);
+                }
+            }
+            TieredStorageBackend::S3 => {
+                if self.s3.endpoint.is_empty()
+                    || self.s3.region.is_empty()
+                    || self.s3.bucket.is_empty()
+                {
+                    error!("Tiered storage S3 endpoint, region and bucket cannot be empty.");
+                    return Err(ServerError::InvalidConfiguration);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ConsumerGroupMemberCleanerConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.interval == 0 {
+            error!(
+                "Consumer group member cleaner interval cannot be zero, it must be greater than 0."
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.max_tokens_per_user == 0 {
@@ -114,3 +183,73 @@ impl Validatable<ServerError> for PersonalAccessTokenConfig {
         Ok(())
     }
 }
+
+impl Validatable<ServerError> for ClusterConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let nodes = match self.get_nodes() {
+            Ok(nodes) => nodes,
+            Err(error) => {
+                error!("Invalid cluster nodes: {}, {}", self.nodes, error);
+                return Err(ServerError::InvalidConfiguration);
+            }
+        };
+
+        if self.replication_factor == 0 || self.replication_factor as usize > nodes.len() {
+            error!(
+                "Cluster replication factor: {} must be between 1 and the number of the nodes: {}.",
+                self.replication_factor,
+                nodes.len()
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.min_insync_replicas == 0 || self.min_insync_replicas > self.replication_factor {
+            error!(
+                "Cluster min in-sync replicas: {} must be between 1 and the replication factor: {}.",
+                self.min_insync_replicas, self.replication_factor
+            );
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.heartbeat_interval == 0
+            || self.node_timeout == 0
+            || self.replica_lag_timeout == 0
+            || self.replication_interval == 0
+            || self.acks_timeout == 0
+        {
+            error!("Cluster intervals and timeouts cannot be zero, they must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.node_timeout <= self.heartbeat_interval {
+            error!("Cluster node timeout must be greater than the heartbeat interval.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for TelemetryConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.service_name.trim().is_empty() {
+            error!("Telemetry service name cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        if self.endpoint.trim().is_empty() {
+            error!("Telemetry endpoint cannot be empty.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
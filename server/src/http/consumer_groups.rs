@@ -7,9 +7,11 @@ use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Extension, Json, Router};
+use iggy::consumer::Consumer;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::identifier::Identifier;
 use iggy::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -20,6 +22,7 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/:consumer_group_id",
             get(get_consumer_group).delete(delete_consumer_group),
         )
+        .route("/:consumer_group_id/lag", get(get_consumer_group_lag))
         .with_state(state)
 }
 
@@ -43,6 +46,26 @@ async fn get_consumer_group(
     Ok(Json(consumer_group))
 }
 
+async fn get_consumer_group_lag(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, consumer_group_id)): Path<(String, String, String)>,
+) -> Result<Json<ConsumerLag>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let consumer_group_id = Identifier::from_str_value(&consumer_group_id)?;
+    let system = state.system.read().await;
+    let lag = system
+        .get_consumer_lag(
+            &Session::stateless(identity.user_id),
+            &Consumer::group(consumer_group_id),
+            &stream_id,
+            &topic_id,
+        )
+        .await?;
+    Ok(Json(lag))
+}
+
 async fn get_consumer_groups(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -74,6 +97,9 @@ async fn create_consumer_group(
             &command.topic_id,
             command.consumer_group_id,
             &command.name,
+            command.partition_assignment_strategy,
+            command.session_timeout,
+            command.dead_letter_topic,
         )
         .await?;
     Ok(StatusCode::CREATED)
@@ -11,6 +11,13 @@ pub enum CustomError {
     Error(#[from] Error),
 }
 
+/// Attached to the error responses, so that the errors can be counted by the metrics middleware.
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorCode {
+    pub code: u32,
+    pub name: &'static str,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
     pub id: u32,
@@ -34,8 +41,10 @@ impl IntoResponse for CustomError {
                     Error::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     Error::CannotLoadResource(_) => StatusCode::NOT_FOUND,
                     Error::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    Error::TransactionNotFound(_) => StatusCode::NOT_FOUND,
                     Error::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::WriteError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                    Error::CannotCreateBackup(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::CannotParseInt(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::CannotParseSlice(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::CannotParseUtf8(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -43,10 +52,16 @@ impl IntoResponse for CustomError {
                     Error::Unauthorized => StatusCode::FORBIDDEN,
                     _ => StatusCode::BAD_REQUEST,
                 };
-                (status_code, Json(ErrorResponse::from_error(error)))
+                let error_code = ErrorCode {
+                    code: error.as_code(),
+                    name: error.as_string(),
+                };
+                let mut response =
+                    (status_code, Json(ErrorResponse::from_error(error))).into_response();
+                response.extensions_mut().insert(error_code);
+                response
             }
         }
-        .into_response()
     }
 }
 
@@ -4,9 +4,10 @@ use crate::http::jwt::jwt_manager::JwtManager;
 use crate::http::jwt::middleware::jwt_auth;
 use crate::http::metrics::metrics;
 use crate::http::state::AppState;
+use crate::http::telemetry::trace;
 use crate::http::{
-    consumer_groups, consumer_offsets, messages, partitions, personal_access_tokens, streams,
-    system, topics, users,
+    consumer_groups, consumer_offsets, messages, partitions, personal_access_tokens, schemas,
+    streams, system, topics, transactions, users,
 };
 use crate::streaming::systems::system::System;
 use axum::http::Method;
@@ -34,6 +35,7 @@ pub async fn start(config: HttpConfig, system: Arc<RwLock<System>>) {
                 personal_access_tokens::router(app_state.clone()),
             )
             .nest("/users", users::router(app_state.clone()))
+            .nest("/transactions", transactions::router(app_state.clone()))
             .nest(
                 "/streams",
                 streams::router(app_state.clone()).nest(
@@ -51,7 +53,8 @@ pub async fn start(config: HttpConfig, system: Arc<RwLock<System>>) {
                         .nest(
                             "/:topic_id/partitions",
                             partitions::router(app_state.clone()),
-                        ),
+                        )
+                        .nest("/:topic_id/schemas", schemas::router(app_state.clone())),
                 ),
             ),
     );
@@ -66,6 +69,7 @@ pub async fn start(config: HttpConfig, system: Arc<RwLock<System>>) {
 
     start_expired_tokens_cleaner(app_state.clone());
     app = app.layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
+    app = app.layer(middleware::from_fn(trace));
     info!("Started {api_name} on: {:?}", config.address);
 
     if !config.tls.enabled {
@@ -1,12 +1,15 @@
 use crate::streaming::clients::client_manager::Client;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::quotas::quota_manager::QuotaUsage;
+use crate::streaming::schemas::schema::TopicSchema;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
-use iggy::models::client_info::ConsumerGroupInfo;
+use iggy::models::client_info::{ConsumerGroupInfo, QuotaUsageInfo};
 use iggy::models::consumer_group::{ConsumerGroupDetails, ConsumerGroupMember};
 use iggy::models::personal_access_token::PersonalAccessTokenInfo;
+use iggy::models::schema::{Schema, TopicSchemas};
 use iggy::models::stream::StreamDetails;
 use iggy::models::topic::TopicDetails;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
@@ -57,6 +60,11 @@ pub async fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
             partitions_count: topic.get_partitions().len() as u32,
             messages_count: topic.get_messages_count().await,
             message_expiry: topic.message_expiry,
+            compression_algorithm: topic.compression_algorithm,
+            compression_ratio: topic.get_compression_ratio().await,
+            compaction_enabled: topic.compaction_enabled,
+            max_topic_size: topic.max_topic_size,
+            max_partition_size: topic.max_partition_size,
         };
         topics_data.push(topic);
     }
@@ -74,6 +82,11 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         partitions_count: topic.get_partitions().len() as u32,
         partitions: Vec::new(),
         message_expiry: topic.message_expiry,
+        compression_algorithm: topic.compression_algorithm,
+        compression_ratio: topic.get_compression_ratio().await,
+        compaction_enabled: topic.compaction_enabled,
+        max_topic_size: topic.max_topic_size,
+        max_partition_size: topic.max_partition_size,
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -132,7 +145,10 @@ pub fn map_personal_access_tokens(
     personal_access_tokens_data
 }
 
-pub async fn map_client(client: &Client) -> iggy::models::client_info::ClientInfoDetails {
+pub async fn map_client(
+    client: &Client,
+    quota_usage: &QuotaUsage,
+) -> iggy::models::client_info::ClientInfoDetails {
     let client = iggy::models::client_info::ClientInfoDetails {
         client_id: client.client_id,
         user_id: client.user_id,
@@ -148,6 +164,11 @@ pub async fn map_client(client: &Client) -> iggy::models::client_info::ClientInf
                 consumer_group_id: consumer_group.consumer_group_id,
             })
             .collect(),
+        quota_usage: QuotaUsageInfo {
+            requests: quota_usage.requests,
+            bytes: quota_usage.bytes,
+            throttled_requests: quota_usage.throttled_requests,
+        },
     };
     client
 }
@@ -196,6 +217,10 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
         name: consumer_group.name.clone(),
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.get_members().len() as u32,
+        partition_assignment_strategy: consumer_group.partition_assignment_strategy,
+        session_timeout: consumer_group.session_timeout,
+        generation: consumer_group.get_generation(),
+        dead_letter_topic: consumer_group.dead_letter_topic,
         members: Vec::new(),
     };
     let members = consumer_group.get_members();
@@ -210,3 +235,20 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
     }
     consumer_group_details
 }
+
+pub fn map_schema(schema: &TopicSchema) -> Schema {
+    Schema {
+        id: schema.id,
+        format: schema.format,
+        definition: schema.definition.clone(),
+        created_at: schema.created_at,
+    }
+}
+
+pub fn map_topic_schemas(topic: &Topic) -> TopicSchemas {
+    TopicSchemas {
+        compatibility: topic.schema_compatibility,
+        validation_enabled: topic.schema_validation_enabled,
+        schemas: topic.get_schemas().into_iter().map(map_schema).collect(),
+    }
+}
@@ -5,19 +5,24 @@ use crate::streaming;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::System;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::messages::poison_message::PoisonMessage;
 use iggy::messages::poll_messages::PollMessages;
-use iggy::messages::send_messages::SendMessages;
+use iggy::messages::send_messages::{Acks, SendMessages};
 use iggy::validatable::Validatable;
 use std::sync::Arc;
+use tracing::warn;
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/", get(poll_messages).post(send_messages))
+        .route("/poison", post(poison_message))
         .with_state(state)
 }
 
@@ -34,16 +39,21 @@ async fn poll_messages(
     let partition_id = query.partition_id.unwrap_or(0);
     let consumer_id = PollingConsumer::resolve_consumer_id(&query.consumer.id);
     let consumer = PollingConsumer::Consumer(consumer_id, partition_id);
-    let system = state.system.read().await;
-    let polled_messages = system
-        .poll_messages(
-            &Session::stateless(identity.user_id),
-            consumer,
-            &query.stream_id,
-            &query.topic_id,
-            PollingArgs::new(query.strategy, query.count, query.auto_commit),
-        )
-        .await?;
+    let polled_messages = System::poll_messages_with_wait(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        consumer,
+        &query.stream_id,
+        &query.topic_id,
+        PollingArgs::new(
+            query.strategy,
+            query.count,
+            query.auto_commit,
+            query.max_wait,
+            query.filter.clone(),
+        ),
+    )
+    .await?;
     Ok(Json(polled_messages))
 }
 
@@ -58,15 +68,61 @@ async fn send_messages(
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.validate()?;
 
+    if command.acks == Acks::None {
+        tokio::spawn(async move {
+            if let Err(error) = append_messages(&state, identity.user_id, &command).await {
+                warn!(
+                    "Failed to append the messages sent without acks by user with ID: {}, error: {error}",
+                    identity.user_id
+                );
+            }
+        });
+        return Ok(StatusCode::ACCEPTED);
+    }
+
+    append_messages(&state, identity.user_id, &command).await?;
+    Ok(StatusCode::CREATED)
+}
+
+async fn append_messages(
+    state: &AppState,
+    user_id: u32,
+    command: &SendMessages,
+) -> Result<(), Error> {
+    System::append_messages_with_acks(
+        &state.system,
+        &Session::stateless(user_id),
+        &command.stream_id,
+        &command.topic_id,
+        &command.partitioning,
+        command.producer.as_ref(),
+        command.transaction_id,
+        command.acks,
+        &command.messages,
+    )
+    .await
+}
+
+async fn poison_message(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<PoisonMessage>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let consumer_id = PollingConsumer::resolve_consumer_id(&command.consumer.id);
+    let consumer = PollingConsumer::Consumer(consumer_id, command.partition_id.unwrap_or(0));
     let system = state.system.read().await;
     system
-        .append_messages(
+        .poison_message(
             &Session::stateless(identity.user_id),
+            consumer,
             &command.stream_id,
             &command.topic_id,
-            &command.partitioning,
-            &command.messages,
+            command.offset,
         )
         .await?;
-    Ok(StatusCode::CREATED)
+    Ok(StatusCode::NO_CONTENT)
 }
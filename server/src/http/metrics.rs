@@ -1,3 +1,4 @@
+use crate::http::error::ErrorCode;
 use crate::http::state::AppState;
 use axum::{
     extract::State,
@@ -13,5 +14,14 @@ pub async fn metrics<T>(
     next: Next<T>,
 ) -> Result<Response, StatusCode> {
     state.system.read().await.metrics.increment_http_requests();
-    Ok(next.run(request).await)
+    let response = next.run(request).await;
+    if let Some(error_code) = response.extensions().get::<ErrorCode>() {
+        state
+            .system
+            .read()
+            .await
+            .metrics
+            .increment_errors(error_code.code, error_code.name);
+    }
+    Ok(response)
 }
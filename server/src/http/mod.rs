@@ -8,8 +8,11 @@ pub mod messages;
 pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod state;
 pub mod streams;
 pub mod system;
+pub mod telemetry;
 pub mod topics;
+pub mod transactions;
 pub mod users;
@@ -2,6 +2,7 @@ use crate::http::error::CustomError;
 use crate::http::jwt::json_web_token::Identity;
 use crate::http::state::AppState;
 use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::post;
@@ -27,15 +28,20 @@ async fn create_partitions(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
-    let mut system = state.system.write().await;
-    system
-        .create_partitions(
-            &Session::stateless(identity.user_id),
-            &command.stream_id,
-            &command.topic_id,
-            command.partitions_count,
-        )
+    state
+        .system
+        .read()
+        .await
+        .ensure_cluster_controller()
         .await?;
+    System::create_partitions(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        &command.stream_id,
+        &command.topic_id,
+        command.partitions_count,
+    )
+    .await?;
     Ok(StatusCode::CREATED)
 }
 
@@ -48,14 +54,19 @@ async fn delete_partitions(
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
-    let mut system = state.system.write().await;
-    system
-        .delete_partitions(
-            &Session::stateless(identity.user_id),
-            &query.stream_id,
-            &query.topic_id,
-            query.partitions_count,
-        )
+    state
+        .system
+        .read()
+        .await
+        .ensure_cluster_controller()
         .await?;
+    System::delete_partitions(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        &query.stream_id,
+        &query.topic_id,
+        query.partitions_count,
+    )
+    .await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -0,0 +1,121 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::state::AppState;
+use crate::streaming::schemas::schema::SchemaDefinition;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use iggy::identifier::Identifier;
+use iggy::models::schema::{Schema, TopicSchemas};
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::schemas::update_schema_settings::UpdateSchemaSettings;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route(
+            "/",
+            get(get_schemas)
+                .post(register_schema)
+                .put(update_schema_settings),
+        )
+        .route("/:schema_id", get(get_schema).delete(delete_schema))
+        .with_state(state)
+}
+
+async fn get_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, schema_id)): Path<(String, String, u32)>,
+) -> Result<Json<Schema>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let schema = system.get_schema(
+        &Session::stateless(identity.user_id),
+        &stream_id,
+        &topic_id,
+        schema_id,
+    )?;
+    Ok(Json(mapper::map_schema(schema)))
+}
+
+async fn get_schemas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<TopicSchemas>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let topic = system.get_schemas(&Session::stateless(identity.user_id), &stream_id, &topic_id)?;
+    Ok(Json(mapper::map_topic_schemas(topic)))
+}
+
+async fn register_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RegisterSchema>,
+) -> Result<Json<Schema>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    // The definition is parsed before taking the lock, as it might take a while.
+    let definition = SchemaDefinition::parse_blocking(command.format, &command.definition).await?;
+    let mut system = state.system.write().await;
+    let schema = system
+        .register_schema(
+            &Session::stateless(identity.user_id),
+            &command.stream_id,
+            &command.topic_id,
+            definition,
+        )
+        .await?;
+    Ok(Json(mapper::map_schema(schema)))
+}
+
+async fn update_schema_settings(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<UpdateSchemaSettings>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let mut system = state.system.write().await;
+    system
+        .update_schema_settings(
+            &Session::stateless(identity.user_id),
+            &command.stream_id,
+            &command.topic_id,
+            command.compatibility,
+            command.validation_enabled,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, schema_id)): Path<(String, String, u32)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let mut system = state.system.write().await;
+    system
+        .delete_schema(
+            &Session::stateless(identity.user_id),
+            &stream_id,
+            &topic_id,
+            schema_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
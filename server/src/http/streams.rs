@@ -3,6 +3,7 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::state::AppState;
 use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::get;
@@ -52,14 +53,19 @@ async fn create_stream(
     Json(command): Json<CreateStream>,
 ) -> Result<StatusCode, CustomError> {
     command.validate()?;
-    let mut system = state.system.write().await;
-    system
-        .create_stream(
-            &Session::stateless(identity.user_id),
-            command.stream_id,
-            &command.name,
-        )
+    state
+        .system
+        .read()
+        .await
+        .ensure_cluster_controller()
         .await?;
+    System::create_stream(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        command.stream_id,
+        &command.name,
+    )
+    .await?;
     Ok(StatusCode::CREATED)
 }
 
@@ -71,14 +77,19 @@ async fn update_stream(
 ) -> Result<StatusCode, CustomError> {
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.validate()?;
-    let mut system = state.system.write().await;
-    system
-        .update_stream(
-            &Session::stateless(identity.user_id),
-            &command.stream_id,
-            &command.name,
-        )
+    state
+        .system
+        .read()
+        .await
+        .ensure_cluster_controller()
         .await?;
+    System::update_stream(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        &command.stream_id,
+        &command.name,
+    )
+    .await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -88,9 +99,17 @@ async fn delete_stream(
     Path(stream_id): Path<String>,
 ) -> Result<StatusCode, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
-    let mut system = state.system.write().await;
-    system
-        .delete_stream(&Session::stateless(identity.user_id), &stream_id)
+    state
+        .system
+        .read()
+        .await
+        .ensure_cluster_controller()
         .await?;
+    System::delete_stream(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        &stream_id,
+    )
+    .await?;
     Ok(StatusCode::NO_CONTENT)
 }
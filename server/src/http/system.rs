@@ -4,10 +4,13 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::state::AppState;
 use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
 use axum::extract::{Path, State};
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use iggy::models::backup::Backup;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
+use iggy::models::cluster::ClusterMetadata;
 use iggy::models::stats::Stats;
 use std::sync::Arc;
 
@@ -19,6 +22,8 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
         .route("/", get(|| async { NAME }))
         .route("/ping", get(|| async { PONG }))
         .route("/stats", get(get_stats))
+        .route("/backups", post(create_backup))
+        .route("/cluster", get(get_cluster_metadata))
         .route("/clients", get(get_clients))
         .route("/clients/:client_id", get(get_client));
     if metrics_config.enabled {
@@ -30,6 +35,8 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
 
 async fn get_metrics(State(state): State<Arc<AppState>>) -> Result<String, CustomError> {
     let system = state.system.read().await;
+    system.update_consumer_lag_metrics().await;
+    system.update_cache_metrics().await;
     Ok(system.metrics.get_formatted_output())
 }
 
@@ -44,6 +51,26 @@ async fn get_stats(
     Ok(Json(stats))
 }
 
+async fn create_backup(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Backup>, CustomError> {
+    let backup =
+        System::create_backup(&state.system, &Session::stateless(identity.user_id)).await?;
+    Ok(Json(backup))
+}
+
+async fn get_cluster_metadata(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<ClusterMetadata>, CustomError> {
+    let system = state.system.read().await;
+    let metadata = system
+        .get_cluster_metadata(&Session::stateless(identity.user_id))
+        .await?;
+    Ok(Json(metadata))
+}
+
 async fn get_client(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -54,7 +81,8 @@ async fn get_client(
         .get_client(&Session::stateless(identity.user_id), client_id)
         .await?;
     let client = client.read().await;
-    let client = mapper::map_client(&client).await;
+    let quota_usage = system.quotas.get_client_usage(client.client_id);
+    let client = mapper::map_client(&client, &quota_usage).await;
     Ok(Json(client))
 }
 
@@ -0,0 +1,19 @@
+use crate::telemetry::start_http_span;
+use axum::{http::Request, middleware::Next, response::Response};
+use opentelemetry::trace::{Span, Status};
+use opentelemetry::KeyValue;
+
+pub async fn trace<T>(request: Request<T>, next: Next<T>) -> Response {
+    let mut span = start_http_span(request.method(), request.uri().path(), request.headers());
+    let response = next.run(request).await;
+    let status = response.status();
+    span.set_attribute(KeyValue::new(
+        "http.response.status_code",
+        status.as_u16() as i64,
+    ));
+    if status.is_server_error() {
+        span.set_status(Status::error(status.to_string()));
+    }
+    span.end();
+    response
+}
@@ -3,6 +3,7 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::state::AppState;
 use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::get;
@@ -57,17 +58,26 @@ async fn create_topic(
 ) -> Result<StatusCode, CustomError> {
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.validate()?;
-    let mut system = state.system.write().await;
-    system
-        .create_topic(
-            &Session::stateless(identity.user_id),
-            &command.stream_id,
-            command.topic_id,
-            &command.name,
-            command.partitions_count,
-            command.message_expiry,
-        )
+    state
+        .system
+        .read()
+        .await
+        .ensure_cluster_controller()
         .await?;
+    System::create_topic(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        &command.stream_id,
+        command.topic_id,
+        &command.name,
+        command.partitions_count,
+        command.message_expiry,
+        command.compression_algorithm,
+        command.compaction_enabled,
+        command.max_topic_size,
+        command.max_partition_size,
+    )
+    .await?;
     Ok(StatusCode::CREATED)
 }
 
@@ -80,16 +90,23 @@ async fn update_topic(
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
-    let mut system = state.system.write().await;
-    system
-        .update_topic(
-            &Session::stateless(identity.user_id),
-            &command.stream_id,
-            &command.topic_id,
-            &command.name,
-            command.message_expiry,
-        )
+    state
+        .system
+        .read()
+        .await
+        .ensure_cluster_controller()
         .await?;
+    System::update_topic(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        &command.stream_id,
+        &command.topic_id,
+        &command.name,
+        command.message_expiry,
+        command.max_topic_size,
+        command.max_partition_size,
+    )
+    .await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -100,9 +117,18 @@ async fn delete_topic(
 ) -> Result<StatusCode, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
-    let mut system = state.system.write().await;
-    system
-        .delete_topic(&Session::stateless(identity.user_id), &stream_id, &topic_id)
+    state
+        .system
+        .read()
+        .await
+        .ensure_cluster_controller()
         .await?;
+    System::delete_topic(
+        &state.system,
+        &Session::stateless(identity.user_id),
+        &stream_id,
+        &topic_id,
+    )
+    .await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -0,0 +1,53 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::state::AppState;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{delete, post};
+use axum::{Extension, Json, Router};
+use iggy::models::transaction::TransactionInfo;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/", post(begin_transaction))
+        .route("/:transaction_id", delete(abort_transaction))
+        .route("/:transaction_id/commit", post(commit_transaction))
+        .with_state(state)
+}
+
+async fn begin_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<TransactionInfo>, CustomError> {
+    let system = state.system.read().await;
+    let id = system
+        .begin_transaction(&Session::stateless(identity.user_id))
+        .await?;
+    Ok(Json(TransactionInfo { id }))
+}
+
+async fn commit_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .commit_transaction(&Session::stateless(identity.user_id), transaction_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn abort_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .abort_transaction(&Session::stateless(identity.user_id), transaction_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -73,6 +73,7 @@ async fn create_user(
             &Session::stateless(identity.user_id),
             &command.username,
             &command.password,
+            command.status,
             command.permissions.clone(),
         )
         .await?;
@@ -94,6 +95,7 @@ async fn update_user(
             &command.user_id,
             command.username,
             command.status,
+            command.quota,
         )
         .await?;
     Ok(StatusCode::NO_CONTENT)
@@ -8,3 +8,4 @@ pub mod quic;
 pub mod server_error;
 pub mod streaming;
 pub mod tcp;
+pub mod telemetry;
@@ -8,11 +8,17 @@ mod quic;
 mod server_error;
 mod streaming;
 mod tcp;
+mod telemetry;
 
 use crate::args::Args;
+use crate::channels::commands::clean_consumer_group_members::CleanConsumerGroupMembersExecutor;
 use crate::channels::commands::clean_messages::CleanMessagesExecutor;
 use crate::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use crate::channels::commands::compact_messages::CompactMessagesExecutor;
+use crate::channels::commands::offload_segments::OffloadSegmentsExecutor;
+use crate::channels::commands::replicate_messages::ReplicateMessagesExecutor;
 use crate::channels::commands::save_messages::SaveMessagesExecutor;
+use crate::channels::commands::send_cluster_heartbeats::SendClusterHeartbeatsExecutor;
 use crate::channels::handler::ServerCommandHandler;
 use crate::configs::config_provider;
 use crate::configs::server::ServerConfig;
@@ -20,6 +26,7 @@ use crate::http::http_server;
 use crate::logging::Logging;
 use crate::quic::quic_server;
 use crate::server_error::ServerError;
+use crate::streaming::cluster::cluster_manager::ClusterManager;
 use crate::streaming::persistence::persister::FileWithSyncPersister;
 use crate::streaming::segments::storage::FileSegmentStorage;
 use crate::streaming::systems::system::System;
@@ -50,15 +57,29 @@ async fn main() -> Result<(), ServerError> {
     let config = ServerConfig::load(config_provider.as_ref()).await?;
 
     logging.late_init(config.system.get_system_path(), &config.system.logging)?;
+    telemetry::init(&config.telemetry)?;
+
+    if let Some(backup_path) = &args.restore_backup {
+        System::restore_backup(&config.system, backup_path).await?;
+    }
 
     let mut system = System::new(config.system.clone(), None, config.personal_access_token);
 
+    if config.cluster.enabled {
+        system.enable_cluster(ClusterManager::new(&config.cluster)?);
+    }
+
     system.init().await?;
     let system = Arc::new(RwLock::new(system));
     let _command_handler = ServerCommandHandler::new(system.clone(), &config)
         .install_handler(SaveMessagesExecutor)
         .install_handler(CleanMessagesExecutor)
-        .install_handler(CleanPersonalAccessTokensExecutor);
+        .install_handler(CompactMessagesExecutor)
+        .install_handler(OffloadSegmentsExecutor)
+        .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(CleanConsumerGroupMembersExecutor)
+        .install_handler(SendClusterHeartbeatsExecutor::default())
+        .install_handler(ReplicateMessagesExecutor::default());
 
     #[cfg(unix)]
     let (mut ctrl_c, mut sigterm) = {
@@ -114,9 +135,12 @@ async fn main() -> Result<(), ServerError> {
 
     let shutdown_timestamp = Instant::now();
     let mut system = system.write().await;
-    let persister = Arc::new(FileWithSyncPersister);
-    let storage = Arc::new(FileSegmentStorage::new(persister));
+    let persister = Arc::new(FileWithSyncPersister::new(
+        system.metrics.segment_flush_duration(),
+    ));
+    let storage = Arc::new(FileSegmentStorage::new(persister, None));
     system.shutdown(storage).await?;
+    telemetry::shutdown().await;
     let elapsed_time = shutdown_timestamp.elapsed();
 
     info!(
@@ -1,4 +1,7 @@
 use crate::binary::command;
+use crate::binary::executor::RequestExecutor;
+use crate::binary::handshake;
+use crate::binary::sender::Sender;
 use crate::quic::quic_sender::QuicSender;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
@@ -13,6 +16,7 @@ use tracing::{debug, error, info};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
+const CORRELATION_ID_BYTES_LENGTH: usize = 4;
 
 pub fn start(endpoint: Endpoint, system: Arc<RwLock<System>>) {
     for _ in 0..LISTENERS_COUNT {
@@ -49,6 +53,7 @@ async fn handle_connection(
             .add_client(&address, Transport::Quic)
             .await;
         let mut session = Session::from_client_id(client_id);
+        let mut executor: Option<RequestExecutor> = None;
         loop {
             let stream = connection.accept_bi().await;
             let mut stream = match stream {
@@ -74,46 +79,91 @@ async fn handle_connection(
             let request = request.unwrap();
             if request.len() < INITIAL_BYTES_LENGTH {
                 error!(
-                "Unable to read the QUIC request length, expected: {} bytes, received: {} bytes.",
-                INITIAL_BYTES_LENGTH, request.len()
-            );
+                    "Unable to read the QUIC request length, expected: {} bytes, received: {} bytes.",
+                    INITIAL_BYTES_LENGTH,
+                    request.len()
+                );
                 continue;
             }
 
             debug!("Trying to read command...");
             let length = &request[..INITIAL_BYTES_LENGTH];
             let length = u32::from_le_bytes(length.try_into().unwrap_or([0; 4]));
-            let command = Command::from_bytes(&request[INITIAL_BYTES_LENGTH..]);
-            if command.is_err() {
-                error!(
-                    "Error when reading the QUIC request command: {:?}",
-                    command.err()
-                );
-                continue;
+            let mut request = &request[INITIAL_BYTES_LENGTH..];
+            let mut sender = QuicSender {
+                correlation_id: None,
+                send: stream.0,
+            };
+            if executor.is_none() {
+                if let Some(version) = handshake::negotiate_protocol_version(request) {
+                    info!(
+                        "Negotiated the QUIC protocol version: {} for client with ID: {}.",
+                        version, client_id
+                    );
+                    if let Err(error) = sender.send_ok_response(&version.to_le_bytes()).await {
+                        error!("Error when sending the QUIC handshake response: {:?}", error);
+                    }
+                    executor = Some(RequestExecutor::start(
+                        session.clone(),
+                        system.clone(),
+                        "QUIC",
+                    ));
+                    continue;
+                }
+            } else {
+                if request.len() < CORRELATION_ID_BYTES_LENGTH {
+                    error!(
+                        "Unable to read the QUIC request correlation ID, expected: {} bytes, received: {} bytes.",
+                        CORRELATION_ID_BYTES_LENGTH,
+                        request.len()
+                    );
+                    continue;
+                }
+
+                let correlation_id = &request[..CORRELATION_ID_BYTES_LENGTH];
+                sender.correlation_id = Some(u32::from_le_bytes(
+                    correlation_id.try_into().unwrap_or([0; 4]),
+                ));
+                request = &request[CORRELATION_ID_BYTES_LENGTH..];
             }
 
-            let command = command.unwrap();
+            let command = match Command::from_bytes(request) {
+                Ok(command) => command,
+                Err(error) => {
+                    error!("Error when reading the QUIC request command: {:?}", error);
+                    if let Err(error) = sender.send_error_response(error).await {
+                        error!("Error when sending the QUIC response: {:?}", error);
+                    }
+                    continue;
+                }
+            };
             debug!(
-                "Received a QUIC command: {}, payload size: {}",
-                command, length
+                "Received a QUIC command: {}, correlation ID: {:?}, payload size: {}",
+                command, sender.correlation_id, length
             );
 
-            let result = command::handle(
-                &command,
-                &mut QuicSender {
-                    send: stream.0,
-                    recv: stream.1,
-                },
-                &mut session,
-                system.clone(),
-            )
-            .await;
-            if result.is_err() {
-                error!("Error when handling the QUIC request: {:?}", result.err());
+            // Each request has its own stream, so the next one is accepted while the previous ones are handled by the executor.
+            let Some(executor) = &executor else {
+                handle_command(&command, &mut sender, &mut session, system.clone()).await;
                 continue;
+            };
+
+            if let Err(error) = executor.execute(command, Box::new(sender)).await {
+                error!("Error when queueing the QUIC request: {:?}", error);
             }
         }
     }
     .await?;
     Ok(())
 }
+
+async fn handle_command(
+    command: &Command,
+    sender: &mut QuicSender,
+    session: &mut Session,
+    system: Arc<RwLock<System>>,
+) {
+    if let Err(error) = command::handle(command, sender, session, system).await {
+        error!("Error when handling the QUIC request: {:?}", error);
+    }
+}
@@ -1,15 +1,15 @@
 use crate::binary::sender::Sender;
 use async_trait::async_trait;
 use iggy::error::Error;
-use quinn::{RecvStream, SendStream};
+use quinn::SendStream;
 use tracing::debug;
 
 const STATUS_OK: &[u8] = &[0; 4];
 
 #[derive(Debug)]
 pub struct QuicSender {
+    pub(crate) correlation_id: Option<u32>,
     pub(crate) send: SendStream,
-    pub(crate) recv: RecvStream,
 }
 
 unsafe impl Send for QuicSender {}
@@ -17,15 +17,6 @@ unsafe impl Sync for QuicSender {}
 
 #[async_trait]
 impl Sender for QuicSender {
-    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, Error> {
-        let read_bytes = self.recv.read(buffer).await;
-        if let Err(error) = read_bytes {
-            return Err(Error::from(error));
-        }
-
-        Ok(read_bytes.unwrap().unwrap())
-    }
-
     async fn send_empty_ok_response(&mut self) -> Result<(), Error> {
         self.send_ok_response(&[]).await
     }
@@ -42,11 +33,18 @@ impl Sender for QuicSender {
 
 impl QuicSender {
     async fn send_response(&mut self, status: &[u8], payload: &[u8]) -> Result<(), Error> {
-        debug!("Sending response with status: {:?}...", status);
+        debug!(
+            "Sending response with status: {:?}, correlation ID: {:?}...",
+            status, self.correlation_id
+        );
         let length = (payload.len() as u32).to_le_bytes();
-        self.send
-            .write_all(&[status, &length, payload].as_slice().concat())
-            .await?;
+        let response = match self.correlation_id {
+            Some(correlation_id) => [&correlation_id.to_le_bytes(), status, &length, payload]
+                .as_slice()
+                .concat(),
+            None => [status, &length, payload].as_slice().concat(),
+        };
+        self.send.write_all(&response).await?;
         self.send.finish().await?;
         debug!("Sent response with status: {:?}", status);
         Ok(())
@@ -29,6 +29,8 @@ pub enum ServerError {
     StdoutReloadFailure,
     #[error("Logging file reload failure")]
     FileReloadFailure,
+    #[error("Cannot initialize telemetry: {0}")]
+    CannotInitializeTelemetry(String),
     #[error("Cache config validation failure: {0}")]
     CacheConfigValidationFailure(String),
 }
@@ -3,6 +3,7 @@ use iggy::sizeable::Sizeable;
 use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::ops::Index;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 #[derive(Debug)]
@@ -10,6 +11,8 @@ pub struct SmartCache<T: Sizeable + Debug> {
     current_size: u64,
     buffer: VecDeque<T>,
     memory_tracker: Arc<CacheMemoryTracker>,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 impl<T> SmartCache<T>
@@ -25,9 +28,28 @@ where
             current_size,
             buffer,
             memory_tracker,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
         }
     }
 
+    /// The reads are performed via the shared reference, thus the hits and misses are counted atomically.
+    pub fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
     // Used only for cache validation tests
     #[cfg(test)]
     pub fn to_vec(&self) -> Vec<T> {
@@ -70,6 +92,33 @@ where
         }
     }
 
+    /// Removes all the elements.
+    pub fn purge(&mut self) {
+        self.memory_tracker.decrement_used_memory(self.current_size);
+        self.current_size = 0;
+        self.buffer.clear();
+    }
+
+    /// Removes the elements not matching the predicate, e.g. the messages removed by the compaction.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&T) -> bool) {
+        let mut removed_size = 0;
+        self.buffer.retain(|element| {
+            if predicate(element) {
+                return true;
+            }
+
+            removed_size += element.get_size_bytes() as u64;
+            false
+        });
+        self.memory_tracker.decrement_used_memory(removed_size);
+        self.current_size -= removed_size;
+    }
+
+    /// Returns the index of the first element not matching the predicate, the elements must be partitioned by it.
+    pub fn partition_point(&self, predicate: impl FnMut(&T) -> bool) -> usize {
+        self.buffer.partition_point(predicate)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.buffer.is_empty()
     }
@@ -89,10 +138,6 @@ where
         });
         self.buffer.extend(elements);
     }
-
-    pub fn len(&self) -> usize {
-        self.buffer.len()
-    }
 }
 
 impl<T> Index<usize> for SmartCache<T>
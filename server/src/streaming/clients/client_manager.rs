@@ -101,7 +101,8 @@ impl ClientManager {
         self.clients.values().cloned().collect()
     }
 
-    pub async fn delete_clients_for_user(&mut self, user_id: UserId) -> Result<(), Error> {
+    /// Deletes the clients authenticated as the given user and returns their IDs.
+    pub async fn delete_clients_for_user(&mut self, user_id: UserId) -> Result<Vec<u32>, Error> {
         let mut clients_to_remove = Vec::new();
         for client in self.clients.values() {
             let client = client.read().await;
@@ -112,11 +113,11 @@ impl ClientManager {
             }
         }
 
-        for client_id in clients_to_remove {
-            self.clients.remove(&client_id);
+        for client_id in &clients_to_remove {
+            self.clients.remove(client_id);
         }
 
-        Ok(())
+        Ok(clients_to_remove)
     }
 
     pub fn delete_client(&mut self, address: &SocketAddr) -> Option<Arc<RwLock<Client>>> {
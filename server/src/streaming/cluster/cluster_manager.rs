@@ -0,0 +1,719 @@
+use crate::configs::cluster::{ClusterConfig, ClusterNodeConfig};
+use iggy::error::Error;
+use iggy::models::cluster::{ClusterMetadata, ClusterNode, ClusterNodeStatus, PartitionReplicas};
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tokio::sync::{Notify, RwLock};
+use tokio::time::{timeout_at, Instant};
+use tracing::{info, warn};
+
+/// Stream ID, topic ID and partition ID.
+pub type PartitionKey = (u32, u32, u32);
+
+/// Keeps track of the cluster nodes and the leaders of the partitions. Each partition is replicated
+/// to the number of the nodes equal to the replication factor, the replicas are spread across the nodes.
+/// The online node with the lowest ID is the controller, which assigns the partition leaders and accepts
+/// the changes of the streams, topics and partitions. The other nodes follow its assignments.
+///
+/// There is a single controller chosen from the heartbeats, not elected by the consensus protocol,
+/// thus the cluster is not fault tolerant against all the network partitions. To avoid the split brain
+/// in the common case of the node being cut off from the rest of the cluster, the controller is chosen
+/// and the partition leaders accept the messages only as long as the majority of the nodes is online,
+/// as seen by the given node. The asymmetric network partitions (e.g. the node reachable only one way)
+/// might still result in two controllers and the diverging partitions.
+#[derive(Debug)]
+pub struct ClusterManager {
+    pub node_id: u32,
+    nodes: Vec<ClusterNodeConfig>,
+    replication_factor: usize,
+    min_insync_replicas: usize,
+    unclean_leader_election: bool,
+    started_at: u64,
+    node_timeout: u64,
+    replica_lag_timeout: u64,
+    acks_timeout: Duration,
+    state: RwLock<ClusterState>,
+    replicas_notifier: Notify,
+}
+
+#[derive(Debug, Default)]
+struct ClusterState {
+    last_seen: HashMap<u32, u64>,
+    partitions: HashMap<PartitionKey, PartitionReplicas>,
+    followers: HashMap<PartitionKey, HashMap<u32, FollowerProgress>>,
+    /// The leader epoch and the next offset of the partitions at the time this node became their leader.
+    epoch_start_offsets: HashMap<PartitionKey, (u32, u64)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FollowerProgress {
+    next_offset: u64,
+    caught_up_at: u64,
+}
+
+impl ClusterManager {
+    pub fn new(config: &ClusterConfig) -> Result<Self, Error> {
+        Ok(ClusterManager {
+            node_id: config.node_id,
+            nodes: config.get_nodes()?,
+            replication_factor: config.replication_factor as usize,
+            min_insync_replicas: config.min_insync_replicas as usize,
+            unclean_leader_election: config.unclean_leader_election,
+            started_at: TimeStamp::now().to_micros(),
+            node_timeout: config.node_timeout * 1000,
+            replica_lag_timeout: config.replica_lag_timeout * 1000,
+            acks_timeout: Duration::from_millis(config.acks_timeout),
+            state: RwLock::new(ClusterState::default()),
+            replicas_notifier: Notify::new(),
+        })
+    }
+
+    pub fn get_peers(&self) -> Vec<ClusterNodeConfig> {
+        self.nodes
+            .iter()
+            .filter(|node| node.id != self.node_id)
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_node(&self, node_id: u32) -> Option<&ClusterNodeConfig> {
+        self.nodes.iter().find(|node| node.id == node_id)
+    }
+
+    pub async fn record_heartbeat(&self, node_id: u32) {
+        let mut state = self.state.write().await;
+        state
+            .last_seen
+            .insert(node_id, TimeStamp::now().to_micros());
+    }
+
+    /// Returns the ID of the controller, unless the majority of the nodes is offline.
+    pub async fn get_controller_id(&self) -> Option<u32> {
+        let state = self.state.read().await;
+        self.get_controller_id_at(&state, TimeStamp::now().to_micros())
+    }
+
+    /// Returns true, if the majority of the nodes (including this one) is online.
+    pub async fn has_quorum(&self) -> bool {
+        let state = self.state.read().await;
+        self.has_quorum_at(&state, TimeStamp::now().to_micros())
+    }
+
+    pub async fn is_node_online(&self, node_id: u32) -> bool {
+        let state = self.state.read().await;
+        self.is_node_online_at(&state, node_id, TimeStamp::now().to_micros())
+    }
+
+    /// Returns true, if the given node is one of the replicas storing the partition.
+    pub fn is_replica(&self, key: &PartitionKey, node_id: u32) -> bool {
+        self.get_replicas(key).contains(&node_id)
+    }
+
+    pub async fn get_leader_id(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> u32 {
+        let key = (stream_id, topic_id, partition_id);
+        let state = self.state.read().await;
+        match state.partitions.get(&key) {
+            Some(replicas) => replicas.leader_id,
+            None => self.get_default_leader_id(&key),
+        }
+    }
+
+    pub async fn get_leader_epoch(&self, key: &PartitionKey) -> u32 {
+        let state = self.state.read().await;
+        state
+            .partitions
+            .get(key)
+            .map(|replicas| replicas.leader_epoch)
+            .unwrap_or(0)
+    }
+
+    /// Returns the offset, from which this node has been leading the partition in the given epoch.
+    pub async fn get_epoch_start_offset(
+        &self,
+        key: &PartitionKey,
+        leader_epoch: u32,
+    ) -> Option<u64> {
+        let state = self.state.read().await;
+        state
+            .epoch_start_offsets
+            .get(key)
+            .filter(|(epoch, _)| *epoch == leader_epoch)
+            .map(|(_, offset)| *offset)
+    }
+
+    /// Records the next offset of the partition as the start of the leader epoch, unless it's been already recorded.
+    /// Must be invoked before the leader appends any messages in the new epoch.
+    pub async fn record_epoch_start_offset(
+        &self,
+        key: PartitionKey,
+        leader_epoch: u32,
+        next_offset: u64,
+    ) {
+        let mut state = self.state.write().await;
+        if let Some((epoch, _)) = state.epoch_start_offsets.get(&key) {
+            if *epoch >= leader_epoch {
+                return;
+            }
+        }
+
+        state
+            .epoch_start_offsets
+            .insert(key, (leader_epoch, next_offset));
+    }
+
+    /// Returns the metadata of the given partitions, the in-sync replicas of the partitions led by this node
+    /// are calculated based on the progress of the followers.
+    pub async fn get_metadata(&self, partitions: &[PartitionKey]) -> ClusterMetadata {
+        let now = TimeStamp::now().to_micros();
+        let state = self.state.read().await;
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| ClusterNode {
+                id: node.id,
+                address: node.address.clone(),
+                status: match self.is_node_online_at(&state, node.id, now) {
+                    true => ClusterNodeStatus::Online,
+                    false => ClusterNodeStatus::Offline,
+                },
+            })
+            .collect();
+        let partitions = partitions
+            .iter()
+            .map(|key| {
+                let mut replicas = match state.partitions.get(key) {
+                    Some(replicas) => replicas.clone(),
+                    None => self.create_replicas(key, self.get_default_leader_id(key), 0),
+                };
+                if replicas.leader_id == self.node_id {
+                    replicas.in_sync_replicas = self.get_in_sync_replicas(&state, key, now);
+                }
+                replicas
+            })
+            .collect();
+
+        ClusterMetadata {
+            node_id: self.node_id,
+            controller_id: self.get_controller_id_at(&state, now).unwrap_or(0),
+            nodes,
+            partitions,
+        }
+    }
+
+    /// Adopts the partition leaders assigned by the controller.
+    pub async fn apply_controller_metadata(&self, metadata: &ClusterMetadata) {
+        let mut state = self.state.write().await;
+        let mut partitions = HashMap::with_capacity(metadata.partitions.len());
+        for replicas in &metadata.partitions {
+            let key = (replicas.stream_id, replicas.topic_id, replicas.partition_id);
+            let previous_leader_id = state
+                .partitions
+                .get(&key)
+                .map(|replicas| replicas.leader_id);
+            if previous_leader_id != Some(replicas.leader_id) {
+                info!(
+                    "Node with ID: {} is the leader of partition with ID: {} for topic with ID: {} and stream with ID: {}, epoch: {}.",
+                    replicas.leader_id, replicas.partition_id, replicas.topic_id, replicas.stream_id, replicas.leader_epoch
+                );
+                state.followers.remove(&key);
+            }
+            partitions.insert(key, replicas.clone());
+        }
+        state.partitions = partitions;
+    }
+
+    /// Updates the in-sync replicas of the partitions reported by their leader.
+    pub async fn apply_leader_metadata(&self, metadata: &ClusterMetadata) {
+        let mut state = self.state.write().await;
+        for reported_replicas in &metadata.partitions {
+            if reported_replicas.leader_id != metadata.node_id {
+                continue;
+            }
+
+            let key = (
+                reported_replicas.stream_id,
+                reported_replicas.topic_id,
+                reported_replicas.partition_id,
+            );
+            if let Some(replicas) = state.partitions.get_mut(&key) {
+                if replicas.leader_id == reported_replicas.leader_id
+                    && replicas.leader_epoch == reported_replicas.leader_epoch
+                {
+                    replicas
+                        .in_sync_replicas
+                        .clone_from(&reported_replicas.in_sync_replicas);
+                }
+            }
+        }
+    }
+
+    /// Assigns the leaders of the new partitions and elects the new leaders of the partitions,
+    /// which leaders are offline. Should be invoked only by the controller.
+    pub async fn assign_leaders(&self, partitions: &[PartitionKey]) {
+        let now = TimeStamp::now().to_micros();
+        let mut state = self.state.write().await;
+        let existing_partitions = partitions.iter().copied().collect::<HashSet<_>>();
+        state
+            .partitions
+            .retain(|key, _| existing_partitions.contains(key));
+        state
+            .followers
+            .retain(|key, _| existing_partitions.contains(key));
+        state
+            .epoch_start_offsets
+            .retain(|key, _| existing_partitions.contains(key));
+
+        for key in partitions {
+            let (leader_id, leader_epoch) = match state.partitions.get(key) {
+                None => match self.elect_leader(&state, key, None, now) {
+                    Some(leader_id) => (leader_id, 1),
+                    None => continue,
+                },
+                Some(replicas) if !self.is_node_online_at(&state, replicas.leader_id, now) => {
+                    match self.elect_leader(&state, key, Some(replicas), now) {
+                        Some(leader_id) => (leader_id, replicas.leader_epoch + 1),
+                        None => continue,
+                    }
+                }
+                Some(_) => continue,
+            };
+
+            info!(
+                "Assigned node with ID: {} as the leader of partition with ID: {} for topic with ID: {} and stream with ID: {}, epoch: {}.",
+                leader_id, key.2, key.1, key.0, leader_epoch
+            );
+            state.followers.remove(key);
+            state
+                .partitions
+                .insert(*key, self.create_replicas(key, leader_id, leader_epoch));
+        }
+    }
+
+    /// Records the offset, from which the follower fetches the messages of the partition led by this node.
+    pub async fn record_replica_progress(
+        &self,
+        key: PartitionKey,
+        replica_id: u32,
+        next_offset: u64,
+        leader_next_offset: u64,
+    ) {
+        if !self.is_replica(&key, replica_id) {
+            return;
+        }
+
+        let now = TimeStamp::now().to_micros();
+        {
+            let mut state = self.state.write().await;
+            let progress = state
+                .followers
+                .entry(key)
+                .or_default()
+                .entry(replica_id)
+                .or_insert(FollowerProgress {
+                    next_offset,
+                    caught_up_at: 0,
+                });
+            progress.next_offset = next_offset;
+            if next_offset >= leader_next_offset {
+                progress.caught_up_at = now;
+            }
+        }
+        self.replicas_notifier.notify_waiters();
+    }
+
+    /// Waits until all the in-sync followers of the partition have replicated the message with the given offset.
+    /// Fails if the number of the in-sync replicas drops below the configured minimum, even though
+    /// the messages have already been appended by the leader.
+    pub async fn wait_for_replicas(&self, key: PartitionKey, offset: u64) -> Result<(), Error> {
+        let deadline = Instant::now() + self.acks_timeout;
+        loop {
+            // Registered before checking the progress, so the progress recorded in the meantime will not be missed.
+            let notified = self.replicas_notifier.notified();
+            {
+                let now = TimeStamp::now().to_micros();
+                let state = self.state.read().await;
+                let in_sync_replicas = self.get_in_sync_replicas(&state, &key, now);
+                if in_sync_replicas.len() < self.min_insync_replicas {
+                    return Err(Error::NotEnoughInSyncReplicas(
+                        key.2,
+                        key.1,
+                        key.0,
+                        in_sync_replicas.len() as u32,
+                        self.min_insync_replicas as u32,
+                    ));
+                }
+
+                let followers = state.followers.get(&key);
+                let replicated = in_sync_replicas
+                    .iter()
+                    .filter(|replica_id| **replica_id != self.node_id)
+                    .all(|replica_id| {
+                        followers
+                            .and_then(|followers| followers.get(replica_id))
+                            .map(|progress| progress.next_offset > offset)
+                            .unwrap_or(false)
+                    });
+                if replicated {
+                    return Ok(());
+                }
+            }
+
+            if timeout_at(deadline, notified).await.is_err() {
+                return Err(Error::ReplicationTimeout(key.2, key.1, key.0));
+            }
+        }
+    }
+
+    fn get_controller_id_at(&self, state: &ClusterState, now: u64) -> Option<u32> {
+        if !self.has_quorum_at(state, now) {
+            return None;
+        }
+
+        self.nodes
+            .iter()
+            .map(|node| node.id)
+            .find(|node_id| self.is_node_online_at(state, *node_id, now))
+    }
+
+    fn has_quorum_at(&self, state: &ClusterState, now: u64) -> bool {
+        let online_nodes = self
+            .nodes
+            .iter()
+            .filter(|node| self.is_node_online_at(state, node.id, now))
+            .count();
+        online_nodes * 2 > self.nodes.len()
+    }
+
+    /// The peers, which have not responded yet since the startup, are considered online until the node timeout.
+    fn is_node_online_at(&self, state: &ClusterState, node_id: u32, now: u64) -> bool {
+        if node_id == self.node_id {
+            return true;
+        }
+
+        let last_seen = state
+            .last_seen
+            .get(&node_id)
+            .copied()
+            .unwrap_or(self.started_at);
+        now.saturating_sub(last_seen) <= self.node_timeout
+    }
+
+    fn get_in_sync_replicas(&self, state: &ClusterState, key: &PartitionKey, now: u64) -> Vec<u32> {
+        let mut in_sync_replicas = vec![self.node_id];
+        if let Some(followers) = state.followers.get(key) {
+            for (replica_id, progress) in followers {
+                if self.is_replica(key, *replica_id)
+                    && self.is_node_online_at(state, *replica_id, now)
+                    && now.saturating_sub(progress.caught_up_at) <= self.replica_lag_timeout
+                {
+                    in_sync_replicas.push(*replica_id);
+                }
+            }
+        }
+        in_sync_replicas.sort();
+        in_sync_replicas
+    }
+
+    /// Picks the first online in-sync replica in the order of the partition replicas. If none of them is online,
+    /// the partition has no leader, unless the unclean leader election is enabled, then any online replica is picked,
+    /// which might result in the loss of the messages.
+    fn elect_leader(
+        &self,
+        state: &ClusterState,
+        key: &PartitionKey,
+        replicas: Option<&PartitionReplicas>,
+        now: u64,
+    ) -> Option<u32> {
+        let replica_ids = self.get_replicas(key);
+        let mut online_nodes = replica_ids
+            .iter()
+            .copied()
+            .filter(|node_id| self.is_node_online_at(state, *node_id, now));
+        let Some(replicas) = replicas else {
+            return online_nodes.next();
+        };
+
+        if let Some(leader_id) = replica_ids.iter().copied().find(|node_id| {
+            replicas.in_sync_replicas.contains(node_id)
+                && self.is_node_online_at(state, *node_id, now)
+        }) {
+            return Some(leader_id);
+        }
+
+        if !self.unclean_leader_election {
+            warn!(
+                "None of the in-sync replicas of partition with ID: {} for topic with ID: {} and stream with ID: {} is online, the partition will have no leader until one of them is back.",
+                key.2, key.1, key.0
+            );
+            return None;
+        }
+
+        let leader_id = online_nodes.next();
+        if let Some(leader_id) = leader_id {
+            warn!(
+                "None of the in-sync replicas of partition with ID: {} for topic with ID: {} and stream with ID: {} is online, node with ID: {} will be the leader, some messages might be lost.",
+                key.2, key.1, key.0, leader_id
+            );
+        }
+        leader_id
+    }
+
+    /// The leaders of the partitions are spread across the nodes, starting from the different node for each partition.
+    fn get_rotation(&self, key: &PartitionKey) -> impl Iterator<Item = u32> + '_ {
+        let start = (key.0 + key.1 + key.2) as usize % self.nodes.len();
+        self.nodes[start..]
+            .iter()
+            .chain(self.nodes[..start].iter())
+            .map(|node| node.id)
+    }
+
+    /// The first nodes of the partition rotation are its replicas, the first one is the preferred leader.
+    fn get_replicas(&self, key: &PartitionKey) -> Vec<u32> {
+        self.get_rotation(key)
+            .take(self.replication_factor)
+            .collect()
+    }
+
+    fn get_default_leader_id(&self, key: &PartitionKey) -> u32 {
+        self.get_rotation(key).next().unwrap_or(self.node_id)
+    }
+
+    fn create_replicas(
+        &self,
+        key: &PartitionKey,
+        leader_id: u32,
+        leader_epoch: u32,
+    ) -> PartitionReplicas {
+        PartitionReplicas {
+            stream_id: key.0,
+            topic_id: key.1,
+            partition_id: key.2,
+            leader_id,
+            leader_epoch,
+            replicas: self.get_replicas(key),
+            in_sync_replicas: vec![leader_id],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: PartitionKey = (1, 1, 1);
+
+    #[tokio::test]
+    async fn node_with_the_lowest_id_should_be_the_controller() {
+        let cluster = create_cluster(2);
+        assert_eq!(cluster.get_controller_id().await, Some(1));
+    }
+
+    #[tokio::test]
+    async fn node_should_become_the_controller_given_offline_nodes_with_lower_ids() {
+        let cluster = create_cluster(2);
+        set_last_seen(&cluster, 1, 0).await;
+        assert_eq!(cluster.get_controller_id().await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn there_should_be_no_controller_given_offline_majority_of_nodes() {
+        let cluster = create_cluster(3);
+        set_last_seen(&cluster, 1, 0).await;
+        set_last_seen(&cluster, 2, 0).await;
+        assert!(!cluster.has_quorum().await);
+        assert_eq!(cluster.get_controller_id().await, None);
+    }
+
+    #[tokio::test]
+    async fn leaders_should_be_spread_across_the_nodes() {
+        let cluster = create_cluster(1);
+        let partitions = vec![(1, 1, 1), (1, 1, 2), (1, 1, 3)];
+
+        cluster.assign_leaders(&partitions).await;
+
+        let metadata = cluster.get_metadata(&partitions).await;
+        let leaders = metadata
+            .partitions
+            .iter()
+            .map(|replicas| replicas.leader_id)
+            .collect::<Vec<_>>();
+        assert_eq!(leaders, vec![1, 2, 3]);
+        assert!(metadata
+            .partitions
+            .iter()
+            .all(|replicas| replicas.leader_epoch == 1));
+    }
+
+    #[tokio::test]
+    async fn new_leader_should_be_elected_from_in_sync_replicas_given_offline_leader() {
+        let cluster = create_cluster(2);
+        cluster.assign_leaders(&[KEY]).await;
+        assert_eq!(cluster.get_leader_id(1, 1, 1).await, 1);
+        cluster
+            .apply_leader_metadata(&ClusterMetadata {
+                node_id: 1,
+                controller_id: 1,
+                nodes: vec![],
+                partitions: vec![PartitionReplicas {
+                    stream_id: 1,
+                    topic_id: 1,
+                    partition_id: 1,
+                    leader_id: 1,
+                    leader_epoch: 1,
+                    replicas: vec![1, 2, 3],
+                    in_sync_replicas: vec![1, 3],
+                }],
+            })
+            .await;
+
+        set_last_seen(&cluster, 1, 0).await;
+        cluster.assign_leaders(&[KEY]).await;
+
+        let metadata = cluster.get_metadata(&[KEY]).await;
+        let replicas = metadata.get_partition(1, 1, 1).unwrap();
+        assert_eq!(replicas.leader_id, 3);
+        assert_eq!(replicas.leader_epoch, 2);
+        assert_eq!(replicas.in_sync_replicas, vec![3]);
+    }
+
+    #[tokio::test]
+    async fn assignments_of_deleted_partitions_should_be_removed() {
+        let cluster = create_cluster(1);
+        cluster.assign_leaders(&[KEY, (1, 1, 2)]).await;
+
+        cluster.assign_leaders(&[KEY]).await;
+
+        let state = cluster.state.read().await;
+        assert_eq!(state.partitions.len(), 1);
+        assert!(state.partitions.contains_key(&KEY));
+    }
+
+    #[tokio::test]
+    async fn caught_up_follower_should_be_in_sync_replica() {
+        let cluster = create_cluster(1);
+        cluster.assign_leaders(&[KEY]).await;
+
+        cluster.record_replica_progress(KEY, 2, 5, 10).await;
+        cluster.record_replica_progress(KEY, 3, 10, 10).await;
+
+        let metadata = cluster.get_metadata(&[KEY]).await;
+        assert_eq!(metadata.partitions[0].in_sync_replicas, vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn waiting_for_replicas_should_succeed_given_replicated_offset() {
+        let cluster = create_cluster(1);
+        cluster.record_replica_progress(KEY, 2, 10, 10).await;
+
+        assert!(cluster.wait_for_replicas(KEY, 9).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn waiting_for_replicas_should_time_out_given_lagging_in_sync_replica() {
+        let cluster = create_cluster(1);
+        cluster.record_replica_progress(KEY, 2, 10, 10).await;
+
+        let result = cluster.wait_for_replicas(KEY, 10).await;
+
+        assert!(matches!(result, Err(Error::ReplicationTimeout(1, 1, 1))));
+    }
+
+    #[tokio::test]
+    async fn waiting_for_replicas_should_fail_given_too_few_in_sync_replicas() {
+        let mut cluster = create_cluster(1);
+        cluster.min_insync_replicas = 2;
+        cluster.record_replica_progress(KEY, 2, 5, 10).await;
+
+        let result = cluster.wait_for_replicas(KEY, 4).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::NotEnoughInSyncReplicas(1, 1, 1, 1, 2))
+        ));
+    }
+
+    #[tokio::test]
+    async fn partitions_should_be_assigned_to_replication_factor_nodes() {
+        let cluster = create_cluster_with_replication_factor(1, 2);
+        let partitions = vec![(1, 1, 1), (1, 1, 2), (1, 1, 3)];
+
+        cluster.assign_leaders(&partitions).await;
+
+        let metadata = cluster.get_metadata(&partitions).await;
+        let replicas = metadata
+            .partitions
+            .iter()
+            .map(|replicas| replicas.replicas.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(replicas, vec![vec![1, 2], vec![2, 3], vec![3, 1]]);
+        assert!(cluster.is_replica(&(1, 1, 1), 2));
+        assert!(!cluster.is_replica(&(1, 1, 1), 3));
+    }
+
+    #[tokio::test]
+    async fn new_leader_should_not_be_elected_given_offline_in_sync_replicas() {
+        let cluster = create_cluster(2);
+        cluster.assign_leaders(&[KEY]).await;
+
+        set_last_seen(&cluster, 1, 0).await;
+        cluster.assign_leaders(&[KEY]).await;
+
+        let replicas = cluster.get_metadata(&[KEY]).await.partitions[0].clone();
+        assert_eq!(replicas.leader_id, 1);
+        assert_eq!(replicas.leader_epoch, 1);
+    }
+
+    #[tokio::test]
+    async fn new_leader_should_be_elected_only_from_replicas() {
+        let mut cluster = create_cluster_with_replication_factor(2, 2);
+        cluster.unclean_leader_election = true;
+        cluster.assign_leaders(&[KEY]).await;
+        cluster.record_replica_progress(KEY, 3, 10, 10).await;
+
+        set_last_seen(&cluster, 1, 0).await;
+        set_last_seen(&cluster, 3, TimeStamp::now().to_micros()).await;
+        cluster.assign_leaders(&[KEY]).await;
+
+        let replicas = cluster.get_metadata(&[KEY]).await.partitions[0].clone();
+        assert_eq!(replicas.replicas, vec![1, 2]);
+        assert_eq!(replicas.leader_id, 2);
+        assert_eq!(replicas.in_sync_replicas, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn epoch_start_offset_should_be_recorded_once_per_epoch() {
+        let cluster = create_cluster(1);
+
+        cluster.record_epoch_start_offset(KEY, 1, 10).await;
+        cluster.record_epoch_start_offset(KEY, 1, 20).await;
+        cluster.record_epoch_start_offset(KEY, 2, 30).await;
+
+        assert_eq!(cluster.get_epoch_start_offset(&KEY, 1).await, None);
+        assert_eq!(cluster.get_epoch_start_offset(&KEY, 2).await, Some(30));
+    }
+
+    fn create_cluster(node_id: u32) -> ClusterManager {
+        create_cluster_with_replication_factor(node_id, 3)
+    }
+
+    fn create_cluster_with_replication_factor(
+        node_id: u32,
+        replication_factor: u32,
+    ) -> ClusterManager {
+        ClusterManager::new(&ClusterConfig {
+            enabled: true,
+            node_id,
+            nodes: "1@127.0.0.1:8090,2@127.0.0.1:8091,3@127.0.0.1:8092".to_string(),
+            replication_factor,
+            acks_timeout: 10,
+            ..ClusterConfig::default()
+        })
+        .unwrap()
+    }
+
+    async fn set_last_seen(cluster: &ClusterManager, node_id: u32, last_seen: u64) {
+        let mut state = cluster.state.write().await;
+        state.last_seen.insert(node_id, last_seen);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod cluster_manager;
+pub mod peer_clients;
@@ -0,0 +1,88 @@
+use crate::configs::cluster::{ClusterConfig, ClusterNodeConfig};
+use iggy::binary;
+use iggy::client::Client;
+use iggy::error::Error;
+use iggy::tcp::client::TcpClient;
+use iggy::tcp::config::TcpClientConfig;
+use iggy::users::login_user::LoginUser;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::timeout;
+use tracing::debug;
+
+/// The authenticated TCP clients used by the node to send the requests to the other cluster nodes.
+#[derive(Debug)]
+pub struct PeerClients {
+    username: String,
+    password: String,
+    request_timeout: Duration,
+    clients: HashMap<u32, Arc<TcpClient>>,
+}
+
+impl PeerClients {
+    pub fn new(config: &ClusterConfig) -> Self {
+        PeerClients {
+            username: config.username.clone(),
+            password: config.password.clone(),
+            // The node which does not respond within this time is considered offline anyway.
+            request_timeout: Duration::from_millis(config.node_timeout),
+            clients: HashMap::new(),
+        }
+    }
+
+    /// Sends the request to the node, the client is dropped on any error (including the timeout),
+    /// so that the new connection will be established on the next request.
+    pub async fn send<T, F, Fut>(
+        &mut self,
+        node: &ClusterNodeConfig,
+        request: F,
+    ) -> Result<T, Error>
+    where
+        F: FnOnce(Arc<TcpClient>) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let result = match timeout(self.request_timeout, self.connect(node)).await {
+            Ok(Ok(client)) => match timeout(self.request_timeout, request(client)).await {
+                Ok(result) => result,
+                Err(_) => Err(Error::NotConnected),
+            },
+            Ok(Err(error)) => Err(error),
+            Err(_) => Err(Error::NotConnected),
+        };
+
+        if let Err(error) = &result {
+            debug!(
+                "Request to the cluster node with ID: {} has failed: {}",
+                node.id, error
+            );
+            self.clients.remove(&node.id);
+        }
+        result
+    }
+
+    async fn connect(&mut self, node: &ClusterNodeConfig) -> Result<Arc<TcpClient>, Error> {
+        if let Some(client) = self.clients.get(&node.id) {
+            return Ok(client.clone());
+        }
+
+        let client = TcpClient::create(Arc::new(TcpClientConfig {
+            server_address: node.address.clone(),
+            reconnection_retries: 0,
+            ..TcpClientConfig::default()
+        }))?;
+        client.connect().await?;
+        binary::users::login_user(
+            &client,
+            &LoginUser {
+                username: self.username.clone(),
+                password: self.password.clone(),
+            },
+        )
+        .await?;
+        let client = Arc::new(client);
+        self.clients.insert(node.id, client.clone());
+        Ok(client)
+    }
+}
@@ -1,9 +1,21 @@
+use iggy::consumer::ConsumerKind;
 use prometheus_client::encoding::text::encode;
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
 use prometheus_client::registry::Registry;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 use tracing::error;
 
+type Labels = Vec<(String, String)>;
+
+// The latencies are observed in seconds, the buckets start at 0.1 ms and end at ~6.5 s.
+const LATENCY_BUCKETS_START: f64 = 0.0001;
+const LATENCY_BUCKETS_FACTOR: f64 = 2.0;
+const LATENCY_BUCKETS_COUNT: u16 = 17;
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
@@ -15,6 +27,18 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    consumer_lag: Family<Labels, Gauge>,
+    messages_in: Family<Labels, Counter>,
+    bytes_in: Family<Labels, Counter>,
+    messages_out: Family<Labels, Counter>,
+    bytes_out: Family<Labels, Counter>,
+    append_latency: Histogram,
+    poll_latency: Histogram,
+    segment_flush_duration: Histogram,
+    cache_hits: Family<Labels, Counter>,
+    cache_misses: Family<Labels, Counter>,
+    connections: Family<Labels, Gauge>,
+    errors: Family<Labels, Counter>,
 }
 
 impl Metrics {
@@ -29,19 +53,108 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            consumer_lag: Family::default(),
+            messages_in: Family::default(),
+            bytes_in: Family::default(),
+            messages_out: Family::default(),
+            bytes_out: Family::default(),
+            append_latency: Self::latency_histogram(),
+            poll_latency: Self::latency_histogram(),
+            segment_flush_duration: Self::latency_histogram(),
+            cache_hits: Family::default(),
+            cache_misses: Family::default(),
+            connections: Family::default(),
+            errors: Family::default(),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
         metrics.register_gauge("streams", metrics.streams.clone());
+        metrics.register_gauge("topics", metrics.topics.clone());
         metrics.register_gauge("partitions", metrics.partitions.clone());
         metrics.register_gauge("segments", metrics.segments.clone());
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.registry.register(
+            "consumer_lag",
+            "count of messages not consumed yet by the consumer or consumer group per partition",
+            metrics.consumer_lag.clone(),
+        );
+        metrics.registry.register(
+            "messages_in",
+            "total count of messages appended per partition",
+            metrics.messages_in.clone(),
+        );
+        metrics.registry.register(
+            "bytes_in",
+            "total size in bytes of messages appended per partition",
+            metrics.bytes_in.clone(),
+        );
+        metrics.registry.register(
+            "messages_out",
+            "total count of messages polled per partition",
+            metrics.messages_out.clone(),
+        );
+        metrics.registry.register(
+            "bytes_out",
+            "total size in bytes of messages polled per partition",
+            metrics.bytes_out.clone(),
+        );
+        metrics.registry.register(
+            "append_latency_seconds",
+            "latency of appending the messages",
+            metrics.append_latency.clone(),
+        );
+        metrics.registry.register(
+            "poll_latency_seconds",
+            "latency of polling the messages",
+            metrics.poll_latency.clone(),
+        );
+        metrics.registry.register(
+            "segment_flush_duration_seconds",
+            "duration of writing the segment data to the disk",
+            metrics.segment_flush_duration.clone(),
+        );
+        metrics.registry.register(
+            "cache_hits",
+            "total count of polls served from the partition cache",
+            metrics.cache_hits.clone(),
+        );
+        metrics.registry.register(
+            "cache_misses",
+            "total count of polls that could not be served from the partition cache",
+            metrics.cache_misses.clone(),
+        );
+        metrics.registry.register(
+            "connections",
+            "count of connected clients per transport",
+            metrics.connections.clone(),
+        );
+        metrics.registry.register(
+            "errors",
+            "total count of errors returned to the clients per error code",
+            metrics.errors.clone(),
+        );
 
         metrics
     }
 
+    fn latency_histogram() -> Histogram {
+        Histogram::new(exponential_buckets(
+            LATENCY_BUCKETS_START,
+            LATENCY_BUCKETS_FACTOR,
+            LATENCY_BUCKETS_COUNT,
+        ))
+    }
+
+    fn partition_labels(stream_id: u32, topic_id: u32, partition_id: u32) -> Labels {
+        vec![
+            ("stream_id".to_string(), stream_id.to_string()),
+            ("topic_id".to_string(), topic_id.to_string()),
+            ("partition_id".to_string(), partition_id.to_string()),
+        ]
+    }
+
     fn register_counter(&mut self, name: &str, counter: Counter) {
         self.registry
             .register(name, format!("total count of {name}"), counter)
@@ -119,4 +232,180 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    pub fn set_consumer_lag(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        consumer_kind: ConsumerKind,
+        consumer_id: u32,
+        lag: u64,
+    ) {
+        let mut labels = Self::partition_labels(stream_id, topic_id, partition_id);
+        labels.push(("consumer_kind".to_string(), consumer_kind.to_string()));
+        labels.push(("consumer_id".to_string(), consumer_id.to_string()));
+        self.consumer_lag.get_or_create(&labels).set(lag as i64);
+    }
+
+    pub fn clear_consumer_lag(&self) {
+        self.consumer_lag.clear();
+    }
+
+    /// Counts the messages actually appended to the partition, both in the total and in the per partition metrics.
+    pub fn increment_appended_messages(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        count: u64,
+        size_bytes: u64,
+    ) {
+        self.messages.inc_by(count as i64);
+        let labels = Self::partition_labels(stream_id, topic_id, partition_id);
+        self.messages_in.get_or_create(&labels).inc_by(count);
+        self.bytes_in.get_or_create(&labels).inc_by(size_bytes);
+    }
+
+    pub fn increment_messages_out(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        count: u64,
+        size_bytes: u64,
+    ) {
+        let labels = Self::partition_labels(stream_id, topic_id, partition_id);
+        self.messages_out.get_or_create(&labels).inc_by(count);
+        self.bytes_out.get_or_create(&labels).inc_by(size_bytes);
+    }
+
+    /// Removes the series of the deleted partition, so they are no longer exported.
+    pub fn remove_partition(&self, stream_id: u32, topic_id: u32, partition_id: u32) {
+        let labels = Self::partition_labels(stream_id, topic_id, partition_id);
+        for family in [
+            &self.messages_in,
+            &self.bytes_in,
+            &self.messages_out,
+            &self.bytes_out,
+            &self.cache_hits,
+            &self.cache_misses,
+        ] {
+            family.remove(&labels);
+        }
+    }
+
+    pub fn observe_append_latency(&self, latency: Duration) {
+        self.append_latency.observe(latency.as_secs_f64());
+    }
+
+    pub fn observe_poll_latency(&self, latency: Duration) {
+        self.poll_latency.observe(latency.as_secs_f64());
+    }
+
+    /// Returns the histogram shared with the persisters, which observe the duration of each write.
+    pub fn segment_flush_duration(&self) -> Histogram {
+        self.segment_flush_duration.clone()
+    }
+
+    /// The hits and misses are counted by the partition caches, so the values are overwritten on each scrape.
+    pub fn set_cache_usage(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        hits: u64,
+        misses: u64,
+    ) {
+        let labels = Self::partition_labels(stream_id, topic_id, partition_id);
+        self.cache_hits
+            .get_or_create(&labels)
+            .inner()
+            .store(hits, Ordering::Relaxed);
+        self.cache_misses
+            .get_or_create(&labels)
+            .inner()
+            .store(misses, Ordering::Relaxed);
+    }
+
+    pub fn clear_cache_usage(&self) {
+        self.cache_hits.clear();
+        self.cache_misses.clear();
+    }
+
+    pub fn increment_connections(&self, transport: &str) {
+        self.connections
+            .get_or_create(&vec![("transport".to_string(), transport.to_string())])
+            .inc();
+    }
+
+    pub fn decrement_connections(&self, transport: &str) {
+        self.connections
+            .get_or_create(&vec![("transport".to_string(), transport.to_string())])
+            .dec();
+    }
+
+    pub fn increment_errors(&self, code: u32, name: &str) {
+        self.errors
+            .get_or_create(&vec![
+                ("code".to_string(), code.to_string()),
+                ("name".to_string(), name.to_string()),
+            ])
+            .inc();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_data_path_metrics() {
+        let metrics = Metrics::init();
+        metrics.increment_topics(2);
+        metrics.increment_appended_messages(1, 2, 3, 10, 1000);
+        metrics.increment_messages_out(1, 2, 3, 5, 500);
+        metrics.observe_append_latency(Duration::from_millis(1));
+        metrics.segment_flush_duration().observe(0.01);
+        metrics.set_cache_usage(1, 2, 3, 7, 3);
+        metrics.increment_connections("tcp");
+        metrics.increment_errors(1009, "stream_id_not_found");
+
+        let output = metrics.get_formatted_output();
+
+        assert!(output.contains("topics 2"));
+        assert!(output.contains("messages 10"));
+        assert!(
+            output.contains(r#"messages_in_total{stream_id="1",topic_id="2",partition_id="3"} 10"#)
+        );
+        assert!(
+            output.contains(r#"bytes_out_total{stream_id="1",topic_id="2",partition_id="3"} 500"#)
+        );
+        assert!(output.contains("append_latency_seconds_count 1"));
+        assert!(output.contains("segment_flush_duration_seconds_count 1"));
+        assert!(
+            output.contains(r#"cache_hits_total{stream_id="1",topic_id="2",partition_id="3"} 7"#)
+        );
+        assert!(
+            output.contains(r#"cache_misses_total{stream_id="1",topic_id="2",partition_id="3"} 3"#)
+        );
+        assert!(output.contains(r#"connections{transport="tcp"} 1"#));
+        assert!(output.contains(r#"errors_total{code="1009",name="stream_id_not_found"} 1"#));
+    }
+
+    #[test]
+    fn series_of_deleted_partition_should_be_removed() {
+        let metrics = Metrics::init();
+        metrics.increment_appended_messages(1, 2, 3, 10, 1000);
+        metrics.increment_appended_messages(1, 2, 4, 10, 1000);
+        metrics.increment_messages_out(1, 2, 3, 5, 500);
+
+        metrics.remove_partition(1, 2, 3);
+
+        let output = metrics.get_formatted_output();
+        assert!(!output.contains(r#"partition_id="3""#));
+        assert!(
+            output.contains(r#"messages_in_total{stream_id="1",topic_id="2",partition_id="4"} 10"#)
+        );
+    }
 }
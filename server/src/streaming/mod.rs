@@ -1,16 +1,21 @@
 pub mod cache;
 pub mod clients;
+pub mod cluster;
 pub mod diagnostics;
 pub mod models;
 pub mod partitions;
 pub mod persistence;
 pub mod personal_access_tokens;
 pub mod polling_consumer;
+pub mod quotas;
+pub mod schemas;
 pub mod segments;
 pub mod session;
 pub mod storage;
 pub mod streams;
 pub mod systems;
+pub mod tiered_storage;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
@@ -7,5 +7,10 @@ use std::sync::Arc;
 pub struct PolledMessages {
     pub partition_id: u32,
     pub current_offset: u64,
+    /// The offset of the last message checked while polling, which is stored by the auto commit.
+    /// It's greater than the offset of the last returned message, when the next ones were filtered out.
+    #[serde(skip)]
+    pub last_scanned_offset: Option<u64>,
+    pub generation: u32,
     pub messages: Vec<Arc<Message>>,
 }
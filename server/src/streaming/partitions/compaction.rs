@@ -0,0 +1,89 @@
+use crate::streaming::partitions::partition::Partition;
+use iggy::error::Error;
+use std::collections::{HashMap, HashSet};
+use tracing::trace;
+
+#[derive(Debug, Default)]
+pub struct CompactedSegments {
+    pub segments_count: u32,
+    pub messages_count: u64,
+}
+
+impl Partition {
+    /// Rewrites the closed segments, keeping only the latest message for each messages key.
+    /// The tombstones (keyed messages with an empty payload) are kept until they are older than the tombstone retention (in microseconds).
+    /// The messages without the key are never removed.
+    pub async fn compact(
+        &mut self,
+        now: u64,
+        tombstone_retention: u64,
+    ) -> Result<CompactedSegments, Error> {
+        let mut compacted_segments = CompactedSegments::default();
+        if !self.segments.iter().any(|segment| segment.is_closed) {
+            return Ok(compacted_segments);
+        }
+
+        let mut removed_offsets = HashSet::new();
+        let mut latest_offsets = HashMap::new();
+        for segment in &self.segments {
+            for message in segment.get_all_messages().await? {
+                if let Some(key) = message.get_messages_key() {
+                    latest_offsets.insert(key.to_vec(), message.offset);
+                }
+            }
+        }
+
+        for segment in self.segments.iter_mut().filter(|segment| segment.is_closed) {
+            let messages = segment.get_all_messages().await?;
+            let retained_messages = messages
+                .iter()
+                .filter(|message| match message.get_messages_key() {
+                    Some(key) => {
+                        let is_latest = latest_offsets.get(key) == Some(&message.offset);
+                        let is_expired_tombstone = message.payload.is_empty()
+                            && message.timestamp + tombstone_retention <= now;
+                        is_latest && !is_expired_tombstone
+                    }
+                    None => true,
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if retained_messages.len() == messages.len() {
+                continue;
+            }
+
+            trace!(
+                "Compacting segment with start offset: {} for partition with ID: {}, retained messages: {} of {}.",
+                segment.start_offset,
+                self.partition_id,
+                retained_messages.len(),
+                messages.len()
+            );
+            segment.save_compacted_messages(&retained_messages).await?;
+            let retained_offsets = retained_messages
+                .iter()
+                .map(|message| message.offset)
+                .collect::<HashSet<_>>();
+            removed_offsets.extend(
+                messages
+                    .iter()
+                    .map(|message| message.offset)
+                    .filter(|offset| !retained_offsets.contains(offset)),
+            );
+            compacted_segments.segments_count += 1;
+            compacted_segments.messages_count += (messages.len() - retained_messages.len()) as u64;
+        }
+
+        // The removed messages must not be returned from the cache or the index of the delayed messages anymore.
+        if !removed_offsets.is_empty() {
+            if let Some(cache) = &mut self.cache {
+                cache.retain(|message| !removed_offsets.contains(&message.offset));
+            }
+            self.delayed_messages
+                .retain(|(_, offset)| !removed_offsets.contains(offset));
+        }
+
+        Ok(compacted_segments)
+    }
+}
@@ -2,6 +2,7 @@ use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::polling_consumer::PollingConsumer;
 use iggy::consumer::ConsumerKind;
 use iggy::error::Error;
+use iggy::models::consumer_lag::PartitionLag;
 use std::collections::HashMap;
 use tracing::trace;
 
@@ -34,6 +35,39 @@ impl Partition {
         Ok(0)
     }
 
+    pub async fn get_consumer_lag(&self, consumer: PollingConsumer) -> PartitionLag {
+        let stored_offset = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => self
+                .consumer_offsets
+                .read()
+                .await
+                .get(&consumer_id)
+                .map(|consumer_offset| consumer_offset.offset),
+            PollingConsumer::ConsumerGroup(consumer_group_id, _) => self
+                .consumer_group_offsets
+                .read()
+                .await
+                .get(&consumer_group_id)
+                .map(|consumer_offset| consumer_offset.offset),
+        };
+
+        PartitionLag {
+            partition_id: self.partition_id,
+            current_offset: self.current_offset,
+            stored_offset,
+            lag: self.calculate_consumer_lag(stored_offset),
+        }
+    }
+
+    pub fn calculate_consumer_lag(&self, stored_offset: Option<u64>) -> u64 {
+        match stored_offset {
+            Some(stored_offset) => self.current_offset.saturating_sub(stored_offset),
+            // Nothing has been consumed yet, so all the messages (if any) are pending.
+            None if self.should_increment_offset => self.current_offset + 1,
+            None => 0,
+        }
+    }
+
     pub async fn store_consumer_offset(
         &self,
         consumer: PollingConsumer,
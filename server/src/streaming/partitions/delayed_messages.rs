@@ -0,0 +1,266 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::polling_consumer::PollingConsumer;
+use iggy::error::Error;
+use iggy::messages::message_filter::MessageFilter;
+use iggy::models::messages::Message;
+use std::ops::Bound;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{info, trace};
+
+impl Partition {
+    /// Adds the messages, which are delayed beyond their append time, to the time-ordered index.
+    pub(crate) fn index_delayed_messages(&mut self, messages: &[Arc<Message>]) {
+        for message in messages {
+            if let Some(deliver_at) = message.get_deliver_at() {
+                if deliver_at > message.timestamp {
+                    self.delayed_messages.insert((deliver_at, message.offset));
+                }
+            }
+        }
+    }
+
+    /// Removes the delayed messages with the offsets in the given range (inclusive) from the index.
+    pub(crate) fn remove_delayed_messages(&mut self, start_offset: u64, end_offset: u64) {
+        self.delayed_messages
+            .retain(|(_, offset)| *offset < start_offset || *offset > end_offset);
+    }
+
+    /// Returns the timestamp at which the next delayed message becomes due after the given time.
+    pub fn get_next_delivery_at(&self, now: u64) -> Option<u64> {
+        self.delayed_messages
+            .range((Bound::Excluded((now, u64::MAX)), Bound::Unbounded))
+            .next()
+            .map(|(deliver_at, _)| *deliver_at)
+    }
+
+    /// Returns the delayed messages which weren't due yet when the consumer polled past their offsets,
+    /// and which have become due since its previous poll. Each one is returned to the consumer only once,
+    /// as the consumer keeps the cursor of the last delayed message returned from the index.
+    pub async fn get_due_delayed_messages(
+        &self,
+        consumer: PollingConsumer,
+        now: u64,
+        count: u32,
+        filter: Option<&MessageFilter>,
+    ) -> Result<Vec<Arc<Message>>, Error> {
+        let (consumer_offsets, mut cursors, consumer_id) = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => (
+                self.consumer_offsets.read().await,
+                self.consumer_delayed_messages_cursors.write().await,
+                consumer_id,
+            ),
+            PollingConsumer::ConsumerGroup(consumer_group_id, _) => (
+                self.consumer_group_offsets.read().await,
+                self.consumer_group_delayed_messages_cursors.write().await,
+                consumer_group_id,
+            ),
+        };
+
+        let stored_offset = consumer_offsets
+            .get(&consumer_id)
+            .map(|consumer_offset| consumer_offset.offset);
+        let cursor = match (cursors.get(&consumer_id), stored_offset) {
+            (Some(cursor), _) => *cursor,
+            // Without the cursor (e.g. after the restart), every delayed message due before the message
+            // at the stored offset was appended, has already been returned by one of the previous polls.
+            (None, Some(offset)) => {
+                let appended_at = self
+                    .get_messages_by_offset(offset, 1)
+                    .await?
+                    .first()
+                    .map(|message| message.timestamp)
+                    .unwrap_or_default();
+                (appended_at, u64::MAX)
+            }
+            (None, None) => (now, u64::MAX),
+        };
+
+        // The messages after the stored offset will be checked by the regular polling, once the consumer gets there.
+        let mut due_messages = Vec::new();
+        let mut last_cursor = (now, u64::MAX);
+        if let Some(stored_offset) = stored_offset {
+            for (deliver_at, offset) in self
+                .delayed_messages
+                .range((Bound::Excluded(cursor), Bound::Included((now, u64::MAX))))
+            {
+                if *offset > stored_offset {
+                    continue;
+                }
+
+                if due_messages.len() == count as usize {
+                    break;
+                }
+
+                last_cursor = (*deliver_at, *offset);
+                let message = self.get_messages_by_offset(*offset, 1).await?;
+                // The message might have been removed by the compaction in the meantime.
+                due_messages.extend(message.into_iter().filter(|message| {
+                    message.offset == *offset
+                        && filter.is_none_or(|filter| filter.matches(&message.headers))
+                }));
+            }
+        }
+
+        if due_messages.len() < count as usize {
+            last_cursor = (now, u64::MAX);
+        }
+        cursors.insert(consumer_id, last_cursor);
+        if !due_messages.is_empty() {
+            trace!(
+                "Found: {} due delayed message(s) for {}, partition: {}.",
+                due_messages.len(),
+                consumer,
+                self.partition_id
+            );
+        }
+
+        due_messages.sort_by_key(|message| message.offset);
+        Ok(due_messages)
+    }
+
+    /// Removes the delayed messages, which every consumer has already got past, as each of them is returned
+    /// from the index only once per consumer. The consumers with the stored offset, which haven't polled
+    /// the messages yet (e.g. after the restart), might still need any of them, so nothing is removed until they do.
+    pub(crate) async fn remove_delivered_delayed_messages(&mut self, now: u64) {
+        if self
+            .delayed_messages
+            .first()
+            .is_none_or(|(deliver_at, _)| *deliver_at > now)
+        {
+            return;
+        }
+
+        let mut delivered_cursor = (now, u64::MAX);
+        for (offsets, cursors) in [
+            (
+                &self.consumer_offsets,
+                &self.consumer_delayed_messages_cursors,
+            ),
+            (
+                &self.consumer_group_offsets,
+                &self.consumer_group_delayed_messages_cursors,
+            ),
+        ] {
+            let offsets = offsets.read().await;
+            let cursors = cursors.read().await;
+            if offsets
+                .keys()
+                .any(|consumer_id| !cursors.contains_key(consumer_id))
+            {
+                return;
+            }
+
+            if let Some(cursor) = cursors.values().min() {
+                delivered_cursor = delivered_cursor.min(*cursor);
+            }
+        }
+
+        self.delayed_messages = self.delayed_messages.split_off(&delivered_cursor);
+        self.delayed_messages.remove(&delivered_cursor);
+    }
+
+    /// Rebuilds the index of the delayed messages from the delayed indexes of the segments.
+    /// The segments without the delayed index (e.g. created before it was introduced) are scanned once
+    /// and their delayed index is saved, unless they are stored only remotely.
+    pub async fn load_delayed_messages(&mut self) -> Result<(), Error> {
+        let mut messages = Vec::new();
+        for segment in &self.segments {
+            if let Some(delayed_index) = self.storage.segment.load_delayed_index(segment).await? {
+                self.delayed_messages.extend(delayed_index);
+                continue;
+            }
+
+            if segment.is_uploaded && !Path::new(&segment.log_path).exists() {
+                continue;
+            }
+
+            let segment_messages = segment.get_all_messages().await?;
+            self.storage
+                .segment
+                .save_delayed_index(segment, &segment_messages)
+                .await?;
+            messages.extend(segment_messages);
+        }
+
+        self.index_delayed_messages(&messages);
+        if !self.delayed_messages.is_empty() {
+            info!(
+                "Loaded: {} delayed message(s) for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                self.delayed_messages.len(),
+                self.partition_id,
+                self.topic_id,
+                self.stream_id
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::partitions::partition::ConsumerOffset;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::consumer::ConsumerKind;
+
+    #[tokio::test]
+    async fn delayed_messages_should_be_removed_once_all_consumers_got_past_them() {
+        let mut partition = Partition::create(
+            1,
+            1,
+            1,
+            false,
+            Arc::new(SystemConfig::default()),
+            Arc::new(get_test_system_storage()),
+            None,
+            CompressionAlgorithm::None,
+        );
+        partition.delayed_messages = [(10, 1), (20, 2), (30, 3), (100, 4)].into();
+        partition.consumer_offsets.write().await.insert(
+            1,
+            ConsumerOffset::new(ConsumerKind::Consumer, 1, 4, 1, 1, 1),
+        );
+
+        // The consumer with the stored offset might still need any of them, until it polls.
+        partition.remove_delivered_delayed_messages(50).await;
+        assert_eq!(partition.delayed_messages.len(), 4);
+
+        partition
+            .consumer_delayed_messages_cursors
+            .write()
+            .await
+            .insert(1, (20, 2));
+        partition
+            .consumer_group_delayed_messages_cursors
+            .write()
+            .await
+            .insert(1, (50, u64::MAX));
+        partition.remove_delivered_delayed_messages(50).await;
+        assert_eq!(
+            partition
+                .delayed_messages
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![(30, 3), (100, 4)]
+        );
+
+        partition
+            .consumer_delayed_messages_cursors
+            .write()
+            .await
+            .insert(1, (50, u64::MAX));
+        partition.remove_delivered_delayed_messages(50).await;
+        assert_eq!(
+            partition
+                .delayed_messages
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![(100, 4)]
+        );
+    }
+}
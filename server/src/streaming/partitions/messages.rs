@@ -3,12 +3,55 @@ use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::utils::random_id;
 use iggy::error::Error;
+use iggy::messages::message_filter::MessageFilter;
+use iggy::messages::send_messages::Producer;
 use iggy::models::messages::Message;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::BTreeSet;
 use std::sync::Arc;
-use tracing::{trace, warn};
+use tracing::{info, trace, warn};
 
 const EMPTY_MESSAGES: Vec<Arc<Message>> = vec![];
 
+/// The messages returned by the poll, along with the offset of the last message scanned to find them.
+/// It's greater than the offset of the last returned message, when the following ones didn't match the filter.
+#[derive(Debug, Default)]
+pub struct ScannedMessages {
+    pub messages: Vec<Arc<Message>>,
+    pub last_scanned_offset: Option<u64>,
+}
+
+/// The count and the size of the messages actually appended to the partition, e.g. without the producer duplicates.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AppendedMessages {
+    pub partition_id: u32,
+    pub count: u32,
+    pub size_bytes: u64,
+}
+
+impl AppendedMessages {
+    fn new(partition_id: u32, messages: &[Arc<Message>]) -> Self {
+        AppendedMessages {
+            partition_id,
+            count: messages.len() as u32,
+            size_bytes: messages
+                .iter()
+                .map(|message| message.get_size_bytes() as u64)
+                .sum(),
+        }
+    }
+}
+
+impl ScannedMessages {
+    fn new(messages: Vec<Arc<Message>>) -> Self {
+        let last_scanned_offset = messages.last().map(|message| message.offset);
+        ScannedMessages {
+            messages,
+            last_scanned_offset,
+        }
+    }
+}
+
 impl Partition {
     pub fn get_messages_count(&self) -> u64 {
         let first_segment = self.segments.first();
@@ -16,8 +59,13 @@ impl Partition {
             return 0;
         }
 
+        // The segments might have been emptied by the compaction, so all of them need to be checked.
         let first_segment = first_segment.unwrap();
-        if first_segment.current_size_bytes == 0 {
+        if self
+            .segments
+            .iter()
+            .all(|segment| segment.current_size_bytes == 0)
+        {
             return 0;
         }
 
@@ -29,14 +77,15 @@ impl Partition {
         &self,
         timestamp: u64,
         count: u32,
-    ) -> Result<Vec<Arc<Message>>, Error> {
+        filter: Option<&MessageFilter>,
+    ) -> Result<ScannedMessages, Error> {
         trace!(
             "Getting messages by timestamp: {} for partition: {}...",
             timestamp,
             self.partition_id
         );
         if self.segments.is_empty() {
-            return Ok(EMPTY_MESSAGES);
+            return Ok(ScannedMessages::default());
         }
 
         let mut maybe_start_offset = None;
@@ -75,10 +124,10 @@ impl Partition {
 
         if maybe_start_offset.is_none() {
             trace!("Start offset for timestamp: {} was not found.", timestamp);
-            return Ok(EMPTY_MESSAGES);
+            return Ok(ScannedMessages::default());
         }
 
-        self.get_messages_by_offset(maybe_start_offset.unwrap(), count)
+        self.get_filtered_messages_by_offset(maybe_start_offset.unwrap(), count, filter)
             .await
     }
 
@@ -114,43 +163,92 @@ impl Partition {
         }
     }
 
-    pub async fn get_first_messages(&self, count: u32) -> Result<Vec<Arc<Message>>, Error> {
-        self.get_messages_by_offset(0, count).await
+    /// Returns up to `count` messages matching the filter, starting from the given offset.
+    /// The messages which don't match it are skipped, so the partition is scanned until enough messages
+    /// were found, its end was reached or the scan budget of the poll was used up.
+    pub async fn get_filtered_messages_by_offset(
+        &self,
+        start_offset: u64,
+        count: u32,
+        filter: Option<&MessageFilter>,
+    ) -> Result<ScannedMessages, Error> {
+        match filter {
+            Some(filter) => {
+                self.get_matching_messages(start_offset, count, |message| {
+                    filter.matches(&message.headers)
+                })
+                .await
+            }
+            None => Ok(ScannedMessages::new(
+                self.get_messages_by_offset(start_offset, count).await?,
+            )),
+        }
+    }
+
+    pub async fn get_first_messages(
+        &self,
+        count: u32,
+        filter: Option<&MessageFilter>,
+    ) -> Result<ScannedMessages, Error> {
+        self.get_filtered_messages_by_offset(0, count, filter).await
     }
 
-    pub async fn get_last_messages(&self, count: u32) -> Result<Vec<Arc<Message>>, Error> {
+    pub async fn get_last_messages(
+        &self,
+        count: u32,
+        filter: Option<&MessageFilter>,
+    ) -> Result<ScannedMessages, Error> {
+        if let Some(filter) = filter {
+            return self.get_last_matching_messages(count, filter).await;
+        }
+
         let mut count = count as u64;
         if count > self.current_offset + 1 {
             count = self.current_offset + 1
         }
 
         let start_offset = 1 + self.current_offset - count;
-        self.get_messages_by_offset(start_offset, count as u32)
-            .await
+        Ok(ScannedMessages::new(
+            self.get_messages_by_offset(start_offset, count as u32)
+                .await?,
+        ))
     }
 
     pub async fn get_next_messages(
         &self,
         consumer: PollingConsumer,
         count: u32,
-    ) -> Result<Vec<Arc<Message>>, Error> {
-        let (consumer_offsets, consumer_id) = match consumer {
-            PollingConsumer::Consumer(consumer_id, _) => {
-                (self.consumer_offsets.read().await, consumer_id)
-            }
-            PollingConsumer::ConsumerGroup(consumer_group_id, _) => {
-                (self.consumer_group_offsets.read().await, consumer_group_id)
-            }
+        filter: Option<&MessageFilter>,
+    ) -> Result<ScannedMessages, Error> {
+        let (consumer_offsets, poisoned_offsets, consumer_id) = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => (
+                self.consumer_offsets.read().await,
+                self.consumer_poisoned_offsets.read().await,
+                consumer_id,
+            ),
+            PollingConsumer::ConsumerGroup(consumer_group_id, _) => (
+                self.consumer_group_offsets.read().await,
+                self.consumer_group_poisoned_offsets.read().await,
+                consumer_group_id,
+            ),
         };
 
         let consumer_offset = consumer_offsets.get(&consumer_id);
+        let poisoned_offsets = poisoned_offsets.get(&consumer_id);
         if consumer_offset.is_none() {
             trace!(
                 "Consumer: {} hasn't stored offset for partition: {}, returning the first messages...",
                 consumer_id,
                 self.partition_id
             );
-            return self.get_first_messages(count).await;
+            return self
+                .get_next_messages_from_offset(
+                    0,
+                    count,
+                    poisoned_offsets.map(|poisoned_offsets| &poisoned_offsets.offsets),
+                    filter,
+                )
+                .await;
         }
 
         let consumer_offset = consumer_offset.unwrap();
@@ -161,7 +259,7 @@ impl Partition {
                 consumer_offset.offset,
                 self.partition_id
             );
-            return Ok(EMPTY_MESSAGES);
+            return Ok(ScannedMessages::default());
         }
 
         let offset = consumer_offset.offset + 1;
@@ -172,7 +270,154 @@ impl Partition {
             offset
         );
 
-        self.get_messages_by_offset(offset, count).await
+        self.get_next_messages_from_offset(
+            offset,
+            count,
+            poisoned_offsets.map(|poisoned_offsets| &poisoned_offsets.offsets),
+            filter,
+        )
+        .await
+    }
+
+    async fn get_next_messages_from_offset(
+        &self,
+        offset: u64,
+        count: u32,
+        poisoned_offsets: Option<&BTreeSet<u64>>,
+        filter: Option<&MessageFilter>,
+    ) -> Result<ScannedMessages, Error> {
+        match (poisoned_offsets, filter) {
+            (None, _) => {
+                self.get_filtered_messages_by_offset(offset, count, filter)
+                    .await
+            }
+            (Some(poisoned_offsets), None) => Ok(ScannedMessages::new(
+                self.get_messages_without_poisoned(offset, count, poisoned_offsets)
+                    .await?,
+            )),
+            (Some(poisoned_offsets), Some(filter)) => {
+                self.get_matching_messages(offset, count, |message| {
+                    !poisoned_offsets.contains(&message.offset) && filter.matches(&message.headers)
+                })
+                .await
+            }
+        }
+    }
+
+    /// Filters out the poisoned messages. If all the fetched messages were poisoned,
+    /// the next ones are fetched, so that the consumer doesn't get stuck on them.
+    async fn get_messages_without_poisoned(
+        &self,
+        mut offset: u64,
+        count: u32,
+        poisoned_offsets: &BTreeSet<u64>,
+    ) -> Result<Vec<Arc<Message>>, Error> {
+        loop {
+            let messages = self.get_messages_by_offset(offset, count).await?;
+            if messages.is_empty() {
+                return Ok(messages);
+            }
+
+            let last_offset = messages.last().unwrap().offset;
+            let messages = messages
+                .into_iter()
+                .filter(|message| !poisoned_offsets.contains(&message.offset))
+                .collect::<Vec<_>>();
+            if !messages.is_empty() || last_offset >= self.current_offset {
+                return Ok(messages);
+            }
+
+            offset = last_offset + 1;
+        }
+    }
+
+    /// Scans the partition forwards, until `count` messages matching the predicate were found. At most
+    /// `max_scanned_messages` are scanned by a single poll, so that the sparse matches don't hold the partition lock
+    /// for the whole partition, and the offset of the last scanned message lets the consumer continue from there.
+    async fn get_matching_messages(
+        &self,
+        mut offset: u64,
+        count: u32,
+        predicate: impl Fn(&Message) -> bool,
+    ) -> Result<ScannedMessages, Error> {
+        let mut remaining_budget = self.config.partition.max_scanned_messages.max(1) as u64;
+        let mut scanned_messages = ScannedMessages::default();
+        while remaining_budget > 0 {
+            let batch_count = remaining_budget.min(count as u64) as u32;
+            let messages = self.get_messages_by_offset(offset, batch_count).await?;
+            let Some(last_offset) = messages.last().map(|message| message.offset) else {
+                break;
+            };
+
+            for message in messages {
+                if !predicate(&message) {
+                    continue;
+                }
+
+                let message_offset = message.offset;
+                scanned_messages.messages.push(message);
+                if scanned_messages.messages.len() == count as usize {
+                    // The following messages weren't checked yet, so they must be scanned again by the next poll.
+                    scanned_messages.last_scanned_offset = Some(message_offset);
+                    return Ok(scanned_messages);
+                }
+            }
+
+            scanned_messages.last_scanned_offset = Some(last_offset);
+            if last_offset >= self.current_offset {
+                break;
+            }
+
+            remaining_budget = remaining_budget.saturating_sub(last_offset + 1 - offset);
+            offset = last_offset + 1;
+        }
+
+        Ok(scanned_messages)
+    }
+
+    /// Scans the partition backwards, until the last `count` messages matching the filter were found,
+    /// or `max_scanned_messages` were scanned.
+    async fn get_last_matching_messages(
+        &self,
+        count: u32,
+        filter: &MessageFilter,
+    ) -> Result<ScannedMessages, Error> {
+        if self.get_messages_count() == 0 {
+            return Ok(ScannedMessages::default());
+        }
+
+        let mut remaining_budget = self.config.partition.max_scanned_messages.max(1) as u64;
+        let mut matching_messages = Vec::new();
+        let mut end_offset = self.current_offset;
+        loop {
+            let batch_count = remaining_budget.min(count as u64);
+            let start_offset = end_offset.saturating_sub(batch_count - 1);
+            let messages = self
+                .get_messages_by_offset(start_offset, (end_offset - start_offset + 1) as u32)
+                .await?;
+            let remaining_count = count as usize - matching_messages.len();
+            let mut batch = messages
+                .into_iter()
+                .rev()
+                .filter(|message| filter.matches(&message.headers))
+                .take(remaining_count)
+                .collect::<Vec<_>>();
+            batch.reverse();
+            matching_messages.splice(..0, batch);
+            remaining_budget -= end_offset - start_offset + 1;
+            if matching_messages.len() == count as usize
+                || start_offset == 0
+                || remaining_budget == 0
+            {
+                // The scan starts from the end of the partition, so all the messages were scanned.
+                return Ok(ScannedMessages {
+                    messages: matching_messages,
+                    last_scanned_offset: Some(self.current_offset),
+                });
+            }
+
+            end_offset = start_offset - 1;
+        }
     }
 
     fn get_end_offset(&self, offset: u64, count: u32) -> u64 {
@@ -219,7 +464,12 @@ impl Partition {
         end_offset: u64,
     ) -> Option<Vec<Arc<Message>>> {
         let cache = self.cache.as_ref()?;
-        if cache.is_empty() || start_offset > end_offset || end_offset > self.current_offset {
+        if start_offset > end_offset || end_offset > self.current_offset {
+            return None;
+        }
+
+        if cache.is_empty() {
+            cache.record_miss();
             return None;
         }
 
@@ -231,9 +481,11 @@ impl Partition {
         );
 
         if start_offset >= first_buffered_offset {
+            cache.record_hit();
             return Some(self.load_messages_from_cache(start_offset, end_offset));
         }
 
+        cache.record_miss();
         None
     }
 
@@ -290,25 +542,14 @@ impl Partition {
             return EMPTY_MESSAGES;
         }
 
-        let first_offset = cache[0].offset;
-        let start_index = (start_offset - first_offset) as usize;
-        let end_index = usize::min(cache.len(), (end_offset - first_offset + 1) as usize);
-        let expected_messages_count = end_index - start_index;
-
-        let mut messages = Vec::with_capacity(expected_messages_count);
+        // The offsets are not contiguous, e.g. after the compaction, so the messages are looked up by their offsets.
+        let start_index = cache.partition_point(|message| message.offset < start_offset);
+        let end_index = cache.partition_point(|message| message.offset <= end_offset);
+        let mut messages = Vec::with_capacity(end_index.saturating_sub(start_index));
         for i in start_index..end_index {
             messages.push(cache[i].clone());
         }
 
-        if messages.len() != expected_messages_count {
-            warn!(
-                "Loaded {} messages from cache, expected {}.",
-                messages.len(),
-                expected_messages_count
-            );
-            return EMPTY_MESSAGES;
-        }
-
         trace!(
             "Loaded {} messages from cache, start offset: {}, end offset: {}...",
             messages.len(),
@@ -319,20 +560,20 @@ impl Partition {
         messages
     }
 
-    pub async fn append_messages(&mut self, mut messages: Vec<Message>) -> Result<(), Error> {
-        {
-            let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
-
-            if last_segment.is_closed {
-                let start_offset = last_segment.end_offset + 1;
-                trace!(
-                    "Current segment is closed, creating new segment with start offset: {} for partition with ID: {}...",
-                    start_offset, self.partition_id
-                );
-                self.add_persisted_segment(start_offset).await?;
+    pub async fn append_messages(
+        &mut self,
+        mut messages: Vec<Message>,
+        producer: Option<&Producer>,
+    ) -> Result<AppendedMessages, Error> {
+        let producer_messages_count = messages.len() as u32;
+        if let Some(producer) = producer {
+            messages = self.skip_producer_duplicates(producer, messages)?;
+            if messages.is_empty() {
+                return Ok(AppendedMessages::new(self.partition_id, &[]));
             }
         }
 
+        self.ensure_active_segment().await?;
         if let Some(message_ids) = &mut self.message_ids {
             messages = messages
                 .into_iter()
@@ -352,8 +593,6 @@ impl Partition {
                 .collect();
         }
 
-        let messages_count = messages.len() as u32;
-
         for message in &mut messages {
             if self.should_increment_offset {
                 self.current_offset += 1;
@@ -364,31 +603,175 @@ impl Partition {
         }
 
         let messages = messages.into_iter().map(Arc::new).collect::<Vec<_>>();
-        {
-            let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
-            last_segment.append_messages(&messages).await?;
+        self.append_to_active_segment(&messages).await?;
+        if let Some(producer) = producer {
+            self.update_producer_state(producer, producer_messages_count)
+                .await?;
+        }
+
+        self.persist_unsaved_messages(messages.len() as u32).await?;
+        Ok(AppendedMessages::new(self.partition_id, &messages))
+    }
+
+    /// Returns the offset, from which the next message will be appended.
+    pub fn get_next_offset(&self) -> u64 {
+        if self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            0
+        }
+    }
+
+    /// Appends the messages fetched from the partition leader in the given epoch, keeping their offsets, IDs and timestamps.
+    /// The messages from the older epochs are rejected. The first time the messages from the newer epoch are received,
+    /// the messages from the start offset of the epoch are truncated, as they are not present on the new leader,
+    /// and the fetched messages are skipped, as they will be fetched again starting at the truncated offset.
+    /// The messages, which offsets are already present in the partition, are skipped.
+    pub async fn append_replicated_messages(
+        &mut self,
+        leader_epoch: u32,
+        epoch_start_offset: u64,
+        messages: Vec<Arc<Message>>,
+    ) -> Result<AppendedMessages, Error> {
+        if leader_epoch < self.leader_epoch {
+            return Err(Error::InvalidLeaderEpoch(
+                leader_epoch,
+                self.partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        if leader_epoch > self.leader_epoch {
+            let truncated = epoch_start_offset < self.get_next_offset();
+            if truncated {
+                self.truncate_messages(epoch_start_offset).await?;
+            }
+            self.leader_epoch = leader_epoch;
+            if truncated {
+                return Ok(AppendedMessages::new(self.partition_id, &[]));
+            }
+        }
+
+        let next_offset = self.get_next_offset();
+        let messages = messages
+            .into_iter()
+            .filter(|message| message.offset >= next_offset)
+            .collect::<Vec<_>>();
+        if messages.is_empty() {
+            return Ok(AppendedMessages::new(self.partition_id, &[]));
+        }
+
+        self.ensure_active_segment().await?;
+        if let Some(message_ids) = &mut self.message_ids {
+            for message in &messages {
+                message_ids.insert(message.id);
+            }
         }
 
+        // The offsets might not be contiguous, e.g. when the messages were removed by the compaction on the leader.
+        self.current_offset = messages.last().unwrap().offset;
+        self.should_increment_offset = true;
+        self.append_to_active_segment(&messages).await?;
+        self.persist_unsaved_messages(messages.len() as u32).await?;
+        Ok(AppendedMessages::new(self.partition_id, &messages))
+    }
+
+    /// Removes the messages with the offsets greater than or equal to the given one.
+    /// Returns the number of the removed messages.
+    pub async fn truncate_messages(&mut self, offset: u64) -> Result<u64, Error> {
+        if offset >= self.get_next_offset() {
+            return Ok(0);
+        }
+
+        info!(
+            "Truncating partition with ID: {} for topic with ID: {} and stream with ID: {} at offset: {}...",
+            self.partition_id, self.topic_id, self.stream_id, offset
+        );
+        let mut removed_messages = Vec::new();
+        while let Some(segment) = self.segments.last() {
+            if segment.start_offset < offset {
+                break;
+            }
+
+            let start_offset = segment.start_offset;
+            removed_messages.extend(segment.get_all_messages().await?);
+            self.delete_segment(start_offset).await?;
+        }
+
+        match self.segments.last_mut() {
+            Some(segment) => removed_messages.extend(segment.truncate_messages(offset).await?),
+            None => self.add_persisted_segment(offset).await?,
+        }
+
+        if let Some(message_ids) = &mut self.message_ids {
+            for message in &removed_messages {
+                message_ids.remove(&message.id);
+            }
+        }
+        if let Some(cache) = &mut self.cache {
+            cache.purge();
+        }
+        self.remove_delayed_messages(offset, u64::MAX);
+
+        self.unsaved_messages_count = 0;
+        self.should_increment_offset = offset > 0;
+        self.current_offset = offset.saturating_sub(1);
+        info!(
+            "Truncated {} messages of partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            removed_messages.len(),
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(removed_messages.len() as u64)
+    }
+
+    async fn ensure_active_segment(&mut self) -> Result<(), Error> {
+        let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
+        if last_segment.is_closed {
+            let start_offset = last_segment.end_offset + 1;
+            trace!(
+                "Current segment is closed, creating new segment with start offset: {} for partition with ID: {}...",
+                start_offset, self.partition_id
+            );
+            self.add_persisted_segment(start_offset).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn append_to_active_segment(&mut self, messages: &[Arc<Message>]) -> Result<(), Error> {
+        let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
+        last_segment.append_messages(messages).await?;
         if let Some(cache) = &mut self.cache {
-            cache.extend(messages);
+            cache.extend(messages.iter().cloned());
         }
+        self.remove_delivered_delayed_messages(TimeStamp::now().to_micros())
+            .await;
+        self.index_delayed_messages(messages);
 
+        Ok(())
+    }
+
+    async fn persist_unsaved_messages(&mut self, messages_count: u32) -> Result<(), Error> {
         self.unsaved_messages_count += messages_count;
+        let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
+        if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
+            || last_segment.is_full().await
         {
-            let last_segment = self.segments.last_mut().ok_or(Error::SegmentNotFound)?;
-            if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
-                || last_segment.is_full().await
-            {
-                trace!(
-                    "Segment with start offset: {} for partition with ID: {} will be persisted on disk...",
-                    last_segment.start_offset,
-                    self.partition_id
-                );
-                last_segment
-                    .persist_messages(self.storage.segment.clone())
-                    .await?;
-                self.unsaved_messages_count = 0;
-            }
+            trace!(
+                "Segment with start offset: {} for partition with ID: {} will be persisted on disk...",
+                last_segment.start_offset,
+                self.partition_id
+            );
+            last_segment
+                .persist_messages(self.storage.segment.clone())
+                .await?;
+            self.unsaved_messages_count = 0;
+            // The producers state is saved together with the messages, so that the already persisted
+            // messages are not appended again by the retrying producer after the server restart.
+            self.persist_producers().await?;
         }
 
         Ok(())
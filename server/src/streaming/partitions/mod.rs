@@ -1,6 +1,11 @@
+pub mod compaction;
 pub mod consumer_offsets;
+pub mod delayed_messages;
 pub mod messages;
 pub mod partition;
 pub mod persistence;
+pub mod poisoned_offsets;
+pub mod producers;
 pub mod segments;
 pub mod storage;
+pub mod tiering;
@@ -3,12 +3,13 @@ use crate::streaming::cache::buffer::SmartCache;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::storage::SystemStorage;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::ConsumerKind;
 use iggy::models::messages::Message;
 use iggy::utils::timestamp::TimeStamp;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 
 #[derive(Debug)]
 pub struct Partition {
@@ -24,8 +25,22 @@ pub struct Partition {
     pub should_increment_offset: bool,
     pub created_at: u64,
     pub(crate) message_expiry: Option<u32>,
+    pub(crate) compression_algorithm: CompressionAlgorithm,
     pub(crate) consumer_offsets: RwLock<HashMap<u32, ConsumerOffset>>,
     pub(crate) consumer_group_offsets: RwLock<HashMap<u32, ConsumerOffset>>,
+    pub(crate) consumer_poisoned_offsets: RwLock<HashMap<u32, PoisonedOffsets>>,
+    pub(crate) consumer_group_poisoned_offsets: RwLock<HashMap<u32, PoisonedOffsets>>,
+    /// The time-ordered index of the delayed messages, as the pairs of their delivery timestamp and offset.
+    pub(crate) delayed_messages: BTreeSet<(u64, u64)>,
+    /// The last entry of the delayed messages index returned to each consumer.
+    pub(crate) consumer_delayed_messages_cursors: RwLock<HashMap<u32, (u64, u64)>>,
+    pub(crate) consumer_group_delayed_messages_cursors: RwLock<HashMap<u32, (u64, u64)>>,
+    /// Wakes up the consumers waiting for the new messages appended to the partition.
+    pub(crate) messages_notifier: Arc<Notify>,
+    pub(crate) producers: HashMap<u64, ProducerState>,
+    pub(crate) unsaved_producer_ids: HashSet<u64>,
+    /// The epoch of the leader, from which the messages were replicated most recently.
+    pub(crate) leader_epoch: u32,
     pub(crate) segments: Vec<Segment>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
@@ -69,7 +84,78 @@ impl ConsumerOffset {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct PoisonedOffsets {
+    pub kind: ConsumerKind,
+    pub consumer_id: u32,
+    pub offsets: BTreeSet<u64>,
+    pub key: String,
+}
+
+impl PoisonedOffsets {
+    pub fn new(
+        kind: ConsumerKind,
+        consumer_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> PoisonedOffsets {
+        PoisonedOffsets {
+            key: format!(
+                "{}:{consumer_id}",
+                Self::get_key_prefix(kind, stream_id, topic_id, partition_id)
+            ),
+            kind,
+            consumer_id,
+            offsets: BTreeSet::new(),
+        }
+    }
+
+    pub fn get_key_prefix(
+        kind: ConsumerKind,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        format!("{kind}_poisoned_offsets:{stream_id}:{topic_id}:{partition_id}")
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProducerState {
+    pub producer_id: u64,
+    pub last_sequence: u64,
+    pub last_appended_at: u64,
+    pub key: String,
+}
+
+impl ProducerState {
+    pub fn new(
+        producer_id: u64,
+        last_sequence: u64,
+        last_appended_at: u64,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> ProducerState {
+        ProducerState {
+            key: format!(
+                "{}:{producer_id}",
+                Self::get_key_prefix(stream_id, topic_id, partition_id)
+            ),
+            producer_id,
+            last_sequence,
+            last_appended_at,
+        }
+    }
+
+    pub fn get_key_prefix(stream_id: u32, topic_id: u32, partition_id: u32) -> String {
+        format!("producers:{stream_id}:{topic_id}:{partition_id}")
+    }
+}
+
 impl Partition {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         stream_id: u32,
         topic_id: u32,
@@ -78,6 +164,7 @@ impl Partition {
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
         message_expiry: Option<u32>,
+        compression_algorithm: CompressionAlgorithm,
     ) -> Partition {
         let path = config.get_partition_path(stream_id, topic_id, partition_id);
         let (cached_memory_tracker, messages) = match config.cache.enabled {
@@ -94,6 +181,7 @@ impl Partition {
             partition_id,
             path,
             message_expiry,
+            compression_algorithm,
             cache: messages,
             cached_memory_tracker,
             message_ids: match config.partition.deduplicate_messages {
@@ -106,6 +194,15 @@ impl Partition {
             should_increment_offset: false,
             consumer_offsets: RwLock::new(HashMap::new()),
             consumer_group_offsets: RwLock::new(HashMap::new()),
+            consumer_poisoned_offsets: RwLock::new(HashMap::new()),
+            consumer_group_poisoned_offsets: RwLock::new(HashMap::new()),
+            delayed_messages: BTreeSet::new(),
+            consumer_delayed_messages_cursors: RwLock::new(HashMap::new()),
+            consumer_group_delayed_messages_cursors: RwLock::new(HashMap::new()),
+            messages_notifier: Arc::new(Notify::new()),
+            producers: HashMap::new(),
+            unsaved_producer_ids: HashSet::new(),
+            leader_epoch: 0,
             config,
             storage,
             created_at: TimeStamp::now().to_micros(),
@@ -120,6 +217,7 @@ impl Partition {
                 partition.config.clone(),
                 partition.storage.clone(),
                 partition.message_expiry,
+                partition.compression_algorithm,
             );
             partition.segments.push(segment);
         }
@@ -133,6 +231,13 @@ impl Partition {
             .map(|segment| segment.current_size_bytes as u64)
             .sum()
     }
+
+    pub fn get_uncompressed_size_bytes(&self) -> u64 {
+        self.segments
+            .iter()
+            .map(|segment| segment.current_uncompressed_size_bytes as u64)
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -140,6 +245,7 @@ mod tests {
     use crate::configs::system::{CacheConfig, SystemConfig};
     use crate::streaming::partitions::partition::Partition;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
     use std::sync::Arc;
 
     #[test]
@@ -160,6 +266,7 @@ mod tests {
             config,
             storage,
             message_expiry,
+            CompressionAlgorithm::Zstd,
         );
 
         assert_eq!(partition.stream_id, stream_id);
@@ -174,7 +281,13 @@ mod tests {
         assert!(partition.cache.as_ref().unwrap().is_empty());
         let consumer_offsets = partition.consumer_offsets.blocking_read();
         assert_eq!(partition.message_expiry, message_expiry);
+        assert_eq!(partition.compression_algorithm, CompressionAlgorithm::Zstd);
+        assert_eq!(
+            partition.segments[0].compression_algorithm,
+            CompressionAlgorithm::Zstd
+        );
         assert!(consumer_offsets.is_empty());
+        assert!(partition.producers.is_empty());
     }
 
     #[test]
@@ -194,6 +307,7 @@ mod tests {
             }),
             storage,
             None,
+            CompressionAlgorithm::None,
         );
         assert!(partition.cache.is_none());
     }
@@ -210,6 +324,7 @@ mod tests {
             Arc::new(SystemConfig::default()),
             storage,
             None,
+            CompressionAlgorithm::None,
         );
         assert!(partition.segments.is_empty());
     }
@@ -0,0 +1,93 @@
+use crate::streaming::partitions::partition::{Partition, PoisonedOffsets};
+use crate::streaming::polling_consumer::PollingConsumer;
+use iggy::consumer::ConsumerKind;
+use iggy::error::Error;
+use tracing::trace;
+
+impl Partition {
+    /// Marks the message as poisoned for the consumer, so it will be skipped when polling the next messages.
+    /// Returns `false` if the message was already poisoned.
+    pub async fn poison_message(
+        &self,
+        consumer: PollingConsumer,
+        offset: u64,
+    ) -> Result<bool, Error> {
+        trace!(
+            "Poisoning message at offset: {} for {}, partition: {}...",
+            offset,
+            consumer,
+            self.partition_id
+        );
+        if self.get_messages_count() == 0 || offset > self.current_offset {
+            return Err(Error::InvalidOffset(offset));
+        }
+
+        let (kind, consumer_id, mut poisoned_offsets) = match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => (
+                ConsumerKind::Consumer,
+                consumer_id,
+                self.consumer_poisoned_offsets.write().await,
+            ),
+            PollingConsumer::ConsumerGroup(consumer_group_id, _) => (
+                ConsumerKind::ConsumerGroup,
+                consumer_group_id,
+                self.consumer_group_poisoned_offsets.write().await,
+            ),
+        };
+
+        let consumer_poisoned_offsets = poisoned_offsets.entry(consumer_id).or_insert_with(|| {
+            PoisonedOffsets::new(
+                kind,
+                consumer_id,
+                self.stream_id,
+                self.topic_id,
+                self.partition_id,
+            )
+        });
+        if !consumer_poisoned_offsets.offsets.insert(offset) {
+            return Ok(false);
+        }
+
+        self.storage
+            .partition
+            .save_poisoned_offsets(consumer_poisoned_offsets)
+            .await?;
+        Ok(true)
+    }
+
+    pub async fn load_poisoned_offsets(&mut self) -> Result<(), Error> {
+        trace!(
+            "Loading poisoned offsets for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        for kind in [ConsumerKind::Consumer, ConsumerKind::ConsumerGroup] {
+            let loaded_poisoned_offsets = self
+                .storage
+                .partition
+                .load_poisoned_offsets(kind, self.stream_id, self.topic_id, self.partition_id)
+                .await?;
+            let poisoned_offsets = match kind {
+                ConsumerKind::Consumer => self.consumer_poisoned_offsets.get_mut(),
+                ConsumerKind::ConsumerGroup => self.consumer_group_poisoned_offsets.get_mut(),
+            };
+            for consumer_poisoned_offsets in loaded_poisoned_offsets {
+                trace!("Loaded: {} poisoned offset(s) for {} with ID: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                    consumer_poisoned_offsets.offsets.len(),
+                    kind,
+                    consumer_poisoned_offsets.consumer_id,
+                    self.partition_id,
+                    self.topic_id,
+                    self.stream_id
+                );
+                poisoned_offsets.insert(
+                    consumer_poisoned_offsets.consumer_id,
+                    consumer_poisoned_offsets,
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+use crate::streaming::partitions::partition::{Partition, ProducerState};
+use iggy::error::Error;
+use iggy::messages::send_messages::Producer;
+use iggy::models::messages::Message;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
+use tracing::{trace, warn};
+
+impl Partition {
+    /// Skips the messages which were already appended by the producer (e.g. retried after the network error)
+    /// and fails if the sequence number of the first message is not the next expected one.
+    pub(crate) fn skip_producer_duplicates(
+        &self,
+        producer: &Producer,
+        mut messages: Vec<Message>,
+    ) -> Result<Vec<Message>, Error> {
+        let producer_state = self.producers.get(&producer.id);
+        if producer_state.is_none() {
+            return Ok(messages);
+        }
+
+        let producer_state = producer_state.unwrap();
+        let expected_sequence = producer_state.last_sequence + 1;
+        if producer.sequence > expected_sequence {
+            return Err(Error::OutOfOrderProducerSequence(
+                producer.sequence,
+                expected_sequence,
+                producer.id,
+            ));
+        }
+
+        let duplicates_count = (expected_sequence - producer.sequence) as usize;
+        if duplicates_count == 0 {
+            return Ok(messages);
+        }
+
+        warn!(
+            "Ignored: {} duplicated message(s) of the producer with ID: {} for partition with ID: {}.",
+            usize::min(duplicates_count, messages.len()),
+            producer.id,
+            self.partition_id
+        );
+        if duplicates_count >= messages.len() {
+            return Ok(Vec::new());
+        }
+
+        Ok(messages.split_off(duplicates_count))
+    }
+
+    /// Checks the sequence numbers of the consecutive batches without appending them, the same way as `skip_producer_duplicates`
+    /// does for each of them once the previous ones are appended. Used to reject the whole transaction before any of its batches is appended.
+    pub(crate) fn validate_producer_sequences<'a>(
+        &self,
+        batches: impl Iterator<Item = (&'a Producer, usize)>,
+    ) -> Result<(), Error> {
+        let mut last_sequences = HashMap::new();
+        for (producer, messages_count) in batches {
+            if messages_count == 0 {
+                continue;
+            }
+
+            let last_sequence = last_sequences.get(&producer.id).copied().or_else(|| {
+                self.producers
+                    .get(&producer.id)
+                    .map(|producer_state| producer_state.last_sequence)
+            });
+            if let Some(last_sequence) = last_sequence {
+                let expected_sequence = last_sequence + 1;
+                if producer.sequence > expected_sequence {
+                    return Err(Error::OutOfOrderProducerSequence(
+                        producer.sequence,
+                        expected_sequence,
+                        producer.id,
+                    ));
+                }
+            }
+
+            // The batch consisting only of the duplicates doesn't change the last sequence number.
+            let batch_last_sequence = producer.sequence + messages_count as u64 - 1;
+            last_sequences.insert(
+                producer.id,
+                last_sequence.map_or(batch_last_sequence, |last_sequence| {
+                    last_sequence.max(batch_last_sequence)
+                }),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Stores the sequence number of the last appended message, evicting the least recently used producer
+    /// if the limit of the tracked producers has been reached.
+    pub(crate) async fn update_producer_state(
+        &mut self,
+        producer: &Producer,
+        messages_count: u32,
+    ) -> Result<(), Error> {
+        let last_sequence = producer.sequence + messages_count as u64 - 1;
+        let now = TimeStamp::now().to_micros();
+        if let Some(producer_state) = self.producers.get_mut(&producer.id) {
+            producer_state.last_sequence = last_sequence;
+            producer_state.last_appended_at = now;
+            self.unsaved_producer_ids.insert(producer.id);
+            return Ok(());
+        }
+
+        while !self.producers.is_empty()
+            && self.producers.len() >= self.config.partition.max_producers as usize
+        {
+            self.evict_least_recently_used_producer().await?;
+        }
+
+        self.producers.insert(
+            producer.id,
+            ProducerState::new(
+                producer.id,
+                last_sequence,
+                now,
+                self.stream_id,
+                self.topic_id,
+                self.partition_id,
+            ),
+        );
+        self.unsaved_producer_ids.insert(producer.id);
+        Ok(())
+    }
+
+    pub async fn persist_producers(&mut self) -> Result<(), Error> {
+        for producer_id in self.unsaved_producer_ids.drain() {
+            if let Some(producer_state) = self.producers.get(&producer_id) {
+                self.storage.partition.save_producer(producer_state).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn load_producers(&mut self) -> Result<(), Error> {
+        trace!(
+            "Loading producers for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        let producers = self
+            .storage
+            .partition
+            .load_producers(self.stream_id, self.topic_id, self.partition_id)
+            .await?;
+        for producer_state in producers {
+            trace!("Loaded last sequence number: {} for producer with ID: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                producer_state.last_sequence,
+                producer_state.producer_id,
+                self.partition_id,
+                self.topic_id,
+                self.stream_id
+            );
+            self.producers
+                .insert(producer_state.producer_id, producer_state);
+        }
+
+        Ok(())
+    }
+
+    async fn evict_least_recently_used_producer(&mut self) -> Result<(), Error> {
+        let producer_id = self
+            .producers
+            .values()
+            .min_by_key(|producer_state| producer_state.last_appended_at)
+            .map(|producer_state| producer_state.producer_id);
+        if producer_id.is_none() {
+            return Ok(());
+        }
+
+        let producer_state = self.producers.remove(&producer_id.unwrap()).unwrap();
+        self.unsaved_producer_ids
+            .remove(&producer_state.producer_id);
+        self.storage
+            .partition
+            .delete_producer(&producer_state)
+            .await?;
+        trace!(
+            "Evicted producer with ID: {} for partition with ID: {}.",
+            producer_state.producer_id,
+            self.partition_id
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::{PartitionConfig, SystemConfig};
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::models::messages::MessageState;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn duplicated_messages_of_the_producer_should_be_skipped() {
+        let mut partition = create_partition(10);
+        let producer = Producer { id: 1, sequence: 0 };
+        partition.update_producer_state(&producer, 3).await.unwrap();
+
+        let retried_producer = Producer { id: 1, sequence: 1 };
+        let messages = partition
+            .skip_producer_duplicates(&retried_producer, get_messages(4))
+            .unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].id, 3);
+        assert_eq!(messages[1].id, 4);
+    }
+
+    #[tokio::test]
+    async fn already_appended_batch_of_the_producer_should_be_skipped() {
+        let mut partition = create_partition(10);
+        let producer = Producer { id: 1, sequence: 0 };
+        partition.update_producer_state(&producer, 3).await.unwrap();
+
+        let messages = partition
+            .skip_producer_duplicates(&producer, get_messages(3))
+            .unwrap();
+
+        assert!(messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn out_of_order_sequence_of_the_producer_should_fail() {
+        let mut partition = create_partition(10);
+        let producer = Producer { id: 1, sequence: 0 };
+        partition.update_producer_state(&producer, 3).await.unwrap();
+
+        let producer = Producer { id: 1, sequence: 5 };
+        let result = partition.skip_producer_duplicates(&producer, get_messages(1));
+
+        assert!(matches!(
+            result,
+            Err(Error::OutOfOrderProducerSequence(5, 3, 1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn unknown_producer_should_be_able_to_start_from_any_sequence() {
+        let partition = create_partition(10);
+        let producer = Producer {
+            id: 1,
+            sequence: 100,
+        };
+
+        let messages = partition
+            .skip_producer_duplicates(&producer, get_messages(2))
+            .unwrap();
+
+        assert_eq!(messages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn consecutive_batches_of_the_producer_should_be_validated_without_appending() {
+        let mut partition = create_partition(10);
+        partition
+            .update_producer_state(&Producer { id: 1, sequence: 0 }, 3)
+            .await
+            .unwrap();
+        let retried = Producer { id: 1, sequence: 1 };
+        let next = Producer { id: 1, sequence: 5 };
+        let other = Producer { id: 2, sequence: 7 };
+        let gap = Producer { id: 1, sequence: 8 };
+
+        let batches = [(&retried, 4), (&next, 2), (&other, 1)];
+        assert!(partition
+            .validate_producer_sequences(batches.into_iter())
+            .is_ok());
+
+        let batches = [(&retried, 4), (&next, 2), (&gap, 1)];
+        let result = partition.validate_producer_sequences(batches.into_iter());
+        assert!(matches!(
+            result,
+            Err(Error::OutOfOrderProducerSequence(8, 7, 1))
+        ));
+        assert_eq!(partition.producers.get(&1).unwrap().last_sequence, 2);
+    }
+
+    #[tokio::test]
+    async fn least_recently_used_producer_should_be_evicted_given_producers_limit() {
+        let mut partition = create_partition(2);
+        for id in 1..=2 {
+            partition
+                .update_producer_state(&Producer { id, sequence: 0 }, 1)
+                .await
+                .unwrap();
+            partition.producers.get_mut(&id).unwrap().last_appended_at = id;
+        }
+
+        partition
+            .update_producer_state(&Producer { id: 3, sequence: 0 }, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(partition.producers.len(), 2);
+        assert!(!partition.producers.contains_key(&1));
+        assert!(partition.producers.contains_key(&2));
+        assert!(partition.producers.contains_key(&3));
+    }
+
+    fn create_partition(max_producers: u32) -> Partition {
+        let storage = Arc::new(get_test_system_storage());
+        let config = Arc::new(SystemConfig {
+            partition: PartitionConfig {
+                max_producers,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        Partition::create(
+            1,
+            1,
+            1,
+            false,
+            config,
+            storage,
+            None,
+            CompressionAlgorithm::None,
+        )
+    }
+
+    fn get_messages(count: u128) -> Vec<Message> {
+        (1..=count)
+            .map(|id| {
+                Message::create(
+                    0,
+                    MessageState::Available,
+                    0,
+                    id,
+                    Bytes::from("test"),
+                    0,
+                    None,
+                )
+            })
+            .collect()
+    }
+}
@@ -19,7 +19,7 @@ impl Partition {
     pub async fn get_expired_segments_start_offsets(&self, now: u64) -> Vec<u64> {
         let mut expired_segments = Vec::new();
         for segment in &self.segments {
-            if segment.is_expired(now).await {
+            if segment.is_closed && segment.is_expired(now).await {
                 expired_segments.push(segment.start_offset);
             }
         }
@@ -28,6 +28,14 @@ impl Partition {
         expired_segments
     }
 
+    pub fn get_oldest_closed_segment_start_offset(&self) -> Option<u64> {
+        self.segments
+            .iter()
+            .filter(|segment| segment.is_closed)
+            .map(|segment| segment.start_offset)
+            .min()
+    }
+
     pub async fn add_persisted_segment(&mut self, start_offset: u64) -> Result<(), Error> {
         info!(
             "Creating the new segment for partition with ID: {}, stream with ID: {}, topic with ID: {}...",
@@ -41,23 +49,26 @@ impl Partition {
             self.config.clone(),
             self.storage.clone(),
             self.message_expiry,
+            self.compression_algorithm,
         );
         new_segment.persist().await?;
         self.segments.push(new_segment);
         Ok(())
     }
 
-    pub async fn delete_segment(&mut self, start_offset: u64) -> Result<&Segment, Error> {
-        let segment = self
+    pub async fn delete_segment(&mut self, start_offset: u64) -> Result<Segment, Error> {
+        let index = self
             .segments
             .iter()
-            .find(|s| s.start_offset == start_offset);
-        if segment.is_none() {
+            .position(|s| s.start_offset == start_offset);
+        if index.is_none() {
             return Err(Error::SegmentNotFound);
         }
 
-        let segment = segment.unwrap();
-        self.storage.segment.delete(segment).await?;
+        let index = index.unwrap();
+        self.storage.segment.delete(&self.segments[index]).await?;
+        let segment = self.segments.remove(index);
+        self.remove_delayed_messages(segment.start_offset, segment.current_offset);
         Ok(segment)
     }
 }
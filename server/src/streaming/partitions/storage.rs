@@ -1,16 +1,19 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, Partition, PoisonedOffsets, ProducerState,
+};
 use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::Error;
 use serde::{Deserialize, Serialize};
 use sled::Db;
+use std::collections::BTreeSet;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
 use tokio::fs::create_dir;
 use tracing::{error, info, trace};
 
-use crate::streaming::segments::segment::{Segment, LOG_EXTENSION};
+use crate::streaming::segments::segment::{Segment, LOG_EXTENSION, REMOTE_EXTENSION};
 use crate::streaming::storage::{PartitionStorage, Storage};
 
 #[derive(Debug)]
@@ -125,6 +128,182 @@ impl PartitionStorage for FilePartitionStorage {
 
         Ok(())
     }
+
+    async fn save_poisoned_offsets(&self, poisoned_offsets: &PoisonedOffsets) -> Result<(), Error> {
+        // All the poisoned offsets of the consumer are stored under a single key as the raw big-endian bytes.
+        let mut value = Vec::with_capacity(8 * poisoned_offsets.offsets.len());
+        for offset in &poisoned_offsets.offsets {
+            value.extend(offset.to_be_bytes());
+        }
+        if self.db.insert(&poisoned_offsets.key, value).is_err() {
+            return Err(Error::CannotSaveResource(poisoned_offsets.key.to_string()));
+        }
+
+        trace!(
+            "Stored: {} poisoned offset(s) for {} with ID: {}",
+            poisoned_offsets.offsets.len(),
+            poisoned_offsets.kind,
+            poisoned_offsets.consumer_id
+        );
+        Ok(())
+    }
+
+    async fn load_poisoned_offsets(
+        &self,
+        kind: ConsumerKind,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<PoisonedOffsets>, Error> {
+        let mut poisoned_offsets = Vec::new();
+        let key_prefix = format!(
+            "{}:",
+            PoisonedOffsets::get_key_prefix(kind, stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            let consumer_poisoned_offsets = match data {
+                Ok((key, value)) => {
+                    let key = String::from_utf8(key.to_vec()).unwrap();
+                    let consumer_id = key.split(':').next_back().unwrap().parse::<u32>().unwrap();
+                    let offsets = value
+                        .chunks_exact(8)
+                        .map(|offset| u64::from_be_bytes(offset.try_into().unwrap()))
+                        .collect();
+                    PoisonedOffsets {
+                        kind,
+                        consumer_id,
+                        offsets,
+                        key,
+                    }
+                }
+                Err(err) => {
+                    error!("Cannot load poisoned offsets. Error: {}", err);
+                    return Err(Error::CannotLoadResource(key_prefix.to_string()));
+                }
+            };
+            poisoned_offsets.push(consumer_poisoned_offsets);
+        }
+
+        Ok(poisoned_offsets)
+    }
+
+    async fn delete_poisoned_offsets(
+        &self,
+        kind: ConsumerKind,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error> {
+        let key_prefix = format!(
+            "{}:",
+            PoisonedOffsets::get_key_prefix(kind, stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            if let Err(error) = data {
+                error!("Cannot delete poisoned offsets. Error: {}", error);
+                return Err(Error::CannotLoadResource(key_prefix.to_string()));
+            }
+
+            let (key, _) = data.unwrap();
+            if let Err(error) = self.db.remove(&key) {
+                error!("Cannot delete poisoned offsets. Error: {}", error);
+                return Err(Error::CannotDeleteResource(key_prefix.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn save_producer(&self, producer: &ProducerState) -> Result<(), Error> {
+        // Same as for the consumer offsets, the value is stored as the raw big-endian bytes.
+        let mut value = Vec::with_capacity(16);
+        value.extend(producer.last_sequence.to_be_bytes());
+        value.extend(producer.last_appended_at.to_be_bytes());
+        if self.db.insert(&producer.key, value).is_err() {
+            return Err(Error::CannotSaveResource(producer.key.to_string()));
+        }
+
+        trace!(
+            "Stored last sequence number: {} for producer with ID: {}",
+            producer.last_sequence,
+            producer.producer_id
+        );
+        Ok(())
+    }
+
+    async fn load_producers(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<ProducerState>, Error> {
+        let mut producers = Vec::new();
+        let key_prefix = format!(
+            "{}:",
+            ProducerState::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            let producer = match data {
+                Ok((key, value)) => {
+                    let key = String::from_utf8(key.to_vec()).unwrap();
+                    let last_sequence = u64::from_be_bytes(value[..8].try_into().unwrap());
+                    let last_appended_at = u64::from_be_bytes(value[8..16].try_into().unwrap());
+                    let producer_id = key.split(':').next_back().unwrap().parse::<u64>().unwrap();
+                    ProducerState {
+                        key,
+                        producer_id,
+                        last_sequence,
+                        last_appended_at,
+                    }
+                }
+                Err(err) => {
+                    error!("Cannot load producer. Error: {}", err);
+                    return Err(Error::CannotLoadResource(key_prefix.to_string()));
+                }
+            };
+            producers.push(producer);
+        }
+
+        Ok(producers)
+    }
+
+    async fn delete_producer(&self, producer: &ProducerState) -> Result<(), Error> {
+        if let Err(error) = self.db.remove(&producer.key) {
+            error!(
+                "Cannot delete producer with ID: {}. Error: {}",
+                producer.producer_id, error
+            );
+            return Err(Error::CannotDeleteResource(producer.key.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_producers(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error> {
+        let key_prefix = format!(
+            "{}:",
+            ProducerState::get_key_prefix(stream_id, topic_id, partition_id)
+        );
+        for data in self.db.scan_prefix(&key_prefix) {
+            if let Err(error) = data {
+                error!("Cannot delete producers. Error: {}", error);
+                return Err(Error::CannotLoadResource(key_prefix.to_string()));
+            }
+
+            let (key, _) = data.unwrap();
+            if let Err(error) = self.db.remove(&key) {
+                error!("Cannot delete producer. Error: {}", error);
+                return Err(Error::CannotDeleteResource(key_prefix.to_string()));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -171,6 +350,8 @@ impl Storage<Partition> for FilePartitionStorage {
         let partition_data = partition_data.unwrap();
         partition.created_at = partition_data.created_at;
 
+        // The segments uploaded to the remote storage might have no local log file, but only the remote marker file.
+        let mut start_offsets = BTreeSet::new();
         let mut dir_entries = dir_entries.unwrap();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
             let metadata = dir_entry.metadata().await.unwrap();
@@ -180,17 +361,23 @@ impl Storage<Partition> for FilePartitionStorage {
 
             let path = dir_entry.path();
             let extension = path.extension();
-            if extension.is_none() || extension.unwrap() != LOG_EXTENSION {
+            if extension.is_none()
+                || (extension.unwrap() != LOG_EXTENSION && extension.unwrap() != REMOTE_EXTENSION)
+            {
                 continue;
             }
 
-            let log_file_name = dir_entry
-                .file_name()
-                .into_string()
+            let start_offset = path
+                .file_stem()
+                .unwrap()
+                .to_str()
                 .unwrap()
-                .replace(&format!(".{}", LOG_EXTENSION), "");
+                .parse::<u64>()
+                .unwrap();
+            start_offsets.insert(start_offset);
+        }
 
-            let start_offset = log_file_name.parse::<u64>().unwrap();
+        for start_offset in start_offsets {
             let mut segment = Segment::create(
                 partition.stream_id,
                 partition.topic_id,
@@ -199,6 +386,7 @@ impl Storage<Partition> for FilePartitionStorage {
                 partition.config.clone(),
                 partition.storage.clone(),
                 partition.message_expiry,
+                partition.compression_algorithm,
             );
             segment.load().await?;
             if !segment.is_closed {
@@ -260,6 +448,9 @@ impl Storage<Partition> for FilePartitionStorage {
         }
 
         partition.load_consumer_offsets().await?;
+        partition.load_poisoned_offsets().await?;
+        partition.load_delayed_messages().await?;
+        partition.load_producers().await?;
         info!(
             "Loaded partition with ID: {} for stream with ID: {} and topic with ID: {}, current offset: {}.",
             partition.partition_id, partition.stream_id, partition.topic_id, partition.current_offset
@@ -379,6 +570,49 @@ impl Storage<Partition> for FilePartitionStorage {
             ));
         }
 
+        for kind in [ConsumerKind::Consumer, ConsumerKind::ConsumerGroup] {
+            if let Err(err) = self
+                .delete_poisoned_offsets(
+                    kind,
+                    partition.stream_id,
+                    partition.topic_id,
+                    partition.partition_id,
+                )
+                .await
+            {
+                error!("Cannot delete {} poisoned offsets for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", kind, partition.partition_id, partition.topic_id, partition.stream_id, err);
+                return Err(Error::CannotDeletePartition(
+                    partition.partition_id,
+                    partition.topic_id,
+                    partition.stream_id,
+                ));
+            }
+        }
+
+        if let Err(err) = self
+            .delete_producers(
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+            )
+            .await
+        {
+            error!("Cannot delete producers for partition with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}", partition.partition_id, partition.topic_id, partition.stream_id, err);
+            return Err(Error::CannotDeletePartition(
+                partition.partition_id,
+                partition.topic_id,
+                partition.stream_id,
+            ));
+        }
+
+        for segment in partition
+            .get_segments()
+            .iter()
+            .filter(|segment| segment.is_uploaded)
+        {
+            partition.storage.segment.delete(segment).await?;
+        }
+
         if fs::remove_dir_all(&partition.path).await.is_err() {
             error!("Cannot delete partition directory: {} for partition with ID: {} for topic with ID: {} for stream with ID: {}.", partition.path, partition.partition_id, partition.topic_id, partition.stream_id);
             return Err(Error::CannotDeletePartitionDirectory(
@@ -0,0 +1,115 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::segment::Segment;
+use iggy::error::Error;
+use std::path::Path;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+#[derive(Debug, Default)]
+pub struct OffloadedSegments {
+    pub uploaded_segments_count: u32,
+    pub removed_local_segments_count: u32,
+}
+
+impl Partition {
+    /// Uploads the closed segments to the remote storage, and removes the local copies of the uploaded segments,
+    /// except for the newest `retain_local_segments` closed ones, which are the most likely to be read by the consumers.
+    /// The removed segments are downloaded back from the remote storage once their messages are requested.
+    /// The files of the closed segments are uploaded without holding the partition lock, so that the appends
+    /// and the polls can proceed, and the lock is acquired only to mark the segments as uploaded.
+    pub async fn offload_segments(
+        partition: &RwLock<Partition>,
+        retain_local_segments: u32,
+    ) -> Result<OffloadedSegments, Error> {
+        let mut offloaded_segments = OffloadedSegments::default();
+        let segments = partition
+            .read()
+            .await
+            .segments
+            .iter()
+            .filter(|segment| segment.is_closed && !segment.is_uploaded)
+            .map(|segment| segment.get_upload_copy())
+            .collect::<Vec<_>>();
+        for segment in segments {
+            trace!(
+                "Uploading segment with start offset: {} for partition with ID: {}.",
+                segment.start_offset,
+                segment.partition_id
+            );
+            segment.storage.segment.upload(&segment).await?;
+            if partition
+                .write()
+                .await
+                .mark_segment_uploaded(&segment)
+                .await?
+            {
+                offloaded_segments.uploaded_segments_count += 1;
+            }
+        }
+
+        offloaded_segments.removed_local_segments_count = partition
+            .write()
+            .await
+            .remove_local_segments(retain_local_segments)
+            .await?;
+        Ok(offloaded_segments)
+    }
+
+    /// Marks the segment as uploaded, unless it was deleted or rewritten (e.g. by the compaction) during the upload,
+    /// in which case the uploaded files are outdated, and the segment will be uploaded again by the next offload.
+    async fn mark_segment_uploaded(&mut self, uploaded_segment: &Segment) -> Result<bool, Error> {
+        let segment = self.segments.iter_mut().find(|segment| {
+            segment.start_offset == uploaded_segment.start_offset
+                && segment.is_closed
+                && !segment.is_uploaded
+                && segment.current_offset == uploaded_segment.current_offset
+                && segment.current_size_bytes == uploaded_segment.current_size_bytes
+                && segment.current_uncompressed_size_bytes
+                    == uploaded_segment.current_uncompressed_size_bytes
+        });
+        let Some(segment) = segment else {
+            trace!(
+                "Segment with start offset: {} for partition with ID: {} was changed during the upload.",
+                uploaded_segment.start_offset,
+                self.partition_id
+            );
+            return Ok(false);
+        };
+
+        segment.storage.segment.mark_uploaded(segment).await?;
+        segment.is_uploaded = true;
+        Ok(true)
+    }
+
+    async fn remove_local_segments(&mut self, retain_local_segments: u32) -> Result<u32, Error> {
+        let closed_segments_count = self
+            .segments
+            .iter()
+            .filter(|segment| segment.is_closed)
+            .count();
+        let removable_segments_count =
+            closed_segments_count.saturating_sub(retain_local_segments as usize);
+        let mut removed_local_segments_count = 0;
+        for segment in self
+            .segments
+            .iter_mut()
+            .filter(|segment| segment.is_closed)
+            .take(removable_segments_count)
+        {
+            if !segment.is_uploaded || !Path::new(&segment.log_path).exists() {
+                continue;
+            }
+
+            trace!(
+                "Removing local copy of segment with start offset: {} for partition with ID: {}.",
+                segment.start_offset,
+                self.partition_id
+            );
+            segment.storage.segment.delete_local_copy(segment).await?;
+            segment.indexes = None;
+            removed_local_segments_count += 1;
+        }
+
+        Ok(removed_local_segments_count)
+    }
+}
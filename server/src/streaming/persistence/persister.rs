@@ -1,9 +1,11 @@
 use crate::streaming::utils::file;
 use async_trait::async_trait;
 use iggy::error::Error;
+use prometheus_client::metrics::histogram::Histogram;
 use std::fmt::Debug;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
+use tokio::time::Instant;
 
 #[async_trait]
 pub trait Persister: Sync + Send {
@@ -20,11 +22,38 @@ impl Debug for dyn Persister {
     }
 }
 
-#[derive(Debug)]
-pub struct FilePersister;
+/// The persisters optionally observe the duration of each append (segment flush) in the provided histogram.
+#[derive(Debug, Default)]
+pub struct FilePersister {
+    flush_duration: Option<Histogram>,
+}
+
+#[derive(Debug, Default)]
+pub struct FileWithSyncPersister {
+    flush_duration: Option<Histogram>,
+}
+
+impl FilePersister {
+    pub fn new(flush_duration: Histogram) -> Self {
+        Self {
+            flush_duration: Some(flush_duration),
+        }
+    }
+}
 
-#[derive(Debug)]
-pub struct FileWithSyncPersister;
+impl FileWithSyncPersister {
+    pub fn new(flush_duration: Histogram) -> Self {
+        Self {
+            flush_duration: Some(flush_duration),
+        }
+    }
+}
+
+fn observe_flush_duration(flush_duration: &Option<Histogram>, started_at: Instant) {
+    if let Some(flush_duration) = flush_duration {
+        flush_duration.observe(started_at.elapsed().as_secs_f64());
+    }
+}
 
 unsafe impl Send for FilePersister {}
 unsafe impl Sync for FilePersister {}
@@ -35,8 +64,10 @@ unsafe impl Sync for FileWithSyncPersister {}
 #[async_trait]
 impl Persister for FilePersister {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        let started_at = Instant::now();
         let mut file = file::append(path).await?;
         file.write_all(bytes).await?;
+        observe_flush_duration(&self.flush_duration, started_at);
         Ok(())
     }
 
@@ -55,9 +86,11 @@ impl Persister for FilePersister {
 #[async_trait]
 impl Persister for FileWithSyncPersister {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), Error> {
+        let started_at = Instant::now();
         let mut file = file::append(path).await?;
         file.write_all(bytes).await?;
         file.sync_all().await?;
+        observe_flush_duration(&self.flush_duration, started_at);
         Ok(())
     }
 
@@ -0,0 +1,278 @@
+use crate::configs::system::QuotaConfig;
+use dashmap::DashMap;
+use iggy::error::Error;
+use iggy::models::user_info::UserId;
+use iggy::models::user_quota::UserQuota;
+use iggy::utils::timestamp::TimeStamp;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::debug;
+
+const WINDOW_MICROS: u64 = 1_000_000;
+
+/// Enforces the byte-rate and request-rate quotas of the users and clients sending and polling the messages.
+/// The usage is counted within the fixed one second windows, and the request exceeding any of the limits is throttled.
+/// The usages are kept in the concurrent maps, so the requests of different users and clients don't contend for a single lock.
+#[derive(Debug)]
+pub struct QuotaManager {
+    enabled: bool,
+    user_limit: QuotaLimit,
+    client_limit: QuotaLimit,
+    user_limits: DashMap<UserId, QuotaLimit>,
+    users: DashMap<UserId, QuotaUsage>,
+    clients: DashMap<u32, QuotaUsage>,
+    throttled_requests: AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct QuotaLimit {
+    bytes_per_second: u64,
+    requests_per_second: u32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct QuotaUsage {
+    pub requests: u32,
+    pub bytes: u64,
+    pub throttled_requests: u64,
+    window_start: u64,
+}
+
+impl QuotaManager {
+    pub fn new(config: &QuotaConfig) -> Self {
+        QuotaManager {
+            enabled: config.enabled,
+            user_limit: QuotaLimit {
+                bytes_per_second: config.user_bytes_per_second,
+                requests_per_second: config.user_requests_per_second,
+            },
+            client_limit: QuotaLimit {
+                bytes_per_second: config.client_bytes_per_second,
+                requests_per_second: config.client_requests_per_second,
+            },
+            user_limits: DashMap::new(),
+            users: DashMap::new(),
+            clients: DashMap::new(),
+            throttled_requests: AtomicU64::new(0),
+        }
+    }
+
+    /// Sets the quota of the user, which overrides the default user limits from the config, or restores them if `None`.
+    pub fn set_user_quota(&self, user_id: UserId, quota: Option<UserQuota>) {
+        match quota {
+            Some(quota) => {
+                self.user_limits.insert(
+                    user_id,
+                    QuotaLimit {
+                        bytes_per_second: quota.bytes_per_second,
+                        requests_per_second: quota.requests_per_second,
+                    },
+                );
+            }
+            None => {
+                self.user_limits.remove(&user_id);
+            }
+        }
+    }
+
+    /// Counts the request with the given size against the quotas of the user and the client (if any),
+    /// or returns `QuotaExceeded` error without counting it, if any of the limits has been already reached.
+    pub fn acquire(&self, user_id: UserId, client_id: u32, bytes: u64) -> Result<(), Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let now = TimeStamp::now().to_micros();
+        let user_limit = self
+            .user_limits
+            .get(&user_id)
+            .map(|limit| *limit)
+            .unwrap_or(self.user_limit);
+        // The user entry is always locked before the client one, so the concurrent requests can't deadlock.
+        let mut user_usage = self.users.entry(user_id).or_default();
+        user_usage.refresh(now);
+        // The stateless (HTTP) sessions are not bound to any client.
+        let mut client_usage = match client_id {
+            0 => None,
+            _ => Some(self.clients.entry(client_id).or_default()),
+        };
+        if let Some(client_usage) = client_usage.as_mut() {
+            client_usage.refresh(now);
+        }
+
+        if user_usage.exceeds(&user_limit, bytes)
+            || client_usage
+                .as_ref()
+                .is_some_and(|client_usage| client_usage.exceeds(&self.client_limit, bytes))
+        {
+            user_usage.throttled_requests += 1;
+            if let Some(client_usage) = client_usage.as_mut() {
+                client_usage.throttled_requests += 1;
+            }
+            self.throttled_requests.fetch_add(1, Ordering::Relaxed);
+            debug!(
+                "Throttled the request of user with ID: {user_id}, client with ID: {client_id}."
+            );
+            return Err(Error::QuotaExceeded);
+        }
+
+        user_usage.add(bytes);
+        if let Some(client_usage) = client_usage.as_mut() {
+            client_usage.add(bytes);
+        }
+        Ok(())
+    }
+
+    /// Counts the bytes which are known only once the request has been handled, e.g. the polled messages.
+    pub fn record_bytes(&self, user_id: UserId, client_id: u32, bytes: u64) {
+        if !self.enabled || bytes == 0 {
+            return;
+        }
+
+        let now = TimeStamp::now().to_micros();
+        if let Some(mut user_usage) = self.users.get_mut(&user_id) {
+            user_usage.refresh(now);
+            user_usage.bytes += bytes;
+        }
+        if let Some(mut client_usage) = self.clients.get_mut(&client_id) {
+            client_usage.refresh(now);
+            client_usage.bytes += bytes;
+        }
+    }
+
+    pub fn get_client_usage(&self, client_id: u32) -> QuotaUsage {
+        self.clients
+            .get(&client_id)
+            .map(|usage| usage.current(TimeStamp::now().to_micros()))
+            .unwrap_or_default()
+    }
+
+    pub fn delete_client_usage(&self, client_id: u32) {
+        self.clients.remove(&client_id);
+    }
+
+    /// Deletes the usage and the quota of the deleted user.
+    pub fn delete_user_usage(&self, user_id: UserId) {
+        self.users.remove(&user_id);
+        self.user_limits.remove(&user_id);
+    }
+
+    pub fn get_throttled_requests_count(&self) -> u64 {
+        self.throttled_requests.load(Ordering::Relaxed)
+    }
+}
+
+impl QuotaUsage {
+    fn refresh(&mut self, now: u64) {
+        if now.saturating_sub(self.window_start) >= WINDOW_MICROS {
+            self.requests = 0;
+            self.bytes = 0;
+            self.window_start = now;
+        }
+    }
+
+    fn current(&self, now: u64) -> QuotaUsage {
+        let mut usage = *self;
+        usage.refresh(now);
+        usage
+    }
+
+    fn exceeds(&self, limit: &QuotaLimit, bytes: u64) -> bool {
+        if limit.requests_per_second > 0 && self.requests >= limit.requests_per_second {
+            return true;
+        }
+
+        if limit.bytes_per_second == 0 {
+            return false;
+        }
+
+        // The request larger than the limit is still allowed at the beginning of the window, otherwise it would never pass.
+        self.bytes >= limit.bytes_per_second
+            || (self.bytes > 0 && self.bytes + bytes > limit.bytes_per_second)
+    }
+
+    fn add(&mut self, bytes: u64) {
+        self.requests += 1;
+        self.bytes += bytes;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_exceeding_the_user_requests_limit_should_be_throttled() {
+        let quota_manager = QuotaManager::new(&QuotaConfig {
+            enabled: true,
+            user_requests_per_second: 2,
+            ..Default::default()
+        });
+
+        assert!(quota_manager.acquire(1, 1, 10).is_ok());
+        assert!(quota_manager.acquire(1, 2, 10).is_ok());
+        let result = quota_manager.acquire(1, 3, 10);
+        assert!(matches!(result, Err(Error::QuotaExceeded)));
+        assert!(quota_manager.acquire(2, 4, 10).is_ok());
+        assert_eq!(quota_manager.get_throttled_requests_count(), 1);
+        assert_eq!(quota_manager.get_client_usage(3).throttled_requests, 1);
+    }
+
+    #[test]
+    fn requests_exceeding_the_client_bytes_limit_should_be_throttled() {
+        let quota_manager = QuotaManager::new(&QuotaConfig {
+            enabled: true,
+            client_bytes_per_second: 100,
+            ..Default::default()
+        });
+
+        assert!(quota_manager.acquire(1, 1, 150).is_ok());
+        let result = quota_manager.acquire(1, 1, 1);
+        assert!(matches!(result, Err(Error::QuotaExceeded)));
+        assert!(quota_manager.acquire(1, 2, 50).is_ok());
+        quota_manager.record_bytes(1, 2, 50);
+        let result = quota_manager.acquire(1, 2, 0);
+        assert!(matches!(result, Err(Error::QuotaExceeded)));
+
+        let usage = quota_manager.get_client_usage(2);
+        assert_eq!(usage.requests, 1);
+        assert_eq!(usage.bytes, 100);
+        assert_eq!(usage.throttled_requests, 1);
+    }
+
+    #[test]
+    fn requests_should_not_be_throttled_when_quotas_are_disabled() {
+        let quota_manager = QuotaManager::new(&QuotaConfig {
+            enabled: false,
+            user_requests_per_second: 1,
+            ..Default::default()
+        });
+
+        for _ in 0..10 {
+            assert!(quota_manager.acquire(1, 1, 10).is_ok());
+        }
+        assert_eq!(quota_manager.get_throttled_requests_count(), 0);
+    }
+
+    #[test]
+    fn user_quota_should_override_the_default_user_limits() {
+        let quota_manager = QuotaManager::new(&QuotaConfig {
+            enabled: true,
+            user_requests_per_second: 1,
+            ..Default::default()
+        });
+
+        quota_manager.set_user_quota(1, Some(UserQuota::new(0, 2)));
+        assert!(quota_manager.acquire(1, 1, 10).is_ok());
+        assert!(quota_manager.acquire(1, 1, 10).is_ok());
+        assert!(quota_manager.acquire(1, 1, 10).is_err());
+        assert!(quota_manager.acquire(2, 2, 10).is_ok());
+        assert!(quota_manager.acquire(2, 2, 10).is_err());
+
+        quota_manager.delete_user_usage(1);
+        quota_manager.delete_client_usage(1);
+        assert_eq!(quota_manager.get_client_usage(1), QuotaUsage::default());
+        // The deleted user's quota no longer applies, so the default limit is used.
+        assert!(quota_manager.acquire(1, 1, 10).is_ok());
+        assert!(quota_manager.acquire(1, 1, 10).is_err());
+    }
+}
@@ -0,0 +1,830 @@
+use iggy::error::Error;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+
+// The payloads of the recursive types (e.g. the linked list) are validated only up to this depth.
+const MAX_DEPTH: usize = 128;
+
+/// The Avro schema parsed from its JSON representation, with the references to the named types resolved.
+/// The logical types are validated as their underlying types.
+#[derive(Debug, Clone)]
+pub struct AvroDefinition {
+    schema: AvroSchema,
+    // The records, enums and fixed types referenced by their index, so that they can be recursive.
+    types: Vec<NamedType>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum AvroSchema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Array(Box<AvroSchema>),
+    Map(Box<AvroSchema>),
+    Union(Vec<AvroSchema>),
+    Named(usize),
+}
+
+#[derive(Debug, Clone)]
+enum NamedType {
+    Record {
+        name: String,
+        aliases: Vec<String>,
+        fields: Vec<AvroField>,
+    },
+    Enum {
+        name: String,
+        aliases: Vec<String>,
+        symbols: Vec<String>,
+        default: Option<String>,
+    },
+    Fixed {
+        name: String,
+        aliases: Vec<String>,
+        size: usize,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct AvroField {
+    name: String,
+    aliases: Vec<String>,
+    schema: AvroSchema,
+    has_default: bool,
+}
+
+impl NamedType {
+    fn name(&self) -> &str {
+        match self {
+            NamedType::Record { name, .. }
+            | NamedType::Enum { name, .. }
+            | NamedType::Fixed { name, .. } => name,
+        }
+    }
+
+    fn aliases(&self) -> &[String] {
+        match self {
+            NamedType::Record { aliases, .. }
+            | NamedType::Enum { aliases, .. }
+            | NamedType::Fixed { aliases, .. } => aliases,
+        }
+    }
+}
+
+impl AvroDefinition {
+    /// Checks if the payload is the binary encoded datum of the schema, without any trailing bytes.
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        let mut bytes = payload;
+        self.skip(&self.schema, &mut bytes, 0)
+            .map_err(|reason| format!("payload is not a valid Avro datum: {reason}"))?;
+        if !bytes.is_empty() {
+            return Err(format!(
+                "payload has {} unexpected trailing bytes",
+                bytes.len()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn skip(&self, schema: &AvroSchema, bytes: &mut &[u8], depth: usize) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("value is nested deeper than {MAX_DEPTH} levels"));
+        }
+
+        match schema {
+            AvroSchema::Null => Ok(()),
+            AvroSchema::Boolean => match take(bytes, 1)?[0] {
+                0 | 1 => Ok(()),
+                value => Err(format!("invalid boolean: {value}")),
+            },
+            AvroSchema::Int => {
+                let value = read_long(bytes)?;
+                if i32::try_from(value).is_err() {
+                    return Err(format!("int: {value} is out of range"));
+                }
+                Ok(())
+            }
+            AvroSchema::Long => read_long(bytes).map(|_| ()),
+            AvroSchema::Float => take(bytes, 4).map(|_| ()),
+            AvroSchema::Double => take(bytes, 8).map(|_| ()),
+            AvroSchema::Bytes => read_bytes(bytes).map(|_| ()),
+            AvroSchema::String => read_string(bytes).map(|_| ()),
+            AvroSchema::Array(items) => {
+                skip_blocks(bytes, |bytes| self.skip(items, bytes, depth + 1))
+            }
+            AvroSchema::Map(values) => skip_blocks(bytes, |bytes| {
+                read_string(bytes)?;
+                self.skip(values, bytes, depth + 1)
+            }),
+            AvroSchema::Union(schemas) => {
+                let index = read_long(bytes)?;
+                let schema = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| schemas.get(index))
+                    .ok_or_else(|| format!("invalid union index: {index}"))?;
+                self.skip(schema, bytes, depth + 1)
+            }
+            AvroSchema::Named(index) => match &self.types[*index] {
+                NamedType::Record { fields, .. } => {
+                    for field in fields {
+                        self.skip(&field.schema, bytes, depth + 1)
+                            .map_err(|reason| format!("{reason} in field '{}'", field.name))?;
+                    }
+                    Ok(())
+                }
+                NamedType::Enum { name, symbols, .. } => {
+                    let index = read_long(bytes)?;
+                    if index < 0 || index as usize >= symbols.len() {
+                        return Err(format!("invalid index: {index} of enum '{name}'"));
+                    }
+                    Ok(())
+                }
+                NamedType::Fixed { size, .. } => take(bytes, *size).map(|_| ()),
+            },
+        }
+    }
+
+    fn describe(&self, schema: &AvroSchema) -> String {
+        match schema {
+            AvroSchema::Null => "null".to_string(),
+            AvroSchema::Boolean => "boolean".to_string(),
+            AvroSchema::Int => "int".to_string(),
+            AvroSchema::Long => "long".to_string(),
+            AvroSchema::Float => "float".to_string(),
+            AvroSchema::Double => "double".to_string(),
+            AvroSchema::Bytes => "bytes".to_string(),
+            AvroSchema::String => "string".to_string(),
+            AvroSchema::Array(items) => format!("array of {}", self.describe(items)),
+            AvroSchema::Map(values) => format!("map of {}", self.describe(values)),
+            AvroSchema::Union(schemas) => format!(
+                "union of [{}]",
+                schemas
+                    .iter()
+                    .map(|schema| self.describe(schema))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            AvroSchema::Named(index) => {
+                let kind = match &self.types[*index] {
+                    NamedType::Record { .. } => "record",
+                    NamedType::Enum { .. } => "enum",
+                    NamedType::Fixed { .. } => "fixed",
+                };
+                format!("{kind} '{}'", self.types[*index].name())
+            }
+        }
+    }
+}
+
+pub fn parse(definition: &str) -> Result<AvroDefinition, Error> {
+    let value = serde_json::from_str::<Value>(definition)
+        .map_err(|error| invalid(format!("Avro schema is not a valid JSON: {error}")))?;
+    let mut parser = Parser::default();
+    let schema = parser.parse(&value, None).map_err(invalid)?;
+    Ok(AvroDefinition {
+        schema,
+        types: parser.types,
+    })
+}
+
+#[derive(Default)]
+struct Parser {
+    types: Vec<NamedType>,
+    names: HashMap<String, usize>,
+}
+
+impl Parser {
+    fn parse(&mut self, value: &Value, namespace: Option<&str>) -> Result<AvroSchema, String> {
+        match value {
+            Value::String(name) => self.parse_name(name, namespace),
+            Value::Array(values) => {
+                let mut schemas = Vec::with_capacity(values.len());
+                for value in values {
+                    let schema = self.parse(value, namespace)?;
+                    if matches!(schema, AvroSchema::Union(_)) {
+                        return Err("union can't immediately contain another union".to_string());
+                    }
+                    // Only the named types might be repeated in the union, as long as they have different names.
+                    let duplicated = match schema {
+                        AvroSchema::Named(_) => schemas.contains(&schema),
+                        _ => schemas.iter().any(|other| same_kind(other, &schema)),
+                    };
+                    if duplicated {
+                        return Err("union contains the duplicated type".to_string());
+                    }
+                    schemas.push(schema);
+                }
+                Ok(AvroSchema::Union(schemas))
+            }
+            Value::Object(object) => self.parse_object(object, namespace),
+            _ => Err(format!("invalid schema: {value}")),
+        }
+    }
+
+    fn parse_name(&self, name: &str, namespace: Option<&str>) -> Result<AvroSchema, String> {
+        let schema = match name {
+            "null" => AvroSchema::Null,
+            "boolean" => AvroSchema::Boolean,
+            "int" => AvroSchema::Int,
+            "long" => AvroSchema::Long,
+            "float" => AvroSchema::Float,
+            "double" => AvroSchema::Double,
+            "bytes" => AvroSchema::Bytes,
+            "string" => AvroSchema::String,
+            _ => {
+                let full_name = get_full_name(name, namespace);
+                let index = self
+                    .names
+                    .get(&full_name)
+                    .or_else(|| self.names.get(name))
+                    .ok_or_else(|| format!("unknown type '{name}'"))?;
+                AvroSchema::Named(*index)
+            }
+        };
+        Ok(schema)
+    }
+
+    fn parse_object(
+        &mut self,
+        object: &Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> Result<AvroSchema, String> {
+        let kind = match object.get("type") {
+            Some(Value::String(kind)) => kind.as_str(),
+            Some(value) => return self.parse(value, namespace),
+            None => return Err("schema is missing the 'type' attribute".to_string()),
+        };
+
+        match kind {
+            "array" => {
+                let items = object
+                    .get("items")
+                    .ok_or("array is missing the 'items' attribute")?;
+                Ok(AvroSchema::Array(Box::new(self.parse(items, namespace)?)))
+            }
+            "map" => {
+                let values = object
+                    .get("values")
+                    .ok_or("map is missing the 'values' attribute")?;
+                Ok(AvroSchema::Map(Box::new(self.parse(values, namespace)?)))
+            }
+            "record" | "error" | "enum" | "fixed" => self.parse_named(kind, object, namespace),
+            // The logical types are annotated with the other attributes of their underlying types.
+            _ => self.parse_name(kind, namespace),
+        }
+    }
+
+    fn parse_named(
+        &mut self,
+        kind: &str,
+        object: &Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> Result<AvroSchema, String> {
+        let name = get_string(object, "name")?.ok_or(format!("{kind} is missing the 'name'"))?;
+        let namespace = match get_string(object, "namespace")? {
+            Some(namespace) if !namespace.is_empty() => Some(namespace),
+            Some(_) => None,
+            None => namespace,
+        };
+        let full_name = get_full_name(name, namespace);
+        for part in full_name.split('.') {
+            validate_name(part)?;
+        }
+        if self.names.contains_key(&full_name) {
+            return Err(format!("type '{full_name}' is defined more than once"));
+        }
+
+        // The namespace of the type applies to the types defined within it, e.g. the record fields.
+        let namespace = full_name.rsplit_once('.').map(|(namespace, _)| namespace);
+        let aliases = get_strings(object, "aliases")?
+            .into_iter()
+            .map(|alias| get_full_name(alias, namespace))
+            .collect::<Vec<_>>();
+        let index = self.types.len();
+        let named_type = match kind {
+            "enum" => {
+                let symbols = get_strings(object, "symbols")?;
+                let mut unique_symbols = HashSet::new();
+                for symbol in &symbols {
+                    validate_name(symbol)?;
+                    if !unique_symbols.insert(symbol) {
+                        return Err(format!(
+                            "enum '{full_name}' has duplicated symbol '{symbol}'"
+                        ));
+                    }
+                }
+                let default = get_string(object, "default")?;
+                if let Some(default) = default {
+                    if !symbols.contains(&default) {
+                        return Err(format!(
+                            "default '{default}' of enum '{full_name}' is not its symbol"
+                        ));
+                    }
+                }
+                NamedType::Enum {
+                    name: full_name.clone(),
+                    aliases,
+                    symbols: symbols.into_iter().map(str::to_string).collect(),
+                    default: default.map(str::to_string),
+                }
+            }
+            "fixed" => {
+                let size = object
+                    .get("size")
+                    .and_then(Value::as_u64)
+                    .ok_or(format!("fixed '{full_name}' is missing the valid 'size'"))?;
+                NamedType::Fixed {
+                    name: full_name.clone(),
+                    aliases,
+                    size: size as usize,
+                }
+            }
+            _ => NamedType::Record {
+                name: full_name.clone(),
+                aliases,
+                fields: Vec::new(),
+            },
+        };
+
+        // The type is registered before its fields are parsed, so that they can reference it.
+        self.types.push(named_type);
+        self.names.insert(full_name.clone(), index);
+        if matches!(self.types[index], NamedType::Record { .. }) {
+            let fields = self.parse_fields(&full_name, object, namespace)?;
+            if let NamedType::Record {
+                fields: record_fields,
+                ..
+            } = &mut self.types[index]
+            {
+                *record_fields = fields;
+            }
+        }
+
+        Ok(AvroSchema::Named(index))
+    }
+
+    fn parse_fields(
+        &mut self,
+        record: &str,
+        object: &Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> Result<Vec<AvroField>, String> {
+        let values = match object.get("fields") {
+            Some(Value::Array(values)) => values,
+            _ => return Err(format!("record '{record}' is missing the 'fields' array")),
+        };
+
+        let mut fields: Vec<AvroField> = Vec::with_capacity(values.len());
+        for value in values {
+            let Value::Object(field) = value else {
+                return Err(format!("field of record '{record}' must be an object"));
+            };
+
+            let name = get_string(field, "name")?
+                .ok_or(format!("field of record '{record}' is missing the 'name'"))?;
+            validate_name(name)?;
+            if fields.iter().any(|field| field.name == name) {
+                return Err(format!("record '{record}' has duplicated field '{name}'"));
+            }
+
+            let schema = field.get("type").ok_or(format!(
+                "field '{name}' of record '{record}' is missing the 'type'"
+            ))?;
+            fields.push(AvroField {
+                name: name.to_string(),
+                aliases: get_strings(field, "aliases")?
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+                schema: self
+                    .parse(schema, namespace)
+                    .map_err(|reason| format!("{reason} in field '{name}' of record '{record}'"))?,
+                has_default: field.contains_key("default"),
+            });
+        }
+
+        Ok(fields)
+    }
+}
+
+/// Checks if the data encoded with the writer schema can be decoded with the reader schema, following the Avro schema resolution rules.
+/// The fields are matched by their names (or the aliases of the reader fields), so the added fields must have the default values.
+pub fn check_readable(reader: &AvroDefinition, writer: &AvroDefinition) -> Result<(), String> {
+    Resolver {
+        reader,
+        writer,
+        resolving: HashSet::new(),
+    }
+    .check(&reader.schema, &writer.schema)
+}
+
+struct Resolver<'a> {
+    reader: &'a AvroDefinition,
+    writer: &'a AvroDefinition,
+    // The pairs of the named types already being checked, so that the recursive types are checked only once.
+    resolving: HashSet<(usize, usize)>,
+}
+
+impl Resolver<'_> {
+    fn check(&mut self, reader: &AvroSchema, writer: &AvroSchema) -> Result<(), String> {
+        match (reader, writer) {
+            (_, AvroSchema::Union(writer_schemas)) => {
+                for writer_schema in writer_schemas {
+                    self.check(reader, writer_schema)?;
+                }
+                Ok(())
+            }
+            (AvroSchema::Union(reader_schemas), _) => {
+                if reader_schemas
+                    .iter()
+                    .any(|reader_schema| self.check(reader_schema, writer).is_ok())
+                {
+                    return Ok(());
+                }
+                Err(self.mismatch(reader, writer))
+            }
+            (AvroSchema::Array(reader_items), AvroSchema::Array(writer_items)) => {
+                self.check(reader_items, writer_items)
+            }
+            (AvroSchema::Map(reader_values), AvroSchema::Map(writer_values)) => {
+                self.check(reader_values, writer_values)
+            }
+            (AvroSchema::Named(reader_index), AvroSchema::Named(writer_index)) => {
+                let reader_type = &self.reader.types[*reader_index];
+                let writer_type = &self.writer.types[*writer_index];
+                if !same_named_type(reader_type, writer_type) {
+                    return Err(self.mismatch(reader, writer));
+                }
+
+                if !self.resolving.insert((*reader_index, *writer_index)) {
+                    return Ok(());
+                }
+                let result = self.check_named(reader_type, writer_type);
+                if result.is_err() {
+                    // The failed pair is checked again, if it's reached through another union branch.
+                    self.resolving.remove(&(*reader_index, *writer_index));
+                }
+                result
+            }
+            _ if reader == writer || is_promoted(reader, writer) => Ok(()),
+            _ => Err(self.mismatch(reader, writer)),
+        }
+    }
+
+    fn check_named(&mut self, reader: &NamedType, writer: &NamedType) -> Result<(), String> {
+        match (reader, writer) {
+            (
+                NamedType::Record {
+                    fields: reader_fields,
+                    ..
+                },
+                NamedType::Record {
+                    fields: writer_fields,
+                    ..
+                },
+            ) => {
+                for reader_field in reader_fields {
+                    let writer_field = writer_fields.iter().find(|writer_field| {
+                        writer_field.name == reader_field.name
+                            || reader_field.aliases.contains(&writer_field.name)
+                    });
+                    match writer_field {
+                        Some(writer_field) => self
+                            .check(&reader_field.schema, &writer_field.schema)
+                            .map_err(|reason| {
+                                format!(
+                                    "field '{}' of record '{}': {reason}",
+                                    reader_field.name,
+                                    reader.name()
+                                )
+                            })?,
+                        None if reader_field.has_default => {}
+                        None => {
+                            return Err(format!(
+                                "field '{}' of record '{}' has no default value for the reader, but is missing for the writer",
+                                reader_field.name,
+                                reader.name()
+                            ))
+                        }
+                    }
+                }
+                Ok(())
+            }
+            (
+                NamedType::Enum {
+                    symbols: reader_symbols,
+                    default,
+                    ..
+                },
+                NamedType::Enum {
+                    symbols: writer_symbols,
+                    ..
+                },
+            ) => {
+                if default.is_some() {
+                    return Ok(());
+                }
+                match writer_symbols
+                    .iter()
+                    .find(|symbol| !reader_symbols.contains(symbol))
+                {
+                    Some(symbol) => Err(format!(
+                        "symbol '{symbol}' of enum '{}' is missing for the reader, which has no default",
+                        reader.name()
+                    )),
+                    None => Ok(()),
+                }
+            }
+            (
+                NamedType::Fixed {
+                    size: reader_size, ..
+                },
+                NamedType::Fixed {
+                    size: writer_size, ..
+                },
+            ) if reader_size != writer_size => Err(format!(
+                "fixed '{}' has size: {reader_size} for the reader, but {writer_size} for the writer",
+                reader.name()
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn mismatch(&self, reader: &AvroSchema, writer: &AvroSchema) -> String {
+        format!(
+            "{} of the writer can't be read as {}",
+            self.writer.describe(writer),
+            self.reader.describe(reader)
+        )
+    }
+}
+
+/// The writer values of these types can be read as the wider reader types.
+fn is_promoted(reader: &AvroSchema, writer: &AvroSchema) -> bool {
+    matches!(
+        (writer, reader),
+        (
+            AvroSchema::Int,
+            AvroSchema::Long | AvroSchema::Float | AvroSchema::Double
+        ) | (AvroSchema::Long, AvroSchema::Float | AvroSchema::Double)
+            | (AvroSchema::Float, AvroSchema::Double)
+            | (AvroSchema::String, AvroSchema::Bytes)
+            | (AvroSchema::Bytes, AvroSchema::String)
+    )
+}
+
+fn same_kind(first: &AvroSchema, second: &AvroSchema) -> bool {
+    std::mem::discriminant(first) == std::mem::discriminant(second)
+}
+
+/// The named types are matched by their unqualified names or the aliases of the reader type.
+fn same_named_type(reader: &NamedType, writer: &NamedType) -> bool {
+    let writer_name = get_short_name(writer.name());
+    let names_match = get_short_name(reader.name()) == writer_name
+        || reader
+            .aliases()
+            .iter()
+            .any(|alias| get_short_name(alias) == writer_name);
+    names_match && std::mem::discriminant(reader) == std::mem::discriminant(writer)
+}
+
+fn get_full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) if !name.contains('.') => format!("{namespace}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+fn get_short_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn validate_name(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic() || char == '_')
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_');
+    if !valid {
+        return Err(format!("invalid name '{name}'"));
+    }
+
+    Ok(())
+}
+
+fn get_string<'a>(object: &'a Map<String, Value>, key: &str) -> Result<Option<&'a str>, String> {
+    match object.get(key) {
+        Some(Value::String(value)) => Ok(Some(value)),
+        Some(_) => Err(format!("'{key}' must be a string")),
+        None => Ok(None),
+    }
+}
+
+fn get_strings<'a>(object: &'a Map<String, Value>, key: &str) -> Result<Vec<&'a str>, String> {
+    match object.get(key) {
+        Some(Value::Array(values)) => values
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .ok_or(format!("'{key}' must contain strings"))
+            })
+            .collect(),
+        Some(_) => Err(format!("'{key}' must be an array")),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn take<'a>(bytes: &mut &'a [u8], length: usize) -> Result<&'a [u8], String> {
+    if bytes.len() < length {
+        return Err(format!(
+            "expected {length} bytes, but only {} are left",
+            bytes.len()
+        ));
+    }
+
+    let (taken, remaining) = bytes.split_at(length);
+    *bytes = remaining;
+    Ok(taken)
+}
+
+/// The arrays and maps are encoded as the blocks of items, each prefixed with the items count, until the empty block.
+fn skip_blocks(
+    bytes: &mut &[u8],
+    mut skip_item: impl FnMut(&mut &[u8]) -> Result<(), String>,
+) -> Result<(), String> {
+    loop {
+        let mut count = read_long(bytes)?;
+        if count == 0 {
+            return Ok(());
+        }
+
+        // The negative count is followed by the size of the block in bytes.
+        if count < 0 {
+            count = count.checked_neg().ok_or("invalid block count")?;
+            read_long(bytes)?;
+        }
+
+        let remaining = bytes.len();
+        skip_item(bytes)?;
+        if bytes.len() == remaining {
+            // The item of this type has no bytes (e.g. null), so the remaining ones don't have to be read.
+            continue;
+        }
+
+        // Each of the remaining items has at least one byte.
+        if count as u64 - 1 > bytes.len() as u64 {
+            return Err(format!("block of {count} items is truncated"));
+        }
+        for _ in 1..count {
+            skip_item(bytes)?;
+        }
+    }
+}
+
+/// Reads the zigzag encoded variable length long.
+fn read_long(bytes: &mut &[u8]) -> Result<i64, String> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = take(bytes, 1)?[0];
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+        }
+    }
+
+    Err("long is longer than 10 bytes".to_string())
+}
+
+fn read_bytes<'a>(bytes: &mut &'a [u8]) -> Result<&'a [u8], String> {
+    let length = read_long(bytes)?;
+    if length < 0 {
+        return Err(format!("invalid length: {length}"));
+    }
+
+    take(bytes, length as usize)
+}
+
+fn read_string<'a>(bytes: &mut &'a [u8]) -> Result<&'a str, String> {
+    std::str::from_utf8(read_bytes(bytes)?).map_err(|_| "string is not a valid UTF-8".to_string())
+}
+
+fn invalid(reason: String) -> Error {
+    Error::InvalidSchemaDefinition(reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: &str = r#"{
+        "type": "record",
+        "name": "User",
+        "namespace": "users",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": "string"},
+            {"name": "status", "type": {"type": "enum", "name": "Status", "symbols": ["ACTIVE", "INACTIVE"]}},
+            {"name": "tags", "type": {"type": "array", "items": "string"}},
+            {"name": "manager", "type": ["null", "User"], "default": null},
+            {"name": "created_at", "type": {"type": "long", "logicalType": "timestamp-micros"}}
+        ]
+    }"#;
+
+    #[test]
+    fn definition_should_be_parsed() {
+        let definition = parse(USER).unwrap();
+        assert_eq!(definition.types.len(), 2);
+        assert_eq!(definition.types[0].name(), "users.User");
+        assert_eq!(definition.types[1].name(), "users.Status");
+        assert_eq!(
+            definition.describe(&definition.schema),
+            "record 'users.User'"
+        );
+    }
+
+    #[test]
+    fn invalid_definition_should_not_be_parsed() {
+        assert!(parse("{").is_err());
+        assert!(parse(r#""unknown""#).is_err());
+        assert!(parse(r#"{"type": "record", "name": "Test"}"#).is_err());
+        assert!(parse(
+            r#"{"type": "record", "name": "Test", "fields": [{"name": "id", "type": "Unknown"}]}"#
+        )
+        .is_err());
+        assert!(parse(r#"{"type": "record", "name": "Test", "fields": [{"name": "id", "type": "int"}, {"name": "id", "type": "int"}]}"#).is_err());
+        assert!(parse(r#"{"type": "enum", "name": "Test", "symbols": ["A", "A"]}"#).is_err());
+        assert!(parse(r#"{"type": "fixed", "name": "1Test", "size": 4}"#).is_err());
+        assert!(parse(r#"["int", "int"]"#).is_err());
+        assert!(parse(r#"["null", ["int"]]"#).is_err());
+    }
+
+    #[test]
+    fn payload_should_be_validated_against_schema() {
+        let definition = parse(USER).unwrap();
+        // ID: 1, name: "test", status: INACTIVE, tags: ["a"], manager: {ID: 2, name: "", status: ACTIVE, tags: [], manager: null, created_at: 0}, created_at: 3.
+        let payload = b"\x02\x08test\x02\x02\x02a\x00\x02\x04\x00\x00\x00\x00\x00\x06";
+
+        assert!(definition.validate(payload).is_ok());
+        // The payload is truncated.
+        assert!(definition.validate(&payload[..payload.len() - 1]).is_err());
+        // The payload has the trailing bytes.
+        assert!(definition
+            .validate(&[payload.as_slice(), b"\x00"].concat())
+            .is_err());
+        // The enum index is out of range.
+        assert!(definition
+            .validate(b"\x02\x08test\x04\x00\x00\x06")
+            .is_err());
+        // The union index is out of range.
+        assert!(definition
+            .validate(b"\x02\x08test\x02\x00\x04\x06")
+            .is_err());
+        // The array of the huge number of items is not read item by item.
+        let definition = parse(r#"{"type": "array", "items": "null"}"#).unwrap();
+        assert!(definition
+            .validate(b"\xfe\xff\xff\xff\xff\xff\xff\xff\xff\x01\x00")
+            .is_ok());
+        let definition = parse(r#"{"type": "array", "items": "int"}"#).unwrap();
+        assert!(definition
+            .validate(b"\xfe\xff\xff\xff\xff\xff\xff\xff\xff\x01\x02\x00")
+            .is_err());
+    }
+
+    #[test]
+    fn added_field_with_default_and_promoted_type_should_be_readable() {
+        let writer = parse(
+            r#"{"type": "record", "name": "Test", "fields": [{"name": "id", "type": "int"}]}"#,
+        )
+        .unwrap();
+        let reader = parse(
+            r#"{"type": "record", "name": "Test", "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": "string", "default": ""}]}"#,
+        )
+        .unwrap();
+
+        assert!(check_readable(&reader, &writer).is_ok());
+        // The long ID can't be read as the int one.
+        assert!(check_readable(&writer, &reader).is_err());
+    }
+
+    #[test]
+    fn added_field_without_default_or_removed_enum_symbol_should_not_be_readable() {
+        let writer = parse(USER).unwrap();
+        let added_field = USER.replace(
+            r#"{"name": "id", "type": "long"},"#,
+            r#"{"name": "id", "type": "long"}, {"name": "email", "type": "string"},"#,
+        );
+        let removed_symbol = USER.replace(r#"["ACTIVE", "INACTIVE"]"#, r#"["ACTIVE"]"#);
+
+        assert!(check_readable(&parse(&added_field).unwrap(), &writer).is_err());
+        assert!(check_readable(&parse(&removed_symbol).unwrap(), &writer).is_err());
+        assert!(check_readable(&writer, &parse(&removed_symbol).unwrap()).is_ok());
+        assert!(check_readable(&writer, &writer).is_ok());
+    }
+}
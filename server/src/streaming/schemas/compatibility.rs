@@ -0,0 +1,91 @@
+use crate::streaming::schemas::schema::{SchemaValidator, TopicSchema};
+use crate::streaming::schemas::{avro, json_schema, protobuf};
+use iggy::error::Error;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+
+/// Checks if the new schema can be registered after the latest one for the given compatibility mode.
+/// The backward compatibility means that the new schema can read the data written with the latest one,
+/// while the forward compatibility means that the latest schema can read the data written with the new one.
+pub fn check(
+    compatibility: SchemaCompatibility,
+    latest: &TopicSchema,
+    new: &TopicSchema,
+) -> Result<(), Error> {
+    if compatibility == SchemaCompatibility::None {
+        return Ok(());
+    }
+
+    if latest.format != new.format {
+        return Err(Error::IncompatibleSchema(format!(
+            "format '{}' differs from '{}' of schema with ID: {}",
+            new.format, latest.format, latest.id
+        )));
+    }
+
+    if compatibility.is_backward() {
+        check_readable(new, latest)
+            .map_err(|reason| Error::IncompatibleSchema(format!("backward: {reason}")))?;
+    }
+
+    if compatibility.is_forward() {
+        check_readable(latest, new)
+            .map_err(|reason| Error::IncompatibleSchema(format!("forward: {reason}")))?;
+    }
+
+    Ok(())
+}
+
+fn check_readable(reader: &TopicSchema, writer: &TopicSchema) -> Result<(), String> {
+    match (reader.get_validator(), writer.get_validator()) {
+        (SchemaValidator::JsonSchema(_), SchemaValidator::JsonSchema(_)) => {
+            let parse_error = |error: Error| error.to_string();
+            json_schema::check_readable(
+                &json_schema::parse(&reader.definition).map_err(parse_error)?,
+                &json_schema::parse(&writer.definition).map_err(parse_error)?,
+            )
+        }
+        (SchemaValidator::Protobuf(reader), SchemaValidator::Protobuf(writer)) => {
+            protobuf::check_readable(reader, writer)
+        }
+        (SchemaValidator::Avro(reader), SchemaValidator::Avro(writer)) => {
+            avro::check_readable(reader, writer)
+        }
+        _ => Err("schemas have different formats".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::schemas::schema_format::SchemaFormat;
+
+    const V1: &str =
+        r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#;
+    const V2: &str = r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"required":["id","name"]}"#;
+
+    #[test]
+    fn compatibility_mode_should_be_applied() {
+        let latest = TopicSchema::new(1, SchemaFormat::JsonSchema, V1, 0).unwrap();
+        let new = TopicSchema::new(2, SchemaFormat::JsonSchema, V2, 0).unwrap();
+
+        assert!(check(SchemaCompatibility::None, &latest, &new).is_ok());
+        assert!(check(SchemaCompatibility::Forward, &latest, &new).is_ok());
+        assert!(check(SchemaCompatibility::Backward, &latest, &new).is_err());
+        assert!(check(SchemaCompatibility::Full, &latest, &new).is_err());
+    }
+
+    #[test]
+    fn different_format_should_be_accepted_only_without_compatibility() {
+        let latest = TopicSchema::new(1, SchemaFormat::JsonSchema, V1, 0).unwrap();
+        let new = TopicSchema::new(
+            2,
+            SchemaFormat::Protobuf,
+            "syntax = \"proto3\"; message User { uint64 id = 1; }",
+            0,
+        )
+        .unwrap();
+
+        assert!(check(SchemaCompatibility::None, &latest, &new).is_ok());
+        assert!(check(SchemaCompatibility::Backward, &latest, &new).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use iggy::error::Error;
+use jsonschema::JSONSchema;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The top-level object properties of the JSON Schema, which are taken into account by the compatibility checks.
+#[derive(Debug)]
+pub struct JsonObjectSchema {
+    properties: BTreeMap<String, Value>,
+    required: BTreeSet<String>,
+    additional_properties: bool,
+}
+
+pub fn compile(definition: &str) -> Result<JSONSchema, Error> {
+    let definition = parse_value(definition)?;
+    JSONSchema::compile(&definition)
+        .map_err(|error| Error::InvalidSchemaDefinition(error.to_string()))
+}
+
+pub fn parse(definition: &str) -> Result<JsonObjectSchema, Error> {
+    let definition = parse_value(definition)?;
+    let properties = match definition.get("properties") {
+        Some(Value::Object(properties)) => properties
+            .iter()
+            .map(|(name, property)| (name.clone(), property.clone()))
+            .collect(),
+        Some(_) => {
+            return Err(Error::InvalidSchemaDefinition(
+                "'properties' must be an object".to_string(),
+            ))
+        }
+        None => BTreeMap::new(),
+    };
+    let required = match definition.get("required") {
+        Some(Value::Array(required)) => required
+            .iter()
+            .map(|name| match name {
+                Value::String(name) => Ok(name.clone()),
+                _ => Err(Error::InvalidSchemaDefinition(
+                    "'required' must contain only strings".to_string(),
+                )),
+            })
+            .collect::<Result<BTreeSet<String>, Error>>()?,
+        Some(_) => {
+            return Err(Error::InvalidSchemaDefinition(
+                "'required' must be an array".to_string(),
+            ))
+        }
+        None => BTreeSet::new(),
+    };
+    let additional_properties = !matches!(
+        definition.get("additionalProperties"),
+        Some(Value::Bool(false))
+    );
+
+    Ok(JsonObjectSchema {
+        properties,
+        required,
+        additional_properties,
+    })
+}
+
+/// Checks if every document valid for the writer schema is also valid for the reader schema.
+pub fn check_readable(reader: &JsonObjectSchema, writer: &JsonObjectSchema) -> Result<(), String> {
+    for name in &reader.required {
+        if !writer.required.contains(name) {
+            return Err(format!(
+                "property '{name}' is required by the reader, but optional for the writer"
+            ));
+        }
+    }
+
+    for (name, reader_property) in &reader.properties {
+        let Some(writer_property) = writer.properties.get(name) else {
+            continue;
+        };
+        let reader_type = reader_property.get("type");
+        let writer_type = writer_property.get("type");
+        if reader_type.is_some() && writer_type.is_some() && reader_type != writer_type {
+            return Err(format!(
+                "property '{name}' has a different type for the reader and the writer"
+            ));
+        }
+    }
+
+    if reader.additional_properties {
+        return Ok(());
+    }
+
+    if writer.additional_properties {
+        return Err(
+            "additional properties are allowed for the writer, but not for the reader".to_string(),
+        );
+    }
+
+    for name in writer.properties.keys() {
+        if !reader.properties.contains_key(name) {
+            return Err(format!(
+                "property '{name}' is unknown for the reader, which doesn't allow additional properties"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_value(definition: &str) -> Result<Value, Error> {
+    let definition = serde_json::from_str::<Value>(definition)
+        .map_err(|error| Error::InvalidSchemaDefinition(error.to_string()))?;
+    if !definition.is_object() {
+        return Err(Error::InvalidSchemaDefinition(
+            "JSON Schema must be an object".to_string(),
+        ));
+    }
+
+    Ok(definition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optional_property_should_be_readable() {
+        let writer = parse(r#"{"type":"object","properties":{"id":{"type":"integer"}}}"#).unwrap();
+        let reader = parse(
+            r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}}}"#,
+        )
+        .unwrap();
+
+        assert!(check_readable(&reader, &writer).is_ok());
+        assert!(check_readable(&writer, &reader).is_ok());
+    }
+
+    #[test]
+    fn new_required_property_should_not_be_readable() {
+        let writer = parse(r#"{"type":"object","properties":{"id":{"type":"integer"}}}"#).unwrap();
+        let reader =
+            parse(r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#)
+                .unwrap();
+
+        assert!(check_readable(&reader, &writer).is_err());
+        assert!(check_readable(&writer, &reader).is_ok());
+    }
+
+    #[test]
+    fn changed_property_type_should_not_be_readable() {
+        let writer = parse(r#"{"type":"object","properties":{"id":{"type":"integer"}}}"#).unwrap();
+        let reader = parse(r#"{"type":"object","properties":{"id":{"type":"string"}}}"#).unwrap();
+
+        assert!(check_readable(&reader, &writer).is_err());
+    }
+
+    #[test]
+    fn unknown_property_should_not_be_readable_when_additional_properties_are_not_allowed() {
+        let writer = parse(
+            r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"additionalProperties":false}"#,
+        )
+        .unwrap();
+        let reader = parse(
+            r#"{"type":"object","properties":{"id":{"type":"integer"}},"additionalProperties":false}"#,
+        )
+        .unwrap();
+
+        assert!(check_readable(&reader, &writer).is_err());
+        assert!(check_readable(&writer, &reader).is_ok());
+    }
+}
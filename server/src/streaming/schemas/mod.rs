@@ -0,0 +1,5 @@
+pub mod avro;
+pub mod compatibility;
+pub mod json_schema;
+pub mod protobuf;
+pub mod schema;
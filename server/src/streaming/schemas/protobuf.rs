@@ -0,0 +1,280 @@
+use iggy::error::Error;
+use protobuf::descriptor::field_descriptor_proto::Label;
+use protobuf::reflect::{FileDescriptor, MessageDescriptor};
+use protobuf::well_known_types::{
+    any, api, duration, empty, field_mask, source_context, struct_, timestamp, type_, wrappers,
+};
+use protobuf_parse::pure::parse_dependencies;
+use protobuf_parse::Parser;
+use std::collections::BTreeMap;
+use std::fs;
+
+const FILE_NAME: &str = "schema.proto";
+
+/// The Protobuf definition parsed and type checked by the `protobuf-parse` crate.
+/// The payloads are validated against the first message declared in the definition.
+#[derive(Debug, Clone)]
+pub struct ProtobufDefinition {
+    file: FileDescriptor,
+    message: MessageDescriptor,
+}
+
+impl ProtobufDefinition {
+    /// Checks if the payload can be decoded as the message, including its required fields.
+    pub fn validate(&self, payload: &[u8]) -> Result<(), String> {
+        let message = self.message.parse_from_bytes(payload).map_err(|error| {
+            format!(
+                "payload is not a valid '{}' message: {error}",
+                self.message.full_name()
+            )
+        })?;
+        if !message.is_initialized_dyn() {
+            return Err(format!(
+                "payload is missing the required fields of '{}' message",
+                self.message.full_name()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the fields of all the messages, including the nested ones, identified by the full message name and the field number.
+    fn get_fields(&self) -> BTreeMap<String, BTreeMap<i32, ProtobufField>> {
+        let mut messages = BTreeMap::new();
+        let mut descriptors = self.file.messages().collect::<Vec<_>>();
+        while let Some(descriptor) = descriptors.pop() {
+            let fields = descriptor
+                .fields()
+                .map(|field| {
+                    let proto = field.proto();
+                    let mut kind = match proto.type_name() {
+                        "" => format!("{:?}", proto.type_()),
+                        type_name => type_name.trim_start_matches('.').to_string(),
+                    };
+                    // The repeated field is treated as a different type than the singular one.
+                    if proto.label() == Label::LABEL_REPEATED {
+                        kind = format!("repeated {kind}");
+                    }
+                    (
+                        field.number(),
+                        ProtobufField {
+                            name: field.name().to_string(),
+                            kind,
+                            required: field.is_required(),
+                        },
+                    )
+                })
+                .collect();
+            messages.insert(descriptor.full_name().to_string(), fields);
+            descriptors.extend(descriptor.nested_messages());
+        }
+        messages
+    }
+}
+
+struct ProtobufField {
+    name: String,
+    kind: String,
+    required: bool,
+}
+
+pub fn parse(definition: &str) -> Result<ProtobufDefinition, Error> {
+    let well_known_types = [
+        any::file_descriptor(),
+        api::file_descriptor(),
+        duration::file_descriptor(),
+        empty::file_descriptor(),
+        field_mask::file_descriptor(),
+        source_context::file_descriptor(),
+        struct_::file_descriptor(),
+        timestamp::file_descriptor(),
+        type_::file_descriptor(),
+        wrappers::file_descriptor(),
+    ]
+    .map(|file| file.clone());
+    // Only the well-known types can be imported, so the parser never reads any other file than the definition itself.
+    let dependencies = parse_dependencies(definition)
+        .map_err(|error| invalid(format!("{error:#}")))?
+        .dependency;
+    if let Some(dependency) = dependencies.iter().find(|dependency| {
+        !well_known_types
+            .iter()
+            .any(|file| file.name() == dependency.as_str())
+    }) {
+        return Err(invalid(format!(
+            "import '{dependency}' is not supported, only the well-known types can be imported"
+        )));
+    }
+
+    // The parser reads the definitions from the files, so that the imports can be resolved.
+    let directory = tempfile::tempdir().map_err(|error| invalid(error.to_string()))?;
+    let path = directory.path().join(FILE_NAME);
+    fs::write(&path, definition).map_err(|error| invalid(error.to_string()))?;
+    let descriptors = Parser::new()
+        .pure()
+        .include(directory.path())
+        .input(&path)
+        .file_descriptor_set()
+        .map_err(|error| invalid(format!("{error:#}")))?
+        .file;
+    // The well-known types, e.g. `google/protobuf/timestamp.proto`, are resolved from the ones built into the crate.
+    let descriptors = descriptors
+        .into_iter()
+        .filter(|descriptor| {
+            !well_known_types
+                .iter()
+                .any(|file| file.name() == descriptor.name())
+        })
+        .collect();
+    let file = FileDescriptor::new_dynamic_fds(descriptors, &well_known_types)
+        .map_err(|error| invalid(error.to_string()))?
+        .into_iter()
+        .find(|file| file.name() == FILE_NAME)
+        .ok_or_else(|| invalid(format!("file '{FILE_NAME}' was not parsed")))?;
+    let Some(message) = file.messages().next() else {
+        return Err(invalid(
+            "Protobuf definition must contain at least one message".to_string(),
+        ));
+    };
+
+    Ok(ProtobufDefinition { file, message })
+}
+
+/// Checks if the messages encoded with the writer definition can be decoded with the reader definition.
+/// The fields are matched by their numbers, so renaming the field is allowed, while changing its type is not.
+pub fn check_readable(
+    reader: &ProtobufDefinition,
+    writer: &ProtobufDefinition,
+) -> Result<(), String> {
+    let writer_messages = writer.get_fields();
+    for (message, reader_fields) in reader.get_fields() {
+        let Some(writer_fields) = writer_messages.get(&message) else {
+            continue;
+        };
+
+        for (number, reader_field) in reader_fields {
+            match writer_fields.get(&number) {
+                Some(writer_field) => {
+                    if reader_field.kind != writer_field.kind {
+                        return Err(format!(
+                            "field '{}' with number {number} in message '{message}' has a different type for the reader and the writer",
+                            reader_field.name
+                        ));
+                    }
+                }
+                None => {
+                    if reader_field.required {
+                        return Err(format!(
+                            "field '{}' with number {number} in message '{message}' is required by the reader, but missing for the writer",
+                            reader_field.name
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn invalid(reason: String) -> Error {
+    Error::InvalidSchemaDefinition(reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFINITION: &str = r#"
+        syntax = "proto3";
+        package orders;
+
+        import "google/protobuf/timestamp.proto";
+
+        // The order placed by the customer.
+        message Order {
+            uint64 id = 1;
+            repeated Item items = 2;
+            map<string, string> metadata = 3 [deprecated = true];
+            oneof payment {
+                string card = 4;
+                string voucher = 5;
+            }
+            enum Status { NEW = 0; PAID = 1; }
+            Status status = 6;
+            google.protobuf.Timestamp created_at = 7;
+
+            message Item {
+                string name = 1;
+                uint32 quantity = 2;
+            }
+        }
+    "#;
+
+    #[test]
+    fn definition_should_be_parsed() {
+        let definition = parse(DEFINITION).unwrap();
+        assert_eq!(definition.message.full_name(), "orders.Order");
+        let messages = definition.get_fields();
+        let order = messages.get("orders.Order").unwrap();
+        assert_eq!(order.len(), 7);
+        assert_eq!(order.get(&2).unwrap().kind, "repeated orders.Order.Item");
+        assert_eq!(
+            order.get(&3).unwrap().kind,
+            "repeated orders.Order.MetadataEntry"
+        );
+        assert_eq!(order.get(&5).unwrap().name, "voucher");
+        assert_eq!(order.get(&7).unwrap().kind, "google.protobuf.Timestamp");
+        let item = messages.get("orders.Order.Item").unwrap();
+        assert_eq!(item.get(&2).unwrap().kind, "TYPE_UINT32");
+    }
+
+    #[test]
+    fn invalid_definition_should_not_be_parsed() {
+        assert!(parse("syntax = \"proto3\";").is_err());
+        assert!(parse("message Test { string name = 0; }").is_err());
+        assert!(parse("message Test { string name = 1; string other = 1; }").is_err());
+        assert!(parse("message Test { string name = 1;").is_err());
+        assert!(parse("message Test { Unknown name = 1; }").is_err());
+        assert!(parse("import \"../../other.proto\"; message Test {}").is_err());
+        assert!(parse("import \"/etc/other.proto\"; message Test {}").is_err());
+    }
+
+    #[test]
+    fn payload_should_be_validated_against_first_message() {
+        let definition =
+            parse("syntax = \"proto2\"; message Test { required string name = 1; optional uint32 count = 2; }")
+                .unwrap();
+
+        assert!(definition.validate(b"\x0a\x04test\x10\x02").is_ok());
+        // The required name field is missing.
+        assert!(definition.validate(b"\x10\x02").is_err());
+        // The name field is not a valid UTF-8 string.
+        assert!(definition.validate(b"\x0a\x02\xff\xfe").is_err());
+        // The length of the name field exceeds the payload.
+        assert!(definition.validate(b"\x0a\x10test").is_err());
+    }
+
+    #[test]
+    fn renamed_and_added_fields_should_be_readable() {
+        let writer = parse("message Test { optional string name = 1; }").unwrap();
+        let reader =
+            parse("message Test { optional string title = 1; optional uint32 count = 2; }")
+                .unwrap();
+
+        assert!(check_readable(&reader, &writer).is_ok());
+        assert!(check_readable(&writer, &reader).is_ok());
+    }
+
+    #[test]
+    fn changed_field_type_or_new_required_field_should_not_be_readable() {
+        let writer = parse("message Test { optional string name = 1; }").unwrap();
+        let changed = parse("message Test { optional uint64 name = 1; }").unwrap();
+        let required =
+            parse("message Test { optional string name = 1; required uint32 count = 2; }").unwrap();
+
+        assert!(check_readable(&changed, &writer).is_err());
+        assert!(check_readable(&required, &writer).is_err());
+        assert!(check_readable(&writer, &required).is_ok());
+    }
+}
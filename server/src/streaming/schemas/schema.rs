@@ -0,0 +1,174 @@
+use crate::streaming::schemas::avro::AvroDefinition;
+use crate::streaming::schemas::protobuf::ProtobufDefinition;
+use crate::streaming::schemas::{avro, json_schema, protobuf};
+use iggy::error::Error;
+use iggy::schemas::schema_format::SchemaFormat;
+use jsonschema::JSONSchema;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct TopicSchema {
+    pub id: u32,
+    pub format: SchemaFormat,
+    pub definition: String,
+    pub created_at: u64,
+    validator: SchemaValidator,
+}
+
+#[derive(Debug)]
+pub(crate) enum SchemaValidator {
+    JsonSchema(Arc<JSONSchema>),
+    Protobuf(ProtobufDefinition),
+    Avro(AvroDefinition),
+}
+
+/// The schema definition parsed before its ID is assigned, so that it can be parsed without holding any lock.
+#[derive(Debug)]
+pub struct SchemaDefinition {
+    pub format: SchemaFormat,
+    pub definition: String,
+    validator: SchemaValidator,
+}
+
+impl SchemaDefinition {
+    /// Makes sure the definition can be parsed and builds the validator of the payloads upfront.
+    pub fn parse(format: SchemaFormat, definition: &str) -> Result<Self, Error> {
+        let validator = match format {
+            SchemaFormat::JsonSchema => {
+                SchemaValidator::JsonSchema(Arc::new(json_schema::compile(definition)?))
+            }
+            SchemaFormat::Protobuf => SchemaValidator::Protobuf(protobuf::parse(definition)?),
+            SchemaFormat::Avro => SchemaValidator::Avro(avro::parse(definition)?),
+        };
+
+        Ok(SchemaDefinition {
+            format,
+            definition: definition.to_string(),
+            validator,
+        })
+    }
+
+    /// Parsing the Protobuf definition writes and reads the files, so it's done on the thread dedicated to the blocking operations.
+    pub async fn parse_blocking(format: SchemaFormat, definition: &str) -> Result<Self, Error> {
+        let definition = definition.to_string();
+        tokio::task::spawn_blocking(move || Self::parse(format, &definition))
+            .await
+            .map_err(|error| {
+                Error::InvalidSchemaDefinition(format!("parsing has failed: {error}"))
+            })?
+    }
+}
+
+impl TopicSchema {
+    pub fn new(
+        id: u32,
+        format: SchemaFormat,
+        definition: &str,
+        created_at: u64,
+    ) -> Result<Self, Error> {
+        Ok(Self::from_definition(
+            id,
+            SchemaDefinition::parse(format, definition)?,
+            created_at,
+        ))
+    }
+
+    pub fn from_definition(id: u32, definition: SchemaDefinition, created_at: u64) -> Self {
+        TopicSchema {
+            id,
+            format: definition.format,
+            definition: definition.definition,
+            created_at,
+            validator: definition.validator,
+        }
+    }
+
+    pub(crate) fn get_validator(&self) -> &SchemaValidator {
+        &self.validator
+    }
+
+    pub fn validate_payload(&self, payload: &[u8]) -> Result<(), Error> {
+        let validator = match &self.validator {
+            SchemaValidator::JsonSchema(validator) => validator,
+            SchemaValidator::Protobuf(definition) => {
+                return definition
+                    .validate(payload)
+                    .map_err(|reason| Error::InvalidSchemaPayload(self.id, reason));
+            }
+            SchemaValidator::Avro(definition) => {
+                return definition
+                    .validate(payload)
+                    .map_err(|reason| Error::InvalidSchemaPayload(self.id, reason));
+            }
+        };
+
+        let payload = serde_json::from_slice(payload).map_err(|error| {
+            Error::InvalidSchemaPayload(self.id, format!("payload is not a valid JSON: {error}"))
+        })?;
+        if let Err(mut errors) = validator.validate(&payload) {
+            let reason = match errors.next() {
+                Some(error) => format!("{error} at '{}'", error.instance_path),
+                None => "payload is invalid".to_string(),
+            };
+            return Err(Error::InvalidSchemaPayload(self.id, reason));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_payload_should_be_validated_against_schema() {
+        let schema = TopicSchema::new(
+            1,
+            SchemaFormat::JsonSchema,
+            r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#,
+            0,
+        )
+        .unwrap();
+
+        assert!(schema.validate_payload(br#"{"id":1}"#).is_ok());
+        assert!(schema.validate_payload(br#"{"id":"1"}"#).is_err());
+        assert!(schema.validate_payload(br#"{"name":"test"}"#).is_err());
+        assert!(schema.validate_payload(b"not json").is_err());
+    }
+
+    #[test]
+    fn protobuf_payload_should_be_validated_against_schema() {
+        let schema = TopicSchema::new(
+            1,
+            SchemaFormat::Protobuf,
+            "syntax = \"proto3\"; message Test { string name = 1; }",
+            0,
+        )
+        .unwrap();
+
+        assert!(schema.validate_payload(b"\x0a\x04test").is_ok());
+        assert!(schema.validate_payload(b"\x0a\x10test").is_err());
+    }
+
+    #[test]
+    fn avro_payload_should_be_validated_against_schema() {
+        let schema = TopicSchema::new(
+            1,
+            SchemaFormat::Avro,
+            r#"{"type":"record","name":"User","fields":[{"name":"name","type":"string"}]}"#,
+            0,
+        )
+        .unwrap();
+
+        assert!(schema.validate_payload(b"\x08test").is_ok());
+        assert!(schema.validate_payload(b"\x10test").is_err());
+    }
+
+    #[test]
+    fn schema_should_not_be_created_given_invalid_definition() {
+        assert!(TopicSchema::new(1, SchemaFormat::JsonSchema, "{", 0).is_err());
+        assert!(TopicSchema::new(1, SchemaFormat::Protobuf, "message Test {", 0).is_err());
+        assert!(TopicSchema::new(1, SchemaFormat::Avro, r#"{"type":"record"}"#, 0).is_err());
+    }
+}
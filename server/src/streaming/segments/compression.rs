@@ -0,0 +1,113 @@
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::Error;
+use tracing::error;
+
+// The header is written only for the compressed segments, the uncompressed ones keep the original format.
+pub const SEGMENT_HEADER_MAGIC: &[u8; 8] = b"IGGYSEGC";
+pub const SEGMENT_HEADER_SIZE: u32 = 9;
+// Compressed length + uncompressed length.
+pub const BATCH_HEADER_SIZE: u32 = 8;
+
+pub fn get_segment_header(compression_algorithm: CompressionAlgorithm) -> Vec<u8> {
+    let mut header = Vec::with_capacity(SEGMENT_HEADER_SIZE as usize);
+    header.extend(SEGMENT_HEADER_MAGIC);
+    header.push(compression_algorithm.as_code());
+    header
+}
+
+pub fn read_segment_header(header: &[u8]) -> Result<Option<CompressionAlgorithm>, Error> {
+    if header.len() < SEGMENT_HEADER_SIZE as usize || &header[..8] != SEGMENT_HEADER_MAGIC {
+        return Ok(None);
+    }
+
+    Ok(Some(CompressionAlgorithm::from_code(header[8])?))
+}
+
+pub fn compress(
+    compression_algorithm: CompressionAlgorithm,
+    bytes: &[u8],
+) -> Result<Vec<u8>, Error> {
+    match compression_algorithm {
+        CompressionAlgorithm::None => Ok(bytes.to_vec()),
+        CompressionAlgorithm::Lz4 => Ok(lz4_flex::block::compress(bytes)),
+        CompressionAlgorithm::Zstd => zstd::bulk::compress(bytes, zstd::DEFAULT_COMPRESSION_LEVEL)
+            .map_err(|error| {
+                error!("Cannot compress messages using zstd: {}", error);
+                Error::CannotCompressMessages
+            }),
+    }
+}
+
+pub fn decompress(
+    compression_algorithm: CompressionAlgorithm,
+    bytes: &[u8],
+    uncompressed_length: usize,
+) -> Result<Vec<u8>, Error> {
+    let decompressed = match compression_algorithm {
+        CompressionAlgorithm::None => Ok(bytes.to_vec()),
+        CompressionAlgorithm::Lz4 => lz4_flex::block::decompress(bytes, uncompressed_length)
+            .map_err(|error| {
+                error!("Cannot decompress messages using lz4: {}", error);
+                Error::CannotDecompressMessages
+            }),
+        CompressionAlgorithm::Zstd => {
+            zstd::bulk::decompress(bytes, uncompressed_length).map_err(|error| {
+                error!("Cannot decompress messages using zstd: {}", error);
+                Error::CannotDecompressMessages
+            })
+        }
+    }?;
+
+    if decompressed.len() != uncompressed_length {
+        error!(
+            "Decompressed messages length: {} is different than expected: {}.",
+            decompressed.len(),
+            uncompressed_length
+        );
+        return Err(Error::CannotDecompressMessages);
+    }
+
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed_bytes_should_be_decompressed() {
+        let bytes = "test message ".repeat(100).into_bytes();
+        for compression_algorithm in [
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Lz4,
+            CompressionAlgorithm::Zstd,
+        ] {
+            let compressed = compress(compression_algorithm, &bytes).unwrap();
+            if compression_algorithm != CompressionAlgorithm::None {
+                assert!(compressed.len() < bytes.len());
+            }
+
+            let decompressed = decompress(compression_algorithm, &compressed, bytes.len()).unwrap();
+            assert_eq!(decompressed, bytes);
+        }
+    }
+
+    #[test]
+    fn segment_header_should_be_read() {
+        let header = get_segment_header(CompressionAlgorithm::Zstd);
+        assert_eq!(header.len() as u32, SEGMENT_HEADER_SIZE);
+        assert_eq!(
+            read_segment_header(&header).unwrap(),
+            Some(CompressionAlgorithm::Zstd)
+        );
+    }
+
+    #[test]
+    fn segment_without_header_should_be_treated_as_uncompressed() {
+        let mut bytes = Vec::new();
+        bytes.extend(0u64.to_le_bytes());
+        bytes.push(1);
+        assert_eq!(read_segment_header(&bytes).unwrap(), None);
+        assert_eq!(read_segment_header(&[]).unwrap(), None);
+    }
+}
@@ -2,6 +2,7 @@ use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::time_index::TimeIndex;
 use crate::streaming::storage::SegmentStorage;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::models::messages::Message;
 use std::sync::Arc;
@@ -249,6 +250,11 @@ impl Segment {
             }
         }
 
+        self.current_uncompressed_size_bytes += messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<u32>();
+
         Ok(())
     }
 
@@ -272,16 +278,32 @@ impl Segment {
             self.partition_id
         );
 
+        let unsaved_messages_count = unsaved_messages.len();
+        let unsaved_size_bytes = unsaved_messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<u32>();
+        let current_position = self.current_size_bytes - unsaved_size_bytes;
         let saved_bytes = storage.save_messages(self, unsaved_messages).await?;
-        let current_position = self.current_size_bytes - saved_bytes;
         storage
             .save_index(self, current_position, unsaved_messages)
             .await?;
         storage.save_time_index(self, unsaved_messages).await?;
+        storage.save_delayed_index(self, unsaved_messages).await?;
+
+        if self.compression_algorithm != CompressionAlgorithm::None {
+            // The messages were written as a single compressed batch, so they all share its position.
+            self.current_size_bytes = current_position + saved_bytes;
+            if let Some(indexes) = self.indexes.as_mut() {
+                for index in indexes.iter_mut().rev().take(unsaved_messages_count) {
+                    index.position = current_position;
+                }
+            }
+        }
 
         trace!(
             "Saved {} messages on disk in segment with start offset: {} for partition with ID: {}, total bytes written: {}.",
-            unsaved_messages.len(),
+            unsaved_messages_count,
             self.start_offset,
             self.partition_id,
             saved_bytes
@@ -297,4 +319,75 @@ impl Segment {
 
         Ok(())
     }
+
+    /// Removes the messages with the offsets greater than or equal to the given one, which must be greater than
+    /// the start offset of the segment. The segment is reopened for the appends. Returns the removed messages.
+    pub async fn truncate_messages(&mut self, offset: u64) -> Result<Vec<Arc<Message>>, Error> {
+        self.persist_messages(self.storage.segment.clone()).await?;
+        let (retained_messages, removed_messages): (Vec<_>, Vec<_>) = self
+            .get_all_messages()
+            .await?
+            .into_iter()
+            .partition(|message| message.offset < offset);
+        trace!(
+            "Truncating segment with start offset: {} for partition with ID: {} at offset: {}, removed messages: {}.",
+            self.start_offset,
+            self.partition_id,
+            offset,
+            removed_messages.len()
+        );
+
+        self.current_offset = offset - 1;
+        self.end_offset = 0;
+        self.is_closed = false;
+        self.unsaved_messages = None;
+        let size_bytes = self
+            .storage
+            .segment
+            .save_truncated_messages(self, &retained_messages)
+            .await?;
+        self.update_rewritten_messages(size_bytes, &retained_messages)
+            .await?;
+        Ok(removed_messages)
+    }
+
+    pub async fn save_compacted_messages(
+        &mut self,
+        messages: &[Arc<Message>],
+    ) -> Result<(), Error> {
+        trace!(
+            "Saving {} compacted messages on disk in segment with start offset: {} for partition with ID: {}...",
+            messages.len(),
+            self.start_offset,
+            self.partition_id
+        );
+
+        let size_bytes = self
+            .storage
+            .segment
+            .save_compacted_messages(self, messages)
+            .await?;
+        self.update_rewritten_messages(size_bytes, messages).await
+    }
+
+    async fn update_rewritten_messages(
+        &mut self,
+        size_bytes: u32,
+        messages: &[Arc<Message>],
+    ) -> Result<(), Error> {
+        self.current_size_bytes = size_bytes;
+        self.is_uploaded = false;
+        self.current_uncompressed_size_bytes = messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum::<u32>();
+        if self.indexes.is_some() {
+            self.indexes = Some(self.storage.segment.load_all_indexes(self).await?);
+        }
+        if self.time_indexes.is_some() {
+            self.time_indexes = Some(self.storage.segment.load_all_time_indexes(self).await?);
+        }
+
+        Ok(())
+    }
 }
@@ -1,3 +1,4 @@
+pub mod compression;
 pub mod index;
 pub mod messages;
 pub mod persistence;
@@ -2,6 +2,7 @@ use crate::configs::system::SystemConfig;
 use crate::streaming::segments::index::Index;
 use crate::streaming::segments::time_index::TimeIndex;
 use crate::streaming::storage::SystemStorage;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::models::messages::Message;
 use iggy::utils::timestamp::TimeStamp;
 use std::sync::Arc;
@@ -9,6 +10,8 @@ use std::sync::Arc;
 pub const LOG_EXTENSION: &str = "log";
 pub const INDEX_EXTENSION: &str = "index";
 pub const TIME_INDEX_EXTENSION: &str = "timeindex";
+pub const DELAYED_INDEX_EXTENSION: &str = "delayed";
+pub const REMOTE_EXTENSION: &str = "remote";
 pub const MAX_SIZE_BYTES: u32 = 1024 * 1024 * 1024;
 
 #[derive(Debug)]
@@ -22,8 +25,14 @@ pub struct Segment {
     pub index_path: String,
     pub log_path: String,
     pub time_index_path: String,
+    /// The delivery timestamps and the offsets of the delayed messages, so they don't have to be scanned on load.
+    pub delayed_index_path: String,
+    pub remote_path: String,
     pub current_size_bytes: u32,
+    pub current_uncompressed_size_bytes: u32,
+    pub compression_algorithm: CompressionAlgorithm,
     pub is_closed: bool,
+    pub is_uploaded: bool,
     pub(crate) message_expiry: Option<u32>,
     pub(crate) unsaved_messages: Option<Vec<Arc<Message>>>,
     pub(crate) config: Arc<SystemConfig>,
@@ -33,6 +42,7 @@ pub struct Segment {
 }
 
 impl Segment {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         stream_id: u32,
         topic_id: u32,
@@ -41,6 +51,7 @@ impl Segment {
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
         message_expiry: Option<u32>,
+        compression_algorithm: CompressionAlgorithm,
     ) -> Segment {
         let path = config.get_segment_path(stream_id, topic_id, partition_id, start_offset);
 
@@ -54,7 +65,11 @@ impl Segment {
             log_path: Self::get_log_path(&path),
             index_path: Self::get_index_path(&path),
             time_index_path: Self::get_time_index_path(&path),
+            delayed_index_path: Self::get_delayed_index_path(&path),
+            remote_path: Self::get_remote_path(&path),
             current_size_bytes: 0,
+            current_uncompressed_size_bytes: 0,
+            compression_algorithm,
             message_expiry,
             indexes: match config.segment.cache_indexes {
                 true => Some(Vec::new()),
@@ -66,11 +81,35 @@ impl Segment {
             },
             unsaved_messages: None,
             is_closed: false,
+            is_uploaded: false,
             config,
             storage,
         }
     }
 
+    /// Returns the copy of the closed segment without the cached data, which is sufficient to upload its files,
+    /// once the lock of the partition is released.
+    pub(crate) fn get_upload_copy(&self) -> Segment {
+        let mut segment = Segment::create(
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.start_offset,
+            self.config.clone(),
+            self.storage.clone(),
+            self.message_expiry,
+            self.compression_algorithm,
+        );
+        segment.end_offset = self.end_offset;
+        segment.current_offset = self.current_offset;
+        segment.current_size_bytes = self.current_size_bytes;
+        segment.current_uncompressed_size_bytes = self.current_uncompressed_size_bytes;
+        segment.is_closed = self.is_closed;
+        segment.indexes = None;
+        segment.time_indexes = None;
+        segment
+    }
+
     pub async fn is_full(&self) -> bool {
         if self.current_size_bytes >= self.config.segment.size_bytes {
             return true;
@@ -110,6 +149,14 @@ impl Segment {
     fn get_time_index_path(path: &str) -> String {
         format!("{}.{}", path, TIME_INDEX_EXTENSION)
     }
+
+    fn get_delayed_index_path(path: &str) -> String {
+        format!("{}.{}", path, DELAYED_INDEX_EXTENSION)
+    }
+
+    fn get_remote_path(path: &str) -> String {
+        format!("{}.{}", path, REMOTE_EXTENSION)
+    }
 }
 
 #[cfg(test)]
@@ -130,6 +177,8 @@ mod tests {
         let log_path = Segment::get_log_path(&path);
         let index_path = Segment::get_index_path(&path);
         let time_index_path = Segment::get_time_index_path(&path);
+        let delayed_index_path = Segment::get_delayed_index_path(&path);
+        let remote_path = Segment::get_remote_path(&path);
         let message_expiry = Some(10);
 
         let segment = Segment::create(
@@ -140,6 +189,7 @@ mod tests {
             config,
             storage,
             message_expiry,
+            CompressionAlgorithm::Zstd,
         );
 
         assert_eq!(segment.stream_id, stream_id);
@@ -152,11 +202,15 @@ mod tests {
         assert_eq!(segment.log_path, log_path);
         assert_eq!(segment.index_path, index_path);
         assert_eq!(segment.time_index_path, time_index_path);
+        assert_eq!(segment.delayed_index_path, delayed_index_path);
+        assert_eq!(segment.remote_path, remote_path);
         assert_eq!(segment.message_expiry, message_expiry);
+        assert_eq!(segment.compression_algorithm, CompressionAlgorithm::Zstd);
         assert!(segment.unsaved_messages.is_none());
         assert!(segment.indexes.is_some());
         assert!(segment.time_indexes.is_some());
         assert!(!segment.is_closed);
+        assert!(!segment.is_uploaded);
         assert!(!segment.is_full().await);
     }
 
@@ -183,6 +237,7 @@ mod tests {
             config,
             storage,
             None,
+            CompressionAlgorithm::None,
         );
 
         assert!(segment.indexes.is_none());
@@ -211,6 +266,7 @@ mod tests {
             config,
             storage,
             None,
+            CompressionAlgorithm::None,
         );
 
         assert!(segment.time_indexes.is_none());
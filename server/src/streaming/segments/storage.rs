@@ -1,18 +1,25 @@
 use crate::streaming::persistence::persister::Persister;
+use crate::streaming::tiered_storage::remote_storage::RemoteStorage;
 use async_trait::async_trait;
 use bytes::{BufMut, Bytes};
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::checksum;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::SeekFrom;
 use std::path::Path;
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
+use tokio::fs;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt, BufReader};
+use tokio::sync::Mutex;
 use tracing::log::{trace, warn};
 use tracing::{error, info};
 
+use crate::streaming::segments::compression;
+use crate::streaming::segments::compression::{BATCH_HEADER_SIZE, SEGMENT_HEADER_SIZE};
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::time_index::TimeIndex;
@@ -23,15 +30,133 @@ const EMPTY_INDEXES: Vec<Index> = vec![];
 const EMPTY_TIME_INDEXES: Vec<TimeIndex> = vec![];
 const INDEX_SIZE: u32 = 4;
 const BUF_READER_CAPACITY_BYTES: usize = 512 * 1024;
+const COMPACTED_EXTENSION: &str = "compacted";
+const DOWNLOAD_EXTENSION: &str = "download";
 
 #[derive(Debug)]
 pub struct FileSegmentStorage {
     persister: Arc<dyn Persister>,
+    remote_storage: Option<Arc<dyn RemoteStorage>>,
+    /// The downloads are serialized per segment, keyed by the path of its log file.
+    download_locks: std::sync::Mutex<HashMap<String, Arc<Mutex<()>>>>,
+}
+
+/// Stored in the local `.remote` file once the segment is uploaded to the remote storage,
+/// so that the segment can be loaded without fetching its log file.
+#[derive(Debug, Serialize, Deserialize)]
+struct RemoteSegmentInfo {
+    current_offset: u64,
+    size_bytes: u32,
+    uncompressed_size_bytes: u32,
+    compression_algorithm: CompressionAlgorithm,
 }
 
 impl FileSegmentStorage {
-    pub fn new(persister: Arc<dyn Persister>) -> Self {
-        Self { persister }
+    pub fn new(
+        persister: Arc<dyn Persister>,
+        remote_storage: Option<Arc<dyn RemoteStorage>>,
+    ) -> Self {
+        Self {
+            persister,
+            remote_storage,
+            download_locks: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn load_uploaded(&self, segment: &mut Segment) -> Result<(), Error> {
+        let data = fs::read(&segment.remote_path).await?;
+        let info = serde_json::from_slice::<RemoteSegmentInfo>(&data)
+            .map_err(|_| Error::CannotDeserializeResource(segment.remote_path.clone()))?;
+        segment.current_offset = info.current_offset;
+        segment.current_size_bytes = info.size_bytes;
+        segment.current_uncompressed_size_bytes = info.uncompressed_size_bytes;
+        segment.compression_algorithm = info.compression_algorithm;
+        segment.indexes = None;
+        segment.time_indexes = None;
+        if segment.config.segment.cache_time_indexes && Path::new(&segment.time_index_path).exists()
+        {
+            segment.time_indexes = Some(load_time_indexes(segment).await?);
+        }
+        segment.is_uploaded = true;
+        segment.is_closed = true;
+        info!(
+            "Loaded uploaded segment with start offset: {}, current offset: {}, and partition with ID: {} for topic with ID: {} and stream with ID: {}, local copy is not available.",
+            segment.start_offset, segment.current_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        Ok(())
+    }
+
+    /// Downloads the missing segment files from the remote storage, if the local copy was removed after the upload.
+    async fn ensure_local_copy(&self, segment: &Segment) -> Result<(), Error> {
+        if !segment.is_uploaded || Path::new(&segment.log_path).exists() {
+            return Ok(());
+        }
+
+        let remote_storage = match &self.remote_storage {
+            Some(remote_storage) => remote_storage,
+            None => {
+                error!(
+                    "Cannot download segment: {}, remote storage is not configured.",
+                    segment.log_path
+                );
+                return Err(Error::CannotDownloadSegment(segment.log_path.clone()));
+            }
+        };
+
+        let download_lock = self
+            .download_locks
+            .lock()
+            .unwrap()
+            .entry(segment.log_path.clone())
+            .or_default()
+            .clone();
+        let download_guard = download_lock.lock().await;
+        let result = if Path::new(&segment.log_path).exists() {
+            Ok(())
+        } else {
+            self.download_segment(segment, remote_storage.as_ref())
+                .await
+        };
+        drop(download_guard);
+
+        let mut download_locks = self.download_locks.lock().unwrap();
+        // The lock is no longer needed, unless another download of the same segment is waiting for it.
+        if Arc::strong_count(&download_lock) == 2 {
+            download_locks.remove(&segment.log_path);
+        }
+        result
+    }
+
+    async fn download_segment(
+        &self,
+        segment: &Segment,
+        remote_storage: &dyn RemoteStorage,
+    ) -> Result<(), Error> {
+        info!(
+            "Downloading segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} from the remote storage...",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        // The log file goes last, as its presence marks the complete local copy of the segment.
+        for path in [
+            &segment.index_path,
+            &segment.time_index_path,
+            &segment.log_path,
+        ] {
+            if Path::new(path).exists() {
+                continue;
+            }
+
+            let download_path = format!("{}.{}", path, DOWNLOAD_EXTENSION);
+            remote_storage
+                .download(&get_object_key(segment, path), &download_path)
+                .await?;
+            fs::rename(&download_path, path).await?;
+        }
+        info!(
+            "Downloaded segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        Ok(())
     }
 }
 
@@ -46,13 +171,36 @@ impl Storage<Segment> for FileSegmentStorage {
             "Loading segment from disk for start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {} ...",
             segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
         );
-        let log_file = file::open(&segment.log_path).await?;
+        segment.is_uploaded = Path::new(&segment.remote_path).exists();
+        if segment.is_uploaded && !Path::new(&segment.log_path).exists() {
+            return self.load_uploaded(segment).await;
+        }
+
+        let mut log_file = file::open(&segment.log_path).await?;
         let file_size = log_file.metadata().await.unwrap().len() as u32;
-        segment.current_size_bytes = file_size;
+        let mut header = vec![0; SEGMENT_HEADER_SIZE as usize];
+        if file_size < SEGMENT_HEADER_SIZE || log_file.read_exact(&mut header).await.is_err() {
+            header.clear();
+        }
+
+        // Segments without the header (e.g. created before the compression was supported) are not compressed.
+        match compression::read_segment_header(&header)? {
+            Some(compression_algorithm) => {
+                segment.compression_algorithm = compression_algorithm;
+                segment.current_size_bytes = file_size - SEGMENT_HEADER_SIZE;
+                segment.current_uncompressed_size_bytes =
+                    load_uncompressed_size_bytes(segment).await?;
+            }
+            None => {
+                segment.compression_algorithm = CompressionAlgorithm::None;
+                segment.current_size_bytes = file_size;
+                segment.current_uncompressed_size_bytes = file_size;
+            }
+        }
 
         info!(
-            "Segment log file for start offset {}, current offset: {}, and partition with ID: {} for topic with ID: {} and stream with ID: {} has {} bytes of size.",
-            segment.start_offset, segment.current_offset, segment.partition_id, segment.topic_id, segment.stream_id, segment.current_size_bytes
+            "Segment log file for start offset {}, current offset: {}, and partition with ID: {} for topic with ID: {} and stream with ID: {} has {} bytes of size, compression algorithm: {}.",
+            segment.start_offset, segment.current_offset, segment.partition_id, segment.topic_id, segment.stream_id, segment.current_size_bytes, segment.compression_algorithm
         );
 
         if segment.config.segment.cache_indexes {
@@ -97,7 +245,7 @@ impl Storage<Segment> for FileSegmentStorage {
             }
         }
 
-        if segment.is_full().await {
+        if segment.is_uploaded || segment.is_full().await {
             segment.is_closed = true;
         }
 
@@ -107,14 +255,33 @@ impl Storage<Segment> for FileSegmentStorage {
     async fn save(&self, segment: &Segment) -> Result<(), Error> {
         info!("Saving segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
             segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id);
-        if !Path::new(&segment.log_path).exists()
-            && self
+        if segment.is_uploaded {
+            return Ok(());
+        }
+
+        let header = match segment.compression_algorithm {
+            CompressionAlgorithm::None => Vec::new(),
+            compression_algorithm => compression::get_segment_header(compression_algorithm),
+        };
+        if !Path::new(&segment.log_path).exists() {
+            if self
                 .persister
-                .overwrite(&segment.log_path, &[])
+                .overwrite(&segment.log_path, &header)
                 .await
                 .is_err()
-        {
-            return Err(Error::CannotCreateSegmentLogFile(segment.log_path.clone()));
+            {
+                return Err(Error::CannotCreateSegmentLogFile(segment.log_path.clone()));
+            }
+
+            // Only the new segments get the empty delayed index, the ones without it are scanned once on load.
+            if self
+                .persister
+                .overwrite(&segment.delayed_index_path, &[])
+                .await
+                .is_err()
+            {
+                return Err(Error::CannotSaveDelayedIndexToSegment);
+            }
         }
 
         if !Path::new(&segment.time_index_path).exists()
@@ -152,9 +319,26 @@ impl Storage<Segment> for FileSegmentStorage {
             "Deleting segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}...",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
         );
-        self.persister.delete(&segment.log_path).await?;
-        self.persister.delete(&segment.index_path).await?;
-        self.persister.delete(&segment.time_index_path).await?;
+        self.delete_local_copy(segment).await?;
+        for path in [&segment.time_index_path, &segment.delayed_index_path] {
+            if Path::new(path).exists() {
+                self.persister.delete(path).await?;
+            }
+        }
+        if segment.is_uploaded {
+            if let Some(remote_storage) = &self.remote_storage {
+                for path in [
+                    &segment.log_path,
+                    &segment.index_path,
+                    &segment.time_index_path,
+                ] {
+                    remote_storage
+                        .delete(&get_object_key(segment, path))
+                        .await?;
+                }
+            }
+            self.persister.delete(&segment.remote_path).await?;
+        }
         info!(
             "Deleted segment with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
@@ -170,6 +354,7 @@ impl SegmentStorage for FileSegmentStorage {
         segment: &Segment,
         index_range: &IndexRange,
     ) -> Result<Vec<Arc<Message>>, Error> {
+        self.ensure_local_copy(segment).await?;
         let mut messages = Vec::with_capacity(
             1 + (index_range.end.relative_offset - index_range.start.relative_offset) as usize,
         );
@@ -187,6 +372,7 @@ impl SegmentStorage for FileSegmentStorage {
         segment: &Segment,
         size_bytes: u64,
     ) -> Result<Vec<Arc<Message>>, Error> {
+        self.ensure_local_copy(segment).await?;
         let mut messages = Vec::new();
         let mut total_size_bytes = 0;
         load_messages_by_size(segment, size_bytes, |message: Message| {
@@ -208,25 +394,110 @@ impl SegmentStorage for FileSegmentStorage {
         segment: &Segment,
         messages: &[Arc<Message>],
     ) -> Result<u32, Error> {
-        let messages_size = messages
-            .iter()
-            .map(|message| message.get_size_bytes())
-            .sum::<u32>();
+        let bytes = get_messages_bytes(segment, messages)?;
+        if let Err(error) = self.persister.append(&segment.log_path, &bytes).await {
+            error!("Cannot save messages to segment: {}", error);
+            return Err(Error::CannotSaveMessagesToSegment);
+        }
 
-        let mut bytes = Vec::with_capacity(messages_size as usize);
-        for message in messages {
-            message.extend(&mut bytes);
+        Ok(bytes.len() as u32)
+    }
+
+    async fn save_compacted_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u32, Error> {
+        self.ensure_local_copy(segment).await?;
+        let mut log_bytes = match segment.compression_algorithm {
+            CompressionAlgorithm::None => Vec::new(),
+            compression_algorithm => compression::get_segment_header(compression_algorithm),
+        };
+        let header_size = log_bytes.len();
+        if !messages.is_empty() {
+            log_bytes.extend(get_messages_bytes(segment, messages)?);
         }
 
-        if let Err(error) = self.persister.append(&segment.log_path, &bytes).await {
-            error!("Cannot save messages to segment: {}", error);
+        // The index stays dense, the removed offsets point to the position of the next retained message,
+        // so that the range lookups keep working for the gaps left after the compaction.
+        let mut index_bytes = Vec::new();
+        let mut position = 0;
+        let mut retained_messages = messages.iter().peekable();
+        for offset in segment.start_offset..=segment.current_offset {
+            index_bytes.put_u32_le(position);
+            if let Some(message) = retained_messages.next_if(|message| message.offset == offset) {
+                if segment.compression_algorithm == CompressionAlgorithm::None {
+                    position += message.get_size_bytes();
+                }
+            }
+        }
+
+        let compacted_log_path = format!("{}.{}", segment.log_path, COMPACTED_EXTENSION);
+        let compacted_index_path = format!("{}.{}", segment.index_path, COMPACTED_EXTENSION);
+        if let Err(error) = self
+            .persister
+            .overwrite(&compacted_log_path, &log_bytes)
+            .await
+        {
+            error!("Cannot save compacted messages to segment: {}", error);
             return Err(Error::CannotSaveMessagesToSegment);
         }
 
-        Ok(messages_size)
+        if self
+            .persister
+            .overwrite(&compacted_index_path, &index_bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveIndexToSegment);
+        }
+
+        fs::rename(&compacted_log_path, &segment.log_path).await?;
+        fs::rename(&compacted_index_path, &segment.index_path).await?;
+        if self
+            .persister
+            .overwrite(
+                &segment.delayed_index_path,
+                &get_delayed_index_bytes(messages),
+            )
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveDelayedIndexToSegment);
+        }
+
+        // The remote copy is outdated now, so the segment will be uploaded again.
+        if segment.is_uploaded {
+            self.persister.delete(&segment.remote_path).await?;
+        }
+        Ok((log_bytes.len() - header_size) as u32)
+    }
+
+    async fn save_truncated_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u32, Error> {
+        let size_bytes = self.save_compacted_messages(segment, messages).await?;
+        let mut time_index_bytes = Vec::with_capacity(messages.len() * 8);
+        for message in messages {
+            time_index_bytes.put_u64_le(message.timestamp);
+        }
+
+        if self
+            .persister
+            .overwrite(&segment.time_index_path, &time_index_bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveTimeIndexToSegment);
+        }
+
+        Ok(size_bytes)
     }
 
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error> {
+        self.ensure_local_copy(segment).await?;
         let mut message_ids = Vec::new();
         load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
             message_ids.push(message.id);
@@ -238,6 +509,7 @@ impl SegmentStorage for FileSegmentStorage {
     }
 
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error> {
+        self.ensure_local_copy(segment).await?;
         load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
             let calculated_checksum = checksum::calculate(&message.payload);
             trace!(
@@ -260,6 +532,7 @@ impl SegmentStorage for FileSegmentStorage {
     }
 
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, Error> {
+        self.ensure_local_copy(segment).await?;
         trace!("Loading indexes from file...");
         let file = file::open(&segment.index_path).await?;
         let file_size = file.metadata().await?.len() as usize;
@@ -322,6 +595,7 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(None);
         }
 
+        self.ensure_local_copy(segment).await?;
         let mut file = file::open(&segment.index_path).await?;
         let file_length = file.metadata().await?.len() as u32;
         if file_length == 0 {
@@ -397,7 +671,10 @@ impl SegmentStorage for FileSegmentStorage {
         for message in messages {
             trace!("Persisting index for position: {}", current_position);
             bytes.put_u32_le(current_position);
-            current_position += message.get_size_bytes();
+            // All the messages from the compressed batch are indexed by the position of the batch.
+            if segment.compression_algorithm == CompressionAlgorithm::None {
+                current_position += message.get_size_bytes();
+            }
         }
 
         if self
@@ -413,44 +690,8 @@ impl SegmentStorage for FileSegmentStorage {
     }
 
     async fn load_all_time_indexes(&self, segment: &Segment) -> Result<Vec<TimeIndex>, Error> {
-        trace!("Loading time indexes from file...");
-        let file = file::open(&segment.time_index_path).await?;
-        let file_size = file.metadata().await?.len() as usize;
-        if file_size == 0 {
-            trace!("Time index file is empty.");
-            return Ok(EMPTY_TIME_INDEXES);
-        }
-
-        let indexes_count = file_size / 8;
-        let mut indexes = Vec::with_capacity(indexes_count);
-        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
-        for offset in 0..indexes_count {
-            let timestamp = reader.read_u64_le().await;
-            if timestamp.is_err() {
-                error!(
-                    "Cannot read timestamp from time index file for offset: {}.",
-                    offset
-                );
-                break;
-            }
-
-            indexes.push(TimeIndex {
-                relative_offset: offset as u32,
-                timestamp: timestamp.unwrap(),
-            });
-        }
-
-        if indexes.len() != indexes_count {
-            error!(
-                "Loaded {} time indexes from disk, expected {}.",
-                indexes.len(),
-                indexes_count
-            );
-        }
-
-        trace!("Loaded {} time indexes from file.", indexes_count);
-
-        Ok(indexes)
+        self.ensure_local_copy(segment).await?;
+        load_time_indexes(segment).await
     }
 
     async fn load_last_time_index(&self, segment: &Segment) -> Result<Option<TimeIndex>, Error> {
@@ -497,6 +738,156 @@ impl SegmentStorage for FileSegmentStorage {
 
         Ok(())
     }
+
+    async fn load_delayed_index(
+        &self,
+        segment: &Segment,
+    ) -> Result<Option<Vec<(u64, u64)>>, Error> {
+        if !Path::new(&segment.delayed_index_path).exists() {
+            return Ok(None);
+        }
+
+        let bytes = fs::read(&segment.delayed_index_path).await?;
+        let delayed_index = bytes
+            .chunks_exact(16)
+            .map(|entry| {
+                (
+                    u64::from_le_bytes(entry[..8].try_into().unwrap()),
+                    u64::from_le_bytes(entry[8..].try_into().unwrap()),
+                )
+            })
+            .collect::<Vec<_>>();
+        trace!(
+            "Loaded {} delayed index entries from file.",
+            delayed_index.len()
+        );
+        Ok(Some(delayed_index))
+    }
+
+    async fn save_delayed_index(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<(), Error> {
+        // The empty delayed index is still created, so the segment won't be scanned again on load.
+        let bytes = get_delayed_index_bytes(messages);
+        if bytes.is_empty() && Path::new(&segment.delayed_index_path).exists() {
+            return Ok(());
+        }
+
+        if self
+            .persister
+            .append(&segment.delayed_index_path, &bytes)
+            .await
+            .is_err()
+        {
+            return Err(Error::CannotSaveDelayedIndexToSegment);
+        }
+
+        Ok(())
+    }
+
+    async fn upload(&self, segment: &Segment) -> Result<(), Error> {
+        let remote_storage = match &self.remote_storage {
+            Some(remote_storage) => remote_storage,
+            None => return Err(Error::CannotUploadSegment(segment.log_path.clone())),
+        };
+
+        info!(
+            "Uploading segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {} to the remote storage...",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        for path in [
+            &segment.log_path,
+            &segment.index_path,
+            &segment.time_index_path,
+        ] {
+            remote_storage
+                .upload(&get_object_key(segment, path), path)
+                .await?;
+        }
+        info!(
+            "Uploaded segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
+        );
+        Ok(())
+    }
+
+    async fn mark_uploaded(&self, segment: &Segment) -> Result<(), Error> {
+        let info = RemoteSegmentInfo {
+            current_offset: segment.current_offset,
+            size_bytes: segment.current_size_bytes,
+            uncompressed_size_bytes: segment.current_uncompressed_size_bytes,
+            compression_algorithm: segment.compression_algorithm,
+        };
+        let data = serde_json::to_vec(&info)
+            .map_err(|_| Error::CannotSerializeResource(segment.remote_path.clone()))?;
+        self.persister
+            .overwrite(&segment.remote_path, &data)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_local_copy(&self, segment: &Segment) -> Result<(), Error> {
+        // The time index is kept, so that the messages can be still looked up by the timestamp,
+        // as well as the delayed index, so that the delayed messages can be still indexed on load.
+        for path in [&segment.log_path, &segment.index_path] {
+            if Path::new(path).exists() {
+                self.persister.delete(path).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The object key is the path of the segment file relative to the system path,
+/// e.g. `streams/1/topics/1/partitions/1/00000000000000000000.log`.
+fn get_object_key(segment: &Segment, path: &str) -> String {
+    let system_path = format!("{}/", segment.config.get_system_path());
+    path.strip_prefix(&system_path).unwrap_or(path).to_string()
+}
+
+/// Each entry of the delayed index is the delivery timestamp followed by the offset of the message.
+fn get_delayed_index_bytes(messages: &[Arc<Message>]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for message in messages {
+        if let Some(deliver_at) = message.get_deliver_at() {
+            if deliver_at > message.timestamp {
+                bytes.put_u64_le(deliver_at);
+                bytes.put_u64_le(message.offset);
+            }
+        }
+    }
+    bytes
+}
+
+fn get_messages_bytes(segment: &Segment, messages: &[Arc<Message>]) -> Result<Vec<u8>, Error> {
+    let messages_size = messages
+        .iter()
+        .map(|message| message.get_size_bytes())
+        .sum::<u32>();
+
+    let mut bytes = Vec::with_capacity(messages_size as usize);
+    for message in messages {
+        message.extend(&mut bytes);
+    }
+
+    if segment.compression_algorithm == CompressionAlgorithm::None {
+        return Ok(bytes);
+    }
+
+    let compressed_bytes = compression::compress(segment.compression_algorithm, &bytes)?;
+    let mut batch_bytes = Vec::with_capacity(BATCH_HEADER_SIZE as usize + compressed_bytes.len());
+    batch_bytes.put_u32_le(compressed_bytes.len() as u32);
+    batch_bytes.put_u32_le(messages_size);
+    batch_bytes.extend(compressed_bytes);
+    trace!(
+        "Compressed {} bytes of messages to {} bytes using {}.",
+        messages_size,
+        batch_bytes.len(),
+        segment.compression_algorithm
+    );
+    Ok(batch_bytes)
 }
 
 async fn load_messages_by_range(
@@ -504,6 +895,10 @@ async fn load_messages_by_range(
     index_range: &IndexRange,
     mut on_message: impl FnMut(Message) -> Result<(), Error>,
 ) -> Result<(), Error> {
+    if segment.compression_algorithm != CompressionAlgorithm::None {
+        return load_compressed_messages_by_range(segment, index_range, on_message).await;
+    }
+
     let file = file::open(&segment.log_path).await?;
     let file_size = file.metadata().await?.len();
     if file_size == 0 {
@@ -519,82 +914,64 @@ async fn load_messages_by_range(
         .seek(SeekFrom::Start(index_range.start.position as u64))
         .await?;
 
-    let mut read_messages = 0;
-    let messages_count =
-        (1 + index_range.end.relative_offset - index_range.start.relative_offset) as usize;
-
-    while read_messages < messages_count {
-        let offset = reader.read_u64_le().await;
-        if offset.is_err() {
+    // The compacted segments might have the gaps between the offsets, so the messages are read up to the end offset.
+    let end_offset = segment.start_offset + index_range.end.relative_offset as u64;
+    loop {
+        let message = read_message(&mut reader).await?;
+        if message.is_none() {
             break;
         }
 
-        let state = reader.read_u8().await;
-        if state.is_err() {
-            return Err(Error::CannotReadMessageState);
+        let message = message.unwrap();
+        if message.offset > end_offset {
+            break;
         }
 
-        let state = MessageState::from_code(state.unwrap())?;
-        let timestamp = reader.read_u64_le().await;
-        if timestamp.is_err() {
-            return Err(Error::CannotReadMessageTimestamp);
-        }
+        on_message(message)?;
+    }
+    Ok(())
+}
 
-        let id = reader.read_u128_le().await;
-        if id.is_err() {
-            return Err(Error::CannotReadMessageId);
-        }
+async fn load_compressed_messages_by_range(
+    segment: &Segment,
+    index_range: &IndexRange,
+    mut on_message: impl FnMut(Message) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let file = file::open(&segment.log_path).await?;
+    let file_size = file.metadata().await?.len();
+    if file_size <= SEGMENT_HEADER_SIZE as u64 {
+        return Ok(());
+    }
 
-        let checksum = reader.read_u32_le().await;
-        if checksum.is_err() {
-            return Err(Error::CannotReadMessageChecksum);
-        }
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    reader
+        .seek(SeekFrom::Start(
+            SEGMENT_HEADER_SIZE as u64 + index_range.start.position as u64,
+        ))
+        .await?;
 
-        let headers_length = reader.read_u32_le().await;
-        if headers_length.is_err() {
-            return Err(Error::CannotReadHeadersLength);
+    // The batch might contain the messages outside of the requested range, which have to be skipped.
+    let start_offset = segment.start_offset + index_range.start.relative_offset as u64;
+    let end_offset = segment.start_offset + index_range.end.relative_offset as u64;
+    loop {
+        let batch = read_batch(&mut reader, segment.compression_algorithm).await?;
+        if batch.is_none() {
+            break;
         }
 
-        let headers_length = headers_length.unwrap();
-        let headers = match headers_length {
-            0 => None,
-            _ => {
-                let mut headers_payload = vec![0; headers_length as usize];
-                if reader.read_exact(&mut headers_payload).await.is_err() {
-                    return Err(Error::CannotReadHeadersPayload);
-                }
-
-                let headers = HashMap::from_bytes(&headers_payload)?;
-                Some(headers)
+        let (batch, _) = batch.unwrap();
+        let mut batch = batch.as_slice();
+        while let Some(message) = read_message(&mut batch).await? {
+            if message.offset < start_offset {
+                continue;
             }
-        };
 
-        let payload_length = reader.read_u32_le().await;
-        if payload_length.is_err() {
-            return Err(Error::CannotReadMessageLength);
-        }
+            if message.offset > end_offset {
+                return Ok(());
+            }
 
-        let mut payload = vec![0; payload_length.unwrap() as usize];
-        if reader.read_exact(&mut payload).await.is_err() {
-            return Err(Error::CannotReadMessagePayload);
+            on_message(message)?;
         }
-
-        let offset = offset.unwrap();
-        let timestamp = timestamp.unwrap();
-        let id = id.unwrap();
-        let checksum = checksum.unwrap();
-
-        let message = Message::create(
-            offset,
-            state,
-            timestamp,
-            id,
-            Bytes::from(payload),
-            checksum,
-            headers,
-        );
-        read_messages += 1;
-        on_message(message)?;
     }
     Ok(())
 }
@@ -604,6 +981,10 @@ async fn load_messages_by_size(
     size_bytes: u64,
     mut on_message: impl FnMut(Message) -> Result<(), Error>,
 ) -> Result<(), Error> {
+    if segment.compression_algorithm != CompressionAlgorithm::None {
+        return load_compressed_messages_by_size(segment, size_bytes, on_message).await;
+    }
+
     let file = file::open(&segment.log_path).await?;
     let file_size = file.metadata().await?.len();
     if file_size == 0 {
@@ -615,87 +996,234 @@ async fn load_messages_by_size(
     let mut accumulated_size: u64 = 0;
 
     loop {
-        let offset = reader.read_u64_le().await;
-        if offset.is_err() {
+        let message = read_message(&mut reader).await?;
+        if message.is_none() {
             break;
         }
 
-        let state = reader.read_u8().await;
-        if state.is_err() {
-            return Err(Error::CannotReadMessageState);
-        }
+        let message = message.unwrap();
+        let message_size = message.get_size_bytes() as u64;
 
-        let state = MessageState::from_code(state.unwrap())?;
-        let timestamp = reader.read_u64_le().await;
-        if timestamp.is_err() {
-            return Err(Error::CannotReadMessageTimestamp);
+        if accumulated_size >= threshold {
+            on_message(message)?;
         }
 
-        let id = reader.read_u128_le().await;
-        if id.is_err() {
-            return Err(Error::CannotReadMessageId);
+        accumulated_size += message_size;
+
+        if accumulated_size >= file_size {
+            break;
         }
+    }
+
+    Ok(())
+}
+
+async fn load_compressed_messages_by_size(
+    segment: &Segment,
+    size_bytes: u64,
+    mut on_message: impl FnMut(Message) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let file = file::open(&segment.log_path).await?;
+    let file_size = file.metadata().await?.len();
+    if file_size <= SEGMENT_HEADER_SIZE as u64 {
+        return Ok(());
+    }
 
-        let checksum = reader.read_u32_le().await;
-        if checksum.is_err() {
-            return Err(Error::CannotReadMessageChecksum);
+    let data_size = file_size - SEGMENT_HEADER_SIZE as u64;
+    let threshold = data_size.saturating_sub(size_bytes);
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    reader
+        .seek(SeekFrom::Start(SEGMENT_HEADER_SIZE as u64))
+        .await?;
+
+    let mut accumulated_size: u64 = 0;
+    loop {
+        let batch = read_batch(&mut reader, segment.compression_algorithm).await?;
+        if batch.is_none() {
+            break;
         }
 
-        let headers_length = reader.read_u32_le().await;
-        if headers_length.is_err() {
-            return Err(Error::CannotReadHeadersLength);
+        let (batch, batch_size) = batch.unwrap();
+        accumulated_size += batch_size as u64;
+        if accumulated_size <= threshold {
+            continue;
         }
 
-        let headers_length = headers_length.unwrap();
-        let headers = match headers_length {
-            0 => None,
-            _ => {
-                let mut headers_payload = vec![0; headers_length as usize];
-                if reader.read_exact(&mut headers_payload).await.is_err() {
-                    return Err(Error::CannotReadHeadersPayload);
-                }
+        let mut batch = batch.as_slice();
+        while let Some(message) = read_message(&mut batch).await? {
+            on_message(message)?;
+        }
+    }
 
-                let headers = HashMap::from_bytes(&headers_payload)?;
-                Some(headers)
-            }
-        };
+    Ok(())
+}
 
-        let payload_length = reader.read_u32_le().await;
-        if payload_length.is_err() {
-            return Err(Error::CannotReadMessageLength);
-        }
+async fn load_time_indexes(segment: &Segment) -> Result<Vec<TimeIndex>, Error> {
+    trace!("Loading time indexes from file...");
+    let file = file::open(&segment.time_index_path).await?;
+    let file_size = file.metadata().await?.len() as usize;
+    if file_size == 0 {
+        trace!("Time index file is empty.");
+        return Ok(EMPTY_TIME_INDEXES);
+    }
 
-        let mut payload = vec![0; payload_length.unwrap() as usize];
-        if reader.read_exact(&mut payload).await.is_err() {
-            return Err(Error::CannotReadMessagePayload);
+    let indexes_count = file_size / 8;
+    let mut indexes = Vec::with_capacity(indexes_count);
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    for offset in 0..indexes_count {
+        let timestamp = reader.read_u64_le().await;
+        if timestamp.is_err() {
+            error!(
+                "Cannot read timestamp from time index file for offset: {}.",
+                offset
+            );
+            break;
         }
 
-        let offset = offset.unwrap();
-        let timestamp = timestamp.unwrap();
-        let id = id.unwrap();
-        let checksum = checksum.unwrap();
+        indexes.push(TimeIndex {
+            relative_offset: offset as u32,
+            timestamp: timestamp.unwrap(),
+        });
+    }
 
-        let message = Message::create(
-            offset,
-            state,
-            timestamp,
-            id,
-            Bytes::from(payload),
-            checksum,
-            headers,
+    if indexes.len() != indexes_count {
+        error!(
+            "Loaded {} time indexes from disk, expected {}.",
+            indexes.len(),
+            indexes_count
         );
-        let message_size = message.get_size_bytes() as u64;
+    }
 
-        if accumulated_size >= threshold {
-            on_message(message)?;
-        }
+    trace!("Loaded {} time indexes from file.", indexes_count);
 
-        accumulated_size += message_size;
+    Ok(indexes)
+}
 
-        if accumulated_size >= file_size {
+async fn load_uncompressed_size_bytes(segment: &Segment) -> Result<u32, Error> {
+    let file = file::open(&segment.log_path).await?;
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    reader
+        .seek(SeekFrom::Start(SEGMENT_HEADER_SIZE as u64))
+        .await?;
+
+    let mut uncompressed_size_bytes = 0;
+    loop {
+        let compressed_length = reader.read_u32_le().await;
+        if compressed_length.is_err() {
             break;
         }
+
+        let uncompressed_length = reader.read_u32_le().await;
+        if uncompressed_length.is_err() {
+            return Err(Error::CannotDecompressMessages);
+        }
+
+        uncompressed_size_bytes += uncompressed_length.unwrap();
+        reader
+            .seek(SeekFrom::Current(compressed_length.unwrap() as i64))
+            .await?;
     }
 
-    Ok(())
+    Ok(uncompressed_size_bytes)
+}
+
+// Returns the decompressed batch along with its size on disk.
+async fn read_batch<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    compression_algorithm: CompressionAlgorithm,
+) -> Result<Option<(Vec<u8>, u32)>, Error> {
+    let compressed_length = reader.read_u32_le().await;
+    if compressed_length.is_err() {
+        return Ok(None);
+    }
+
+    let uncompressed_length = reader.read_u32_le().await;
+    if uncompressed_length.is_err() {
+        return Err(Error::CannotDecompressMessages);
+    }
+
+    let compressed_length = compressed_length.unwrap();
+    let mut compressed_bytes = vec![0; compressed_length as usize];
+    if reader.read_exact(&mut compressed_bytes).await.is_err() {
+        return Err(Error::CannotDecompressMessages);
+    }
+
+    let bytes = compression::decompress(
+        compression_algorithm,
+        &compressed_bytes,
+        uncompressed_length.unwrap() as usize,
+    )?;
+    Ok(Some((bytes, BATCH_HEADER_SIZE + compressed_length)))
+}
+
+async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<Message>, Error> {
+    let offset = reader.read_u64_le().await;
+    if offset.is_err() {
+        return Ok(None);
+    }
+
+    let state = reader.read_u8().await;
+    if state.is_err() {
+        return Err(Error::CannotReadMessageState);
+    }
+
+    let state = MessageState::from_code(state.unwrap())?;
+    let timestamp = reader.read_u64_le().await;
+    if timestamp.is_err() {
+        return Err(Error::CannotReadMessageTimestamp);
+    }
+
+    let id = reader.read_u128_le().await;
+    if id.is_err() {
+        return Err(Error::CannotReadMessageId);
+    }
+
+    let checksum = reader.read_u32_le().await;
+    if checksum.is_err() {
+        return Err(Error::CannotReadMessageChecksum);
+    }
+
+    let headers_length = reader.read_u32_le().await;
+    if headers_length.is_err() {
+        return Err(Error::CannotReadHeadersLength);
+    }
+
+    let headers_length = headers_length.unwrap();
+    let headers = match headers_length {
+        0 => None,
+        _ => {
+            let mut headers_payload = vec![0; headers_length as usize];
+            if reader.read_exact(&mut headers_payload).await.is_err() {
+                return Err(Error::CannotReadHeadersPayload);
+            }
+
+            let headers = HashMap::from_bytes(&headers_payload)?;
+            Some(headers)
+        }
+    };
+
+    let payload_length = reader.read_u32_le().await;
+    if payload_length.is_err() {
+        return Err(Error::CannotReadMessageLength);
+    }
+
+    let mut payload = vec![0; payload_length.unwrap() as usize];
+    if reader.read_exact(&mut payload).await.is_err() {
+        return Err(Error::CannotReadMessagePayload);
+    }
+
+    let offset = offset.unwrap();
+    let timestamp = timestamp.unwrap();
+    let id = id.unwrap();
+    let checksum = checksum.unwrap();
+
+    Ok(Some(Message::create(
+        offset,
+        state,
+        timestamp,
+        id,
+        Bytes::from(payload),
+        checksum,
+        headers,
+    )))
 }
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use iggy::models::user_info::UserId;
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Session {
     pub user_id: UserId,
     pub client_id: u32,
@@ -1,8 +1,11 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, Partition, PoisonedOffsets, ProducerState,
+};
 use crate::streaming::partitions::storage::FilePartitionStorage;
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::personal_access_tokens::storage::FilePersonalAccessTokenStorage;
+use crate::streaming::schemas::schema::TopicSchema;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::storage::FileSegmentStorage;
@@ -11,6 +14,7 @@ use crate::streaming::streams::storage::FileStreamStorage;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::info::SystemInfo;
 use crate::streaming::systems::storage::FileSystemInfoStorage;
+use crate::streaming::tiered_storage::remote_storage::RemoteStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::storage::FileTopicStorage;
 use crate::streaming::topics::topic::Topic;
@@ -68,6 +72,9 @@ pub trait TopicStorage: Storage<Topic> {
         topic: &Topic,
         consumer_group: &ConsumerGroup,
     ) -> Result<(), Error>;
+    async fn save_schema(&self, topic: &Topic, schema: &TopicSchema) -> Result<(), Error>;
+    async fn load_schemas(&self, topic: &Topic) -> Result<Vec<TopicSchema>, Error>;
+    async fn delete_schema(&self, topic: &Topic, schema: &TopicSchema) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -87,6 +94,35 @@ pub trait PartitionStorage: Storage<Partition> {
         topic_id: u32,
         partition_id: u32,
     ) -> Result<(), Error>;
+    async fn save_poisoned_offsets(&self, poisoned_offsets: &PoisonedOffsets) -> Result<(), Error>;
+    async fn load_poisoned_offsets(
+        &self,
+        kind: ConsumerKind,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<PoisonedOffsets>, Error>;
+    async fn delete_poisoned_offsets(
+        &self,
+        kind: ConsumerKind,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error>;
+    async fn save_producer(&self, producer: &ProducerState) -> Result<(), Error>;
+    async fn load_producers(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Vec<ProducerState>, Error>;
+    async fn delete_producer(&self, producer: &ProducerState) -> Result<(), Error>;
+    async fn delete_producers(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -106,6 +142,16 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         messages: &[Arc<Message>],
     ) -> Result<u32, Error>;
+    async fn save_compacted_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u32, Error>;
+    async fn save_truncated_messages(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<u32, Error>;
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error>;
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error>;
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, Error>;
@@ -129,6 +175,17 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         messages: &[Arc<Message>],
     ) -> Result<(), Error>;
+    /// Returns the delivery timestamps and the offsets of the delayed messages, or none if the segment has no delayed index.
+    async fn load_delayed_index(&self, segment: &Segment)
+        -> Result<Option<Vec<(u64, u64)>>, Error>;
+    async fn save_delayed_index(
+        &self,
+        segment: &Segment,
+        messages: &[Arc<Message>],
+    ) -> Result<(), Error>;
+    async fn upload(&self, segment: &Segment) -> Result<(), Error>;
+    async fn mark_uploaded(&self, segment: &Segment) -> Result<(), Error>;
+    async fn delete_local_copy(&self, segment: &Segment) -> Result<(), Error>;
 }
 
 #[derive(Debug)]
@@ -143,7 +200,11 @@ pub struct SystemStorage {
 }
 
 impl SystemStorage {
-    pub fn new(db: Arc<Db>, persister: Arc<dyn Persister>) -> Self {
+    pub fn new(
+        db: Arc<Db>,
+        persister: Arc<dyn Persister>,
+        remote_storage: Option<Arc<dyn RemoteStorage>>,
+    ) -> Self {
         Self {
             info: Arc::new(FileSystemInfoStorage::new(db.clone())),
             user: Arc::new(FileUserStorage::new(db.clone())),
@@ -151,7 +212,7 @@ impl SystemStorage {
             stream: Arc::new(FileStreamStorage::new(db.clone())),
             topic: Arc::new(FileTopicStorage::new(db.clone())),
             partition: Arc::new(FilePartitionStorage::new(db.clone())),
-            segment: Arc::new(FileSegmentStorage::new(persister.clone())),
+            segment: Arc::new(FileSegmentStorage::new(persister.clone(), remote_storage)),
         }
     }
 }
@@ -365,6 +426,18 @@ pub(crate) mod tests {
         ) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn save_schema(&self, _topic: &Topic, _schema: &TopicSchema) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_schemas(&self, _topic: &Topic) -> Result<Vec<TopicSchema>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_schema(&self, _topic: &Topic, _schema: &TopicSchema) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     #[async_trait]
@@ -407,6 +480,59 @@ pub(crate) mod tests {
         ) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn save_poisoned_offsets(
+            &self,
+            _poisoned_offsets: &PoisonedOffsets,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_poisoned_offsets(
+            &self,
+            _kind: ConsumerKind,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<Vec<PoisonedOffsets>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_poisoned_offsets(
+            &self,
+            _kind: ConsumerKind,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save_producer(&self, _producer: &ProducerState) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn load_producers(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<Vec<ProducerState>, Error> {
+            Ok(vec![])
+        }
+
+        async fn delete_producer(&self, _producer: &ProducerState) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete_producers(
+            &self,
+            _stream_id: u32,
+            _topic_id: u32,
+            _partition_id: u32,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     #[async_trait]
@@ -450,6 +576,22 @@ pub(crate) mod tests {
             Ok(0)
         }
 
+        async fn save_compacted_messages(
+            &self,
+            _segment: &Segment,
+            _messages: &[Arc<Message>],
+        ) -> Result<u32, Error> {
+            Ok(0)
+        }
+
+        async fn save_truncated_messages(
+            &self,
+            _segment: &Segment,
+            _messages: &[Arc<Message>],
+        ) -> Result<u32, Error> {
+            Ok(0)
+        }
+
         async fn load_message_ids(&self, _segment: &Segment) -> Result<Vec<u128>, Error> {
             Ok(vec![])
         }
@@ -499,6 +641,33 @@ pub(crate) mod tests {
         ) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn load_delayed_index(
+            &self,
+            _segment: &Segment,
+        ) -> Result<Option<Vec<(u64, u64)>>, Error> {
+            Ok(None)
+        }
+
+        async fn save_delayed_index(
+            &self,
+            _segment: &Segment,
+            _messages: &[Arc<Message>],
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn upload(&self, _segment: &Segment) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn mark_uploaded(&self, _segment: &Segment) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete_local_copy(&self, _segment: &Segment) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     pub fn get_test_system_storage() -> SystemStorage {
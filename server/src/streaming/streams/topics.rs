@@ -1,5 +1,6 @@
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::topic::Topic;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::utils::text;
@@ -10,13 +11,19 @@ impl Stream {
         self.topics.len() as u32
     }
 
-    pub async fn create_topic(
-        &mut self,
+    /// Creates the topic which is neither persisted nor added to the stream yet.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_topic(
+        &self,
         id: u32,
         name: &str,
         partitions_count: u32,
         message_expiry: Option<u32>,
-    ) -> Result<(), Error> {
+        compression_algorithm: CompressionAlgorithm,
+        compaction_enabled: bool,
+        max_topic_size: Option<u64>,
+        max_partition_size: Option<u64>,
+    ) -> Result<Topic, Error> {
         if self.topics.contains_key(&id) {
             return Err(Error::TopicIdAlreadyExists(id, self.stream_id));
         }
@@ -34,23 +41,36 @@ impl Stream {
             self.config.clone(),
             self.storage.clone(),
             message_expiry,
+            compression_algorithm,
+            compaction_enabled,
+            max_topic_size,
+            max_partition_size,
         )?;
-        topic.persist().await?;
+
+        Ok(topic)
+    }
+
+    pub fn add_topic(&mut self, topic: Topic) {
         info!(
-            "Created topic: {} with ID: {}, partitions: {}",
-            name, id, partitions_count
+            "Created topic: {} with ID: {}, partitions: {}, compression algorithm: {}, compaction enabled: {}",
+            topic.name,
+            topic.topic_id,
+            topic.get_partitions_count(),
+            topic.compression_algorithm,
+            topic.compaction_enabled
         );
-        self.topics_ids.insert(name, id);
-        self.topics.insert(id, topic);
-
-        Ok(())
+        self.topics_ids.insert(topic.name.clone(), topic.topic_id);
+        self.topics.insert(topic.topic_id, topic);
     }
 
+    /// Updates the in-memory state of the topic, which has to be persisted afterwards.
     pub async fn update_topic(
         &mut self,
         id: &Identifier,
         name: &str,
         message_expiry: Option<u32>,
+        max_topic_size: Option<u64>,
+        max_partition_size: Option<u64>,
     ) -> Result<(), Error> {
         let topic_id;
         {
@@ -82,6 +102,8 @@ impl Stream {
             let topic = self.get_topic_mut(id)?;
             topic.name = updated_name;
             topic.message_expiry = message_expiry;
+            topic.max_topic_size = max_topic_size;
+            topic.max_partition_size = max_partition_size;
             for partition in topic.partitions.values_mut() {
                 let mut partition = partition.write().await;
                 partition.message_expiry = message_expiry;
@@ -89,9 +111,6 @@ impl Stream {
                     segment.message_expiry = message_expiry;
                 }
             }
-
-            topic.persist().await?;
-            info!("Updated topic: {} with ID: {}", topic.name, id);
         }
 
         Ok(())
@@ -151,21 +170,11 @@ impl Stream {
         Ok(self.topics.get_mut(topic_id.unwrap()).unwrap())
     }
 
-    pub async fn delete_topic(&mut self, id: &Identifier) -> Result<Topic, Error> {
-        let topic_id;
-        let topic_name;
-        {
-            let topic = self.get_topic(id)?;
-            topic_id = topic.topic_id;
-            topic_name = topic.name.clone();
-            if topic.delete().await.is_err() {
-                return Err(Error::CannotDeleteTopic(topic.topic_id, self.stream_id));
-            }
-        }
-
+    /// Removes the topic from the stream, its files have to be deleted afterwards.
+    pub fn remove_topic(&mut self, id: &Identifier) -> Result<Topic, Error> {
+        let topic_id = self.get_topic(id)?.topic_id;
         let topic = self.topics.remove(&topic_id).unwrap();
-        self.topics_ids.remove(&topic_name);
-
+        self.topics_ids.remove(&topic.name);
         Ok(topic)
     }
 }
@@ -187,10 +196,19 @@ mod tests {
         let config = Arc::new(SystemConfig::default());
         let storage = Arc::new(get_test_system_storage());
         let mut stream = Stream::create(stream_id, stream_name, config, storage);
-        stream
-            .create_topic(topic_id, topic_name, 1, message_expiry)
-            .await
+        let topic = stream
+            .create_topic(
+                topic_id,
+                topic_name,
+                1,
+                message_expiry,
+                CompressionAlgorithm::default(),
+                false,
+                None,
+                None,
+            )
             .unwrap();
+        stream.add_topic(topic);
 
         let topic = stream.get_topic(&Identifier::numeric(topic_id).unwrap());
         assert!(topic.is_ok());
@@ -0,0 +1,544 @@
+use crate::configs::system::SystemConfig;
+use crate::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::Error;
+use iggy::models::backup::Backup;
+use iggy::utils::timestamp::TimeStamp;
+use serde::{Deserialize, Serialize};
+use sled::Db;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::sync::RwLock;
+use tokio::task;
+use tracing::{error, info, warn};
+
+const DATABASE_SNAPSHOT_FILE: &str = "database.snapshot";
+const BACKUP_EXTENSION: &str = "tar";
+const TEMP_EXTENSION: &str = "tmp";
+const SNAPSHOT_EXTENSION: &str = "snapshot";
+const PREVIOUS_EXTENSION: &str = "previous";
+const MANIFEST_FILE: &str = "manifest";
+const SEGMENT_EXTENSIONS: [&str; 3] = [LOG_EXTENSION, INDEX_EXTENSION, TIME_INDEX_EXTENSION];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupManifest {
+    database_snapshot_size: u64,
+    entries: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestEntry {
+    path: String,
+    size_bytes: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DatabaseSnapshot {
+    trees: Vec<TreeSnapshot>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TreeSnapshot {
+    name: Vec<u8>,
+    entries: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl System {
+    /// Creates the archive with the snapshot of the metadata database and the segment files.
+    /// The buffered messages are flushed first, then the metadata and the system write lock are held only
+    /// for the time of taking the snapshot: the database is dumped, the segment files are hard-linked (or copied)
+    /// into the staging directory and their current lengths are recorded, so the later appends are not included.
+    /// The archive is then written from the staging directory without holding any lock.
+    /// The objects offloaded to the remote storage are not included, only the references to them.
+    pub async fn create_backup(
+        system: &RwLock<System>,
+        session: &Session,
+    ) -> Result<Backup, Error> {
+        {
+            let system = system.read().await;
+            system.ensure_authenticated(session)?;
+            system.permissioner.create_backup(session.user_id)?;
+            if system.db.is_none() {
+                return Err(Error::CannotCreateBackup(system.config.get_backups_path()));
+            }
+
+            // Most of the buffered messages are saved before the lock is acquired, to keep the snapshot short.
+            system
+                .persist_messages(system.storage.segment.clone())
+                .await?;
+        }
+
+        let created_at = TimeStamp::now().to_micros();
+        let name = format!("backup_{}.{}", created_at, BACKUP_EXTENSION);
+        let (path, snapshot_path, streams_directory, database_snapshot, segments) = {
+            let _metadata_guard = System::lock_metadata(system).await;
+            let system = system.write().await;
+            let db = match system.db.as_ref() {
+                Some(db) => db.clone(),
+                None => return Err(Error::CannotCreateBackup(system.config.get_backups_path())),
+            };
+
+            let path = system.config.get_backup_path(&name);
+            info!("Creating backup: {}...", path);
+            system
+                .persist_messages(system.storage.segment.clone())
+                .await?;
+            let database_snapshot = match create_database_snapshot(&db).await {
+                Ok(snapshot) => snapshot,
+                Err(err) => {
+                    error!("Cannot create database snapshot. Error: {}", err);
+                    return Err(Error::CannotCreateBackup(path));
+                }
+            };
+
+            if !Path::new(&system.config.get_backups_path()).exists()
+                && fs::create_dir_all(&system.config.get_backups_path())
+                    .await
+                    .is_err()
+            {
+                return Err(Error::CannotCreateBackup(path));
+            }
+
+            let snapshot_path = format!("{}.{}", path, SNAPSHOT_EXTENSION);
+            let streams_path = system.config.get_streams_path();
+            let streams_directory = system.config.stream.path.clone();
+            let staging_path = format!("{}/{}", snapshot_path, streams_directory);
+            let result = task::spawn_blocking(move || {
+                snapshot_segments(Path::new(&streams_path), Path::new(&staging_path))
+            })
+            .await;
+            let segments = match result {
+                Ok(Ok(segments)) => segments,
+                Ok(Err(err)) => {
+                    error!(
+                        "Cannot snapshot segments for backup: {}. Error: {}",
+                        path, err
+                    );
+                    remove_snapshot(&snapshot_path).await;
+                    return Err(Error::CannotCreateBackup(path));
+                }
+                Err(err) => {
+                    error!(
+                        "Cannot snapshot segments for backup: {}. Error: {}",
+                        path, err
+                    );
+                    remove_snapshot(&snapshot_path).await;
+                    return Err(Error::CannotCreateBackup(path));
+                }
+            };
+            (
+                path,
+                snapshot_path,
+                streams_directory,
+                database_snapshot,
+                segments,
+            )
+        };
+
+        let archive_path = path.clone();
+        let archive_snapshot_path = snapshot_path.clone();
+        let result = task::spawn_blocking(move || {
+            write_archive(
+                &archive_path,
+                &database_snapshot,
+                &streams_directory,
+                Path::new(&archive_snapshot_path),
+                &segments,
+            )
+        })
+        .await;
+        remove_snapshot(&snapshot_path).await;
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => {
+                error!("Cannot write backup: {}. Error: {}", path, err);
+                return Err(Error::CannotCreateBackup(path));
+            }
+            Err(err) => {
+                error!("Cannot write backup: {}. Error: {}", path, err);
+                return Err(Error::CannotCreateBackup(path));
+            }
+        }
+
+        let size_bytes = fs::metadata(&path).await?.len();
+        info!("Created backup: {}, size: {} bytes.", path, size_bytes);
+        Ok(Backup {
+            name,
+            path,
+            size_bytes,
+            created_at,
+        })
+    }
+
+    /// Replaces the metadata database and the segment files with the ones from the backup archive.
+    /// The archive is extracted and validated against its manifest in the temporary directory first,
+    /// and the existing directories are swapped with the restored ones only then, so the current state is kept
+    /// if the backup is invalid or the restore fails at any point.
+    /// It must be invoked before the system is created, as the database cannot be opened more than once.
+    pub async fn restore_backup(config: &SystemConfig, path: &str) -> Result<(), Error> {
+        if !Path::new(path).exists() {
+            error!("Backup: {} does not exist.", path);
+            return Err(Error::CannotRestoreBackup(path.to_string()));
+        }
+
+        info!("Restoring the server state from backup: {}...", path);
+        let system_path = config.get_system_path();
+        if !Path::new(&system_path).exists() && fs::create_dir_all(&system_path).await.is_err() {
+            return Err(Error::CannotCreateBaseDirectory);
+        }
+
+        let restore_path = format!(
+            "{}/restore_{}.{}",
+            system_path,
+            TimeStamp::now().to_micros(),
+            TEMP_EXTENSION
+        );
+        if fs::create_dir_all(&restore_path).await.is_err() {
+            error!("Cannot create directory: {} for restore.", restore_path);
+            return Err(Error::CannotRestoreBackup(path.to_string()));
+        }
+
+        let archive_path = path.to_string();
+        let target_path = restore_path.clone();
+        let database_directory = config.database.path.clone();
+        let result = task::spawn_blocking(move || {
+            read_archive(&archive_path, &target_path, &database_directory)
+        })
+        .await;
+        let restored = match result {
+            Ok(Ok(())) => true,
+            Ok(Err(err)) => {
+                error!("Cannot restore backup: {}. Error: {}", path, err);
+                false
+            }
+            Err(err) => {
+                error!("Cannot restore backup: {}. Error: {}", path, err);
+                false
+            }
+        };
+
+        if restored {
+            let directories = [
+                (
+                    format!("{}/{}", restore_path, config.database.path),
+                    config.get_database_path(),
+                ),
+                (
+                    format!("{}/{}", restore_path, config.stream.path),
+                    config.get_streams_path(),
+                ),
+            ];
+            if let Err(err) = swap_directories(&directories).await {
+                error!(
+                    "Cannot swap directories for backup: {}. Error: {}",
+                    path, err
+                );
+                remove_snapshot(&restore_path).await;
+                return Err(Error::CannotRestoreBackup(path.to_string()));
+            }
+        }
+
+        remove_snapshot(&restore_path).await;
+        if !restored {
+            return Err(Error::CannotRestoreBackup(path.to_string()));
+        }
+
+        info!("Restored the server state from backup: {}.", path);
+        Ok(())
+    }
+}
+
+async fn remove_snapshot(path: &str) {
+    if Path::new(path).exists() && fs::remove_dir_all(path).await.is_err() {
+        warn!("Cannot remove temporary backup directory: {}.", path);
+    }
+}
+
+/// Moves each restored directory to its target path, keeping the previous one aside until all of them are in place.
+/// If any of the renames fails, the already swapped directories are rolled back.
+async fn swap_directories(directories: &[(String, String)]) -> Result<(), std::io::Error> {
+    let mut swapped = Vec::new();
+    let mut result = Ok(());
+    for (restored_path, target_path) in directories {
+        let previous_path = format!("{}.{}", target_path, PREVIOUS_EXTENSION);
+        if Path::new(&previous_path).exists() {
+            fs::remove_dir_all(&previous_path).await?;
+        }
+
+        let has_previous = Path::new(target_path).exists();
+        if has_previous {
+            if let Err(err) = fs::rename(target_path, &previous_path).await {
+                result = Err(err);
+                break;
+            }
+        }
+
+        if let Err(err) = fs::rename(restored_path, target_path).await {
+            if has_previous && fs::rename(&previous_path, target_path).await.is_err() {
+                error!("Cannot roll back directory: {}.", target_path);
+            }
+            result = Err(err);
+            break;
+        }
+
+        swapped.push((target_path, previous_path, has_previous));
+    }
+
+    if result.is_err() {
+        for (target_path, previous_path, has_previous) in swapped.into_iter().rev() {
+            if fs::remove_dir_all(target_path).await.is_err()
+                || (has_previous && fs::rename(&previous_path, target_path).await.is_err())
+            {
+                error!("Cannot roll back directory: {}.", target_path);
+            }
+        }
+        return result;
+    }
+
+    for (_, previous_path, has_previous) in swapped {
+        if has_previous && fs::remove_dir_all(&previous_path).await.is_err() {
+            warn!("Cannot remove previous directory: {}.", previous_path);
+        }
+    }
+    Ok(())
+}
+
+async fn create_database_snapshot(db: &Db) -> Result<Vec<u8>, anyhow::Error> {
+    db.flush_async().await?;
+    let mut trees = Vec::new();
+    for name in db.tree_names() {
+        let tree = db.open_tree(&name)?;
+        let mut entries = Vec::new();
+        for entry in tree.iter() {
+            let (key, value) = entry?;
+            entries.push((key.to_vec(), value.to_vec()));
+        }
+        trees.push(TreeSnapshot {
+            name: name.to_vec(),
+            entries,
+        });
+    }
+
+    Ok(rmp_serde::to_vec(&DatabaseSnapshot { trees })?)
+}
+
+fn restore_database_snapshot(path: &str, snapshot: &[u8]) -> Result<(), anyhow::Error> {
+    let snapshot = rmp_serde::from_slice::<DatabaseSnapshot>(snapshot)?;
+    let db = sled::open(path)?;
+    for tree_snapshot in snapshot.trees {
+        let tree = db.open_tree(tree_snapshot.name)?;
+        for (key, value) in tree_snapshot.entries {
+            tree.insert(key, value)?;
+        }
+    }
+    db.flush()?;
+    Ok(())
+}
+
+/// Hard-links (or copies, if linking is not possible) the segment files into the staging directory
+/// and copies the remaining files, returning the lengths of the segment files at the time of the snapshot.
+/// The segment files are append-only, so the recorded length bounds what is archived from the linked file.
+fn snapshot_segments(source: &Path, target: &Path) -> Result<Vec<ManifestEntry>, anyhow::Error> {
+    let mut segments = Vec::new();
+    std::fs::create_dir_all(target)?;
+    if source.exists() {
+        snapshot_directory(source, target, Path::new(""), &mut segments)?;
+    }
+    Ok(segments)
+}
+
+fn snapshot_directory(
+    source: &Path,
+    target: &Path,
+    relative_path: &Path,
+    segments: &mut Vec<ManifestEntry>,
+) -> Result<(), anyhow::Error> {
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let source_path = entry.path();
+        let target_path = target.join(entry.file_name());
+        let relative_path = relative_path.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            std::fs::create_dir_all(&target_path)?;
+            snapshot_directory(&source_path, &target_path, &relative_path, segments)?;
+            continue;
+        }
+
+        let is_segment = source_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| SEGMENT_EXTENSIONS.contains(&extension))
+            .unwrap_or(false);
+        if !is_segment {
+            std::fs::copy(&source_path, &target_path)?;
+            continue;
+        }
+
+        let size_bytes = entry.metadata()?.len();
+        if std::fs::hard_link(&source_path, &target_path).is_err() {
+            std::fs::copy(&source_path, &target_path)?;
+        }
+        segments.push(ManifestEntry {
+            path: relative_path.to_string_lossy().to_string(),
+            size_bytes,
+        });
+    }
+    Ok(())
+}
+
+fn write_archive(
+    path: &str,
+    database_snapshot: &[u8],
+    streams_directory: &str,
+    snapshot_path: &Path,
+    segments: &[ManifestEntry],
+) -> Result<(), anyhow::Error> {
+    // The archive is written under the temporary name first, so the incomplete backups are never visible.
+    let temp_path = format!("{}.{}", path, TEMP_EXTENSION);
+    let file = std::fs::File::create(&temp_path)?;
+    let mut builder = tar::Builder::new(file);
+    let streams_path = snapshot_path.join(streams_directory);
+    let mut files = Vec::new();
+    collect_files(&streams_path, Path::new(""), &mut files)?;
+    let segment_sizes = segments
+        .iter()
+        .map(|segment| (segment.path.as_str(), segment.size_bytes))
+        .collect::<HashMap<_, _>>();
+
+    let mut entries = Vec::with_capacity(files.len());
+    for relative_path in &files {
+        let key = relative_path.to_string_lossy().to_string();
+        let size_bytes = match segment_sizes.get(key.as_str()) {
+            Some(size_bytes) => *size_bytes,
+            None => std::fs::metadata(streams_path.join(relative_path))?.len(),
+        };
+        entries.push(ManifestEntry {
+            path: format!("{}/{}", streams_directory, key),
+            size_bytes,
+        });
+    }
+
+    let manifest = BackupManifest {
+        database_snapshot_size: database_snapshot.len() as u64,
+        entries,
+    };
+    append_data(&mut builder, MANIFEST_FILE, &rmp_serde::to_vec(&manifest)?)?;
+    append_data(&mut builder, DATABASE_SNAPSHOT_FILE, database_snapshot)?;
+    for (relative_path, entry) in files.iter().zip(manifest.entries.iter()) {
+        // Only the length recorded in the snapshot is archived, as the active segments are still being appended.
+        let file = std::fs::File::open(streams_path.join(relative_path))?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(entry.size_bytes);
+        header.set_mode(0o644);
+        header.set_mtime(TimeStamp::now().to_secs());
+        header.set_cksum();
+        builder.append_data(&mut header, &entry.path, file.take(entry.size_bytes))?;
+    }
+    let file = builder.into_inner()?;
+    file.sync_all()?;
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+fn append_data(
+    builder: &mut tar::Builder<std::fs::File>,
+    path: &str,
+    data: &[u8],
+) -> Result<(), anyhow::Error> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(TimeStamp::now().to_secs());
+    header.set_cksum();
+    builder.append_data(&mut header, path, data)?;
+    Ok(())
+}
+
+fn collect_files(
+    path: &Path,
+    relative_path: &Path,
+    files: &mut Vec<PathBuf>,
+) -> Result<(), anyhow::Error> {
+    if !path.exists() {
+        return Ok(());
+    }
+
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        let relative_path = relative_path.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            collect_files(&entry.path(), &relative_path, files)?;
+        } else {
+            files.push(relative_path);
+        }
+    }
+    Ok(())
+}
+
+/// Extracts the archive into the given directory and validates the extracted files against the manifest.
+fn read_archive(
+    path: &str,
+    target_path: &str,
+    database_directory: &str,
+) -> Result<(), anyhow::Error> {
+    let file = std::fs::File::open(path)?;
+    let mut archive = tar::Archive::new(file);
+    let mut manifest = None;
+    let mut database_snapshot = None;
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_path = entry.path()?.to_path_buf();
+        if entry_path.as_os_str() == MANIFEST_FILE
+            || entry_path.as_os_str() == DATABASE_SNAPSHOT_FILE
+        {
+            let mut data = Vec::with_capacity(entry.size() as usize);
+            entry.read_to_end(&mut data)?;
+            if entry_path.as_os_str() == MANIFEST_FILE {
+                manifest = Some(rmp_serde::from_slice::<BackupManifest>(&data)?);
+            } else {
+                database_snapshot = Some(data);
+            }
+            continue;
+        }
+
+        // The entries pointing outside the target path are skipped.
+        if !entry.unpack_in(target_path)? {
+            warn!(
+                "Skipped backup entry: {} outside of the system path.",
+                entry_path.display()
+            );
+        }
+    }
+
+    let Some(manifest) = manifest else {
+        return Err(anyhow::anyhow!("missing backup manifest"));
+    };
+    let Some(database_snapshot) = database_snapshot else {
+        return Err(anyhow::anyhow!("missing database snapshot"));
+    };
+    if database_snapshot.len() as u64 != manifest.database_snapshot_size {
+        return Err(anyhow::anyhow!("invalid database snapshot size"));
+    }
+
+    for entry in &manifest.entries {
+        let entry_path = Path::new(target_path).join(&entry.path);
+        let size_bytes = std::fs::metadata(&entry_path)
+            .map_err(|_| anyhow::anyhow!("missing backup entry: {}", entry.path))?
+            .len();
+        if size_bytes != entry.size_bytes {
+            return Err(anyhow::anyhow!(
+                "invalid size of backup entry: {}",
+                entry.path
+            ));
+        }
+    }
+
+    restore_database_snapshot(
+        &format!("{}/{}", target_path, database_directory),
+        &database_snapshot,
+    )?;
+    Ok(())
+}
@@ -14,6 +14,8 @@ impl System {
         let client_id = client_manager.add_client(address, transport);
         info!("Added {transport} client with ID: {client_id} for address: {address}");
         self.metrics.increment_clients(1);
+        self.metrics
+            .increment_connections(&transport.to_string().to_lowercase());
         client_id
     }
 
@@ -56,6 +58,7 @@ impl System {
             }
         }
 
+        self.quotas.delete_client_usage(client_id);
         {
             let mut client_manager = self.client_manager.write().await;
             let client = client_manager.delete_client(address);
@@ -66,6 +69,8 @@ impl System {
             self.metrics.decrement_clients(1);
             let client = client.unwrap();
             let client = client.read().await;
+            self.metrics
+                .decrement_connections(&client.transport.to_string().to_lowercase());
 
             info!(
                 "Deleted {} client with ID: {} for address: {}",
@@ -0,0 +1,333 @@
+use crate::streaming::cluster::cluster_manager::{ClusterManager, PartitionKey};
+use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::cluster::replicate_messages::ReplicateMessages;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::models::cluster::ClusterMetadata;
+use iggy::models::messages::Message;
+use iggy::models::stream::StreamDetails;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, trace};
+
+impl System {
+    pub fn get_cluster(&self) -> Result<Arc<ClusterManager>, Error> {
+        self.cluster.clone().ok_or(Error::ClusterDisabled)
+    }
+
+    pub fn get_partition_keys(&self) -> Vec<PartitionKey> {
+        let mut keys = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition_id in topic.partitions.keys() {
+                    keys.push((stream.stream_id, topic.topic_id, *partition_id));
+                }
+            }
+        }
+        keys.sort();
+        keys
+    }
+
+    pub async fn get_cluster_metadata(&self, session: &Session) -> Result<ClusterMetadata, Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_cluster_metadata(session.user_id)?;
+        let cluster = self.get_cluster()?;
+        Ok(cluster.get_metadata(&self.get_partition_keys()).await)
+    }
+
+    /// The changes of the streams, topics and partitions are accepted only by the controller,
+    /// the other nodes synchronize them from the controller.
+    pub async fn ensure_cluster_controller(&self) -> Result<(), Error> {
+        let Some(cluster) = &self.cluster else {
+            return Ok(());
+        };
+
+        let Some(controller_id) = cluster.get_controller_id().await else {
+            return Err(Error::ClusterQuorumUnavailable);
+        };
+        if controller_id != cluster.node_id {
+            return Err(Error::NotClusterController(controller_id));
+        }
+
+        Ok(())
+    }
+
+    pub async fn ensure_partition_leader(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<(), Error> {
+        let Some(cluster) = &self.cluster else {
+            return Ok(());
+        };
+
+        let leader_id = cluster
+            .get_leader_id(stream_id, topic_id, partition_id)
+            .await;
+        if leader_id != cluster.node_id {
+            return Err(Error::NotPartitionLeader(
+                partition_id,
+                topic_id,
+                stream_id,
+                leader_id,
+            ));
+        }
+
+        // The leader cut off from the majority of the nodes might have been already replaced.
+        if !cluster.has_quorum().await {
+            return Err(Error::ClusterQuorumUnavailable);
+        }
+
+        // The followers truncate the messages from the previous leaders starting at this offset.
+        let key = (stream_id, topic_id, partition_id);
+        let leader_epoch = cluster.get_leader_epoch(&key).await;
+        if cluster
+            .get_epoch_start_offset(&key, leader_epoch)
+            .await
+            .is_none()
+        {
+            let partition = self
+                .get_stream(&Identifier::numeric(stream_id)?)?
+                .get_topic(&Identifier::numeric(topic_id)?)?
+                .get_partition(partition_id)?;
+            let next_offset = partition.read().await.get_next_offset();
+            cluster
+                .record_epoch_start_offset(key, leader_epoch, next_offset)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the start offset of the current leader epoch and the messages (still encrypted, if the encryption is enabled)
+    /// requested by the follower, and records its progress, which is used to track the in-sync replicas of the partition.
+    /// The requests with the leader epoch different from the current one are rejected.
+    pub async fn replicate_messages(
+        &self,
+        session: &Session,
+        command: &ReplicateMessages,
+    ) -> Result<(u64, PolledMessages), Error> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.replicate_messages(session.user_id)?;
+        let cluster = self.get_cluster()?;
+        self.ensure_partition_leader(command.stream_id, command.topic_id, command.partition_id)
+            .await?;
+        let key = (command.stream_id, command.topic_id, command.partition_id);
+        let leader_epoch = cluster.get_leader_epoch(&key).await;
+        let epoch_start_offset = match cluster.get_epoch_start_offset(&key, leader_epoch).await {
+            Some(offset) if command.leader_epoch == leader_epoch => offset,
+            _ => {
+                return Err(Error::InvalidLeaderEpoch(
+                    command.leader_epoch,
+                    command.partition_id,
+                    command.topic_id,
+                    command.stream_id,
+                ))
+            }
+        };
+        let partition = self
+            .get_stream(&Identifier::numeric(command.stream_id)?)?
+            .get_topic(&Identifier::numeric(command.topic_id)?)?
+            .get_partition(command.partition_id)?;
+        let partition = partition.read().await;
+        let messages = partition
+            .get_messages_by_offset(command.offset, command.count)
+            .await?;
+        cluster
+            .record_replica_progress(
+                key,
+                command.replica_id,
+                command.offset,
+                partition.get_next_offset(),
+            )
+            .await;
+        Ok((
+            epoch_start_offset,
+            PolledMessages {
+                messages,
+                partition_id: command.partition_id,
+                current_offset: partition.current_offset,
+                last_scanned_offset: None,
+                generation: 0,
+            },
+        ))
+    }
+
+    /// Returns the offset, from which the follower should fetch the next messages of the partition.
+    pub async fn get_replication_offset(&self, key: PartitionKey) -> Result<u64, Error> {
+        let partition = self
+            .get_stream(&Identifier::numeric(key.0)?)?
+            .get_topic(&Identifier::numeric(key.1)?)?
+            .get_partition(key.2)?;
+        let partition = partition.read().await;
+        Ok(partition.get_next_offset())
+    }
+
+    /// Appends the messages fetched from the leader in the given epoch. The first time the messages from the new epoch
+    /// are appended, the messages from the start offset of the epoch are truncated, as they were not replicated by the new leader.
+    pub async fn append_replicated_messages(
+        &self,
+        key: PartitionKey,
+        leader_epoch: u32,
+        epoch_start_offset: u64,
+        messages: Vec<Message>,
+    ) -> Result<u32, Error> {
+        let topic = self
+            .get_stream(&Identifier::numeric(key.0)?)?
+            .get_topic(&Identifier::numeric(key.1)?)?;
+        let batch_size_bytes = messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum();
+        self.ensure_cache_capacity(batch_size_bytes).await;
+        let partition = topic.get_partition(key.2)?;
+        let mut partition = partition.write().await;
+        let appended_messages = partition
+            .append_replicated_messages(
+                leader_epoch,
+                epoch_start_offset,
+                messages.into_iter().map(Arc::new).collect(),
+            )
+            .await?;
+        if appended_messages.count > 0 {
+            partition.messages_notifier.notify_waiters();
+            self.metrics.increment_appended_messages(
+                key.0,
+                key.1,
+                key.2,
+                appended_messages.count as u64,
+                appended_messages.size_bytes,
+            );
+            trace!(
+                "Replicated {} messages to partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                appended_messages.count, key.2, key.1, key.0
+            );
+        }
+        Ok(appended_messages.count)
+    }
+
+    /// Creates and deletes the streams, topics and partitions, so that they match the ones of the controller.
+    /// The streams and topics are identified by their IDs, the other changes (e.g. the renames) are not synchronized.
+    pub async fn sync_cluster_streams(
+        system: &RwLock<System>,
+        streams: &[StreamDetails],
+    ) -> Result<(), Error> {
+        let session = Session::stateless(DEFAULT_ROOT_USER_ID);
+        let deleted_streams = {
+            let system = system.read().await;
+            system
+                .streams
+                .keys()
+                .filter(|stream_id| !streams.iter().any(|stream| stream.id == **stream_id))
+                .copied()
+                .collect::<Vec<_>>()
+        };
+        for stream_id in deleted_streams {
+            info!("Deleting stream with ID: {stream_id} removed on the cluster controller...");
+            System::delete_stream(system, &session, &Identifier::numeric(stream_id)?).await?;
+        }
+
+        for stream in streams {
+            let stream_id = Identifier::numeric(stream.id)?;
+            let local_topics = {
+                let system = system.read().await;
+                system.streams.get(&stream.id).map(|local_stream| {
+                    local_stream
+                        .topics
+                        .values()
+                        .map(|topic| (topic.topic_id, topic.get_partitions_count()))
+                        .collect::<Vec<_>>()
+                })
+            };
+            let Some(local_topics) = local_topics else {
+                info!(
+                    "Creating stream with ID: {} from the cluster controller...",
+                    stream.id
+                );
+                System::create_stream(system, &session, stream.id, &stream.name).await?;
+                for topic in &stream.topics {
+                    System::create_topic_from_controller(system, &session, &stream_id, topic)
+                        .await?;
+                }
+                continue;
+            };
+
+            for (topic_id, _) in &local_topics {
+                if !stream.topics.iter().any(|topic| topic.id == *topic_id) {
+                    info!("Deleting topic with ID: {topic_id} for stream with ID: {} removed on the cluster controller...", stream.id);
+                    System::delete_topic(
+                        system,
+                        &session,
+                        &stream_id,
+                        &Identifier::numeric(*topic_id)?,
+                    )
+                    .await?;
+                }
+            }
+
+            for topic in &stream.topics {
+                let Some((_, partitions_count)) = local_topics
+                    .iter()
+                    .find(|(topic_id, _)| *topic_id == topic.id)
+                else {
+                    System::create_topic_from_controller(system, &session, &stream_id, topic)
+                        .await?;
+                    continue;
+                };
+
+                let topic_id = Identifier::numeric(topic.id)?;
+                if topic.partitions_count > *partitions_count {
+                    System::create_partitions(
+                        system,
+                        &session,
+                        &stream_id,
+                        &topic_id,
+                        topic.partitions_count - partitions_count,
+                    )
+                    .await?;
+                } else if topic.partitions_count < *partitions_count {
+                    System::delete_partitions(
+                        system,
+                        &session,
+                        &stream_id,
+                        &topic_id,
+                        partitions_count - topic.partitions_count,
+                    )
+                    .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn create_topic_from_controller(
+        system: &RwLock<System>,
+        session: &Session,
+        stream_id: &Identifier,
+        topic: &iggy::models::topic::Topic,
+    ) -> Result<(), Error> {
+        info!(
+            "Creating topic with ID: {} for stream with ID: {} from the cluster controller...",
+            topic.id, stream_id
+        );
+        System::create_topic(
+            system,
+            session,
+            stream_id,
+            topic.id,
+            &topic.name,
+            topic.partitions_count,
+            topic.message_expiry,
+            topic.compression_algorithm,
+            topic.compaction_enabled,
+            topic.max_topic_size,
+            topic.max_partition_size,
+        )
+        .await
+    }
+}
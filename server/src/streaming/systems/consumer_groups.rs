@@ -1,9 +1,12 @@
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_group::DeadLetterTopic;
 use tokio::sync::RwLock;
+use tracing::warn;
 
 impl System {
     pub fn get_consumer_group(
@@ -37,6 +40,7 @@ impl System {
         Ok(topic.get_consumer_groups())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_consumer_group(
         &mut self,
         session: &Session,
@@ -44,6 +48,9 @@ impl System {
         topic_id: &Identifier,
         consumer_group_id: u32,
         name: &str,
+        partition_assignment_strategy: PartitionAssignmentStrategy,
+        session_timeout: Option<u32>,
+        dead_letter_topic: Option<DeadLetterTopic>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
         {
@@ -54,10 +61,26 @@ impl System {
                 stream.stream_id,
                 topic.topic_id,
             )?;
+
+            if let Some(dead_letter_topic) = dead_letter_topic {
+                self.validate_dead_letter_topic(
+                    stream.stream_id,
+                    topic.topic_id,
+                    dead_letter_topic,
+                )?;
+            }
         }
 
         let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
-        topic.create_consumer_group(consumer_group_id, name).await?;
+        topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                partition_assignment_strategy,
+                session_timeout,
+                dead_letter_topic,
+            )
+            .await?;
         Ok(())
     }
 
@@ -179,6 +202,61 @@ impl System {
         .await
     }
 
+    pub async fn heartbeat_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .join_consumer_group(session.user_id, stream.stream_id, topic.topic_id)?;
+        topic
+            .heartbeat_consumer_group(consumer_group_id, session.client_id)
+            .await
+    }
+
+    /// Removes the members, which exceeded the session timeout of their consumer group, so that their partitions can be reassigned.
+    pub async fn delete_stale_consumer_group_members(&self, now: u64) -> Result<u32, Error> {
+        let mut stale_members = Vec::new();
+        for stream in self.get_streams() {
+            for topic in stream.get_topics() {
+                for consumer_group in topic.get_consumer_groups() {
+                    let consumer_group = consumer_group.read().await;
+                    for member_id in consumer_group.get_stale_members(now).await {
+                        stale_members.push((
+                            stream.stream_id,
+                            topic.topic_id,
+                            consumer_group.consumer_group_id,
+                            member_id,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut deleted_members_count = 0;
+        for (stream_id, topic_id, consumer_group_id, member_id) in stale_members {
+            self.leave_consumer_group_by_client(
+                &Identifier::numeric(stream_id)?,
+                &Identifier::numeric(topic_id)?,
+                &Identifier::numeric(consumer_group_id)?,
+                member_id,
+            )
+            .await?;
+            warn!(
+                "Member with ID: {} exceeded the session timeout and was removed from consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
+                member_id, consumer_group_id, topic_id, stream_id
+            );
+            deleted_members_count += 1;
+        }
+
+        Ok(deleted_members_count)
+    }
+
     pub async fn leave_consumer_group_by_client(
         &self,
         stream_id: &Identifier,
@@ -211,4 +289,23 @@ impl System {
             .leave_consumer_group(client_id, stream_id_value, topic_id_value, group_id)
             .await
     }
+
+    /// The dead letter topic must exist and can't be the same topic, which is consumed by the group.
+    fn validate_dead_letter_topic(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        dead_letter_topic: DeadLetterTopic,
+    ) -> Result<(), Error> {
+        if dead_letter_topic.stream_id == stream_id && dead_letter_topic.topic_id == topic_id {
+            return Err(Error::InvalidDeadLetterTopic(
+                dead_letter_topic.topic_id,
+                dead_letter_topic.stream_id,
+            ));
+        }
+
+        self.get_stream(&Identifier::numeric(dead_letter_topic.stream_id)?)?
+            .get_topic(&Identifier::numeric(dead_letter_topic.topic_id)?)?;
+        Ok(())
+    }
 }
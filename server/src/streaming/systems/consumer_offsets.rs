@@ -1,8 +1,10 @@
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
 
 impl System {
@@ -41,4 +43,50 @@ impl System {
 
         topic.get_consumer_offset(consumer).await
     }
+
+    pub async fn get_consumer_lag(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<ConsumerLag, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .get_consumer_offset(session.user_id, stream.stream_id, topic.topic_id)?;
+
+        topic.get_consumer_lag(consumer).await
+    }
+
+    /// Refreshes the consumer lag gauges for all the consumers and consumer groups that have stored the offsets.
+    pub async fn update_consumer_lag_metrics(&self) {
+        self.metrics.clear_consumer_lag();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    for (kind, offsets) in [
+                        (ConsumerKind::Consumer, &partition.consumer_offsets),
+                        (
+                            ConsumerKind::ConsumerGroup,
+                            &partition.consumer_group_offsets,
+                        ),
+                    ] {
+                        for (consumer_id, consumer_offset) in offsets.read().await.iter() {
+                            self.metrics.set_consumer_lag(
+                                stream.stream_id,
+                                topic.topic_id,
+                                partition.partition_id,
+                                kind,
+                                *consumer_id,
+                                partition.calculate_consumer_lag(Some(consumer_offset.offset)),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
@@ -1,32 +1,143 @@
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::cluster::cluster_manager::PartitionKey;
 use crate::streaming::models::messages::PolledMessages;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use crate::streaming::transactions::transaction::TransactionBatch;
 use bytes::Bytes;
+use futures::future;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::messages::message_filter::MessageFilter;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
-use iggy::models::messages::Message;
+use iggy::messages::send_messages::{Acks, Partitioning, Producer};
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::timestamp::TimeStamp;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::time::{sleep_until, timeout_at, Instant};
 use tracing::{error, trace};
 
 impl System {
+    /// Polls the messages and, if none are available yet and `max_wait` was specified,
+    /// parks the request until the new messages are appended to the polled partitions, the next delayed message
+    /// in them becomes due or the timeout fires. The wait is limited by the `max_wait` of the polling config.
+    /// The system lock is released while waiting, so that the appends can proceed.
+    pub async fn poll_messages_with_wait(
+        system: &RwLock<System>,
+        session: &Session,
+        consumer: PollingConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        args: PollingArgs,
+    ) -> Result<PolledMessages, Error> {
+        let max_wait = {
+            let system = system.read().await;
+            system.ensure_authenticated(session)?;
+            // The request is counted once, no matter how many times it is retried while waiting for the messages.
+            system
+                .quotas
+                .acquire(session.user_id, session.client_id, 0)?;
+            args.max_wait.min(system.config.polling.max_wait)
+        };
+
+        let deadline = Instant::now() + Duration::from_millis(max_wait as u64);
+        // The consumer group member polls its partitions in turn, so each of them is polled before waiting.
+        let mut polls_before_wait = None;
+        let polled_messages = loop {
+            let system = system.read().await;
+            system.ensure_authenticated(session)?;
+            let topic = system.get_stream(stream_id)?.get_topic(topic_id)?;
+            let notifiers = topic.get_messages_notifiers(consumer).await;
+            // Registered before polling, so the messages appended in the meantime will not be missed.
+            let notified = notifiers
+                .iter()
+                .map(|(_, notifier)| Box::pin(notifier.notified()))
+                .collect::<Vec<_>>();
+            let polled_messages = system
+                .poll_messages(session, consumer, stream_id, topic_id, &args)
+                .await?;
+            let now = TimeStamp::now().to_micros();
+            let mut next_delivery_at = None;
+            for (partition_id, _) in &notifiers {
+                if let Some(deliver_at) = topic.get_next_delivery_at(*partition_id, now).await {
+                    next_delivery_at =
+                        Some(next_delivery_at.map_or(deliver_at, |next: u64| next.min(deliver_at)));
+                }
+            }
+            drop(system);
+            if !polled_messages.messages.is_empty() || Instant::now() >= deadline {
+                break polled_messages;
+            }
+
+            let remaining_polls = polls_before_wait
+                .unwrap_or(notifiers.len())
+                .saturating_sub(1);
+            polls_before_wait = Some(remaining_polls);
+            if remaining_polls > 0 {
+                continue;
+            }
+
+            trace!(
+                "No messages available for {}, stream: {}, topic: {}, waiting for the new ones...",
+                consumer,
+                stream_id,
+                topic_id
+            );
+            // There's no append announcing the delayed message becoming due, so the wait ends by then.
+            let wait_until = next_delivery_at
+                .map(|deliver_at| {
+                    let delay = deliver_at.saturating_sub(TimeStamp::now().to_micros());
+                    Instant::now() + Duration::from_micros(delay)
+                })
+                .filter(|wake_at| *wake_at < deadline)
+                .unwrap_or(deadline);
+            let notified = match notified.is_empty() {
+                true => {
+                    sleep_until(wait_until).await;
+                    false
+                }
+                false => timeout_at(wait_until, future::select_all(notified))
+                    .await
+                    .is_ok(),
+            };
+            if !notified && wait_until == deadline {
+                break polled_messages;
+            }
+
+            polls_before_wait = None;
+        };
+
+        let polled_bytes = polled_messages
+            .messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum();
+        system
+            .read()
+            .await
+            .quotas
+            .record_bytes(session.user_id, session.client_id, polled_bytes);
+        Ok(polled_messages)
+    }
+
     pub async fn poll_messages(
         &self,
         session: &Session,
         consumer: PollingConsumer,
         stream_id: &Identifier,
         topic_id: &Identifier,
-        args: PollingArgs,
+        args: &PollingArgs,
     ) -> Result<PolledMessages, Error> {
         self.ensure_authenticated(session)?;
         if args.count == 0 {
             return Err(Error::InvalidMessagesCount);
         }
 
+        let poll_timestamp = Instant::now();
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner
@@ -36,38 +147,63 @@ impl System {
             return Err(Error::NoPartitions(topic.topic_id, topic.stream_id));
         }
 
-        let partition_id = match consumer {
-            PollingConsumer::Consumer(_, partition_id) => partition_id,
+        let (partition_id, generation) = match consumer {
+            PollingConsumer::Consumer(_, partition_id) => (partition_id, 0),
             PollingConsumer::ConsumerGroup(consumer_group_id, member_id) => {
                 let consumer_group = topic
                     .get_consumer_group_by_id(consumer_group_id)?
                     .read()
                     .await;
-                consumer_group.calculate_partition_id(member_id).await?
+                (
+                    consumer_group.calculate_partition_id(member_id).await?,
+                    consumer_group.get_generation(),
+                )
             }
         };
 
         let mut polled_messages = topic
-            .get_messages(consumer, partition_id, args.strategy, args.count)
+            .get_messages(
+                consumer,
+                partition_id,
+                args.strategy,
+                args.count,
+                args.filter.as_ref(),
+            )
             .await?;
+        polled_messages.generation = generation;
+        self.metrics.increment_messages_out(
+            stream.stream_id,
+            topic.topic_id,
+            partition_id,
+            polled_messages.messages.len() as u64,
+            polled_messages
+                .messages
+                .iter()
+                .map(|message| message.get_size_bytes() as u64)
+                .sum(),
+        );
+        self.metrics.observe_poll_latency(poll_timestamp.elapsed());
 
-        if polled_messages.messages.is_empty() {
-            return Ok(polled_messages);
-        }
-
-        let offset = polled_messages.messages.last().unwrap().offset;
         if args.auto_commit {
-            trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
-            topic.store_consumer_offset(consumer, offset).await?;
+            if let Some(offset) = polled_messages.last_scanned_offset {
+                trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
+                topic.store_consumer_offset(consumer, offset).await?;
+            }
         }
 
-        if self.encryptor.is_none() {
+        if polled_messages.messages.is_empty() || self.encryptor.is_none() {
             return Ok(polled_messages);
         }
 
         let encryptor = self.encryptor.as_ref().unwrap();
         let mut decrypted_messages = Vec::with_capacity(polled_messages.messages.len());
         for message in polled_messages.messages.iter() {
+            // The tombstones are stored with the empty payload, which is never encrypted.
+            if message.payload.is_empty() {
+                decrypted_messages.push(message.clone());
+                continue;
+            }
+
             let payload = encryptor.decrypt(&message.payload);
             if payload.is_err() {
                 error!("Cannot decrypt the message.");
@@ -91,19 +227,73 @@ impl System {
         Ok(polled_messages)
     }
 
+    /// Appends the messages and, in the cluster mode with `Acks::All`, waits until they have been replicated
+    /// by all the in-sync replicas of the partition. The system lock is released while waiting.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn append_messages_with_acks(
+        system: &RwLock<System>,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        producer: Option<&Producer>,
+        transaction_id: Option<u64>,
+        acks: Acks,
+        messages: &Vec<send_messages::Message>,
+    ) -> Result<(), Error> {
+        let (cluster, appended_offset) = {
+            let system = system.read().await;
+            let appended_offset = system
+                .append_messages(
+                    session,
+                    stream_id,
+                    topic_id,
+                    partitioning,
+                    producer,
+                    transaction_id,
+                    messages,
+                )
+                .await?;
+            (system.cluster.clone(), appended_offset)
+        };
+
+        if acks != Acks::All {
+            return Ok(());
+        }
+
+        match (cluster, appended_offset) {
+            (Some(cluster), Some((key, offset))) => cluster.wait_for_replicas(key, offset).await,
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the partition and the offset of the last appended message, unless the messages were added to the transaction.
+    #[allow(clippy::too_many_arguments)]
     pub async fn append_messages(
         &self,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitioning: &Partitioning,
+        producer: Option<&Producer>,
+        transaction_id: Option<u64>,
         messages: &Vec<send_messages::Message>,
-    ) -> Result<(), Error> {
+    ) -> Result<Option<(PartitionKey, u64)>, Error> {
         self.ensure_authenticated(session)?;
+        let append_timestamp = Instant::now();
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
         self.permissioner
             .append_messages(session.user_id, stream.stream_id, topic.topic_id)?;
+        topic.validate_messages(messages)?;
+        self.quotas.acquire(
+            session.user_id,
+            session.client_id,
+            messages
+                .iter()
+                .map(|message| message.get_size_bytes() as u64)
+                .sum(),
+        )?;
 
         let mut received_messages = Vec::with_capacity(messages.len());
         let mut batch_size_bytes = 0u64;
@@ -112,7 +302,7 @@ impl System {
         for message in messages {
             let encrypted_message;
             let message = match self.encryptor {
-                Some(ref encryptor) => {
+                Some(ref encryptor) if !message.payload.is_empty() => {
                     let payload = encryptor.encrypt(message.payload.as_ref())?;
                     encrypted_message = send_messages::Message {
                         id: message.id,
@@ -122,12 +312,175 @@ impl System {
                     };
                     &encrypted_message
                 }
-                None => message,
+                _ => message,
             };
             batch_size_bytes += message.get_size_bytes() as u64;
             received_messages.push(Message::from_message(message));
         }
 
+        let (partition_id, received_messages) =
+            topic.prepare_messages(partitioning, received_messages)?;
+        self.ensure_partition_leader(stream.stream_id, topic.topic_id, partition_id)
+            .await?;
+
+        // The transactional messages are appended to the partitions only when the transaction is committed.
+        if let Some(transaction_id) = transaction_id {
+            let batch = TransactionBatch {
+                stream_id: stream.stream_id,
+                topic_id: topic.topic_id,
+                partition_id,
+                producer: producer.copied(),
+                messages: received_messages,
+            };
+            self.transactions
+                .add_messages(session.user_id, transaction_id, batch, batch_size_bytes)
+                .await?;
+            return Ok(None);
+        }
+
+        self.ensure_cache_capacity(batch_size_bytes).await;
+        if received_messages.is_empty() {
+            return Ok(None);
+        }
+
+        let appended_messages = topic
+            .append_messages_to_partition(partition_id, producer, received_messages)
+            .await?;
+        self.metrics.increment_appended_messages(
+            stream.stream_id,
+            topic.topic_id,
+            partition_id,
+            appended_messages.count as u64,
+            appended_messages.size_bytes,
+        );
+        self.metrics
+            .observe_append_latency(append_timestamp.elapsed());
+        // The offset might already include the messages appended in the meantime, which only makes the replication wait longer.
+        let offset = topic
+            .get_partition(partition_id)?
+            .read()
+            .await
+            .current_offset;
+        Ok(Some((
+            (stream.stream_id, topic.topic_id, partition_id),
+            offset,
+        )))
+    }
+
+    /// Marks the message as poisoned for the consumer and, if the consumer group has the dead letter topic configured,
+    /// copies the message (still encrypted, if the encryption is enabled) to that topic with the `Poisoned` state.
+    pub async fn poison_message(
+        &self,
+        session: &Session,
+        consumer: PollingConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        offset: u64,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .poll_messages(session.user_id, stream.stream_id, topic.topic_id)?;
+
+        let dead_letter_topic = match consumer {
+            PollingConsumer::Consumer(_, _) => None,
+            PollingConsumer::ConsumerGroup(consumer_group_id, _) => {
+                let consumer_group = topic.get_consumer_group_by_id(consumer_group_id)?;
+                let consumer_group = consumer_group.read().await;
+                match consumer_group.dead_letter_topic {
+                    Some(dead_letter_topic) => {
+                        let dead_letter_stream =
+                            self.get_stream(&Identifier::numeric(dead_letter_topic.stream_id)?)?;
+                        let dead_letter_topic = dead_letter_stream
+                            .get_topic(&Identifier::numeric(dead_letter_topic.topic_id)?)?;
+                        self.permissioner.append_messages(
+                            session.user_id,
+                            dead_letter_stream.stream_id,
+                            dead_letter_topic.topic_id,
+                        )?;
+                        Some(dead_letter_topic)
+                    }
+                    None => None,
+                }
+            }
+        };
+
+        // The partition lock is released before appending to the dead letter topic.
+        let poisoned_message;
+        {
+            let partition = topic.resolve_partition(consumer).await?;
+            let partition = partition.read().await;
+            if !partition.poison_message(consumer, offset).await? || dead_letter_topic.is_none() {
+                return Ok(());
+            }
+
+            poisoned_message = partition
+                .get_messages_by_offset(offset, 1)
+                .await?
+                .into_iter()
+                .find(|message| message.offset == offset);
+        }
+
+        // The message might have been already removed, e.g. by the compaction.
+        if poisoned_message.is_none() {
+            return Ok(());
+        }
+
+        let poisoned_message = poisoned_message.unwrap();
+        let message = Message::empty(
+            TimeStamp::now().to_micros(),
+            MessageState::Poisoned,
+            poisoned_message.id,
+            poisoned_message.payload.clone(),
+            poisoned_message.checksum,
+            poisoned_message.headers.clone(),
+        );
+        self.ensure_cache_capacity(message.get_size_bytes() as u64)
+            .await;
+        let dead_letter_topic = dead_letter_topic.unwrap();
+        let appended_messages = dead_letter_topic
+            .append_messages(&Partitioning::balanced(), None, vec![message])
+            .await?;
+        self.metrics.increment_appended_messages(
+            dead_letter_topic.stream_id,
+            dead_letter_topic.topic_id,
+            appended_messages.partition_id,
+            appended_messages.count as u64,
+            appended_messages.size_bytes,
+        );
+        trace!(
+            "Copied poisoned message at offset: {} for {}, stream: {}, topic: {} to the dead letter topic.",
+            offset,
+            consumer,
+            stream_id,
+            topic_id
+        );
+        Ok(())
+    }
+
+    /// Exports the hits and misses counted by the partition caches, as they are not tracked by the metrics directly.
+    pub async fn update_cache_metrics(&self) {
+        self.metrics.clear_cache_usage();
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                for partition in topic.partitions.values() {
+                    let partition = partition.read().await;
+                    if let Some(cache) = partition.cache.as_ref() {
+                        self.metrics.set_cache_usage(
+                            stream.stream_id,
+                            topic.topic_id,
+                            partition.partition_id,
+                            cache.hits(),
+                            cache.misses(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) async fn ensure_cache_capacity(&self, batch_size_bytes: u64) {
         // If there's enough space in cache, do nothing.
         // Otherwise, clean the cache.
         if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
@@ -135,27 +488,32 @@ impl System {
                 self.clean_cache(batch_size_bytes).await;
             }
         }
-        topic
-            .append_messages(partitioning, received_messages)
-            .await?;
-        self.metrics.increment_messages(messages.len() as u64);
-        Ok(())
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
     pub auto_commit: bool,
+    pub max_wait: u32,
+    pub filter: Option<MessageFilter>,
 }
 
 impl PollingArgs {
-    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: bool) -> Self {
+    pub fn new(
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        max_wait: u32,
+        filter: Option<MessageFilter>,
+    ) -> Self {
         Self {
             strategy,
             count,
             auto_commit,
+            max_wait,
+            filter,
         }
     }
 }
@@ -1,13 +1,17 @@
+pub mod backups;
 pub mod clients;
+pub mod cluster;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod info;
 pub mod messages;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
@@ -2,60 +2,100 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use tokio::sync::RwLock;
 
 impl System {
     pub async fn create_partitions(
-        &mut self,
+        system: &RwLock<System>,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
     ) -> Result<(), Error> {
-        self.ensure_authenticated(session)?;
-        {
-            let stream = self.get_stream(stream_id)?;
+        let _metadata_guard = System::lock_metadata(system).await;
+        let partitions = {
+            let system = system.read().await;
+            system.ensure_authenticated(session)?;
+            let stream = system.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
-            self.permissioner.create_partitons(
+            system.permissioner.create_partitons(
                 session.user_id,
                 stream.stream_id,
                 topic.topic_id,
             )?;
+            topic.create_partitions(partitions_count)?
+        };
+
+        // The new partitions are persisted before being added, so that no messages can be appended to them in the meantime.
+        for partition in &partitions {
+            partition.persist().await?;
         }
 
-        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
-        topic.add_persisted_partitions(partitions_count).await?;
+        let mut system = system.write().await;
+        let topic = system.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic.insert_partitions(partitions);
         topic.reassign_consumer_groups().await;
-        self.metrics.increment_partitions(partitions_count);
-        self.metrics.increment_segments(partitions_count);
+        system.metrics.increment_partitions(partitions_count);
+        system.metrics.increment_segments(partitions_count);
         Ok(())
     }
 
     pub async fn delete_partitions(
-        &mut self,
+        system: &RwLock<System>,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitions_count: u32,
     ) -> Result<(), Error> {
-        self.ensure_authenticated(session)?;
-        {
-            let stream = self.get_stream(stream_id)?;
-            let topic = stream.get_topic(topic_id)?;
-            self.permissioner.delete_partitions(
-                session.user_id,
-                stream.stream_id,
-                topic.topic_id,
-            )?;
+        let _metadata_guard = System::lock_metadata(system).await;
+        let partitions = {
+            let mut system = system.write().await;
+            system.ensure_authenticated(session)?;
+            {
+                let stream = system.get_stream(stream_id)?;
+                let topic = stream.get_topic(topic_id)?;
+                system.permissioner.delete_partitions(
+                    session.user_id,
+                    stream.stream_id,
+                    topic.topic_id,
+                )?;
+            }
+
+            let topic = system.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+            let partitions = topic.remove_partitions(partitions_count);
+            topic.reassign_consumer_groups().await;
+            partitions
+        };
+
+        if partitions.is_empty() {
+            return Ok(());
         }
 
-        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
-        let partitions = topic.delete_persisted_partitions(partitions_count).await?;
-        topic.reassign_consumer_groups().await;
-        if let Some(partitions) = partitions {
-            self.metrics.decrement_partitions(partitions_count);
-            self.metrics.decrement_segments(partitions.segments_count);
-            self.metrics.decrement_messages(partitions.messages_count);
+        // The partitions are no longer available, so their files can be deleted without holding the system lock.
+        let mut segments_count = 0;
+        let mut messages_count = 0;
+        let mut partitions_ids = Vec::with_capacity(partitions.len());
+        for partition in &partitions {
+            let partition = partition.read().await;
+            partition.delete().await?;
+            segments_count += partition.get_segments_count();
+            messages_count += partition.get_messages_count();
+            partitions_ids.push((
+                partition.stream_id,
+                partition.topic_id,
+                partition.partition_id,
+            ));
+        }
+
+        let system = system.read().await;
+        for (stream_id, topic_id, partition_id) in partitions_ids {
+            system
+                .metrics
+                .remove_partition(stream_id, topic_id, partition_id);
         }
+        system.metrics.decrement_partitions(partitions.len() as u32);
+        system.metrics.decrement_segments(segments_count);
+        system.metrics.decrement_messages(messages_count);
         Ok(())
     }
 }
@@ -0,0 +1,105 @@
+use crate::streaming::schemas::schema::{SchemaDefinition, TopicSchema};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+
+impl System {
+    pub fn get_schema(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_id: u32,
+    ) -> Result<&TopicSchema, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .get_schema(session.user_id, stream.stream_id, topic.topic_id)?;
+
+        topic.get_schema(schema_id)
+    }
+
+    /// Returns the topic, as the schemas are returned along with the settings of the registry.
+    pub fn get_schemas(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<&Topic, Error> {
+        self.ensure_authenticated(session)?;
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        self.permissioner
+            .get_schemas(session.user_id, stream.stream_id, topic.topic_id)?;
+
+        Ok(topic)
+    }
+
+    pub async fn register_schema(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        definition: SchemaDefinition,
+    ) -> Result<&TopicSchema, Error> {
+        self.ensure_authenticated(session)?;
+        {
+            let stream = self.get_stream(stream_id)?;
+            let topic = stream.get_topic(topic_id)?;
+            self.permissioner
+                .register_schema(session.user_id, stream.stream_id, topic.topic_id)?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic.register_schema(definition).await
+    }
+
+    pub async fn delete_schema(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_id: u32,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        {
+            let stream = self.get_stream(stream_id)?;
+            let topic = stream.get_topic(topic_id)?;
+            self.permissioner
+                .delete_schema(session.user_id, stream.stream_id, topic.topic_id)?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic.delete_schema(schema_id).await?;
+        Ok(())
+    }
+
+    pub async fn update_schema_settings(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        {
+            let stream = self.get_stream(stream_id)?;
+            let topic = stream.get_topic(topic_id)?;
+            self.permissioner.update_schema_settings(
+                session.user_id,
+                stream.stream_id,
+                topic.topic_id,
+            )?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic
+            .update_schema_settings(compatibility, validation_enabled)
+            .await
+    }
+}
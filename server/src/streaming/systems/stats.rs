@@ -51,6 +51,7 @@ impl System {
                         .sum::<u32>()
                 })
                 .sum::<u32>(),
+            throttled_requests_count: self.quotas.get_throttled_requests_count(),
             read_bytes: 0,
             written_bytes: 0,
             messages_size_bytes: 0,
@@ -7,7 +7,7 @@ use iggy::identifier::{IdKind, Identifier};
 use iggy::utils::text;
 use std::sync::Arc;
 use tokio::fs::read_dir;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 use tracing::{error, info};
 
 impl System {
@@ -156,50 +156,69 @@ impl System {
     }
 
     pub async fn create_stream(
-        &mut self,
+        system: &RwLock<System>,
         session: &Session,
         stream_id: u32,
         name: &str,
     ) -> Result<(), Error> {
-        self.ensure_authenticated(session)?;
-        self.permissioner.create_stream(session.user_id)?;
-        if self.streams.contains_key(&stream_id) {
-            return Err(Error::StreamIdAlreadyExists(stream_id));
-        }
+        let _metadata_guard = System::lock_metadata(system).await;
+        let stream = {
+            let system = system.read().await;
+            system.ensure_authenticated(session)?;
+            system.permissioner.create_stream(session.user_id)?;
+            if system.streams.contains_key(&stream_id) {
+                return Err(Error::StreamIdAlreadyExists(stream_id));
+            }
 
-        let name = text::to_lowercase_non_whitespace(name);
-        if self.streams_ids.contains_key(&name) {
-            return Err(Error::StreamNameAlreadyExists(name.to_string()));
-        }
+            let name = text::to_lowercase_non_whitespace(name);
+            if system.streams_ids.contains_key(&name) {
+                return Err(Error::StreamNameAlreadyExists(name.to_string()));
+            }
+
+            Stream::create(
+                stream_id,
+                &name,
+                system.config.clone(),
+                system.storage.clone(),
+            )
+        };
 
-        let stream = Stream::create(stream_id, &name, self.config.clone(), self.storage.clone());
         stream.persist().await?;
-        info!("Created stream with ID: {}, name: '{}'.", stream_id, name);
-        self.streams_ids.insert(name, stream.stream_id);
-        self.streams.insert(stream.stream_id, stream);
-        self.metrics.increment_streams(1);
+        info!(
+            "Created stream with ID: {}, name: '{}'.",
+            stream_id, stream.name
+        );
+        let mut system = system.write().await;
+        system
+            .streams_ids
+            .insert(stream.name.clone(), stream.stream_id);
+        system.streams.insert(stream.stream_id, stream);
+        system.metrics.increment_streams(1);
         Ok(())
     }
 
     pub async fn update_stream(
-        &mut self,
+        system: &RwLock<System>,
         session: &Session,
         id: &Identifier,
         name: &str,
     ) -> Result<(), Error> {
-        self.ensure_authenticated(session)?;
+        let _metadata_guard = System::lock_metadata(system).await;
+        let mut system = system.write().await;
+        system.ensure_authenticated(session)?;
         let stream_id;
         {
-            let stream = self.get_stream(id)?;
+            let stream = system.get_stream(id)?;
             stream_id = stream.stream_id;
         }
 
-        self.permissioner
+        system
+            .permissioner
             .update_stream(session.user_id, stream_id)?;
         let updated_name = text::to_lowercase_non_whitespace(name);
 
         {
-            if let Some(stream_id_by_name) = self.streams_ids.get(&updated_name) {
+            if let Some(stream_id_by_name) = system.streams_ids.get(&updated_name) {
                 if *stream_id_by_name != stream_id {
                     return Err(Error::StreamNameAlreadyExists(updated_name.clone()));
                 }
@@ -208,17 +227,19 @@ impl System {
 
         let old_name;
         {
-            let stream = self.get_stream_mut(id)?;
+            let stream = system.get_stream_mut(id)?;
             old_name = stream.name.clone();
             stream.name = updated_name.clone();
-            stream.persist().await?;
         }
 
         {
-            self.streams_ids.remove(&old_name);
-            self.streams_ids.insert(updated_name.clone(), stream_id);
+            system.streams_ids.remove(&old_name);
+            system.streams_ids.insert(updated_name.clone(), stream_id);
         }
 
+        // Only the in-memory state requires the write lock, the stream is persisted under the read lock.
+        let system = system.downgrade();
+        system.get_stream_by_id(stream_id)?.persist().await?;
         info!(
             "Stream with ID '{}' updated. Old name: '{}' changed to: '{}'.",
             id, old_name, updated_name
@@ -227,32 +248,55 @@ impl System {
     }
 
     pub async fn delete_stream(
-        &mut self,
+        system: &RwLock<System>,
         session: &Session,
         id: &Identifier,
     ) -> Result<u32, Error> {
-        self.ensure_authenticated(session)?;
-        let stream = self.get_stream(id)?;
+        let _metadata_guard = System::lock_metadata(system).await;
+        let stream = {
+            let mut system = system.write().await;
+            system.ensure_authenticated(session)?;
+            let stream = system.get_stream(id)?;
+            let stream_id = stream.stream_id;
+            system
+                .permissioner
+                .delete_stream(session.user_id, stream_id)?;
+            let stream = system.streams.remove(&stream_id).unwrap();
+            system.streams_ids.remove(&stream.name);
+            stream
+        };
+
+        // The stream is no longer available, so its files can be deleted without holding the system lock.
         let stream_id = stream.stream_id;
-        self.permissioner
-            .delete_stream(session.user_id, stream_id)?;
-        let stream_name = stream.name.clone();
         if stream.delete().await.is_err() {
+            let mut system = system.write().await;
+            system
+                .streams_ids
+                .insert(stream.name.clone(), stream.stream_id);
+            system.streams.insert(stream.stream_id, stream);
             return Err(Error::CannotDeleteStream(stream_id));
         }
 
-        self.metrics.decrement_streams(1);
-        self.metrics.decrement_topics(stream.get_topics_count());
-        self.metrics
+        let system = system.read().await;
+        for topic in stream.get_topics() {
+            for partition_id in topic.partitions.keys() {
+                system
+                    .metrics
+                    .remove_partition(stream_id, topic.topic_id, *partition_id);
+            }
+        }
+        system.metrics.decrement_streams(1);
+        system.metrics.decrement_topics(stream.get_topics_count());
+        system
+            .metrics
             .decrement_partitions(stream.get_partitions_count());
-        self.metrics
+        system
+            .metrics
             .decrement_messages(stream.get_messages_count().await);
-        self.metrics
+        system
+            .metrics
             .decrement_segments(stream.get_segments_count().await);
-
-        self.streams.remove(&stream_id);
-        self.streams_ids.remove(&stream_name);
-        let client_manager = self.client_manager.read().await;
+        let client_manager = system.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_stream(stream_id)
             .await;
@@ -279,11 +323,12 @@ mod tests {
         let root = User::root();
         let session = Session::new(1, root.id);
         system.permissioner.init_permissions_for_user(root);
-        system
-            .create_stream(&session, stream_id, stream_name)
+        let system = RwLock::new(system);
+        System::create_stream(&system, &session, stream_id, stream_name)
             .await
             .unwrap();
 
+        let system = system.read().await;
         let stream = system.get_stream(&Identifier::numeric(stream_id).unwrap());
         assert!(stream.is_ok());
         let stream = stream.unwrap();
@@ -296,4 +341,29 @@ mod tests {
         assert_eq!(stream.stream_id, stream_id);
         assert_eq!(stream.name, stream_name);
     }
+
+    #[tokio::test]
+    async fn concurrently_created_streams_with_the_same_id_should_be_created_once() {
+        let stream_id = 1;
+        let config = Arc::new(SystemConfig::default());
+        let storage = get_test_system_storage();
+        let mut system =
+            System::create(config, storage, None, PersonalAccessTokenConfig::default());
+        let root = User::root();
+        let session = Session::new(1, root.id);
+        system.permissioner.init_permissions_for_user(root);
+        let system = RwLock::new(system);
+
+        let results = join_all(
+            ["test-1", "test-2", "test-3"]
+                .iter()
+                .map(|name| System::create_stream(&system, &session, stream_id, name)),
+        )
+        .await;
+
+        assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+        let system = system.read().await;
+        assert_eq!(system.streams.len(), 1);
+        assert_eq!(system.streams_ids.len(), 1);
+    }
 }
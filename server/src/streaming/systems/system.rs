@@ -2,11 +2,15 @@ use crate::configs::server::PersonalAccessTokenConfig;
 use crate::configs::system::SystemConfig;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
+use crate::streaming::cluster::cluster_manager::ClusterManager;
 use crate::streaming::diagnostics::metrics::Metrics;
 use crate::streaming::persistence::persister::*;
+use crate::streaming::quotas::quota_manager::QuotaManager;
 use crate::streaming::session::Session;
 use crate::streaming::storage::{SegmentStorage, SystemStorage};
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::tiered_storage::remote_storage;
+use crate::streaming::transactions::coordinator::TransactionCoordinator;
 use crate::streaming::users::permissioner::Permissioner;
 use iggy::error::Error;
 use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
@@ -15,7 +19,7 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs::create_dir;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, OwnedMutexGuard, RwLock};
 use tokio::time::Instant;
 use tracing::{info, trace};
 
@@ -31,6 +35,10 @@ pub struct System {
     pub(crate) metrics: Metrics,
     pub(crate) db: Option<Arc<Db>>,
     pub personal_access_token: PersonalAccessTokenConfig,
+    pub(crate) transactions: TransactionCoordinator,
+    pub(crate) quotas: QuotaManager,
+    pub(crate) cluster: Option<Arc<ClusterManager>>,
+    metadata_lock: Arc<Mutex<()>>,
 }
 
 /// For each cache eviction, we want to remove more than the size we need.
@@ -53,16 +61,20 @@ impl System {
                 Arc::new(db.unwrap())
             }
         };
+        let metrics = Metrics::init();
         let persister: Arc<dyn Persister> = match config.partition.enforce_fsync {
-            true => Arc::new(FileWithSyncPersister {}),
-            false => Arc::new(FilePersister {}),
+            true => Arc::new(FileWithSyncPersister::new(metrics.segment_flush_duration())),
+            false => Arc::new(FilePersister::new(metrics.segment_flush_duration())),
         };
-        Self::create(
+        let remote_storage = remote_storage::create(&config);
+        let mut system = Self::create(
             config,
-            SystemStorage::new(db.clone(), persister),
+            SystemStorage::new(db.clone(), persister, remote_storage),
             Some(db),
             pat_config,
-        )
+        );
+        system.metrics = metrics;
+        system
     }
 
     pub fn create(
@@ -76,6 +88,8 @@ impl System {
             Self::map_toggle_str(config.encryption.enabled)
         );
         System {
+            transactions: TransactionCoordinator::new(&config.transaction),
+            quotas: QuotaManager::new(&config.quota),
             encryptor: match config.encryption.enabled {
                 true => Some(Box::new(
                     Aes256GcmEncryptor::from_base64_key(&config.encryption.key).unwrap(),
@@ -91,9 +105,17 @@ impl System {
             metrics: Metrics::init(),
             db,
             personal_access_token: pat_config,
+            cluster: None,
+            metadata_lock: Arc::new(Mutex::new(())),
         }
     }
 
+    /// Enables the cluster mode, in which the partitions are replicated to the other nodes.
+    pub fn enable_cluster(&mut self, cluster: ClusterManager) {
+        info!("Cluster mode is enabled, node ID: {}.", cluster.node_id);
+        self.cluster = Some(Arc::new(cluster));
+    }
+
     pub async fn init(&mut self) -> Result<(), Error> {
         if !Path::new(&self.config.get_system_path()).exists()
             && create_dir(&self.config.get_system_path()).await.is_err()
@@ -132,6 +154,15 @@ impl System {
         Ok(())
     }
 
+    /// Serializes the changes of the streams, topics and partitions, so that they can be validated under the read lock
+    /// and persisted or deleted on disk without holding the system write lock, which would block the appends and polls.
+    /// The system write lock is then acquired only to update the in-memory state.
+    pub(crate) async fn lock_metadata(system: &RwLock<System>) -> OwnedMutexGuard<()> {
+        // The read lock must be released before waiting for the metadata lock, as its holder may be waiting for the write lock.
+        let metadata_lock = system.read().await.metadata_lock.clone();
+        metadata_lock.lock_owned().await
+    }
+
     pub fn ensure_authenticated(&self, session: &Session) -> Result<(), Error> {
         match session.is_authenticated() {
             true => Ok(()),
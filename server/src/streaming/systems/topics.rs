@@ -1,8 +1,11 @@
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use crate::streaming::topics::topic::Topic;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use tokio::sync::RwLock;
+use tracing::info;
 
 impl System {
     pub fn find_topic(
@@ -31,83 +34,144 @@ impl System {
         Ok(stream.get_topics())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_topic(
-        &mut self,
+        system: &RwLock<System>,
         session: &Session,
         stream_id: &Identifier,
         topic_id: u32,
         name: &str,
         partitions_count: u32,
         message_expiry: Option<u32>,
+        compression_algorithm: CompressionAlgorithm,
+        compaction_enabled: bool,
+        max_topic_size: Option<u64>,
+        max_partition_size: Option<u64>,
     ) -> Result<(), Error> {
-        self.ensure_authenticated(session)?;
-        {
-            let stream = self.get_stream(stream_id)?;
-            self.permissioner
+        let _metadata_guard = System::lock_metadata(system).await;
+        let topic = {
+            let system = system.read().await;
+            system.ensure_authenticated(session)?;
+            let stream = system.get_stream(stream_id)?;
+            system
+                .permissioner
                 .create_topic(session.user_id, stream.stream_id)?;
-        }
+            stream.create_topic(
+                topic_id,
+                name,
+                partitions_count,
+                message_expiry,
+                compression_algorithm,
+                compaction_enabled,
+                max_topic_size,
+                max_partition_size,
+            )?
+        };
 
-        self.get_stream_mut(stream_id)?
-            .create_topic(topic_id, name, partitions_count, message_expiry)
-            .await?;
-        self.metrics.increment_topics(1);
-        self.metrics.increment_partitions(partitions_count);
-        self.metrics.increment_segments(partitions_count);
+        topic.persist().await?;
+        let mut system = system.write().await;
+        system.get_stream_mut(stream_id)?.add_topic(topic);
+        system.metrics.increment_topics(1);
+        system.metrics.increment_partitions(partitions_count);
+        system.metrics.increment_segments(partitions_count);
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_topic(
-        &mut self,
+        system: &RwLock<System>,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
         name: &str,
         message_expiry: Option<u32>,
+        max_topic_size: Option<u64>,
+        max_partition_size: Option<u64>,
     ) -> Result<(), Error> {
-        self.ensure_authenticated(session)?;
+        let _metadata_guard = System::lock_metadata(system).await;
+        let mut system = system.write().await;
+        system.ensure_authenticated(session)?;
         {
-            let stream = self.get_stream(stream_id)?;
+            let stream = system.get_stream(stream_id)?;
             let topic = stream.get_topic(topic_id)?;
-            self.permissioner
+            system
+                .permissioner
                 .update_topic(session.user_id, stream.stream_id, topic.topic_id)?;
         }
 
-        self.get_stream_mut(stream_id)?
-            .update_topic(topic_id, name, message_expiry)
+        system
+            .get_stream_mut(stream_id)?
+            .update_topic(
+                topic_id,
+                name,
+                message_expiry,
+                max_topic_size,
+                max_partition_size,
+            )
             .await?;
+
+        // Only the in-memory state requires the write lock, the topic is persisted under the read lock.
+        let system = system.downgrade();
+        let topic = system.get_stream(stream_id)?.get_topic(topic_id)?;
+        topic.persist().await?;
+        info!("Updated topic: {} with ID: {}", topic.name, topic.topic_id);
         Ok(())
     }
 
     pub async fn delete_topic(
-        &mut self,
+        system: &RwLock<System>,
         session: &Session,
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<(), Error> {
-        self.ensure_authenticated(session)?;
-        let stream_id_value;
-        {
-            let stream = self.get_stream(stream_id)?;
-            let topic = stream.get_topic(topic_id)?;
-            self.permissioner
-                .delete_topic(session.user_id, stream.stream_id, topic.topic_id)?;
-            stream_id_value = stream.stream_id;
+        let _metadata_guard = System::lock_metadata(system).await;
+        let topic = {
+            let mut system = system.write().await;
+            system.ensure_authenticated(session)?;
+            {
+                let stream = system.get_stream(stream_id)?;
+                let topic = stream.get_topic(topic_id)?;
+                system.permissioner.delete_topic(
+                    session.user_id,
+                    stream.stream_id,
+                    topic.topic_id,
+                )?;
+            }
+
+            system.get_stream_mut(stream_id)?.remove_topic(topic_id)?
+        };
+
+        // The topic is no longer available, so its files can be deleted without holding the system lock.
+        if topic.delete().await.is_err() {
+            let topic_id = topic.topic_id;
+            let stream_id_value = topic.stream_id;
+            system
+                .write()
+                .await
+                .get_stream_mut(stream_id)?
+                .add_topic(topic);
+            return Err(Error::CannotDeleteTopic(topic_id, stream_id_value));
         }
 
-        let topic = self
-            .get_stream_mut(stream_id)?
-            .delete_topic(topic_id)
-            .await?;
-        self.metrics.decrement_topics(1);
-        self.metrics
+        let system = system.read().await;
+        for partition_id in topic.partitions.keys() {
+            system
+                .metrics
+                .remove_partition(topic.stream_id, topic.topic_id, *partition_id);
+        }
+        system.metrics.decrement_topics(1);
+        system
+            .metrics
             .decrement_partitions(topic.get_partitions_count());
-        self.metrics
+        system
+            .metrics
             .decrement_messages(topic.get_messages_count().await);
-        self.metrics
+        system
+            .metrics
             .decrement_segments(topic.get_segments_count().await);
-        let client_manager = self.client_manager.read().await;
+        let client_manager = system.client_manager.read().await;
         client_manager
-            .delete_consumer_groups_for_topic(stream_id_value, topic.topic_id)
+            .delete_consumer_groups_for_topic(topic.stream_id, topic.topic_id)
             .await;
         Ok(())
     }
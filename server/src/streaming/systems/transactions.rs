@@ -0,0 +1,195 @@
+use crate::streaming::partitions::messages::AppendedMessages;
+use crate::streaming::partitions::partition::{Partition, ProducerState};
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::transactions::transaction::{Transaction, TransactionBatch};
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{error, info};
+
+impl System {
+    pub async fn begin_transaction(&self, session: &Session) -> Result<u64, Error> {
+        self.ensure_authenticated(session)?;
+        Ok(self.transactions.begin(session.user_id).await)
+    }
+
+    pub async fn commit_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let transaction = self
+            .transactions
+            .take(session.user_id, transaction_id)
+            .await?;
+        if transaction.batches.is_empty() {
+            return Ok(());
+        }
+
+        // Nothing is left appended if the commit fails, so the transaction is kept to be committed again or aborted.
+        if let Err(err) = self.append_transaction(session, &transaction).await {
+            error!("Cannot commit transaction with ID: {transaction_id}. Error: {err}");
+            self.transactions.restore(transaction).await;
+            return Err(err);
+        }
+
+        info!(
+            "Committed transaction with ID: {}, messages: {}.",
+            transaction.id, transaction.messages_count
+        );
+        Ok(())
+    }
+
+    async fn append_transaction(
+        &self,
+        session: &Session,
+        transaction: &Transaction,
+    ) -> Result<(), Error> {
+        // All the partitions are resolved upfront, so nothing is appended if any of them is gone.
+        // They are sorted by the IDs, so the concurrent commits always lock them in the same order.
+        let mut partitions: BTreeMap<(u32, u32, u32), PartitionBatches> = BTreeMap::new();
+        for batch in &transaction.batches {
+            let key = (batch.stream_id, batch.topic_id, batch.partition_id);
+            if let Some(partition_batches) = partitions.get_mut(&key) {
+                partition_batches.batches.push(batch);
+                continue;
+            }
+
+            let stream = self.get_stream(&Identifier::numeric(batch.stream_id)?)?;
+            let topic = stream.get_topic(&Identifier::numeric(batch.topic_id)?)?;
+            self.permissioner
+                .append_messages(session.user_id, stream.stream_id, topic.topic_id)?;
+            let partition = topic.get_partition(batch.partition_id)?;
+            // The leadership might have changed since the messages were sent within the transaction.
+            self.ensure_partition_leader(stream.stream_id, topic.topic_id, batch.partition_id)
+                .await?;
+            partitions.insert(
+                key,
+                PartitionBatches {
+                    partition,
+                    batches: vec![batch],
+                },
+            );
+        }
+
+        self.ensure_cache_capacity(transaction.size_bytes).await;
+
+        // Holding the locks of all the partitions until everything is appended,
+        // so the consumers can't observe the partially committed transaction.
+        let mut locked_partitions = Vec::with_capacity(partitions.len());
+        for partition_batches in partitions.values() {
+            locked_partitions.push(partition_batches.partition.clone().write_owned().await);
+        }
+
+        for (partition, partition_batches) in locked_partitions.iter().zip(partitions.values()) {
+            partition.validate_producer_sequences(partition_batches.batches.iter().filter_map(
+                |batch| {
+                    batch
+                        .producer
+                        .as_ref()
+                        .map(|producer| (producer, batch.messages.len()))
+                },
+            ))?;
+        }
+
+        // The state of each partition before the commit, to which it's rolled back if any of the appends fails.
+        let snapshots = locked_partitions
+            .iter()
+            .map(|partition| (partition.get_next_offset(), partition.producers.clone()))
+            .collect::<Vec<_>>();
+        let mut appended_messages = vec![AppendedMessages::default(); locked_partitions.len()];
+        for (index, (partition, ((stream_id, topic_id, _), partition_batches))) in locked_partitions
+            .iter_mut()
+            .zip(partitions.iter())
+            .enumerate()
+        {
+            for batch in &partition_batches.batches {
+                match partition
+                    .append_messages(batch.messages.clone(), batch.producer.as_ref())
+                    .await
+                {
+                    Ok(batch_appended_messages) => {
+                        appended_messages[index].count += batch_appended_messages.count;
+                        appended_messages[index].size_bytes += batch_appended_messages.size_bytes;
+                    }
+                    Err(err) => {
+                        error!("Cannot append messages of transaction with ID: {} to partition with ID: {} for topic with ID: {topic_id} and stream with ID: {stream_id}. Error: {err}", transaction.id, partition.partition_id);
+                        for (partition, (offset, producers)) in
+                            locked_partitions.iter_mut().zip(snapshots).take(index + 1)
+                        {
+                            Self::roll_back_partition(partition, offset, producers).await;
+                        }
+                        return Err(err);
+                    }
+                }
+            }
+        }
+
+        // The woken consumers will see the messages only once all the locks are released.
+        for partition in &locked_partitions {
+            partition.messages_notifier.notify_waiters();
+        }
+        drop(locked_partitions);
+
+        for ((stream_id, topic_id, partition_id), appended_messages) in
+            partitions.keys().zip(appended_messages)
+        {
+            self.metrics.increment_appended_messages(
+                *stream_id,
+                *topic_id,
+                *partition_id,
+                appended_messages.count as u64,
+                appended_messages.size_bytes,
+            );
+        }
+        Ok(())
+    }
+
+    async fn roll_back_partition(
+        partition: &mut Partition,
+        offset: u64,
+        producers: HashMap<u64, ProducerState>,
+    ) {
+        if let Err(err) = partition.truncate_messages(offset).await {
+            error!(
+                "Cannot roll back partition with ID: {} for topic with ID: {} and stream with ID: {} to offset: {offset}. Error: {err}",
+                partition.partition_id, partition.topic_id, partition.stream_id
+            );
+        }
+        // The producers evicted in the meantime are saved again together with the next messages.
+        partition.unsaved_producer_ids.extend(producers.keys());
+        partition.producers = producers;
+    }
+
+    pub async fn abort_transaction(
+        &self,
+        session: &Session,
+        transaction_id: u64,
+    ) -> Result<(), Error> {
+        self.ensure_authenticated(session)?;
+        let transaction = self
+            .transactions
+            .take(session.user_id, transaction_id)
+            .await;
+        // The expired transaction has been already discarded, so there's nothing left to abort.
+        if let Err(Error::TransactionExpired(_)) = transaction {
+            return Ok(());
+        }
+
+        let transaction = transaction?;
+        info!(
+            "Aborted transaction with ID: {}, discarded messages: {}.",
+            transaction.id, transaction.messages_count
+        );
+        Ok(())
+    }
+}
+
+struct PartitionBatches<'a> {
+    partition: Arc<RwLock<Partition>>,
+    batches: Vec<&'a TransactionBatch>,
+}
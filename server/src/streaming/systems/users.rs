@@ -5,6 +5,7 @@ use crate::streaming::utils::crypto;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::models::permissions::Permissions;
+use iggy::models::user_quota::UserQuota;
 use iggy::models::user_status::UserStatus;
 use iggy::utils::text;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -28,6 +29,9 @@ impl System {
         let users_count = users.len();
         let current_user_id = users.iter().map(|user| user.id).max().unwrap_or(1);
         USER_ID.store(current_user_id + 1, Ordering::SeqCst);
+        for user in &users {
+            self.quotas.set_user_quota(user.id, user.quota);
+        }
         self.permissioner.init(users);
         info!("Initialized {} user(s).", users_count);
         Ok(())
@@ -71,6 +75,7 @@ impl System {
         session: &Session,
         username: &str,
         password: &str,
+        status: UserStatus,
         permissions: Option<Permissions>,
     ) -> Result<(), Error> {
         self.ensure_authenticated(session)?;
@@ -82,7 +87,8 @@ impl System {
         }
         let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
         info!("Creating user: {username} with ID: {user_id}...");
-        let user = User::new(user_id, &username, password, permissions);
+        let mut user = User::new(user_id, &username, password, permissions);
+        user.status = status;
         self.storage.user.save(&user).await?;
         self.permissioner.init_permissions_for_user(user);
         info!("Created user: {username} with ID: {user_id}.");
@@ -107,7 +113,10 @@ impl System {
         self.storage.user.delete(&user).await?;
         self.permissioner.delete_permissions_for_user(user.id);
         let mut client_manager = self.client_manager.write().await;
-        client_manager.delete_clients_for_user(user.id).await?;
+        for client_id in client_manager.delete_clients_for_user(user.id).await? {
+            self.quotas.delete_client_usage(client_id);
+        }
+        self.quotas.delete_user_usage(user.id);
         info!("Deleted user: {} with ID: {user_id}.", user.username);
         self.metrics.decrement_users(1);
         Ok(user)
@@ -119,6 +128,7 @@ impl System {
         user_id: &Identifier,
         username: Option<String>,
         status: Option<UserStatus>,
+        quota: Option<UserQuota>,
     ) -> Result<User, Error> {
         self.ensure_authenticated(session)?;
         self.permissioner.update_user(session.user_id)?;
@@ -138,8 +148,13 @@ impl System {
             user.status = status;
         }
 
+        if quota.is_some() {
+            user.quota = quota;
+        }
+
         info!("Updating user: {} with ID: {}...", user.username, user.id);
         self.storage.user.save(&user).await?;
+        self.quotas.set_user_quota(user.id, user.quota);
         info!("Updated user: {} with ID: {}.", user.username, user.id);
         Ok(user)
     }
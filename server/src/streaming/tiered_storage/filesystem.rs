@@ -0,0 +1,77 @@
+use crate::streaming::tiered_storage::remote_storage::RemoteStorage;
+use async_trait::async_trait;
+use iggy::error::Error;
+use std::io::ErrorKind;
+use std::path::Path;
+use tokio::fs;
+use tracing::error;
+
+const TEMP_EXTENSION: &str = "tmp";
+
+/// Keeps the offloaded segments in the directory, e.g. a mounted network volume.
+#[derive(Debug)]
+pub struct FilesystemRemoteStorage {
+    path: String,
+}
+
+impl FilesystemRemoteStorage {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+        }
+    }
+
+    fn get_object_path(&self, key: &str) -> String {
+        format!("{}/{}", self.path, key)
+    }
+}
+
+#[async_trait]
+impl RemoteStorage for FilesystemRemoteStorage {
+    async fn upload(&self, key: &str, path: &str) -> Result<(), Error> {
+        let object_path = self.get_object_path(key);
+        if let Some(parent) = Path::new(&object_path).parent() {
+            if let Err(err) = fs::create_dir_all(parent).await {
+                error!("Cannot create remote storage directory: {}", err);
+                return Err(Error::CannotUploadSegment(path.to_string()));
+            }
+        }
+
+        // The object is copied under the temporary name first, so the partially uploaded files are never visible.
+        let temp_path = format!("{}.{}", object_path, TEMP_EXTENSION);
+        if let Err(err) = fs::copy(path, &temp_path).await {
+            error!(
+                "Cannot copy segment file: {} to: {}. {}",
+                path, temp_path, err
+            );
+            return Err(Error::CannotUploadSegment(path.to_string()));
+        }
+
+        if fs::rename(&temp_path, &object_path).await.is_err() {
+            return Err(Error::CannotUploadSegment(path.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn download(&self, key: &str, path: &str) -> Result<(), Error> {
+        let object_path = self.get_object_path(key);
+        if let Err(err) = fs::copy(&object_path, path).await {
+            error!(
+                "Cannot copy remote object: {} to: {}. {}",
+                object_path, path, err
+            );
+            return Err(Error::CannotDownloadSegment(path.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Error> {
+        match fs::remove_file(self.get_object_path(key)).await {
+            Ok(_) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(_) => Err(Error::CannotDeleteRemoteSegment(key.to_string())),
+        }
+    }
+}
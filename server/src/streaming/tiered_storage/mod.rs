@@ -0,0 +1,3 @@
+pub mod filesystem;
+pub mod remote_storage;
+pub mod s3;
@@ -0,0 +1,43 @@
+use crate::configs::system::{SystemConfig, TieredStorageBackend};
+use crate::streaming::tiered_storage::filesystem::FilesystemRemoteStorage;
+use crate::streaming::tiered_storage::s3::S3RemoteStorage;
+use async_trait::async_trait;
+use iggy::error::Error;
+use std::fmt::Debug;
+use std::sync::Arc;
+use tracing::info;
+
+/// The object store to which the closed segments are offloaded, the objects are addressed by the keys
+/// being the paths of the segment files relative to the system path.
+#[async_trait]
+pub trait RemoteStorage: Sync + Send {
+    async fn upload(&self, key: &str, path: &str) -> Result<(), Error>;
+    async fn download(&self, key: &str, path: &str) -> Result<(), Error>;
+    async fn delete(&self, key: &str) -> Result<(), Error>;
+}
+
+impl Debug for dyn RemoteStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteStorage")
+            .field("type", &"RemoteStorage")
+            .finish()
+    }
+}
+
+pub fn create(config: &SystemConfig) -> Option<Arc<dyn RemoteStorage>> {
+    if !config.tiered_storage.enabled {
+        return None;
+    }
+
+    info!(
+        "Tiered storage is enabled, closed segments will be offloaded to the {} backend.",
+        config.tiered_storage.backend
+    );
+    let remote_storage: Arc<dyn RemoteStorage> = match config.tiered_storage.backend {
+        TieredStorageBackend::Filesystem => Arc::new(FilesystemRemoteStorage::new(
+            &config.get_tiered_storage_path(),
+        )),
+        TieredStorageBackend::S3 => Arc::new(S3RemoteStorage::new(&config.tiered_storage.s3)),
+    };
+    Some(remote_storage)
+}
@@ -1,4 +1,7 @@
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::error::Error;
+use iggy::models::consumer_group::DeadLetterTopic;
+use iggy::utils::timestamp::TimeStamp;
 use std::collections::HashMap;
 use tokio::sync::RwLock;
 use tracing::trace;
@@ -9,6 +12,10 @@ pub struct ConsumerGroup {
     pub consumer_group_id: u32,
     pub name: String,
     pub partitions_count: u32,
+    pub partition_assignment_strategy: PartitionAssignmentStrategy,
+    pub session_timeout: Option<u32>,
+    pub dead_letter_topic: Option<DeadLetterTopic>,
+    generation: u32,
     members: HashMap<u32, RwLock<ConsumerGroupMember>>,
 }
 
@@ -18,6 +25,7 @@ pub struct ConsumerGroupMember {
     partitions: HashMap<u32, u32>,
     current_partition_index: u32,
     current_partition_id: u32,
+    last_heartbeat_at: u64,
 }
 
 impl ConsumerGroup {
@@ -26,16 +34,28 @@ impl ConsumerGroup {
         consumer_group_id: u32,
         name: &str,
         partitions_count: u32,
+        partition_assignment_strategy: PartitionAssignmentStrategy,
+        session_timeout: Option<u32>,
+        dead_letter_topic: Option<DeadLetterTopic>,
     ) -> ConsumerGroup {
         ConsumerGroup {
             topic_id,
             consumer_group_id,
             name: name.to_string(),
             partitions_count,
+            partition_assignment_strategy,
+            session_timeout,
+            dead_letter_topic,
+            generation: 0,
             members: HashMap::new(),
         }
     }
 
+    /// The generation is incremented on every rebalance, so the members can detect that their partitions have changed.
+    pub fn get_generation(&self) -> u32 {
+        self.generation
+    }
+
     pub fn get_members(&self) -> Vec<&RwLock<ConsumerGroupMember>> {
         self.members.values().collect()
     }
@@ -45,10 +65,26 @@ impl ConsumerGroup {
         self.assign_partitions().await;
     }
 
+    /// Returns the partitions assigned to the member, which are polled by it in turn.
+    pub async fn get_member_partitions(&self, member_id: u32) -> Result<Vec<u32>, Error> {
+        let member = self.members.get(&member_id);
+        if let Some(member) = member {
+            return Ok(member.read().await.get_partitions());
+        }
+        Err(Error::ConsumerGroupMemberNotFound(
+            member_id,
+            self.consumer_group_id,
+            self.topic_id,
+        ))
+    }
+
     pub async fn calculate_partition_id(&self, member_id: u32) -> Result<u32, Error> {
         let member = self.members.get(&member_id);
         if let Some(member) = member {
-            return Ok(member.write().await.calculate_partition_id());
+            let mut member = member.write().await;
+            // Polling the messages also proves that the member is still alive.
+            member.last_heartbeat_at = TimeStamp::now().to_micros();
+            return Ok(member.calculate_partition_id());
         }
         Err(Error::ConsumerGroupMemberNotFound(
             member_id,
@@ -69,6 +105,36 @@ impl ConsumerGroup {
         ))
     }
 
+    pub async fn heartbeat(&self, member_id: u32) -> Result<(), Error> {
+        let member = self.members.get(&member_id);
+        if let Some(member) = member {
+            member.write().await.last_heartbeat_at = TimeStamp::now().to_micros();
+            return Ok(());
+        }
+        Err(Error::ConsumerGroupMemberNotFound(
+            member_id,
+            self.consumer_group_id,
+            self.topic_id,
+        ))
+    }
+
+    /// Returns the IDs of the members, which haven't sent the heartbeat (or polled the messages) within the session timeout.
+    pub async fn get_stale_members(&self, now: u64) -> Vec<u32> {
+        let session_timeout = match self.session_timeout {
+            Some(session_timeout) => session_timeout as u64 * 1_000_000,
+            None => return Vec::new(),
+        };
+
+        let mut stale_members = Vec::new();
+        for member in self.members.values() {
+            let member = member.read().await;
+            if member.last_heartbeat_at + session_timeout < now {
+                stale_members.push(member.id);
+            }
+        }
+        stale_members
+    }
+
     pub async fn add_member(&mut self, member_id: u32) {
         self.members.insert(
             member_id,
@@ -77,6 +143,7 @@ impl ConsumerGroup {
                 partitions: HashMap::new(),
                 current_partition_index: 0,
                 current_partition_id: 0,
+                last_heartbeat_at: TimeStamp::now().to_micros(),
             }),
         );
         trace!(
@@ -101,32 +168,130 @@ impl ConsumerGroup {
     }
 
     async fn assign_partitions(&mut self) {
-        let mut members = self.members.values_mut().collect::<Vec<_>>();
+        self.generation = self.generation.wrapping_add(1);
+        let mut members = self.members.iter_mut().collect::<Vec<_>>();
         if members.is_empty() {
             return;
         }
 
-        let members_count = members.len() as u32;
-        for member in members.iter_mut() {
+        members.sort_by_key(|(member_id, _)| **member_id);
+        let mut owned_partitions = Vec::with_capacity(members.len());
+        for (_, member) in members.iter() {
+            owned_partitions.push(member.read().await.get_ordered_partitions());
+        }
+
+        let assignments = match self.partition_assignment_strategy {
+            PartitionAssignmentStrategy::RoundRobin => {
+                assign_round_robin(self.partitions_count, members.len())
+            }
+            PartitionAssignmentStrategy::Range => {
+                assign_range(self.partitions_count, members.len())
+            }
+            PartitionAssignmentStrategy::Sticky => {
+                assign_sticky(self.partitions_count, &owned_partitions)
+            }
+        };
+
+        let keep_current_partition =
+            self.partition_assignment_strategy == PartitionAssignmentStrategy::Sticky;
+        for ((_, member), partitions) in members.iter_mut().zip(assignments) {
             let mut member = member.write().await;
+            let current_partition_id = member.current_partition_id;
+            member.partitions.clear();
             member.current_partition_index = 0;
             member.current_partition_id = 0;
-            member.partitions.clear();
+            for (member_partition_index, partition_id) in partitions.into_iter().enumerate() {
+                let member_partition_index = member_partition_index as u32;
+                if keep_current_partition && partition_id == current_partition_id {
+                    member.current_partition_index = member_partition_index;
+                    member.current_partition_id = partition_id;
+                }
+                member
+                    .partitions
+                    .insert(member_partition_index, partition_id);
+                trace!("Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                    partition_id, member.id, self.topic_id, self.consumer_group_id)
+            }
         }
+    }
+}
 
-        for partition_index in 0..self.partitions_count {
-            let partition_id = partition_index + 1;
-            let member_index = partition_index % members_count;
-            let member = members.get(member_index as usize).unwrap();
-            let mut member = member.write().await;
-            let member_partition_index = member.partitions.len() as u32;
-            member
-                .partitions
-                .insert(member_partition_index, partition_id);
-            trace!("Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
-                partition_id, member.id, self.topic_id, self.consumer_group_id)
+/// Deals the partitions one by one to the members.
+fn assign_round_robin(partitions_count: u32, members_count: usize) -> Vec<Vec<u32>> {
+    let mut assignments = vec![Vec::new(); members_count];
+    for partition_index in 0..partitions_count {
+        let member_index = partition_index as usize % members_count;
+        assignments[member_index].push(partition_index + 1);
+    }
+    assignments
+}
+
+/// Splits the partitions into contiguous ranges, the first members get one more partition if they can't be split evenly.
+fn assign_range(partitions_count: u32, members_count: usize) -> Vec<Vec<u32>> {
+    let members_count = members_count as u32;
+    let partitions_per_member = partitions_count / members_count;
+    let members_with_extra_partition = partitions_count % members_count;
+    let mut assignments = Vec::with_capacity(members_count as usize);
+    let mut partition_id = 1;
+    for member_index in 0..members_count {
+        let mut count = partitions_per_member;
+        if member_index < members_with_extra_partition {
+            count += 1;
         }
+        assignments.push((partition_id..partition_id + count).collect());
+        partition_id += count;
     }
+    assignments
+}
+
+/// Keeps as many of the already owned partitions as the balanced assignment allows,
+/// and hands out the remaining ones to the members with the fewest partitions.
+fn assign_sticky(partitions_count: u32, owned_partitions: &[Vec<u32>]) -> Vec<Vec<u32>> {
+    let members_count = owned_partitions.len();
+    let partitions_per_member = partitions_count as usize / members_count;
+    let members_with_extra_partition = partitions_count as usize % members_count;
+    let mut taken = vec![false; partitions_count as usize + 1];
+    let mut assignments = vec![Vec::new(); members_count];
+    for (member_index, partitions) in owned_partitions.iter().enumerate() {
+        for partition_id in partitions {
+            let partition_id = *partition_id;
+            if partition_id == 0 || partition_id > partitions_count || taken[partition_id as usize]
+            {
+                continue;
+            }
+            taken[partition_id as usize] = true;
+            assignments[member_index].push(partition_id);
+        }
+    }
+
+    // The members that already own the most partitions are the ones allowed to keep an extra one.
+    let mut members_by_load = (0..members_count).collect::<Vec<_>>();
+    members_by_load.sort_by_key(|member_index| std::cmp::Reverse(assignments[*member_index].len()));
+    let mut quotas = vec![partitions_per_member; members_count];
+    for member_index in members_by_load.iter().take(members_with_extra_partition) {
+        quotas[*member_index] += 1;
+    }
+
+    for (member_index, partitions) in assignments.iter_mut().enumerate() {
+        while partitions.len() > quotas[member_index] {
+            let partition_id = partitions.pop().unwrap();
+            taken[partition_id as usize] = false;
+        }
+    }
+
+    let mut unassigned_partitions = (1..=partitions_count)
+        .filter(|partition_id| !taken[*partition_id as usize])
+        .collect::<Vec<_>>()
+        .into_iter();
+    for (member_index, partitions) in assignments.iter_mut().enumerate() {
+        while partitions.len() < quotas[member_index] {
+            match unassigned_partitions.next() {
+                Some(partition_id) => partitions.push(partition_id),
+                None => break,
+            }
+        }
+    }
+    assignments
 }
 
 impl ConsumerGroupMember {
@@ -134,6 +299,12 @@ impl ConsumerGroupMember {
         self.partitions.values().copied().collect()
     }
 
+    fn get_ordered_partitions(&self) -> Vec<u32> {
+        (0..self.partitions.len() as u32)
+            .filter_map(|index| self.partitions.get(&index).copied())
+            .collect()
+    }
+
     pub fn calculate_partition_id(&mut self) -> u32 {
         let partition_index = self.current_partition_index;
         let partition_id = *self.partitions.get(&partition_index).unwrap();
@@ -164,6 +335,10 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            partition_assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            session_timeout: None,
+            dead_letter_topic: None,
+            generation: 0,
             members: HashMap::new(),
         };
 
@@ -185,6 +360,10 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            partition_assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            session_timeout: None,
+            dead_letter_topic: None,
+            generation: 0,
             members: HashMap::new(),
         };
 
@@ -210,6 +389,10 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            partition_assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            session_timeout: None,
+            dead_letter_topic: None,
+            generation: 0,
             members: HashMap::new(),
         };
 
@@ -247,6 +430,10 @@ mod tests {
             consumer_group_id: 1,
             name: "test".to_string(),
             partitions_count: 1,
+            partition_assignment_strategy: PartitionAssignmentStrategy::RoundRobin,
+            session_timeout: None,
+            dead_letter_topic: None,
+            generation: 0,
             members: HashMap::new(),
         };
 
@@ -263,4 +450,150 @@ mod tests {
             assert_eq!(member2.partitions.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn should_assign_contiguous_partitions_ranges_to_the_members() {
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            5,
+            PartitionAssignmentStrategy::Range,
+            None,
+            None,
+        );
+
+        consumer_group.add_member(2).await;
+        consumer_group.add_member(1).await;
+        let member1 = consumer_group.members.get(&1).unwrap().read().await;
+        let member2 = consumer_group.members.get(&2).unwrap().read().await;
+        assert_eq!(member1.get_ordered_partitions(), vec![1, 2, 3]);
+        assert_eq!(member2.get_ordered_partitions(), vec![4, 5]);
+    }
+
+    #[tokio::test]
+    async fn should_keep_owned_partitions_when_members_change_using_sticky_assignment() {
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            6,
+            PartitionAssignmentStrategy::Sticky,
+            None,
+            None,
+        );
+
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        let member1_partitions = get_member_partitions(&consumer_group, 1).await;
+        let member2_partitions = get_member_partitions(&consumer_group, 2).await;
+        assert_eq!(member1_partitions.len(), 3);
+        assert_eq!(member2_partitions.len(), 3);
+
+        consumer_group.add_member(3).await;
+        let member1_new_partitions = get_member_partitions(&consumer_group, 1).await;
+        let member2_new_partitions = get_member_partitions(&consumer_group, 2).await;
+        let member3_partitions = get_member_partitions(&consumer_group, 3).await;
+        assert_eq!(member1_new_partitions.len(), 2);
+        assert_eq!(member2_new_partitions.len(), 2);
+        assert_eq!(member3_partitions.len(), 2);
+        assert!(member1_new_partitions
+            .iter()
+            .all(|partition_id| member1_partitions.contains(partition_id)));
+        assert!(member2_new_partitions
+            .iter()
+            .all(|partition_id| member2_partitions.contains(partition_id)));
+
+        consumer_group.delete_member(1).await;
+        let member2_partitions = get_member_partitions(&consumer_group, 2).await;
+        let member3_new_partitions = get_member_partitions(&consumer_group, 3).await;
+        assert_eq!(member2_partitions.len(), 3);
+        assert_eq!(member3_new_partitions.len(), 3);
+        assert!(member2_new_partitions
+            .iter()
+            .all(|partition_id| member2_partitions.contains(partition_id)));
+        assert!(member3_partitions
+            .iter()
+            .all(|partition_id| member3_new_partitions.contains(partition_id)));
+        for partition_id in 1..=consumer_group.partitions_count {
+            assert!(
+                member2_partitions.contains(&partition_id)
+                    || member3_new_partitions.contains(&partition_id)
+            );
+        }
+    }
+
+    async fn get_member_partitions(consumer_group: &ConsumerGroup, member_id: u32) -> Vec<u32> {
+        consumer_group
+            .members
+            .get(&member_id)
+            .unwrap()
+            .read()
+            .await
+            .get_ordered_partitions()
+    }
+
+    #[tokio::test]
+    async fn should_return_stale_members_only_when_session_timeout_is_set() {
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            3,
+            PartitionAssignmentStrategy::RoundRobin,
+            Some(10),
+            None,
+        );
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        consumer_group
+            .members
+            .get(&1)
+            .unwrap()
+            .write()
+            .await
+            .last_heartbeat_at = 0;
+
+        let now = TimeStamp::now().to_micros();
+        assert_eq!(consumer_group.get_stale_members(now).await, vec![1]);
+
+        consumer_group.heartbeat(1).await.unwrap();
+        assert!(consumer_group.get_stale_members(now).await.is_empty());
+        assert!(consumer_group.heartbeat(3).await.is_err());
+
+        consumer_group.session_timeout = None;
+        assert!(consumer_group
+            .get_stale_members(now + 60_000_000)
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_increment_generation_on_every_rebalance() {
+        let mut consumer_group = ConsumerGroup::new(
+            1,
+            1,
+            "test",
+            3,
+            PartitionAssignmentStrategy::RoundRobin,
+            None,
+            None,
+        );
+        assert_eq!(consumer_group.get_generation(), 0);
+
+        consumer_group.add_member(1).await;
+        assert_eq!(consumer_group.get_generation(), 1);
+
+        consumer_group.add_member(2).await;
+        assert_eq!(consumer_group.get_generation(), 2);
+
+        consumer_group.reassign_partitions(4).await;
+        assert_eq!(consumer_group.get_generation(), 3);
+
+        consumer_group.delete_member(3).await;
+        assert_eq!(consumer_group.get_generation(), 3);
+
+        consumer_group.delete_member(2).await;
+        assert_eq!(consumer_group.get_generation(), 4);
+    }
 }
@@ -1,7 +1,9 @@
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::consumer_group::DeadLetterTopic;
 use iggy::utils::text;
 use tokio::sync::RwLock;
 use tracing::info;
@@ -60,7 +62,14 @@ impl Topic {
         Ok(consumer_group.unwrap())
     }
 
-    pub async fn create_consumer_group(&mut self, id: u32, name: &str) -> Result<(), Error> {
+    pub async fn create_consumer_group(
+        &mut self,
+        id: u32,
+        name: &str,
+        partition_assignment_strategy: PartitionAssignmentStrategy,
+        session_timeout: Option<u32>,
+        dead_letter_topic: Option<DeadLetterTopic>,
+    ) -> Result<(), Error> {
         if self.consumer_groups.contains_key(&id) {
             return Err(Error::ConsumerGroupIdAlreadyExists(id, self.topic_id));
         }
@@ -70,8 +79,15 @@ impl Topic {
             return Err(Error::ConsumerGroupNameAlreadyExists(name, self.topic_id));
         }
 
-        let consumer_group =
-            ConsumerGroup::new(self.topic_id, id, &name, self.partitions.len() as u32);
+        let consumer_group = ConsumerGroup::new(
+            self.topic_id,
+            id,
+            &name,
+            self.partitions.len() as u32,
+            partition_assignment_strategy,
+            session_timeout,
+            dead_letter_topic,
+        );
         self.consumer_groups.insert(id, RwLock::new(consumer_group));
         self.consumer_groups_ids.insert(name, id);
         let consumer_group = self.get_consumer_group_by_id(id)?;
@@ -136,6 +152,16 @@ impl Topic {
         Ok(())
     }
 
+    pub async fn heartbeat_consumer_group(
+        &self,
+        consumer_group_id: &Identifier,
+        member_id: u32,
+    ) -> Result<(), Error> {
+        let consumer_group = self.get_consumer_group(consumer_group_id)?;
+        let consumer_group = consumer_group.read().await;
+        consumer_group.heartbeat(member_id).await
+    }
+
     pub async fn leave_consumer_group(
         &self,
         consumer_group_id: &Identifier,
@@ -157,6 +183,7 @@ mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
     use std::sync::Arc;
 
     #[tokio::test]
@@ -164,7 +191,15 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let consumer_group = topic
@@ -185,10 +220,26 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
+            .await;
         assert!(result.is_err());
         assert_eq!(topic.consumer_groups.len(), 1);
         let err = result.unwrap_err();
@@ -200,11 +251,27 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let consumer_group_id = consumer_group_id + 1;
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
+            .await;
         assert!(result.is_err());
         assert_eq!(topic.consumer_groups.len(), 1);
         let err = result.unwrap_err();
@@ -216,7 +283,15 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let result = topic
@@ -231,7 +306,15 @@ mod tests {
         let consumer_group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id, name).await;
+        let result = topic
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let consumer_group_id = consumer_group_id + 1;
@@ -249,7 +332,13 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic();
         topic
-            .create_consumer_group(consumer_group_id, name)
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
             .await
             .unwrap();
         let result = topic
@@ -272,7 +361,13 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic();
         topic
-            .create_consumer_group(consumer_group_id, name)
+            .create_consumer_group(
+                consumer_group_id,
+                name,
+                PartitionAssignmentStrategy::default(),
+                None,
+                None,
+            )
             .await
             .unwrap();
         topic
@@ -300,6 +395,19 @@ mod tests {
         let partitions_count = 3;
         let config = Arc::new(SystemConfig::default());
 
-        Topic::create(stream_id, id, name, partitions_count, config, storage, None).unwrap()
+        Topic::create(
+            stream_id,
+            id,
+            name,
+            partitions_count,
+            config,
+            storage,
+            None,
+            CompressionAlgorithm::default(),
+            false,
+            None,
+            None,
+        )
+        .unwrap()
     }
 }
@@ -1,7 +1,9 @@
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
+use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::error::Error;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
 use tokio::sync::RwLock;
 
@@ -30,7 +32,30 @@ impl Topic {
         })
     }
 
-    async fn resolve_partition(
+    pub async fn get_consumer_lag(&self, consumer: &Consumer) -> Result<ConsumerLag, Error> {
+        let consumer = match consumer.kind {
+            ConsumerKind::Consumer => {
+                PollingConsumer::Consumer(PollingConsumer::resolve_consumer_id(&consumer.id), 0)
+            }
+            ConsumerKind::ConsumerGroup => {
+                let consumer_group = self.get_consumer_group(&consumer.id)?.read().await;
+                PollingConsumer::ConsumerGroup(consumer_group.consumer_group_id, 0)
+            }
+        };
+
+        let mut partitions = Vec::with_capacity(self.partitions.len());
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            partitions.push(partition.get_consumer_lag(consumer).await);
+        }
+        partitions.sort_by_key(|partition| partition.partition_id);
+        Ok(ConsumerLag {
+            total_lag: partitions.iter().map(|partition| partition.lag).sum(),
+            partitions,
+        })
+    }
+
+    pub(crate) async fn resolve_partition(
         &self,
         consumer: PollingConsumer,
     ) -> Result<&RwLock<Partition>, Error> {
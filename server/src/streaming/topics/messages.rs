@@ -1,15 +1,20 @@
 use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::partitions::messages::{AppendedMessages, ScannedMessages};
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::utils::file::folder_size;
 use crate::streaming::utils::hash;
 use iggy::error::Error;
+use iggy::messages::message_filter::MessageFilter;
 use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
-use iggy::messages::send_messages::{Partitioning, PartitioningKind};
+use iggy::messages::send_messages::{Partitioning, PartitioningKind, Producer};
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::Message;
+use iggy::utils::timestamp::TimeStamp;
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use tokio::sync::Notify;
 use tracing::{info, trace, warn};
 
 impl Topic {
@@ -28,6 +33,7 @@ impl Topic {
         partition_id: u32,
         strategy: PollingStrategy,
         count: u32,
+        filter: Option<&MessageFilter>,
     ) -> Result<PolledMessages, Error> {
         if !self.has_partitions() {
             return Err(Error::NoPartitions(self.topic_id, self.stream_id));
@@ -45,32 +51,121 @@ impl Topic {
         let partition = partition.unwrap();
         let partition = partition.read().await;
         let value = strategy.value;
-        let messages = match strategy.kind {
-            PollingKind::Offset => partition.get_messages_by_offset(value, count).await,
-            PollingKind::Timestamp => partition.get_messages_by_timestamp(value, count).await,
-            PollingKind::First => partition.get_first_messages(count).await,
-            PollingKind::Last => partition.get_last_messages(count).await,
-            PollingKind::Next => partition.get_next_messages(consumer, count).await,
+        let now = TimeStamp::now().to_micros();
+        // The delayed messages, which the consumer has already polled past before they were due, come first.
+        let mut due_delayed_messages = Vec::new();
+        if strategy.kind == PollingKind::Next {
+            due_delayed_messages = partition
+                .get_due_delayed_messages(consumer, now, count, filter)
+                .await?;
+        }
+
+        let remaining_count = count - due_delayed_messages.len() as u32;
+        let scanned_messages = match strategy.kind {
+            PollingKind::Offset => {
+                partition
+                    .get_filtered_messages_by_offset(value, count, filter)
+                    .await
+            }
+            PollingKind::Timestamp => {
+                partition
+                    .get_messages_by_timestamp(value, count, filter)
+                    .await
+            }
+            PollingKind::First => partition.get_first_messages(count, filter).await,
+            PollingKind::Last => partition.get_last_messages(count, filter).await,
+            PollingKind::Next if remaining_count == 0 => Ok(ScannedMessages::default()),
+            PollingKind::Next => {
+                partition
+                    .get_next_messages(consumer, remaining_count, filter)
+                    .await
+            }
         }?;
 
+        // The scan may have stopped past the last returned message, when the following ones didn't match the filter,
+        // so the consumer doesn't have to scan them again.
+        let last_scanned_offset = scanned_messages.last_scanned_offset;
+        let mut messages = scanned_messages.messages;
+
+        // The delayed messages which aren't due yet are skipped without holding back the following ones,
+        // as they are returned from the index of the delayed messages, once they are due.
+        if strategy.kind == PollingKind::Next {
+            messages.retain(|message| message.is_due(now));
+            due_delayed_messages.append(&mut messages);
+            messages = due_delayed_messages;
+        }
+
         Ok(PolledMessages {
             messages,
             partition_id,
             current_offset: partition.current_offset,
+            last_scanned_offset,
+            generation: 0,
         })
     }
 
+    /// Returns the timestamp at which the next delayed message in the partition becomes due after the given time.
+    pub async fn get_next_delivery_at(&self, partition_id: u32, now: u64) -> Option<u64> {
+        let partition = self.partitions.get(&partition_id)?;
+        let partition = partition.read().await;
+        partition.get_next_delivery_at(now)
+    }
+
+    /// Returns the notifiers of the new messages for the partitions polled by the consumer, along with their IDs.
+    /// The consumer group member polls all its assigned partitions in turn, so it's woken up by any of them.
+    /// The partitions which can't be resolved are skipped, as polling them will return the error anyway.
+    pub async fn get_messages_notifiers(
+        &self,
+        consumer: PollingConsumer,
+    ) -> Vec<(u32, Arc<Notify>)> {
+        let partition_ids = match consumer {
+            PollingConsumer::Consumer(_, partition_id) => vec![partition_id],
+            PollingConsumer::ConsumerGroup(consumer_group_id, member_id) => {
+                match self.get_consumer_group_by_id(consumer_group_id) {
+                    Ok(consumer_group) => consumer_group
+                        .read()
+                        .await
+                        .get_member_partitions(member_id)
+                        .await
+                        .unwrap_or_default(),
+                    Err(_) => Vec::new(),
+                }
+            }
+        };
+
+        let mut notifiers = Vec::with_capacity(partition_ids.len());
+        for partition_id in partition_ids {
+            if let Some(partition) = self.partitions.get(&partition_id) {
+                let notifier = partition.read().await.messages_notifier.clone();
+                notifiers.push((partition_id, notifier));
+            }
+        }
+        notifiers
+    }
+
     pub async fn append_messages(
         &self,
         partitioning: &Partitioning,
+        producer: Option<&Producer>,
         messages: Vec<Message>,
-    ) -> Result<(), Error> {
-        if !self.has_partitions() {
-            return Err(Error::NoPartitions(self.topic_id, self.stream_id));
+    ) -> Result<AppendedMessages, Error> {
+        if messages.is_empty() {
+            return Ok(AppendedMessages::default());
         }
 
-        if messages.is_empty() {
-            return Ok(());
+        let (partition_id, messages) = self.prepare_messages(partitioning, messages)?;
+        self.append_messages_to_partition(partition_id, producer, messages)
+            .await
+    }
+
+    /// Returns the ID of the partition, to which the messages should be appended.
+    pub fn prepare_messages(
+        &self,
+        partitioning: &Partitioning,
+        mut messages: Vec<Message>,
+    ) -> Result<(u32, Vec<Message>), Error> {
+        if !self.has_partitions() {
+            return Err(Error::NoPartitions(self.topic_id, self.stream_id));
         }
 
         let partition_id = match partitioning.kind {
@@ -83,15 +178,29 @@ impl Topic {
             }
         };
 
-        self.append_messages_to_partition(partition_id, messages)
-            .await
+        // The messages key has to be persisted with each message, so that the compactor can find its latest value.
+        if self.compaction_enabled && partitioning.kind == PartitioningKind::MessagesKey {
+            for message in messages.iter_mut() {
+                if message.get_messages_key().is_some() {
+                    continue;
+                }
+
+                message.headers.get_or_insert_with(HashMap::new).insert(
+                    HeaderKey::messages_key(),
+                    HeaderValue::from_raw(&partitioning.value)?,
+                );
+            }
+        }
+
+        Ok((partition_id, messages))
     }
 
-    async fn append_messages_to_partition(
+    pub(crate) async fn append_messages_to_partition(
         &self,
         partition_id: u32,
+        producer: Option<&Producer>,
         messages: Vec<Message>,
-    ) -> Result<(), Error> {
+    ) -> Result<AppendedMessages, Error> {
         let partition = self.partitions.get(&partition_id);
         if partition.is_none() {
             return Err(Error::PartitionNotFound(
@@ -103,8 +212,9 @@ impl Topic {
 
         let partition = partition.unwrap();
         let mut partition = partition.write().await;
-        partition.append_messages(messages).await?;
-        Ok(())
+        let appended_messages = partition.append_messages(messages, producer).await?;
+        partition.messages_notifier.notify_waiters();
+        Ok(appended_messages)
     }
 
     fn get_next_partition_id(&self) -> u32 {
@@ -211,26 +321,14 @@ impl Topic {
             return false;
         }
 
-        let first_offset = cache[0].offset;
-        let last_offset = cache[cache.len() - 1].offset;
-
+        // The offsets might have gaps left by the compaction, but they must be increasing.
         for i in 1..cache.len() {
-            if cache[i].offset != cache[i - 1].offset + 1 {
-                warn!("Offsets are not subsequent at index {} offset {}, for previous index {} offset is {}", i, cache[i].offset, i-1, cache[i-1].offset);
+            if cache[i].offset <= cache[i - 1].offset {
+                warn!("Offsets are not increasing at index {} offset {}, for previous index {} offset is {}", i, cache[i].offset, i-1, cache[i-1].offset);
                 return false;
             }
         }
 
-        let expected_messages_count: u64 = last_offset - first_offset + 1;
-        if cache.len() != expected_messages_count as usize {
-            warn!(
-                "Messages count is in cache ({}) not equal to expected messages count ({})",
-                cache.len(),
-                expected_messages_count
-            );
-            return false;
-        }
-
         true
     }
 
@@ -261,6 +359,7 @@ mod tests {
     use crate::configs::system::SystemConfig;
     use crate::streaming::storage::tests::get_test_system_storage;
     use bytes::Bytes;
+    use iggy::compression::compression_algorithm::CompressionAlgorithm;
     use iggy::models::messages::MessageState;
     use std::sync::Arc;
 
@@ -283,7 +382,7 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(&partitioning, None, messages)
                 .await
                 .unwrap();
         }
@@ -319,7 +418,7 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(&partitioning, None, messages)
                 .await
                 .unwrap();
         }
@@ -382,6 +481,19 @@ mod tests {
         let name = "test";
         let config = Arc::new(SystemConfig::default());
 
-        Topic::create(stream_id, id, name, partitions_count, config, storage, None).unwrap()
+        Topic::create(
+            stream_id,
+            id,
+            name,
+            partitions_count,
+            config,
+            storage,
+            None,
+            CompressionAlgorithm::default(),
+            false,
+            None,
+            None,
+        )
+        .unwrap()
     }
 }
@@ -17,6 +17,12 @@ impl Topic {
     }
 
     pub fn add_partitions(&mut self, count: u32) -> Result<Vec<u32>, Error> {
+        let partitions = self.create_partitions(count)?;
+        Ok(self.insert_partitions(partitions))
+    }
+
+    /// Creates the next partitions which are neither persisted nor added to the topic yet.
+    pub fn create_partitions(&self, count: u32) -> Result<Vec<Partition>, Error> {
         if count == 0 {
             return Ok(vec![]);
         }
@@ -26,9 +32,9 @@ impl Topic {
             return Err(Error::TooManyPartitions);
         }
 
-        let mut partition_ids = Vec::with_capacity(count as usize);
+        let mut partitions = Vec::with_capacity(count as usize);
         for partition_id in current_partitions_count + 1..=current_partitions_count + count {
-            let partition = Partition::create(
+            partitions.push(Partition::create(
                 self.stream_id,
                 self.topic_id,
                 partition_id,
@@ -36,55 +42,34 @@ impl Topic {
                 self.config.clone(),
                 self.storage.clone(),
                 self.message_expiry,
-            );
-            self.partitions
-                .insert(partition_id, Arc::new(RwLock::new(partition)));
-            partition_ids.push(partition_id)
+                self.compression_algorithm,
+            ));
         }
 
-        Ok(partition_ids)
+        Ok(partitions)
     }
 
-    pub async fn add_persisted_partitions(&mut self, count: u32) -> Result<Vec<u32>, Error> {
-        let partition_ids = self.add_partitions(count)?;
-        for partition_id in &partition_ids {
-            let partition = self.partitions.get(partition_id).unwrap();
-            let partition = partition.read().await;
-            partition.persist().await?;
+    pub fn insert_partitions(&mut self, partitions: Vec<Partition>) -> Vec<u32> {
+        let mut partition_ids = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            partition_ids.push(partition.partition_id);
+            self.partitions
+                .insert(partition.partition_id, Arc::new(RwLock::new(partition)));
         }
-        Ok(partition_ids)
+        partition_ids
     }
 
-    pub async fn delete_persisted_partitions(
-        &mut self,
-        mut count: u32,
-    ) -> Result<Option<DeletedPartitions>, Error> {
-        if count == 0 {
-            return Ok(None);
-        }
-
+    /// Removes the last partitions from the topic, their files have to be deleted afterwards.
+    pub fn remove_partitions(&mut self, mut count: u32) -> Vec<Arc<RwLock<Partition>>> {
         let current_partitions_count = self.partitions.len() as u32;
         if count > current_partitions_count {
             count = current_partitions_count;
         }
 
-        let mut segments_count = 0;
-        let mut messages_count = 0;
+        let mut partitions = Vec::with_capacity(count as usize);
         for partition_id in current_partitions_count - count + 1..=current_partitions_count {
-            let partition = self.partitions.remove(&partition_id).unwrap();
-            let partition = partition.read().await;
-            partition.delete().await?;
-            segments_count += partition.get_segments_count();
-            messages_count += partition.get_messages_count();
+            partitions.push(self.partitions.remove(&partition_id).unwrap());
         }
-        Ok(Some(DeletedPartitions {
-            segments_count,
-            messages_count,
-        }))
+        partitions
     }
 }
-
-pub struct DeletedPartitions {
-    pub segments_count: u32,
-    pub messages_count: u64,
-}
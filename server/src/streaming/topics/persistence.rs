@@ -22,9 +22,15 @@ impl Topic {
                     consumer_group.consumer_group_id,
                     &consumer_group.name,
                     self.get_partitions_count(),
+                    consumer_group.partition_assignment_strategy,
+                    consumer_group.session_timeout,
+                    consumer_group.dead_letter_topic,
                 )),
             );
         }
+        for schema in storage.topic.load_schemas(self).await? {
+            self.schemas.insert(schema.id, schema);
+        }
         Ok(())
     }
 
@@ -47,6 +53,7 @@ impl Topic {
             for segment in partition.get_segments_mut() {
                 segment.persist_messages(storage.clone()).await?;
             }
+            partition.persist_producers().await?;
         }
 
         Ok(())
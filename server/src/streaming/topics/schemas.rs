@@ -0,0 +1,205 @@
+use crate::streaming::schemas::compatibility;
+use crate::streaming::schemas::schema::{SchemaDefinition, TopicSchema};
+use crate::streaming::topics::topic::Topic;
+use iggy::error::Error;
+use iggy::messages::send_messages::Message;
+use iggy::models::header::get_schema_id;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+use iggy::utils::timestamp::TimeStamp;
+use tracing::info;
+
+impl Topic {
+    pub fn get_schemas(&self) -> Vec<&TopicSchema> {
+        self.schemas.values().collect()
+    }
+
+    pub fn get_schema(&self, id: u32) -> Result<&TopicSchema, Error> {
+        match self.schemas.get(&id) {
+            Some(schema) => Ok(schema),
+            None => Err(Error::SchemaNotFound(id, self.topic_id, self.stream_id)),
+        }
+    }
+
+    /// Registers the next version of the schema, which must be compatible with the latest one for the configured compatibility mode.
+    pub async fn register_schema(
+        &mut self,
+        definition: SchemaDefinition,
+    ) -> Result<&TopicSchema, Error> {
+        let id = self.current_schema_id + 1;
+        let format = definition.format;
+        let schema = TopicSchema::from_definition(id, definition, TimeStamp::now().to_micros());
+        if let Some(latest) = self.schemas.values().next_back() {
+            compatibility::check(self.schema_compatibility, latest, &schema)?;
+        }
+
+        self.storage.topic.save_schema(self, &schema).await?;
+        self.current_schema_id = id;
+        self.persist().await?;
+        self.schemas.insert(id, schema);
+        info!(
+            "Registered schema with ID: {} and format: {} for topic with ID: {} and stream with ID: {}.",
+            id, format, self.topic_id, self.stream_id
+        );
+        self.get_schema(id)
+    }
+
+    pub async fn delete_schema(&mut self, id: u32) -> Result<TopicSchema, Error> {
+        let schema = self.get_schema(id)?;
+        self.storage.topic.delete_schema(self, schema).await?;
+        Ok(self.schemas.remove(&id).unwrap())
+    }
+
+    pub async fn update_schema_settings(
+        &mut self,
+        compatibility: SchemaCompatibility,
+        validation_enabled: bool,
+    ) -> Result<(), Error> {
+        self.schema_compatibility = compatibility;
+        self.schema_validation_enabled = validation_enabled;
+        self.persist().await?;
+        info!(
+            "Updated schema settings for topic with ID: {} and stream with ID: {}, compatibility: {}, validation enabled: {}.",
+            self.topic_id, self.stream_id, compatibility, validation_enabled
+        );
+        Ok(())
+    }
+
+    /// When the validation is enabled, each message must reference the existing schema of the topic in its schema ID header.
+    pub fn validate_messages(&self, messages: &[Message]) -> Result<(), Error> {
+        if !self.schema_validation_enabled {
+            return Ok(());
+        }
+
+        for message in messages {
+            let Some(schema_id) = get_schema_id(&message.headers) else {
+                return Err(Error::MissingSchemaId(self.topic_id, self.stream_id));
+            };
+
+            self.get_schema(schema_id)?
+                .validate_payload(&message.payload)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use bytes::Bytes;
+    use iggy::models::header::set_schema_id;
+    use iggy::schemas::schema_format::SchemaFormat;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    const V1: &str =
+        r#"{"type":"object","properties":{"id":{"type":"integer"}},"required":["id"]}"#;
+    const V2: &str = r#"{"type":"object","properties":{"id":{"type":"integer"},"name":{"type":"string"}},"required":["id"]}"#;
+
+    #[tokio::test]
+    async fn schemas_should_be_registered_with_sequential_ids() {
+        let mut topic = get_topic();
+        let first_id = topic
+            .register_schema(SchemaDefinition::parse(SchemaFormat::JsonSchema, V1).unwrap())
+            .await
+            .unwrap()
+            .id;
+        let second_id = topic
+            .register_schema(SchemaDefinition::parse(SchemaFormat::JsonSchema, V2).unwrap())
+            .await
+            .unwrap()
+            .id;
+        topic.delete_schema(second_id).await.unwrap();
+        let third_id = topic
+            .register_schema(SchemaDefinition::parse(SchemaFormat::JsonSchema, V2).unwrap())
+            .await
+            .unwrap()
+            .id;
+
+        assert_eq!(first_id, 1);
+        assert_eq!(second_id, 2);
+        assert_eq!(third_id, 3);
+        assert_eq!(topic.get_schemas().len(), 2);
+        assert!(topic.get_schema(second_id).is_err());
+    }
+
+    #[tokio::test]
+    async fn incompatible_schema_should_not_be_registered() {
+        let mut topic = get_topic();
+        topic
+            .register_schema(SchemaDefinition::parse(SchemaFormat::JsonSchema, V1).unwrap())
+            .await
+            .unwrap();
+        topic
+            .update_schema_settings(SchemaCompatibility::Full, false)
+            .await
+            .unwrap();
+
+        let result = topic
+            .register_schema(
+                SchemaDefinition::parse(
+                    SchemaFormat::JsonSchema,
+                    r#"{"type":"object","properties":{"id":{"type":"string"}}}"#,
+                )
+                .unwrap(),
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(topic.get_schemas().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn messages_should_be_validated_only_when_enabled() {
+        let mut topic = get_topic();
+        let schema_id = topic
+            .register_schema(SchemaDefinition::parse(SchemaFormat::JsonSchema, V1).unwrap())
+            .await
+            .unwrap()
+            .id;
+        let valid = get_message(r#"{"id":1}"#, Some(schema_id));
+        let invalid = get_message(r#"{"id":"1"}"#, Some(schema_id));
+        let untagged = get_message(r#"{"id":1}"#, None);
+        let unknown = get_message(r#"{"id":1}"#, Some(schema_id + 1));
+
+        assert!(topic.validate_messages(&[invalid]).is_ok());
+
+        topic
+            .update_schema_settings(SchemaCompatibility::default(), true)
+            .await
+            .unwrap();
+        let invalid = get_message(r#"{"id":"1"}"#, Some(schema_id));
+        assert!(topic.validate_messages(&[valid]).is_ok());
+        assert!(topic.validate_messages(&[invalid]).is_err());
+        assert!(topic.validate_messages(&[untagged]).is_err());
+        assert!(topic.validate_messages(&[unknown]).is_err());
+    }
+
+    fn get_message(payload: &str, schema_id: Option<u32>) -> Message {
+        let headers = schema_id.map(|schema_id| {
+            let mut headers = HashMap::new();
+            set_schema_id(&mut headers, schema_id).unwrap();
+            headers
+        });
+        Message::new(None, Bytes::from(payload.to_string()), headers)
+    }
+
+    fn get_topic() -> Topic {
+        Topic::create(
+            1,
+            1,
+            "test",
+            1,
+            Arc::new(SystemConfig::default()),
+            Arc::new(get_test_system_storage()),
+            None,
+            Default::default(),
+            false,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+}
@@ -1,4 +1,12 @@
 use crate::streaming::topics::topic::Topic;
+use iggy::error::Error;
+use tracing::info;
+
+#[derive(Debug)]
+pub struct DeletedSegments {
+    pub segments_count: u32,
+    pub messages_count: u64,
+}
 
 impl Topic {
     pub async fn get_segments_count(&self) -> u32 {
@@ -9,4 +17,79 @@ impl Topic {
 
         segments_count
     }
+
+    pub async fn delete_oversized_segments(&self) -> Result<Option<DeletedSegments>, Error> {
+        if self.max_topic_size.is_none() && self.max_partition_size.is_none() {
+            return Ok(None);
+        }
+
+        let mut segments_count = 0;
+        let mut messages_count = 0;
+        if let Some(max_partition_size) = self.max_partition_size {
+            for partition in self.get_partitions() {
+                let mut partition = partition.write().await;
+                while partition.get_size_bytes() > max_partition_size {
+                    let start_offset = match partition.get_oldest_closed_segment_start_offset() {
+                        Some(start_offset) => start_offset,
+                        None => break,
+                    };
+                    let deleted_segment = partition.delete_segment(start_offset).await?;
+                    segments_count += 1;
+                    messages_count += deleted_segment.get_messages_count();
+                }
+            }
+        }
+
+        if let Some(max_topic_size) = self.max_topic_size {
+            // The oldest closed segment of the largest partition is deleted first,
+            // so that the size budget is shared evenly between the partitions.
+            while self.get_size_bytes().await > max_topic_size {
+                let mut largest_partition = None;
+                let mut largest_partition_size = 0;
+                for partition in self.get_partitions() {
+                    let partition_size;
+                    {
+                        let partition = partition.read().await;
+                        if partition.get_oldest_closed_segment_start_offset().is_none() {
+                            continue;
+                        }
+                        partition_size = partition.get_size_bytes();
+                    }
+
+                    if largest_partition.is_none() || partition_size > largest_partition_size {
+                        largest_partition = Some(partition);
+                        largest_partition_size = partition_size;
+                    }
+                }
+
+                if largest_partition.is_none() {
+                    break;
+                }
+
+                let largest_partition = largest_partition.unwrap();
+                let mut partition = largest_partition.write().await;
+                let start_offset = match partition.get_oldest_closed_segment_start_offset() {
+                    Some(start_offset) => start_offset,
+                    None => break,
+                };
+                let deleted_segment = partition.delete_segment(start_offset).await?;
+                segments_count += 1;
+                messages_count += deleted_segment.get_messages_count();
+            }
+        }
+
+        if segments_count == 0 {
+            return Ok(None);
+        }
+
+        info!(
+            "Deleted {} segments exceeding the size limits for stream ID: {}, topic ID: {}",
+            segments_count, self.stream_id, self.topic_id
+        );
+
+        Ok(Some(DeletedSegments {
+            segments_count,
+            messages_count,
+        }))
+    }
 }
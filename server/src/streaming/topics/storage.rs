@@ -1,10 +1,16 @@
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::schemas::schema::TopicSchema;
 use crate::streaming::storage::{Storage, TopicStorage};
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use async_trait::async_trait;
 use futures::future::join_all;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer_groups::partition_assignment_strategy::PartitionAssignmentStrategy;
 use iggy::error::Error;
+use iggy::models::consumer_group::DeadLetterTopic;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
+use iggy::schemas::schema_format::SchemaFormat;
 use serde::{Deserialize, Serialize};
 use sled::Db;
 use std::path::Path;
@@ -32,6 +38,20 @@ unsafe impl Sync for FileTopicStorage {}
 struct ConsumerGroupData {
     id: u32,
     name: String,
+    #[serde(default)]
+    dead_letter_topic: Option<DeadLetterTopic>,
+    #[serde(default)]
+    partition_assignment_strategy: PartitionAssignmentStrategy,
+    #[serde(default)]
+    session_timeout: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SchemaData {
+    id: u32,
+    format: SchemaFormat,
+    definition: String,
+    created_at: u64,
 }
 
 #[async_trait]
@@ -49,6 +69,9 @@ impl TopicStorage for FileTopicStorage {
         match rmp_serde::to_vec(&ConsumerGroupData {
             id: consumer_group.consumer_group_id,
             name: consumer_group.name.clone(),
+            dead_letter_topic: consumer_group.dead_letter_topic,
+            partition_assignment_strategy: consumer_group.partition_assignment_strategy,
+            session_timeout: consumer_group.session_timeout,
         }) {
             Ok(data) => {
                 if let Err(err) = self.db.insert(key, data) {
@@ -106,6 +129,9 @@ impl TopicStorage for FileTopicStorage {
                 consumer_group.id,
                 &consumer_group.name,
                 topic.get_partitions_count(),
+                consumer_group.partition_assignment_strategy,
+                consumer_group.session_timeout,
+                consumer_group.dead_letter_topic,
             );
             consumer_groups.push(consumer_group);
         }
@@ -134,6 +160,82 @@ impl TopicStorage for FileTopicStorage {
 
         Ok(())
     }
+
+    async fn save_schema(&self, topic: &Topic, schema: &TopicSchema) -> Result<(), Error> {
+        let key = get_schema_key(topic.stream_id, topic.topic_id, schema.id);
+        match rmp_serde::to_vec(&SchemaData {
+            id: schema.id,
+            format: schema.format,
+            definition: schema.definition.clone(),
+            created_at: schema.created_at,
+        }) {
+            Ok(data) => {
+                if let Err(err) = self.db.insert(&key, data) {
+                    error!(
+                        "Cannot save schema with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}",
+                        schema.id, topic.topic_id, topic.stream_id, err
+                    );
+                    return Err(Error::CannotSaveResource(key));
+                }
+            }
+            Err(err) => {
+                error!(
+                    "Cannot serialize schema with ID: {} for topic with ID: {} for stream with ID: {}. Error: {}",
+                    schema.id, topic.topic_id, topic.stream_id, err
+                );
+                return Err(Error::CannotSerializeResource(key));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn load_schemas(&self, topic: &Topic) -> Result<Vec<TopicSchema>, Error> {
+        info!(
+            "Loading schemas for topic with ID: {} for stream with ID: {} from disk...",
+            topic.topic_id, topic.stream_id
+        );
+
+        let key_prefix = get_schemas_key_prefix(topic.stream_id, topic.topic_id);
+        let mut schemas = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", key_prefix)) {
+            let schema = match data {
+                Ok((_, value)) => match rmp_serde::from_slice::<SchemaData>(&value) {
+                    Ok(schema) => schema,
+                    Err(err) => {
+                        error!("Cannot deserialize schema. Error: {}", err);
+                        return Err(Error::CannotDeserializeResource(key_prefix));
+                    }
+                },
+                Err(err) => {
+                    error!("Cannot load schema. Error: {}", err);
+                    return Err(Error::CannotLoadResource(key_prefix));
+                }
+            };
+            schemas.push(TopicSchema::new(
+                schema.id,
+                schema.format,
+                &schema.definition,
+                schema.created_at,
+            )?);
+        }
+
+        Ok(schemas)
+    }
+
+    async fn delete_schema(&self, topic: &Topic, schema: &TopicSchema) -> Result<(), Error> {
+        let key = get_schema_key(topic.stream_id, topic.topic_id, schema.id);
+        if self.db.remove(&key).is_err() {
+            return Err(Error::CannotDeleteResource(key));
+        }
+
+        info!(
+            "Schema with ID: {} for topic with ID: {} and stream with ID: {} was deleted.",
+            schema.id, topic.topic_id, topic.stream_id
+        );
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -141,6 +243,20 @@ struct TopicData {
     name: String,
     created_at: u64,
     message_expiry: Option<u32>,
+    #[serde(default)]
+    compression_algorithm: CompressionAlgorithm,
+    #[serde(default)]
+    compaction_enabled: bool,
+    #[serde(default)]
+    max_topic_size: Option<u64>,
+    #[serde(default)]
+    max_partition_size: Option<u64>,
+    #[serde(default)]
+    current_schema_id: u32,
+    #[serde(default)]
+    schema_compatibility: SchemaCompatibility,
+    #[serde(default)]
+    schema_validation_enabled: bool,
 }
 
 #[async_trait]
@@ -175,6 +291,13 @@ impl Storage<Topic> for FileTopicStorage {
         topic.name = topic_data.name;
         topic.created_at = topic_data.created_at;
         topic.message_expiry = topic_data.message_expiry;
+        topic.compression_algorithm = topic_data.compression_algorithm;
+        topic.compaction_enabled = topic_data.compaction_enabled;
+        topic.max_topic_size = topic_data.max_topic_size;
+        topic.max_partition_size = topic_data.max_partition_size;
+        topic.current_schema_id = topic_data.current_schema_id;
+        topic.schema_compatibility = topic_data.schema_compatibility;
+        topic.schema_validation_enabled = topic_data.schema_validation_enabled;
 
         let dir_entries = fs::read_dir(&topic.partitions_path).await;
         if dir_entries.is_err() {
@@ -205,6 +328,7 @@ impl Storage<Topic> for FileTopicStorage {
                 topic.config.clone(),
                 topic.storage.clone(),
                 topic.message_expiry,
+                topic.compression_algorithm,
             );
             unloaded_partitions.push(partition);
         }
@@ -237,8 +361,8 @@ impl Storage<Topic> for FileTopicStorage {
         topic.load_messages_from_disk_to_cache().await?;
 
         info!(
-            "Loaded topic: '{}' with ID: {} for stream with ID: {} from disk. Message expiry: {:?}",
-            &topic.name, &topic.topic_id, topic.stream_id, topic.message_expiry
+            "Loaded topic: '{}' with ID: {} for stream with ID: {} from disk. Message expiry: {:?}, compression algorithm: {}, compaction enabled: {}, max topic size: {:?}, max partition size: {:?}",
+            &topic.name, &topic.topic_id, topic.stream_id, topic.message_expiry, topic.compression_algorithm, topic.compaction_enabled, topic.max_topic_size, topic.max_partition_size
         );
 
         Ok(())
@@ -266,6 +390,13 @@ impl Storage<Topic> for FileTopicStorage {
             name: topic.name.clone(),
             created_at: topic.created_at,
             message_expiry: topic.message_expiry,
+            compression_algorithm: topic.compression_algorithm,
+            compaction_enabled: topic.compaction_enabled,
+            max_topic_size: topic.max_topic_size,
+            max_partition_size: topic.max_partition_size,
+            current_schema_id: topic.current_schema_id,
+            schema_compatibility: topic.schema_compatibility,
+            schema_validation_enabled: topic.schema_validation_enabled,
         }) {
             Ok(data) => {
                 if let Err(err) = self.db.insert(&key, data) {
@@ -317,6 +448,9 @@ impl Storage<Topic> for FileTopicStorage {
             let consumer_group = consumer_group.read().await;
             self.delete_consumer_group(topic, &consumer_group).await?;
         }
+        for schema in topic.schemas.values() {
+            self.delete_schema(topic, schema).await?;
+        }
         if fs::remove_dir_all(&topic.path).await.is_err() {
             return Err(Error::CannotDeleteTopicDirectory(
                 topic.topic_id,
@@ -348,3 +482,15 @@ fn get_consumer_group_key(stream_id: u32, topic_id: u32, consumer_group_id: u32)
 fn get_consumer_groups_key_prefix(stream_id: u32, topic_id: u32) -> String {
     format!("streams:{}:topics:{}:consumer_groups", stream_id, topic_id)
 }
+
+fn get_schema_key(stream_id: u32, topic_id: u32, schema_id: u32) -> String {
+    format!(
+        "{}:{}",
+        get_schemas_key_prefix(stream_id, topic_id),
+        schema_id
+    )
+}
+
+fn get_schemas_key_prefix(stream_id: u32, topic_id: u32) -> String {
+    format!("streams:{}:topics:{}:schemas", stream_id, topic_id)
+}
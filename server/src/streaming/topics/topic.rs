@@ -1,10 +1,13 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::schemas::schema::TopicSchema;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
+use iggy::schemas::schema_compatibility::SchemaCompatibility;
 use iggy::utils::timestamp::TimeStamp;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -22,7 +25,15 @@ pub struct Topic {
     pub(crate) consumer_groups: HashMap<u32, RwLock<ConsumerGroup>>,
     pub(crate) consumer_groups_ids: HashMap<String, u32>,
     pub(crate) current_partition_id: AtomicU32,
+    pub(crate) schemas: BTreeMap<u32, TopicSchema>,
+    pub(crate) current_schema_id: u32,
+    pub schema_compatibility: SchemaCompatibility,
+    pub schema_validation_enabled: bool,
     pub message_expiry: Option<u32>,
+    pub compression_algorithm: CompressionAlgorithm,
+    pub compaction_enabled: bool,
+    pub max_topic_size: Option<u64>,
+    pub max_partition_size: Option<u64>,
     pub created_at: u64,
 }
 
@@ -33,9 +44,23 @@ impl Topic {
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
     ) -> Topic {
-        Topic::create(stream_id, topic_id, "", 0, config, storage, None).unwrap()
+        Topic::create(
+            stream_id,
+            topic_id,
+            "",
+            0,
+            config,
+            storage,
+            None,
+            CompressionAlgorithm::default(),
+            false,
+            None,
+            None,
+        )
+        .unwrap()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         stream_id: u32,
         topic_id: u32,
@@ -44,6 +69,10 @@ impl Topic {
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
         message_expiry: Option<u32>,
+        compression_algorithm: CompressionAlgorithm,
+        compaction_enabled: bool,
+        max_topic_size: Option<u64>,
+        max_partition_size: Option<u64>,
     ) -> Result<Topic, Error> {
         let path = config.get_topic_path(stream_id, topic_id);
         let partitions_path = config.get_partitions_path(stream_id, topic_id);
@@ -58,6 +87,10 @@ impl Topic {
             consumer_groups: HashMap::new(),
             consumer_groups_ids: HashMap::new(),
             current_partition_id: AtomicU32::new(1),
+            schemas: BTreeMap::new(),
+            current_schema_id: 0,
+            schema_compatibility: SchemaCompatibility::default(),
+            schema_validation_enabled: false,
             message_expiry: match message_expiry {
                 Some(expiry) => match expiry {
                     0 => None,
@@ -68,6 +101,10 @@ impl Topic {
                     expiry => Some(expiry),
                 },
             },
+            compression_algorithm,
+            compaction_enabled,
+            max_topic_size,
+            max_partition_size,
             config,
             created_at: TimeStamp::now().to_micros(),
         };
@@ -84,6 +121,22 @@ impl Topic {
         size_bytes
     }
 
+    pub async fn get_compression_ratio(&self) -> f64 {
+        let mut size_bytes = 0;
+        let mut uncompressed_size_bytes = 0;
+        for partition in self.get_partitions() {
+            let partition = partition.read().await;
+            size_bytes += partition.get_size_bytes();
+            uncompressed_size_bytes += partition.get_uncompressed_size_bytes();
+        }
+
+        if size_bytes == 0 {
+            return 1.0;
+        }
+
+        uncompressed_size_bytes as f64 / size_bytes as f64
+    }
+
     pub fn get_partitions(&self) -> Vec<Arc<RwLock<Partition>>> {
         self.partitions.values().map(Arc::clone).collect()
     }
@@ -124,6 +177,10 @@ mod tests {
             config,
             storage,
             Some(message_expiry),
+            CompressionAlgorithm::Lz4,
+            true,
+            None,
+            None,
         )
         .unwrap();
 
@@ -133,6 +190,8 @@ mod tests {
         assert_eq!(topic.name, name);
         assert_eq!(topic.partitions.len(), partitions_count as usize);
         assert_eq!(topic.message_expiry, Some(message_expiry));
+        assert_eq!(topic.compression_algorithm, CompressionAlgorithm::Lz4);
+        assert!(topic.compaction_enabled);
 
         for (id, partition) in topic.partitions {
             let partition = partition.blocking_read();
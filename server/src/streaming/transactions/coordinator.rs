@@ -0,0 +1,193 @@
+use crate::configs::system::TransactionConfig;
+use crate::streaming::transactions::transaction::{Transaction, TransactionBatch};
+use iggy::error::Error;
+use iggy::models::user_info::UserId;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::Mutex;
+use tracing::{info, trace};
+
+/// Keeps the messages of the open transactions in memory until they are committed or aborted,
+/// so the messages of the aborted (or expired) transactions never reach the partitions.
+#[derive(Debug)]
+pub struct TransactionCoordinator {
+    timeout: u64,
+    max_messages: u32,
+    current_transaction_id: AtomicU64,
+    transactions: Mutex<HashMap<u64, Transaction>>,
+}
+
+impl TransactionCoordinator {
+    pub fn new(config: &TransactionConfig) -> Self {
+        TransactionCoordinator {
+            timeout: config.timeout,
+            max_messages: config.max_messages,
+            // Starting from the current timestamp, so the IDs of the transactions lost on restart are not reused.
+            current_transaction_id: AtomicU64::new(TimeStamp::now().to_micros()),
+            transactions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn begin(&self, user_id: UserId) -> u64 {
+        let now = TimeStamp::now().to_micros();
+        let mut transactions = self.transactions.lock().await;
+        let transactions_count = transactions.len();
+        transactions.retain(|_, transaction| !transaction.is_expired(now));
+        let expired_transactions_count = transactions_count - transactions.len();
+        if expired_transactions_count > 0 {
+            info!("Removed {expired_transactions_count} expired transaction(s).");
+        }
+
+        let id = self.current_transaction_id.fetch_add(1, Ordering::SeqCst) + 1;
+        transactions.insert(id, Transaction::new(id, user_id, now, self.timeout));
+        trace!("Started transaction with ID: {id} for user with ID: {user_id}.");
+        id
+    }
+
+    pub async fn add_messages(
+        &self,
+        user_id: UserId,
+        transaction_id: u64,
+        batch: TransactionBatch,
+        size_bytes: u64,
+    ) -> Result<(), Error> {
+        let mut transactions = self.transactions.lock().await;
+        let transaction = match transactions.get_mut(&transaction_id) {
+            Some(transaction) if transaction.user_id == user_id => transaction,
+            _ => return Err(Error::TransactionNotFound(transaction_id)),
+        };
+
+        if transaction.is_expired(TimeStamp::now().to_micros()) {
+            transactions.remove(&transaction_id);
+            return Err(Error::TransactionExpired(transaction_id));
+        }
+
+        let messages_count = transaction.messages_count + batch.messages.len() as u32;
+        if messages_count > self.max_messages {
+            return Err(Error::TooManyTransactionMessages(
+                transaction_id,
+                self.max_messages,
+            ));
+        }
+
+        transaction.messages_count = messages_count;
+        transaction.size_bytes += size_bytes;
+        transaction.batches.push(batch);
+        Ok(())
+    }
+
+    /// Removes the transaction, which is then either committed or discarded by the caller.
+    pub async fn take(&self, user_id: UserId, transaction_id: u64) -> Result<Transaction, Error> {
+        let mut transactions = self.transactions.lock().await;
+        match transactions.get(&transaction_id) {
+            Some(transaction) if transaction.user_id == user_id => {}
+            _ => return Err(Error::TransactionNotFound(transaction_id)),
+        }
+
+        let transaction = transactions.remove(&transaction_id).unwrap();
+        if transaction.is_expired(TimeStamp::now().to_micros()) {
+            return Err(Error::TransactionExpired(transaction_id));
+        }
+
+        Ok(transaction)
+    }
+
+    /// Puts back the transaction, which couldn't be committed, so it can be committed again or aborted.
+    pub async fn restore(&self, transaction: Transaction) {
+        let mut transactions = self.transactions.lock().await;
+        transactions.insert(transaction.id, transaction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::messages::{Message, MessageState};
+
+    #[tokio::test]
+    async fn messages_should_be_added_to_the_transaction_of_the_same_user() {
+        let coordinator = create_coordinator(60, 10);
+        let user_id = 1;
+        let transaction_id = coordinator.begin(user_id).await;
+
+        coordinator
+            .add_messages(user_id, transaction_id, get_batch(2), 100)
+            .await
+            .unwrap();
+        let result = coordinator
+            .add_messages(2, transaction_id, get_batch(1), 100)
+            .await;
+        assert!(matches!(result, Err(Error::TransactionNotFound(id)) if id == transaction_id));
+
+        let transaction = coordinator.take(user_id, transaction_id).await.unwrap();
+        assert_eq!(transaction.id, transaction_id);
+        assert_eq!(transaction.messages_count, 2);
+        assert_eq!(transaction.size_bytes, 100);
+        assert_eq!(transaction.batches.len(), 1);
+        assert!(coordinator.take(user_id, transaction_id).await.is_err());
+
+        coordinator.restore(transaction).await;
+        let transaction = coordinator.take(user_id, transaction_id).await.unwrap();
+        assert_eq!(transaction.batches.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn transaction_should_not_exceed_the_messages_limit() {
+        let coordinator = create_coordinator(60, 3);
+        let transaction_id = coordinator.begin(1).await;
+
+        coordinator
+            .add_messages(1, transaction_id, get_batch(2), 100)
+            .await
+            .unwrap();
+        let result = coordinator
+            .add_messages(1, transaction_id, get_batch(2), 100)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::TooManyTransactionMessages(_, 3))
+        ));
+    }
+
+    #[tokio::test]
+    async fn expired_transaction_should_not_be_committed() {
+        let coordinator = create_coordinator(0, 10);
+        let transaction_id = coordinator.begin(1).await;
+
+        let result = coordinator.take(1, transaction_id).await;
+
+        assert!(matches!(result, Err(Error::TransactionExpired(id)) if id == transaction_id));
+    }
+
+    fn create_coordinator(timeout: u64, max_messages: u32) -> TransactionCoordinator {
+        TransactionCoordinator::new(&TransactionConfig {
+            timeout,
+            max_messages,
+        })
+    }
+
+    fn get_batch(messages_count: u128) -> TransactionBatch {
+        TransactionBatch {
+            stream_id: 1,
+            topic_id: 1,
+            partition_id: 1,
+            producer: None,
+            messages: (1..=messages_count)
+                .map(|id| {
+                    Message::create(
+                        0,
+                        MessageState::Available,
+                        0,
+                        id,
+                        Bytes::from("test"),
+                        0,
+                        None,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use iggy::messages::send_messages::Producer;
+use iggy::models::messages::Message;
+use iggy::models::user_info::UserId;
+
+#[derive(Debug)]
+pub struct Transaction {
+    pub id: u64,
+    pub user_id: UserId,
+    pub expires_at: u64,
+    pub messages_count: u32,
+    pub size_bytes: u64,
+    pub batches: Vec<TransactionBatch>,
+}
+
+#[derive(Debug)]
+pub struct TransactionBatch {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub producer: Option<Producer>,
+    pub messages: Vec<Message>,
+}
+
+impl Transaction {
+    pub fn new(id: u64, user_id: UserId, created_at: u64, timeout: u64) -> Self {
+        Transaction {
+            id,
+            user_id,
+            expires_at: created_at + timeout * 1_000_000,
+            messages_count: 0,
+            size_bytes: 0,
+            batches: Vec::new(),
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+}
@@ -2,6 +2,7 @@ mod consumer_groups;
 pub mod consumer_offsets;
 mod messages;
 mod partitions;
+mod schemas;
 mod streams;
 mod system;
 mod topics;
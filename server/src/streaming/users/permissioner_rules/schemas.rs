@@ -0,0 +1,34 @@
+use crate::streaming::users::permissioner::Permissioner;
+use iggy::error::Error;
+
+impl Permissioner {
+    pub fn get_schema(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), Error> {
+        self.get_topic(user_id, stream_id, topic_id)
+    }
+
+    pub fn get_schemas(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), Error> {
+        self.get_topic(user_id, stream_id, topic_id)
+    }
+
+    pub fn register_schema(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), Error> {
+        self.update_topic(user_id, stream_id, topic_id)
+    }
+
+    pub fn delete_schema(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), Error> {
+        self.update_topic(user_id, stream_id, topic_id)
+    }
+
+    pub fn update_schema_settings(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), Error> {
+        self.update_topic(user_id, stream_id, topic_id)
+    }
+}
@@ -14,6 +14,28 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_cluster_metadata(&self, user_id: u32) -> Result<(), Error> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn replicate_messages(&self, user_id: u32) -> Result<(), Error> {
+        self.manage_servers(user_id)
+    }
+
+    pub fn create_backup(&self, user_id: u32) -> Result<(), Error> {
+        self.manage_servers(user_id)
+    }
+
+    fn manage_servers(&self, user_id: u32) -> Result<(), Error> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(Error::Unauthorized)
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), Error> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.read_servers {
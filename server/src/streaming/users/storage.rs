@@ -99,6 +99,7 @@ impl Storage<User> for FileUserStorage {
         user.password = user_data.password;
         user.created_at = user_data.created_at;
         user.permissions = user_data.permissions;
+        user.quota = user_data.quota;
         Ok(())
     }
 
@@ -1,4 +1,5 @@
 use crate::streaming::utils::crypto;
+use iggy::models::user_quota::UserQuota;
 use iggy::models::user_status::UserStatus;
 use iggy::models::{permissions::Permissions, user_info::UserId};
 use iggy::users::defaults::*;
@@ -13,6 +14,9 @@ pub struct User {
     pub password: String,
     pub created_at: u64,
     pub permissions: Option<Permissions>,
+    /// Overrides the default user quota from the server config.
+    #[serde(default)]
+    pub quota: Option<UserQuota>,
 }
 
 impl Default for User {
@@ -24,6 +28,7 @@ impl Default for User {
             password: "secret".to_string(),
             created_at: TimeStamp::now().to_micros(),
             permissions: None,
+            quota: None,
         }
     }
 }
@@ -44,6 +49,7 @@ impl User {
             created_at: TimeStamp::now().to_micros(),
             status: UserStatus::Active,
             permissions,
+            quota: None,
         }
     }
 
@@ -1,34 +1,52 @@
 use crate::binary::command;
+use crate::binary::executor::RequestExecutor;
+use crate::binary::handshake;
 use crate::binary::sender::Sender;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use crate::tcp::sender;
+use crate::tcp::tcp_sender::TcpSender;
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
+use iggy::error::Error;
 use std::io::ErrorKind;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::io::{split, AsyncRead, AsyncWrite};
+use tokio::sync::{mpsc, RwLock};
 use tracing::{debug, error, info};
 
 const INITIAL_BYTES_LENGTH: usize = 4;
+const CORRELATION_ID_BYTES_LENGTH: usize = 4;
+const RESPONSES_BUFFER_SIZE: usize = 1000;
 
-pub(crate) async fn handle_connection(
+/// Reads the requests from the stream until it's closed. Once the client has negotiated the correlation IDs,
+/// the requests are queued to the executor, so the next ones are read while the previous ones are being handled,
+/// and the responses are written by the connection writer as soon as they are ready.
+pub(crate) async fn handle_connection<T>(
     address: &SocketAddr,
-    sender: &mut dyn Sender,
+    stream: T,
     system: Arc<RwLock<System>>,
-) -> Result<(), ServerError> {
+) -> Result<(), ServerError>
+where
+    T: AsyncRead + AsyncWrite + Send + 'static,
+{
     let client_id = system
         .read()
         .await
         .add_client(address, Transport::Tcp)
         .await;
 
+    let (mut reader, writer) = split(stream);
+    let (responses, responses_receiver) = mpsc::channel(RESPONSES_BUFFER_SIZE);
+    tokio::spawn(sender::write_responses(writer, responses_receiver));
     let mut session = Session::from_client_id(client_id);
+    let mut executor: Option<RequestExecutor> = None;
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
     loop {
-        let read_length = sender.read(&mut initial_buffer).await?;
+        let read_length = sender::read(&mut reader, &mut initial_buffer).await?;
         if read_length != INITIAL_BYTES_LENGTH {
             error!(
                 "Unable to read the TCP request length, expected: {} bytes, received: {} bytes.",
@@ -39,22 +57,101 @@ pub(crate) async fn handle_connection(
 
         let length = u32::from_le_bytes(initial_buffer);
         debug!("Received a TCP request, length: {}", length);
-        let mut command_buffer = vec![0u8; length as usize];
-        sender.read(&mut command_buffer).await?;
-        let command = Command::from_bytes(&command_buffer)?;
+        let mut request_buffer = vec![0u8; length as usize];
+        sender::read(&mut reader, &mut request_buffer).await?;
+        let Some(executor) = &executor else {
+            let mut sender = TcpSender {
+                correlation_id: None,
+                responses: responses.clone(),
+            };
+            if let Some(version) = handshake::negotiate_protocol_version(&request_buffer) {
+                info!(
+                    "Negotiated the TCP protocol version: {} for client with ID: {}.",
+                    version, client_id
+                );
+                sender.send_ok_response(&version.to_le_bytes()).await?;
+                executor = Some(RequestExecutor::start(
+                    session.clone(),
+                    system.clone(),
+                    "TCP",
+                ));
+                continue;
+            }
+
+            // The legacy clients expect the responses in the order of their requests.
+            handle_request(&request_buffer, &mut sender, &mut session, system.clone()).await?;
+            continue;
+        };
+
+        // Without the correlation ID, the client couldn't match any response to this request.
+        if request_buffer.len() < CORRELATION_ID_BYTES_LENGTH {
+            error!(
+                "Unable to read the TCP request correlation ID, expected: {} bytes, received: {} bytes, closing the connection.",
+                CORRELATION_ID_BYTES_LENGTH, request_buffer.len()
+            );
+            return Err(ServerError::SdkError(Error::InvalidCommand));
+        }
+
+        let correlation_id = u32::from_le_bytes(
+            request_buffer[..CORRELATION_ID_BYTES_LENGTH]
+                .try_into()
+                .unwrap(),
+        );
+        let mut sender = TcpSender {
+            correlation_id: Some(correlation_id),
+            responses: responses.clone(),
+        };
+        let request = &request_buffer[CORRELATION_ID_BYTES_LENGTH..];
+        let command = match read_command(request, &mut sender).await? {
+            Some(command) => command,
+            None => continue,
+        };
         debug!(
-            "Received a TCP command: {}, payload size: {}",
-            command, length
+            "Received a TCP command: {}, correlation ID: {}, payload size: {}",
+            command, correlation_id, length
         );
-        let result = command::handle(&command, sender, &mut session, system.clone()).await;
-        if result.is_err() {
-            error!("Error when handling the TCP request: {:?}", result.err());
-            continue;
+        executor.execute(command, Box::new(sender)).await?;
+    }
+}
+
+async fn handle_request(
+    request: &[u8],
+    sender: &mut TcpSender,
+    session: &mut Session,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    if let Some(command) = read_command(request, sender).await? {
+        debug!("Received a TCP command: {}", command);
+        handle_command(&command, sender, session, system).await;
+    }
+    Ok(())
+}
+
+/// Returns the command read from the request, or sends the error response if the request is invalid.
+async fn read_command(request: &[u8], sender: &mut TcpSender) -> Result<Option<Command>, Error> {
+    match Command::from_bytes(request) {
+        Ok(command) => Ok(Some(command)),
+        Err(error) => {
+            error!("Unable to read the TCP request command: {}", error);
+            sender.send_error_response(error).await?;
+            Ok(None)
         }
-        debug!("Sent a TCP response.");
     }
 }
 
+async fn handle_command(
+    command: &Command,
+    sender: &mut TcpSender,
+    session: &mut Session,
+    system: Arc<RwLock<System>>,
+) {
+    if let Err(error) = command::handle(command, sender, session, system).await {
+        error!("Error when handling the TCP request: {:?}", error);
+        return;
+    }
+    debug!("Handled a TCP request.");
+}
+
 pub(crate) fn handle_error(error: ServerError) {
     match error {
         ServerError::IoError(error) => match error.kind() {
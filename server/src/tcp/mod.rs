@@ -4,4 +4,3 @@ pub mod tcp_listener;
 mod tcp_sender;
 pub mod tcp_server;
 pub mod tcp_tls_listener;
-pub mod tcp_tls_sender;
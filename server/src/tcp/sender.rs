@@ -1,12 +1,13 @@
 use iggy::error::Error;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
-use tracing::debug;
+use tokio::sync::mpsc::Receiver;
+use tracing::{debug, error};
 
-const STATUS_OK: &[u8] = &[0; 4];
+pub(crate) const STATUS_OK: &[u8] = &[0; 4];
 
 pub(crate) async fn read<T>(stream: &mut T, buffer: &mut [u8]) -> Result<usize, Error>
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncRead + Unpin,
 {
     let read_bytes = stream.read_exact(buffer).await;
     if let Err(error) = read_bytes {
@@ -16,40 +17,33 @@ where
     Ok(read_bytes.unwrap())
 }
 
-pub(crate) async fn send_empty_ok_response<T>(stream: &mut T) -> Result<(), Error>
-where
-    T: AsyncRead + AsyncWrite + Unpin,
-{
-    send_ok_response(stream, &[]).await
-}
-
-pub(crate) async fn send_ok_response<T>(stream: &mut T, payload: &[u8]) -> Result<(), Error>
-where
-    T: AsyncRead + AsyncWrite + Unpin,
-{
-    send_response(stream, STATUS_OK, payload).await
-}
-
-pub(crate) async fn send_error_response<T>(stream: &mut T, error: Error) -> Result<(), Error>
-where
-    T: AsyncRead + AsyncWrite + Unpin,
-{
-    send_response(stream, &error.as_code().to_le_bytes(), &[]).await
-}
-
-pub(crate) async fn send_response<T>(
-    stream: &mut T,
+/// Returns the response framed as its status, length and payload,
+/// prefixed with the correlation ID of the request, if the connection uses one.
+pub(crate) fn frame_response(
+    correlation_id: Option<u32>,
     status: &[u8],
     payload: &[u8],
-) -> Result<(), Error>
+) -> Vec<u8> {
+    let length = (payload.len() as u32).to_le_bytes();
+    match correlation_id {
+        Some(correlation_id) => [&correlation_id.to_le_bytes(), status, &length, payload]
+            .as_slice()
+            .concat(),
+        None => [status, &length, payload].as_slice().concat(),
+    }
+}
+
+/// Writes the framed responses to the stream in the order in which the requests were completed.
+/// Stops once all the senders of the connection have been dropped or the stream can't be written.
+pub(crate) async fn write_responses<T>(mut stream: T, mut responses: Receiver<Vec<u8>>)
 where
-    T: AsyncRead + AsyncWrite + Unpin,
+    T: AsyncWrite + Unpin,
 {
-    debug!("Sending response with status: {:?}...", status);
-    let length = (payload.len() as u32).to_le_bytes();
-    stream
-        .write_all(&[status, &length, payload].as_slice().concat())
-        .await?;
-    debug!("Sent response with status: {:?}", status);
-    Ok(())
+    while let Some(response) = responses.recv().await {
+        if let Err(error) = stream.write_all(&response).await {
+            error!("Unable to write the TCP response: {}", error);
+            return;
+        }
+        debug!("Sent a TCP response, size: {} bytes.", response.len());
+    }
 }
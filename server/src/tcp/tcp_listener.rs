@@ -1,6 +1,5 @@
 use crate::streaming::systems::system::System;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
-use crate::tcp::tcp_sender::TcpSender;
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio::sync::RwLock;
@@ -20,10 +19,9 @@ pub fn start(address: &str, system: Arc<RwLock<System>>) {
                 Ok((stream, address)) => {
                     info!("Accepted new TCP connection: {}", address);
                     let system = system.clone();
-                    let mut sender = TcpSender { stream };
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(&address, &mut sender, system.clone()).await
+                            handle_connection(&address, stream, system.clone()).await
                         {
                             handle_error(error);
                             system.read().await.delete_client(&address).await;
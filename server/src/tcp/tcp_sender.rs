@@ -2,31 +2,44 @@ use crate::binary::sender::Sender;
 use crate::tcp::sender;
 use async_trait::async_trait;
 use iggy::error::Error;
-use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tracing::debug;
 
+/// Sends the response of a single request to the writer of its connection, which might be shared
+/// with the other requests being handled concurrently, so the response is tagged with the correlation ID.
 #[derive(Debug)]
 pub struct TcpSender {
-    pub(crate) stream: TcpStream,
+    pub(crate) correlation_id: Option<u32>,
+    pub(crate) responses: mpsc::Sender<Vec<u8>>,
 }
 
-unsafe impl Send for TcpSender {}
-unsafe impl Sync for TcpSender {}
-
 #[async_trait]
 impl Sender for TcpSender {
-    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, Error> {
-        sender::read(&mut self.stream, buffer).await
-    }
-
     async fn send_empty_ok_response(&mut self) -> Result<(), Error> {
-        sender::send_empty_ok_response(&mut self.stream).await
+        self.send_ok_response(&[]).await
     }
 
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), Error> {
-        sender::send_ok_response(&mut self.stream, payload).await
+        self.send_response(sender::STATUS_OK, payload).await
     }
 
     async fn send_error_response(&mut self, error: Error) -> Result<(), Error> {
-        sender::send_error_response(&mut self.stream, error).await
+        self.send_response(&error.as_code().to_le_bytes(), &[])
+            .await
+    }
+}
+
+impl TcpSender {
+    async fn send_response(&mut self, status: &[u8], payload: &[u8]) -> Result<(), Error> {
+        debug!(
+            "Sending response with status: {:?}, correlation ID: {:?}...",
+            status, self.correlation_id
+        );
+        let response = sender::frame_response(self.correlation_id, status, payload);
+        if self.responses.send(response).await.is_err() {
+            return Err(Error::NotConnected);
+        }
+
+        Ok(())
     }
 }
@@ -1,7 +1,6 @@
 use crate::configs::tcp::TcpTlsConfig;
 use crate::streaming::systems::system::System;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
-use crate::tcp::tcp_tls_sender::TcpTlsSender;
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio::sync::RwLock;
@@ -41,10 +40,9 @@ pub(crate) fn start(address: &str, config: TcpTlsConfig, system: Arc<RwLock<Syst
                     let acceptor = acceptor.clone();
                     let stream = acceptor.accept(stream).await.unwrap();
                     let system = system.clone();
-                    let mut sender = TcpTlsSender { stream };
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(&address, &mut sender, system.clone()).await
+                            handle_connection(&address, stream, system.clone()).await
                         {
                             handle_error(error);
                             system.read().await.delete_client(&address).await;
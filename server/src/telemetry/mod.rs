@@ -0,0 +1,103 @@
+use crate::configs::telemetry::TelemetryConfig;
+use crate::server_error::ServerError;
+use axum::http::{HeaderMap, Method};
+use iggy::command::Command;
+use iggy::telemetry::extract_context;
+use opentelemetry::global::BoxedSpan;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry::trace::{SpanKind, TraceContextExt, Tracer};
+use opentelemetry::{global, Context, KeyValue};
+use opentelemetry_otlp::{Protocol, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::TracerProvider;
+use opentelemetry_sdk::{runtime, Resource};
+use tracing::{error, info};
+
+const TRACER_NAME: &str = "iggy-server";
+
+/// Installs the global tracer provider exporting the spans to the OpenTelemetry collector.
+/// If the telemetry is disabled, the default no-op provider remains in place, so creating the spans costs next to nothing.
+pub fn init(config: &TelemetryConfig) -> Result<(), ServerError> {
+    if !config.enabled {
+        info!("Telemetry is disabled.");
+        return Ok(());
+    }
+
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_protocol(Protocol::HttpJson)
+        .with_endpoint(&config.endpoint)
+        .build()
+        .map_err(|error| ServerError::CannotInitializeTelemetry(error.to_string()))?;
+    let provider = TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_resource(Resource::new(vec![KeyValue::new(
+            "service.name",
+            config.service_name.clone(),
+        )]))
+        .build();
+    global::set_tracer_provider(provider);
+    info!(
+        "Telemetry is enabled, spans will be exported to: {} as service: {}.",
+        config.endpoint, config.service_name
+    );
+    Ok(())
+}
+
+/// Flushes the spans which haven't been exported yet. The provider blocks the current thread until the batch exporter
+/// running on the Tokio runtime has finished, therefore it's shut down on the dedicated blocking thread.
+pub async fn shutdown() {
+    if let Err(error) = tokio::task::spawn_blocking(global::shutdown_tracer_provider).await {
+        error!("Failed to shutdown the tracer provider: {error}");
+    }
+}
+
+/// Starts the span of handling the command. For the sent messages, the span continues the trace
+/// of the first message carrying the W3C trace context in its headers, so that the trace follows the message.
+pub fn start_command_span(command: &Command, client_id: u32, user_id: u32) -> BoxedSpan {
+    let parent = match command {
+        Command::SendMessages(command) => command
+            .messages
+            .iter()
+            .map(|message| extract_context(&message.headers))
+            .find(|context| context.span().span_context().is_valid())
+            .unwrap_or_default(),
+        _ => Context::new(),
+    };
+
+    let tracer = global::tracer(TRACER_NAME);
+    tracer
+        .span_builder(command.name())
+        .with_kind(SpanKind::Server)
+        .with_attributes(vec![
+            KeyValue::new("iggy.client_id", client_id as i64),
+            KeyValue::new("iggy.user_id", user_id as i64),
+        ])
+        .start_with_context(&tracer, &parent)
+}
+
+struct HttpHeadersExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HttpHeadersExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key)?.to_str().ok()
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+/// Starts the span of handling the HTTP request, continuing the trace from the W3C `traceparent` request header, if present.
+pub fn start_http_span(method: &Method, path: &str, headers: &HeaderMap) -> BoxedSpan {
+    let parent = TraceContextPropagator::new().extract(&HttpHeadersExtractor(headers));
+    let tracer = global::tracer(TRACER_NAME);
+    tracer
+        .span_builder(method.to_string())
+        .with_kind(SpanKind::Server)
+        .with_attributes(vec![
+            KeyValue::new("http.request.method", method.to_string()),
+            KeyValue::new("url.path", path.to_string()),
+        ])
+        .start_with_context(&tracer, &parent)
+}
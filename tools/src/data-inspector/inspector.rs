@@ -0,0 +1,689 @@
+use anyhow::Result;
+use bytes::BufMut;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::MessageState;
+use iggy::utils::checksum;
+use server::streaming::segments::compression;
+use server::streaming::segments::compression::{BATCH_HEADER_SIZE, SEGMENT_HEADER_SIZE};
+use server::streaming::segments::segment::{
+    INDEX_EXTENSION, LOG_EXTENSION, REMOTE_EXTENSION, TIME_INDEX_EXTENSION,
+};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+// Offset + state + timestamp + ID + checksum + headers length + payload length.
+const MESSAGE_HEADER_SIZE: usize = 8 + 1 + 8 + 16 + 4 + 4 + 4;
+const INDEX_SIZE: usize = 4;
+const TIME_INDEX_SIZE: usize = 8;
+const TEMP_EXTENSION: &str = "tmp";
+const READ_BUFFER_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug)]
+pub struct SegmentReport {
+    pub log_path: String,
+    pub messages_count: usize,
+    pub issues: Vec<String>,
+    pub repaired: bool,
+}
+
+impl SegmentReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+#[derive(Debug)]
+struct ScannedMessage {
+    offset: u64,
+    timestamp: u64,
+    // Relative to the end of the segment header, all the messages from the compressed batch share its position.
+    position: u32,
+}
+
+#[derive(Debug)]
+enum ScanError {
+    // The data is corrupted, so it's reported as the issue.
+    Invalid(String),
+    // The file cannot be read, so the inspection is aborted.
+    Io(std::io::Error),
+}
+
+impl From<std::io::Error> for ScanError {
+    fn from(error: std::io::Error) -> Self {
+        ScanError::Io(error)
+    }
+}
+
+impl From<String> for ScanError {
+    fn from(issue: String) -> Self {
+        ScanError::Invalid(issue)
+    }
+}
+
+#[derive(Debug)]
+struct ScannedLog {
+    header_size: usize,
+    messages: Vec<ScannedMessage>,
+    // Size of the data (excluding the segment header) up to the end of the last valid message or batch.
+    valid_size: usize,
+    issue: Option<String>,
+}
+
+/// Validates all the segments found under the streams path and returns the report for each of them.
+pub fn inspect(streams_path: &str, repair: bool) -> Result<Vec<SegmentReport>> {
+    let mut log_paths = Vec::new();
+    if Path::new(streams_path).exists() {
+        collect_log_paths(Path::new(streams_path), &mut log_paths)?;
+    } else {
+        warn!("Streams directory: {} does not exist.", streams_path);
+    }
+
+    log_paths.sort();
+    let mut reports = Vec::with_capacity(log_paths.len());
+    for log_path in log_paths {
+        let report = inspect_segment(&log_path, repair)?;
+        if report.is_valid() {
+            info!(
+                "Segment: {} is valid, messages: {}.",
+                report.log_path, report.messages_count
+            );
+        } else {
+            for issue in &report.issues {
+                warn!("Segment: {} is corrupted: {}.", report.log_path, issue);
+            }
+        }
+        if report.repaired {
+            info!(
+                "Segment: {} has been repaired, messages: {}.",
+                report.log_path, report.messages_count
+            );
+        }
+        reports.push(report);
+    }
+
+    Ok(reports)
+}
+
+fn collect_log_paths(directory: &Path, log_paths: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_log_paths(&path, log_paths)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == LOG_EXTENSION)
+        {
+            log_paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn inspect_segment(log_path: &Path, repair: bool) -> Result<SegmentReport> {
+    let mut report = SegmentReport {
+        log_path: log_path.display().to_string(),
+        messages_count: 0,
+        issues: Vec::new(),
+        repaired: false,
+    };
+    let start_offset = match log_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| stem.parse::<u64>().ok())
+    {
+        Some(start_offset) => start_offset,
+        None => {
+            report
+                .issues
+                .push("cannot parse the start offset from the file name".to_string());
+            return Ok(report);
+        }
+    };
+
+    let file = fs::File::open(log_path)?;
+    let log_size = file.metadata()?.len() as usize;
+    let mut reader = BufReader::with_capacity(READ_BUFFER_SIZE, file);
+    let scanned_log = match scan_log(&mut reader, log_size, start_offset) {
+        Ok(scanned_log) => scanned_log,
+        Err(ScanError::Invalid(issue)) => {
+            // The segment cannot be repaired without knowing its format, so it's only reported.
+            report.issues.push(issue);
+            return Ok(report);
+        }
+        Err(ScanError::Io(error)) => return Err(error.into()),
+    };
+
+    report.messages_count = scanned_log.messages.len();
+    if let Some(issue) = &scanned_log.issue {
+        report.issues.push(issue.clone());
+    }
+
+    let index_path = log_path.with_extension(INDEX_EXTENSION);
+    let time_index_path = log_path.with_extension(TIME_INDEX_EXTENSION);
+    let expected_positions = get_expected_positions(&scanned_log, start_offset);
+    let index = read_if_exists(&index_path)?;
+    let time_index = read_if_exists(&time_index_path)?;
+    let indexes_issues = validate_indexes(
+        &scanned_log,
+        start_offset,
+        &expected_positions,
+        &index,
+        &time_index,
+    );
+    report.issues.extend(indexes_issues);
+    if !repair || report.is_valid() {
+        return Ok(report);
+    }
+
+    if scanned_log.issue.is_some() {
+        let truncated_size = (scanned_log.header_size + scanned_log.valid_size) as u64;
+        let file = fs::OpenOptions::new().write(true).open(log_path)?;
+        file.set_len(truncated_size)?;
+        file.sync_all()?;
+        info!(
+            "Truncated log file: {} from {} to {} bytes.",
+            log_path.display(),
+            log_size,
+            truncated_size
+        );
+    }
+
+    let (index, time_index) = build_indexes(&expected_positions);
+    overwrite(&index_path, &index)?;
+    overwrite(&time_index_path, &time_index)?;
+    // The remote copy no longer matches the local one, so the segment will be uploaded again.
+    let remote_path = log_path.with_extension(REMOTE_EXTENSION);
+    if remote_path.exists() {
+        fs::remove_file(&remote_path)?;
+    }
+
+    report.repaired = true;
+    Ok(report)
+}
+
+/// Reads the messages one by one until the end of the log or the first invalid message (or batch), which is reported as the issue.
+/// Returns the invalid error only if the segment header is invalid.
+fn scan_log(
+    reader: &mut BufReader<fs::File>,
+    log_size: usize,
+    start_offset: u64,
+) -> Result<ScannedLog, ScanError> {
+    let mut header = Vec::with_capacity(SEGMENT_HEADER_SIZE as usize);
+    reader
+        .by_ref()
+        .take(SEGMENT_HEADER_SIZE as u64)
+        .read_to_end(&mut header)?;
+    let compression_algorithm = compression::read_segment_header(&header)
+        .map_err(|error| format!("invalid segment header: {}", error))?;
+    let (compression_algorithm, header_size) = match compression_algorithm {
+        Some(compression_algorithm) => (compression_algorithm, SEGMENT_HEADER_SIZE as usize),
+        None => {
+            // The log has no header, so the bytes read so far belong to the first message.
+            reader.seek(SeekFrom::Start(0))?;
+            (CompressionAlgorithm::None, 0)
+        }
+    };
+
+    let data_size = log_size - header_size;
+    let mut scanned_log = ScannedLog {
+        header_size,
+        messages: Vec::new(),
+        valid_size: 0,
+        issue: None,
+    };
+    let mut expected_offset = start_offset;
+    while scanned_log.valid_size < data_size {
+        let position = scanned_log.valid_size;
+        let remaining = data_size - position;
+        let result = match compression_algorithm {
+            CompressionAlgorithm::None => read_message(reader, remaining).map(|(message, size)| {
+                (
+                    vec![ScannedMessage {
+                        offset: message.offset,
+                        timestamp: message.timestamp,
+                        position: position as u32,
+                    }],
+                    size,
+                )
+            }),
+            compression_algorithm => {
+                read_batch(reader, remaining, compression_algorithm).map(|(messages, size)| {
+                    (
+                        messages
+                            .into_iter()
+                            .map(|message| ScannedMessage {
+                                offset: message.offset,
+                                timestamp: message.timestamp,
+                                position: position as u32,
+                            })
+                            .collect(),
+                        size,
+                    )
+                })
+            }
+        };
+
+        let (messages, size) = match result {
+            Ok(result) => result,
+            Err(ScanError::Invalid(issue)) => {
+                scanned_log.issue = Some(format!("{} at position: {}", issue, position));
+                break;
+            }
+            Err(ScanError::Io(error)) => return Err(ScanError::Io(error)),
+        };
+
+        // The offsets might have gaps after the compaction, but they must be increasing.
+        if let Some(message) = messages
+            .iter()
+            .find(|message| message.offset < expected_offset)
+        {
+            scanned_log.issue = Some(format!(
+                "unexpected offset: {}, expected at least: {} at position: {}",
+                message.offset, expected_offset, position
+            ));
+            break;
+        }
+
+        if let Some(message) = messages.last() {
+            expected_offset = message.offset + 1;
+        }
+        scanned_log.messages.extend(messages);
+        scanned_log.valid_size += size;
+    }
+
+    Ok(scanned_log)
+}
+
+#[derive(Debug)]
+struct MessageInfo {
+    offset: u64,
+    timestamp: u64,
+}
+
+/// Reads the next message, the lengths are checked against the remaining size before reading, so the corrupted ones are never allocated.
+fn read_message(
+    reader: &mut impl Read,
+    remaining: usize,
+) -> Result<(MessageInfo, usize), ScanError> {
+    if remaining < MESSAGE_HEADER_SIZE {
+        return Err("truncated message header".to_string().into());
+    }
+
+    let mut header = [0; MESSAGE_HEADER_SIZE - 4];
+    reader.read_exact(&mut header)?;
+    let offset = u64::from_le_bytes(header[..8].try_into().unwrap());
+    MessageState::from_code(header[8])
+        .map_err(|_| format!("invalid state: {} for offset: {}", header[8], offset))?;
+    let timestamp = u64::from_le_bytes(header[9..17].try_into().unwrap());
+    let checksum = u32::from_le_bytes(header[33..37].try_into().unwrap());
+    let headers_length = u32::from_le_bytes(header[37..41].try_into().unwrap()) as usize;
+    let mut size = header.len();
+    if remaining < size + headers_length + 4 {
+        return Err(format!("truncated headers for offset: {}", offset).into());
+    }
+
+    // The headers are followed by the payload length.
+    let mut headers = vec![0; headers_length + 4];
+    reader.read_exact(&mut headers)?;
+    if headers_length > 0
+        && HashMap::<HeaderKey, HeaderValue>::from_bytes(&headers[..headers_length]).is_err()
+    {
+        return Err(format!("invalid headers for offset: {}", offset).into());
+    }
+
+    size += headers.len();
+    let payload_length = u32::from_le_bytes(headers[headers_length..].try_into().unwrap()) as usize;
+    if remaining < size + payload_length {
+        return Err(format!("truncated payload for offset: {}", offset).into());
+    }
+
+    let mut payload = vec![0; payload_length];
+    reader.read_exact(&mut payload)?;
+    let calculated_checksum = checksum::calculate(&payload);
+    if calculated_checksum != checksum {
+        return Err(format!(
+            "invalid checksum: {}, expected: {} for offset: {}",
+            calculated_checksum, checksum, offset
+        )
+        .into());
+    }
+
+    Ok((MessageInfo { offset, timestamp }, size + payload_length))
+}
+
+fn read_batch(
+    reader: &mut impl Read,
+    remaining: usize,
+    compression_algorithm: CompressionAlgorithm,
+) -> Result<(Vec<MessageInfo>, usize), ScanError> {
+    let header_size = BATCH_HEADER_SIZE as usize;
+    if remaining < header_size {
+        return Err("truncated batch header".to_string().into());
+    }
+
+    let mut header = [0; BATCH_HEADER_SIZE as usize];
+    reader.read_exact(&mut header)?;
+    let compressed_length = u32::from_le_bytes(header[..4].try_into().unwrap()) as usize;
+    let uncompressed_length = u32::from_le_bytes(header[4..8].try_into().unwrap()) as usize;
+    if remaining < header_size + compressed_length {
+        return Err("truncated batch".to_string().into());
+    }
+
+    let mut compressed = vec![0; compressed_length];
+    reader.read_exact(&mut compressed)?;
+    let batch = compression::decompress(compression_algorithm, &compressed, uncompressed_length)
+        .map_err(|_| "cannot decompress batch".to_string())?;
+    if batch.len() != uncompressed_length {
+        return Err(format!(
+            "invalid batch size: {}, expected: {}",
+            batch.len(),
+            uncompressed_length
+        )
+        .into());
+    }
+
+    let mut messages = Vec::new();
+    let mut position = 0;
+    let mut batch_reader = batch.as_slice();
+    while position < batch.len() {
+        let (message, size) = read_message(&mut batch_reader, batch.len() - position).map_err(
+            |error| match error {
+                ScanError::Invalid(issue) => ScanError::Invalid(format!("{} in batch", issue)),
+                error => error,
+            },
+        )?;
+        messages.push(message);
+        position += size;
+    }
+
+    Ok((messages, header_size + compressed_length))
+}
+
+/// Returns the position for each offset from the start offset up to the last valid message.
+/// The offsets removed by the compaction point to the position of the next retained message.
+fn get_expected_positions(scanned_log: &ScannedLog, start_offset: u64) -> Vec<(u32, u64)> {
+    let last_offset = match scanned_log.messages.last() {
+        Some(message) => message.offset,
+        None => return Vec::new(),
+    };
+
+    let mut positions = Vec::with_capacity((last_offset - start_offset + 1) as usize);
+    let mut messages = scanned_log.messages.iter().peekable();
+    for offset in start_offset..=last_offset {
+        // The offsets are increasing, so there is always the retained message for the current offset or after it.
+        let message = messages.peek().unwrap();
+        positions.push((message.position, message.timestamp));
+        if message.offset == offset {
+            messages.next();
+        }
+    }
+
+    positions
+}
+
+fn validate_indexes(
+    scanned_log: &ScannedLog,
+    start_offset: u64,
+    expected_positions: &[(u32, u64)],
+    index: &[u8],
+    time_index: &[u8],
+) -> Vec<String> {
+    let mut issues = Vec::new();
+    if !index.len().is_multiple_of(INDEX_SIZE) {
+        issues.push(format!("index file has invalid size: {}", index.len()));
+    }
+
+    if !time_index.len().is_multiple_of(TIME_INDEX_SIZE) {
+        issues.push(format!(
+            "time index file has invalid size: {}",
+            time_index.len()
+        ));
+    }
+
+    let positions = index
+        .chunks_exact(INDEX_SIZE)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .collect::<Vec<u32>>();
+    let timestamps = time_index
+        .chunks_exact(TIME_INDEX_SIZE)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .collect::<Vec<u64>>();
+
+    if let Some(relative_offset) = positions
+        .windows(2)
+        .position(|positions| positions[1] < positions[0])
+    {
+        issues.push(format!(
+            "index is not monotonic at relative offset: {}",
+            relative_offset + 1
+        ));
+    }
+
+    if positions.len() < expected_positions.len() {
+        issues.push(format!(
+            "index has {} entries, expected: {}",
+            positions.len(),
+            expected_positions.len()
+        ));
+    } else if let Some(relative_offset) = expected_positions
+        .iter()
+        .zip(positions.iter())
+        .position(|((expected_position, _), position)| expected_position != position)
+    {
+        issues.push(format!(
+            "index entry for relative offset: {} does not match the log",
+            relative_offset
+        ));
+    }
+
+    // The offsets removed by the compaction after the last retained message point to the end of the log.
+    let trailing_positions = &positions[positions.len().min(expected_positions.len())..];
+    if trailing_positions
+        .iter()
+        .any(|position| *position as usize != scanned_log.valid_size)
+    {
+        issues.push(format!(
+            "index has {} entries beyond the last message",
+            trailing_positions.len()
+        ));
+    }
+
+    if timestamps.len() != positions.len().max(expected_positions.len()) {
+        issues.push(format!(
+            "time index has {} entries, expected: {}",
+            timestamps.len(),
+            positions.len().max(expected_positions.len())
+        ));
+    } else if let Some(message) = scanned_log
+        .messages
+        .iter()
+        .find(|message| timestamps[(message.offset - start_offset) as usize] != message.timestamp)
+    {
+        issues.push(format!(
+            "time index entry for offset: {} does not match the log",
+            message.offset
+        ));
+    }
+
+    issues
+}
+
+fn build_indexes(expected_positions: &[(u32, u64)]) -> (Vec<u8>, Vec<u8>) {
+    let mut index = Vec::with_capacity(expected_positions.len() * INDEX_SIZE);
+    let mut time_index = Vec::with_capacity(expected_positions.len() * TIME_INDEX_SIZE);
+    for (position, timestamp) in expected_positions {
+        index.put_u32_le(*position);
+        time_index.put_u64_le(*timestamp);
+    }
+
+    (index, time_index)
+}
+
+fn read_if_exists(path: &Path) -> Result<Vec<u8>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    Ok(fs::read(path)?)
+}
+
+fn overwrite(path: &Path, bytes: &[u8]) -> Result<()> {
+    // The file is written under the temporary name first, so it's never left partially written.
+    let temp_path = path.with_extension(format!(
+        "{}.{}",
+        path.extension().unwrap().to_string_lossy(),
+        TEMP_EXTENSION
+    ));
+    let mut file = fs::File::create(&temp_path)?;
+    file.write_all(bytes)?;
+    file.sync_all()?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::messages::Message;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DIRECTORY_ID: AtomicUsize = AtomicUsize::new(1);
+
+    #[test]
+    fn valid_segment_should_not_have_issues() {
+        let directory = create_directory();
+        let log_path = write_segment(&directory, 5);
+
+        let report = inspect_segment(&log_path, false).unwrap();
+
+        assert!(report.is_valid());
+        assert_eq!(report.messages_count, 5);
+        assert!(!report.repaired);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn truncated_log_should_be_repaired_up_to_the_last_valid_message() {
+        let directory = create_directory();
+        let log_path = write_segment(&directory, 5);
+        let log_size = fs::metadata(&log_path).unwrap().len();
+        let file = fs::OpenOptions::new().write(true).open(&log_path).unwrap();
+        file.set_len(log_size - 3).unwrap();
+
+        let report = inspect_segment(&log_path, false).unwrap();
+        assert!(!report.is_valid());
+        assert!(!report.repaired);
+
+        let report = inspect_segment(&log_path, true).unwrap();
+        assert!(report.repaired);
+        assert_eq!(report.messages_count, 4);
+
+        let report = inspect_segment(&log_path, false).unwrap();
+        assert!(report.is_valid());
+        assert_eq!(report.messages_count, 4);
+        assert_eq!(
+            fs::metadata(log_path.with_extension(TIME_INDEX_EXTENSION))
+                .unwrap()
+                .len(),
+            4 * TIME_INDEX_SIZE as u64
+        );
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn message_with_invalid_checksum_should_be_reported() {
+        let directory = create_directory();
+        let log_path = write_segment(&directory, 3);
+        let mut log = fs::read(&log_path).unwrap();
+        let last_byte = log.len() - 1;
+        log[last_byte] ^= 0xff;
+        fs::write(&log_path, log).unwrap();
+
+        let report = inspect_segment(&log_path, false).unwrap();
+
+        assert_eq!(report.messages_count, 2);
+        assert!(report.issues[0].starts_with("invalid checksum"));
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn message_with_invalid_payload_length_should_be_reported_as_truncated() {
+        let directory = create_directory();
+        let log_path = write_segment(&directory, 3);
+        let mut log = fs::read(&log_path).unwrap();
+        let message_size = log.len() / 3;
+        // The payload length of the last message follows its header (without the payload length) as there are no headers.
+        let payload_length_position = 2 * message_size + MESSAGE_HEADER_SIZE - 4;
+        log[payload_length_position..payload_length_position + 4]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+        fs::write(&log_path, log).unwrap();
+
+        let report = inspect_segment(&log_path, false).unwrap();
+
+        assert_eq!(report.messages_count, 2);
+        assert!(report.issues[0].starts_with("truncated payload for offset: 2"));
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn missing_index_entries_should_be_rebuilt() {
+        let directory = create_directory();
+        let log_path = write_segment(&directory, 3);
+        let index_path = log_path.with_extension(INDEX_EXTENSION);
+        let index = fs::read(&index_path).unwrap();
+        fs::write(&index_path, &index[..INDEX_SIZE]).unwrap();
+
+        let report = inspect_segment(&log_path, true).unwrap();
+        assert!(report.repaired);
+
+        assert_eq!(fs::read(&index_path).unwrap(), index);
+        assert!(inspect_segment(&log_path, false).unwrap().is_valid());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    fn create_directory() -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "iggy_data_inspector_{}_{}",
+            std::process::id(),
+            DIRECTORY_ID.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn write_segment(directory: &Path, messages_count: u64) -> PathBuf {
+        let log_path = directory.join(format!("{:0>20}.{}", 0, LOG_EXTENSION));
+        let mut log = Vec::new();
+        let mut index = Vec::new();
+        let mut time_index = Vec::new();
+        for offset in 0..messages_count {
+            let payload = Bytes::from(format!("message-{}", offset));
+            let checksum = checksum::calculate(&payload);
+            let timestamp = 1000 + offset;
+            let message = Message::create(
+                offset,
+                MessageState::Available,
+                timestamp,
+                offset as u128,
+                payload,
+                checksum,
+                None,
+            );
+            index.put_u32_le(log.len() as u32);
+            time_index.put_u64_le(timestamp);
+            message.extend(&mut log);
+        }
+
+        fs::write(&log_path, log).unwrap();
+        fs::write(log_path.with_extension(INDEX_EXTENSION), index).unwrap();
+        fs::write(log_path.with_extension(TIME_INDEX_EXTENSION), time_index).unwrap();
+        log_path
+    }
+}
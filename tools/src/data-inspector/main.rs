@@ -0,0 +1,54 @@
+mod inspector;
+
+use anyhow::Result;
+use clap::Parser;
+use server::configs::system::SystemConfig;
+use tracing::{error, info};
+
+/// Validates the segments stored in the server data directory and optionally repairs the corrupted ones.
+/// The tool must not be run while the server is using the data directory.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct DataInspectorArgs {
+    /// Path of the server data directory (the `system.path` from the server configuration)
+    #[arg(long, default_value = "local_data")]
+    pub path: String,
+
+    /// Truncate the corrupted segments to the last valid message and rebuild their indexes
+    #[arg(long, default_value_t = false)]
+    pub repair: bool,
+}
+
+fn main() -> Result<()> {
+    let args = DataInspectorArgs::parse();
+    tracing_subscriber::fmt::init();
+    let config = SystemConfig {
+        path: args.path,
+        ..SystemConfig::default()
+    };
+    let streams_path = config.get_streams_path();
+    info!(
+        "Inspecting segments in: {}, repair is {}...",
+        streams_path,
+        if args.repair { "enabled" } else { "disabled" }
+    );
+    let reports = inspector::inspect(&streams_path, args.repair)?;
+    let corrupted_segments_count = reports.iter().filter(|report| !report.is_valid()).count();
+    let repaired_segments_count = reports.iter().filter(|report| report.repaired).count();
+    info!(
+        "Inspected {} segments, found {} corrupted segments, repaired {} segments.",
+        reports.len(),
+        corrupted_segments_count,
+        repaired_segments_count
+    );
+
+    if corrupted_segments_count > repaired_segments_count {
+        error!("Some segments are corrupted, run the tool with --repair option to fix them.");
+        anyhow::bail!(
+            "Found {} corrupted segments.",
+            corrupted_segments_count - repaired_segments_count
+        );
+    }
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use iggy::client::{MessageClient, StreamClient, TopicClient};
 use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::send_messages::{Acks, Message, Partitioning, SendMessages};
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -54,6 +55,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "orders".to_string(),
                 partitions_count: 1,
                 message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
             })
             .await?;
 
@@ -64,6 +69,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "users".to_string(),
                 partitions_count: 2,
                 message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
             })
             .await?;
 
@@ -74,6 +83,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "notifications".to_string(),
                 partitions_count: 3,
                 message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
             })
             .await?;
 
@@ -84,6 +97,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "payments".to_string(),
                 partitions_count: 2,
                 message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
             })
             .await?;
 
@@ -94,6 +111,10 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "deliveries".to_string(),
                 partitions_count: 1,
                 message_expiry: None,
+                compression_algorithm: CompressionAlgorithm::default(),
+                compaction_enabled: false,
+                max_topic_size: None,
+                max_partition_size: None,
             })
             .await?;
     }
@@ -142,6 +163,9 @@ async fn send_messages(client: &IggyClient) -> Result<(), Error> {
                         stream_id: Identifier::numeric(stream_id)?,
                         topic_id: Identifier::numeric(topic.id)?,
                         partitioning: Partitioning::balanced(),
+                        producer: None,
+                        transaction_id: None,
+                        acks: Acks::default(),
                         messages,
                     })
                     .await?;